@@ -45,12 +45,19 @@ const NO_STRIDE: usize = 0;
 /// very efficiently. However, sequence assertions have liner complexity in the number of
 /// asserted values. Though, unless many thousands of values are asserted, practical impact of
 /// this linear complexity should be negligible.
+///
+/// For very large sequences (e.g., a public vector of many thousands of outputs), a sequence
+/// assertion can be declared as a **public column** via [Assertion::public_column()]. Such
+/// assertions are semantically identical to sequence assertions, but the verifier does not
+/// interpolate the asserted values into a polynomial; instead, it evaluates the interpolant
+/// directly using the barycentric formula.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Assertion<E: FieldElement> {
     pub(super) column: usize,
     pub(super) first_step: usize,
     pub(super) stride: usize,
     pub(super) values: Vec<E>,
+    pub(super) is_public_column: bool,
 }
 
 impl<E: FieldElement> Assertion<E> {
@@ -66,6 +73,7 @@ impl<E: FieldElement> Assertion<E> {
             first_step: step,
             stride: NO_STRIDE,
             values: vec![value],
+            is_public_column: false,
         }
     }
 
@@ -86,6 +94,7 @@ impl<E: FieldElement> Assertion<E> {
             first_step,
             stride,
             values: vec![value],
+            is_public_column: false,
         }
     }
 
@@ -118,9 +127,31 @@ impl<E: FieldElement> Assertion<E> {
             first_step,
             stride: if values.len() == 1 { NO_STRIDE } else { stride },
             values,
+            is_public_column: false,
         }
     }
 
+    /// Returns a public column assertion against multiple cells of a single column.
+    ///
+    /// The returned assertion is equivalent to the assertion returned by
+    /// [Assertion::sequence()]: values in the specified `column` must be equal to the provided
+    /// `values` at steps which start at `first_step` and repeat in equal intervals specified by
+    /// `stride`. However, boundary constraints built from public column assertions are evaluated
+    /// differently: the verifier evaluates the interpolant of the asserted values at a point
+    /// using the barycentric formula, and the prover interpolates the values using FFT only when
+    /// it needs to evaluate them over the constraint evaluation domain.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `stride` is not a power of two, or is smaller than 2.
+    /// * `first_step` is greater than `stride`.
+    /// * `values` is empty or number of values in not a power of two.
+    pub fn public_column(column: usize, first_step: usize, stride: usize, values: Vec<E>) -> Self {
+        let mut assertion = Self::sequence(column, first_step, stride, values);
+        assertion.is_public_column = assertion.is_sequence();
+        assertion
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -165,6 +196,14 @@ impl<E: FieldElement> Assertion<E> {
         self.values.len() > 1
     }
 
+    /// Returns true if this is a public column assertion (many values, many steps, evaluated by
+    /// the verifier without interpolation).
+    ///
+    /// Every public column assertion is also a sequence assertion.
+    pub fn is_public_column(&self) -> bool {
+        self.is_public_column
+    }
+
     // PUBLIC METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -300,6 +339,17 @@ impl<E: FieldElement> Assertion<E> {
             self.values.len()
         }
     }
+
+    /// Returns a copy of this assertion with asserted values mapped into field `F`.
+    pub(crate) fn lift<F: FieldElement + From<E>>(&self) -> Assertion<F> {
+        Assertion {
+            column: self.column,
+            first_step: self.first_step,
+            stride: self.stride,
+            values: self.values.iter().map(|&value| F::from(value)).collect(),
+            is_public_column: self.is_public_column,
+        }
+    }
 }
 
 // OTHER TRAIT IMPLEMENTATIONS
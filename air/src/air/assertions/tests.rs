@@ -199,6 +199,54 @@ fn sequence_assertion_num_values_not_power_of_two() {
     );
 }
 
+// PUBLIC COLUMN ASSERTIONS
+// ================================================================================================
+
+#[test]
+fn public_column_assertion() {
+    let values = rand_vector::<BaseElement>(4);
+    let a = Assertion::public_column(1, 1, 4, values.clone());
+    assert_eq!(1, a.column);
+    assert_eq!(1, a.first_step);
+    assert_eq!(values, a.values);
+    assert_eq!(4, a.stride);
+    assert_eq!(4, a.get_num_steps(16));
+    assert!(a.is_sequence());
+    assert!(a.is_public_column());
+
+    // public column assertions are equivalent to sequence assertions, except for the flag
+    let b = Assertion::sequence(1, 1, 4, values.clone());
+    assert!(!b.is_public_column());
+    assert_ne!(a, b);
+    assert_eq!(core::cmp::Ordering::Equal, a.cmp(&b));
+    assert!(a.overlaps_with(&b));
+
+    let mut steps = Vec::new();
+    a.apply(16, |step, val| {
+        assert_eq!(values[step / 4], val);
+        steps.push(step);
+    });
+    assert_eq!(vec![1, 5, 9, 13], steps);
+
+    assert_eq!(
+        Err(AssertionError::TraceLengthNotExact(16, 32)),
+        a.validate_trace_length(32)
+    );
+
+    // a public column with a single value is just a single-value assertion
+    let a = Assertion::public_column(1, 1, 4, values[..1].to_vec());
+    assert!(a.is_single());
+    assert!(!a.is_public_column());
+}
+
+#[test]
+#[should_panic(
+    expected = "invalid assertion for column 0: number of asserted values must be a power of two, but was 3"
+)]
+fn public_column_assertion_num_values_not_power_of_two() {
+    let _ = Assertion::public_column(0, 0, 2, rand_vector::<BaseElement>(3));
+}
+
 // OVERLAPPING ASSERTIONS
 // ================================================================================================
 
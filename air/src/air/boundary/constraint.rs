@@ -4,7 +4,7 @@
 // LICENSE file in the root directory of this source tree.
 
 use super::{Assertion, BTreeMap, ExtensionOf, FieldElement, Vec};
-use math::{fft, polynom};
+use math::{batch_inversion, fft, log2, polynom, StarkField};
 
 // BOUNDARY CONSTRAINT
 // ================================================================================================
@@ -25,6 +25,10 @@ use math::{fft, polynom};
 /// * `F` could be the base field of the protocol, in which case `E` is the extension field used.
 /// * `F` could be the extension field, in which case `F` and `E` are the same type.
 ///
+/// For constraints derived from public column assertions (see [Assertion::public_column()]), the
+/// value polynomial is not built. Instead, the constraint retains the asserted values, and $b(x)$
+/// is computed directly from these values using the barycentric formula.
+///
 /// Boundary constraints cannot be instantiated directly, they are created internally from
 /// [Assertions](Assertion).
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -36,6 +40,7 @@ where
     column: usize,
     poly: Vec<F>,
     poly_offset: (usize, F::BaseField),
+    public_values: Vec<F>,
     cc: (E, E),
 }
 
@@ -53,6 +58,25 @@ where
         twiddle_map: &mut BTreeMap<usize, Vec<F::BaseField>>,
         composition_coefficients: (E, E),
     ) -> Self {
+        // for public column assertions we keep the values as is; these will be interpolated
+        // only if and when a prover needs to evaluate them over the entire domain
+        if assertion.is_public_column() {
+            let poly_offset = if assertion.first_step == 0 {
+                (0, F::BaseField::ONE)
+            } else {
+                let x_offset = inv_g.exp((assertion.first_step as u64).into());
+                (assertion.first_step, x_offset)
+            };
+
+            return BoundaryConstraint {
+                column: assertion.column,
+                poly: Vec::new(),
+                poly_offset,
+                public_values: assertion.values,
+                cc: composition_coefficients,
+            };
+        }
+
         // build a polynomial which evaluates to constraint values at asserted steps; for
         // single-value assertions we use the value as constant coefficient of degree 0
         // polynomial; but for multi-value assertions, we need to interpolate the values
@@ -81,6 +105,7 @@ where
             column: assertion.column,
             poly,
             poly_offset,
+            public_values: Vec::new(),
             cc: composition_coefficients,
         }
     }
@@ -94,6 +119,9 @@ where
     }
 
     /// Returns a value polynomial for this constraint.
+    ///
+    /// For constraints derived from public column assertions this is an empty slice; the value
+    /// polynomial for such constraints can be built via [BoundaryConstraint::interpolate_poly()].
     pub fn poly(&self) -> &[F] {
         &self.poly
     }
@@ -107,11 +135,39 @@ where
         self.poly_offset
     }
 
+    /// Returns true if this constraint was derived from a public column assertion.
+    pub fn is_public_column(&self) -> bool {
+        !self.public_values.is_empty()
+    }
+
+    /// Returns values of the public column asserted by this constraint.
+    ///
+    /// For constraints which were not derived from public column assertions this is an empty
+    /// slice.
+    pub fn public_values(&self) -> &[F] {
+        &self.public_values
+    }
+
     /// Returns composition coefficients for this constraint.
     pub fn cc(&self) -> &(E, E) {
         &self.cc
     }
 
+    /// Returns a value polynomial for this constraint.
+    ///
+    /// For constraints derived from public column assertions, the polynomial is interpolated
+    /// from the public values using FFT; for all other constraints, this returns a copy of the
+    /// polynomial returned by [BoundaryConstraint::poly()].
+    pub fn interpolate_poly(&self) -> Vec<F> {
+        if !self.is_public_column() {
+            return self.poly.clone();
+        }
+        let mut poly = self.public_values.clone();
+        let inv_twiddles = fft::get_inv_twiddles(poly.len());
+        fft::interpolate_poly(&mut poly, &inv_twiddles);
+        poly
+    }
+
     // CONSTRAINT EVALUATOR
     // --------------------------------------------------------------------------------------------
     /// Evaluates this constraint at the specified point `x`.
@@ -122,8 +178,15 @@ where
     /// * $b$ is the value polynomial for this constraint.
     ///
     /// For boundary constraints derived from single and periodic assertions, $b(x)$ is a constant.
+    /// For boundary constraints derived from public column assertions, $b(x)$ is computed from
+    /// the public values in time linear in the number of values.
     pub fn evaluate_at(&self, x: E, trace_value: E) -> E {
-        let assertion_value = if self.poly.len() == 1 {
+        let assertion_value = if self.is_public_column() {
+            // evaluate the interpolant of public values at x * offset without building the
+            // value polynomial
+            let x = x * E::from(self.poly_offset.1);
+            evaluate_barycentric(&self.public_values, x)
+        } else if self.poly.len() == 1 {
             // if the value polynomial consists of just a constant, use that constant
             E::from(self.poly[0])
         } else {
@@ -141,3 +204,45 @@ where
         trace_value - assertion_value
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Evaluates the polynomial interpolating `values` over the multiplicative subgroup of size
+/// `values.len()` at the specified point `x` using the barycentric formula:
+/// $$
+/// b(x) = \frac{x^n - 1}{n} \cdot \sum_{i=0}^{n-1}{\frac{v_i \cdot g^i}{x - g^i}}
+/// $$
+/// where $g$ is the generator of the subgroup and $n$ is its size.
+///
+/// This requires a single field inversion and $O(n)$ multiplications.
+fn evaluate_barycentric<F, E>(values: &[F], x: E) -> E
+where
+    F: FieldElement,
+    E: FieldElement<BaseField = F::BaseField> + ExtensionOf<F>,
+{
+    let n = values.len();
+    let g = F::BaseField::get_root_of_unity(log2(n));
+
+    // compute x - g^i for all i; if x is in the subgroup, the value of the interpolant at x is
+    // the corresponding public value
+    let mut denominators = Vec::with_capacity(n);
+    let mut g_i = F::BaseField::ONE;
+    for &value in values.iter() {
+        let denominator = x - E::from(g_i);
+        if denominator == E::ZERO {
+            return E::from(value);
+        }
+        denominators.push(denominator);
+        g_i *= g;
+    }
+
+    let mut result = E::ZERO;
+    let mut g_i = F::BaseField::ONE;
+    for (&value, &inv_denominator) in values.iter().zip(batch_inversion(&denominators).iter()) {
+        result += inv_denominator.mul_base(value) * E::from(g_i);
+        g_i *= g;
+    }
+
+    result * (x.exp((n as u64).into()) - E::ONE) / E::from(n as u64)
+}
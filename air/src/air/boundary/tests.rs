@@ -191,6 +191,68 @@ fn boundary_constraint_from_sequence_assertion() {
     );
 }
 
+#[test]
+fn boundary_constraint_from_public_column_assertion() {
+    let (inv_g, mut twiddle_map, mut prng) = build_constraint_params(64);
+
+    // constraint should be built correctly for column 1, first step 0, stride 4
+    let values = rand_vector::<BaseElement>(16);
+    let constraint_poly = build_sequence_poly(&values, 64);
+    let assertion = Assertion::public_column(1, 0, 4, values.clone());
+    let constraint = BoundaryConstraint::<BaseElement, BaseElement>::new(
+        assertion,
+        inv_g,
+        &mut twiddle_map,
+        prng.draw_pair().unwrap(),
+    );
+    assert_eq!(1, constraint.column());
+    assert!(constraint.is_public_column());
+    assert!(constraint.poly().is_empty());
+    assert_eq!(values, constraint.public_values());
+    assert_eq!(constraint_poly, constraint.interpolate_poly());
+    assert_eq!((0, BaseElement::ONE), constraint.poly_offset());
+
+    // public values are not interpolated when the constraint is built
+    assert!(twiddle_map.is_empty());
+
+    // public column constraints should evaluate to trace_value - constraint_poly(x)
+    let x = rand_value::<BaseElement>();
+    let trace_value = rand_value::<BaseElement>();
+    assert_eq!(
+        trace_value - polynom::eval(&constraint_poly, x),
+        constraint.evaluate_at(x, trace_value)
+    );
+
+    // constraint should be built correctly for column 0, first step 3, stride 8
+    let values = rand_vector::<BaseElement>(8);
+    let constraint_poly = build_sequence_poly(&values, 64);
+    let assertion = Assertion::public_column(0, 3, 8, values.clone());
+    let constraint = BoundaryConstraint::<BaseElement, BaseElement>::new(
+        assertion,
+        inv_g,
+        &mut twiddle_map,
+        prng.draw_pair().unwrap(),
+    );
+    assert_eq!(constraint_poly, constraint.interpolate_poly());
+    assert_eq!((3, inv_g.exp(3)), constraint.poly_offset());
+
+    // public column constraints with offset should evaluate to
+    // trace_value - constraint_poly(x * offset)
+    let x = rand_value::<BaseElement>();
+    let trace_value = rand_value::<BaseElement>();
+    assert_eq!(
+        trace_value - polynom::eval(&constraint_poly, x * constraint.poly_offset().1),
+        constraint.evaluate_at(x, trace_value)
+    );
+
+    // at the asserted steps, constraints should evaluate to trace_value - asserted value
+    let g = inv_g.inv();
+    for (i, &value) in values.iter().enumerate() {
+        let x = g.exp((3 + 8 * i as u64).into());
+        assert_eq!(trace_value - value, constraint.evaluate_at(x, trace_value));
+    }
+}
+
 // PREPARE ASSERTIONS
 // ================================================================================================
 
@@ -3,7 +3,11 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use crate::{air::TransitionConstraintDegree, ProofOptions, TraceInfo};
+use crate::{
+    air::{TransitionConstraintDegree, TransitionDivisor},
+    ProofOptions, TraceInfo,
+};
+use core::cmp;
 use math::{log2, StarkField};
 use utils::collections::Vec;
 
@@ -21,7 +25,9 @@ pub struct AirContext<B: StarkField> {
     pub(super) ce_blowup_factor: usize,
     pub(super) trace_domain_generator: B,
     pub(super) lde_domain_generator: B,
-    pub(super) num_transition_exemptions: usize,
+    pub(super) transition_divisors: Vec<TransitionDivisor>,
+    pub(super) main_transition_divisor_indexes: Vec<usize>,
+    pub(super) aux_transition_divisor_indexes: Vec<usize>,
 }
 
 impl<B: StarkField> AirContext<B> {
@@ -146,6 +152,10 @@ impl<B: StarkField> AirContext<B> {
         let trace_length = trace_info.length();
         let lde_domain_size = trace_length * options.blowup_factor();
 
+        // initially, all transition constraints share the default divisor
+        let main_transition_divisor_indexes = vec![0; main_transition_constraint_degrees.len()];
+        let aux_transition_divisor_indexes = vec![0; aux_transition_constraint_degrees.len()];
+
         AirContext {
             options,
             trace_info,
@@ -156,7 +166,9 @@ impl<B: StarkField> AirContext<B> {
             ce_blowup_factor,
             trace_domain_generator: B::get_root_of_unity(log2(trace_length)),
             lde_domain_generator: B::get_root_of_unity(log2(lde_domain_size)),
-            num_transition_exemptions: 1,
+            transition_divisors: vec![TransitionDivisor::new(1)],
+            main_transition_divisor_indexes,
+            aux_transition_divisor_indexes,
         }
     }
 
@@ -234,7 +246,28 @@ impl<B: StarkField> AirContext<B> {
     /// The maximum number of exemptions is determined by a combination of transition constraint
     /// degrees and blowup factor specified for the computation.
     pub fn num_transition_exemptions(&self) -> usize {
-        self.num_transition_exemptions
+        self.transition_divisors[0].num_exemptions()
+    }
+
+    /// Returns a list of distinct transition divisors used by transition constraints of a
+    /// computation.
+    ///
+    /// The first divisor in the list is always the default divisor which applies to all
+    /// transition constraints for which a different divisor has not been set.
+    pub fn transition_divisors(&self) -> &[TransitionDivisor] {
+        &self.transition_divisors
+    }
+
+    /// Returns the divisor of the transition constraint at the specified index in the list of
+    /// main trace segment transition constraints.
+    pub fn main_transition_divisor(&self, constraint_idx: usize) -> &TransitionDivisor {
+        &self.transition_divisors[self.main_transition_divisor_indexes[constraint_idx]]
+    }
+
+    /// Returns the divisor of the transition constraint at the specified index in the list of
+    /// auxiliary trace segment transition constraints.
+    pub fn aux_transition_divisor(&self, constraint_idx: usize) -> &TransitionDivisor {
+        &self.transition_divisors[self.aux_transition_divisor_indexes[constraint_idx]]
     }
 
     // DATA MUTATORS
@@ -278,7 +311,146 @@ impl<B: StarkField> AirContext<B> {
             )
         }
 
-        self.num_transition_exemptions = n;
+        self.transition_divisors[0] = TransitionDivisor::new(n);
+        self
+    }
+
+    /// Sets the divisor for the specified transition constraints against the main trace segment.
+    ///
+    /// The constraints are identified by their indexes in the list of main transition constraint
+    /// degrees used to instantiate this context. Once the divisor is set, the constraints will
+    /// need to hold only on the steps of the execution trace described by the divisor.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * Any of the constraint indexes is out of bounds.
+    /// * The divisor is not valid for the execution trace described by this context.
+    /// * Given the degrees of the specified constraints, the blowup factor in this context is too
+    ///   small to accommodate the divisor.
+    pub fn set_main_transition_divisor<I>(
+        mut self,
+        constraints: I,
+        divisor: TransitionDivisor,
+    ) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let divisor_idx = self.add_transition_divisor(divisor);
+        for constraint_idx in constraints {
+            assert!(
+                constraint_idx < self.main_transition_divisor_indexes.len(),
+                "main transition constraint index {} is out of bounds",
+                constraint_idx
+            );
+            self.main_transition_divisor_indexes[constraint_idx] = divisor_idx;
+        }
+        self.update_ce_blowup_factor();
+        self
+    }
+
+    /// Sets the divisor for the specified transition constraints against auxiliary trace
+    /// segments.
+    ///
+    /// The constraints are identified by their indexes in the list of auxiliary transition
+    /// constraint degrees used to instantiate this context. Once the divisor is set, the
+    /// constraints will need to hold only on the steps of the execution trace described by the
+    /// divisor.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * Any of the constraint indexes is out of bounds.
+    /// * The divisor is not valid for the execution trace described by this context.
+    /// * Given the degrees of the specified constraints, the blowup factor in this context is too
+    ///   small to accommodate the divisor.
+    pub fn set_aux_transition_divisor<I>(
+        mut self,
+        constraints: I,
+        divisor: TransitionDivisor,
+    ) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let divisor_idx = self.add_transition_divisor(divisor);
+        for constraint_idx in constraints {
+            assert!(
+                constraint_idx < self.aux_transition_divisor_indexes.len(),
+                "auxiliary transition constraint index {} is out of bounds",
+                constraint_idx
+            );
+            self.aux_transition_divisor_indexes[constraint_idx] = divisor_idx;
+        }
+        self.update_ce_blowup_factor();
         self
     }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the divisor to the list of transition divisors (unless an identical divisor is
+    /// already in the list) and returns its index in the list.
+    fn add_transition_divisor(&mut self, divisor: TransitionDivisor) -> usize {
+        let num_steps = self.trace_len() / divisor.cycle_length();
+        assert!(
+            divisor.cycle_length() <= self.trace_len(),
+            "transition divisor cycle length cannot exceed trace length {}, but was {}",
+            self.trace_len(),
+            divisor.cycle_length()
+        );
+        assert!(
+            divisor.num_exemptions() < num_steps,
+            "number of transition divisor exemptions must be smaller than {}, but was {}",
+            num_steps,
+            divisor.num_exemptions()
+        );
+
+        // the default divisor is never shared with constraints which set their own divisor
+        // because its exemptions can be changed later on
+        match self.transition_divisors[1..]
+            .iter()
+            .position(|d| d == &divisor)
+        {
+            Some(idx) => idx + 1,
+            None => {
+                self.transition_divisors.push(divisor);
+                self.transition_divisors.len() - 1
+            }
+        }
+    }
+
+    /// Updates the constraint evaluation blowup factor to accommodate constraints which have
+    /// non-default divisors.
+    ///
+    /// A divisor which applies to a subset of trace steps has a smaller degree than the default
+    /// divisor; thus, the degree of rational function `C(x) / z(x)` for such a constraint is
+    /// larger than it would be with the default divisor.
+    fn update_ce_blowup_factor(&mut self) {
+        let trace_length = self.trace_len();
+        let degrees = self
+            .main_transition_constraint_degrees
+            .iter()
+            .zip(self.main_transition_divisor_indexes.iter())
+            .chain(
+                self.aux_transition_constraint_degrees
+                    .iter()
+                    .zip(self.aux_transition_divisor_indexes.iter()),
+            );
+
+        for (degree, &divisor_idx) in degrees {
+            if divisor_idx == 0 {
+                continue;
+            }
+            let divisor_degree = self.transition_divisors[divisor_idx].degree(trace_length);
+            let quotient_degree = degree.get_evaluation_degree(trace_length) - divisor_degree;
+            let ce_domain_size = cmp::max(quotient_degree, trace_length + 1).next_power_of_two();
+            let blowup_factor = cmp::max(degree.min_blowup_factor(), ce_domain_size / trace_length);
+            self.ce_blowup_factor = cmp::max(self.ce_blowup_factor, blowup_factor);
+        }
+
+        assert!(
+            self.options.blowup_factor() >= self.ce_blowup_factor,
+            "blowup factor too small; expected at least {}, but was {}",
+            self.ce_blowup_factor,
+            self.options.blowup_factor()
+        );
+    }
 }
@@ -58,10 +58,60 @@ impl<B: StarkField> ConstraintDivisor<B> {
             num_exemptions > 0,
             "invalid number of transition exemptions: must be greater than zero"
         );
-        let exemptions = (trace_length - num_exemptions..trace_length)
-            .map(|step| get_trace_domain_value_at::<B>(trace_length, step))
+        Self::from_periodic_transition(trace_length, 1, 0, num_exemptions)
+    }
+
+    /// Builds a divisor for transition constraints which hold only on a periodic subset of
+    /// steps of the execution trace.
+    ///
+    /// The constraints are expected to hold on steps $a, a + j, a + 2 \cdot j, ...$ where $j$ is
+    /// the cycle length and $a$ is the offset within the cycle, except for the last $e$ of these
+    /// steps. The divisor polynomial for such constraints is:
+    ///
+    /// $$
+    /// z(x) = \frac{x^k - g^{a \cdot k}}{ \prod_{i=1}^e (x - g^{a + (k - i) \cdot j})}
+    /// $$
+    ///
+    /// where $k = n / j$ is the number of steps in the subset, $n$ is the length of the execution
+    /// trace, and $g$ is the generator of the trace domain. For $j = 1$ and $a = 0$ this is the
+    /// same divisor as the one built by [ConstraintDivisor::from_transition()].
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `cycle_length` is not a power of two, or is greater than `trace_length`.
+    /// * `offset` is greater than or equal to `cycle_length`.
+    /// * `num_exemptions` is greater than or equal to the number of steps in the subset.
+    pub fn from_periodic_transition(
+        trace_length: usize,
+        cycle_length: usize,
+        offset: usize,
+        num_exemptions: usize,
+    ) -> Self {
+        assert!(
+            cycle_length.is_power_of_two() && cycle_length <= trace_length,
+            "cycle length must be a power of two no greater than {}, but was {}",
+            trace_length,
+            cycle_length
+        );
+        assert!(
+            offset < cycle_length,
+            "cycle offset must be smaller than {}, but was {}",
+            cycle_length,
+            offset
+        );
+        let num_steps = trace_length / cycle_length;
+        assert!(
+            num_exemptions < num_steps,
+            "number of transition exemptions must be smaller than {}, but was {}",
+            num_steps,
+            num_exemptions
+        );
+
+        let numerator_offset = get_trace_domain_value_at::<B>(trace_length, offset * num_steps);
+        let exemptions = (num_steps - num_exemptions..num_steps)
+            .map(|i| get_trace_domain_value_at::<B>(trace_length, offset + i * cycle_length))
             .collect();
-        Self::new(vec![(trace_length, B::ONE)], exemptions)
+        Self::new(vec![(num_steps, numerator_offset)], exemptions)
     }
 
     /// Builds a divisor for a boundary constraint described by the assertion.
@@ -187,6 +237,7 @@ pub fn get_trace_domain_value_at<B: StarkField>(trace_length: usize, step: usize
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::air::TransitionDivisor;
     use math::{fields::f128::BaseElement, polynom};
 
     #[test]
@@ -251,6 +302,42 @@ mod tests {
         assert_eq!(expected, div.evaluate_at(BaseElement::new(4)));
     }
 
+    #[test]
+    fn periodic_transition_divisor() {
+        let n = 32_usize;
+        let g = BaseElement::get_root_of_unity(n.trailing_zeros());
+
+        // with cycle length 1 and offset 0 the divisor is the same as the default one
+        assert_eq!(
+            ConstraintDivisor::<BaseElement>::from_transition(n, 2),
+            ConstraintDivisor::from_periodic_transition(n, 1, 0, 2)
+        );
+
+        // the divisor must evaluate to zero exactly on the steps described by the spec
+        for (cycle_length, offset, num_exemptions) in [(4, 3, 1), (8, 2, 0), (8, 5, 2), (32, 7, 0)]
+        {
+            let spec = TransitionDivisor::periodic(cycle_length, offset)
+                .with_num_exemptions(num_exemptions);
+            let divisor = spec.to_constraint_divisor::<BaseElement>(n);
+            assert_eq!(n / cycle_length - num_exemptions, divisor.degree());
+            assert_eq!(spec.degree(n), divisor.degree());
+            assert_eq!(num_exemptions, divisor.exemptions().len());
+
+            for i in 0..n {
+                let x = g.exp((i as u32).into());
+                if divisor.exemptions().contains(&x) {
+                    // exempt steps are on the last cycles of the trace
+                    assert_eq!(offset, i % cycle_length);
+                    assert!(i / cycle_length >= n / cycle_length - num_exemptions);
+                    assert!(!spec.is_active_at(i, n));
+                    continue;
+                }
+                let value = divisor.evaluate_at(x);
+                assert_eq!(spec.is_active_at(i, n), value == BaseElement::ZERO);
+            }
+        }
+    }
+
     #[test]
     fn constraint_divisor_equivalence() {
         let n = 8_usize;
@@ -4,11 +4,12 @@
 // LICENSE file in the root directory of this source tree.
 
 use crate::ProofOptions;
+use core::marker::PhantomData;
 use crypto::{Hasher, RandomCoin, RandomCoinError};
 use math::{fft, ExtensibleField, ExtensionOf, FieldElement, StarkField};
 use utils::{
     collections::{BTreeMap, Vec},
-    Serializable,
+    ByteWriter, Serializable,
 };
 
 mod trace_info;
@@ -26,6 +27,7 @@ pub use boundary::{BoundaryConstraint, BoundaryConstraintGroup, BoundaryConstrai
 mod transition;
 pub use transition::{
     EvaluationFrame, TransitionConstraintDegree, TransitionConstraintGroup, TransitionConstraints,
+    TransitionDivisor,
 };
 
 mod coefficients;
@@ -36,6 +38,12 @@ pub use coefficients::{
 mod divisor;
 pub use divisor::ConstraintDivisor;
 
+mod segment;
+pub use segment::SegmentPublicInputs;
+
+mod padding;
+pub use padding::TracePadding;
+
 #[cfg(test)]
 mod tests;
 
@@ -66,6 +74,8 @@ const MIN_CYCLE_LENGTH: usize = 2;
 ///    [assertions](#trace-assertions) for a given instance of your computation.
 /// 7. If your computation requires [periodic values](#periodic-values), you can also override
 ///    the default [Air::get_periodic_column_values()] method.
+/// 8. Optionally, set [Air::ID] to a unique byte string identifying your computation. This
+///    ensures that proofs generated for your computation are bound to it at the transcript level.
 ///
 /// If your computation uses [Randomized AIR](#randomized-air), you will also need to override
 /// [Air::evaluate_aux_transition()] and [Air::get_aux_assertions()] methods.
@@ -171,9 +181,11 @@ const MIN_CYCLE_LENGTH: usize = 2;
 ///   will need to provide a [TraceLayout] which describes the shape of a multi-segment execution
 ///   trace.
 /// * Override [Air::evaluate_aux_transition()] method. This method is similar to the
-///   [Air::evaluate_transition()] method but it also accepts two extra parameters:
-///   `aux_evaluation_frame` and `aux_rand_elements`. These parameters are needed for evaluating
-///   transition constraints over the auxiliary trace segments.
+///   [Air::evaluate_transition()] method but it also accepts three extra parameters:
+///   `aux_evaluation_frame`, `aux_periodic_values`, and `aux_rand_elements`. These parameters
+///   are needed for evaluating transition constraints over the auxiliary trace segments.
+/// * Optionally, override [Air::get_aux_periodic_column_values()] method to define periodic
+///   columns with values which depend on `aux_rand_elements`.
 /// * Override [Air::get_aux_assertions()] method. This method is similar to the
 ///   [Air::get_assertions()] method, but it should return assertions against columns of the
 ///   auxiliary trace segments.
@@ -187,6 +199,17 @@ pub trait Air: Send + Sync {
     /// This could be any type as long as it can be serialized into a sequence of bytes.
     type PublicInputs: Serializable;
 
+    /// Domain separation tag for the computation described by this AIR.
+    ///
+    /// The tag is absorbed into the seed of the public coin by both the prover and the verifier.
+    /// Thus, a proof generated for one AIR will not be accepted by a verifier instantiated with
+    /// an AIR which has a different tag, even if the public inputs and proof contexts of the two
+    /// are byte-for-byte identical.
+    ///
+    /// The default tag is an empty byte string. The tag must not be longer than 255 bytes; this
+    /// is checked at compile time when the AIR is used to generate or to verify proofs.
+    const ID: &'static [u8] = &[];
+
     // REQUIRED METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -235,7 +258,9 @@ pub trait Air: Send + Sync {
     /// transition constraints defined for this computation.
     ///
     /// The default implementation of this function panics. It must be overridden for AIRs
-    /// describing computations which require multiple trace segments.
+    /// describing computations which require multiple trace segments, unless the AIR overrides
+    /// [evaluate_aux_transition_with_periodic_values()](Air::evaluate_aux_transition_with_periodic_values)
+    /// instead.
     ///
     /// The types for main and auxiliary trace evaluation frames are defined as follows:
     /// * When the entire protocol is executed in a prime field, types `F` and `E` are the same,
@@ -265,6 +290,44 @@ pub trait Air: Send + Sync {
         unimplemented!("evaluation of auxiliary transition constraints has not been implemented");
     }
 
+    /// Evaluates transition constraints over the specified evaluation frames for the main and
+    /// auxiliary trace segments given the values of auxiliary periodic columns at the current
+    /// step.
+    ///
+    /// This is the method invoked by the prover and the verifier to evaluate auxiliary transition
+    /// constraints. Values of auxiliary periodic columns defined by
+    /// [get_aux_periodic_column_values()](Air::get_aux_periodic_column_values) are passed in via
+    /// the `aux_periodic_values` parameter; all other parameters are the same as for
+    /// [evaluate_aux_transition()](Air::evaluate_aux_transition).
+    ///
+    /// The default implementation of this function ignores `aux_periodic_values` and delegates
+    /// to [evaluate_aux_transition()](Air::evaluate_aux_transition). It must be overridden for
+    /// AIRs which define auxiliary periodic columns.
+    fn evaluate_aux_transition_with_periodic_values<F, E>(
+        &self,
+        main_frame: &EvaluationFrame<F>,
+        aux_frame: &EvaluationFrame<E>,
+        periodic_values: &[F],
+        aux_periodic_values: &[E],
+        aux_rand_elements: &AuxTraceRandElements<E>,
+        result: &mut [E],
+    ) where
+        F: FieldElement<BaseField = Self::BaseField>,
+        E: FieldElement<BaseField = Self::BaseField> + ExtensionOf<F>,
+    {
+        debug_assert!(
+            aux_periodic_values.is_empty(),
+            "AIRs with auxiliary periodic columns must override evaluate_aux_transition_with_periodic_values()"
+        );
+        self.evaluate_aux_transition(
+            main_frame,
+            aux_frame,
+            periodic_values,
+            aux_rand_elements,
+            result,
+        );
+    }
+
     /// Returns a set of assertions placed against auxiliary trace segments.
     ///
     /// The default implementation of this function returns an empty vector. It should be
@@ -287,6 +350,26 @@ pub trait Air: Send + Sync {
         Vec::new()
     }
 
+    // TRACE PADDING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the policy for padding execution traces whose length is not a power of two.
+    ///
+    /// The default implementation returns [TracePadding::None], and thus, the prover rejects
+    /// traces whose length is not a power of two.
+    fn trace_padding(&self) -> TracePadding {
+        TracePadding::None
+    }
+
+    /// Computes the `next` padding row of the main trace segment from the `current` row.
+    ///
+    /// This method is invoked by the prover only if [trace_padding()](Air::trace_padding) returns
+    /// [TracePadding::Transition]; for the first padding row, `current` is the last row of the
+    /// original trace. The default implementation copies the current row into the next row.
+    fn apply_padding_transition(&self, current: &[Self::BaseField], next: &mut [Self::BaseField]) {
+        next.copy_from_slice(current);
+    }
+
     // PROVIDED METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -308,39 +391,42 @@ pub trait Air: Send + Sync {
     /// These polynomials are interpolated from the values returned from the
     /// [get_periodic_column_values()](Air::get_periodic_column_values) method.
     fn get_periodic_column_polys(&self) -> Vec<Vec<Self::BaseField>> {
-        // cache inverse twiddles for each cycle length so that we don't have to re-build them
-        // for columns with identical cycle lengths
-        let mut twiddle_map = BTreeMap::new();
-        // iterate over all periodic columns and convert column values into polynomials
-        self.get_periodic_column_values()
-            .into_iter()
-            .map(|mut column| {
-                let cycle_length = column.len();
-                assert!(
-                    cycle_length >= MIN_CYCLE_LENGTH,
-                    "number of values in a periodic column must be at least {}, but was {}",
-                    MIN_CYCLE_LENGTH,
-                    cycle_length
-                );
-                assert!(
-                    cycle_length.is_power_of_two(),
-                    "number of values in a periodic column must be a power of two, but was {}",
-                    cycle_length
-                );
-                assert!(cycle_length <= self.trace_length(),
-                    "number of values in a periodic column cannot exceed trace length {}, but was {}",
-                    self.trace_length(),
-                    cycle_length
-                );
-
-                // get twiddles for interpolation and interpolate values into a polynomial
-                let inv_twiddles = twiddle_map
-                    .entry(cycle_length)
-                    .or_insert_with(|| fft::get_inv_twiddles::<Self::BaseField>(cycle_length));
-                fft::interpolate_poly(&mut column, inv_twiddles);
-                column
-            })
-            .collect()
+        build_periodic_column_polys(self.get_periodic_column_values(), self.trace_length())
+    }
+
+    /// Returns values for all auxiliary periodic columns used in the computation.
+    ///
+    /// Auxiliary periodic columns are similar to the columns returned from the
+    /// [get_periodic_column_values()](Air::get_periodic_column_values) method, but their values
+    /// may depend on random elements sent by the verifier after the main trace segment has been
+    /// committed to (e.g., powers of a random element), and are defined over the field `E` in
+    /// which auxiliary trace segments are defined. The values of these columns at a given step
+    /// of the computation will be supplied to the
+    /// [evaluate_aux_transition_with_periodic_values()](Air::evaluate_aux_transition_with_periodic_values)
+    /// method via the `aux_periodic_values` parameter.
+    ///
+    /// The default implementation of this method returns an empty vector. Number of values for
+    /// each auxiliary periodic column must be a power of two.
+    #[allow(unused_variables)]
+    fn get_aux_periodic_column_values<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        aux_rand_elements: &AuxTraceRandElements<E>,
+    ) -> Vec<Vec<E>> {
+        Vec::new()
+    }
+
+    /// Returns polynomials for all auxiliary periodic columns.
+    ///
+    /// These polynomials are interpolated from the values returned from the
+    /// [get_aux_periodic_column_values()](Air::get_aux_periodic_column_values) method.
+    fn get_aux_periodic_column_polys<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        aux_rand_elements: &AuxTraceRandElements<E>,
+    ) -> Vec<Vec<E>> {
+        build_periodic_column_polys(
+            self.get_aux_periodic_column_values(aux_rand_elements),
+            self.trace_length(),
+        )
     }
 
     /// Groups transition constraints together by their degree.
@@ -398,6 +484,15 @@ pub trait Air: Send + Sync {
         self.context().trace_info.length()
     }
 
+    /// Returns length of the execution trace before padding for an instance of the computation
+    /// described by this AIR.
+    ///
+    /// This is the same as [trace_length()](Air::trace_length) unless the trace was padded as
+    /// specified by [trace_padding()](Air::trace_padding).
+    fn original_trace_length(&self) -> usize {
+        self.context().trace_info.original_length()
+    }
+
     /// Returns a description of how execution trace columns are arranged into segments for
     /// an instance of a computation described by this AIR.
     fn trace_layout(&self) -> &TraceLayout {
@@ -560,3 +655,94 @@ pub trait Air: Send + Sync {
         })
     }
 }
+
+// DOMAIN SEPARATION TAG
+// ================================================================================================
+
+/// Writes the domain separation tag of the specified AIR (see [Air::ID]) into the target,
+/// prefixed by the length of the tag encoded as a single byte.
+///
+/// The length of the tag is checked at compile time, and thus, an AIR with a tag longer than 255
+/// bytes cannot be used with this function:
+///
+/// ```compile_fail
+/// # use winter_air::{write_air_id, Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo};
+/// # use math::{fields::f128::BaseElement, FieldElement};
+/// struct LongIdAir(AirContext<BaseElement>);
+///
+/// impl Air for LongIdAir {
+///     type BaseField = BaseElement;
+///     type PublicInputs = ();
+///     const ID: &'static [u8] = &[0; 256];
+///     # fn new(_: TraceInfo, _: (), _: ProofOptions) -> Self { unimplemented!() }
+///     # fn context(&self) -> &AirContext<BaseElement> { &self.0 }
+///     # fn evaluate_transition<E: FieldElement<BaseField = BaseElement>>(
+///     #     &self, _: &EvaluationFrame<E>, _: &[E], _: &mut [E]) {}
+///     # fn get_assertions(&self) -> Vec<Assertion<BaseElement>> { Vec::new() }
+/// }
+///
+/// let mut seed = Vec::new();
+/// write_air_id::<LongIdAir, _>(&mut seed);
+/// ```
+pub fn write_air_id<A: Air, W: ByteWriter>(target: &mut W) {
+    target.write_u8(AirIdLength::<A>::VALUE);
+    target.write_u8_slice(A::ID);
+}
+
+/// Length of the domain separation tag of an AIR; evaluating [AirIdLength::VALUE] fails at compile
+/// time if the tag is longer than 255 bytes.
+struct AirIdLength<A>(PhantomData<A>);
+
+impl<A: Air> AirIdLength<A> {
+    const VALUE: u8 = {
+        assert!(
+            A::ID.len() <= u8::MAX as usize,
+            "AIR domain separation tag cannot be longer than 255 bytes"
+        );
+        A::ID.len() as u8
+    };
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Interpolates values of the provided periodic columns into polynomials.
+fn build_periodic_column_polys<E: FieldElement>(
+    columns: Vec<Vec<E>>,
+    trace_length: usize,
+) -> Vec<Vec<E>> {
+    // cache inverse twiddles for each cycle length so that we don't have to re-build them
+    // for columns with identical cycle lengths
+    let mut twiddle_map = BTreeMap::new();
+    // iterate over all periodic columns and convert column values into polynomials
+    columns
+        .into_iter()
+        .map(|mut column| {
+            let cycle_length = column.len();
+            assert!(
+                cycle_length >= MIN_CYCLE_LENGTH,
+                "number of values in a periodic column must be at least {}, but was {}",
+                MIN_CYCLE_LENGTH,
+                cycle_length
+            );
+            assert!(
+                cycle_length.is_power_of_two(),
+                "number of values in a periodic column must be a power of two, but was {}",
+                cycle_length
+            );
+            assert!(
+                cycle_length <= trace_length,
+                "number of values in a periodic column cannot exceed trace length {}, but was {}",
+                trace_length,
+                cycle_length
+            );
+
+            // get twiddles for interpolation and interpolate values into a polynomial
+            let inv_twiddles = twiddle_map
+                .entry(cycle_length)
+                .or_insert_with(|| fft::get_inv_twiddles::<E::BaseField>(cycle_length));
+            fft::interpolate_poly(&mut column, inv_twiddles);
+            column
+        })
+        .collect()
+}
@@ -0,0 +1,46 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+// TRACE PADDING
+// ================================================================================================
+/// Policy for padding execution traces whose length is not a power of two.
+///
+/// The policy is declared by an AIR via [Air::trace_padding()](crate::Air::trace_padding). When
+/// the prover receives an execution trace whose length is not a power of two, it appends rows to
+/// the main segment of the trace until its length reaches the next power of two. The padding rows
+/// are built as specified by the policy, and it is up to the AIR to make sure that constraints
+/// are satisfied over the padding rows as well.
+///
+/// The length of the trace before padding is exposed to the AIR via
+/// [Air::original_trace_length()](crate::Air::original_trace_length); thus, assertions against
+/// the last step of the original trace can be placed at `original_trace_length() - 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TracePadding {
+    /// Traces must have a length which is a power of two; no padding is applied.
+    None,
+    /// Every padding row is a copy of the last row of the original trace.
+    RepeatLastRow,
+    /// Every padding row is computed from the previous row via
+    /// [Air::apply_padding_transition()](crate::Air::apply_padding_transition).
+    ///
+    /// This is useful when the transition function of a computation can simply be executed for
+    /// a few more steps.
+    Transition,
+    /// All values in padding rows are set to zeros, and the column at the specified index is a
+    /// selector column: the prover sets its values to ONE in all rows of the original trace and
+    /// to ZERO in all padding rows.
+    ///
+    /// The selector can be used to disable constraints over the padding rows.
+    ZeroFill {
+        /// Index of the selector column in the main segment of the trace.
+        selector_column: usize,
+    },
+}
+
+impl Default for TracePadding {
+    fn default() -> Self {
+        Self::None
+    }
+}
@@ -0,0 +1,30 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::fmt::Debug;
+
+// SEGMENT PUBLIC INPUTS
+// ================================================================================================
+/// Public inputs of a computation which can be proven in segments.
+///
+/// A long computation can be split into segments such that each segment is proven separately
+/// by a standard prover (see [ContinuationProof](crate::proof::ContinuationProof)). For this to
+/// be sound, public inputs of each segment must expose the state of the computation at the start
+/// and at the end of the segment, and the AIR of the computation must bind these states to the
+/// first and the last rows of the segment's execution trace (e.g., via assertions).
+///
+/// A continuation is valid if the final state of every segment is equal to the initial state of
+/// the next segment. When the full state of the computation is large, the AIR can expose a
+/// commitment to the state (e.g., a hash computed in the trace) instead of the state itself.
+pub trait SegmentPublicInputs {
+    /// Boundary state of a segment (or a commitment to it).
+    type State: Eq + Debug;
+
+    /// Returns the state of the computation at the first step of the segment.
+    fn initial_state(&self) -> Self::State;
+
+    /// Returns the state of the computation at the last step of the segment.
+    fn final_state(&self) -> Self::State;
+}
@@ -4,13 +4,16 @@
 // LICENSE file in the root directory of this source tree.
 
 use super::{
-    Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
-    TransitionConstraintDegree,
+    Air, AirContext, Assertion, ConstraintDivisor, EvaluationFrame, ProofOptions, TraceInfo,
+    TransitionConstraintDegree, TransitionConstraints, TransitionDivisor,
 };
-use crate::{AuxTraceRandElements, FieldExtension, HashFunction};
+use crate::{proof::Context, AuxTraceRandElements, FieldExtension, HashFunction, TracePadding};
 use crypto::{hashers::Blake3_256, RandomCoin};
 use math::{fields::f128::BaseElement, get_power_series, log2, polynom, FieldElement, StarkField};
-use utils::collections::{BTreeMap, Vec};
+use utils::{
+    collections::{BTreeMap, Vec},
+    Deserializable, Serializable, SliceReader,
+};
 
 // PERIODIC COLUMNS
 // ================================================================================================
@@ -59,7 +62,109 @@ fn get_periodic_column_polys_num_values_not_power_of_two() {
 // TRANSITION CONSTRAINTS
 // ================================================================================================
 
-// TODO
+#[test]
+fn get_transition_constraints_with_divisors() {
+    let trace_length = 16;
+    let degrees = vec![
+        TransitionConstraintDegree::new(2),
+        TransitionConstraintDegree::new(2),
+        TransitionConstraintDegree::new(1),
+        TransitionConstraintDegree::new(1),
+    ];
+    let periodic_divisor = TransitionDivisor::periodic(4, 3).with_num_exemptions(1);
+    let context = build_context_with_degrees(trace_length, degrees, 8)
+        .set_main_transition_divisor([1, 3], periodic_divisor.clone());
+    assert_eq!(2, context.ce_blowup_factor);
+    assert_eq!(&periodic_divisor, context.main_transition_divisor(3));
+    assert_eq!(
+        &TransitionDivisor::new(1),
+        context.main_transition_divisor(2)
+    );
+
+    let mut prng = build_prng();
+    let coefficients = (0..4)
+        .map(|_| prng.draw_pair::<BaseElement>().unwrap())
+        .collect::<Vec<_>>();
+    let constraints = TransitionConstraints::new(&context, &coefficients);
+
+    // the default divisor goes first, followed by the periodic divisor
+    assert_eq!(
+        &[
+            ConstraintDivisor::from_transition(trace_length, 1),
+            ConstraintDivisor::from_periodic_transition(trace_length, 4, 3, 1)
+        ],
+        constraints.divisors()
+    );
+    assert_eq!(&[0, 1, 0, 1], constraints.main_constraint_divisor_indexes());
+
+    // constraints are grouped by divisor first, and then by degree
+    let groups = constraints.main_constraints();
+    assert_eq!(4, groups.len());
+    let expected = [(0, vec![2]), (0, vec![0]), (1, vec![3]), (1, vec![1])];
+    for (group, (divisor_idx, indexes)) in groups.iter().zip(expected) {
+        assert_eq!(divisor_idx, group.divisor_index());
+        assert_eq!(indexes, group.indexes());
+    }
+
+    // each group is divided by its own divisor when combining evaluations
+    let x = prng.draw::<BaseElement>().unwrap();
+    let evaluations = prng.draw_pair::<BaseElement>().unwrap();
+    let evaluations = [evaluations.0, evaluations.1, evaluations.1, evaluations.0];
+    let expected = groups.iter().fold(BaseElement::ZERO, |acc, group| {
+        let divisor = &constraints.divisors()[group.divisor_index()];
+        acc + group.merge_evaluations::<BaseElement, BaseElement>(&evaluations, x)
+            / divisor.evaluate_at(x)
+    });
+    assert_eq!(
+        expected,
+        constraints.combine_evaluations(&evaluations, &[], x)
+    );
+}
+
+#[test]
+fn get_transition_constraints_without_default_divisor() {
+    let degrees = vec![TransitionConstraintDegree::new(2)];
+    let context = build_context_with_degrees(16, degrees, 8)
+        .set_main_transition_divisor(0..1, TransitionDivisor::periodic(8, 0));
+
+    // divisors which do not apply to any constraints are omitted
+    let constraints = TransitionConstraints::new(&context, &[(BaseElement::ONE, BaseElement::ONE)]);
+    assert_eq!(
+        &[ConstraintDivisor::from_periodic_transition(16, 8, 0, 0)],
+        constraints.divisors()
+    );
+    assert_eq!(&[0], constraints.main_constraint_divisor_indexes());
+}
+
+#[test]
+fn transition_divisor_ce_blowup_factor() {
+    // with the default divisor, degree 5 constraints require blowup factor of 4
+    let degrees = vec![TransitionConstraintDegree::new(5)];
+    let context = build_context_with_degrees(16, degrees, 8);
+    assert_eq!(4, context.ce_blowup_factor);
+
+    // but with a divisor of degree 1 the degree of the quotient is 74, and thus, the blowup
+    // factor needs to be 8
+    let context = context.set_main_transition_divisor([0], TransitionDivisor::periodic(16, 0));
+    assert_eq!(8, context.ce_blowup_factor);
+    assert_eq!(1, context.num_transition_exemptions());
+}
+
+#[test]
+#[should_panic(expected = "blowup factor too small; expected at least 8, but was 4")]
+fn transition_divisor_blowup_factor_too_small() {
+    let degrees = vec![TransitionConstraintDegree::new(5)];
+    let _ = build_context_with_degrees(16, degrees, 4)
+        .set_main_transition_divisor([0], TransitionDivisor::periodic(16, 0));
+}
+
+#[test]
+#[should_panic(expected = "main transition constraint index 1 is out of bounds")]
+fn transition_divisor_invalid_constraint_index() {
+    let degrees = vec![TransitionConstraintDegree::new(2)];
+    let _ = build_context_with_degrees(16, degrees, 8)
+        .set_main_transition_divisor([1], TransitionDivisor::periodic(4, 0));
+}
 
 // BOUNDARY CONSTRAINTS
 // ================================================================================================
@@ -217,6 +322,60 @@ fn get_boundary_constraints() {
     assert_eq!(expected_cc[&7], constraint.cc().clone());
 }
 
+// TRACE PADDING
+// ================================================================================================
+
+#[test]
+fn trace_info_padded_length() {
+    let trace_info = TraceInfo::new(4, 16);
+    assert_eq!(16, trace_info.length());
+    assert_eq!(16, trace_info.original_length());
+    assert!(!trace_info.is_padded());
+
+    let trace_info = TraceInfo::new(4, 100);
+    assert_eq!(128, trace_info.length());
+    assert_eq!(100, trace_info.original_length());
+    assert!(trace_info.is_padded());
+
+    let air = MockAir::with_periodic_columns(vec![], 100);
+    assert_eq!(128, air.trace_length());
+    assert_eq!(100, air.original_trace_length());
+    assert_eq!(TracePadding::None, air.trace_padding());
+}
+
+#[test]
+fn context_padded_trace_length_serialization() {
+    let options = build_context::<BaseElement>(8, 4, 1).options;
+    for length in [8, 9, 64, 100, 1023] {
+        let trace_info = TraceInfo::new(4, length);
+        let context = Context::new::<BaseElement>(&trace_info, options.clone());
+        assert_eq!(length, context.original_trace_length());
+
+        let bytes = context.to_bytes();
+        let result = Context::read_from(&mut SliceReader::new(&bytes)).unwrap();
+        assert_eq!(context, result);
+        assert_eq!(trace_info, result.get_trace_info());
+    }
+
+    // contexts of traces which were not padded are serialized in the same way as before
+    let trace_info = TraceInfo::new(4, 64);
+    let unpadded = Context::new::<BaseElement>(&trace_info, options.clone()).to_bytes();
+    let trace_info = TraceInfo::new(4, 63);
+    let padded = Context::new::<BaseElement>(&trace_info, options).to_bytes();
+    assert!(Context::read_from(&mut SliceReader::new(&padded)).is_ok());
+    assert_eq!(unpadded.len() + 8, padded.len());
+
+    // original length must be consistent with the padded length
+    // (the original length follows the version byte, the trace layout, and the length byte)
+    let offset = 2 + trace_info.layout().to_bytes().len();
+    let invalid_lengths: [u64; 3] = [64, 32, 7];
+    for length in invalid_lengths {
+        let mut bytes = padded.clone();
+        bytes[offset..offset + 8].copy_from_slice(&length.to_le_bytes());
+        assert!(Context::read_from(&mut SliceReader::new(&bytes)).is_err());
+    }
+}
+
 // MOCK AIR
 // ================================================================================================
 
@@ -273,7 +432,11 @@ impl Air for MockAir {
 
     fn new(trace_info: TraceInfo, _pub_inputs: (), _options: ProofOptions) -> Self {
         let num_assertions = trace_info.meta()[0] as usize;
-        let context = build_context(trace_info.length(), trace_info.width(), num_assertions);
+        let context = build_context(
+            trace_info.original_length(),
+            trace_info.width(),
+            num_assertions,
+        );
         MockAir {
             context,
             assertions: Vec::new(),
@@ -324,6 +487,24 @@ pub fn build_context<B: StarkField>(
     AirContext::new(trace_info, t_degrees, num_assertions, options)
 }
 
+pub fn build_context_with_degrees(
+    trace_length: usize,
+    t_degrees: Vec<TransitionConstraintDegree>,
+    blowup_factor: usize,
+) -> AirContext<BaseElement> {
+    let options = ProofOptions::new(
+        32,
+        blowup_factor,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        256,
+    );
+    let trace_info = TraceInfo::new(4, trace_length);
+    AirContext::new(trace_info, t_degrees, 1, options)
+}
+
 pub fn build_prng() -> RandomCoin<BaseElement, Blake3_256<BaseElement>> {
     RandomCoin::new(&[0; 32])
 }
@@ -8,6 +8,9 @@ use utils::{
     DeserializationError, Serializable,
 };
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 // CONSTANTS
 // ================================================================================================
 
@@ -22,10 +25,31 @@ const NUM_AUX_SEGMENTS: usize = 1;
 /// specifies the number of columns for all trace segments. Currently, a trace can consist of at
 /// most two segments. Metadata is just a vector of bytes and can store any values up to 64KB in
 /// size.
+///
+/// The length of an execution trace does not need to be a power of two. In such a case, the trace
+/// is padded to the next power of two according to the [TracePadding](crate::TracePadding) policy
+/// of the AIR; [length()](TraceInfo::length) returns the padded length, while the length of the
+/// trace before padding is available via [original_length()](TraceInfo::original_length).
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawTraceInfo"))]
 pub struct TraceInfo {
     layout: TraceLayout,
     length: usize,
+    original_length: usize,
+    #[cfg_attr(feature = "serde", serde(with = "utils::serde_hex"))]
+    meta: Vec<u8>,
+}
+
+/// Unvalidated [TraceInfo] used when deserializing via serde; the info is validated when it is
+/// converted into [TraceInfo].
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawTraceInfo {
+    layout: TraceLayout,
+    length: usize,
+    original_length: usize,
+    #[serde(with = "utils::serde_hex")]
     meta: Vec<u8>,
 }
 
@@ -49,7 +73,7 @@ impl TraceInfo {
     /// # Panics
     /// Panics if:
     /// * Trace width is zero or greater than 255.
-    /// * Trace length is smaller than 8 or is not a power of two.
+    /// * Trace length is smaller than 8.
     pub fn new(width: usize, length: usize) -> Self {
         Self::with_meta(width, length, vec![])
     }
@@ -61,7 +85,7 @@ impl TraceInfo {
     /// # Panics
     /// Panics if:
     /// * Trace width is zero or greater than 255.
-    /// * Trace length is smaller than 8 or is not a power of two.
+    /// * Trace length is smaller than 8.
     /// * Length of `meta` is greater than 65535;
     pub fn with_meta(width: usize, length: usize, meta: Vec<u8>) -> Self {
         assert!(width > 0, "trace width must be greater than 0");
@@ -71,11 +95,14 @@ impl TraceInfo {
 
     /// Creates a new [TraceInfo] from the specified trace segment widths, length, and metadata.
     ///
+    /// If `length` is not a power of two, the trace described by this trace info is assumed to
+    /// be padded to the next power of two.
+    ///
     /// # Panics
     /// Panics if:
     /// * The width of the first trace segment is zero.
     /// * Total width of all trace segments is greater than 255.
-    /// * Trace length is smaller than 8 or is not a power of two.
+    /// * Trace length is smaller than 8.
     pub fn new_multi_segment(layout: TraceLayout, length: usize, meta: Vec<u8>) -> Self {
         assert!(
             length >= Self::MIN_TRACE_LENGTH,
@@ -83,11 +110,6 @@ impl TraceInfo {
             Self::MIN_TRACE_LENGTH,
             length
         );
-        assert!(
-            length.is_power_of_two(),
-            "trace length must be a power of two, but was {}",
-            length
-        );
         assert!(
             meta.len() <= Self::MAX_META_LENGTH,
             "number of metadata bytes cannot be greater than {}, but was {}",
@@ -96,7 +118,8 @@ impl TraceInfo {
         );
         TraceInfo {
             layout,
-            length,
+            length: length.next_power_of_two(),
+            original_length: length,
             meta,
         }
     }
@@ -120,11 +143,24 @@ impl TraceInfo {
 
     /// Returns execution trace length.
     ///
-    /// The length is guaranteed to be a power of two.
+    /// The length is guaranteed to be a power of two. If the trace was padded, this is the length
+    /// of the trace after padding.
     pub fn length(&self) -> usize {
         self.length
     }
 
+    /// Returns the length of the execution trace before padding.
+    ///
+    /// For traces which do not need padding, this is the same as [length()](TraceInfo::length).
+    pub fn original_length(&self) -> usize {
+        self.original_length
+    }
+
+    /// Returns true if the execution trace needs to be padded to a power of two.
+    pub fn is_padded(&self) -> bool {
+        self.original_length != self.length
+    }
+
     /// Returns execution trace metadata.
     pub fn meta(&self) -> &[u8] {
         &self.meta
@@ -134,6 +170,55 @@ impl TraceInfo {
     pub fn is_multi_segment(&self) -> bool {
         self.layout.num_aux_segments > 0
     }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an error if `length` is not the length of an execution trace with
+    /// `original_length` rows after the trace was padded to the next power of two, or if the
+    /// metadata is too long.
+    pub(crate) fn validate(
+        length: usize,
+        original_length: usize,
+        meta: &[u8],
+    ) -> Result<(), DeserializationError> {
+        if original_length < Self::MIN_TRACE_LENGTH {
+            return Err(DeserializationError::InvalidValue(format!(
+                "trace length must be at least {}, but was {}",
+                Self::MIN_TRACE_LENGTH,
+                original_length
+            )));
+        }
+        if original_length.checked_next_power_of_two() != Some(length) {
+            return Err(DeserializationError::InvalidValue(format!(
+                "original trace length {} is not valid for padded trace length {}",
+                original_length, length
+            )));
+        }
+        if meta.len() > Self::MAX_META_LENGTH {
+            return Err(DeserializationError::InvalidValue(format!(
+                "number of metadata bytes cannot be greater than {}, but was {}",
+                Self::MAX_META_LENGTH,
+                meta.len()
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawTraceInfo> for TraceInfo {
+    type Error = DeserializationError;
+
+    fn try_from(raw: RawTraceInfo) -> Result<Self, Self::Error> {
+        TraceInfo::validate(raw.length, raw.original_length, &raw.meta)?;
+        Ok(TraceInfo {
+            layout: raw.layout,
+            length: raw.length,
+            original_length: raw.original_length,
+            meta: raw.meta,
+        })
+    }
 }
 
 // TRACE LAYOUT
@@ -155,6 +240,8 @@ impl TraceInfo {
 /// segment. For example, an auxiliary segment may contain just one column, but may require many
 /// random elements.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawTraceLayout"))]
 pub struct TraceLayout {
     main_segment_width: usize,
     aux_segment_widths: [usize; NUM_AUX_SEGMENTS],
@@ -162,6 +249,17 @@ pub struct TraceLayout {
     num_aux_segments: usize,
 }
 
+/// Unvalidated [TraceLayout] used when deserializing via serde; the layout is validated when it
+/// is converted into [TraceLayout].
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawTraceLayout {
+    main_segment_width: usize,
+    aux_segment_widths: [usize; NUM_AUX_SEGMENTS],
+    aux_segment_rands: [usize; NUM_AUX_SEGMENTS],
+    num_aux_segments: usize,
+}
+
 impl TraceLayout {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
@@ -267,49 +365,29 @@ impl TraceLayout {
         // TODO: panic if segment_idx is not within num_aux_segments
         self.aux_segment_rands[segment_idx]
     }
-}
 
-impl Serializable for TraceLayout {
-    /// Serializes `self` and writes the resulting bytes into the `target`.
-    fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        target.write_u8(self.main_segment_width as u8);
-        for &w in self.aux_segment_widths.iter() {
-            debug_assert!(
-                w <= u8::MAX as usize,
-                "aux segment width does not fit into u8 value"
-            );
-            target.write_u8(w as u8);
-        }
-        for &rc in self.aux_segment_rands.iter() {
-            debug_assert!(
-                rc <= u8::MAX as usize,
-                "aux segment random element count does not fit into u8 value"
-            );
-            target.write_u8(rc as u8);
-        }
-    }
-}
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
 
-impl Deserializable for TraceLayout {
-    /// Reads [TraceLayout] from the specified `source` and returns the result.
+    /// Returns a new [TraceLayout] instantiated with the provided info.
     ///
     /// # Errors
-    /// Returns an error of a valid [TraceLayout] struct could not be read from the specified
-    /// `source`.
-    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let main_width = source.read_u8()? as usize;
+    /// Returns an error under the same conditions under which [new()](TraceLayout::new) panics.
+    fn from_raw_parts(
+        main_width: usize,
+        aux_widths: [usize; NUM_AUX_SEGMENTS],
+        aux_rands: [usize; NUM_AUX_SEGMENTS],
+    ) -> Result<Self, DeserializationError> {
         if main_width == 0 {
             return Err(DeserializationError::InvalidValue(
                 "main trace segment width must be greater than zero".to_string(),
             ));
         }
 
-        // read and validate auxiliary trace segment widths
+        // validate auxiliary trace segment widths
         let mut was_zero_width = false;
-        let mut aux_widths = [0; NUM_AUX_SEGMENTS];
-        for width in aux_widths.iter_mut() {
-            *width = source.read_u8()? as usize;
-            if *width != 0 {
+        for &width in aux_widths.iter() {
+            if width != 0 {
                 if was_zero_width {
                     return Err(DeserializationError::InvalidValue(
                         "a non-empty trace segment cannot follow an empty segment".to_string(),
@@ -320,8 +398,11 @@ impl Deserializable for TraceLayout {
             }
         }
 
-        let full_trace_width = main_width + aux_widths.iter().sum::<usize>();
-        if full_trace_width >= TraceInfo::MAX_TRACE_WIDTH {
+        let full_trace_width = aux_widths
+            .iter()
+            .try_fold(main_width, |acc, &width| acc.checked_add(width))
+            .unwrap_or(usize::MAX);
+        if full_trace_width > TraceInfo::MAX_TRACE_WIDTH {
             return Err(DeserializationError::InvalidValue(format!(
                 "full trace width cannot be greater than {}, but was {}",
                 TraceInfo::MAX_TRACE_WIDTH,
@@ -329,24 +410,22 @@ impl Deserializable for TraceLayout {
             )));
         }
 
-        // read and validate number of random elements for each auxiliary trace segment
-        let mut aux_rands = [0; NUM_AUX_SEGMENTS];
-        for (num_rand_elements, &width) in aux_rands.iter_mut().zip(aux_widths.iter()) {
-            *num_rand_elements = source.read_u8()? as usize;
-            if width == 0 && *num_rand_elements != 0 {
+        // validate number of random elements for each auxiliary trace segment
+        for (&num_rand_elements, &width) in aux_rands.iter().zip(aux_widths.iter()) {
+            if width == 0 && num_rand_elements != 0 {
                 return Err(DeserializationError::InvalidValue(
                     "an empty trace segment cannot require random elements".to_string(),
                 ));
-            } else if width != 0 && *num_rand_elements == 0 {
+            } else if width != 0 && num_rand_elements == 0 {
                 return Err(DeserializationError::InvalidValue(
                     "a non-empty trace segment must require at least one random element"
                         .to_string(),
                 ));
-            } else if *num_rand_elements > TraceInfo::MAX_RAND_SEGMENT_ELEMENTS {
+            } else if num_rand_elements > TraceInfo::MAX_RAND_SEGMENT_ELEMENTS {
                 return Err(DeserializationError::InvalidValue(format!(
                     "number of random elements required by a segment cannot exceed {}, but was {}",
                     TraceInfo::MAX_RAND_SEGMENT_ELEMENTS,
-                    *num_rand_elements
+                    num_rand_elements
                 )));
             }
         }
@@ -354,3 +433,65 @@ impl Deserializable for TraceLayout {
         Ok(TraceLayout::new(main_width, aux_widths, aux_rands))
     }
 }
+
+impl Serializable for TraceLayout {
+    /// Serializes `self` and writes the resulting bytes into the `target`.
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.main_segment_width as u8);
+        for &w in self.aux_segment_widths.iter() {
+            debug_assert!(
+                w <= u8::MAX as usize,
+                "aux segment width does not fit into u8 value"
+            );
+            target.write_u8(w as u8);
+        }
+        for &rc in self.aux_segment_rands.iter() {
+            debug_assert!(
+                rc <= u8::MAX as usize,
+                "aux segment random element count does not fit into u8 value"
+            );
+            target.write_u8(rc as u8);
+        }
+    }
+}
+
+impl Deserializable for TraceLayout {
+    /// Reads [TraceLayout] from the specified `source` and returns the result.
+    ///
+    /// # Errors
+    /// Returns an error of a valid [TraceLayout] struct could not be read from the specified
+    /// `source`.
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let main_width = source.read_u8()? as usize;
+        let mut aux_widths = [0; NUM_AUX_SEGMENTS];
+        for width in aux_widths.iter_mut() {
+            *width = source.read_u8()? as usize;
+        }
+        let mut aux_rands = [0; NUM_AUX_SEGMENTS];
+        for num_rand_elements in aux_rands.iter_mut() {
+            *num_rand_elements = source.read_u8()? as usize;
+        }
+
+        TraceLayout::from_raw_parts(main_width, aux_widths, aux_rands)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawTraceLayout> for TraceLayout {
+    type Error = DeserializationError;
+
+    fn try_from(raw: RawTraceLayout) -> Result<Self, Self::Error> {
+        let layout = TraceLayout::from_raw_parts(
+            raw.main_segment_width,
+            raw.aux_segment_widths,
+            raw.aux_segment_rands,
+        )?;
+        if layout.num_aux_segments != raw.num_aux_segments {
+            return Err(DeserializationError::InvalidValue(format!(
+                "expected {} auxiliary trace segments, but was {}",
+                layout.num_aux_segments, raw.num_aux_segments
+            )));
+        }
+        Ok(layout)
+    }
+}
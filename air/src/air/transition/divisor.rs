@@ -0,0 +1,141 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::ConstraintDivisor;
+use math::StarkField;
+
+// TRANSITION DIVISOR
+// ================================================================================================
+/// Describes the subset of execution trace steps on which a group of transition constraints
+/// must hold.
+///
+/// By default, transition constraints must hold on all steps of the execution trace except for
+/// the last few (see [AirContext::set_num_transition_exemptions()](crate::AirContext::set_num_transition_exemptions)).
+/// A transition divisor can be used to restrict a group of constraints to every `cycle_length`-th
+/// step starting at `offset` (e.g., to the last step of every hash round cycle). This avoids
+/// multiplying such constraints by periodic selector columns, and thus, does not increase
+/// constraint degrees.
+///
+/// Transition divisors are attached to constraints via
+/// [AirContext::set_main_transition_divisor()](crate::AirContext::set_main_transition_divisor)
+/// and [AirContext::set_aux_transition_divisor()](crate::AirContext::set_aux_transition_divisor).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransitionDivisor {
+    cycle_length: usize,
+    offset: usize,
+    num_exemptions: usize,
+}
+
+impl TransitionDivisor {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a divisor for constraints which must hold on all steps of an execution trace
+    /// except for the last `num_exemptions` steps.
+    ///
+    /// # Panics
+    /// Panics if `num_exemptions` is zero.
+    pub fn new(num_exemptions: usize) -> Self {
+        assert!(
+            num_exemptions > 0,
+            "number of transition exemptions must be greater than zero"
+        );
+        TransitionDivisor {
+            cycle_length: 1,
+            offset: 0,
+            num_exemptions,
+        }
+    }
+
+    /// Returns a divisor for constraints which must hold on steps `offset`,
+    /// `offset + cycle_length`, `offset + 2 * cycle_length` etc.
+    ///
+    /// The returned divisor has no exemptions; thus, if `offset` is the last step of a cycle, the
+    /// constraints will be enforced on the transition from the last step of the trace to the
+    /// first one, unless an exemption is added via [TransitionDivisor::with_num_exemptions()].
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `cycle_length` is not a power of two or is smaller than 2.
+    /// * `offset` is greater than or equal to `cycle_length`.
+    pub fn periodic(cycle_length: usize, offset: usize) -> Self {
+        assert!(
+            cycle_length >= 2 && cycle_length.is_power_of_two(),
+            "cycle length must be a power of two greater than one, but was {}",
+            cycle_length
+        );
+        assert!(
+            offset < cycle_length,
+            "cycle offset must be smaller than {}, but was {}",
+            cycle_length,
+            offset
+        );
+        TransitionDivisor {
+            cycle_length,
+            offset,
+            num_exemptions: 0,
+        }
+    }
+
+    /// Returns this divisor with the last `num_exemptions` steps of the subset described by it
+    /// excluded from the subset.
+    pub fn with_num_exemptions(mut self, num_exemptions: usize) -> Self {
+        self.num_exemptions = num_exemptions;
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of steps after which the subset of steps described by this divisor
+    /// repeats.
+    pub fn cycle_length(&self) -> usize {
+        self.cycle_length
+    }
+
+    /// Returns the offset of the first step of this subset within a cycle.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the number of steps at the end of the subset on which the constraints do not
+    /// need to hold.
+    pub fn num_exemptions(&self) -> usize {
+        self.num_exemptions
+    }
+
+    /// Returns the number of steps in an execution trace of the specified length on which
+    /// constraints with this divisor must hold.
+    pub fn num_steps(&self, trace_length: usize) -> usize {
+        trace_length / self.cycle_length - self.num_exemptions
+    }
+
+    /// Returns true if constraints with this divisor must hold on the specified step of an
+    /// execution trace of the specified length.
+    pub fn is_active_at(&self, step: usize, trace_length: usize) -> bool {
+        step % self.cycle_length == self.offset
+            && step / self.cycle_length < self.num_steps(trace_length)
+    }
+
+    /// Returns the degree of the divisor polynomial for an execution trace of the specified
+    /// length.
+    pub fn degree(&self, trace_length: usize) -> usize {
+        self.num_steps(trace_length)
+    }
+
+    /// Builds the constraint divisor described by this spec for an execution trace of the
+    /// specified length.
+    pub fn to_constraint_divisor<B: StarkField>(
+        &self,
+        trace_length: usize,
+    ) -> ConstraintDivisor<B> {
+        ConstraintDivisor::from_periodic_transition(
+            trace_length,
+            self.cycle_length,
+            self.offset,
+            self.num_exemptions,
+        )
+    }
+}
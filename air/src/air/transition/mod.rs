@@ -11,6 +11,9 @@ pub use frame::EvaluationFrame;
 mod degree;
 pub use degree::TransitionConstraintDegree;
 
+mod divisor;
+pub use divisor::TransitionDivisor;
+
 // CONSTANTS
 // ================================================================================================
 
@@ -23,15 +26,17 @@ const MIN_CYCLE_LENGTH: usize = 2;
 /// This metadata includes:
 /// - List of transition constraint degrees for the main trace segment, as well as for auxiliary
 ///   trace segments (if any).
-/// - Groupings of constraints by their degree, separately for the main trace segment and for
-///   auxiliary tace segment.
-/// - Divisor of transition constraints for a computation.
+/// - Groupings of constraints by their divisor and degree, separately for the main trace segment
+///   and for auxiliary tace segment.
+/// - Divisors of transition constraints for a computation.
 pub struct TransitionConstraints<E: FieldElement> {
     main_constraints: Vec<TransitionConstraintGroup<E>>,
     main_constraint_degrees: Vec<TransitionConstraintDegree>,
+    main_divisor_indexes: Vec<usize>,
     aux_constraints: Vec<TransitionConstraintGroup<E>>,
     aux_constraint_degrees: Vec<TransitionConstraintDegree>,
-    divisor: ConstraintDivisor<E::BaseField>,
+    aux_divisor_indexes: Vec<usize>,
+    divisors: Vec<ConstraintDivisor<E::BaseField>>,
 }
 
 impl<E: FieldElement> TransitionConstraints<E> {
@@ -50,39 +55,62 @@ impl<E: FieldElement> TransitionConstraints<E> {
             "number of transition constraints must match the number of composition coefficient tuples"
         );
 
-        // build constraint divisor; the same divisor applies to all transition constraints
-        let divisor = ConstraintDivisor::from_transition(
-            context.trace_len(),
-            context.num_transition_exemptions(),
-        );
-
-        // group constraints by their degree, separately for constraints against main and auxiliary
-        // trace segments
+        // build constraint divisors; only the divisors which apply to at least one constraint
+        // are built, and constraint divisor indexes are remapped to point into this list
+        let mut used_divisors = context
+            .main_transition_divisor_indexes
+            .iter()
+            .chain(context.aux_transition_divisor_indexes.iter())
+            .copied()
+            .collect::<Vec<_>>();
+        used_divisors.sort_unstable();
+        used_divisors.dedup();
+        let divisors = used_divisors
+            .iter()
+            .map(|&idx| {
+                context.transition_divisors()[idx].to_constraint_divisor(context.trace_len())
+            })
+            .collect::<Vec<_>>();
+        let remap_divisor_indexes = |indexes: &[usize]| {
+            indexes
+                .iter()
+                .map(|idx| used_divisors.binary_search(idx).expect("divisor not found"))
+                .collect::<Vec<_>>()
+        };
+
+        // group constraints by their divisor and degree, separately for constraints against main
+        // and auxiliary trace segments
 
         let (main_constraint_coefficients, aux_constraint_coefficients) =
             composition_coefficients.split_at(context.main_transition_constraint_degrees.len());
 
         let main_constraint_degrees = context.main_transition_constraint_degrees.clone();
+        let main_divisor_indexes = remap_divisor_indexes(&context.main_transition_divisor_indexes);
         let main_constraints = group_constraints(
             &main_constraint_degrees,
+            &main_divisor_indexes,
             context,
             main_constraint_coefficients,
-            divisor.degree(),
+            &divisors,
         );
         let aux_constraint_degrees = context.aux_transition_constraint_degrees.clone();
+        let aux_divisor_indexes = remap_divisor_indexes(&context.aux_transition_divisor_indexes);
         let aux_constraints = group_constraints(
             &aux_constraint_degrees,
+            &aux_divisor_indexes,
             context,
             aux_constraint_coefficients,
-            divisor.degree(),
+            &divisors,
         );
 
         Self {
             main_constraints,
             main_constraint_degrees,
+            main_divisor_indexes,
             aux_constraints,
             aux_constraint_degrees,
-            divisor,
+            aux_divisor_indexes,
+            divisors,
         }
     }
 
@@ -90,7 +118,7 @@ impl<E: FieldElement> TransitionConstraints<E> {
     // --------------------------------------------------------------------------------------------
 
     /// Returns transition constraint info for constraints applied against the main trace segment
-    /// of a computation grouped by constraint degree.
+    /// of a computation grouped by constraint divisor and degree.
     pub fn main_constraints(&self) -> &[TransitionConstraintGroup<E>] {
         &self.main_constraints
     }
@@ -110,8 +138,14 @@ impl<E: FieldElement> TransitionConstraints<E> {
         self.main_constraint_degrees.len()
     }
 
+    /// Returns indexes into the list of [divisors](TransitionConstraints::divisors) for all
+    /// constraints applied against the main trace segment of a computation.
+    pub fn main_constraint_divisor_indexes(&self) -> &[usize] {
+        &self.main_divisor_indexes
+    }
+
     /// Returns transition constraint info for constraints applied against auxiliary trace segments
-    /// of a computation grouped by constraint degree.
+    /// of a computation grouped by constraint divisor and degree.
     pub fn aux_constraints(&self) -> &[TransitionConstraintGroup<E>] {
         &self.aux_constraints
     }
@@ -131,26 +165,34 @@ impl<E: FieldElement> TransitionConstraints<E> {
         self.aux_constraint_degrees.len()
     }
 
-    /// Returns a divisor for transition constraints.
+    /// Returns indexes into the list of [divisors](TransitionConstraints::divisors) for all
+    /// constraints applied against auxiliary trace segments of a computation.
+    pub fn aux_constraint_divisor_indexes(&self) -> &[usize] {
+        &self.aux_divisor_indexes
+    }
+
+    /// Returns a list of all distinct divisors for transition constraints.
     ///
-    /// All transition constraints have the same divisor which has the form:
+    /// Unless a different divisor is set for a constraint in [AirContext], the constraint has
+    /// the default divisor which has the form:
     /// $$
     /// z(x) = \frac{x^n - 1}{x - g^{n - 1}}
     /// $$
     /// where: $n$ is the length of the execution trace and $g$ is the generator of the trace
-    /// domain.
-    ///
-    /// This divisor specifies that transition constraints must hold on all steps of the
+    /// domain. This divisor specifies that transition constraints must hold on all steps of the
     /// execution trace except for the last one.
-    pub fn divisor(&self) -> &ConstraintDivisor<E::BaseField> {
-        &self.divisor
+    ///
+    /// If the default divisor applies to at least one constraint, it is the first divisor in
+    /// the list; divisors which do not apply to any constraints are not included in the list.
+    pub fn divisors(&self) -> &[ConstraintDivisor<E::BaseField>] {
+        &self.divisors
     }
 
     // CONSTRAINT COMPOSITION
     // --------------------------------------------------------------------------------------------
 
-    /// Computes a linear combination of all transition constraint evaluations and divides the
-    /// result by transition constraint divisor.
+    /// Computes a linear combination of all transition constraint evaluations divided by their
+    /// respective divisors.
     ///
     /// A transition constraint is described by a rational function of the form $\frac{C(x)}{z(x)}$,
     /// where:
@@ -160,36 +202,43 @@ impl<E: FieldElement> TransitionConstraints<E> {
     /// Thus, this function computes a linear combination of $C(x)$ evaluations. For more detail on
     ///  how this linear combination is computed refer to [TransitionConstraintGroup::merge_evaluations].
     ///
-    /// Since, the divisor polynomial is the same for all transition constraints (see
-    /// [ConstraintDivisor::from_transition]), we can divide the linear combination by the
-    /// divisor rather than dividing each individual $C(x)$ evaluation. This requires executing only
-    /// one division at the end.
+    /// Since many transition constraints share the same divisor polynomial, we first compute a
+    /// linear combination of constraints for each distinct divisor, and then divide each of these
+    /// linear combinations by its divisor rather than dividing each individual $C(x)$ evaluation.
+    /// This requires executing only one division per divisor.
     pub fn combine_evaluations<F>(&self, main_evaluations: &[F], aux_evaluations: &[E], x: F) -> E
     where
         F: FieldElement<BaseField = E::BaseField>,
         E: ExtensionOf<F>,
     {
+        let mut results = vec![E::ZERO; self.divisors.len()];
+
         // merge constraint evaluations for the main trace segment
-        let mut result = self.main_constraints().iter().fold(E::ZERO, |acc, group| {
-            acc + group.merge_evaluations::<F, F>(main_evaluations, x)
-        });
+        for group in self.main_constraints().iter() {
+            results[group.divisor_index()] += group.merge_evaluations::<F, F>(main_evaluations, x);
+        }
 
         // merge constraint evaluations for auxiliary trace segments (if any)
         if self.num_aux_constraints() > 0 {
-            result += self.aux_constraints().iter().fold(E::ZERO, |acc, group| {
-                acc + group.merge_evaluations::<F, E>(aux_evaluations, x)
-            });
+            for group in self.aux_constraints().iter() {
+                results[group.divisor_index()] +=
+                    group.merge_evaluations::<F, E>(aux_evaluations, x);
+            }
         }
 
-        // divide out the evaluation of divisor at x and return the result
-        let z = E::from(self.divisor.evaluate_at(x));
-        result / z
+        // divide out the evaluations of divisors at x and return the sum of the results
+        results
+            .into_iter()
+            .zip(self.divisors.iter())
+            .fold(E::ZERO, |acc, (result, divisor)| {
+                acc + result / E::from(divisor.evaluate_at(x))
+            })
     }
 }
 
 // TRANSITION CONSTRAINT GROUP
 // ================================================================================================
-/// A group of transition constraints all having the same degree.
+/// A group of transition constraints all having the same divisor and degree.
 ///
 /// A transition constraint group does not actually store transition constraints - it stores only
 /// their indexes and the info needed to compute their random linear combination. The indexes are
@@ -199,6 +248,7 @@ impl<E: FieldElement> TransitionConstraints<E> {
 #[derive(Clone, Debug)]
 pub struct TransitionConstraintGroup<E: FieldElement> {
     degree: TransitionConstraintDegree,
+    divisor_idx: usize,
     degree_adjustment: u32,
     indexes: Vec<usize>,
     coefficients: Vec<(E, E)>,
@@ -207,9 +257,11 @@ pub struct TransitionConstraintGroup<E: FieldElement> {
 impl<E: FieldElement> TransitionConstraintGroup<E> {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
-    /// Returns a new transition constraint group to hold constraints of the specified degree.
+    /// Returns a new transition constraint group to hold constraints of the specified degree
+    /// and with the divisor at the specified index.
     pub(super) fn new(
         degree: TransitionConstraintDegree,
+        divisor_idx: usize,
         trace_length: usize,
         composition_degree: usize,
         divisor_degree: usize,
@@ -221,6 +273,7 @@ impl<E: FieldElement> TransitionConstraintGroup<E> {
         let degree_adjustment = (target_degree - evaluation_degree) as u32;
         TransitionConstraintGroup {
             degree,
+            divisor_idx,
             degree_adjustment,
             indexes: vec![],
             coefficients: vec![],
@@ -240,6 +293,12 @@ impl<E: FieldElement> TransitionConstraintGroup<E> {
         &self.degree
     }
 
+    /// Returns the index of the divisor shared by all constraints in this group in the list of
+    /// [TransitionConstraints::divisors].
+    pub fn divisor_index(&self) -> usize {
+        self.divisor_idx
+    }
+
     /// Adds a new constraint to the group. The constraint is identified by an index in the
     /// evaluation table.
     pub fn add(&mut self, constraint_idx: usize, coefficients: (E, E)) {
@@ -268,8 +327,9 @@ impl<E: FieldElement> TransitionConstraintGroup<E> {
     /// the merged evaluations represent a polynomial of degree $D + n - 1$, which is higher
     /// then the target degree of the composition polynomial. This is because at this stage,
     /// we are merging only the numerators of transition constraints, and we will need to divide
-    /// them by the divisor later on. The degree of the divisor for transition constraints is
-    /// always $n - 1$. Thus, once we divide out the divisor, the evaluations will represent a
+    /// them by the divisor later on. The degree of the default divisor for transition constraints
+    /// is $n - 1$ (for other divisors, $n - 1$ in the formula above is replaced by the degree of
+    /// the divisor). Thus, once we divide out the divisor, the evaluations will represent a
     /// polynomial of degree $D$.
     pub fn merge_evaluations<B, F>(&self, evaluations: &[F], x: B) -> E
     where
@@ -294,26 +354,30 @@ impl<E: FieldElement> TransitionConstraintGroup<E> {
 // HELPER FUNCTIONS
 // ================================================================================================
 
-/// Groups transition constraints by their degree.
+/// Groups transition constraints by their divisor and degree.
 fn group_constraints<E: FieldElement>(
     degrees: &[TransitionConstraintDegree],
+    divisor_indexes: &[usize],
     context: &AirContext<E::BaseField>,
     coefficients: &[(E, E)],
-    divisor_degree: usize,
+    divisors: &[ConstraintDivisor<E::BaseField>],
 ) -> Vec<TransitionConstraintGroup<E>> {
     // iterate over transition constraint degrees, and assign each constraint to the appropriate
-    // group based on its degree
+    // group based on its divisor and degree
     let mut groups = BTreeMap::new();
-    for (i, degree) in degrees.iter().enumerate() {
+    for (i, (degree, &divisor_idx)) in degrees.iter().zip(divisor_indexes).enumerate() {
         let evaluation_degree = degree.get_evaluation_degree(context.trace_len());
-        let group = groups.entry(evaluation_degree).or_insert_with(|| {
-            TransitionConstraintGroup::new(
-                degree.clone(),
-                context.trace_len(),
-                context.composition_degree(),
-                divisor_degree,
-            )
-        });
+        let group = groups
+            .entry((divisor_idx, evaluation_degree))
+            .or_insert_with(|| {
+                TransitionConstraintGroup::new(
+                    degree.clone(),
+                    divisor_idx,
+                    context.trace_len(),
+                    context.composition_degree(),
+                    divisors[divisor_idx].degree(),
+                )
+            });
         group.add(i, coefficients[i]);
     }
 
@@ -0,0 +1,238 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{AirBuilder, AssertionBuilder, Constraint, ConstraintGraph};
+use crate::{
+    Air, AirContext, Assertion, AuxTraceRandElements, EvaluationFrame, ProofOptions, TraceInfo,
+};
+use core::marker::PhantomData;
+use math::{ExtensibleField, ExtensionOf, FieldElement, StarkField};
+use utils::{collections::Vec, string::String, Serializable};
+
+// AIR DEFINITION
+// ================================================================================================
+/// Declarative description of a computation.
+///
+/// [DeclarativeAir] implements the [Air] trait for any type implementing this trait; the
+/// structure of the computation is obtained from [AirDefinition::define()], and constraint
+/// degrees are computed from the declared constraint expressions.
+pub trait AirDefinition: Send + Sync {
+    /// Base field for the computation; see [Air::BaseField].
+    type BaseField: StarkField + ExtensibleField<2> + ExtensibleField<3>;
+
+    /// Public inputs for the computation; see [Air::PublicInputs].
+    type PublicInputs: Serializable;
+
+    /// Handles to the trace columns declared by [AirDefinition::define()], e.g., `[Column; 2]`.
+    type Columns: Send + Sync;
+
+    /// Domain separation tag for the computation; see [Air::ID].
+    const ID: &'static [u8] = &[];
+
+    /// Declares trace columns, periodic columns, and transition constraints of the computation
+    /// using the provided builder, and returns handles to the declared columns.
+    ///
+    /// This function must not depend on any external state, as it is invoked every time an
+    /// instance of [DeclarativeAir] or [TraceBuilder](super::TraceBuilder) is created.
+    fn define(air: &mut AirBuilder<Self::BaseField>) -> Self::Columns;
+
+    /// Declares boundary assertions for an instance of the computation defined by the specified
+    /// public inputs.
+    fn get_assertions(
+        columns: &Self::Columns,
+        pub_inputs: &Self::PublicInputs,
+        assertions: &mut AssertionBuilder<Self::BaseField>,
+    );
+}
+
+// DECLARATIVE AIR
+// ================================================================================================
+/// An implementation of the [Air] trait for a computation described via [AirDefinition].
+///
+/// Constraints which reference auxiliary trace columns or random elements are evaluated as
+/// auxiliary transition constraints; in this case, the execution trace must have a single
+/// auxiliary segment with as many columns as were declared via [AirBuilder::aux_column()].
+pub struct DeclarativeAir<D: AirDefinition> {
+    context: AirContext<D::BaseField>,
+    column_names: Vec<String>,
+    aux_column_names: Vec<String>,
+    periodic_column_values: Vec<Vec<D::BaseField>>,
+    constraints: Vec<Constraint<D::BaseField>>,
+    graph: ConstraintGraph<D::BaseField>,
+    aux_graph: ConstraintGraph<D::BaseField>,
+    assertions: Vec<Assertion<D::BaseField>>,
+    aux_assertions: Vec<Assertion<D::BaseField>>,
+    _definition: PhantomData<D>,
+}
+
+impl<D: AirDefinition> DeclarativeAir<D> {
+    /// Returns names of all main trace columns in the order in which they appear in the trace.
+    pub fn column_names(&self) -> &[String] {
+        &self.column_names
+    }
+
+    /// Returns names of all auxiliary trace columns in the order in which they appear in the
+    /// auxiliary trace segment.
+    pub fn aux_column_names(&self) -> &[String] {
+        &self.aux_column_names
+    }
+
+    /// Returns all transition constraints in the order in which they are evaluated; main
+    /// constraints always precede auxiliary constraints.
+    pub fn constraints(&self) -> &[Constraint<D::BaseField>] {
+        &self.constraints
+    }
+
+    /// Returns the graph of transition constraints against the main trace segment.
+    pub fn graph(&self) -> &ConstraintGraph<D::BaseField> {
+        &self.graph
+    }
+
+    /// Returns the graph of transition constraints against the auxiliary trace segment.
+    pub fn aux_graph(&self) -> &ConstraintGraph<D::BaseField> {
+        &self.aux_graph
+    }
+}
+
+impl<D: AirDefinition> Air for DeclarativeAir<D> {
+    type BaseField = D::BaseField;
+    type PublicInputs = D::PublicInputs;
+    const ID: &'static [u8] = D::ID;
+
+    /// Builds the AIR from the definition.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * Width of the main trace segment is different from the number of declared columns.
+    /// * Auxiliary columns have been declared, but the execution trace does not have a single
+    ///   auxiliary segment of the same width, or the segment does not provide enough random
+    ///   elements.
+    /// * No transition constraints or assertions have been declared for any of the segments.
+    /// * Any of the transition constraints does not depend on trace columns.
+    fn new(trace_info: TraceInfo, pub_inputs: D::PublicInputs, options: ProofOptions) -> Self {
+        let mut builder = AirBuilder::new();
+        let columns = D::define(&mut builder);
+
+        let layout = trace_info.layout();
+        assert_eq!(
+            builder.column_names.len(),
+            layout.main_trace_width(),
+            "expected execution trace with {} columns, but was {}",
+            builder.column_names.len(),
+            layout.main_trace_width()
+        );
+        if !builder.aux_column_names.is_empty() {
+            assert_eq!(
+                layout.num_aux_segments(),
+                1,
+                "expected execution trace with a single auxiliary segment, but was {}",
+                layout.num_aux_segments()
+            );
+            assert_eq!(
+                builder.aux_column_names.len(),
+                layout.get_aux_segment_width(0),
+                "expected auxiliary trace segment with {} columns, but was {}",
+                builder.aux_column_names.len(),
+                layout.get_aux_segment_width(0)
+            );
+            assert!(
+                builder.num_rand_elements <= layout.get_aux_segment_rand_elements(0),
+                "expected at least {} random elements for auxiliary trace segment, but was {}",
+                builder.num_rand_elements,
+                layout.get_aux_segment_rand_elements(0)
+            );
+        }
+
+        // compile main and auxiliary constraints into separate graphs; main constraints are
+        // always evaluated first
+        let (aux_constraints, main_constraints): (Vec<_>, Vec<_>) =
+            builder.constraints.into_iter().partition(|c| c.is_aux());
+        let mut graph = ConstraintGraph::new();
+        for constraint in main_constraints.iter() {
+            graph.add_constraint(&constraint.expr);
+        }
+        let mut aux_graph = ConstraintGraph::new();
+        for constraint in aux_constraints.iter() {
+            aux_graph.add_constraint(&constraint.expr);
+        }
+
+        let trace_length = trace_info.length();
+        let mut assertions = AssertionBuilder::new(&trace_info);
+        D::get_assertions(&columns, &pub_inputs, &mut assertions);
+
+        let context = AirContext::new_multi_segment(
+            trace_info,
+            graph.constraint_degrees(trace_length),
+            aux_graph.constraint_degrees(trace_length),
+            assertions.assertions.len(),
+            assertions.aux_assertions.len(),
+            options,
+        );
+
+        let mut constraints = main_constraints;
+        constraints.extend(aux_constraints);
+
+        Self {
+            context,
+            column_names: builder.column_names,
+            aux_column_names: builder.aux_column_names,
+            periodic_column_values: builder.periodic_column_values,
+            constraints,
+            graph,
+            aux_graph,
+            assertions: assertions.assertions,
+            aux_assertions: assertions.aux_assertions,
+            _definition: PhantomData,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        self.graph.evaluate(frame, periodic_values, result);
+    }
+
+    fn evaluate_aux_transition<F, E>(
+        &self,
+        main_frame: &EvaluationFrame<F>,
+        aux_frame: &EvaluationFrame<E>,
+        periodic_values: &[F],
+        aux_rand_elements: &AuxTraceRandElements<E>,
+        result: &mut [E],
+    ) where
+        F: FieldElement<BaseField = Self::BaseField>,
+        E: FieldElement<BaseField = Self::BaseField> + ExtensionOf<F>,
+    {
+        self.aux_graph.evaluate_aux(
+            main_frame,
+            aux_frame,
+            periodic_values,
+            aux_rand_elements.get_segment_elements(0),
+            result,
+        );
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        self.assertions.clone()
+    }
+
+    fn get_aux_assertions<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        _aux_rand_elements: &AuxTraceRandElements<E>,
+    ) -> Vec<Assertion<E>> {
+        self.aux_assertions.iter().map(|a| a.lift()).collect()
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        self.periodic_column_values.clone()
+    }
+}
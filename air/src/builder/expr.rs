@@ -0,0 +1,131 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::ConstraintGraph;
+use crate::TransitionConstraintDegree;
+use core::ops::{Add, Mul, Neg, Sub};
+use math::StarkField;
+
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+
+// EXPRESSION
+// ================================================================================================
+/// An algebraic expression over values of trace columns, periodic columns, and random elements.
+///
+/// Expressions are usually not constructed directly, but are built from [Column](super::Column)
+/// and [AuxColumn](super::AuxColumn) handles, as well as from periodic column values and random
+/// elements returned by [AirBuilder](super::AirBuilder), combined together via `+`, `-`, `*`
+/// operators and [Expr::exp()] method. Constants can be added to, subtracted from, and
+/// multiplied with expressions directly.
+///
+/// Expressions are trees; to evaluate them efficiently, they are compiled into a
+/// [ConstraintGraph].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr<B: StarkField> {
+    /// A constant value.
+    Constant(B),
+    /// Value of the trace column at the specified index in the current row.
+    Current(usize),
+    /// Value of the trace column at the specified index in the next row.
+    Next(usize),
+    /// Value of the column at the specified index of the auxiliary trace segment in the current
+    /// row.
+    AuxCurrent(usize),
+    /// Value of the column at the specified index of the auxiliary trace segment in the next
+    /// row.
+    AuxNext(usize),
+    /// Value of the periodic column at the specified index; `cycle_length` is the number of
+    /// values in the column.
+    Periodic { index: usize, cycle_length: usize },
+    /// Random element at the specified index drawn by the verifier after the main trace segment
+    /// has been committed to.
+    RandElement(usize),
+    /// Sum of two expressions.
+    Add(Box<Expr<B>>, Box<Expr<B>>),
+    /// Difference of two expressions.
+    Sub(Box<Expr<B>>, Box<Expr<B>>),
+    /// Product of two expressions.
+    Mul(Box<Expr<B>>, Box<Expr<B>>),
+    /// Negation of an expression.
+    Neg(Box<Expr<B>>),
+    /// An expression raised to the specified power.
+    Exp(Box<Expr<B>>, u32),
+}
+
+impl<B: StarkField> Expr<B> {
+    /// Returns this expression raised to the specified power.
+    pub fn exp(self, power: u32) -> Self {
+        Self::Exp(Box::new(self), power)
+    }
+
+    /// Returns the degree descriptor of a transition constraint described by this expression
+    /// when evaluated over an execution trace of the specified length.
+    ///
+    /// The degree is computed after the expression is simplified as described in
+    /// [ConstraintGraph]; see [ConstraintGraph::degree()] for details.
+    ///
+    /// # Panics
+    /// Panics if the simplified expression does not depend on any trace columns.
+    pub fn degree(&self, trace_length: usize) -> TransitionConstraintDegree {
+        let mut graph = ConstraintGraph::new();
+        let node = graph.insert(self);
+        graph.degree(node, trace_length)
+    }
+
+    /// Returns true if this expression references columns of the auxiliary trace segment or
+    /// random elements.
+    pub fn is_aux(&self) -> bool {
+        match self {
+            Self::AuxCurrent(_) | Self::AuxNext(_) | Self::RandElement(_) => true,
+            Self::Constant(_) | Self::Current(_) | Self::Next(_) | Self::Periodic { .. } => false,
+            Self::Add(lhs, rhs) | Self::Sub(lhs, rhs) | Self::Mul(lhs, rhs) => {
+                lhs.is_aux() || rhs.is_aux()
+            }
+            Self::Neg(value) | Self::Exp(value, _) => value.is_aux(),
+        }
+    }
+}
+
+impl<B: StarkField> From<B> for Expr<B> {
+    fn from(value: B) -> Self {
+        Self::Constant(value)
+    }
+}
+
+// OPERATORS
+// ================================================================================================
+
+macro_rules! impl_binary_op {
+    ($trait:ident, $method:ident, $variant:ident) => {
+        impl<B: StarkField> $trait for Expr<B> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                Self::$variant(Box::new(self), Box::new(rhs))
+            }
+        }
+
+        impl<B: StarkField> $trait<B> for Expr<B> {
+            type Output = Self;
+
+            fn $method(self, rhs: B) -> Self {
+                Self::$variant(Box::new(self), Box::new(Self::Constant(rhs)))
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, Add);
+impl_binary_op!(Sub, sub, Sub);
+impl_binary_op!(Mul, mul, Mul);
+
+impl<B: StarkField> Neg for Expr<B> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::Neg(Box::new(self))
+    }
+}
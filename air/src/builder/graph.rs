@@ -0,0 +1,505 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::Expr;
+use crate::{EvaluationFrame, TransitionConstraintDegree};
+use core::fmt;
+use math::{ExtensionOf, FieldElement, StarkField};
+use utils::collections::{BTreeMap, Vec};
+
+// NODE INDEX
+// ================================================================================================
+/// Index of a node in a [ConstraintGraph].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NodeIndex(usize);
+
+impl NodeIndex {
+    /// Returns this index as a `usize` value.
+    pub fn as_usize(&self) -> usize {
+        self.0
+    }
+}
+
+impl fmt::Display for NodeIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "n{}", self.0)
+    }
+}
+
+// NODE
+// ================================================================================================
+/// A node of a [ConstraintGraph].
+///
+/// Leaf nodes have the same meaning as the corresponding variants of [Expr]; operation nodes
+/// refer to their operands by [NodeIndex]. Operands always precede the operations in which they
+/// are used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Node<B: StarkField> {
+    /// A constant value.
+    Constant(B),
+    /// Value of a main trace column in the current row.
+    Current(usize),
+    /// Value of a main trace column in the next row.
+    Next(usize),
+    /// Value of an auxiliary trace column in the current row.
+    AuxCurrent(usize),
+    /// Value of an auxiliary trace column in the next row.
+    AuxNext(usize),
+    /// Value of a periodic column.
+    Periodic { index: usize, cycle_length: usize },
+    /// Random element drawn by the verifier for the auxiliary trace segment.
+    RandElement(usize),
+    /// Sum of two nodes.
+    Add(NodeIndex, NodeIndex),
+    /// Difference of two nodes.
+    Sub(NodeIndex, NodeIndex),
+    /// Product of two nodes.
+    Mul(NodeIndex, NodeIndex),
+    /// Negation of a node.
+    Neg(NodeIndex),
+    /// A node raised to the specified power.
+    Exp(NodeIndex, u32),
+}
+
+/// Key used to look up structurally identical nodes.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum NodeKey {
+    Constant(Vec<u8>),
+    Current(usize),
+    Next(usize),
+    AuxCurrent(usize),
+    AuxNext(usize),
+    Periodic(usize, usize),
+    RandElement(usize),
+    Add(NodeIndex, NodeIndex),
+    Sub(NodeIndex, NodeIndex),
+    Mul(NodeIndex, NodeIndex),
+    Neg(NodeIndex),
+    Exp(NodeIndex, u32),
+}
+
+impl NodeKey {
+    fn new<B: StarkField>(node: &Node<B>) -> Self {
+        match *node {
+            Node::Constant(value) => Self::Constant(value.to_bytes()),
+            Node::Current(index) => Self::Current(index),
+            Node::Next(index) => Self::Next(index),
+            Node::AuxCurrent(index) => Self::AuxCurrent(index),
+            Node::AuxNext(index) => Self::AuxNext(index),
+            Node::Periodic {
+                index,
+                cycle_length,
+            } => Self::Periodic(index, cycle_length),
+            Node::RandElement(index) => Self::RandElement(index),
+            Node::Add(lhs, rhs) => Self::Add(lhs, rhs),
+            Node::Sub(lhs, rhs) => Self::Sub(lhs, rhs),
+            Node::Mul(lhs, rhs) => Self::Mul(lhs, rhs),
+            Node::Neg(value) => Self::Neg(value),
+            Node::Exp(base, power) => Self::Exp(base, power),
+        }
+    }
+}
+
+// CONSTRAINT GRAPH
+// ================================================================================================
+/// A set of transition constraints compiled into a directed acyclic graph of operations.
+///
+/// When expressions are inserted into the graph, the following optimizations are applied:
+/// * Common sub-expressions are eliminated: structurally identical sub-expressions (including
+///   sums and products with swapped operands) are mapped to the same node, and thus, are
+///   evaluated only once per evaluation frame, even if they are shared between constraints.
+/// * Constant sub-expressions are folded, and trivial identities are simplified: e.g., `x + 0`,
+///   `x * 1`, and `x^1` become `x`, while `x * 0`, `x - x`, and `x^0` become constants.
+///
+/// Constraints in the graph can be evaluated over any field via [ConstraintGraph::evaluate()]
+/// and [ConstraintGraph::evaluate_aux()], and their degrees can be computed via
+/// [ConstraintGraph::degree()]. The graph can also be traversed via [ConstraintGraph::nodes()]
+/// for analysis or for export into other formats; the [Display](fmt::Display) implementation
+/// prints the graph as a list of assignments.
+#[derive(Debug, Clone)]
+pub struct ConstraintGraph<B: StarkField> {
+    nodes: Vec<Node<B>>,
+    constraints: Vec<NodeIndex>,
+    lookup: BTreeMap<NodeKey, NodeIndex>,
+}
+
+impl<B: StarkField> ConstraintGraph<B> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new graph without any nodes or constraints.
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            constraints: Vec::new(),
+            lookup: BTreeMap::new(),
+        }
+    }
+
+    // GRAPH CONSTRUCTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Inserts the specified expression into this graph and returns the index of the node
+    /// which holds the result of the expression.
+    pub fn insert(&mut self, expr: &Expr<B>) -> NodeIndex {
+        match expr {
+            Expr::Constant(value) => self.constant(*value),
+            Expr::Current(index) => self.add_node(Node::Current(*index)),
+            Expr::Next(index) => self.add_node(Node::Next(*index)),
+            Expr::AuxCurrent(index) => self.add_node(Node::AuxCurrent(*index)),
+            Expr::AuxNext(index) => self.add_node(Node::AuxNext(*index)),
+            Expr::Periodic {
+                index,
+                cycle_length,
+            } => self.add_node(Node::Periodic {
+                index: *index,
+                cycle_length: *cycle_length,
+            }),
+            Expr::RandElement(index) => self.add_node(Node::RandElement(*index)),
+            Expr::Add(lhs, rhs) => {
+                let (lhs, rhs) = (self.insert(lhs), self.insert(rhs));
+                self.add(lhs, rhs)
+            }
+            Expr::Sub(lhs, rhs) => {
+                let (lhs, rhs) = (self.insert(lhs), self.insert(rhs));
+                self.sub(lhs, rhs)
+            }
+            Expr::Mul(lhs, rhs) => {
+                let (lhs, rhs) = (self.insert(lhs), self.insert(rhs));
+                self.mul(lhs, rhs)
+            }
+            Expr::Neg(value) => {
+                let value = self.insert(value);
+                self.neg(value)
+            }
+            Expr::Exp(base, power) => {
+                let base = self.insert(base);
+                self.exp(base, *power)
+            }
+        }
+    }
+
+    /// Inserts the specified expression into this graph and marks the resulting node as a
+    /// transition constraint.
+    ///
+    /// Constraints are evaluated in the order in which they are added.
+    pub fn add_constraint(&mut self, expr: &Expr<B>) -> NodeIndex {
+        let node = self.insert(expr);
+        self.constraints.push(node);
+        node
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns all nodes of this graph; operands of a node always precede the node itself.
+    pub fn nodes(&self) -> &[Node<B>] {
+        &self.nodes
+    }
+
+    /// Returns the node at the specified index.
+    pub fn node(&self, index: NodeIndex) -> &Node<B> {
+        &self.nodes[index.0]
+    }
+
+    /// Returns indexes of nodes which hold the results of transition constraints.
+    pub fn constraints(&self) -> &[NodeIndex] {
+        &self.constraints
+    }
+
+    // DEGREES
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the degree descriptor of the transition constraint described by the specified
+    /// node when evaluated over an execution trace of the specified length.
+    ///
+    /// Trace columns have degree one, periodic columns contribute their cycle lengths, and
+    /// constants and random elements have degree zero. Degrees of products are sums of the
+    /// degrees of the operands, while for sums and differences the operand with the larger
+    /// evaluation degree for the specified trace length is selected. Thus, the degree is exact
+    /// unless terms of the constraint cancel each other out in ways which are not detected by
+    /// the simplifications applied during graph construction.
+    ///
+    /// # Panics
+    /// Panics if the node does not depend on any trace columns.
+    pub fn degree(&self, node: NodeIndex, trace_length: usize) -> TransitionConstraintDegree {
+        let degrees = self.get_node_degrees(node.0 + 1, trace_length);
+        degrees[node.0].to_constraint_degree()
+    }
+
+    /// Returns degree descriptors of all transition constraints in this graph in the order in
+    /// which they were added.
+    ///
+    /// # Panics
+    /// Panics if any of the constraints does not depend on trace columns.
+    pub fn constraint_degrees(&self, trace_length: usize) -> Vec<TransitionConstraintDegree> {
+        let degrees = self.get_node_degrees(self.nodes.len(), trace_length);
+        self.constraints
+            .iter()
+            .map(|node| degrees[node.0].to_constraint_degree())
+            .collect()
+    }
+
+    // EVALUATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Evaluates transition constraints in this graph over the specified evaluation frame and
+    /// periodic values, and writes the results into `result`.
+    ///
+    /// # Panics
+    /// Panics if the graph references columns of the auxiliary trace segment or random elements.
+    pub fn evaluate<E: FieldElement<BaseField = B>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        self.evaluate_nodes(
+            |node| match *node {
+                Node::Current(index) => frame.current()[index],
+                Node::Next(index) => frame.next()[index],
+                Node::Periodic { index, .. } => periodic_values[index],
+                _ => panic!("auxiliary trace values are not available for evaluation"),
+            },
+            result,
+        );
+    }
+
+    /// Evaluates transition constraints in this graph over the specified evaluation frames of
+    /// the main and the auxiliary trace segments, periodic values, and random elements, and
+    /// writes the results into `result`.
+    pub fn evaluate_aux<F, E>(
+        &self,
+        main_frame: &EvaluationFrame<F>,
+        aux_frame: &EvaluationFrame<E>,
+        periodic_values: &[F],
+        rand_elements: &[E],
+        result: &mut [E],
+    ) where
+        F: FieldElement<BaseField = B>,
+        E: FieldElement<BaseField = B> + ExtensionOf<F>,
+    {
+        self.evaluate_nodes(
+            |node| match *node {
+                Node::Current(index) => E::from(main_frame.current()[index]),
+                Node::Next(index) => E::from(main_frame.next()[index]),
+                Node::AuxCurrent(index) => aux_frame.current()[index],
+                Node::AuxNext(index) => aux_frame.next()[index],
+                Node::Periodic { index, .. } => E::from(periodic_values[index]),
+                Node::RandElement(index) => rand_elements[index],
+                _ => unreachable!("not a leaf node"),
+            },
+            result,
+        );
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Evaluates all nodes of the graph using the provided function to get values of leaf nodes
+    /// (other than constants), and writes values of constraint nodes into `result`.
+    fn evaluate_nodes<E, L>(&self, leaf_value: L, result: &mut [E])
+    where
+        E: FieldElement<BaseField = B>,
+        L: Fn(&Node<B>) -> E,
+    {
+        let mut values: Vec<E> = Vec::with_capacity(self.nodes.len());
+        for node in self.nodes.iter() {
+            let value = match *node {
+                Node::Constant(value) => E::from(value),
+                Node::Add(lhs, rhs) => values[lhs.0] + values[rhs.0],
+                Node::Sub(lhs, rhs) => values[lhs.0] - values[rhs.0],
+                Node::Mul(lhs, rhs) => values[lhs.0] * values[rhs.0],
+                Node::Neg(value) => -values[value.0],
+                Node::Exp(base, power) => values[base.0].exp(power.into()),
+                _ => leaf_value(node),
+            };
+            values.push(value);
+        }
+
+        for (result, node) in result.iter_mut().zip(self.constraints.iter()) {
+            *result = values[node.0];
+        }
+    }
+
+    /// Computes degrees of the first `num_nodes` nodes of the graph.
+    fn get_node_degrees(&self, num_nodes: usize, trace_length: usize) -> Vec<NodeDegree> {
+        let mut degrees: Vec<NodeDegree> = Vec::with_capacity(num_nodes);
+        for node in self.nodes[..num_nodes].iter() {
+            let degree = match *node {
+                Node::Constant(_) | Node::RandElement(_) => NodeDegree::new(0, Vec::new()),
+                Node::Current(_) | Node::Next(_) | Node::AuxCurrent(_) | Node::AuxNext(_) => {
+                    NodeDegree::new(1, Vec::new())
+                }
+                Node::Periodic { cycle_length, .. } => NodeDegree::new(0, vec![cycle_length]),
+                Node::Add(lhs, rhs) | Node::Sub(lhs, rhs) => {
+                    let (lhs, rhs) = (&degrees[lhs.0], &degrees[rhs.0]);
+                    if rhs.evaluation_degree(trace_length) > lhs.evaluation_degree(trace_length) {
+                        rhs.clone()
+                    } else {
+                        lhs.clone()
+                    }
+                }
+                Node::Mul(lhs, rhs) => {
+                    let (lhs, rhs) = (&degrees[lhs.0], &degrees[rhs.0]);
+                    let mut cycles = lhs.cycles.clone();
+                    cycles.extend_from_slice(&rhs.cycles);
+                    NodeDegree::new(lhs.base + rhs.base, cycles)
+                }
+                Node::Neg(value) => degrees[value.0].clone(),
+                Node::Exp(base, power) => {
+                    let base = &degrees[base.0];
+                    let power = power as usize;
+                    let mut cycles = Vec::with_capacity(base.cycles.len() * power);
+                    for _ in 0..power {
+                        cycles.extend_from_slice(&base.cycles);
+                    }
+                    NodeDegree::new(base.base * power, cycles)
+                }
+            };
+            degrees.push(degree);
+        }
+        degrees
+    }
+
+    /// Returns the value of the specified node if the node is a constant.
+    fn get_constant(&self, node: NodeIndex) -> Option<B> {
+        match self.nodes[node.0] {
+            Node::Constant(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn constant(&mut self, value: B) -> NodeIndex {
+        self.add_node(Node::Constant(value))
+    }
+
+    fn add(&mut self, lhs: NodeIndex, rhs: NodeIndex) -> NodeIndex {
+        match (self.get_constant(lhs), self.get_constant(rhs)) {
+            (Some(a), Some(b)) => self.constant(a + b),
+            (Some(a), None) if a == B::ZERO => rhs,
+            (None, Some(b)) if b == B::ZERO => lhs,
+            _ => self.add_node(Node::Add(lhs.min(rhs), lhs.max(rhs))),
+        }
+    }
+
+    fn sub(&mut self, lhs: NodeIndex, rhs: NodeIndex) -> NodeIndex {
+        match (self.get_constant(lhs), self.get_constant(rhs)) {
+            (Some(a), Some(b)) => self.constant(a - b),
+            (Some(a), None) if a == B::ZERO => self.neg(rhs),
+            (None, Some(b)) if b == B::ZERO => lhs,
+            _ if lhs == rhs => self.constant(B::ZERO),
+            _ => self.add_node(Node::Sub(lhs, rhs)),
+        }
+    }
+
+    fn mul(&mut self, lhs: NodeIndex, rhs: NodeIndex) -> NodeIndex {
+        match (self.get_constant(lhs), self.get_constant(rhs)) {
+            (Some(a), Some(b)) => self.constant(a * b),
+            (Some(a), _) if a == B::ZERO => self.constant(B::ZERO),
+            (_, Some(b)) if b == B::ZERO => self.constant(B::ZERO),
+            (Some(a), None) if a == B::ONE => rhs,
+            (None, Some(b)) if b == B::ONE => lhs,
+            _ => self.add_node(Node::Mul(lhs.min(rhs), lhs.max(rhs))),
+        }
+    }
+
+    fn neg(&mut self, value: NodeIndex) -> NodeIndex {
+        match self.nodes[value.0] {
+            Node::Constant(value) => self.constant(-value),
+            Node::Neg(inner) => inner,
+            _ => self.add_node(Node::Neg(value)),
+        }
+    }
+
+    fn exp(&mut self, base: NodeIndex, power: u32) -> NodeIndex {
+        match (self.get_constant(base), power) {
+            (_, 0) => self.constant(B::ONE),
+            (_, 1) => base,
+            (Some(value), _) => self.constant(value.exp(power.into())),
+            (None, _) => self.add_node(Node::Exp(base, power)),
+        }
+    }
+
+    /// Adds the specified node to the graph unless a structurally identical node is already
+    /// present; returns index of the node.
+    fn add_node(&mut self, node: Node<B>) -> NodeIndex {
+        let key = NodeKey::new(&node);
+        if let Some(&index) = self.lookup.get(&key) {
+            return index;
+        }
+        let index = NodeIndex(self.nodes.len());
+        self.nodes.push(node);
+        self.lookup.insert(key, index);
+        index
+    }
+}
+
+impl<B: StarkField> Default for ConstraintGraph<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: StarkField> fmt::Display for ConstraintGraph<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, node) in self.nodes.iter().enumerate() {
+            write!(f, "n{} = ", i)?;
+            match node {
+                Node::Constant(value) => writeln!(f, "{}", value)?,
+                Node::Current(index) => writeln!(f, "current[{}]", index)?,
+                Node::Next(index) => writeln!(f, "next[{}]", index)?,
+                Node::AuxCurrent(index) => writeln!(f, "aux_current[{}]", index)?,
+                Node::AuxNext(index) => writeln!(f, "aux_next[{}]", index)?,
+                Node::Periodic { index, .. } => writeln!(f, "periodic[{}]", index)?,
+                Node::RandElement(index) => writeln!(f, "rand[{}]", index)?,
+                Node::Add(lhs, rhs) => writeln!(f, "{} + {}", lhs, rhs)?,
+                Node::Sub(lhs, rhs) => writeln!(f, "{} - {}", lhs, rhs)?,
+                Node::Mul(lhs, rhs) => writeln!(f, "{} * {}", lhs, rhs)?,
+                Node::Neg(value) => writeln!(f, "-{}", value)?,
+                Node::Exp(base, power) => writeln!(f, "{}^{}", base, power)?,
+            }
+        }
+        for (i, node) in self.constraints.iter().enumerate() {
+            writeln!(f, "constraint {} = {}", i, node)?;
+        }
+        Ok(())
+    }
+}
+
+// NODE DEGREE
+// ================================================================================================
+
+/// Degree of a node; unlike [TransitionConstraintDegree], base degree can be zero.
+#[derive(Clone)]
+struct NodeDegree {
+    base: usize,
+    cycles: Vec<usize>,
+}
+
+impl NodeDegree {
+    fn new(base: usize, cycles: Vec<usize>) -> Self {
+        Self { base, cycles }
+    }
+
+    fn evaluation_degree(&self, trace_length: usize) -> usize {
+        let mut result = self.base * (trace_length - 1);
+        for cycle_length in self.cycles.iter() {
+            result += (trace_length / cycle_length) * (cycle_length - 1);
+        }
+        result
+    }
+
+    fn to_constraint_degree(&self) -> TransitionConstraintDegree {
+        assert!(
+            self.base > 0,
+            "expression does not depend on any trace columns"
+        );
+        if self.cycles.is_empty() {
+            TransitionConstraintDegree::new(self.base)
+        } else {
+            TransitionConstraintDegree::with_cycles(self.base, self.cycles.clone())
+        }
+    }
+}
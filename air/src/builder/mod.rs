@@ -0,0 +1,454 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Declarative description of computations in AIR.
+//!
+//! Instead of implementing the [Air](crate::Air) trait directly, a computation can be described
+//! by implementing the [AirDefinition] trait. The definition declares named trace columns,
+//! periodic columns, and transition constraints via an [AirBuilder], and boundary assertions
+//! via an [AssertionBuilder]. Transition constraints are [Expr] values built from column handles
+//! using regular arithmetic operators, and thus, there is no need to keep track of constraint
+//! indexes or degrees manually: [DeclarativeAir] implements the [Air](crate::Air) trait for any
+//! [AirDefinition] and computes constraint degrees from the expressions.
+//!
+//! Constraints may also reference columns of an auxiliary trace segment and random elements
+//! drawn by the verifier (see [AirBuilder::aux_column()] and [AirBuilder::rand_elements()]);
+//! such constraints are evaluated as auxiliary transition constraints.
+//!
+//! Internally, constraint expressions are compiled into a [ConstraintGraph], which eliminates
+//! common sub-expressions and folds constants. The graph can also be used on its own, e.g., to
+//! analyze constraints or to export them into other formats.
+//!
+//! Execution traces for such computations can be built using a [TraceBuilder], which allows
+//! accessing values in a row of the trace via column handles.
+//!
+//! For example, a computation of a Fibonacci sequence, where every row of the trace advances the
+//! sequence by two terms, could be described like so:
+//!
+//! ```
+//! use winter_air::builder::{AirBuilder, AirDefinition, AssertionBuilder, Column};
+//! use math::{fields::f128::BaseElement, FieldElement};
+//!
+//! struct Fibonacci;
+//!
+//! impl AirDefinition for Fibonacci {
+//!     type BaseField = BaseElement;
+//!     type PublicInputs = BaseElement;
+//!     type Columns = [Column; 2];
+//!
+//!     fn define(air: &mut AirBuilder<BaseElement>) -> [Column; 2] {
+//!         let [a, b] = air.columns(["a", "b"]);
+//!         air.enforce("a", a.next() - (a.current() + b.current()));
+//!         air.enforce("b", b.next() - (b.current() + a.next()));
+//!         [a, b]
+//!     }
+//!
+//!     fn get_assertions(
+//!         [a, b]: &[Column; 2],
+//!         result: &BaseElement,
+//!         assertions: &mut AssertionBuilder<BaseElement>,
+//!     ) {
+//!         assertions.first(*a, BaseElement::ONE);
+//!         assertions.first(*b, BaseElement::ONE);
+//!         assertions.last(*b, *result);
+//!     }
+//! }
+//! ```
+
+use crate::{Assertion, TraceInfo};
+use math::StarkField;
+use utils::{
+    collections::Vec,
+    string::{String, ToString},
+};
+
+mod expr;
+pub use expr::Expr;
+
+mod graph;
+pub use graph::{ConstraintGraph, Node, NodeIndex};
+
+mod definition;
+pub use definition::{AirDefinition, DeclarativeAir};
+
+mod trace;
+pub use trace::{Row, TraceBuilder};
+
+#[cfg(test)]
+mod tests;
+
+// COLUMN
+// ================================================================================================
+/// A handle to a column of the main segment of an execution trace declared via
+/// [AirBuilder::column()].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Column(usize);
+
+impl Column {
+    /// Returns index of this column in the execution trace.
+    pub fn index(&self) -> usize {
+        self.0
+    }
+
+    /// Returns an expression for the value of this column in the current row of the trace.
+    pub fn current<B: StarkField>(&self) -> Expr<B> {
+        Expr::Current(self.0)
+    }
+
+    /// Returns an expression for the value of this column in the next row of the trace.
+    pub fn next<B: StarkField>(&self) -> Expr<B> {
+        Expr::Next(self.0)
+    }
+}
+
+// AUXILIARY COLUMN
+// ================================================================================================
+/// A handle to a column of the auxiliary segment of an execution trace declared via
+/// [AirBuilder::aux_column()].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuxColumn(usize);
+
+impl AuxColumn {
+    /// Returns index of this column in the auxiliary segment of the execution trace.
+    pub fn index(&self) -> usize {
+        self.0
+    }
+
+    /// Returns an expression for the value of this column in the current row of the trace.
+    pub fn current<B: StarkField>(&self) -> Expr<B> {
+        Expr::AuxCurrent(self.0)
+    }
+
+    /// Returns an expression for the value of this column in the next row of the trace.
+    pub fn next<B: StarkField>(&self) -> Expr<B> {
+        Expr::AuxNext(self.0)
+    }
+}
+
+// CONSTRAINT
+// ================================================================================================
+/// A named transition constraint declared via [AirBuilder::enforce()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Constraint<B: StarkField> {
+    name: String,
+    expr: Expr<B>,
+}
+
+impl<B: StarkField> Constraint<B> {
+    /// Returns the name of this constraint.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the expression which must evaluate to zero for this constraint to be satisfied.
+    pub fn expr(&self) -> &Expr<B> {
+        &self.expr
+    }
+
+    /// Returns true if this constraint references columns of the auxiliary trace segment or
+    /// random elements.
+    pub fn is_aux(&self) -> bool {
+        self.expr.is_aux()
+    }
+}
+
+// AIR BUILDER
+// ================================================================================================
+/// Collects trace columns, periodic columns, and transition constraints of a computation
+/// declared via [AirDefinition::define()].
+#[derive(Debug, Clone, Default)]
+pub struct AirBuilder<B: StarkField> {
+    column_names: Vec<String>,
+    aux_column_names: Vec<String>,
+    num_rand_elements: usize,
+    periodic_column_names: Vec<String>,
+    periodic_column_values: Vec<Vec<B>>,
+    constraints: Vec<Constraint<B>>,
+}
+
+impl<B: StarkField> AirBuilder<B> {
+    /// Returns a new builder without any columns or constraints.
+    pub fn new() -> Self {
+        Self {
+            column_names: Vec::new(),
+            aux_column_names: Vec::new(),
+            num_rand_elements: 0,
+            periodic_column_names: Vec::new(),
+            periodic_column_values: Vec::new(),
+            constraints: Vec::new(),
+        }
+    }
+
+    // DECLARATIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Declares a new column of the execution trace with the specified name and returns a
+    /// handle to it.
+    ///
+    /// Columns are placed into the execution trace in the order in which they are declared.
+    ///
+    /// # Panics
+    /// Panics if a column or a periodic column with the same name has already been declared.
+    pub fn column(&mut self, name: &str) -> Column {
+        self.check_name(name);
+        self.column_names.push(name.to_string());
+        Column(self.column_names.len() - 1)
+    }
+
+    /// Declares new columns of the execution trace with the specified names and returns handles
+    /// to them.
+    ///
+    /// # Panics
+    /// Panics if a column or a periodic column with the same name has already been declared.
+    pub fn columns<const N: usize>(&mut self, names: [&str; N]) -> [Column; N] {
+        names.map(|name| self.column(name))
+    }
+
+    /// Declares a new column of the auxiliary segment of the execution trace with the specified
+    /// name and returns a handle to it.
+    ///
+    /// Auxiliary columns are placed into the auxiliary trace segment in the order in which they
+    /// are declared. Values of auxiliary columns are not filled by [TraceBuilder]; instead, the
+    /// auxiliary segment must be built by the `Trace` implementation used in the prover.
+    ///
+    /// # Panics
+    /// Panics if a column with the same name has already been declared.
+    pub fn aux_column(&mut self, name: &str) -> AuxColumn {
+        self.check_name(name);
+        self.aux_column_names.push(name.to_string());
+        AuxColumn(self.aux_column_names.len() - 1)
+    }
+
+    /// Declares new columns of the auxiliary segment of the execution trace with the specified
+    /// names and returns handles to them.
+    ///
+    /// # Panics
+    /// Panics if a column with the same name has already been declared.
+    pub fn aux_columns<const N: usize>(&mut self, names: [&str; N]) -> [AuxColumn; N] {
+        names.map(|name| self.aux_column(name))
+    }
+
+    /// Declares `N` more random elements to be drawn by the verifier after the main trace
+    /// segment has been committed to, and returns expressions for their values.
+    ///
+    /// Random elements are assigned indexes in the order in which they are declared; the
+    /// execution trace layout must provide at least as many random elements for the auxiliary
+    /// trace segment.
+    pub fn rand_elements<const N: usize>(&mut self) -> [Expr<B>; N] {
+        let offset = self.num_rand_elements;
+        self.num_rand_elements += N;
+        let mut i = 0;
+        [(); N].map(|_| {
+            i += 1;
+            Expr::RandElement(offset + i - 1)
+        })
+    }
+
+    /// Declares a new periodic column with the specified name and values, and returns an
+    /// expression for the value of this column in the current row of the trace.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * A column or a periodic column with the same name has already been declared.
+    /// * The number of values is smaller than two or is not a power of two.
+    pub fn periodic_column(&mut self, name: &str, values: Vec<B>) -> Expr<B> {
+        self.check_name(name);
+        assert!(
+            values.len() >= 2 && values.len().is_power_of_two(),
+            "number of values in periodic column '{}' must be a power of two greater than one, but was {}",
+            name,
+            values.len()
+        );
+        let cycle_length = values.len();
+        self.periodic_column_names.push(name.to_string());
+        self.periodic_column_values.push(values);
+        Expr::Periodic {
+            index: self.periodic_column_values.len() - 1,
+            cycle_length,
+        }
+    }
+
+    /// Declares a transition constraint with the specified name. The constraint is satisfied
+    /// when the provided expression evaluates to zero for all pairs of consecutive rows of the
+    /// execution trace.
+    ///
+    /// Constraints which reference columns of the auxiliary trace segment or random elements
+    /// are treated as auxiliary transition constraints.
+    ///
+    /// # Panics
+    /// Panics if a constraint with the same name has already been declared.
+    pub fn enforce(&mut self, name: &str, expr: Expr<B>) {
+        assert!(
+            self.constraints.iter().all(|c| c.name != name),
+            "constraint '{}' has already been declared",
+            name
+        );
+        self.constraints.push(Constraint {
+            name: name.to_string(),
+            expr,
+        });
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns names of all declared trace columns in the order of their declaration.
+    pub fn column_names(&self) -> &[String] {
+        &self.column_names
+    }
+
+    /// Returns names of all declared auxiliary trace columns in the order of their declaration.
+    pub fn aux_column_names(&self) -> &[String] {
+        &self.aux_column_names
+    }
+
+    /// Returns the number of declared random elements.
+    pub fn num_rand_elements(&self) -> usize {
+        self.num_rand_elements
+    }
+
+    /// Returns names of all declared periodic columns in the order of their declaration.
+    pub fn periodic_column_names(&self) -> &[String] {
+        &self.periodic_column_names
+    }
+
+    /// Returns values of all declared periodic columns in the order of their declaration.
+    pub fn periodic_column_values(&self) -> &[Vec<B>] {
+        &self.periodic_column_values
+    }
+
+    /// Returns all declared transition constraints in the order of their declaration.
+    pub fn constraints(&self) -> &[Constraint<B>] {
+        &self.constraints
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn check_name(&self, name: &str) {
+        assert!(
+            !self.column_names.iter().any(|n| n == name)
+                && !self.aux_column_names.iter().any(|n| n == name)
+                && !self.periodic_column_names.iter().any(|n| n == name),
+            "column '{}' has already been declared",
+            name
+        );
+    }
+}
+
+// ASSERTION BUILDER
+// ================================================================================================
+/// Collects boundary assertions of a computation declared via
+/// [AirDefinition::get_assertions()].
+#[derive(Debug, Clone)]
+pub struct AssertionBuilder<B: StarkField> {
+    trace_length: usize,
+    original_trace_length: usize,
+    assertions: Vec<Assertion<B>>,
+    aux_assertions: Vec<Assertion<B>>,
+}
+
+impl<B: StarkField> AssertionBuilder<B> {
+    /// Returns a new builder for assertions against an execution trace described by the specified
+    /// trace info.
+    pub fn new(trace_info: &TraceInfo) -> Self {
+        Self {
+            trace_length: trace_info.length(),
+            original_trace_length: trace_info.original_length(),
+            assertions: Vec::new(),
+            aux_assertions: Vec::new(),
+        }
+    }
+
+    /// Returns length of the execution trace against which the assertions are made.
+    ///
+    /// If the trace was padded, this is the length of the trace after padding.
+    pub fn trace_length(&self) -> usize {
+        self.trace_length
+    }
+
+    /// Returns length of the execution trace against which the assertions are made before the
+    /// trace was padded.
+    ///
+    /// This is the same as [trace_length()](Self::trace_length) unless the trace was padded.
+    pub fn original_trace_length(&self) -> usize {
+        self.original_trace_length
+    }
+
+    /// Asserts that the value in the specified column at the first step is equal to `value`.
+    pub fn first(&mut self, column: Column, value: B) {
+        self.single(column, 0, value);
+    }
+
+    /// Asserts that the value in the specified column at the last step is equal to `value`.
+    ///
+    /// If the trace was padded, this is the last step of the original trace.
+    pub fn last(&mut self, column: Column, value: B) {
+        self.single(column, self.original_trace_length - 1, value);
+    }
+
+    /// Asserts that the value in the specified column at the specified step is equal to `value`.
+    pub fn single(&mut self, column: Column, step: usize, value: B) {
+        self.assertions
+            .push(Assertion::single(column.index(), step, value));
+    }
+
+    /// Asserts that values in the specified column at steps `first_step`,
+    /// `first_step + stride`, `first_step + 2 * stride` etc. are all equal to `value`.
+    ///
+    /// See [Assertion::periodic()] for restrictions on the parameters.
+    pub fn periodic(&mut self, column: Column, first_step: usize, stride: usize, value: B) {
+        self.assertions.push(Assertion::periodic(
+            column.index(),
+            first_step,
+            stride,
+            value,
+        ));
+    }
+
+    /// Asserts that values in the specified column at steps `first_step`,
+    /// `first_step + stride`, `first_step + 2 * stride` etc. are equal to the provided values.
+    ///
+    /// See [Assertion::sequence()] for restrictions on the parameters.
+    pub fn sequence(&mut self, column: Column, first_step: usize, stride: usize, values: Vec<B>) {
+        self.assertions.push(Assertion::sequence(
+            column.index(),
+            first_step,
+            stride,
+            values,
+        ));
+    }
+
+    /// Asserts that the value in the specified auxiliary column at the first step is equal to
+    /// `value`.
+    pub fn aux_first(&mut self, column: AuxColumn, value: B) {
+        self.aux_single(column, 0, value);
+    }
+
+    /// Asserts that the value in the specified auxiliary column at the last step is equal to
+    /// `value`.
+    ///
+    /// If the trace was padded, this is the last step of the original trace.
+    pub fn aux_last(&mut self, column: AuxColumn, value: B) {
+        self.aux_single(column, self.original_trace_length - 1, value);
+    }
+
+    /// Asserts that the value in the specified auxiliary column at the specified step is equal
+    /// to `value`.
+    pub fn aux_single(&mut self, column: AuxColumn, step: usize, value: B) {
+        self.aux_assertions
+            .push(Assertion::single(column.index(), step, value));
+    }
+
+    /// Returns assertions against the main trace segment collected so far.
+    pub fn assertions(&self) -> &[Assertion<B>] {
+        &self.assertions
+    }
+
+    /// Returns assertions against the auxiliary trace segment collected so far.
+    pub fn aux_assertions(&self) -> &[Assertion<B>] {
+        &self.aux_assertions
+    }
+}
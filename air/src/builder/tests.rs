@@ -0,0 +1,444 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{
+    AirBuilder, AirDefinition, AssertionBuilder, AuxColumn, Column, ConstraintGraph,
+    DeclarativeAir, Expr, Node, TraceBuilder,
+};
+use crate::{
+    Air, Assertion, AuxTraceRandElements, EvaluationFrame, FieldExtension, HashFunction,
+    ProofOptions, TraceInfo, TraceLayout, TransitionConstraintDegree,
+};
+use math::{fields::f128::BaseElement, FieldElement};
+use utils::{collections::Vec, string::ToString};
+
+// AIR DEFINITIONS
+// ================================================================================================
+
+/// Fibonacci sequence with two terms per row.
+struct Fibonacci;
+
+impl AirDefinition for Fibonacci {
+    type BaseField = BaseElement;
+    type PublicInputs = BaseElement;
+    type Columns = [Column; 2];
+
+    fn define(air: &mut AirBuilder<BaseElement>) -> [Column; 2] {
+        let [a, b] = air.columns(["a", "b"]);
+        air.enforce("a", a.next() - (a.current() + b.current()));
+        air.enforce("b", b.next() - (b.current() + a.next()));
+        [a, b]
+    }
+
+    fn get_assertions(
+        [a, b]: &[Column; 2],
+        result: &BaseElement,
+        assertions: &mut AssertionBuilder<BaseElement>,
+    ) {
+        assertions.first(*a, BaseElement::ONE);
+        assertions.first(*b, BaseElement::ONE);
+        assertions.last(*b, *result);
+    }
+}
+
+/// Computes x_{i+1} = x_i^3 + k_i * y_i and y_{i+1} = y_i + 1 for a periodic column k.
+struct Cubes;
+
+impl AirDefinition for Cubes {
+    type BaseField = BaseElement;
+    type PublicInputs = ();
+    type Columns = [Column; 2];
+
+    fn define(air: &mut AirBuilder<BaseElement>) -> [Column; 2] {
+        let [x, y] = air.columns(["x", "y"]);
+        let k = air.periodic_column("k", cube_keys());
+        air.enforce("x", x.next() - (x.current().exp(3) + k * y.current()));
+        air.enforce("y", y.next() - y.current() - BaseElement::ONE);
+        [x, y]
+    }
+
+    fn get_assertions(
+        [x, y]: &[Column; 2],
+        _pub_inputs: &(),
+        assertions: &mut AssertionBuilder<BaseElement>,
+    ) {
+        assertions.first(*x, BaseElement::ONE);
+        assertions.periodic(*y, 0, 8, BaseElement::ZERO);
+    }
+}
+
+/// Counter a_{i+1} = a_i + 1 with a running product p_{i+1} = p_i * (r + a_i) in the auxiliary
+/// segment, where r is a random element.
+struct RunningProduct;
+
+impl AirDefinition for RunningProduct {
+    type BaseField = BaseElement;
+    type PublicInputs = ();
+    type Columns = (Column, AuxColumn);
+
+    fn define(air: &mut AirBuilder<BaseElement>) -> (Column, AuxColumn) {
+        let a = air.column("a");
+        let p = air.aux_column("p");
+        let [r] = air.rand_elements();
+        air.enforce("p", p.next() - p.current() * (r + a.current()));
+        air.enforce("a", a.next() - a.current() - BaseElement::ONE);
+        (a, p)
+    }
+
+    fn get_assertions(
+        (a, p): &(Column, AuxColumn),
+        _pub_inputs: &(),
+        assertions: &mut AssertionBuilder<BaseElement>,
+    ) {
+        assertions.first(*a, BaseElement::ZERO);
+        assertions.aux_first(*p, BaseElement::ONE);
+    }
+}
+
+fn cube_keys() -> Vec<BaseElement> {
+    (0..8u8).map(BaseElement::from).collect()
+}
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn declarative_air_structure() {
+    let air = DeclarativeAir::<Fibonacci>::new(
+        TraceInfo::new(2, 16),
+        BaseElement::new(42),
+        build_options(),
+    );
+    assert_eq!(["a", "b"], air.column_names());
+    let names = air
+        .constraints()
+        .iter()
+        .map(|c| c.name())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["a", "b"], names);
+    assert_eq!(2, air.context().num_main_transition_constraints());
+    assert_eq!(
+        vec![
+            Assertion::single(0, 0, BaseElement::ONE),
+            Assertion::single(1, 0, BaseElement::ONE),
+            Assertion::single(1, 15, BaseElement::new(42)),
+        ],
+        air.get_assertions()
+    );
+    assert!(air.get_periodic_column_values().is_empty());
+
+    // for padded traces, the last step is the last step of the original trace
+    let air = DeclarativeAir::<Fibonacci>::new(
+        TraceInfo::new(2, 12),
+        BaseElement::new(42),
+        build_options(),
+    );
+    assert_eq!(16, air.trace_length());
+    assert_eq!(
+        Assertion::single(1, 11, BaseElement::new(42)),
+        air.get_assertions()[2]
+    );
+
+    let air = DeclarativeAir::<Cubes>::new(TraceInfo::new(2, 16), (), build_options());
+    assert_eq!(vec![cube_keys()], air.get_periodic_column_values());
+    assert_eq!(
+        vec![
+            Assertion::single(0, 0, BaseElement::ONE),
+            Assertion::periodic(1, 0, 8, BaseElement::ZERO),
+        ],
+        air.get_assertions()
+    );
+}
+
+#[test]
+fn constraint_degrees() {
+    let trace_length = 16;
+    let air = DeclarativeAir::<Cubes>::new(TraceInfo::new(2, trace_length), (), build_options());
+    let degrees = air
+        .constraints()
+        .iter()
+        .map(|c| c.expr().degree(trace_length))
+        .collect::<Vec<_>>();
+
+    // x^3 dominates k * y
+    assert_eq!(
+        vec![
+            TransitionConstraintDegree::new(3),
+            TransitionConstraintDegree::new(1)
+        ],
+        degrees
+    );
+
+    // the term with the periodic column dominates when its degree is higher
+    let mut builder = AirBuilder::<BaseElement>::new();
+    let [x, y] = builder.columns(["x", "y"]);
+    let k = builder.periodic_column("k", cube_keys());
+    let expr = x.current() * y.current() + k.clone() * x.next() * y.next();
+    assert_eq!(
+        TransitionConstraintDegree::with_cycles(2, vec![8]),
+        expr.degree(trace_length)
+    );
+    assert_eq!(
+        TransitionConstraintDegree::with_cycles(2, vec![8, 8]),
+        (k * x.current()).exp(2).degree(trace_length)
+    );
+}
+
+#[test]
+fn evaluate_transition() {
+    let builder = TraceBuilder::<Fibonacci>::new(8);
+    let [a, b] = *builder.columns();
+    let trace = builder.fill(
+        |row| {
+            row[a] = BaseElement::ONE;
+            row[b] = BaseElement::ONE;
+        },
+        |_, row| {
+            let a_next = row[a] + row[b];
+            row[a] = a_next;
+            row[b] += a_next;
+        },
+    );
+    assert_eq!(2, trace.len());
+    assert_eq!(8, trace[0].len());
+    assert_eq!(BaseElement::new(987), trace[1][7]);
+
+    let air = DeclarativeAir::<Fibonacci>::new(
+        TraceInfo::new(2, 8),
+        BaseElement::new(987),
+        build_options(),
+    );
+    let mut result = [BaseElement::ONE; 2];
+    for step in 0..7 {
+        let frame = EvaluationFrame::from_rows(
+            vec![trace[0][step], trace[1][step]],
+            vec![trace[0][step + 1], trace[1][step + 1]],
+        );
+        air.evaluate_transition(&frame, &[], &mut result);
+        assert_eq!([BaseElement::ZERO; 2], result);
+    }
+
+    // an invalid transition is detected
+    let frame = EvaluationFrame::from_rows(
+        vec![trace[0][0], trace[1][0]],
+        vec![trace[0][1], trace[1][1] + BaseElement::ONE],
+    );
+    air.evaluate_transition(&frame, &[], &mut result);
+    assert_eq!(BaseElement::ZERO, result[0]);
+    assert_ne!(BaseElement::ZERO, result[1]);
+}
+
+#[test]
+fn evaluate_transition_with_periodic_values() {
+    let air = DeclarativeAir::<Cubes>::new(TraceInfo::new(2, 16), (), build_options());
+    let x = BaseElement::new(5);
+    let y = BaseElement::new(3);
+    let k = BaseElement::new(7);
+    let frame =
+        EvaluationFrame::from_rows(vec![x, y], vec![x.exp(3) + k * y, y + BaseElement::ONE]);
+    let mut result = [BaseElement::ONE; 2];
+    air.evaluate_transition(&frame, &[k], &mut result);
+    assert_eq!([BaseElement::ZERO; 2], result);
+
+    air.evaluate_transition(&frame, &[k + BaseElement::ONE], &mut result);
+    assert_ne!(BaseElement::ZERO, result[0]);
+}
+
+#[test]
+#[should_panic(expected = "expected execution trace with 2 columns, but was 3")]
+fn declarative_air_wrong_trace_width() {
+    DeclarativeAir::<Fibonacci>::new(TraceInfo::new(3, 16), BaseElement::ONE, build_options());
+}
+
+#[test]
+#[should_panic(expected = "column 'a' has already been declared")]
+fn duplicate_column_name() {
+    let mut builder = AirBuilder::<BaseElement>::new();
+    builder.column("a");
+    builder.periodic_column("a", cube_keys());
+}
+
+#[test]
+#[should_panic(expected = "expression does not depend on any trace columns")]
+fn constant_constraint() {
+    let mut builder = AirBuilder::<BaseElement>::new();
+    let k = builder.periodic_column("k", cube_keys());
+    (k - BaseElement::ONE).degree(16);
+}
+
+#[test]
+fn declarative_air_with_aux_segment() {
+    let trace_info = TraceInfo::new_multi_segment(TraceLayout::new(1, [1], [1]), 16, vec![]);
+    let air = DeclarativeAir::<RunningProduct>::new(trace_info, (), build_options());
+    assert_eq!(["a"], air.column_names());
+    assert_eq!(["p"], air.aux_column_names());
+
+    // main constraints are evaluated before auxiliary constraints
+    let names = air
+        .constraints()
+        .iter()
+        .map(|c| c.name())
+        .collect::<Vec<_>>();
+    assert_eq!(vec!["a", "p"], names);
+    assert_eq!(1, air.context().num_main_transition_constraints());
+    assert_eq!(1, air.context().num_aux_transition_constraints());
+
+    let mut rand_elements = AuxTraceRandElements::new();
+    rand_elements.add_segment_elements(vec![BaseElement::new(11)]);
+    assert_eq!(
+        vec![Assertion::single(0, 0, BaseElement::ONE)],
+        air.get_aux_assertions(&rand_elements)
+    );
+
+    // evaluate constraints over a valid transition
+    let a = BaseElement::new(3);
+    let p = BaseElement::new(5);
+    let main_frame = EvaluationFrame::from_rows(vec![a], vec![a + BaseElement::ONE]);
+    let aux_frame = EvaluationFrame::from_rows(vec![p], vec![p * (BaseElement::new(11) + a)]);
+    let mut result = [BaseElement::ONE];
+    air.evaluate_transition(&main_frame, &[], &mut result);
+    assert_eq!([BaseElement::ZERO], result);
+    air.evaluate_aux_transition(&main_frame, &aux_frame, &[], &rand_elements, &mut result);
+    assert_eq!([BaseElement::ZERO], result);
+
+    // a different random element makes the transition invalid
+    let mut rand_elements = AuxTraceRandElements::new();
+    rand_elements.add_segment_elements(vec![BaseElement::new(12)]);
+    air.evaluate_aux_transition(&main_frame, &aux_frame, &[], &rand_elements, &mut result);
+    assert_ne!([BaseElement::ZERO], result);
+}
+
+#[test]
+#[should_panic(expected = "expected execution trace with a single auxiliary segment, but was 0")]
+fn declarative_air_missing_aux_segment() {
+    DeclarativeAir::<RunningProduct>::new(TraceInfo::new(1, 16), (), build_options());
+}
+
+#[test]
+fn graph_common_subexpressions() {
+    let mut builder = AirBuilder::<BaseElement>::new();
+    let [a, b] = builder.columns(["a", "b"]);
+
+    // a + b and b + a are mapped to the same node
+    let mut graph = ConstraintGraph::<BaseElement>::new();
+    let (na, nb) = (graph.insert(&a.current()), graph.insert(&b.current()));
+    let sum = graph.insert(&(a.current() + b.current()));
+    let c0 = graph.add_constraint(&((a.current() + b.current()) * a.next()));
+    let c1 = graph.add_constraint(&(a.next() * (b.current() + a.current())));
+    assert_eq!(c0, c1);
+    assert_eq!(Node::Add(na, nb), *graph.node(sum));
+
+    // a, b, a + b, a', (a + b) * a'
+    assert_eq!(5, graph.nodes().len());
+    assert_eq!(&[c0, c1], graph.constraints());
+}
+
+#[test]
+fn graph_simplifications() {
+    let mut builder = AirBuilder::<BaseElement>::new();
+    let a = builder.column("a");
+    let two = BaseElement::new(2);
+
+    let mut graph = ConstraintGraph::<BaseElement>::new();
+    let x = graph.insert(&a.current());
+    assert_eq!(x, graph.insert(&(a.current() * BaseElement::ONE)));
+    assert_eq!(x, graph.insert(&(a.current() + BaseElement::ZERO)));
+    assert_eq!(x, graph.insert(&a.current().exp(1)));
+    assert_eq!(x, graph.insert(&-(-a.current())));
+
+    let zero = graph.insert(&(a.current() - a.current()));
+    assert_eq!(Node::Constant(BaseElement::ZERO), *graph.node(zero));
+    let zero = graph.insert(&(a.current() * BaseElement::ZERO));
+    assert_eq!(Node::Constant(BaseElement::ZERO), *graph.node(zero));
+    let one = graph.insert(&a.current().exp(0));
+    assert_eq!(Node::Constant(BaseElement::ONE), *graph.node(one));
+
+    let expr = (Expr::from(two) + BaseElement::ONE).exp(3) * two;
+    let folded = graph.insert(&expr);
+    assert_eq!(Node::Constant(BaseElement::new(54)), *graph.node(folded));
+}
+
+#[test]
+fn graph_degrees() {
+    let mut builder = AirBuilder::<BaseElement>::new();
+    let [x, y] = builder.columns(["x", "y"]);
+    let k = builder.periodic_column("k", cube_keys());
+
+    let mut graph = ConstraintGraph::<BaseElement>::new();
+    graph.add_constraint(&(x.next() - x.current().exp(3)));
+    graph.add_constraint(&((x.current() + y.current()) * k.clone() - y.next()));
+    graph.add_constraint(&(x.current() * x.current() - x.current() * x.current() + y.current()));
+    assert_eq!(
+        vec![
+            TransitionConstraintDegree::new(3),
+            TransitionConstraintDegree::with_cycles(1, vec![8]),
+            TransitionConstraintDegree::new(1),
+        ],
+        graph.constraint_degrees(16)
+    );
+}
+
+#[test]
+fn graph_evaluation() {
+    let mut builder = AirBuilder::<BaseElement>::new();
+    let [x, y] = builder.columns(["x", "y"]);
+    let k = builder.periodic_column("k", cube_keys());
+
+    let mut graph = ConstraintGraph::<BaseElement>::new();
+    graph.add_constraint(&(x.next() - (x.current() + y.current()).exp(2) * k));
+    graph.add_constraint(&(-(x.current() + y.current()) + BaseElement::new(9)));
+
+    let (x0, y0, x1, k0) = (4u8, 5u8, 7u8, 3u8);
+    let frame = EvaluationFrame::from_rows(
+        vec![BaseElement::from(x0), BaseElement::from(y0)],
+        vec![BaseElement::from(x1), BaseElement::ZERO],
+    );
+    let mut result = [BaseElement::ZERO; 2];
+    graph.evaluate(&frame, &[BaseElement::from(k0)], &mut result);
+    assert_eq!(
+        [
+            BaseElement::from(x1) - BaseElement::from(81u8 * k0),
+            BaseElement::ZERO
+        ],
+        result
+    );
+}
+
+#[test]
+fn graph_display() {
+    let mut builder = AirBuilder::<BaseElement>::new();
+    let [a, b] = builder.columns(["a", "b"]);
+
+    let mut graph = ConstraintGraph::<BaseElement>::new();
+    graph.add_constraint(&(a.next() - (a.current() + b.current())));
+    graph.add_constraint(&(b.next() - (b.current() + a.next())));
+    let expected = "\
+        n0 = next[0]\n\
+        n1 = current[0]\n\
+        n2 = current[1]\n\
+        n3 = n1 + n2\n\
+        n4 = n0 - n3\n\
+        n5 = next[1]\n\
+        n6 = n0 + n2\n\
+        n7 = n5 - n6\n\
+        constraint 0 = n4\n\
+        constraint 1 = n7\n";
+    assert_eq!(expected, graph.to_string());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        32,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        256,
+    )
+}
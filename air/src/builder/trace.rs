@@ -0,0 +1,121 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{AirBuilder, AirDefinition, Column};
+use core::ops::{Index, IndexMut};
+use math::FieldElement;
+use utils::collections::Vec;
+
+// TRACE BUILDER
+// ================================================================================================
+/// Builds execution traces for computations described via [AirDefinition].
+///
+/// Values of a trace row can be accessed via handles to the columns declared by
+/// [AirDefinition::define()]; these handles are available via [TraceBuilder::columns()]. The
+/// columns built by [TraceBuilder::fill()] can be used to instantiate a trace table in the
+/// prover (e.g., via `TraceTable::init()`).
+pub struct TraceBuilder<D: AirDefinition> {
+    columns: D::Columns,
+    width: usize,
+    length: usize,
+}
+
+impl<D: AirDefinition> TraceBuilder<D> {
+    /// Returns a new builder for an execution trace with the specified number of rows.
+    ///
+    /// # Panics
+    /// Panics if `length` is smaller than two.
+    pub fn new(length: usize) -> Self {
+        assert!(
+            length >= 2,
+            "execution trace must contain at least two rows, but was {}",
+            length
+        );
+        let mut builder = AirBuilder::new();
+        let columns = D::define(&mut builder);
+        Self {
+            columns,
+            width: builder.column_names.len(),
+            length,
+        }
+    }
+
+    /// Returns handles to the trace columns declared by [AirDefinition::define()].
+    pub fn columns(&self) -> &D::Columns {
+        &self.columns
+    }
+
+    /// Returns the number of columns in the execution trace.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows in the execution trace.
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// Fills the execution trace and returns its columns.
+    ///
+    /// The `init` closure is used to initialize the first row of the trace; all values in the
+    /// row are set to ZERO before the closure is invoked. The `update` closure is then invoked
+    /// for each subsequent row with the index of the last completed row and the values of that
+    /// row; the closure is expected to update the row in place to get the values of the next
+    /// row. This is the same convention as used by `TraceTable::fill()` in the prover.
+    pub fn fill<I, U>(self, init: I, mut update: U) -> Vec<Vec<D::BaseField>>
+    where
+        I: FnOnce(&mut Row<D::BaseField>),
+        U: FnMut(usize, &mut Row<D::BaseField>),
+    {
+        let mut state = vec![D::BaseField::ZERO; self.width];
+        let mut result = (0..self.width)
+            .map(|_| Vec::with_capacity(self.length))
+            .collect::<Vec<_>>();
+
+        init(&mut Row(&mut state));
+        append_row(&mut result, &state);
+        for step in 0..self.length - 1 {
+            update(step, &mut Row(&mut state));
+            append_row(&mut result, &state);
+        }
+
+        result
+    }
+}
+
+// ROW
+// ================================================================================================
+/// A mutable row of an execution trace indexable by [Column] handles.
+pub struct Row<'a, E: FieldElement>(&'a mut [E]);
+
+impl<'a, E: FieldElement> Row<'a, E> {
+    /// Returns values of this row as a slice.
+    pub fn as_slice(&self) -> &[E] {
+        self.0
+    }
+}
+
+impl<'a, E: FieldElement> Index<Column> for Row<'a, E> {
+    type Output = E;
+
+    fn index(&self, column: Column) -> &E {
+        &self.0[column.index()]
+    }
+}
+
+impl<'a, E: FieldElement> IndexMut<Column> for Row<'a, E> {
+    fn index_mut(&mut self, column: Column) -> &mut E {
+        &mut self.0[column.index()]
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn append_row<E: FieldElement>(columns: &mut [Vec<E>], row: &[E]) {
+    for (column, &value) in columns.iter_mut().zip(row) {
+        column.push(value);
+    }
+}
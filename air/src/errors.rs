@@ -3,7 +3,9 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use crate::proof::ProofSection;
 use core::fmt;
+use utils::DeserializationError;
 
 // ASSERTION ERROR
 // ================================================================================================
@@ -43,3 +45,50 @@ impl fmt::Display for AssertionError {
         }
     }
 }
+
+// PROOF DESERIALIZATION ERROR
+// ================================================================================================
+/// Represents an error returned when a STARK proof could not be deserialized.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ProofDeserializationError {
+    /// This error occurs when a proof was serialized using a version of the proof format which
+    /// is not supported by this version of the library.
+    UnsupportedFormatVersion(u8),
+    /// This error occurs when the table describing sections of a serialized proof is malformed.
+    InvalidSectionTable(DeserializationError),
+    /// This error occurs when a section required to reconstruct a proof is not present in the
+    /// serialized proof.
+    MissingSection(ProofSection),
+    /// This error occurs when the same section appears in the serialized proof more than once.
+    DuplicateSection(ProofSection),
+    /// This error occurs when contents of a specific proof section could not be deserialized.
+    InvalidSection(ProofSection, DeserializationError),
+    /// This error occurs when deserialization has finished but not all bytes have been consumed.
+    UnconsumedBytes,
+}
+
+impl fmt::Display for ProofDeserializationError {
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedFormatVersion(version) => {
+                write!(f, "proof format version {} is not supported", version)
+            }
+            Self::InvalidSectionTable(err) => {
+                write!(f, "failed to read proof section table: {}", err)
+            }
+            Self::MissingSection(section) => {
+                write!(f, "proof does not contain {} section", section)
+            }
+            Self::DuplicateSection(section) => {
+                write!(f, "proof contains {} section more than once", section)
+            }
+            Self::InvalidSection(section, err) => {
+                write!(f, "failed to read {} section of the proof: {}", section, err)
+            }
+            Self::UnconsumedBytes => {
+                write!(f, "not all bytes of the proof were consumed")
+            }
+        }
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! AIR gadgets for binary values, bit decompositions, and bitwise operations.
+//!
+//! All constraints in this module are evaluated over values of a single row; it is up to the AIR
+//! using the gadgets to decide which columns of the `current` (or `next`) row hold the values.
+//! Bits of decomposed values are stored in little-endian order (i.e., the least significant bit
+//! comes first).
+
+use crate::TransitionConstraintDegree;
+use math::{FieldElement, StarkField};
+use utils::collections::Vec;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of bits in a u32 value.
+pub const NUM_U32_BITS: usize = 32;
+
+// BITWISE OPERATIONS
+// ================================================================================================
+
+/// Bitwise operations supported by [enforce_bitwise()].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitwiseOp {
+    /// Bitwise AND.
+    And,
+    /// Bitwise OR.
+    Or,
+    /// Bitwise XOR.
+    Xor,
+}
+
+impl BitwiseOp {
+    /// Applies this operation to the specified u32 values.
+    pub fn apply(&self, a: u32, b: u32) -> u32 {
+        match self {
+            Self::And => a & b,
+            Self::Or => a | b,
+            Self::Xor => a ^ b,
+        }
+    }
+
+    /// Applies this operation to the specified bits; the bits are assumed to be binary values.
+    pub fn apply_to_bits<E: FieldElement>(&self, a: E, b: E) -> E {
+        match self {
+            Self::And => a * b,
+            Self::Or => a + b - a * b,
+            Self::Xor => a + b - a * b.double(),
+        }
+    }
+}
+
+// TRACE GENERATION
+// ================================================================================================
+
+/// Writes the lower `target.len()` bits of the specified value into `target`.
+///
+/// # Panics
+/// Panics if `value` does not fit into `target.len()` bits.
+pub fn decompose<B: StarkField>(value: u64, target: &mut [B]) {
+    let num_bits = target.len();
+    assert!(
+        num_bits >= 64 || value >> num_bits == 0,
+        "value {} does not fit into {} bits",
+        value,
+        num_bits
+    );
+    for (i, bit) in target.iter_mut().enumerate() {
+        *bit = B::from((value >> i) & 1);
+    }
+}
+
+/// Returns bits of the specified u32 value as field elements.
+pub fn decompose_u32<B: StarkField>(value: u32) -> [B; NUM_U32_BITS] {
+    let mut result = [B::ZERO; NUM_U32_BITS];
+    decompose(value as u64, &mut result);
+    result
+}
+
+// CONSTRAINTS
+// ================================================================================================
+
+/// Returns zero only when `value` is either zero or one.
+pub fn is_binary<E: FieldElement>(value: E) -> E {
+    value * value - value
+}
+
+/// Enforces that all `values` are binary; one constraint is written into `result` per value.
+pub fn enforce_binary<E: FieldElement>(result: &mut [E], values: &[E]) {
+    for (r, &v) in result.iter_mut().zip(values) {
+        *r = is_binary(v);
+    }
+}
+
+/// Returns degrees of the constraints enforced by [enforce_binary()] for the specified number of
+/// values.
+pub fn binary_constraint_degrees(num_values: usize) -> Vec<TransitionConstraintDegree> {
+    vec![TransitionConstraintDegree::new(2); num_values]
+}
+
+/// Returns the value composed from the specified little-endian bits.
+pub fn compose<E: FieldElement>(bits: &[E]) -> E {
+    bits.iter()
+        .rev()
+        .fold(E::ZERO, |acc, &bit| acc.double() + bit)
+}
+
+/// Enforces that `bits` are a binary decomposition of `value`.
+///
+/// This writes `bits.len() + 1` constraints into `result`: the first `bits.len()` constraints
+/// make sure all bits are binary, and the last constraint makes sure the bits compose into the
+/// value. Since the bits are binary, this also acts as a range check of `value` against
+/// 2<sup>`bits.len()`</sup>, as long as the field modulus is greater than that.
+pub fn enforce_decomposition<E: FieldElement>(result: &mut [E], value: E, bits: &[E]) {
+    enforce_binary(result, bits);
+    result[bits.len()] = value - compose(bits);
+}
+
+/// Returns degrees of the constraints enforced by [enforce_decomposition()] for a decomposition
+/// into the specified number of bits.
+pub fn decomposition_constraint_degrees(num_bits: usize) -> Vec<TransitionConstraintDegree> {
+    let mut result = binary_constraint_degrees(num_bits);
+    result.push(TransitionConstraintDegree::new(1));
+    result
+}
+
+/// Enforces that `value` is the result of applying the bitwise operation to two values with
+/// bit decompositions `a_bits` and `b_bits`; writes one constraint into `result`.
+///
+/// The bits are assumed to be binary; this can be enforced via [enforce_decomposition()] or
+/// [enforce_binary()].
+pub fn enforce_bitwise<E: FieldElement>(
+    result: &mut [E],
+    op: BitwiseOp,
+    a_bits: &[E],
+    b_bits: &[E],
+    value: E,
+) {
+    debug_assert_eq!(a_bits.len(), b_bits.len());
+    let expected = a_bits
+        .iter()
+        .zip(b_bits)
+        .rev()
+        .fold(E::ZERO, |acc, (&a, &b)| {
+            acc.double() + op.apply_to_bits(a, b)
+        });
+    result[0] = value - expected;
+}
+
+/// Returns degrees of the constraints enforced by [enforce_bitwise()] for the specified operation.
+pub fn bitwise_constraint_degrees(_op: BitwiseOp) -> Vec<TransitionConstraintDegree> {
+    vec![TransitionConstraintDegree::new(2)]
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadgets::tests::check_constraint_degrees;
+    use math::fields::f128::BaseElement;
+
+    #[test]
+    fn decomposition() {
+        let value = 0xdead_beef_u32;
+        let bits = decompose_u32::<BaseElement>(value);
+        assert_eq!(BaseElement::from(value), compose(&bits));
+
+        let mut result = [BaseElement::ONE; NUM_U32_BITS + 1];
+        enforce_decomposition(&mut result, BaseElement::from(value), &bits);
+        assert!(result.iter().all(|&v| v == BaseElement::ZERO));
+
+        // a wrong value is detected
+        enforce_decomposition(&mut result, BaseElement::from(value - 1), &bits);
+        assert_ne!(BaseElement::ZERO, result[NUM_U32_BITS]);
+
+        // a non-binary bit is detected even if the bits compose into the value
+        let mut bits = decompose_u32::<BaseElement>(2);
+        bits[0] = BaseElement::from(2u8);
+        bits[1] = BaseElement::ZERO;
+        enforce_decomposition(&mut result, BaseElement::from(2u8), &bits);
+        assert_ne!(BaseElement::ZERO, result[0]);
+        assert_eq!(BaseElement::ZERO, result[NUM_U32_BITS]);
+    }
+
+    #[test]
+    #[should_panic(expected = "value 256 does not fit into 8 bits")]
+    fn decomposition_out_of_range() {
+        let mut bits = [BaseElement::ZERO; 8];
+        decompose(256, &mut bits);
+    }
+
+    #[test]
+    fn bitwise_ops() {
+        let (a, b) = (0xf0f0_1234_u32, 0x0ff0_4321_u32);
+        let a_bits = decompose_u32::<BaseElement>(a);
+        let b_bits = decompose_u32::<BaseElement>(b);
+        for op in [BitwiseOp::And, BitwiseOp::Or, BitwiseOp::Xor] {
+            let value = BaseElement::from(op.apply(a, b));
+            let mut result = [BaseElement::ONE];
+            enforce_bitwise(&mut result, op, &a_bits, &b_bits, value);
+            assert_eq!(BaseElement::ZERO, result[0]);
+
+            enforce_bitwise(&mut result, op, &a_bits, &b_bits, value + BaseElement::ONE);
+            assert_ne!(BaseElement::ZERO, result[0]);
+        }
+    }
+
+    #[test]
+    fn constraint_degrees() {
+        // columns: value, 8 bits of value, 8 bits of another value, and result of a bitwise op
+        let num_bits = 8;
+        let mut degrees = decomposition_constraint_degrees(num_bits);
+        degrees.append(&mut bitwise_constraint_degrees(BitwiseOp::Xor));
+        check_constraint_degrees::<BaseElement, _>(2 + 2 * num_bits, &degrees, |row, result| {
+            let (a_bits, b_bits) = row[1..].split_at(num_bits);
+            enforce_decomposition(result, row[0], a_bits);
+            let value = row[row.len() - 1];
+            enforce_bitwise(
+                &mut result[num_bits + 1..],
+                BitwiseOp::Xor,
+                a_bits,
+                &b_bits[..num_bits],
+                value,
+            );
+        });
+    }
+}
@@ -0,0 +1,18 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Reusable building blocks for describing computations in AIR.
+//!
+//! Each gadget provides functions for generating the relevant portion of an execution trace
+//! together with functions for evaluating transition constraints over it, as well as the
+//! supporting data (constraint degrees, periodic columns) needed to plug the gadget into an
+//! implementation of the [Air](crate::Air) trait.
+
+pub mod bits;
+pub mod rp64_256;
+pub mod uint32;
+
+#[cfg(test)]
+mod tests;
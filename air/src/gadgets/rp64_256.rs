@@ -0,0 +1,320 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! AIR gadget for the [Rp64_256] hash function.
+//!
+//! The gadget describes the Rescue Prime permutation used by [Rp64_256] over a trace segment of
+//! [STATE_WIDTH] columns. A single permutation occupies a cycle of [CYCLE_LENGTH] rows: the first
+//! row of a cycle contains the input state, each of the following [NUM_ROUNDS] rows contains the
+//! state after applying one round of the permutation, and thus, the last row of a cycle contains
+//! the output of the permutation.
+//!
+//! Round constraints must hold on all but the last step of every cycle. This is achieved by
+//! multiplying the constraints by a flag which is ONE on round steps and ZERO otherwise; such a
+//! flag is usually defined as a periodic column with values [CYCLE_MASK]. The transition from the
+//! last row of one cycle to the first row of the next cycle (e.g., absorbing new elements into
+//! the sponge) must be constrained by the AIR using the gadget.
+//!
+//! States built via [init_hash_state()] and [init_merge_state()] match the states used by
+//! [Rp64_256::hash_elements()](crypto::ElementHasher::hash_elements) and
+//! [Rp64_256::merge()](crypto::Hasher::merge) respectively; thus, digests read from the last
+//! row of a cycle via [get_digest()] are identical to the digests computed natively.
+
+use crate::TransitionConstraintDegree;
+use core::ops::Range;
+use crypto::{hashers::Rp64_256, Hasher};
+use math::{fields::f64::BaseElement, FieldElement};
+use utils::collections::Vec;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of field elements in the hasher state; this is also the number of trace columns used
+/// by the gadget.
+pub const STATE_WIDTH: usize = Rp64_256::STATE_WIDTH;
+
+/// Number of rounds in a single permutation.
+pub const NUM_ROUNDS: usize = Rp64_256::NUM_ROUNDS;
+
+/// Number of rows needed to describe a single permutation.
+pub const CYCLE_LENGTH: usize = 8;
+
+/// Number of transition constraints enforced by the gadget.
+pub const NUM_CONSTRAINTS: usize = STATE_WIDTH;
+
+/// Number of periodic columns holding round constants.
+pub const NUM_ROUND_CONSTANTS: usize = 2 * STATE_WIDTH;
+
+/// Capacity portion of the hasher state.
+pub const CAPACITY_RANGE: Range<usize> = Rp64_256::CAPACITY_RANGE;
+
+/// Rate portion of the hasher state.
+pub const RATE_RANGE: Range<usize> = Rp64_256::RATE_RANGE;
+
+/// Portion of the hasher state which contains the digest after a permutation is applied.
+pub const DIGEST_RANGE: Range<usize> = Rp64_256::DIGEST_RANGE;
+
+/// Values of a flag which is ONE on the steps of a cycle at which round constraints must hold.
+pub const CYCLE_MASK: [BaseElement; CYCLE_LENGTH] = [
+    BaseElement::ONE,
+    BaseElement::ONE,
+    BaseElement::ONE,
+    BaseElement::ONE,
+    BaseElement::ONE,
+    BaseElement::ONE,
+    BaseElement::ONE,
+    BaseElement::ZERO,
+];
+
+/// S-Box power.
+const ALPHA: u32 = 7;
+
+// TRACE GENERATION
+// ================================================================================================
+
+/// Returns the initial hasher state for hashing the specified elements with a single permutation.
+///
+/// # Panics
+/// Panics if the number of elements is zero or greater than the rate width (8).
+pub fn init_hash_state(elements: &[BaseElement]) -> [BaseElement; STATE_WIDTH] {
+    assert!(
+        !elements.is_empty() && elements.len() <= RATE_RANGE.len(),
+        "number of elements must be between 1 and {}, but was {}",
+        RATE_RANGE.len(),
+        elements.len()
+    );
+    let mut state = [BaseElement::ZERO; STATE_WIDTH];
+    state[CAPACITY_RANGE.start] = BaseElement::new(elements.len() as u64);
+    state[RATE_RANGE.start..RATE_RANGE.start + elements.len()].copy_from_slice(elements);
+    state
+}
+
+/// Returns the initial hasher state for merging the specified digests.
+pub fn init_merge_state(values: &[<Rp64_256 as Hasher>::Digest; 2]) -> [BaseElement; STATE_WIDTH] {
+    let mut state = [BaseElement::ZERO; STATE_WIDTH];
+    state[CAPACITY_RANGE.start] = BaseElement::new(RATE_RANGE.len() as u64);
+    state[RATE_RANGE].copy_from_slice(<Rp64_256 as Hasher>::Digest::digests_as_elements(values));
+    state
+}
+
+/// Returns the digest contained in the specified hasher state.
+pub fn get_digest(state: &[BaseElement]) -> <Rp64_256 as Hasher>::Digest {
+    let mut digest = [BaseElement::ZERO; 4];
+    digest.copy_from_slice(&state[DIGEST_RANGE]);
+    <Rp64_256 as Hasher>::Digest::new(digest)
+}
+
+/// Applies a single round of the permutation to the specified state, where the round is
+/// determined by the position of the `step` within a cycle.
+///
+/// This function is intended to be invoked when filling an execution trace row by row.
+///
+/// # Panics
+/// Panics if:
+/// * Length of `state` is not [STATE_WIDTH].
+/// * The `step` is the last step of a cycle (there is no round to apply at this step).
+pub fn apply_round(state: &mut [BaseElement], step: usize) {
+    let round = step % CYCLE_LENGTH;
+    assert!(
+        round < NUM_ROUNDS,
+        "no permutation round is applied at step {}",
+        step
+    );
+    let state: &mut [BaseElement; STATE_WIDTH] =
+        state.try_into().expect("invalid hasher state width");
+    Rp64_256::apply_round(state, round);
+}
+
+// CONSTRAINTS
+// ================================================================================================
+
+/// Returns degrees of the transition constraints enforced by [enforce_round()], assuming that the
+/// `flag` passed to it is a periodic column with cycle length [CYCLE_LENGTH].
+pub fn get_constraint_degrees() -> Vec<TransitionConstraintDegree> {
+    (0..NUM_CONSTRAINTS)
+        .map(|_| TransitionConstraintDegree::with_cycles(ALPHA as usize, vec![CYCLE_LENGTH]))
+        .collect()
+}
+
+/// When `flag` = 1, enforces constraints for a single round of the permutation; results are
+/// added to the first [NUM_CONSTRAINTS] values of `result`.
+///
+/// The `ark` slice must contain values of the periodic columns returned by
+/// [get_round_constants()] at the current step.
+pub fn enforce_round<E: FieldElement + From<BaseElement>>(
+    result: &mut [E],
+    current: &[E],
+    next: &[E],
+    ark: &[E],
+    flag: E,
+) {
+    debug_assert_eq!(STATE_WIDTH, current.len());
+    debug_assert_eq!(STATE_WIDTH, next.len());
+    debug_assert_eq!(NUM_ROUND_CONSTANTS, ark.len());
+
+    // compute the state that should result from applying the first half of the round to the
+    // current state of the computation
+    let mut step1 = [E::ZERO; STATE_WIDTH];
+    step1.copy_from_slice(current);
+    apply_sbox(&mut step1);
+    apply_matrix(&mut step1, &Rp64_256::MDS);
+    for (s, &k) in step1.iter_mut().zip(&ark[..STATE_WIDTH]) {
+        *s += k;
+    }
+
+    // compute the state that should result from applying the inverse of the second half of the
+    // round to the next state of the computation
+    let mut step2 = [E::ZERO; STATE_WIDTH];
+    step2.copy_from_slice(next);
+    for (s, &k) in step2.iter_mut().zip(&ark[STATE_WIDTH..]) {
+        *s -= k;
+    }
+    apply_matrix(&mut step2, &Rp64_256::INV_MDS);
+    apply_sbox(&mut step2);
+
+    // make sure that the results are equal
+    for ((r, &s1), &s2) in result.iter_mut().zip(step1.iter()).zip(step2.iter()) {
+        *r += flag * (s2 - s1);
+    }
+}
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// Returns round constants of the permutation arranged in [NUM_ROUND_CONSTANTS] periodic columns
+/// of length [CYCLE_LENGTH].
+///
+/// The first [STATE_WIDTH] columns contain constants added in the first half of a round, and the
+/// remaining columns contain constants added in the second half of a round. Values at the last
+/// step of every cycle are zeros.
+pub fn get_round_constants() -> Vec<Vec<BaseElement>> {
+    let mut constants = vec![vec![BaseElement::ZERO; CYCLE_LENGTH]; NUM_ROUND_CONSTANTS];
+    #[allow(clippy::needless_range_loop)]
+    for round in 0..NUM_ROUNDS {
+        for i in 0..STATE_WIDTH {
+            constants[i][round] = Rp64_256::ARK1[round][i];
+            constants[STATE_WIDTH + i][round] = Rp64_256::ARK2[round][i];
+        }
+    }
+    constants
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+#[inline(always)]
+fn apply_sbox<E: FieldElement>(state: &mut [E; STATE_WIDTH]) {
+    for s in state.iter_mut() {
+        *s = s.exp(ALPHA.into());
+    }
+}
+
+#[inline(always)]
+fn apply_matrix<E: FieldElement + From<BaseElement>>(
+    state: &mut [E; STATE_WIDTH],
+    matrix: &[[BaseElement; STATE_WIDTH]; STATE_WIDTH],
+) {
+    let mut result = [E::ZERO; STATE_WIDTH];
+    for (r, row) in result.iter_mut().zip(matrix.iter()) {
+        for (&m, &s) in row.iter().zip(state.iter()) {
+            *r += E::from(m) * s;
+        }
+    }
+    *state = result;
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crypto::ElementHasher;
+    use math::fields::QuadExtension;
+
+    #[test]
+    fn permutation_trace_matches_native_hash() {
+        let elements = (1..=8).map(BaseElement::new).collect::<Vec<_>>();
+        for num_elements in 1..=8 {
+            let trace = build_permutation_trace(init_hash_state(&elements[..num_elements]));
+            let expected = Rp64_256::hash_elements(&elements[..num_elements]);
+            assert_eq!(expected, get_digest(&trace[CYCLE_LENGTH - 1]));
+        }
+
+        let values = [
+            Rp64_256::hash_elements(&elements[..4]),
+            Rp64_256::hash_elements(&elements[4..]),
+        ];
+        let trace = build_permutation_trace(init_merge_state(&values));
+        assert_eq!(
+            Rp64_256::merge(&values),
+            get_digest(&trace[CYCLE_LENGTH - 1])
+        );
+    }
+
+    #[test]
+    fn round_constraints_are_satisfied() {
+        let elements = (10..15).map(BaseElement::new).collect::<Vec<_>>();
+        let trace = build_permutation_trace(init_hash_state(&elements));
+        let ark = get_round_constants();
+
+        for step in 0..CYCLE_LENGTH - 1 {
+            let ark = ark.iter().map(|column| column[step]).collect::<Vec<_>>();
+            let mut result = [BaseElement::ZERO; NUM_CONSTRAINTS];
+            enforce_round(
+                &mut result,
+                &trace[step],
+                &trace[step + 1],
+                &ark,
+                CYCLE_MASK[step],
+            );
+            assert!(result.iter().all(|&v| v == BaseElement::ZERO));
+
+            // tampering with the next state breaks the constraints
+            let mut next = trace[step + 1];
+            next[step] += BaseElement::ONE;
+            enforce_round(&mut result, &trace[step], &next, &ark, CYCLE_MASK[step]);
+            assert!(result.iter().any(|&v| v != BaseElement::ZERO));
+
+            // constraints can be evaluated over the extension field
+            let current = trace[step].map(QuadExtension::<BaseElement>::from);
+            let next = trace[step + 1].map(QuadExtension::<BaseElement>::from);
+            let ark = ark.into_iter().map(QuadExtension::from).collect::<Vec<_>>();
+            let mut result = [QuadExtension::ZERO; NUM_CONSTRAINTS];
+            enforce_round(&mut result, &current, &next, &ark, QuadExtension::ONE);
+            assert!(result.iter().all(|&v| v == QuadExtension::ZERO));
+        }
+
+        // the constraints are disabled on the last step of a cycle
+        let ark = ark
+            .iter()
+            .map(|column| column[CYCLE_LENGTH - 1])
+            .collect::<Vec<_>>();
+        let mut result = [BaseElement::ZERO; NUM_CONSTRAINTS];
+        let next = init_hash_state(&elements[..1]);
+        let flag = CYCLE_MASK[CYCLE_LENGTH - 1];
+        enforce_round(&mut result, &trace[CYCLE_LENGTH - 1], &next, &ark, flag);
+        assert!(result.iter().all(|&v| v == BaseElement::ZERO));
+    }
+
+    #[test]
+    #[should_panic(expected = "no permutation round is applied at step 15")]
+    fn apply_round_at_last_step_of_cycle() {
+        let mut state = init_hash_state(&[BaseElement::ONE]);
+        apply_round(&mut state, 2 * CYCLE_LENGTH - 1);
+    }
+
+    fn build_permutation_trace(
+        init_state: [BaseElement; STATE_WIDTH],
+    ) -> Vec<[BaseElement; STATE_WIDTH]> {
+        let mut trace = vec![init_state];
+        for step in 0..NUM_ROUNDS {
+            let mut state = trace[step];
+            apply_round(&mut state, step);
+            trace.push(state);
+        }
+        trace
+    }
+}
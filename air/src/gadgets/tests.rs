@@ -0,0 +1,64 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use crate::TransitionConstraintDegree;
+use math::{get_power_series, log2, polynom, StarkField};
+use rand_utils::rand_vector;
+use utils::collections::Vec;
+
+/// Number of rows in traces used for checking constraint degrees.
+const TRACE_LENGTH: usize = 8;
+
+/// Checks that the degrees of row-local constraints computed by the `evaluate` closure over a
+/// random trace of the specified width are equal to the declared `degrees`.
+pub fn check_constraint_degrees<B, F>(
+    trace_width: usize,
+    degrees: &[TransitionConstraintDegree],
+    evaluate: F,
+) where
+    B: StarkField,
+    F: Fn(&[B], &mut [B]),
+{
+    // interpolate random trace columns over the trace domain
+    let g = B::get_root_of_unity(log2(TRACE_LENGTH));
+    let trace_domain = get_power_series(g, TRACE_LENGTH);
+    let polys = (0..trace_width)
+        .map(|_| polynom::interpolate(&trace_domain, &rand_vector::<B>(TRACE_LENGTH), false))
+        .collect::<Vec<_>>();
+
+    // evaluate constraints over a domain large enough to interpolate constraint polynomials
+    let max_degree = degrees
+        .iter()
+        .map(|d| d.get_evaluation_degree(TRACE_LENGTH))
+        .max()
+        .unwrap();
+    let domain_size = (max_degree + 1).next_power_of_two();
+    let g = B::get_root_of_unity(log2(domain_size));
+    let domain = get_power_series(g, domain_size);
+
+    let mut evaluations = vec![Vec::with_capacity(domain_size); degrees.len()];
+    let mut row = vec![B::ZERO; trace_width];
+    let mut result = vec![B::ZERO; degrees.len()];
+    for &x in domain.iter() {
+        for (value, poly) in row.iter_mut().zip(polys.iter()) {
+            *value = polynom::eval(poly, x);
+        }
+        result.fill(B::ZERO);
+        evaluate(&row, &mut result);
+        for (column, &value) in evaluations.iter_mut().zip(result.iter()) {
+            column.push(value);
+        }
+    }
+
+    for (i, (column, degree)) in evaluations.iter().zip(degrees).enumerate() {
+        let poly = polynom::interpolate(&domain, column, true);
+        assert_eq!(
+            degree.get_evaluation_degree(TRACE_LENGTH),
+            polynom::degree_of(&poly),
+            "unexpected degree of constraint {}",
+            i
+        );
+    }
+}
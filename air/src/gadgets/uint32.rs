@@ -0,0 +1,277 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! AIR gadgets for u32 arithmetic.
+//!
+//! Values are represented by single field elements, and thus, the gadgets require the modulus of
+//! the field to be greater than 2<sup>64</sup> - 2<sup>33</sup> + 1 (i.e., the largest product
+//! of two u32 values); this holds for the 64-bit and 128-bit fields, but not for the 62-bit field.
+//!
+//! In the 64-bit field, `lo` + 2<sup>32</sup> * `hi` can exceed the modulus for u32 values `lo`
+//! and `hi`, and thus, the decomposition of a product into `lo` and `hi` is not unique: e.g.,
+//! 2<sup>32</sup> * (2<sup>32</sup> - 1) = -1 in this field, and so `lo` = `a` * `b` + 1 and
+//! `hi` = 2<sup>32</sup> - 1 would satisfy `lo` + 2<sup>32</sup> * `hi` = `a` * `b`. This is
+//! prevented by [enforce_mul()], which requires `hi` to be different from 2<sup>32</sup> - 1
+//! (the upper half of a product of two u32 values is at most 2<sup>32</sup> - 2).
+//!
+//! Constraints in this module do not range check any of the values. For the constraints to be
+//! sound, all values marked as "range checked" below must be constrained to be u32 values by the
+//! AIR using the gadgets, e.g., via [bits::enforce_decomposition()](super::bits) or via
+//! [enforce_limbs()] combined with range checks of the limbs.
+
+use super::bits::is_binary;
+use crate::TransitionConstraintDegree;
+use math::{FieldElement, StarkField};
+use utils::collections::Vec;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of bits in a 16-bit limb of a u32 value.
+pub const LIMB_BITS: usize = 16;
+
+// TRACE GENERATION
+// ================================================================================================
+
+/// Returns the wrapping sum of `a` and `b` together with the carry bit as `[sum, carry]`.
+pub fn build_add<B: StarkField>(a: u32, b: u32) -> [B; 2] {
+    let (sum, carry) = a.overflowing_add(b);
+    [B::from(sum), B::from(carry as u8)]
+}
+
+/// Returns the product of `a` and `b` split into the lower and the upper 32 bits together with
+/// the inverse of `hi` - (2<sup>32</sup> - 1) as `[lo, hi, hi_inv]`.
+pub fn build_mul<B: StarkField>(a: u32, b: u32) -> [B; 3] {
+    let product = a as u64 * b as u64;
+    let hi = B::from((product >> 32) as u32);
+    // the upper half of a product of two u32 values is never equal to u32::MAX
+    let hi_inv = (hi - B::from(u32::MAX)).inv();
+    [B::from(product as u32), hi, hi_inv]
+}
+
+/// Returns values needed to prove the comparison of `a` and `b` as `[diff, lt]`, where `lt` is
+/// ONE if `a < b` and ZERO otherwise, and `diff` is the wrapping difference `a - b`.
+pub fn build_lt<B: StarkField>(a: u32, b: u32) -> [B; 2] {
+    let (diff, borrow) = a.overflowing_sub(b);
+    [B::from(diff), B::from(borrow as u8)]
+}
+
+/// Returns the lower and the upper 16-bit limbs of the specified value as `[lo, hi]`.
+pub fn build_limbs<B: StarkField>(value: u32) -> [B; 2] {
+    [B::from(value as u16), B::from((value >> LIMB_BITS) as u16)]
+}
+
+// CONSTRAINTS
+// ================================================================================================
+
+/// Enforces that `sum` + 2<sup>32</sup> * `carry` = `a` + `b`; writes two constraints into
+/// `result`.
+///
+/// Range checked values: `sum` (`a` and `b` are assumed to be u32 values).
+pub fn enforce_add<E: FieldElement>(result: &mut [E], a: E, b: E, sum: E, carry: E) {
+    result[0] = a + b - sum - carry * two_pow_32();
+    result[1] = is_binary(carry);
+}
+
+/// Returns degrees of the constraints enforced by [enforce_add()].
+pub fn add_constraint_degrees() -> Vec<TransitionConstraintDegree> {
+    vec![
+        TransitionConstraintDegree::new(1),
+        TransitionConstraintDegree::new(2),
+    ]
+}
+
+/// Enforces that `lo` + 2<sup>32</sup> * `hi` = `a` * `b`; writes two constraints into `result`.
+///
+/// The second constraint enforces that `hi` is not equal to 2<sup>32</sup> - 1 by requiring
+/// `hi_inv` to be the inverse of `hi` - (2<sup>32</sup> - 1). Without it, the decomposition of
+/// the product into `lo` and `hi` would not be unique in the 64-bit field (see the
+/// [module documentation](self)).
+///
+/// Range checked values: `lo` and `hi` (`a` and `b` are assumed to be u32 values).
+pub fn enforce_mul<E: FieldElement>(result: &mut [E], a: E, b: E, lo: E, hi: E, hi_inv: E) {
+    result[0] = a * b - lo - hi * two_pow_32();
+    result[1] = (hi - E::from(u32::MAX)) * hi_inv - E::ONE;
+}
+
+/// Returns degrees of the constraints enforced by [enforce_mul()].
+pub fn mul_constraint_degrees() -> Vec<TransitionConstraintDegree> {
+    vec![
+        TransitionConstraintDegree::new(2),
+        TransitionConstraintDegree::new(2),
+    ]
+}
+
+/// Enforces that `lt` is ONE when `a` < `b` and ZERO otherwise; writes two constraints into
+/// `result`.
+///
+/// This is done by enforcing `a` - `b` = `diff` - 2<sup>32</sup> * `lt` for a binary `lt`.
+///
+/// Range checked values: `diff` (`a` and `b` are assumed to be u32 values).
+pub fn enforce_lt<E: FieldElement>(result: &mut [E], a: E, b: E, diff: E, lt: E) {
+    result[0] = a - b - diff + lt * two_pow_32();
+    result[1] = is_binary(lt);
+}
+
+/// Returns degrees of the constraints enforced by [enforce_lt()].
+pub fn lt_constraint_degrees() -> Vec<TransitionConstraintDegree> {
+    vec![
+        TransitionConstraintDegree::new(1),
+        TransitionConstraintDegree::new(2),
+    ]
+}
+
+/// Enforces that `value` = `lo` + 2<sup>16</sup> * `hi`; writes one constraint into `result`.
+///
+/// If both limbs are range checked to be 16-bit values, this is also a range check of `value`.
+pub fn enforce_limbs<E: FieldElement>(result: &mut [E], value: E, lo: E, hi: E) {
+    result[0] = value - lo - hi * E::from(1u32 << LIMB_BITS);
+}
+
+/// Returns degrees of the constraints enforced by [enforce_limbs()].
+pub fn limbs_constraint_degrees() -> Vec<TransitionConstraintDegree> {
+    vec![TransitionConstraintDegree::new(1)]
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+#[inline(always)]
+fn two_pow_32<E: FieldElement>() -> E {
+    E::from(1u64 << 32)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadgets::tests::check_constraint_degrees;
+    use math::fields::{f128, f64::BaseElement};
+
+    const TEST_VALUES: [(u32, u32); 5] = [
+        (0, 0),
+        (1, u32::MAX),
+        (u32::MAX, u32::MAX),
+        (0x1234_5678, 0x8765_4321),
+        (7, 3),
+    ];
+
+    #[test]
+    fn add() {
+        for (a, b) in TEST_VALUES {
+            let [sum, carry] = build_add::<BaseElement>(a, b);
+            let mut result = [BaseElement::ONE; 2];
+            enforce_add(&mut result, a.into(), b.into(), sum, carry);
+            assert_eq!([BaseElement::ZERO; 2], result);
+
+            enforce_add(
+                &mut result,
+                a.into(),
+                b.into(),
+                sum + BaseElement::ONE,
+                carry,
+            );
+            assert_ne!(BaseElement::ZERO, result[0]);
+        }
+    }
+
+    #[test]
+    fn mul() {
+        for (a, b) in TEST_VALUES {
+            let [lo, hi, hi_inv] = build_mul::<BaseElement>(a, b);
+            let mut result = [BaseElement::ONE; 2];
+            enforce_mul(&mut result, a.into(), b.into(), lo, hi, hi_inv);
+            assert_eq!([BaseElement::ZERO; 2], result);
+
+            enforce_mul(
+                &mut result,
+                a.into(),
+                b.into(),
+                lo,
+                hi + BaseElement::ONE,
+                hi_inv,
+            );
+            assert_ne!(BaseElement::ZERO, result[0]);
+        }
+    }
+
+    #[test]
+    fn mul_non_canonical_product() {
+        // 2^32 * (2^32 - 1) = -1 in the 64-bit field, and thus, 0 * 0 could be decomposed into
+        // lo = 1 and hi = 2^32 - 1, which are both u32 values
+        let lo = BaseElement::ONE;
+        let hi = BaseElement::from(u32::MAX);
+        let mut result = [BaseElement::ONE; 2];
+        enforce_mul(
+            &mut result,
+            0u32.into(),
+            0u32.into(),
+            lo,
+            hi,
+            BaseElement::ZERO,
+        );
+        assert_eq!(BaseElement::ZERO, result[0]);
+
+        // but no value of hi_inv satisfies the second constraint for such hi
+        for hi_inv in [BaseElement::ZERO, BaseElement::ONE, -BaseElement::ONE] {
+            enforce_mul(&mut result, 0u32.into(), 0u32.into(), lo, hi, hi_inv);
+            assert_eq!(-BaseElement::ONE, result[1]);
+        }
+    }
+
+    #[test]
+    fn lt() {
+        for (a, b) in TEST_VALUES {
+            for (a, b) in [(a, b), (b, a)] {
+                let [diff, lt] = build_lt::<BaseElement>(a, b);
+                assert_eq!(BaseElement::from((a < b) as u8), lt);
+                let mut result = [BaseElement::ONE; 2];
+                enforce_lt(&mut result, a.into(), b.into(), diff, lt);
+                assert_eq!([BaseElement::ZERO; 2], result);
+            }
+        }
+
+        // claiming the wrong result requires a difference which is not a u32 value
+        let mut result = [BaseElement::ONE; 2];
+        let diff = BaseElement::from(3u8) - BaseElement::from(1u64 << 32);
+        enforce_lt(
+            &mut result,
+            3u32.into(),
+            7u32.into(),
+            diff,
+            BaseElement::ZERO,
+        );
+        assert_ne!(BaseElement::ZERO, result[0]);
+    }
+
+    #[test]
+    fn limbs() {
+        let value = 0xabcd_1234_u32;
+        let [lo, hi] = build_limbs::<BaseElement>(value);
+        assert_eq!(BaseElement::from(0x1234u16), lo);
+        assert_eq!(BaseElement::from(0xabcdu16), hi);
+
+        let mut result = [BaseElement::ONE];
+        enforce_limbs(&mut result, value.into(), lo, hi);
+        assert_eq!(BaseElement::ZERO, result[0]);
+    }
+
+    #[test]
+    fn constraint_degrees() {
+        // columns: a, b, sum, carry, lo, hi, hi_inv, diff, lt
+        let mut degrees = add_constraint_degrees();
+        degrees.append(&mut mul_constraint_degrees());
+        degrees.append(&mut lt_constraint_degrees());
+        degrees.append(&mut limbs_constraint_degrees());
+        check_constraint_degrees::<f128::BaseElement, _>(9, &degrees, |row, result| {
+            enforce_add(result, row[0], row[1], row[2], row[3]);
+            enforce_mul(&mut result[2..], row[0], row[1], row[4], row[5], row[6]);
+            enforce_lt(&mut result[4..], row[0], row[1], row[7], row[8]);
+            enforce_limbs(&mut result[6..], row[0], row[4], row[5]);
+        });
+    }
+}
@@ -33,18 +33,21 @@
 #[macro_use]
 extern crate alloc;
 
+pub mod builder;
+pub mod gadgets;
 pub mod proof;
 
 mod errors;
-pub use errors::AssertionError;
+pub use errors::{AssertionError, ProofDeserializationError};
 
 mod options;
 pub use options::{FieldExtension, HashFunction, ProofOptions};
 
 mod air;
 pub use air::{
-    Air, AirContext, Assertion, AuxTraceRandElements, BoundaryConstraint, BoundaryConstraintGroup,
-    BoundaryConstraints, ConstraintCompositionCoefficients, ConstraintDivisor,
-    DeepCompositionCoefficients, EvaluationFrame, TraceInfo, TraceLayout,
-    TransitionConstraintDegree, TransitionConstraintGroup, TransitionConstraints,
+    write_air_id, Air, AirContext, Assertion, AuxTraceRandElements, BoundaryConstraint,
+    BoundaryConstraintGroup, BoundaryConstraints, ConstraintCompositionCoefficients,
+    ConstraintDivisor, DeepCompositionCoefficients, EvaluationFrame, SegmentPublicInputs,
+    TraceInfo, TraceLayout, TracePadding, TransitionConstraintDegree, TransitionConstraintGroup,
+    TransitionConstraints, TransitionDivisor,
 };
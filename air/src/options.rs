@@ -5,7 +5,13 @@
 
 use fri::FriOptions;
 use math::StarkField;
-use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use utils::{
+    string::{String, ToString},
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 // TYPES AND INTERFACES
 // ================================================================================================
@@ -17,6 +23,7 @@ use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serial
 /// function used by the protocol.
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum HashFunction {
     /// BLAKE3 hash function with 192 bit output.
     ///
@@ -32,6 +39,17 @@ pub enum HashFunction {
     ///
     /// When this function is used in the STARK protocol, proof security cannot exceed 128 bits.
     Sha3_256 = 3,
+
+    /// Poseidon hash function over the 64-bit field with 256 bit output.
+    ///
+    /// This function can be used only with base fields with modulus 2^64 - 2^32 + 1. When this
+    /// function is used in the STARK protocol, proof security cannot exceed 128 bits.
+    Poseidon64_256 = 4,
+
+    /// Keccak hash function with 256 bit output (as used by Ethereum).
+    ///
+    /// When this function is used in the STARK protocol, proof security cannot exceed 128 bits.
+    Keccak256 = 5,
 }
 
 /// Defines an extension field for the composition polynomial.
@@ -49,6 +67,7 @@ pub enum HashFunction {
 /// as much as 50%.
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FieldExtension {
     /// Composition polynomial is constructed in the base field.
     None = 1,
@@ -79,6 +98,8 @@ pub enum FieldExtension {
 ///    generation time. More precisely, proof soundness is bounded by
 ///    `num_queries * log2(blowup_factor) + grinding_factor`.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawProofOptions"))]
 pub struct ProofOptions {
     num_queries: u8,
     blowup_factor: u8,
@@ -89,6 +110,20 @@ pub struct ProofOptions {
     fri_max_remainder_size: u8, // stored as power of 2
 }
 
+/// Unvalidated [ProofOptions] used when deserializing via serde; the options are validated when
+/// they are converted into [ProofOptions].
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawProofOptions {
+    num_queries: u8,
+    blowup_factor: u8,
+    grinding_factor: u8,
+    hash_fn: HashFunction,
+    field_extension: FieldExtension,
+    fri_folding_factor: u8,
+    fri_max_remainder_size: u8,
+}
+
 // PROOF OPTIONS IMPLEMENTATION
 // ================================================================================================
 impl ProofOptions {
@@ -113,7 +148,6 @@ impl ProofOptions {
     /// * `grinding_factor` is greater than 32.
     /// * `fri_folding_factor` is not 4, 8, or 16.
     /// * `fri_max_remainder_size` is smaller than 32, greater than 1024, or is not a power of two.
-    #[rustfmt::skip]
     pub fn new(
         num_queries: usize,
         blowup_factor: usize,
@@ -124,23 +158,15 @@ impl ProofOptions {
         fri_max_remainder_size: usize,
     ) -> ProofOptions {
         // TODO: return errors instead of panicking
-        assert!(num_queries > 0, "number of queries must be greater than 0");
-        assert!(num_queries <= 128, "number of queries cannot be greater than 128");
-
-        assert!(blowup_factor.is_power_of_two(), "blowup factor must be a power of 2");
-        assert!(blowup_factor >= Self::MIN_BLOWUP_FACTOR,
-            "blowup factor cannot be smaller than {}", Self::MIN_BLOWUP_FACTOR);
-        assert!(blowup_factor <= 128, "blowup factor cannot be greater than 128");
-
-        assert!(grinding_factor <= 32, "grinding factor cannot be greater than 32");
-
-        assert!(fri_folding_factor.is_power_of_two(), "FRI folding factor must be a power of 2");
-        assert!(fri_folding_factor >= 4, "FRI folding factor cannot be smaller than 4");
-        assert!(fri_folding_factor <= 16, "FRI folding factor cannot be greater than 16");
-
-        assert!(fri_max_remainder_size.is_power_of_two(), "FRI max remainder size must be a power of 2");
-        assert!(fri_max_remainder_size >= 32, "FRI max remainder size cannot be smaller than 32");
-        assert!(fri_max_remainder_size <= 1024, "FRI max remainder size cannot be greater than 1024");
+        if let Err(err) = Self::validate(
+            num_queries,
+            blowup_factor,
+            grinding_factor,
+            fri_folding_factor,
+            fri_max_remainder_size,
+        ) {
+            panic!("{}", err);
+        }
 
         ProofOptions {
             num_queries: num_queries as u8,
@@ -153,6 +179,48 @@ impl ProofOptions {
         }
     }
 
+    /// Returns a new instance of [ProofOptions] struct constructed from parameters in the form in
+    /// which they are serialized (i.e., with FRI max remainder size stored as a power of two).
+    ///
+    /// # Errors
+    /// Returns an error if any of the parameters is outside of the ranges described in
+    /// [new()](ProofOptions::new).
+    fn from_raw_parts(
+        num_queries: u8,
+        blowup_factor: u8,
+        grinding_factor: u8,
+        hash_fn: HashFunction,
+        field_extension: FieldExtension,
+        fri_folding_factor: u8,
+        fri_max_remainder_size: u8,
+    ) -> Result<Self, DeserializationError> {
+        let max_remainder_size = 2usize
+            .checked_pow(fri_max_remainder_size as u32)
+            .ok_or_else(|| {
+                DeserializationError::InvalidValue(
+                    "FRI max remainder size cannot be greater than 1024".to_string(),
+                )
+            })?;
+        Self::validate(
+            num_queries as usize,
+            blowup_factor as usize,
+            grinding_factor as u32,
+            fri_folding_factor as usize,
+            max_remainder_size,
+        )
+        .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        Ok(ProofOptions {
+            num_queries,
+            blowup_factor,
+            grinding_factor,
+            hash_fn,
+            field_extension,
+            fri_folding_factor,
+            fri_max_remainder_size,
+        })
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -219,6 +287,63 @@ impl ProofOptions {
         let max_remainder_size = 2usize.pow(self.fri_max_remainder_size as u32);
         FriOptions::new(self.blowup_factor(), folding_factor, max_remainder_size)
     }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an error message if any of the specified parameters is outside of its allowed
+    /// range.
+    #[rustfmt::skip]
+    fn validate(
+        num_queries: usize,
+        blowup_factor: usize,
+        grinding_factor: u32,
+        fri_folding_factor: usize,
+        fri_max_remainder_size: usize,
+    ) -> Result<(), String> {
+        if num_queries == 0 {
+            return Err("number of queries must be greater than 0".to_string());
+        }
+        if num_queries > 128 {
+            return Err("number of queries cannot be greater than 128".to_string());
+        }
+
+        if !blowup_factor.is_power_of_two() {
+            return Err("blowup factor must be a power of 2".to_string());
+        }
+        if blowup_factor < Self::MIN_BLOWUP_FACTOR {
+            return Err(format!("blowup factor cannot be smaller than {}", Self::MIN_BLOWUP_FACTOR));
+        }
+        if blowup_factor > 128 {
+            return Err("blowup factor cannot be greater than 128".to_string());
+        }
+
+        if grinding_factor > 32 {
+            return Err("grinding factor cannot be greater than 32".to_string());
+        }
+
+        if !fri_folding_factor.is_power_of_two() {
+            return Err("FRI folding factor must be a power of 2".to_string());
+        }
+        if fri_folding_factor < 4 {
+            return Err("FRI folding factor cannot be smaller than 4".to_string());
+        }
+        if fri_folding_factor > 16 {
+            return Err("FRI folding factor cannot be greater than 16".to_string());
+        }
+
+        if !fri_max_remainder_size.is_power_of_two() {
+            return Err("FRI max remainder size must be a power of 2".to_string());
+        }
+        if fri_max_remainder_size < 32 {
+            return Err("FRI max remainder size cannot be smaller than 32".to_string());
+        }
+        if fri_max_remainder_size > 1024 {
+            return Err("FRI max remainder size cannot be greater than 1024".to_string());
+        }
+
+        Ok(())
+    }
 }
 
 impl Serializable for ProofOptions {
@@ -240,15 +365,32 @@ impl Deserializable for ProofOptions {
     /// # Errors
     /// Returns an error of a valid proof options could not be read from the specified `source`.
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        Ok(ProofOptions::new(
-            source.read_u8()? as usize,
-            source.read_u8()? as usize,
-            source.read_u8()? as u32,
+        ProofOptions::from_raw_parts(
+            source.read_u8()?,
+            source.read_u8()?,
+            source.read_u8()?,
             HashFunction::read_from(source)?,
             FieldExtension::read_from(source)?,
-            source.read_u8()? as usize,
-            2usize.pow(source.read_u8()? as u32),
-        ))
+            source.read_u8()?,
+            source.read_u8()?,
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawProofOptions> for ProofOptions {
+    type Error = DeserializationError;
+
+    fn try_from(raw: RawProofOptions) -> Result<Self, Self::Error> {
+        ProofOptions::from_raw_parts(
+            raw.num_queries,
+            raw.blowup_factor,
+            raw.grinding_factor,
+            raw.hash_fn,
+            raw.field_extension,
+            raw.fri_folding_factor,
+            raw.fri_max_remainder_size,
+        )
     }
 }
 
@@ -303,6 +445,8 @@ impl HashFunction {
             Self::Blake3_192 => 96,
             Self::Blake3_256 => 128,
             Self::Sha3_256 => 128,
+            Self::Poseidon64_256 => 128,
+            Self::Keccak256 => 128,
         }
     }
 }
@@ -321,6 +465,8 @@ impl Deserializable for HashFunction {
             1 => Ok(HashFunction::Blake3_192),
             2 => Ok(HashFunction::Blake3_256),
             3 => Ok(HashFunction::Sha3_256),
+            4 => Ok(HashFunction::Poseidon64_256),
+            5 => Ok(HashFunction::Keccak256),
             value => Err(DeserializationError::InvalidValue(format!(
                 "value {} cannot be deserialized as HashFunction enum",
                 value
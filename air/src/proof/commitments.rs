@@ -9,6 +9,9 @@ use utils::{
     SliceReader,
 };
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 // COMMITMENTS
 // ================================================================================================
 /// Commitments made by the prover during commit phase of the protocol.
@@ -22,7 +25,8 @@ use utils::{
 /// Internally, the commitments are stored as a sequence of bytes. Thus, to retrieve the
 /// commitments, [parse()](Commitments::parse) function should be used.
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
-pub struct Commitments(Vec<u8>);
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Commitments(#[cfg_attr(feature = "serde", serde(with = "utils::serde_hex"))] Vec<u8>);
 
 impl Commitments {
     // CONSTRUCTOR
@@ -10,27 +10,76 @@ use utils::{
     DeserializationError, Serializable,
 };
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Flag set in the serialized trace length byte when the trace was padded to a power of two.
+const PADDED_TRACE_FLAG: u8 = 0x80;
+
 // PROOF CONTEXT
 // ================================================================================================
 /// Basic metadata about a specific execution of a computation.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawContext"))]
 pub struct Context {
+    version: u8,
+    trace_layout: TraceLayout,
+    trace_length: usize,
+    original_trace_length: usize,
+    #[cfg_attr(feature = "serde", serde(with = "utils::serde_hex"))]
+    trace_meta: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "utils::serde_hex"))]
+    field_modulus_bytes: Vec<u8>,
+    options: ProofOptions,
+}
+
+/// Unvalidated [Context] used when deserializing via serde; the context is validated when it is
+/// converted into [Context].
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawContext {
+    version: u8,
     trace_layout: TraceLayout,
     trace_length: usize,
+    original_trace_length: usize,
+    #[serde(with = "utils::serde_hex")]
     trace_meta: Vec<u8>,
+    #[serde(with = "utils::serde_hex")]
     field_modulus_bytes: Vec<u8>,
     options: ProofOptions,
 }
 
 impl Context {
+    /// Version of the STARK protocol implemented by this crate.
+    ///
+    /// The version is recorded in the context of every proof and is absorbed into the seed of the
+    /// public coin together with the rest of the context. A verifier rejects proofs generated
+    /// using a different version of the protocol.
+    pub const PROTOCOL_VERSION: u8 = 1;
+
+    /// Version of the STARK protocol assigned to contexts of legacy proofs, i.e., proofs which
+    /// were generated before protocol versions were recorded in proofs.
+    ///
+    /// Neither the protocol version nor the AIR domain separation tag were absorbed into the seed
+    /// of the public coin for such proofs.
+    pub const LEGACY_PROTOCOL_VERSION: u8 = 0;
+
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Creates a new context for a computation described by the specified field, trace info, and
     /// proof options.
+    ///
+    /// The context is tagged with the current [PROTOCOL_VERSION](Self::PROTOCOL_VERSION).
     pub fn new<B: StarkField>(trace_info: &TraceInfo, options: ProofOptions) -> Self {
         Context {
+            version: Self::PROTOCOL_VERSION,
             trace_layout: trace_info.layout().clone(),
             trace_length: trace_info.length(),
+            original_trace_length: trace_info.original_length(),
             trace_meta: trace_info.meta().to_vec(),
             field_modulus_bytes: B::get_modulus_le_bytes(),
             options,
@@ -40,6 +89,11 @@ impl Context {
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns the version of the STARK protocol used to generate a proof in this context.
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
     /// Returns a layout describing how columns of the execution trace described by this context
     /// are arranged into segments.
     pub fn trace_layout(&self) -> &TraceLayout {
@@ -51,11 +105,19 @@ impl Context {
         self.trace_length
     }
 
+    /// Returns length of the execution trace before padding for the computation described by
+    /// this context.
+    ///
+    /// This is the same as [trace_length()](Context::trace_length) unless the trace was padded.
+    pub fn original_trace_length(&self) -> usize {
+        self.original_trace_length
+    }
+
     /// Returns execution trace info for the computation described by this context.
     pub fn get_trace_info(&self) -> TraceInfo {
         TraceInfo::new_multi_segment(
             self.trace_layout.clone(),
-            self.trace_length(),
+            self.original_trace_length(),
             self.trace_meta.clone(),
         )
     }
@@ -91,13 +153,45 @@ impl Context {
     pub fn options(&self) -> &ProofOptions {
         &self.options
     }
-}
 
-impl Serializable for Context {
-    /// Serializes `self` and writes the resulting bytes into the `target`.
-    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+    // LEGACY SERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Serializes `self` using the layout of legacy proof contexts (i.e., without the protocol
+    /// version) and writes the resulting bytes into the `target`.
+    ///
+    /// This is the form in which contexts of legacy proofs were absorbed into the seed of the
+    /// public coin.
+    pub fn write_legacy_into<W: ByteWriter>(&self, target: &mut W) {
+        debug_assert_eq!(self.trace_length, self.original_trace_length);
+        self.write_fields_into(target);
+    }
+
+    /// Reads a context serialized using the layout of legacy proof contexts (i.e., without the
+    /// protocol version) from the specified `source`. The version of the returned context is set
+    /// to [LEGACY_PROTOCOL_VERSION](Self::LEGACY_PROTOCOL_VERSION).
+    ///
+    /// # Errors
+    /// Returns an error of a valid Context struct could not be read from the specified `source`.
+    pub fn read_legacy_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Self::read_fields_from(source, Self::LEGACY_PROTOCOL_VERSION)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Writes all fields of this context except for the protocol version into the `target`.
+    fn write_fields_into<W: ByteWriter>(&self, target: &mut W) {
         self.trace_layout.write_into(target);
-        target.write_u8(math::log2(self.trace_length) as u8); // store as power of two
+        // trace length is stored as a power of two; if the trace was padded, the top bit of the
+        // length byte is set and the original length follows
+        let log_trace_length = math::log2(self.trace_length) as u8;
+        if self.original_trace_length == self.trace_length {
+            target.write_u8(log_trace_length);
+        } else {
+            target.write_u8(log_trace_length | PADDED_TRACE_FLAG);
+            target.write_u64(self.original_trace_length as u64);
+        }
         target.write_u16(self.trace_meta.len() as u16);
         target.write_u8_slice(&self.trace_meta);
         assert!(self.field_modulus_bytes.len() < u8::MAX as usize);
@@ -105,28 +199,46 @@ impl Serializable for Context {
         target.write_u8_slice(&self.field_modulus_bytes);
         self.options.write_into(target);
     }
-}
 
-impl Deserializable for Context {
-    /// Reads proof context from the specified `source` and returns the result.
+    /// Reads all fields of a context except for the protocol version from the `source`, and
+    /// returns a context for the specified protocol version.
     ///
-    /// # Errors
-    /// Returns an error of a valid Context struct could not be read from the specified `source`.
-    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+    /// Padded traces are not supported by the legacy protocol version.
+    fn read_fields_from<R: ByteReader>(
+        source: &mut R,
+        version: u8,
+    ) -> Result<Self, DeserializationError> {
         // read and validate trace layout info
         let trace_layout = TraceLayout::read_from(source)?;
 
-        // read and validate trace length (which was stored as a power of two)
+        // read trace length (which was stored as a power of two)
         let trace_length = source.read_u8()?;
-        if trace_length < math::log2(TraceInfo::MIN_TRACE_LENGTH) as u8 {
+        let is_padded = trace_length & PADDED_TRACE_FLAG != 0;
+        let trace_length = trace_length & !PADDED_TRACE_FLAG;
+        if trace_length as u32 >= usize::BITS {
             return Err(DeserializationError::InvalidValue(format!(
-                "trace length cannot be smaller than 2^{}, but was 2^{}",
-                math::log2(TraceInfo::MIN_TRACE_LENGTH),
+                "trace length cannot be greater than 2^{}, but was 2^{}",
+                usize::BITS - 1,
                 trace_length
             )));
         }
         let trace_length = 2_usize.pow(trace_length as u32);
 
+        // read original trace length for padded traces; the original length of a padded trace
+        // must be smaller than the padded length
+        let original_trace_length = if is_padded {
+            let original_length = source.read_u64()?;
+            if original_length >= trace_length as u64 {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "original trace length {} is not valid for padded trace length {}",
+                    original_length, trace_length
+                )));
+            }
+            original_length as usize
+        } else {
+            trace_length
+        };
+
         // read trace metadata
         let num_meta_bytes = source.read_u16()? as usize;
         let trace_meta = if num_meta_bytes != 0 {
@@ -135,24 +247,104 @@ impl Deserializable for Context {
             vec![]
         };
 
-        // read and validate field modulus bytes
+        // read field modulus bytes
         let num_modulus_bytes = source.read_u8()? as usize;
-        if num_modulus_bytes == 0 {
-            return Err(DeserializationError::InvalidValue(
-                "field modulus cannot be an empty value".to_string(),
-            ));
-        }
         let field_modulus_bytes = source.read_u8_vec(num_modulus_bytes)?;
 
         // read options
         let options = ProofOptions::read_from(source)?;
 
+        Self::from_raw_parts(
+            version,
+            trace_layout,
+            trace_length,
+            original_trace_length,
+            trace_meta,
+            field_modulus_bytes,
+            options,
+        )
+    }
+
+    /// Returns a context instantiated from the specified fields after validating them.
+    ///
+    /// The trace layout and proof options are assumed to have been validated already.
+    fn from_raw_parts(
+        version: u8,
+        trace_layout: TraceLayout,
+        trace_length: usize,
+        original_trace_length: usize,
+        trace_meta: Vec<u8>,
+        field_modulus_bytes: Vec<u8>,
+        options: ProofOptions,
+    ) -> Result<Self, DeserializationError> {
+        // validate trace length and original trace length
+        TraceInfo::validate(trace_length, original_trace_length, &trace_meta)?;
+        if trace_length != original_trace_length && version == Self::LEGACY_PROTOCOL_VERSION {
+            return Err(DeserializationError::InvalidValue(
+                "padded traces are not supported by the legacy protocol version".to_string(),
+            ));
+        }
+
+        // validate field modulus bytes
+        if field_modulus_bytes.is_empty() {
+            return Err(DeserializationError::InvalidValue(
+                "field modulus cannot be an empty value".to_string(),
+            ));
+        }
+        if field_modulus_bytes.len() >= u8::MAX as usize {
+            return Err(DeserializationError::InvalidValue(format!(
+                "field modulus cannot consist of more than {} bytes, but was {}",
+                u8::MAX - 1,
+                field_modulus_bytes.len()
+            )));
+        }
+
         Ok(Context {
+            version,
             trace_layout,
             trace_length,
+            original_trace_length,
             trace_meta,
             field_modulus_bytes,
             options,
         })
     }
 }
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawContext> for Context {
+    type Error = DeserializationError;
+
+    fn try_from(raw: RawContext) -> Result<Self, Self::Error> {
+        Context::from_raw_parts(
+            raw.version,
+            raw.trace_layout,
+            raw.trace_length,
+            raw.original_trace_length,
+            raw.trace_meta,
+            raw.field_modulus_bytes,
+            raw.options,
+        )
+    }
+}
+
+impl Serializable for Context {
+    /// Serializes `self` and writes the resulting bytes into the `target`.
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.version);
+        self.write_fields_into(target);
+    }
+}
+
+impl Deserializable for Context {
+    /// Reads proof context from the specified `source` and returns the result.
+    ///
+    /// # Errors
+    /// Returns an error of a valid Context struct could not be read from the specified `source`.
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        // read protocol version; the version is not validated here because it is up to the
+        // verifier to decide which versions of the protocol it supports
+        let version = source.read_u8()?;
+        Self::read_fields_from(source, version)
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::StarkProof;
+use utils::{
+    collections::Vec, string::ToString, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, Serializable, SliceReader,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// CONTINUATION PROOF
+// ================================================================================================
+/// A proof of a long computation which was split into segments each proven separately.
+///
+/// Every segment of a continuation is proven by a standard STARK proof against its own public
+/// inputs. Public inputs of each segment expose boundary states of the segment (see
+/// [SegmentPublicInputs](crate::SegmentPublicInputs)), and a continuation is valid only if the
+/// final state of every segment matches the initial state of the next segment.
+///
+/// Unlike a [StarkProof], a continuation proof carries serialized public inputs of all segments
+/// since these are produced by the prover; the verifier needs to supply only the initial and
+/// the final states of the entire computation.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ContinuationProof {
+    /// Proofs of individual segments in the order of execution.
+    pub segments: Vec<SegmentProof>,
+}
+
+impl ContinuationProof {
+    /// Returns the number of segments in this continuation.
+    pub fn num_segments(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Returns the total number of execution steps proven by this continuation.
+    pub fn num_steps(&self) -> usize {
+        self.segments.iter().map(|s| s.proof.trace_length()).sum()
+    }
+
+    /// Returns a continuation proof read from the specified `source`.
+    ///
+    /// # Errors
+    /// Returns an error if a valid continuation proof could not be read from the specified
+    /// `source`, or if not all bytes of the `source` were consumed.
+    pub fn from_bytes(source: &[u8]) -> Result<Self, DeserializationError> {
+        let mut reader = SliceReader::new(source);
+        let result = Self::read_from(&mut reader)?;
+        if reader.has_more_bytes() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+        Ok(result)
+    }
+}
+
+impl Serializable for ContinuationProof {
+    /// Serializes `self` and writes the resulting bytes into the `target` writer.
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.segments.len() as u32);
+        target.write(&self.segments);
+    }
+}
+
+impl Deserializable for ContinuationProof {
+    /// Reads a continuation proof from the specified `source` and returns the result.
+    ///
+    /// # Errors
+    /// Returns an error if a valid continuation proof could not be read from the specified
+    /// `source`.
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_segments = source.read_u32()? as usize;
+        let segments = SegmentProof::read_batch_from(source, num_segments)?;
+        Ok(ContinuationProof { segments })
+    }
+}
+
+// SEGMENT PROOF
+// ================================================================================================
+/// A proof of a single segment of a continuation.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SegmentProof {
+    /// Serialized public inputs of the segment.
+    #[cfg_attr(feature = "serde", serde(with = "utils::serde_hex"))]
+    pub pub_inputs: Vec<u8>,
+    /// STARK proof attesting to the correct execution of the segment against its public inputs.
+    pub proof: StarkProof,
+}
+
+impl Serializable for SegmentProof {
+    /// Serializes `self` and writes the resulting bytes into the `target` writer.
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.pub_inputs.len() as u32);
+        target.write_u8_slice(&self.pub_inputs);
+
+        let proof_bytes = self.proof.to_bytes();
+        target.write_u32(proof_bytes.len() as u32);
+        target.write_u8_slice(&proof_bytes);
+    }
+}
+
+impl Deserializable for SegmentProof {
+    /// Reads a segment proof from the specified `source` and returns the result.
+    ///
+    /// # Errors
+    /// Returns an error if a valid segment proof could not be read from the specified `source`.
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_pub_input_bytes = source.read_u32()? as usize;
+        let pub_inputs = source.read_u8_vec(num_pub_input_bytes)?;
+
+        let num_proof_bytes = source.read_u32()? as usize;
+        let proof_bytes = source.read_u8_vec(num_proof_bytes)?;
+        let proof = StarkProof::from_bytes(&proof_bytes)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        Ok(SegmentProof { pub_inputs, proof })
+    }
+}
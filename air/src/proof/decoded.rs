@@ -0,0 +1,426 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{Context, ProofSection, StarkProof, Table};
+use crate::{EvaluationFrame, ProofDeserializationError};
+use core::fmt;
+use crypto::{BatchMerkleProof, ElementHasher, Hasher};
+use math::{FieldElement, StarkField};
+use utils::{collections::Vec, DeserializationError, Serializable};
+
+// DECODED PROOF
+// ================================================================================================
+/// A [StarkProof] with all of its components decoded into values of concrete types.
+///
+/// In a [StarkProof], commitments, query values, Merkle authentication paths, out-of-domain
+/// evaluations, and FRI layers are stored as opaque sequences of bytes. A decoded proof exposes
+/// these components as digests of the hash function specified by `H` type parameter, and
+/// elements of the field specified by `E` type parameter (elements of the main trace segment
+/// are always in the base field).
+///
+/// Decoding a proof does not verify it: the decoded proof is guaranteed to be well-formed, but
+/// the query values are not checked against the commitments, and none of the constraints are
+/// evaluated. Thus, a decoded proof is primarily intended for debugging and inspection.
+///
+/// The [Display](fmt::Display) implementation of this struct renders the decoded proof as a
+/// human-readable tree.
+#[derive(Debug, Clone)]
+pub struct DecodedProof<E: FieldElement, H: ElementHasher<BaseField = E::BaseField>> {
+    /// Basic metadata about the execution of the computation described by the proof.
+    pub context: Context,
+    /// Commitments to the extended execution trace; one commitment per trace segment.
+    pub trace_commitments: Vec<H::Digest>,
+    /// Commitment to the evaluations of the constraint composition polynomial.
+    pub constraint_commitment: H::Digest,
+    /// Commitments to FRI layers; the last commitment is the commitment to the FRI remainder.
+    pub fri_commitments: Vec<H::Digest>,
+    /// Queried states of the main trace segment.
+    pub main_trace_queries: DecodedQueries<E::BaseField, H>,
+    /// Queried states of auxiliary trace segments; one entry per auxiliary segment.
+    pub aux_trace_queries: Vec<DecodedQueries<E, H>>,
+    /// Queried evaluations of the constraint composition polynomial columns.
+    pub constraint_queries: DecodedQueries<E, H>,
+    /// Evaluations of main trace polynomials at the out-of-domain point *z* and *z * g*.
+    pub ood_main_trace_frame: EvaluationFrame<E>,
+    /// Evaluations of auxiliary trace polynomials at *z* and *z * g*, if the trace has
+    /// auxiliary segments.
+    pub ood_aux_trace_frame: Option<EvaluationFrame<E>>,
+    /// Evaluations of constraint composition polynomial columns at the out-of-domain point.
+    pub ood_constraint_evaluations: Vec<E>,
+    /// Queried values of all FRI layers.
+    pub fri_layers: Vec<DecodedFriLayer<E, H>>,
+    /// Values of the last FRI layer.
+    pub fri_remainder: Vec<E>,
+    /// Number of partitions used by the FRI prover to commit to FRI layers.
+    pub fri_num_partitions: usize,
+    /// Proof-of-work nonce for query seed grinding.
+    pub pow_nonce: u64,
+}
+
+/// Query values of a single commitment together with their Merkle authentication paths.
+#[derive(Debug, Clone)]
+pub struct DecodedQueries<E: FieldElement, H: Hasher> {
+    /// Queried values; each row of the table corresponds to a single query.
+    pub values: Table<E>,
+    /// Merkle authentication paths for all queries aggregated into a single batch proof.
+    pub merkle_proof: BatchMerkleProof<H>,
+}
+
+/// Query values of a single FRI layer together with their Merkle authentication paths.
+#[derive(Debug, Clone)]
+pub struct DecodedFriLayer<E: FieldElement, H: Hasher> {
+    /// Queried values; each query contains as many values as the FRI folding factor.
+    pub values: Vec<E>,
+    /// Merkle authentication paths for all queries aggregated into a single batch proof.
+    pub merkle_proof: BatchMerkleProof<H>,
+}
+
+impl<E: FieldElement, H: ElementHasher<BaseField = E::BaseField>> DecodedProof<E, H> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Decodes all components of the specified `proof`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The base field of `E` is not the field specified in the proof context, or the
+    ///   extension degree of `E` is not the one specified in the proof options.
+    /// * Any of the proof components is inconsistent with the proof context or cannot be parsed
+    ///   into values of the specified types. The error identifies the offending proof section.
+    pub fn new(proof: &StarkProof) -> Result<Self, ProofDeserializationError> {
+        use ProofDeserializationError::InvalidSection;
+
+        let context = proof.context.clone();
+        if E::BaseField::get_modulus_le_bytes() != context.field_modulus_bytes() {
+            return Err(InvalidSection(
+                ProofSection::Context,
+                DeserializationError::InvalidValue(
+                    "proof base field does not match the specified base field".into(),
+                ),
+            ));
+        }
+        let extension_degree = E::ELEMENT_BYTES / E::BaseField::ELEMENT_BYTES;
+        if extension_degree != context.options().field_extension().degree() as usize {
+            return Err(InvalidSection(
+                ProofSection::Context,
+                DeserializationError::InvalidValue(format!(
+                    "proof field extension of degree {} does not match the specified extension of degree {}",
+                    context.options().field_extension().degree(),
+                    extension_degree
+                )),
+            ));
+        }
+
+        let trace_layout = context.trace_layout();
+        let lde_domain_size = context.lde_domain_size();
+        let num_queries = context.options().num_queries();
+        let fri_options = context.options().to_fri_options();
+
+        // --- commitments ------------------------------------------------------------------------
+        let (trace_commitments, constraint_commitment, fri_commitments) = proof
+            .commitments
+            .clone()
+            .parse::<H>(
+                trace_layout.num_segments(),
+                fri_options.num_fri_layers(lde_domain_size),
+            )
+            .map_err(|err| InvalidSection(ProofSection::Commitments, err))?;
+
+        // --- trace queries ----------------------------------------------------------------------
+        if proof.trace_queries.len() != trace_layout.num_segments() {
+            return Err(InvalidSection(
+                ProofSection::TraceQueries,
+                DeserializationError::InvalidValue(format!(
+                    "expected queries for {} trace segments, but was {}",
+                    trace_layout.num_segments(),
+                    proof.trace_queries.len()
+                )),
+            ));
+        }
+        let main_trace_queries = decode_queries(
+            &proof.trace_queries[0],
+            lde_domain_size,
+            num_queries,
+            trace_layout.main_trace_width(),
+        )
+        .map_err(|err| InvalidSection(ProofSection::TraceQueries, err))?;
+        let aux_trace_queries = proof.trace_queries[1..]
+            .iter()
+            .enumerate()
+            .map(|(i, queries)| {
+                let width = trace_layout.get_aux_segment_width(i);
+                decode_queries(queries, lde_domain_size, num_queries, width)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| InvalidSection(ProofSection::TraceQueries, err))?;
+
+        // --- constraint queries -----------------------------------------------------------------
+        // the number of composition polynomial columns is not recorded in the proof context, and
+        // thus, we infer it from the number of queried values
+        let num_constraint_columns = proof.constraint_queries.num_values::<E>() / num_queries;
+        let constraint_queries = decode_queries(
+            &proof.constraint_queries,
+            lde_domain_size,
+            num_queries,
+            num_constraint_columns,
+        )
+        .map_err(|err| InvalidSection(ProofSection::ConstraintQueries, err))?;
+
+        // --- out-of-domain frame ----------------------------------------------------------------
+        let num_ood_evaluations = proof.ood_frame.num_constraint_evaluations::<E>();
+        if num_ood_evaluations == 0 {
+            return Err(InvalidSection(
+                ProofSection::OodFrame,
+                DeserializationError::InvalidValue(
+                    "out-of-domain frame must contain at least one constraint evaluation".into(),
+                ),
+            ));
+        }
+        let (ood_main_trace_frame, ood_aux_trace_frame, ood_constraint_evaluations) = proof
+            .ood_frame
+            .clone()
+            .parse(
+                trace_layout.main_trace_width(),
+                trace_layout.aux_trace_width(),
+                num_ood_evaluations,
+            )
+            .map_err(|err| InvalidSection(ProofSection::OodFrame, err))?;
+
+        // --- FRI proof --------------------------------------------------------------------------
+        let fri_num_partitions = proof.fri_proof.num_partitions();
+        let fri_remainder = proof
+            .fri_proof
+            .parse_remainder()
+            .map_err(|err| InvalidSection(ProofSection::FriProof, err))?;
+        let (fri_layer_values, fri_layer_proofs) = proof
+            .fri_proof
+            .clone()
+            .parse_layers::<H, E>(lde_domain_size, fri_options.folding_factor())
+            .map_err(|err| InvalidSection(ProofSection::FriProof, err))?;
+        let fri_layers = fri_layer_values
+            .into_iter()
+            .zip(fri_layer_proofs)
+            .map(|(values, merkle_proof)| DecodedFriLayer {
+                values,
+                merkle_proof,
+            })
+            .collect();
+
+        Ok(DecodedProof {
+            context,
+            trace_commitments,
+            constraint_commitment,
+            fri_commitments,
+            main_trace_queries,
+            aux_trace_queries,
+            constraint_queries,
+            ood_main_trace_frame,
+            ood_aux_trace_frame,
+            ood_constraint_evaluations,
+            fri_layers,
+            fri_remainder,
+            fri_num_partitions,
+            pow_nonce: proof.pow_nonce,
+        })
+    }
+}
+
+// PRETTY PRINTING
+// ================================================================================================
+
+impl<E, H> fmt::Display for DecodedProof<E, H>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let options = self.context.options();
+        let layout = self.context.trace_layout();
+
+        writeln!(f, "context")?;
+        writeln!(f, "  protocol version: {}", self.context.version())?;
+        writeln!(f, "  trace length: {}", self.context.trace_length())?;
+        writeln!(f, "  main trace width: {}", layout.main_trace_width())?;
+        for i in 0..layout.num_aux_segments() {
+            writeln!(
+                f,
+                "  aux segment {} width: {} (random elements: {})",
+                i,
+                layout.get_aux_segment_width(i),
+                layout.get_aux_segment_rand_elements(i)
+            )?;
+        }
+        writeln!(
+            f,
+            "  trace meta: 0x{}",
+            Hex(self.context.get_trace_info().meta())
+        )?;
+        writeln!(
+            f,
+            "  field modulus (le): 0x{}",
+            Hex(self.context.field_modulus_bytes())
+        )?;
+        writeln!(f, "  options")?;
+        writeln!(f, "    hash function: {:?}", options.hash_fn())?;
+        writeln!(f, "    field extension: {:?}", options.field_extension())?;
+        writeln!(f, "    blowup factor: {}", options.blowup_factor())?;
+        writeln!(f, "    number of queries: {}", options.num_queries())?;
+        writeln!(f, "    grinding factor: {}", options.grinding_factor())?;
+        writeln!(
+            f,
+            "    FRI folding factor: {}",
+            options.to_fri_options().folding_factor()
+        )?;
+
+        writeln!(f, "commitments")?;
+        for (i, commitment) in self.trace_commitments.iter().enumerate() {
+            writeln!(f, "  trace segment {}: {}", i, Digest(commitment))?;
+        }
+        writeln!(f, "  constraints: {}", Digest(&self.constraint_commitment))?;
+        for (i, commitment) in self.fri_commitments.iter().enumerate() {
+            writeln!(f, "  FRI layer {}: {}", i, Digest(commitment))?;
+        }
+
+        writeln!(f, "trace queries")?;
+        write_queries(f, "main segment", &self.main_trace_queries)?;
+        for (i, queries) in self.aux_trace_queries.iter().enumerate() {
+            write_queries(f, &format!("aux segment {}", i), queries)?;
+        }
+
+        writeln!(f, "constraint queries")?;
+        write_queries(f, "composition columns", &self.constraint_queries)?;
+
+        writeln!(f, "out-of-domain frame")?;
+        writeln!(
+            f,
+            "  main trace at z: {}",
+            Values(self.ood_main_trace_frame.current())
+        )?;
+        writeln!(
+            f,
+            "  main trace at z * g: {}",
+            Values(self.ood_main_trace_frame.next())
+        )?;
+        if let Some(frame) = &self.ood_aux_trace_frame {
+            writeln!(f, "  aux trace at z: {}", Values(frame.current()))?;
+            writeln!(f, "  aux trace at z * g: {}", Values(frame.next()))?;
+        }
+        writeln!(
+            f,
+            "  constraint evaluations: {}",
+            Values(&self.ood_constraint_evaluations)
+        )?;
+
+        writeln!(f, "FRI proof")?;
+        writeln!(f, "  number of partitions: {}", self.fri_num_partitions)?;
+        let folding_factor = options.to_fri_options().folding_factor();
+        for (i, layer) in self.fri_layers.iter().enumerate() {
+            writeln!(f, "  layer {}", i)?;
+            write_merkle_proof(f, "    ", &layer.merkle_proof)?;
+            for (j, query) in layer.values.chunks(folding_factor).enumerate() {
+                writeln!(f, "    query {}: {}", j, Values(query))?;
+            }
+        }
+        writeln!(f, "  remainder: {}", Values(&self.fri_remainder))?;
+
+        write!(f, "proof-of-work nonce: {}", self.pow_nonce)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Parses the specified queries into a table of values and a batch Merkle proof.
+fn decode_queries<E, H>(
+    queries: &super::Queries,
+    domain_size: usize,
+    num_queries: usize,
+    values_per_query: usize,
+) -> Result<DecodedQueries<E, H>, DeserializationError>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+{
+    if values_per_query == 0 {
+        return Err(DeserializationError::InvalidValue(
+            "a query must contain at least one value".into(),
+        ));
+    }
+    let (merkle_proof, values) =
+        queries
+            .clone()
+            .parse::<H, E>(domain_size, num_queries, values_per_query)?;
+    Ok(DecodedQueries {
+        values,
+        merkle_proof,
+    })
+}
+
+/// Writes the specified queries, prefixed by `label`, into the formatter.
+fn write_queries<E: FieldElement, H: Hasher>(
+    f: &mut fmt::Formatter<'_>,
+    label: &str,
+    queries: &DecodedQueries<E, H>,
+) -> fmt::Result {
+    writeln!(f, "  {} (width: {})", label, queries.values.num_columns())?;
+    write_merkle_proof(f, "    ", &queries.merkle_proof)?;
+    for (i, row) in queries.values.rows().enumerate() {
+        writeln!(f, "    query {}: {}", i, Values(row))?;
+    }
+    Ok(())
+}
+
+/// Writes a summary of the specified batch Merkle proof into the formatter.
+fn write_merkle_proof<H: Hasher>(
+    f: &mut fmt::Formatter<'_>,
+    indent: &str,
+    proof: &BatchMerkleProof<H>,
+) -> fmt::Result {
+    let num_nodes: usize = proof.nodes().iter().map(|path| path.len()).sum();
+    writeln!(
+        f,
+        "{}merkle proof: depth {}, {} paths, {} internal nodes",
+        indent,
+        proof.depth(),
+        proof.leaves().len(),
+        num_nodes
+    )
+}
+
+/// Formats a sequence of bytes as a lowercase hex string.
+struct Hex<'a>(&'a [u8]);
+
+impl<'a> fmt::Display for Hex<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats a digest as a lowercase hex string.
+struct Digest<'a, D: Serializable>(&'a D);
+
+impl<'a, D: Serializable> fmt::Display for Digest<'a, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", Hex(&self.0.to_bytes()))
+    }
+}
+
+/// Formats a list of field elements as a comma-separated list enclosed in square brackets.
+struct Values<'a, E: FieldElement>(&'a [E]);
+
+impl<'a, E: FieldElement> fmt::Display for Values<'a, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, value) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", value)?;
+        }
+        write!(f, "]")
+    }
+}
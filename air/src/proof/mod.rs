@@ -5,14 +5,28 @@
 
 //! Contains STARK proof struct and associated components.
 
-use crate::{ProofOptions, TraceInfo, TraceLayout};
+use crate::{
+    FieldExtension, HashFunction, ProofDeserializationError, ProofOptions, TraceInfo, TraceLayout,
+};
 use core::cmp;
+use crypto::{
+    hashers::{Blake3_192, Blake3_256, Keccak256, Poseidon64_256, Sha3_256},
+    ElementHasher,
+};
 use fri::FriProof;
-use math::log2;
+use math::{
+    fields::{CubeExtension, QuadExtension},
+    log2, ExtensibleField, FieldElement, StarkField,
+};
 use utils::{
-    collections::Vec, ByteReader, Deserializable, DeserializationError, Serializable, SliceReader,
+    collections::Vec,
+    string::{String, ToString},
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
 };
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 mod context;
 pub use context::Context;
 
@@ -28,6 +42,16 @@ pub use ood_frame::OodFrame;
 mod table;
 pub use table::Table;
 
+mod sections;
+pub use sections::ProofSection;
+
+mod decoded;
+pub use decoded::{DecodedFriLayer, DecodedProof, DecodedQueries};
+
+mod continuation;
+pub use continuation::{ContinuationProof, SegmentProof};
+use sections::SectionTable;
+
 // CONSTANTS
 // ================================================================================================
 
@@ -43,11 +67,14 @@ const GRINDING_CONTRIBUTION_FLOOR: u32 = 80;
 ///
 /// A proof can be serialized into a sequence of bytes using [to_bytes()](StarkProof::to_bytes)
 /// function, and deserialized from a sequence of bytes using [from_bytes()](StarkProof::from_bytes)
-/// function.
+/// function. Serialized proofs are versioned and self-describing: they start with
+/// [MAGIC](StarkProof::MAGIC) bytes followed by the [FORMAT_VERSION](StarkProof::FORMAT_VERSION)
+/// and a table of length-delimited [sections](ProofSection).
 ///
 /// To estimate soundness of a proof (in bits), [security_level()](StarkProof::security_level)
 /// function can be used.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StarkProof {
     /// Basic metadata about the execution of the computation described by this proof.
     pub context: Context,
@@ -68,6 +95,16 @@ pub struct StarkProof {
 }
 
 impl StarkProof {
+    /// Magic bytes with which every serialized proof starts.
+    pub const MAGIC: [u8; 4] = *b"WNTR";
+
+    /// Version of the serialization format used by [to_bytes()](StarkProof::to_bytes).
+    ///
+    /// Version 1 refers to the legacy format in which proof components were written one after
+    /// another without any header; proofs in this format can still be read by
+    /// [from_bytes()](StarkProof::from_bytes).
+    pub const FORMAT_VERSION: u8 = 2;
+
     /// Returns STARK protocol parameters used to generate this proof.
     pub fn options(&self) -> &ProofOptions {
         self.context.options()
@@ -84,6 +121,11 @@ impl StarkProof {
         self.context.trace_length()
     }
 
+    /// Returns trace length before padding for the computation described by this proof.
+    pub fn original_trace_length(&self) -> usize {
+        self.context.original_trace_length()
+    }
+
     /// Returns trace info for the computation described by this proof.
     pub fn get_trace_info(&self) -> TraceInfo {
         self.context.get_trace_info()
@@ -115,54 +157,204 @@ impl StarkProof {
         }
     }
 
+    // DECODING
+    // --------------------------------------------------------------------------------------------
+
+    /// Decodes all components of this proof into digests of hash function `H` and elements of
+    /// field `E`.
+    ///
+    /// `E` and `H` must match the field extension and the hash function specified in the proof
+    /// options. To decode a proof without knowing these in advance, use
+    /// [to_pretty_string()](StarkProof::to_pretty_string).
+    ///
+    /// # Errors
+    /// Returns an error if any of the proof components cannot be decoded into values of the
+    /// specified types; see [DecodedProof::new()] for details.
+    pub fn decode<E, H>(&self) -> Result<DecodedProof<E, H>, ProofDeserializationError>
+    where
+        E: FieldElement,
+        H: ElementHasher<BaseField = E::BaseField>,
+    {
+        DecodedProof::new(self)
+    }
+
+    /// Decodes this proof and renders it as a human-readable tree.
+    ///
+    /// `B` specifies the base field of the computation described by this proof; the extension
+    /// field and the hash function are selected based on the proof options.
+    ///
+    /// # Errors
+    /// Returns an error if the proof could not be decoded (e.g., because `B` is not the field
+    /// used to generate this proof, or `B` does not support the field extension specified in the
+    /// proof options).
+    #[rustfmt::skip]
+    pub fn to_pretty_string<B>(&self) -> Result<String, ProofDeserializationError>
+    where
+        B: StarkField + ExtensibleField<2> + ExtensibleField<3>,
+    {
+        let options = self.options();
+        if options.hash_fn() == HashFunction::Poseidon64_256 && !<Poseidon64_256<B>>::is_supported() {
+            return Err(unsupported_hash_fn(options.hash_fn()));
+        }
+        match options.field_extension() {
+            FieldExtension::None => match options.hash_fn() {
+                HashFunction::Blake3_256 => self.decode::<B, Blake3_256<B>>().map(|p| p.to_string()),
+                HashFunction::Blake3_192 => self.decode::<B, Blake3_192<B>>().map(|p| p.to_string()),
+                HashFunction::Sha3_256 => self.decode::<B, Sha3_256<B>>().map(|p| p.to_string()),
+                HashFunction::Keccak256 => self.decode::<B, Keccak256<B>>().map(|p| p.to_string()),
+                HashFunction::Poseidon64_256 => self.decode::<B, Poseidon64_256<B>>().map(|p| p.to_string()),
+            },
+            FieldExtension::Quadratic => {
+                if !<QuadExtension<B>>::is_supported() {
+                    return Err(unsupported_extension(2));
+                }
+                match options.hash_fn() {
+                    HashFunction::Blake3_256 => self.decode::<QuadExtension<B>, Blake3_256<B>>().map(|p| p.to_string()),
+                    HashFunction::Blake3_192 => self.decode::<QuadExtension<B>, Blake3_192<B>>().map(|p| p.to_string()),
+                    HashFunction::Sha3_256 => self.decode::<QuadExtension<B>, Sha3_256<B>>().map(|p| p.to_string()),
+                    HashFunction::Keccak256 => self.decode::<QuadExtension<B>, Keccak256<B>>().map(|p| p.to_string()),
+                    HashFunction::Poseidon64_256 => self.decode::<QuadExtension<B>, Poseidon64_256<B>>().map(|p| p.to_string()),
+                }
+            }
+            FieldExtension::Cubic => {
+                if !<CubeExtension<B>>::is_supported() {
+                    return Err(unsupported_extension(3));
+                }
+                match options.hash_fn() {
+                    HashFunction::Blake3_256 => self.decode::<CubeExtension<B>, Blake3_256<B>>().map(|p| p.to_string()),
+                    HashFunction::Blake3_192 => self.decode::<CubeExtension<B>, Blake3_192<B>>().map(|p| p.to_string()),
+                    HashFunction::Sha3_256 => self.decode::<CubeExtension<B>, Sha3_256<B>>().map(|p| p.to_string()),
+                    HashFunction::Keccak256 => self.decode::<CubeExtension<B>, Keccak256<B>>().map(|p| p.to_string()),
+                    HashFunction::Poseidon64_256 => self.decode::<CubeExtension<B>, Poseidon64_256<B>>().map(|p| p.to_string()),
+                }
+            }
+        }
+    }
+
     // SERIALIZATION / DESERIALIZATION
     // --------------------------------------------------------------------------------------------
 
     /// Serializes this proof into a vector of bytes.
+    ///
+    /// The proof is serialized as follows:
+    /// * [MAGIC](StarkProof::MAGIC) bytes.
+    /// * [FORMAT_VERSION](StarkProof::FORMAT_VERSION) (1 byte).
+    /// * Section table which specifies identifier and length of each [section](ProofSection).
+    /// * Contents of all sections, in the order in which they are listed in the section table.
     pub fn to_bytes(&self) -> Vec<u8> {
+        let sections = [
+            (ProofSection::Context, self.context.to_bytes()),
+            (ProofSection::Commitments, self.commitments.to_bytes()),
+            (ProofSection::TraceQueries, self.trace_queries.to_bytes()),
+            (
+                ProofSection::ConstraintQueries,
+                self.constraint_queries.to_bytes(),
+            ),
+            (ProofSection::OodFrame, self.ood_frame.to_bytes()),
+            (ProofSection::FriProof, self.fri_proof.to_bytes()),
+            (
+                ProofSection::PowNonce,
+                self.pow_nonce.to_le_bytes().to_vec(),
+            ),
+        ];
+
         let mut result = Vec::new();
-        self.context.write_into(&mut result);
-        self.commitments.write_into(&mut result);
-        self.trace_queries.write_into(&mut result);
-        self.constraint_queries.write_into(&mut result);
-        self.ood_frame.write_into(&mut result);
-        self.fri_proof.write_into(&mut result);
-        result.extend_from_slice(&self.pow_nonce.to_le_bytes());
+        result.write_u8_slice(&Self::MAGIC);
+        result.write_u8(Self::FORMAT_VERSION);
+        SectionTable::write_into(&mut result, &sections);
         result
     }
 
     /// Returns a STARK proof read from the specified `source`.
     ///
+    /// Proofs serialized using the current as well as all previous versions of the proof format
+    /// are supported. Sections which are not known to this version of the library are ignored.
+    ///
     /// # Errors
-    /// Returns an error of a valid STARK proof could not be read from the specified `source`.
-    pub fn from_bytes(source: &[u8]) -> Result<Self, DeserializationError> {
+    /// Returns an error of a valid STARK proof could not be read from the specified `source`. The
+    /// error specifies which section of the proof could not be read.
+    pub fn from_bytes(source: &[u8]) -> Result<Self, ProofDeserializationError> {
+        // proofs serialized using the legacy format do not start with magic bytes; instead, they
+        // start with the trace layout and the trace length of the proof context. This cannot be
+        // confused with the current format because with the trace layout read from the magic
+        // bytes, the next byte would specify a trace length greater than 2^63.
+        if !source.starts_with(&Self::MAGIC) {
+            return Self::read_legacy(source);
+        }
+
+        let source = &source[Self::MAGIC.len()..];
+        match source.first() {
+            Some(&Self::FORMAT_VERSION) => Self::read_sections(&source[1..]),
+            Some(&version) => Err(ProofDeserializationError::UnsupportedFormatVersion(version)),
+            None => Err(ProofDeserializationError::InvalidSectionTable(
+                DeserializationError::UnexpectedEOF,
+            )),
+        }
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Reads a proof serialized as a table of sections (format version 2).
+    fn read_sections(source: &[u8]) -> Result<Self, ProofDeserializationError> {
+        let sections = SectionTable::read_from(source)?;
+
+        let context: Context = read_section(&sections, ProofSection::Context)?;
+        let num_trace_segments = context.trace_layout().num_segments();
+        let trace_queries = read_section_with(&sections, ProofSection::TraceQueries, |source| {
+            Queries::read_batch_from(source, num_trace_segments)
+        })?;
+
+        Ok(StarkProof {
+            context,
+            commitments: read_section(&sections, ProofSection::Commitments)?,
+            trace_queries,
+            constraint_queries: read_section(&sections, ProofSection::ConstraintQueries)?,
+            ood_frame: read_section(&sections, ProofSection::OodFrame)?,
+            fri_proof: read_section(&sections, ProofSection::FriProof)?,
+            pow_nonce: read_section_with(&sections, ProofSection::PowNonce, |source| {
+                source.read_u64()
+            })?,
+        })
+    }
+
+    /// Reads a proof serialized using the legacy format (format version 1), in which proof
+    /// components were written one after another without any header, and the proof context did
+    /// not include the protocol version.
+    fn read_legacy(source: &[u8]) -> Result<Self, ProofDeserializationError> {
+        use ProofDeserializationError::InvalidSection;
         let mut source = SliceReader::new(source);
 
         // parse the context
-        let context = Context::read_from(&mut source)?;
+        let context = Context::read_legacy_from(&mut source)
+            .map_err(|err| InvalidSection(ProofSection::Context, err))?;
 
         // parse the commitments
-        let commitments = Commitments::read_from(&mut source)?;
+        let commitments = Commitments::read_from(&mut source)
+            .map_err(|err| InvalidSection(ProofSection::Commitments, err))?;
 
         // parse trace queries
         let num_trace_segments = context.trace_layout().num_segments();
-        let mut trace_queries = Vec::with_capacity(num_trace_segments);
-        for _ in 0..num_trace_segments {
-            trace_queries.push(Queries::read_from(&mut source)?);
-        }
+        let trace_queries = Queries::read_batch_from(&mut source, num_trace_segments)
+            .map_err(|err| InvalidSection(ProofSection::TraceQueries, err))?;
 
         // parse the rest of the proof
         let proof = StarkProof {
             context,
             commitments,
             trace_queries,
-            constraint_queries: Queries::read_from(&mut source)?,
-            ood_frame: OodFrame::read_from(&mut source)?,
-            fri_proof: FriProof::read_from(&mut source)?,
-            pow_nonce: source.read_u64()?,
+            constraint_queries: Queries::read_from(&mut source)
+                .map_err(|err| InvalidSection(ProofSection::ConstraintQueries, err))?,
+            ood_frame: OodFrame::read_from(&mut source)
+                .map_err(|err| InvalidSection(ProofSection::OodFrame, err))?,
+            fri_proof: FriProof::read_from(&mut source)
+                .map_err(|err| InvalidSection(ProofSection::FriProof, err))?,
+            pow_nonce: source
+                .read_u64()
+                .map_err(|err| InvalidSection(ProofSection::PowNonce, err))?,
         };
         if source.has_more_bytes() {
-            return Err(DeserializationError::UnconsumedBytes);
+            return Err(ProofDeserializationError::UnconsumedBytes);
         }
         Ok(proof)
     }
@@ -198,3 +390,57 @@ fn get_conjectured_security(
         hash_fn_security,
     )
 }
+
+/// Returns an error indicating that the base field cannot be used with the specified hash
+/// function.
+fn unsupported_hash_fn(hash_fn: HashFunction) -> ProofDeserializationError {
+    ProofDeserializationError::InvalidSection(
+        ProofSection::Context,
+        DeserializationError::InvalidValue(format!(
+            "hash function {:?} is not supported by the base field",
+            hash_fn
+        )),
+    )
+}
+
+/// Returns an error indicating that the base field does not support field extension of the
+/// specified degree.
+fn unsupported_extension(degree: u32) -> ProofDeserializationError {
+    ProofDeserializationError::InvalidSection(
+        ProofSection::Context,
+        DeserializationError::InvalidValue(format!(
+            "field extension of degree {} is not supported by the base field",
+            degree
+        )),
+    )
+}
+
+/// Reads a value of type `T` from the specified section of a serialized proof.
+fn read_section<T: Deserializable>(
+    sections: &SectionTable,
+    section: ProofSection,
+) -> Result<T, ProofDeserializationError> {
+    read_section_with(sections, section, |source| T::read_from(source))
+}
+
+/// Reads a value from the specified section of a serialized proof using the provided `reader`
+/// function, and makes sure that all bytes of the section have been consumed.
+fn read_section_with<T, F>(
+    sections: &SectionTable,
+    section: ProofSection,
+    reader: F,
+) -> Result<T, ProofDeserializationError>
+where
+    F: FnOnce(&mut SliceReader) -> Result<T, DeserializationError>,
+{
+    let mut source = SliceReader::new(sections.get(section)?);
+    let result = reader(&mut source)
+        .map_err(|err| ProofDeserializationError::InvalidSection(section, err))?;
+    if source.has_more_bytes() {
+        return Err(ProofDeserializationError::InvalidSection(
+            section,
+            DeserializationError::UnconsumedBytes,
+        ));
+    }
+    Ok(result)
+}
@@ -10,6 +10,9 @@ use utils::{
     SliceReader,
 };
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 // TYPE ALIASES
 // ================================================================================================
 
@@ -29,8 +32,11 @@ type ParsedOodFrame<E> = (EvaluationFrame<E>, Option<EvaluationFrame<E>>, Vec<E>
 /// Internally, the evaluations are stored as a sequence of bytes. Thus, to retrieve the
 /// evaluations, [parse()](OodFrame::parse) function should be used.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OodFrame {
+    #[cfg_attr(feature = "serde", serde(with = "utils::serde_hex"))]
     trace_states: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "utils::serde_hex"))]
     evaluations: Vec<u8>,
 }
 
@@ -70,6 +76,14 @@ impl OodFrame {
         evaluations.write_into(&mut self.evaluations)
     }
 
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of constraint evaluations of type `E` stored in this frame.
+    pub fn num_constraint_evaluations<E: FieldElement>(&self) -> usize {
+        self.evaluations.len() / E::ELEMENT_BYTES
+    }
+
     // PARSER
     // --------------------------------------------------------------------------------------------
     /// Returns main and auxiliary (if any) trace evaluation frames and a vector of out-of-domain
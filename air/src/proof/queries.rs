@@ -11,6 +11,9 @@ use utils::{
     SliceReader,
 };
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 // QUERIES
 // ================================================================================================
 /// Decommitments to evaluations of a set of functions at multiple points.
@@ -28,8 +31,11 @@ use utils::{
 /// retrieve query values and the corresponding Merkle authentication paths,
 /// [parse()](Queries::parse) function should be used.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Queries {
+    #[cfg_attr(feature = "serde", serde(with = "utils::serde_hex"))]
     paths: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "utils::serde_hex"))]
     values: Vec<u8>,
 }
 
@@ -79,6 +85,14 @@ impl Queries {
         Queries { paths, values }
     }
 
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of query values of type `E` stored in this struct (across all queries).
+    pub fn num_values<E: FieldElement>(&self) -> usize {
+        self.values.len() / E::ELEMENT_BYTES
+    }
+
     // PARSER
     // --------------------------------------------------------------------------------------------
     /// Convert internally stored bytes into a set of query values and the corresponding Merkle
@@ -0,0 +1,177 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use crate::ProofDeserializationError;
+use core::fmt;
+use utils::{
+    collections::{BTreeMap, Vec},
+    ByteReader, ByteWriter, DeserializationError, SliceReader,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// PROOF SECTION
+// ================================================================================================
+/// Identifies a section of a serialized STARK proof.
+///
+/// Every section of a serialized proof is prefixed by an entry in the section table which
+/// specifies the section identifier and the length of the section in bytes. Thus, sections can
+/// be located (and skipped) without parsing their contents.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ProofSection {
+    /// Basic metadata about the execution of the computation described by the proof.
+    Context = 0,
+    /// Commitments made by the prover during the commit phase of the protocol.
+    Commitments = 1,
+    /// Decommitments of extended execution trace values for all trace segments.
+    TraceQueries = 2,
+    /// Decommitments of constraint composition polynomial evaluations.
+    ConstraintQueries = 3,
+    /// Trace and constraint polynomial evaluations at an out-of-domain point.
+    OodFrame = 4,
+    /// Low-degree proof for the DEEP composition polynomial.
+    FriProof = 5,
+    /// Proof-of-work nonce for query seed grinding.
+    PowNonce = 6,
+}
+
+impl ProofSection {
+    /// All sections of a proof in the order in which they are written into a serialized proof.
+    pub const ALL: [ProofSection; 7] = [
+        Self::Context,
+        Self::Commitments,
+        Self::TraceQueries,
+        Self::ConstraintQueries,
+        Self::OodFrame,
+        Self::FriProof,
+        Self::PowNonce,
+    ];
+
+    /// Returns the identifier of this section as it appears in the section table.
+    pub fn id(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Returns a section for the specified identifier, or None if the identifier does not refer
+    /// to any of the sections known to this version of the library.
+    pub fn from_id(id: u8) -> Option<Self> {
+        Self::ALL.iter().find(|section| section.id() == id).copied()
+    }
+}
+
+impl fmt::Display for ProofSection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Context => write!(f, "context"),
+            Self::Commitments => write!(f, "commitments"),
+            Self::TraceQueries => write!(f, "trace queries"),
+            Self::ConstraintQueries => write!(f, "constraint queries"),
+            Self::OodFrame => write!(f, "out-of-domain frame"),
+            Self::FriProof => write!(f, "FRI proof"),
+            Self::PowNonce => write!(f, "proof-of-work nonce"),
+        }
+    }
+}
+
+// SECTION TABLE
+// ================================================================================================
+/// Contents of a serialized proof split into sections.
+///
+/// The table is serialized as follows:
+/// * Number of sections (1 byte).
+/// * For each section: section identifier (1 byte) and section length in bytes (4 bytes).
+/// * Contents of all sections concatenated together in the order of the entries above.
+pub(super) struct SectionTable<'a> {
+    sections: BTreeMap<u8, &'a [u8]>,
+}
+
+impl<'a> SectionTable<'a> {
+    /// Writes the specified sections, together with the table describing them, into `target`.
+    pub fn write_into<W: ByteWriter>(target: &mut W, sections: &[(ProofSection, Vec<u8>)]) {
+        assert!(sections.len() <= u8::MAX as usize);
+        target.write_u8(sections.len() as u8);
+        for (section, bytes) in sections {
+            assert!(bytes.len() <= u32::MAX as usize);
+            target.write_u8(section.id());
+            target.write_u32(bytes.len() as u32);
+        }
+        for (_, bytes) in sections {
+            target.write_u8_slice(bytes);
+        }
+    }
+
+    /// Reads a section table from the specified `source`, and splits the bytes following the
+    /// table into sections.
+    ///
+    /// Sections with identifiers unknown to this version of the library are retained, but cannot
+    /// be retrieved. This allows proofs written by newer versions of the library to carry
+    /// additional sections as long as the sections required by this version are present.
+    ///
+    /// # Errors
+    /// Returns an error if the table is malformed, if the same section appears in the table more
+    /// than once, or if the section lengths do not add up to the number of remaining bytes.
+    pub fn read_from(source: &'a [u8]) -> Result<Self, ProofDeserializationError> {
+        let mut reader = SliceReader::new(source);
+
+        // read section table entries
+        let num_sections = reader
+            .read_u8()
+            .map_err(ProofDeserializationError::InvalidSectionTable)?;
+        let mut entries = Vec::with_capacity(num_sections as usize);
+        for _ in 0..num_sections {
+            let id = reader
+                .read_u8()
+                .map_err(ProofDeserializationError::InvalidSectionTable)?;
+            let len = reader
+                .read_u32()
+                .map_err(ProofDeserializationError::InvalidSectionTable)?;
+            entries.push((id, len as usize));
+        }
+
+        // split the remaining bytes into sections
+        let mut offset = 1 + num_sections as usize * 5;
+        let mut sections = BTreeMap::new();
+        for (id, len) in entries {
+            let end = offset
+                .checked_add(len)
+                .filter(|&end| end <= source.len())
+                .ok_or(ProofDeserializationError::InvalidSectionTable(
+                    DeserializationError::UnexpectedEOF,
+                ))?;
+            if sections.insert(id, &source[offset..end]).is_some() {
+                return Err(match ProofSection::from_id(id) {
+                    Some(section) => ProofDeserializationError::DuplicateSection(section),
+                    None => ProofDeserializationError::InvalidSectionTable(
+                        DeserializationError::InvalidValue(format!(
+                            "section {} appears in the section table more than once",
+                            id
+                        )),
+                    ),
+                });
+            }
+            offset = end;
+        }
+
+        if offset != source.len() {
+            return Err(ProofDeserializationError::UnconsumedBytes);
+        }
+
+        Ok(SectionTable { sections })
+    }
+
+    /// Returns the bytes of the specified section.
+    ///
+    /// # Errors
+    /// Returns an error if the section is not present in this table.
+    pub fn get(&self, section: ProofSection) -> Result<&'a [u8], ProofDeserializationError> {
+        self.sections
+            .get(&section.id())
+            .copied()
+            .ok_or(ProofDeserializationError::MissingSection(section))
+    }
+}
@@ -4,10 +4,10 @@
 // LICENSE file in the root directory of this source tree.
 
 use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
-use math::fields::f128;
+use math::fields::{f128, f64};
 use rand_utils::rand_value;
 use winter_crypto::{
-    hashers::{Blake3_256, Rp62_248, Rp64_256, Sha3_256},
+    hashers::{Blake3_256, Keccak256, Poseidon64_256, Rp62_248, Rp64_256, Sha3_256},
     Hasher,
 };
 
@@ -17,9 +17,15 @@ type Blake3Digest = <Blake3 as Hasher>::Digest;
 type Sha3 = Sha3_256<f128::BaseElement>;
 type Sha3Digest = <Sha3 as Hasher>::Digest;
 
+type Keccak = Keccak256<f128::BaseElement>;
+type KeccakDigest = <Keccak as Hasher>::Digest;
+
 type Rp62_248Digest = <Rp62_248 as Hasher>::Digest;
 type Rp64_256Digest = <Rp64_256 as Hasher>::Digest;
 
+type Poseidon = Poseidon64_256<f64::BaseElement>;
+type PoseidonDigest = <Poseidon as Hasher>::Digest;
+
 fn blake3(c: &mut Criterion) {
     let v: [Blake3Digest; 2] = [Blake3::hash(&[1u8]), Blake3::hash(&[2u8])];
     c.bench_function("hash_blake3 (cached)", |bench| {
@@ -60,6 +66,26 @@ fn sha3(c: &mut Criterion) {
     });
 }
 
+fn keccak(c: &mut Criterion) {
+    let v: [KeccakDigest; 2] = [Keccak::hash(&[1u8]), Keccak::hash(&[2u8])];
+    c.bench_function("hash_keccak (cached)", |bench| {
+        bench.iter(|| Keccak::merge(black_box(&v)))
+    });
+
+    c.bench_function("hash_keccak (random)", |b| {
+        b.iter_batched(
+            || {
+                [
+                    Keccak::hash(&rand_value::<u64>().to_le_bytes()),
+                    Keccak::hash(&rand_value::<u64>().to_le_bytes()),
+                ]
+            },
+            |state| Keccak::merge(&state),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
 fn rescue248(c: &mut Criterion) {
     let v: [Rp62_248Digest; 2] = [Rp62_248::hash(&[1u8]), Rp62_248::hash(&[2u8])];
     c.bench_function("hash_rp62_248 (cached)", |bench| {
@@ -100,5 +126,72 @@ fn rescue256(c: &mut Criterion) {
     });
 }
 
-criterion_group!(hash_group, blake3, sha3, rescue248, rescue256);
+/// Compares performance of Rp64_256 with and without SIMD arithmetic; the SIMD backend is the one
+/// detected for the CPU the benchmark runs on.
+#[cfg(feature = "simd")]
+fn rescue256_simd(c: &mut Criterion) {
+    use math::fields::f64::simd::{self, Backend};
+    use winter_crypto::ElementHasher;
+
+    let v: [Rp64_256Digest; 2] = [Rp64_256::hash(&[1u8]), Rp64_256::hash(&[2u8])];
+    let elements: Vec<f64::BaseElement> = (0..100).map(|_| rand_value()).collect();
+    let detected_backend = simd::backend();
+
+    for (name, backend) in [("scalar", Backend::Scalar), ("simd", detected_backend)] {
+        simd::set_backend(backend);
+
+        c.bench_function(&format!("hash_rp64_256 (cached, {})", name), |bench| {
+            bench.iter(|| Rp64_256::merge(black_box(&v)))
+        });
+
+        c.bench_function(
+            &format!("hash_rp64_256 (100 elements, {})", name),
+            |bench| bench.iter(|| Rp64_256::hash_elements(black_box(&elements))),
+        );
+    }
+
+    simd::set_backend(detected_backend);
+}
+
+fn poseidon256(c: &mut Criterion) {
+    let v: [PoseidonDigest; 2] = [Poseidon::hash(&[1u8]), Poseidon::hash(&[2u8])];
+    c.bench_function("hash_poseidon64_256 (cached)", |bench| {
+        bench.iter(|| Poseidon::merge(black_box(&v)))
+    });
+
+    c.bench_function("hash_poseidon64_256 (random)", |b| {
+        b.iter_batched(
+            || {
+                [
+                    Poseidon::hash(&rand_value::<u64>().to_le_bytes()),
+                    Poseidon::hash(&rand_value::<u64>().to_le_bytes()),
+                ]
+            },
+            |state| Poseidon::merge(&state),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+#[cfg(not(feature = "simd"))]
+criterion_group!(
+    hash_group,
+    blake3,
+    sha3,
+    keccak,
+    rescue248,
+    rescue256,
+    poseidon256
+);
+#[cfg(feature = "simd")]
+criterion_group!(
+    hash_group,
+    blake3,
+    sha3,
+    keccak,
+    rescue248,
+    rescue256,
+    rescue256_simd,
+    poseidon256
+);
 criterion_main!(hash_group);
@@ -23,6 +23,10 @@ pub enum MerkleTreeError {
     TooFewLeafIndexes,
     /// Too many leaf index were provided for a batch Merkle proof.
     TooManyLeafIndexes(usize, usize),
+    /// Number of subtrees used to construct a Merkle tree was not a power of two.
+    NumberOfSubtreesNotPowerOfTwo(usize),
+    /// Subtrees used to construct a Merkle tree did not all have the same depth.
+    SubtreeDepthMismatch(usize, usize),
     /// Merkle proof is not valid for the specified position(s).
     InvalidProof,
 }
@@ -64,6 +68,20 @@ impl fmt::Display for MerkleTreeError {
                     max_indexes, num_indexes
                 )
             }
+            Self::NumberOfSubtreesNotPowerOfTwo(num_subtrees) => {
+                write!(
+                    f,
+                    "number of subtrees must be a power of two, but {} were provided",
+                    num_subtrees
+                )
+            }
+            Self::SubtreeDepthMismatch(expected, actual) => {
+                write!(
+                    f,
+                    "all subtrees must have depth {}, but a subtree of depth {} was provided",
+                    expected, actual
+                )
+            }
             Self::InvalidProof => {
                 write!(f, "Merkle proof is invalid")
             }
@@ -7,15 +7,23 @@ use core::{fmt::Debug, slice};
 use math::{FieldElement, StarkField};
 use utils::{ByteReader, Deserializable, DeserializationError, Serializable};
 
+#[cfg(feature = "serde")]
+use core::convert::TryInto;
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
 mod blake;
 pub use blake::{Blake3_192, Blake3_256};
 
 mod sha;
-pub use sha::Sha3_256;
+pub use sha::{Keccak256, Sha3_256};
 
 mod rescue;
 pub use rescue::{Rp62_248, Rp64_256};
 
+mod poseidon;
+pub use poseidon::Poseidon64_256;
+
 // HASHER TRAITS
 // ================================================================================================
 
@@ -122,6 +130,28 @@ impl<const N: usize> Deserializable for ByteDigest<N> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<const N: usize> Serialize for ByteDigest<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        utils::serde_hex::serialize(&self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> Deserialize<'de> for ByteDigest<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = utils::serde_hex::deserialize(deserializer)?;
+        let num_bytes = bytes.len();
+        let value = bytes.try_into().map_err(|_| {
+            D::Error::custom(format!(
+                "invalid digest: expected {} bytes, but was {}",
+                N, num_bytes
+            ))
+        })?;
+        Ok(ByteDigest(value))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{ByteDigest, Digest};
@@ -0,0 +1,812 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{rescue::ElementDigest, ElementHasher, Hasher};
+use core::{convert::TryInto, marker::PhantomData, ops::Range};
+use math::{fields::f64::BaseElement, FieldElement, StarkField};
+use utils::ByteWriter;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Sponge state is set to 12 field elements or 768 bytes; 8 elements are reserved for rate and
+/// the remaining 4 elements are reserved for capacity.
+const STATE_WIDTH: usize = 12;
+
+/// The rate portion of the state is located in elements 4 through 11.
+const RATE_RANGE: Range<usize> = 4..12;
+const RATE_WIDTH: usize = RATE_RANGE.end - RATE_RANGE.start;
+
+const INPUT1_RANGE: Range<usize> = 4..8;
+const INPUT2_RANGE: Range<usize> = 8..12;
+
+/// The capacity portion of the state is located in elements 0, 1, 2, and 3.
+const CAPACITY_RANGE: Range<usize> = 0..4;
+
+/// The output of the hash function is a digest which consists of 4 field elements or 32 bytes.
+///
+/// The digest is returned from state elements 4, 5, 6, and 7 (the first four elements of the
+/// rate portion).
+const DIGEST_RANGE: Range<usize> = 4..8;
+const DIGEST_SIZE: usize = DIGEST_RANGE.end - DIGEST_RANGE.start;
+
+/// The number of full rounds is set to 8 (4 at the beginning and 4 at the end of the
+/// permutation); computed using the script accompanying <https://eprint.iacr.org/2019/458.pdf>.
+const NUM_FULL_ROUNDS: usize = 8;
+const NUM_HALF_FULL_ROUNDS: usize = NUM_FULL_ROUNDS / 2;
+
+/// The number of partial rounds is set to 22 to target 128-bit security level; computed using
+/// the script accompanying <https://eprint.iacr.org/2019/458.pdf>.
+const NUM_PARTIAL_ROUNDS: usize = 22;
+
+const NUM_ROUNDS: usize = NUM_FULL_ROUNDS + NUM_PARTIAL_ROUNDS;
+
+/// S-Box power; the constant is defined for tests only because the exponentiation in the code is
+/// unrolled for efficiency reasons.
+#[cfg(test)]
+const ALPHA: u64 = 7;
+
+// HASHER IMPLEMENTATION
+// ================================================================================================
+
+/// Implementation of [Hasher] trait for Poseidon hash function with 256-bit output.
+///
+/// The hash function is implemented according to the Poseidon
+/// [specifications](https://eprint.iacr.org/2019/458.pdf) with the following exceptions:
+/// * The sponge construction is the same as the one used in [Rp64_256](super::Rp64_256): the
+///   first capacity element is initialized to the number of elements to be hashed, input
+///   sequences are padded with Fp(0) elements only, and capacity occupies the first 4 elements
+///   of the state. Thus, [hash_elements()](Poseidon64_256::hash_elements),
+///   [merge()](Poseidon64_256::merge), and [merge_with_int()](Poseidon64_256::merge_with_int)
+///   are internally consistent, while [hash()](Poseidon64_256::hash) is not consistent with them.
+/// * The permutation is the same as the Poseidon permutation of Polygon Zero's Plonky2. Thus,
+///   instead of round constants generated using the Grain LFSR, we use the round constants of
+///   Plonky2, and unlike in the specifications, all 12 round constants are added to the state
+///   in partial rounds as well. Instead of a Cauchy matrix, we use the MDS matrix of Plonky2;
+///   this matrix is a sum of a circulant matrix with small coefficients and a diagonal matrix,
+///   which makes the linear layer cheap to compute.
+///
+/// Because of the above, [apply_permutation()](Poseidon64_256::apply_permutation) produces the
+/// same outputs as the permutation of Plonky2, but digests are not compatible with the ones
+/// produced by Plonky2, which uses a different sponge construction.
+///
+/// The parameters used to instantiate the function are:
+/// * Field: 64-bit prime field with modulus 2^64 - 2^32 + 1.
+/// * State width: 12 field elements.
+/// * Capacity size: 4 field elements.
+/// * Number of full rounds: 8.
+/// * Number of partial rounds: 22.
+/// * S-Box degree: 7.
+///
+/// The above parameters target 128-bit security level. The digest consists of four field elements
+/// and it can be serialized into 32 bytes (256 bits).
+///
+/// ## Base field
+/// To be usable as one of the hash function options of the STARK protocol, the hasher is generic
+/// over the base field `B` of the elements it hashes, in the same way as BLAKE3 and SHA3 hashers
+/// are. However, the permutation is defined over the 64-bit field only, and thus, the
+/// hasher can be used only with base fields for which [is_supported()](Poseidon64_256::is_supported)
+/// returns true (i.e., fields with modulus 2^64 - 2^32 + 1). Elements of such fields are
+/// absorbed into the state using their canonical representation.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Poseidon64_256<B: StarkField>(PhantomData<B>);
+
+impl<B: StarkField> Hasher for Poseidon64_256<B> {
+    type Digest = ElementDigest;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        // compute the number of elements required to represent the string; we will be processing
+        // the string in 7-byte chunks, thus the number of elements will be equal to the number
+        // of such chunks (including a potential partial chunk at the end).
+        let num_elements = if bytes.len() % 7 == 0 {
+            bytes.len() / 7
+        } else {
+            bytes.len() / 7 + 1
+        };
+
+        // initialize state to all zeros, except for the first element of the capacity part, which
+        // is set to the number of elements to be hashed. this is done so that adding zero elements
+        // at the end of the list always results in a different hash.
+        let mut sponge = Sponge::new(num_elements);
+
+        // break the string into 7-byte chunks, convert each chunk into a field element, and
+        // absorb the element into the rate portion of the state. we use 7-byte chunks because
+        // every 7-byte chunk is guaranteed to map to some field element.
+        let mut buf = [0_u8; 8];
+        for (i, chunk) in bytes.chunks(7).enumerate() {
+            if i < num_elements - 1 {
+                buf[..7].copy_from_slice(chunk);
+            } else {
+                // if we are dealing with the last chunk, it may be smaller than 7 bytes long, so
+                // we need to handle it slightly differently. we also append a byte with value 1
+                // to the end of the string; this pads the string in such a way that adding
+                // trailing zeros results in different hash
+                let chunk_len = chunk.len();
+                buf = [0_u8; 8];
+                buf[..chunk_len].copy_from_slice(chunk);
+                buf[chunk_len] = 1;
+            }
+            sponge.absorb(BaseElement::new(u64::from_le_bytes(buf)));
+        }
+
+        sponge.finalize()
+    }
+
+    fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        // initialize the state by copying the digest elements into the rate portion of the state
+        // (8 total elements), and set the first capacity element to 8 (the number of elements to
+        // be hashed).
+        let mut state = [BaseElement::ZERO; STATE_WIDTH];
+        state[RATE_RANGE].copy_from_slice(Self::Digest::digests_as_elements(values));
+        state[CAPACITY_RANGE.start] = BaseElement::new(RATE_WIDTH as u64);
+
+        // apply the Poseidon permutation and return the first four elements of the rate
+        apply_permutation(&mut state);
+        ElementDigest::new(state[DIGEST_RANGE].try_into().unwrap())
+    }
+
+    fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        // initialize the state as follows:
+        // - seed is copied into the first 4 elements of the rate portion of the state.
+        // - if the value fits into a single field element, copy it into the fifth rate element
+        //   and set the first capacity element to 5 (the number of elements to be hashed).
+        // - if the value doesn't fit into a single field element, split it into two field
+        //   elements, copy them into rate elements 5 and 6, and set the first capacity element
+        //   to 6.
+        let mut state = [BaseElement::ZERO; STATE_WIDTH];
+        state[INPUT1_RANGE].copy_from_slice(seed.as_elements());
+        state[INPUT2_RANGE.start] = BaseElement::new(value);
+        if value < BaseElement::MODULUS {
+            state[CAPACITY_RANGE.start] = BaseElement::new(DIGEST_SIZE as u64 + 1);
+        } else {
+            state[INPUT2_RANGE.start + 1] = BaseElement::new(value / BaseElement::MODULUS);
+            state[CAPACITY_RANGE.start] = BaseElement::new(DIGEST_SIZE as u64 + 2);
+        }
+
+        // apply the Poseidon permutation and return the first four elements of the rate
+        apply_permutation(&mut state);
+        ElementDigest::new(state[DIGEST_RANGE].try_into().unwrap())
+    }
+}
+
+impl<B: StarkField> ElementHasher for Poseidon64_256<B> {
+    type BaseField = B;
+
+    fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
+        debug_assert!(
+            Self::is_supported(),
+            "base field is not supported by Poseidon64_256"
+        );
+
+        // initialize state to all zeros, except for the first element of the capacity part, which
+        // is set to the number of base field elements to be hashed.
+        let num_elements = E::as_base_elements(elements).len();
+        let mut sponge = Sponge::new(num_elements);
+
+        // serialize the elements into the sponge; every base field element is absorbed into the
+        // state using its canonical representation.
+        sponge.write(elements);
+        sponge.finalize()
+    }
+}
+
+// HASH FUNCTION IMPLEMENTATION
+// ================================================================================================
+
+impl<B: StarkField> Poseidon64_256<B> {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The number of full rounds is set to 8; 4 full rounds are applied at the beginning and 4
+    /// full rounds are applied at the end of the permutation.
+    pub const NUM_FULL_ROUNDS: usize = NUM_FULL_ROUNDS;
+
+    /// The number of partial rounds is set to 22.
+    pub const NUM_PARTIAL_ROUNDS: usize = NUM_PARTIAL_ROUNDS;
+
+    /// Sponge state is set to 12 field elements or 768 bytes; 8 elements are reserved for rate and
+    /// the remaining 4 elements are reserved for capacity.
+    pub const STATE_WIDTH: usize = STATE_WIDTH;
+
+    /// The rate portion of the state is located in elements 4 through 11 (inclusive).
+    pub const RATE_RANGE: Range<usize> = RATE_RANGE;
+
+    /// The capacity portion of the state is located in elements 0, 1, 2, and 3.
+    pub const CAPACITY_RANGE: Range<usize> = CAPACITY_RANGE;
+
+    /// The output of the hash function can be read from state elements 4, 5, 6, and 7.
+    pub const DIGEST_RANGE: Range<usize> = DIGEST_RANGE;
+
+    /// First row of the circulant part of the MDS matrix.
+    pub const MDS_CIRC: [u64; STATE_WIDTH] = MDS_CIRC;
+
+    /// Diagonal part of the MDS matrix.
+    pub const MDS_DIAG: [u64; STATE_WIDTH] = MDS_DIAG;
+
+    /// Round constants added to the hasher state at the beginning of every round.
+    pub const ARK: [[BaseElement; STATE_WIDTH]; NUM_ROUNDS] = ARK;
+
+    // PUBLIC FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if elements of the base field `B` can be hashed with this hasher; this is the
+    /// case only if the modulus of `B` is 2^64 - 2^32 + 1.
+    pub fn is_supported() -> bool {
+        B::get_modulus_le_bytes() == BaseElement::get_modulus_le_bytes()
+    }
+
+    /// Applies Poseidon permutation to the provided state.
+    pub fn apply_permutation(state: &mut [BaseElement; STATE_WIDTH]) {
+        apply_permutation(state)
+    }
+}
+
+// POSEIDON PERMUTATION
+// ================================================================================================
+
+/// Applies Poseidon permutation to the provided state; the permutation consists of 4 full rounds,
+/// followed by 22 partial rounds, followed by 4 more full rounds.
+fn apply_permutation(state: &mut [BaseElement; STATE_WIDTH]) {
+    let mut round = 0;
+    for _ in 0..NUM_HALF_FULL_ROUNDS {
+        apply_full_round(state, round);
+        round += 1;
+    }
+    for _ in 0..NUM_PARTIAL_ROUNDS {
+        apply_partial_round(state, round);
+        round += 1;
+    }
+    for _ in 0..NUM_HALF_FULL_ROUNDS {
+        apply_full_round(state, round);
+        round += 1;
+    }
+}
+
+/// Poseidon full round function: the S-Box is applied to all elements of the state.
+#[inline(always)]
+fn apply_full_round(state: &mut [BaseElement; STATE_WIDTH], round: usize) {
+    add_constants(state, &ARK[round]);
+    state.iter_mut().for_each(|s| *s = s.exp7());
+    apply_mds(state);
+}
+
+/// Poseidon partial round function: the S-Box is applied to the first element of the state only.
+#[inline(always)]
+fn apply_partial_round(state: &mut [BaseElement; STATE_WIDTH], round: usize) {
+    add_constants(state, &ARK[round]);
+    state[0] = state[0].exp7();
+    apply_mds(state);
+}
+
+#[inline(always)]
+fn add_constants(state: &mut [BaseElement; STATE_WIDTH], ark: &[BaseElement; STATE_WIDTH]) {
+    state.iter_mut().zip(ark).for_each(|(s, &k)| *s += k);
+}
+
+/// Multiplies the state by the MDS matrix. Coefficients of the matrix are small, and thus, the
+/// products are accumulated as 128-bit integers and are reduced only once per state element.
+#[inline(always)]
+fn apply_mds(state: &mut [BaseElement; STATE_WIDTH]) {
+    let values = state.map(|s| s.as_int() as u128);
+    for (r, s) in state.iter_mut().enumerate() {
+        let mut acc = values[r] * MDS_DIAG[r] as u128;
+        for (i, &c) in MDS_CIRC.iter().enumerate() {
+            acc += values[(i + r) % STATE_WIDTH] * c as u128;
+        }
+        *s = BaseElement::from(acc);
+    }
+}
+
+// SPONGE
+// ================================================================================================
+
+/// Absorbs a sequence of field elements into the rate portion of Poseidon sponge state.
+///
+/// The sponge implements [ByteWriter] trait so that field elements of any field with modulus
+/// 2^64 - 2^32 + 1 can be serialized into it directly; every 8 bytes written into the sponge are
+/// interpreted as a single field element.
+struct Sponge {
+    state: [BaseElement; STATE_WIDTH],
+    rate_idx: usize,
+    buf: [u8; 8],
+    buf_len: usize,
+}
+
+impl Sponge {
+    /// Returns a new sponge for hashing the specified number of elements.
+    fn new(num_elements: usize) -> Self {
+        let mut state = [BaseElement::ZERO; STATE_WIDTH];
+        state[CAPACITY_RANGE.start] = BaseElement::new(num_elements as u64);
+        Self {
+            state,
+            rate_idx: 0,
+            buf: [0; 8],
+            buf_len: 0,
+        }
+    }
+
+    /// Absorbs the element into the rate portion of the state; once the rate is filled up, the
+    /// permutation is applied and absorption starts again from the first rate element.
+    fn absorb(&mut self, element: BaseElement) {
+        self.state[RATE_RANGE.start + self.rate_idx] += element;
+        self.rate_idx += 1;
+        if self.rate_idx == RATE_WIDTH {
+            apply_permutation(&mut self.state);
+            self.rate_idx = 0;
+        }
+    }
+
+    /// Returns the digest of all absorbed elements.
+    ///
+    /// If some of the elements were absorbed without applying a permutation to them (would
+    /// happen when the number of elements is not a multiple of RATE_WIDTH), the permutation is
+    /// applied. No extra padding is needed because the total number of elements was injected
+    /// into the capacity portion of the state during initialization.
+    fn finalize(mut self) -> ElementDigest {
+        debug_assert_eq!(0, self.buf_len, "partially written element");
+        if self.rate_idx > 0 {
+            apply_permutation(&mut self.state);
+        }
+        ElementDigest::new(self.state[DIGEST_RANGE].try_into().unwrap())
+    }
+}
+
+impl ByteWriter for Sponge {
+    fn write_u8(&mut self, value: u8) {
+        self.buf[self.buf_len] = value;
+        self.buf_len += 1;
+        if self.buf_len == self.buf.len() {
+            self.absorb(BaseElement::new(u64::from_le_bytes(self.buf)));
+            self.buf_len = 0;
+        }
+    }
+
+    fn write_u8_slice(&mut self, values: &[u8]) {
+        for &value in values {
+            self.write_u8(value);
+        }
+    }
+}
+
+// MDS
+// ================================================================================================
+
+/// First row of the circulant part of the MDS matrix; the matrix is the same as the one used in
+/// Poseidon instantiation of Plonky2.
+const MDS_CIRC: [u64; STATE_WIDTH] = [17, 15, 41, 16, 2, 28, 13, 13, 39, 18, 34, 20];
+
+/// Diagonal part of the MDS matrix.
+const MDS_DIAG: [u64; STATE_WIDTH] = [8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+// ROUND CONSTANTS
+// ================================================================================================
+
+/// Poseidon round constants; the constants are the same as the ones used in Poseidon
+/// instantiation of Plonky2 (see `ALL_ROUND_CONSTANTS` in `plonky2/src/hash/poseidon.rs`).
+const ARK: [[BaseElement; STATE_WIDTH]; NUM_ROUNDS] = [
+    [
+        BaseElement::new(0xb585f766f2144405),
+        BaseElement::new(0x7746a55f43921ad7),
+        BaseElement::new(0xb2fb0d31cee799b4),
+        BaseElement::new(0x0f6760a4803427d7),
+        BaseElement::new(0xe10d666650f4e012),
+        BaseElement::new(0x8cae14cb07d09bf1),
+        BaseElement::new(0xd438539c95f63e9f),
+        BaseElement::new(0xef781c7ce35b4c3d),
+        BaseElement::new(0xcdc4a239b0c44426),
+        BaseElement::new(0x277fa208bf337bff),
+        BaseElement::new(0xe17653a29da578a1),
+        BaseElement::new(0xc54302f225db2c76),
+    ],
+    [
+        BaseElement::new(0x86287821f722c881),
+        BaseElement::new(0x59cd1a8a41c18e55),
+        BaseElement::new(0xc3b919ad495dc574),
+        BaseElement::new(0xa484c4c5ef6a0781),
+        BaseElement::new(0x308bbd23dc5416cc),
+        BaseElement::new(0x6e4a40c18f30c09c),
+        BaseElement::new(0x9a2eedb70d8f8cfa),
+        BaseElement::new(0xe360c6e0ae486f38),
+        BaseElement::new(0xd5c7718fbfc647fb),
+        BaseElement::new(0xc35eae071903ff0b),
+        BaseElement::new(0x849c2656969c4be7),
+        BaseElement::new(0xc0572c8c08cbbbad),
+    ],
+    [
+        BaseElement::new(0xe9fa634a21de0082),
+        BaseElement::new(0xf56f6d48959a600d),
+        BaseElement::new(0xf7d713e806391165),
+        BaseElement::new(0x8297132b32825daf),
+        BaseElement::new(0xad6805e0e30b2c8a),
+        BaseElement::new(0xac51d9f5fcf8535e),
+        BaseElement::new(0x502ad7dc18c2ad87),
+        BaseElement::new(0x57a1550c110b3041),
+        BaseElement::new(0x66bbd30e6ce0e583),
+        BaseElement::new(0x0da2abef589d644e),
+        BaseElement::new(0xf061274fdb150d61),
+        BaseElement::new(0x28b8ec3ae9c29633),
+    ],
+    [
+        BaseElement::new(0x92a756e67e2b9413),
+        BaseElement::new(0x70e741ebfee96586),
+        BaseElement::new(0x019d5ee2af82ec1c),
+        BaseElement::new(0x6f6f2ed772466352),
+        BaseElement::new(0x7cf416cfe7e14ca1),
+        BaseElement::new(0x61df517b86a46439),
+        BaseElement::new(0x85dc499b11d77b75),
+        BaseElement::new(0x4b959b48b9c10733),
+        BaseElement::new(0xe8be3e5da8043e57),
+        BaseElement::new(0xf5c0bc1de6da8699),
+        BaseElement::new(0x40b12cbf09ef74bf),
+        BaseElement::new(0xa637093ecb2ad631),
+    ],
+    [
+        BaseElement::new(0x3cc3f892184df408),
+        BaseElement::new(0x2e479dc157bf31bb),
+        BaseElement::new(0x6f49de07a6234346),
+        BaseElement::new(0x213ce7bede378d7b),
+        BaseElement::new(0x5b0431345d4dea83),
+        BaseElement::new(0xa2de45780344d6a1),
+        BaseElement::new(0x7103aaf94a7bf308),
+        BaseElement::new(0x5326fc0d97279301),
+        BaseElement::new(0xa9ceb74fec024747),
+        BaseElement::new(0x27f8ec88bb21b1a3),
+        BaseElement::new(0xfceb4fda1ded0893),
+        BaseElement::new(0xfac6ff1346a41675),
+    ],
+    [
+        BaseElement::new(0x7131aa45268d7d8c),
+        BaseElement::new(0x9351036095630f9f),
+        BaseElement::new(0xad535b24afc26bfb),
+        BaseElement::new(0x4627f5c6993e44be),
+        BaseElement::new(0x645cf794b8f1cc58),
+        BaseElement::new(0x241c70ed0af61617),
+        BaseElement::new(0xacb8e076647905f1),
+        BaseElement::new(0x3737e9db4c4f474d),
+        BaseElement::new(0xe7ea5e33e75fffb6),
+        BaseElement::new(0x90dee49fc9bfc23a),
+        BaseElement::new(0xd1b1edf76bc09c92),
+        BaseElement::new(0x0b65481ba645c602),
+    ],
+    [
+        BaseElement::new(0x99ad1aab0814283b),
+        BaseElement::new(0x438a7c91d416ca4d),
+        BaseElement::new(0xb60de3bcc5ea751c),
+        BaseElement::new(0xc99cab6aef6f58bc),
+        BaseElement::new(0x69a5ed92a72ee4ff),
+        BaseElement::new(0x5e7b329c1ed4ad71),
+        BaseElement::new(0x5fc0ac0800144885),
+        BaseElement::new(0x32db829239774eca),
+        BaseElement::new(0x0ade699c5830f310),
+        BaseElement::new(0x7cc5583b10415f21),
+        BaseElement::new(0x85df9ed2e166d64f),
+        BaseElement::new(0x6604df4fee32bcb1),
+    ],
+    [
+        BaseElement::new(0xeb84f608da56ef48),
+        BaseElement::new(0xda608834c40e603d),
+        BaseElement::new(0x8f97fe408061f183),
+        BaseElement::new(0xa93f485c96f37b89),
+        BaseElement::new(0x6704e8ee8f18d563),
+        BaseElement::new(0xcee3e9ac1e072119),
+        BaseElement::new(0x510d0e65e2b470c1),
+        BaseElement::new(0xf6323f486b9038f0),
+        BaseElement::new(0x0b508cdeffa5ceef),
+        BaseElement::new(0xf2417089e4fb3cbd),
+        BaseElement::new(0x60e75c2890d15730),
+        BaseElement::new(0xa6217d8bf660f29c),
+    ],
+    [
+        BaseElement::new(0x7159cd30c3ac118e),
+        BaseElement::new(0x839b4e8fafead540),
+        BaseElement::new(0x0d3f3e5e82920adc),
+        BaseElement::new(0x8f7d83bddee7bba8),
+        BaseElement::new(0x780f2243ea071d06),
+        BaseElement::new(0xeb915845f3de1634),
+        BaseElement::new(0xd19e120d26b6f386),
+        BaseElement::new(0x016ee53a7e5fecc6),
+        BaseElement::new(0xcb5fd54e7933e477),
+        BaseElement::new(0xacb8417879fd449f),
+        BaseElement::new(0x9c22190be7f74732),
+        BaseElement::new(0x5d693c1ba3ba3621),
+    ],
+    [
+        BaseElement::new(0xdcef0797c2b69ec7),
+        BaseElement::new(0x3d639263da827b13),
+        BaseElement::new(0xe273fd971bc8d0e7),
+        BaseElement::new(0x418f02702d227ed5),
+        BaseElement::new(0x8c25fda3b503038c),
+        BaseElement::new(0x2cbaed4daec8c07c),
+        BaseElement::new(0x5f58e6afcdd6ddc2),
+        BaseElement::new(0x284650ac5e1b0eba),
+        BaseElement::new(0x635b337ee819dab5),
+        BaseElement::new(0x9f9a036ed4f2d49f),
+        BaseElement::new(0xb93e260cae5c170e),
+        BaseElement::new(0xb0a7eae879ddb76d),
+    ],
+    [
+        BaseElement::new(0xd0762cbc8ca6570c),
+        BaseElement::new(0x34c6efb812b04bf5),
+        BaseElement::new(0x40bf0ab5fa14c112),
+        BaseElement::new(0xb6b570fc7c5740d3),
+        BaseElement::new(0x5a27b9002de33454),
+        BaseElement::new(0xb1a5b165b6d2b2d2),
+        BaseElement::new(0x8722e0ace9d1be22),
+        BaseElement::new(0x788ee3b37e5680fb),
+        BaseElement::new(0x14a726661551e284),
+        BaseElement::new(0x98b7672f9ef3b419),
+        BaseElement::new(0xbb93ae776bb30e3a),
+        BaseElement::new(0x28fd3b046380f850),
+    ],
+    [
+        BaseElement::new(0x30a4680593258387),
+        BaseElement::new(0x337dc00c61bd9ce1),
+        BaseElement::new(0xd5eca244c7a4ff1d),
+        BaseElement::new(0x7762638264d279bd),
+        BaseElement::new(0xc1e434bedeefd767),
+        BaseElement::new(0x0299351a53b8ec22),
+        BaseElement::new(0xb2d456e4ad251b80),
+        BaseElement::new(0x3e9ed1fda49cea0b),
+        BaseElement::new(0x2972a92ba450bed8),
+        BaseElement::new(0x20216dd77be493de),
+        BaseElement::new(0xadffe8cf28449ec6),
+        BaseElement::new(0x1c4dbb1c4c27d243),
+    ],
+    [
+        BaseElement::new(0x15a16a8a8322d458),
+        BaseElement::new(0x388a128b7fd9a609),
+        BaseElement::new(0x2300e5d6baedf0fb),
+        BaseElement::new(0x2f63aa8647e15104),
+        BaseElement::new(0xf1c36ce86ecec269),
+        BaseElement::new(0x27181125183970c9),
+        BaseElement::new(0xe584029370dca96d),
+        BaseElement::new(0x4d9bbc3e02f1cfb2),
+        BaseElement::new(0xea35bc29692af6f8),
+        BaseElement::new(0x18e21b4beabb4137),
+        BaseElement::new(0x1e3b9fc625b554f4),
+        BaseElement::new(0x25d64362697828fd),
+    ],
+    [
+        BaseElement::new(0x5a3f1bb1c53a9645),
+        BaseElement::new(0xdb7f023869fb8d38),
+        BaseElement::new(0xb462065911d4e1fc),
+        BaseElement::new(0x49c24ae4437d8030),
+        BaseElement::new(0xd793862c112b0566),
+        BaseElement::new(0xaadd1106730d8feb),
+        BaseElement::new(0xc43b6e0e97b0d568),
+        BaseElement::new(0xe29024c18ee6fca2),
+        BaseElement::new(0x5e50c27535b88c66),
+        BaseElement::new(0x10383f20a4ff9a87),
+        BaseElement::new(0x38e8ee9d71a45af8),
+        BaseElement::new(0xdd5118375bf1a9b9),
+    ],
+    [
+        BaseElement::new(0x775005982d74d7f7),
+        BaseElement::new(0x86ab99b4dde6c8b0),
+        BaseElement::new(0xb1204f603f51c080),
+        BaseElement::new(0xef61ac8470250ecf),
+        BaseElement::new(0x1bbcd90f132c603f),
+        BaseElement::new(0x0cd1dabd964db557),
+        BaseElement::new(0x11a3ae5beb9d1ec9),
+        BaseElement::new(0xf755bfeea585d11d),
+        BaseElement::new(0xa3b83250268ea4d7),
+        BaseElement::new(0x516306f4927c93af),
+        BaseElement::new(0xddb4ac49c9efa1da),
+        BaseElement::new(0x64bb6dec369d4418),
+    ],
+    [
+        BaseElement::new(0xf9cc95c22b4c1fcc),
+        BaseElement::new(0x08d37f755f4ae9f6),
+        BaseElement::new(0xeec49b613478675b),
+        BaseElement::new(0xf143933aed25e0b0),
+        BaseElement::new(0xe4c5dd8255dfc622),
+        BaseElement::new(0xe7ad7756f193198e),
+        BaseElement::new(0x92c2318b87fff9cb),
+        BaseElement::new(0x739c25f8fd73596d),
+        BaseElement::new(0x5636cac9f16dfed0),
+        BaseElement::new(0xdd8f909a938e0172),
+        BaseElement::new(0xc6401fe115063f5b),
+        BaseElement::new(0x8ad97b33f1ac1455),
+    ],
+    [
+        BaseElement::new(0x0c49366bb25e8513),
+        BaseElement::new(0x0784d3d2f1698309),
+        BaseElement::new(0x530fb67ea1809a81),
+        BaseElement::new(0x410492299bb01f49),
+        BaseElement::new(0x139542347424b9ac),
+        BaseElement::new(0x9cb0bd5ea1a1115e),
+        BaseElement::new(0x02e3f615c38f49a1),
+        BaseElement::new(0x985d4f4a9c5291ef),
+        BaseElement::new(0x775b9feafdcd26e7),
+        BaseElement::new(0x304265a6384f0f2d),
+        BaseElement::new(0x593664c39773012c),
+        BaseElement::new(0x4f0a2e5fb028f2ce),
+    ],
+    [
+        BaseElement::new(0xdd611f1000c17442),
+        BaseElement::new(0xd8185f9adfea4fd0),
+        BaseElement::new(0xef87139ca9a3ab1e),
+        BaseElement::new(0x3ba71336c34ee133),
+        BaseElement::new(0x7d3a455d56b70238),
+        BaseElement::new(0x660d32e130182684),
+        BaseElement::new(0x297a863f48cd1f43),
+        BaseElement::new(0x90e0a736a751ebb7),
+        BaseElement::new(0x549f80ce550c4fd3),
+        BaseElement::new(0x0f73b2922f38bd64),
+        BaseElement::new(0x16bf1f73fb7a9c3f),
+        BaseElement::new(0x6d1f5a59005bec17),
+    ],
+    [
+        BaseElement::new(0x02ff876fa5ef97c4),
+        BaseElement::new(0xc5cb72a2a51159b0),
+        BaseElement::new(0x8470f39d2d5c900e),
+        BaseElement::new(0x25abb3f1d39fcb76),
+        BaseElement::new(0x23eb8cc9b372442f),
+        BaseElement::new(0xd687ba55c64f6364),
+        BaseElement::new(0xda8d9e90fd8ff158),
+        BaseElement::new(0xe3cbdc7d2fe45ea7),
+        BaseElement::new(0xb9a8c9b3aee52297),
+        BaseElement::new(0xc0d28a5c10960bd3),
+        BaseElement::new(0x45d7ac9b68f71a34),
+        BaseElement::new(0xeeb76e397069e804),
+    ],
+    [
+        BaseElement::new(0x3d06c8bd1514e2d9),
+        BaseElement::new(0x9c9c98207cb10767),
+        BaseElement::new(0x65700b51aedfb5ef),
+        BaseElement::new(0x911f451539869408),
+        BaseElement::new(0x7ae6849fbc3a0ec6),
+        BaseElement::new(0x3bb340eba06afe7e),
+        BaseElement::new(0xb46e9d8b682ea65e),
+        BaseElement::new(0x8dcf22f9a3b34356),
+        BaseElement::new(0x77bdaeda586257a7),
+        BaseElement::new(0xf19e400a5104d20d),
+        BaseElement::new(0xc368a348e46d950f),
+        BaseElement::new(0x9ef1cd60e679f284),
+    ],
+    [
+        BaseElement::new(0xe89cd854d5d01d33),
+        BaseElement::new(0x5cd377dc8bb882a2),
+        BaseElement::new(0xa7b0fb7883eee860),
+        BaseElement::new(0x7684403ec392950d),
+        BaseElement::new(0x5fa3f06f4fed3b52),
+        BaseElement::new(0x8df57ac11bc04831),
+        BaseElement::new(0x2db01efa1e1e1897),
+        BaseElement::new(0x54846de4aadb9ca2),
+        BaseElement::new(0xba6745385893c784),
+        BaseElement::new(0x541d496344d2c75b),
+        BaseElement::new(0xe909678474e687fe),
+        BaseElement::new(0xdfe89923f6c9c2ff),
+    ],
+    [
+        BaseElement::new(0xece5a71e0cfedc75),
+        BaseElement::new(0x5ff98fd5d51fe610),
+        BaseElement::new(0x83e8941918964615),
+        BaseElement::new(0x5922040b47f150c1),
+        BaseElement::new(0xf97d750e3dd94521),
+        BaseElement::new(0x5080d4c2b86f56d7),
+        BaseElement::new(0xa7de115b56c78d70),
+        BaseElement::new(0x6a9242ac87538194),
+        BaseElement::new(0xf7856ef7f9173e44),
+        BaseElement::new(0x2265fc92feb0dc09),
+        BaseElement::new(0x17dfc8e4f7ba8a57),
+        BaseElement::new(0x9001a64209f21db8),
+    ],
+    [
+        BaseElement::new(0x90004c1371b893c5),
+        BaseElement::new(0xb932b7cf752e5545),
+        BaseElement::new(0xa0b1df81b6fe59fc),
+        BaseElement::new(0x8ef1dd26770af2c2),
+        BaseElement::new(0x0541a4f9cfbeed35),
+        BaseElement::new(0x9e61106178bfc530),
+        BaseElement::new(0xb3767e80935d8af2),
+        BaseElement::new(0x0098d5782065af06),
+        BaseElement::new(0x31d191cd5c1466c7),
+        BaseElement::new(0x410fefafa319ac9d),
+        BaseElement::new(0xbdf8f242e316c4ab),
+        BaseElement::new(0x9e8cd55b57637ed0),
+    ],
+    [
+        BaseElement::new(0xde122bebe9a39368),
+        BaseElement::new(0x4d001fd58f002526),
+        BaseElement::new(0xca6637000eb4a9f8),
+        BaseElement::new(0x2f2339d624f91f78),
+        BaseElement::new(0x6d1a7918c80df518),
+        BaseElement::new(0xdf9a4939342308e9),
+        BaseElement::new(0xebc2151ee6c8398c),
+        BaseElement::new(0x03cc2ba8a1116515),
+        BaseElement::new(0xd341d037e840cf83),
+        BaseElement::new(0x387cb5d25af4afcc),
+        BaseElement::new(0xbba2515f22909e87),
+        BaseElement::new(0x7248fe7705f38e47),
+    ],
+    [
+        BaseElement::new(0x4d61e56a525d225a),
+        BaseElement::new(0x262e963c8da05d3d),
+        BaseElement::new(0x59e89b094d220ec2),
+        BaseElement::new(0x055d5b52b78b9c5e),
+        BaseElement::new(0x82b27eb33514ef99),
+        BaseElement::new(0xd30094ca96b7ce7b),
+        BaseElement::new(0xcf5cb381cd0a1535),
+        BaseElement::new(0xfeed4db6919e5a7c),
+        BaseElement::new(0x41703f53753be59f),
+        BaseElement::new(0x5eeea940fcde8b6f),
+        BaseElement::new(0x4cd1f1b175100206),
+        BaseElement::new(0x4a20358574454ec0),
+    ],
+    [
+        BaseElement::new(0x1478d361dbbf9fac),
+        BaseElement::new(0x6f02dc07d141875c),
+        BaseElement::new(0x296a202ed8e556a2),
+        BaseElement::new(0x2afd67999bf32ee5),
+        BaseElement::new(0x7acfd96efa95491d),
+        BaseElement::new(0x6798ba0c0abb2c6d),
+        BaseElement::new(0x34c6f57b26c92122),
+        BaseElement::new(0x5736e1bad206b5de),
+        BaseElement::new(0x20057d2a0056521b),
+        BaseElement::new(0x3dea5bd5d0578bd7),
+        BaseElement::new(0x16e50d897d4634ac),
+        BaseElement::new(0x29bff3ecb9b7a6e3),
+    ],
+    [
+        BaseElement::new(0x475cd3205a3bdcde),
+        BaseElement::new(0x18a42105c31b7e88),
+        BaseElement::new(0x023e7414af663068),
+        BaseElement::new(0x15147108121967d7),
+        BaseElement::new(0xe4a3dff1d7d6fef9),
+        BaseElement::new(0x01a8d1a588085737),
+        BaseElement::new(0x11b4c74eda62beef),
+        BaseElement::new(0xe587cc0d69a73346),
+        BaseElement::new(0x1ff7327017aa2a6e),
+        BaseElement::new(0x594e29c42473d06b),
+        BaseElement::new(0xf6f31db1899b12d5),
+        BaseElement::new(0xc02ac5e47312d3ca),
+    ],
+    [
+        BaseElement::new(0xe70201e960cb78b8),
+        BaseElement::new(0x6f90ff3b6a65f108),
+        BaseElement::new(0x42747a7245e7fa84),
+        BaseElement::new(0xd1f507e43ab749b2),
+        BaseElement::new(0x1c86d265f15750cd),
+        BaseElement::new(0x3996ce73dd832c1c),
+        BaseElement::new(0x8e7fba02983224bd),
+        BaseElement::new(0xba0dec7103255dd4),
+        BaseElement::new(0x9e9cbd781628fc5b),
+        BaseElement::new(0xdae8645996edd6a5),
+        BaseElement::new(0xdebe0853b1a1d378),
+        BaseElement::new(0xa49229d24d014343),
+    ],
+    [
+        BaseElement::new(0x7be5b9ffda905e1c),
+        BaseElement::new(0xa3c95eaec244aa30),
+        BaseElement::new(0x0230bca8f4df0544),
+        BaseElement::new(0x4135c2bebfe148c6),
+        BaseElement::new(0x166fc0cc438a3c72),
+        BaseElement::new(0x3762b59a8ae83efa),
+        BaseElement::new(0xe8928a4c89114750),
+        BaseElement::new(0x2a440b51a4945ee5),
+        BaseElement::new(0x80cefd2b7d99ff83),
+        BaseElement::new(0xbb9879c6e61fd62a),
+        BaseElement::new(0x6e7c8f1a84265034),
+        BaseElement::new(0x164bb2de1bbeddc8),
+    ],
+    [
+        BaseElement::new(0xf3c12fe54d5c653b),
+        BaseElement::new(0x40b9e922ed9771e2),
+        BaseElement::new(0x551f5b0fbe7b1840),
+        BaseElement::new(0x25032aa7c4cb1811),
+        BaseElement::new(0xaaed34074b164346),
+        BaseElement::new(0x8ffd96bbf9c9c81d),
+        BaseElement::new(0x70fc91eb5937085c),
+        BaseElement::new(0x7f795e2a5f915440),
+        BaseElement::new(0x4543d9df5476d3cb),
+        BaseElement::new(0xf172d73e004fc90d),
+        BaseElement::new(0xdfd1c4febcc81238),
+        BaseElement::new(0xbc8dfb627fe558fc),
+    ],
+];
@@ -0,0 +1,276 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{
+    BaseElement, ElementDigest, ElementHasher, FieldElement, Hasher, StarkField, ALPHA, MDS_CIRC,
+    MDS_DIAG, STATE_WIDTH,
+};
+use core::convert::TryInto;
+use math::fields::{f128, f64::BaseElement as F64, QuadExtension};
+use rand_utils::{rand_array, rand_value};
+
+type Poseidon64_256 = super::Poseidon64_256<BaseElement>;
+
+#[test]
+fn mds_is_invertible() {
+    // build the MDS matrix and make sure its determinant is not zero using Gaussian elimination
+    let mut matrix = [[BaseElement::ZERO; STATE_WIDTH]; STATE_WIDTH];
+    for (r, row) in matrix.iter_mut().enumerate() {
+        for (i, &c) in MDS_CIRC.iter().enumerate() {
+            row[(i + r) % STATE_WIDTH] += BaseElement::new(c);
+        }
+        row[r] += BaseElement::new(MDS_DIAG[r]);
+    }
+
+    for col in 0..STATE_WIDTH {
+        let pivot = (col..STATE_WIDTH)
+            .find(|&r| matrix[r][col] != BaseElement::ZERO)
+            .expect("MDS matrix is singular");
+        matrix.swap(col, pivot);
+        let (top, bottom) = matrix.split_at_mut(col + 1);
+        let pivot_row = &top[col];
+        let inv = pivot_row[col].inv();
+        for row in bottom.iter_mut() {
+            let factor = row[col] * inv;
+            for (value, &p) in row.iter_mut().zip(pivot_row.iter()).skip(col) {
+                *value -= factor * p;
+            }
+        }
+    }
+}
+
+#[test]
+fn apply_mds() {
+    let state: [BaseElement; STATE_WIDTH] = rand_array();
+
+    let mut expected = [BaseElement::ZERO; STATE_WIDTH];
+    for (r, e) in expected.iter_mut().enumerate() {
+        for (i, &c) in MDS_CIRC.iter().enumerate() {
+            *e += BaseElement::new(c) * state[(i + r) % STATE_WIDTH];
+        }
+        *e += BaseElement::new(MDS_DIAG[r]) * state[r];
+    }
+
+    let mut actual = state;
+    super::apply_mds(&mut actual);
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_sbox() {
+    let e: BaseElement = rand_value();
+    assert_eq!(e.exp(ALPHA), e.exp7());
+}
+
+#[test]
+fn apply_permutation() {
+    // test vectors for Poseidon permutation of Plonky2 (see `test_vectors()` in
+    // `plonky2/src/hash/poseidon_goldilocks.rs`); inputs are all zeros, integers 0..12, all -1's,
+    // and random elements
+    let neg_one = BaseElement::MODULUS - 1;
+    let test_vectors: [([u64; STATE_WIDTH], [u64; STATE_WIDTH]); 4] = [
+        (
+            [0; STATE_WIDTH],
+            [
+                0x3c18a9786cb0b359,
+                0xc4055e3364a246c3,
+                0x7953db0ab48808f4,
+                0xc71603f33a1144ca,
+                0xd7709673896996dc,
+                0x46a84e87642f44ed,
+                0xd032648251ee0b3c,
+                0x1c687363b207df62,
+                0xdf8565563e8045fe,
+                0x40f5b37ff4254dae,
+                0xd070f637b431067c,
+                0x1792b1c4342109d7,
+            ],
+        ),
+        (
+            [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11],
+            [
+                0xd64e1e3efc5b8e9e,
+                0x53666633020aaa47,
+                0xd40285597c6a8825,
+                0x613a4f81e81231d2,
+                0x414754bfebd051f0,
+                0xcb1f8980294a023f,
+                0x6eb2a9e4d54a9d0f,
+                0x1902bc3af467e056,
+                0xf045d5eafdc6021f,
+                0xe4150f77caaa3be5,
+                0xc9bfd01d39b50cce,
+                0x5c0a27fcb0e1459b,
+            ],
+        ),
+        (
+            [neg_one; STATE_WIDTH],
+            [
+                0xbe0085cfc57a8357,
+                0xd95af71847d05c09,
+                0xcf55a13d33c1c953,
+                0x95803a74f4530e82,
+                0xfcd99eb30a135df1,
+                0xe095905e913a3029,
+                0xde0392461b42919b,
+                0x7d3260e24e81d031,
+                0x10d3d0465d9deaa0,
+                0xa87571083dfc2a47,
+                0xe18263681e9958f8,
+                0xe28e96f1ae5e60d3,
+            ],
+        ),
+        (
+            [
+                0x8ccbbbea4fe5d2b7,
+                0xc2af59ee9ec49970,
+                0x90f7e1a9e658446a,
+                0xdcc0630a3ab8b1b8,
+                0x7ff8256bca20588c,
+                0x5d99a7ca0c44ecfb,
+                0x48452b17a70fbee3,
+                0xeb09d654690b6c88,
+                0x4a55d3a39c676a88,
+                0xc0407a38d2285139,
+                0xa234bac9356386d1,
+                0xe1633f2bad98a52f,
+            ],
+            [
+                0xa89280105650c4ec,
+                0xab542d53860d12ed,
+                0x5704148e9ccab94f,
+                0xd3a826d4b62da9f5,
+                0x8a7a6ca87892574f,
+                0xc7017e1cad1a674e,
+                0x1f06668922318e34,
+                0xa3b203bc8102676f,
+                0xfcc781b0ce382bf2,
+                0x934c69ff3ed14ba5,
+                0x504688a5996e8f13,
+                0x401f3f2ed524a2ba,
+            ],
+        ),
+    ];
+
+    for (input, expected) in test_vectors {
+        let mut state = input.map(BaseElement::new);
+        Poseidon64_256::apply_permutation(&mut state);
+        assert_eq!(expected.map(BaseElement::new), state);
+    }
+}
+
+#[test]
+fn hash_elements() {
+    // the sponge construction is specific to this crate, and thus, there are no published test
+    // vectors for it; expected values were generated using this implementation and serve as
+    // regression vectors (the permutation itself is checked against published test vectors)
+    let elements: Vec<BaseElement> = (0..8).map(BaseElement::new).collect();
+    let expected = ElementDigest::new([
+        BaseElement::new(8626741466631882446),
+        BaseElement::new(1176083052282178005),
+        BaseElement::new(4253666003502015840),
+        BaseElement::new(423453692295970590),
+    ]);
+    assert_eq!(expected, Poseidon64_256::hash_elements(&elements));
+
+    let expected = ElementDigest::new([
+        BaseElement::new(10713650429999817779),
+        BaseElement::new(9748780376615364754),
+        BaseElement::new(13277197922483658752),
+        BaseElement::new(16098115944628271836),
+    ]);
+    assert_eq!(expected, Poseidon64_256::hash_elements(&elements[..5]));
+}
+
+#[test]
+fn hash_elements_vs_merge() {
+    let elements: [BaseElement; 8] = rand_array();
+
+    let digests: [ElementDigest; 2] = [
+        ElementDigest::new(elements[..4].try_into().unwrap()),
+        ElementDigest::new(elements[4..].try_into().unwrap()),
+    ];
+
+    let m_result = Poseidon64_256::merge(&digests);
+    let h_result = Poseidon64_256::hash_elements(&elements);
+    assert_eq!(m_result, h_result);
+}
+
+#[test]
+fn hash_elements_vs_merge_with_int() {
+    let seed = ElementDigest::new(rand_array());
+
+    // ----- value fits into a field element ------------------------------------------------------
+    let val: BaseElement = rand_value();
+    let m_result = Poseidon64_256::merge_with_int(seed, val.as_int());
+
+    let mut elements = seed.as_elements().to_vec();
+    elements.push(val);
+    let h_result = Poseidon64_256::hash_elements(&elements);
+
+    assert_eq!(m_result, h_result);
+
+    // ----- value does not fit into a field element ----------------------------------------------
+    let val = BaseElement::MODULUS + 2;
+    let m_result = Poseidon64_256::merge_with_int(seed, val);
+
+    let mut elements = seed.as_elements().to_vec();
+    elements.push(BaseElement::new(val));
+    elements.push(BaseElement::new(1));
+    let h_result = Poseidon64_256::hash_elements(&elements);
+
+    assert_eq!(m_result, h_result);
+}
+
+#[test]
+fn hash_extension_elements() {
+    // hashing extension field elements should be the same as hashing their base field components
+    let elements: [QuadExtension<BaseElement>; 3] = rand_array();
+    let base_elements = QuadExtension::<BaseElement>::as_base_elements(&elements);
+
+    let e_result = Poseidon64_256::hash_elements(&elements);
+    let b_result = Poseidon64_256::hash_elements(base_elements);
+    assert_eq!(e_result, b_result);
+}
+
+#[test]
+fn hash_padding() {
+    // adding a zero bytes at the end of a byte string should result in a different hash
+    let r1 = Poseidon64_256::hash(&[1_u8, 2, 3]);
+    let r2 = Poseidon64_256::hash(&[1_u8, 2, 3, 0]);
+    assert_ne!(r1, r2);
+
+    // same as above but with bigger inputs
+    let r1 = Poseidon64_256::hash(&[1_u8, 2, 3, 4, 5, 6]);
+    let r2 = Poseidon64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 0]);
+    assert_ne!(r1, r2);
+
+    // same as above but with input splitting over two elements
+    let r1 = Poseidon64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7]);
+    let r2 = Poseidon64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7, 0]);
+    assert_ne!(r1, r2);
+
+    // same as above but with multiple zeros
+    let r1 = Poseidon64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7, 0, 0]);
+    let r2 = Poseidon64_256::hash(&[1_u8, 2, 3, 4, 5, 6, 7, 0, 0, 0, 0]);
+    assert_ne!(r1, r2);
+}
+
+#[test]
+fn hash_elements_padding() {
+    let e1: [BaseElement; 2] = rand_array();
+    let e2 = [e1[0], e1[1], BaseElement::ZERO];
+
+    let r1 = Poseidon64_256::hash_elements(&e1);
+    let r2 = Poseidon64_256::hash_elements(&e2);
+    assert_ne!(r1, r2);
+}
+
+#[test]
+fn supported_fields() {
+    assert!(super::Poseidon64_256::<F64>::is_supported());
+    assert!(!super::Poseidon64_256::<f128::BaseElement>::is_supported());
+}
@@ -3,11 +3,17 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use super::{exp_acc, Digest, ElementHasher, Hasher};
+use super::{Digest, ElementHasher, Hasher};
 use core::convert::TryInto;
 use core::ops::Range;
 use math::{fields::f64::BaseElement, FieldElement, StarkField};
 
+#[cfg(not(feature = "simd"))]
+use super::exp_acc;
+
+#[cfg(feature = "simd")]
+use math::fields::f64::simd;
+
 mod digest;
 pub use digest::ElementDigest;
 
@@ -354,6 +360,7 @@ impl Rp64_256 {
         state.iter_mut().zip(ark).for_each(|(s, &k)| *s += k);
     }
 
+    #[cfg(not(feature = "simd"))]
     #[inline(always)]
     fn apply_sbox(state: &mut [BaseElement; STATE_WIDTH]) {
         state[0] = state[0].exp7();
@@ -370,6 +377,13 @@ impl Rp64_256 {
         state[11] = state[11].exp7();
     }
 
+    #[cfg(feature = "simd")]
+    #[inline(always)]
+    fn apply_sbox(state: &mut [BaseElement; STATE_WIDTH]) {
+        simd::exp7_assign(state);
+    }
+
+    #[cfg(not(feature = "simd"))]
     #[inline(always)]
     fn apply_inv_sbox(state: &mut [BaseElement; STATE_WIDTH]) {
         // compute base^10540996611094048183 using 72 multiplications per array element
@@ -405,6 +419,47 @@ impl Rp64_256 {
             *s = a * b;
         }
     }
+
+    #[cfg(feature = "simd")]
+    #[inline(always)]
+    fn apply_inv_sbox(state: &mut [BaseElement; STATE_WIDTH]) {
+        // this evaluates the same addition chain as the non-vectorized version above, but
+        // processes all state elements at once
+
+        // compute base^10
+        let mut t1 = *state;
+        simd::mul_assign(&mut t1, state);
+
+        // compute base^100
+        let mut t2 = t1;
+        simd::mul_assign(&mut t2, &t1);
+
+        // compute base^100100
+        let mut t3 = t2;
+        simd::exp_acc_assign(&mut t3, 3, &t2);
+
+        // compute base^100100100100
+        let mut t4 = t3;
+        simd::exp_acc_assign(&mut t4, 6, &t3);
+
+        // compute base^100100100100100100100100
+        let mut t5 = t4;
+        simd::exp_acc_assign(&mut t5, 12, &t4);
+
+        // compute base^100100100100100100100100100100
+        let mut t6 = t5;
+        simd::exp_acc_assign(&mut t6, 6, &t3);
+
+        // compute base^1001001001001001001001001001000100100100100100100100100100100
+        let mut t7 = t6;
+        simd::exp_acc_assign(&mut t7, 31, &t6);
+
+        // compute base^1001001001001001001001001001000110110110110110110110110110110111
+        simd::exp_acc_assign(&mut t7, 1, &t6);
+        simd::exp_acc_assign(&mut t7, 2, &t1);
+        simd::mul_assign(&mut t7, &t2);
+        simd::mul_assign(state, &t7);
+    }
 }
 
 // MDS
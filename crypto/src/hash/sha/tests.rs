@@ -0,0 +1,99 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{ElementHasher, Hasher, Keccak256, Sha3_256};
+use crate::Digest;
+use math::{
+    fields::{f128, f62},
+    FieldElement,
+};
+use rand_utils::rand_array;
+use utils::{collections::Vec, ByteWriter};
+
+#[test]
+fn keccak_test_vectors() {
+    // Keccak-256 (as opposed to SHA3-256) digests of an empty string and of "abc"
+    let r = Keccak256::<f62::BaseElement>::hash(&[]);
+    assert_eq!(
+        "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+        to_hex(&r.as_bytes())
+    );
+
+    let r = Keccak256::<f62::BaseElement>::hash(b"abc");
+    assert_eq!(
+        "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+        to_hex(&r.as_bytes())
+    );
+
+    // the same strings hashed with SHA3-256
+    let r = Sha3_256::<f62::BaseElement>::hash(&[]);
+    assert_eq!(
+        "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a",
+        to_hex(&r.as_bytes())
+    );
+}
+
+#[test]
+fn keccak_merge() {
+    let d1 = Keccak256::<f62::BaseElement>::hash(&[1_u8]);
+    let d2 = Keccak256::<f62::BaseElement>::hash(&[2_u8]);
+
+    // merging two digests is the same as hashing a concatenation of their bytes
+    let mut bytes = d1.as_bytes().to_vec();
+    bytes.extend_from_slice(&d2.as_bytes());
+    assert_eq!(
+        Keccak256::<f62::BaseElement>::hash(&bytes),
+        Keccak256::<f62::BaseElement>::merge(&[d1, d2])
+    );
+
+    // merging a digest with an integer is the same as hashing a concatenation of digest bytes
+    // and little-endian bytes of the integer
+    let mut bytes = d1.as_bytes().to_vec();
+    bytes.extend_from_slice(&42_u64.to_le_bytes());
+    assert_eq!(
+        Keccak256::<f62::BaseElement>::hash(&bytes),
+        Keccak256::<f62::BaseElement>::merge_with_int(d1, 42)
+    );
+}
+
+#[test]
+fn keccak_hash_elements() {
+    // hashing elements is the same as hashing their canonical serialization, for fields with
+    // both canonical and non-canonical internal representations
+    // f62 elements are stored in Montgomery form
+    let e1: [f62::BaseElement; 5] = rand_array();
+    assert_eq!(
+        Keccak256::<f62::BaseElement>::hash(&to_bytes(&e1)),
+        Keccak256::hash_elements(&e1)
+    );
+
+    // f128 elements are stored in canonical form
+    let e2: [f128::BaseElement; 5] = rand_array();
+    assert_eq!(
+        Keccak256::<f128::BaseElement>::hash(&to_bytes(&e2)),
+        Keccak256::hash_elements(&e2)
+    );
+}
+
+#[test]
+fn hash_elements_padding() {
+    let e1: [f62::BaseElement; 2] = rand_array();
+    let e2 = [e1[0], e1[1], f62::BaseElement::ZERO];
+
+    // adding a zero element at the end of a list of elements should result in a different hash
+    let r1 = Keccak256::hash_elements(&e1);
+    let r2 = Keccak256::hash_elements(&e2);
+    assert_ne!(r1, r2);
+}
+
+fn to_bytes<E: FieldElement>(elements: &[E]) -> Vec<u8> {
+    let mut result = Vec::new();
+    result.write(elements);
+    result
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
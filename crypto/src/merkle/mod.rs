@@ -6,7 +6,11 @@
 use crate::{errors::MerkleTreeError, hash::Hasher};
 use core::slice;
 use math::log2;
-use utils::collections::{BTreeMap, BTreeSet, Vec};
+use utils::{
+    collections::{BTreeMap, BTreeSet, Vec},
+    string::ToString,
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+};
 
 mod proofs;
 pub use proofs::BatchMerkleProof;
@@ -126,6 +130,58 @@ impl<H: Hasher> MerkleTree<H> {
         Ok(MerkleTree { nodes, leaves })
     }
 
+    /// Returns a Merkle tree built by joining the provided subtrees.
+    ///
+    /// Leaves of the subtrees become leaves of the resulting tree in the order in which subtrees
+    /// are provided. Thus, the result is the same as the tree built via [MerkleTree::new()] from
+    /// the concatenation of all subtree leaves; however, only the nodes above subtree roots are
+    /// hashed. This allows parts of a large tree to be built independently (e.g., on different
+    /// machines) and combined afterwards.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The number of subtrees is not a power of two.
+    /// * Not all subtrees have the same depth.
+    pub fn from_subtrees(subtrees: Vec<Self>) -> Result<Self, MerkleTreeError> {
+        let num_subtrees = subtrees.len();
+        if !num_subtrees.is_power_of_two() {
+            return Err(MerkleTreeError::NumberOfSubtreesNotPowerOfTwo(num_subtrees));
+        }
+        let subtree_depth = subtrees[0].depth();
+        for subtree in subtrees.iter() {
+            if subtree.depth() != subtree_depth {
+                return Err(MerkleTreeError::SubtreeDepthMismatch(
+                    subtree_depth,
+                    subtree.depth(),
+                ));
+            }
+        }
+
+        let num_leaves = num_subtrees << subtree_depth;
+        let mut nodes = vec![H::Digest::default(); num_leaves];
+        let mut leaves = Vec::with_capacity(num_leaves);
+
+        // the root of subtree k is located at index num_subtrees + k of the joined tree; thus,
+        // nodes at level d of subtree k (indexes 2^d..2^(d+1) in the subtree) are located at
+        // indexes (num_subtrees + k) * 2^d..(num_subtrees + k + 1) * 2^d of the joined tree
+        for (k, subtree) in subtrees.into_iter().enumerate() {
+            for d in 0..subtree_depth {
+                let level_size = 1 << d;
+                let start = (num_subtrees + k) << d;
+                nodes[start..start + level_size]
+                    .copy_from_slice(&subtree.nodes[level_size..2 * level_size]);
+            }
+            leaves.extend_from_slice(&subtree.leaves);
+        }
+
+        // compute the nodes above subtree roots
+        for i in (1..num_subtrees).rev() {
+            nodes[i] = H::merge(&[nodes[2 * i], nodes[2 * i + 1]]);
+        }
+
+        Ok(MerkleTree { nodes, leaves })
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -303,6 +359,52 @@ impl<H: Hasher> MerkleTree<H> {
     }
 }
 
+// SERIALIZATION / DESERIALIZATION
+// ================================================================================================
+
+impl<H: Hasher> Serializable for MerkleTree<H> {
+    /// Serializes `self` and writes the resulting bytes into the `target`.
+    ///
+    /// The depth of the tree is written first, followed by all leaves and all internal nodes.
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.depth() as u8);
+        H::Digest::write_batch_into(&self.leaves, target);
+        H::Digest::write_batch_into(&self.nodes[1..], target);
+    }
+}
+
+impl<H: Hasher> Deserializable for MerkleTree<H> {
+    /// Reads a Merkle tree from the specified `source` and returns the result.
+    ///
+    /// Internal nodes are not re-computed from the leaves; thus, the caller is responsible for
+    /// making sure the source of the tree is trusted.
+    ///
+    /// # Errors
+    /// Returns an error if a valid Merkle tree could not be read from the specified `source`.
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let depth = source.read_u8()? as usize;
+        if depth == 0 || depth >= usize::BITS as usize {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid Merkle tree depth: {}",
+                depth
+            )));
+        }
+        let num_leaves = 1 << depth;
+        let leaves = H::Digest::read_batch_from(source, num_leaves)?;
+
+        let mut nodes = Vec::with_capacity(num_leaves);
+        nodes.push(H::Digest::default());
+        nodes.append(&mut H::Digest::read_batch_from(source, num_leaves - 1)?);
+        if nodes.len() != leaves.len() {
+            return Err(DeserializationError::InvalidValue(
+                "number of internal nodes is inconsistent with number of leaves".to_string(),
+            ));
+        }
+
+        Ok(MerkleTree { nodes, leaves })
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
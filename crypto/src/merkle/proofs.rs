@@ -10,6 +10,9 @@ use utils::{
     ByteReader, Deserializable, DeserializationError, Serializable,
 };
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 // CONSTANTS
 // ================================================================================================
 
@@ -28,6 +31,14 @@ pub(super) const MAX_PATHS: usize = 255;
 /// Currently, at most 255 paths can be aggregated into a single proof. This limitation is
 /// imposed primarily for serialization purposes.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "H::Digest: Serialize",
+        deserialize = "H::Digest: Deserialize<'de>"
+    ))
+)]
 pub struct BatchMerkleProof<H: Hasher> {
     pub(super) leaves: Vec<H::Digest>,
     pub(super) nodes: Vec<Vec<H::Digest>>,
@@ -115,6 +126,29 @@ impl<H: Hasher> BatchMerkleProof<H> {
         }
     }
 
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns leaf nodes of all Merkle paths aggregated in this proof.
+    pub fn leaves(&self) -> &[H::Digest] {
+        &self.leaves
+    }
+
+    /// Returns internal nodes of this proof grouped by the Merkle path to which they belong.
+    ///
+    /// Nodes shared between paths are included only once.
+    pub fn nodes(&self) -> &[Vec<H::Digest>] {
+        &self.nodes
+    }
+
+    /// Returns depth of the Merkle tree for which this proof was generated.
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    // ROOT COMPUTATION
+    // --------------------------------------------------------------------------------------------
+
     /// Computes a node to which all Merkle paths aggregated in this proof resolve.
     ///
     /// # Errors
@@ -126,6 +160,67 @@ impl<H: Hasher> BatchMerkleProof<H> {
     /// * List of indexes contains duplicates.
     /// * The proof does not resolve to a single root.
     pub fn get_root(&self, indexes: &[usize]) -> Result<H::Digest, MerkleTreeError> {
+        let mut nodes = self.compute_nodes(indexes)?;
+        nodes.remove(&1).ok_or(MerkleTreeError::InvalidProof)
+    }
+
+    /// Expands this proof into individual Merkle authentication paths for the specified
+    /// `indexes`.
+    ///
+    /// Paths are returned in the same order as `indexes`, and each path has the same format as
+    /// paths returned by [MerkleTree::prove()](super::MerkleTree::prove): the first node is the
+    /// leaf at the corresponding index, followed by the sibling of the leaf and the siblings of
+    /// all nodes on the way to the root (the root itself is not included). This is the inverse
+    /// of [BatchMerkleProof::from_paths()].
+    ///
+    /// The paths are not verified against a tree root; this can be done by verifying each path
+    /// individually, or by verifying this batch proof via
+    /// [MerkleTree::verify_batch()](super::MerkleTree::verify_batch).
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * No indexes were provided (i.e., `indexes` is an empty slice).
+    /// * Number of provided indexes is greater than 255.
+    /// * Any of the specified `indexes` is greater than or equal to the number of leaves in the
+    ///   tree for which this batch proof was generated.
+    /// * List of indexes contains duplicates.
+    /// * The proof does not contain all nodes needed to build paths for the specified indexes.
+    pub fn into_paths(self, indexes: &[usize]) -> Result<Vec<Vec<H::Digest>>, MerkleTreeError> {
+        let nodes = self.compute_nodes(indexes)?;
+        let get_node = |index: usize| {
+            nodes
+                .get(&index)
+                .copied()
+                .ok_or(MerkleTreeError::InvalidProof)
+        };
+
+        let offset = 2usize.pow(self.depth as u32);
+        let mut paths = Vec::with_capacity(indexes.len());
+        for &index in indexes {
+            let mut node_index = offset + index;
+            let mut path = Vec::with_capacity(self.depth as usize + 1);
+            path.push(get_node(node_index)?);
+            while node_index > 1 {
+                path.push(get_node(node_index ^ 1)?);
+                node_index >>= 1;
+            }
+            paths.push(path);
+        }
+
+        Ok(paths)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Computes all internal nodes to which Merkle paths aggregated in this proof resolve, and
+    /// returns them together with leaf nodes and nodes included in the proof. The nodes are
+    /// keyed by their index in the tree, where the root has index 1 and the leaves start at
+    /// index 2^depth.
+    fn compute_nodes(
+        &self,
+        indexes: &[usize],
+    ) -> Result<BTreeMap<usize, H::Digest>, MerkleTreeError> {
         if indexes.is_empty() {
             return Err(MerkleTreeError::TooFewLeafIndexes);
         }
@@ -193,7 +288,9 @@ impl<H: Hasher> BatchMerkleProof<H> {
                 }
             }
 
-            // hash sibling nodes into their parent
+            // record leaf nodes, and hash sibling nodes into their parent
+            v.insert(offset + index, buf[0]);
+            v.insert(offset + index + 1, buf[1]);
             let parent = H::merge(&buf);
 
             let parent_index = (offset + index) >> 1;
@@ -226,6 +323,7 @@ impl<H: Hasher> BatchMerkleProof<H> {
                     }
                     sibling = self.nodes[i][pointer];
                     proof_pointers[i] += 1;
+                    v.insert(sibling_index, sibling);
                 }
 
                 // get the node from the map of hashed nodes
@@ -252,7 +350,7 @@ impl<H: Hasher> BatchMerkleProof<H> {
                 i += 1;
             }
         }
-        v.remove(&1).ok_or(MerkleTreeError::InvalidProof)
+        Ok(v)
     }
 
     // SERIALIZATION / DESERIALIZATION
@@ -6,6 +6,7 @@
 use super::*;
 use math::fields::f128::BaseElement;
 use proptest::prelude::*;
+use utils::SliceReader;
 
 type Digest256 = crate::hash::ByteDigest<32>;
 type Blake3_256 = crate::hash::Blake3_256<BaseElement>;
@@ -232,6 +233,59 @@ fn verify_batch() {
     assert!(MerkleTree::verify_batch(tree.root(), &[0, 1, 2, 3, 4, 5, 6, 7], &proof).is_ok());
 }
 
+#[test]
+fn from_subtrees() {
+    let leaves = Digest256::bytes_as_digests(&LEAVES8).to_vec();
+    let tree = MerkleTree::<Blake3_256>::new(leaves.clone()).unwrap();
+
+    for num_subtrees in [1, 2, 4] {
+        let subtrees = leaves
+            .chunks(leaves.len() / num_subtrees)
+            .map(|chunk| MerkleTree::<Blake3_256>::new(chunk.to_vec()).unwrap())
+            .collect();
+        let joined = MerkleTree::from_subtrees(subtrees).unwrap();
+        assert_eq!(tree.root(), joined.root());
+        assert_eq!(tree.leaves(), joined.leaves());
+        assert_eq!(tree.nodes, joined.nodes);
+    }
+
+    // number of subtrees must be a power of two
+    let subtrees = leaves[..6]
+        .chunks(2)
+        .map(|chunk| MerkleTree::<Blake3_256>::new(chunk.to_vec()).unwrap())
+        .collect();
+    assert_eq!(
+        Err(MerkleTreeError::NumberOfSubtreesNotPowerOfTwo(3)),
+        MerkleTree::from_subtrees(subtrees).map(|tree| *tree.root())
+    );
+
+    // all subtrees must have the same depth
+    let subtrees = vec![
+        MerkleTree::<Blake3_256>::new(leaves[..4].to_vec()).unwrap(),
+        MerkleTree::<Blake3_256>::new(leaves[4..6].to_vec()).unwrap(),
+    ];
+    assert_eq!(
+        Err(MerkleTreeError::SubtreeDepthMismatch(2, 1)),
+        MerkleTree::from_subtrees(subtrees).map(|tree| *tree.root())
+    );
+}
+
+#[test]
+fn serialization() {
+    let leaves = Digest256::bytes_as_digests(&LEAVES8).to_vec();
+    let tree = MerkleTree::<Blake3_256>::new(leaves).unwrap();
+
+    let bytes = tree.to_bytes();
+    let result = MerkleTree::<Blake3_256>::read_from(&mut SliceReader::new(&bytes)).unwrap();
+    assert_eq!(tree.root(), result.root());
+    assert_eq!(tree.leaves(), result.leaves());
+    assert_eq!(tree.nodes, result.nodes);
+
+    // truncated trees cannot be deserialized
+    let result = MerkleTree::<Blake3_256>::read_from(&mut SliceReader::new(&bytes[..100]));
+    assert!(result.is_err());
+}
+
 proptest! {
     #[test]
     fn prove_n_verify(tree in random_blake3_merkle_tree(128),
@@ -269,6 +323,21 @@ proptest! {
 
         prop_assert!(proof1 == proof2);
     }
+
+    #[test]
+    fn batch_proof_into_paths(tree in random_blake3_merkle_tree(128),
+                      proof_indices in prop::collection::vec(any::<prop::sample::Index>(), 10..20)
+    )  {
+        let mut indices: Vec<usize> = proof_indices.iter().map(|idx| idx.index(128)).collect();
+        indices.sort_unstable(); indices.dedup();
+        let proof = tree.prove_batch(&indices[..]).unwrap();
+
+        let paths = proof.into_paths(&indices).unwrap();
+        prop_assert_eq!(paths.len(), indices.len());
+        for (&idx, path) in indices.iter().zip(paths.iter()) {
+            prop_assert_eq!(path, &tree.prove(idx).unwrap());
+        }
+    }
 }
 
 // HELPER FUNCTIONS
@@ -0,0 +1,46 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use examples::{lamport, Example};
+use std::time::Duration;
+use winterfell::{FieldExtension, HashFunction, MatrixLayout, ProofOptions};
+
+const SIZES: [usize; 2] = [4, 8];
+
+const LAYOUTS: [(&str, MatrixLayout); 2] = [
+    ("column_major", MatrixLayout::ColumnMajor),
+    ("row_major", MatrixLayout::RowMajor),
+];
+
+fn lamport_aggregate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lamport_aggregate");
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(25));
+
+    let options = ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        256,
+    );
+
+    for &size in SIZES.iter() {
+        for (layout_name, layout) in LAYOUTS {
+            let lamport = lamport::aggregate::LamportAggregateExample::new(size, options.clone())
+                .with_lde_layout(layout);
+            group.bench_function(BenchmarkId::new(layout_name, size), |bench| {
+                bench.iter(|| lamport.prove());
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(lamport_group, lamport_aggregate);
+criterion_main!(lamport_group);
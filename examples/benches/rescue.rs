@@ -6,10 +6,15 @@
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
 use examples::{rescue, Example};
 use std::time::Duration;
-use winterfell::{FieldExtension, HashFunction, ProofOptions};
+use winterfell::{FieldExtension, HashFunction, MatrixLayout, ProofOptions};
 
 const SIZES: [usize; 2] = [256, 512];
 
+const LAYOUTS: [(&str, MatrixLayout); 2] = [
+    ("column_major", MatrixLayout::ColumnMajor),
+    ("row_major", MatrixLayout::RowMajor),
+];
+
 fn rescue(c: &mut Criterion) {
     let mut group = c.benchmark_group("rescue");
     group.sample_size(10);
@@ -26,10 +31,12 @@ fn rescue(c: &mut Criterion) {
     );
 
     for &size in SIZES.iter() {
-        let resc = rescue::RescueExample::new(size, options.clone());
-        group.bench_function(BenchmarkId::from_parameter(size), |bench| {
-            bench.iter(|| resc.prove());
-        });
+        for (layout_name, layout) in LAYOUTS {
+            let resc = rescue::RescueExample::new(size, options.clone()).with_lde_layout(layout);
+            group.bench_function(BenchmarkId::new(layout_name, size), |bench| {
+                bench.iter(|| resc.prove());
+            });
+        }
     }
     group.finish();
 }
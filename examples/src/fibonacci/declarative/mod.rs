@@ -0,0 +1,177 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::utils::compute_fib_term;
+use crate::{Example, ExampleOptions};
+use log::debug;
+use std::time::Instant;
+use winterfell::{
+    builder::{AirBuilder, AirDefinition, AssertionBuilder, Column, DeclarativeAir, TraceBuilder},
+    math::{fields::f128::BaseElement, log2, FieldElement},
+    ProofOptions, Prover, Serializable, StarkProof, Trace, TraceTable, VerifierError,
+};
+
+#[cfg(test)]
+mod tests;
+
+// FIBONACCI EXAMPLE
+// ================================================================================================
+
+pub fn get_example(options: ExampleOptions, sequence_length: usize) -> Box<dyn Example> {
+    Box::new(FibExample::new(
+        sequence_length,
+        options.to_proof_options(28, 8),
+    ))
+}
+
+/// Same computation as in the `fib2` example, but the AIR is described via [AirDefinition].
+pub struct FibExample {
+    options: ProofOptions,
+    sequence_length: usize,
+    result: BaseElement,
+}
+
+impl FibExample {
+    pub fn new(sequence_length: usize, options: ProofOptions) -> FibExample {
+        assert!(
+            sequence_length.is_power_of_two(),
+            "sequence length must be a power of 2"
+        );
+
+        // compute Fibonacci sequence
+        let now = Instant::now();
+        let result = compute_fib_term(sequence_length);
+        debug!(
+            "Computed Fibonacci sequence up to {}th term in {} ms",
+            sequence_length,
+            now.elapsed().as_millis()
+        );
+
+        FibExample {
+            options,
+            sequence_length,
+            result,
+        }
+    }
+}
+
+// EXAMPLE IMPLEMENTATION
+// ================================================================================================
+
+impl Example for FibExample {
+    fn prove(&self) -> StarkProof {
+        debug!(
+            "Generating proof for computing Fibonacci sequence (2 terms per step) up to {}th term\n\
+            ---------------------",
+            self.sequence_length
+        );
+
+        // create a prover
+        let prover = FibProver::new(self.options.clone());
+
+        // generate execution trace
+        let now = Instant::now();
+        let trace = FibProver::build_trace(self.sequence_length);
+
+        let trace_width = trace.width();
+        let trace_length = trace.length();
+        debug!(
+            "Generated execution trace of {} registers and 2^{} steps in {} ms",
+            trace_width,
+            log2(trace_length),
+            now.elapsed().as_millis()
+        );
+
+        // generate the proof
+        prover.prove(trace).unwrap()
+    }
+
+    fn public_inputs(&self) -> Vec<u8> {
+        self.result.to_bytes()
+    }
+
+    fn verify(&self, proof: StarkProof) -> Result<(), VerifierError> {
+        winterfell::verify::<FibAir>(proof, self.result)
+    }
+
+    fn verify_with_wrong_inputs(&self, proof: StarkProof) -> Result<(), VerifierError> {
+        winterfell::verify::<FibAir>(proof, self.result + BaseElement::ONE)
+    }
+}
+
+// FIBONACCI AIR
+// ================================================================================================
+
+pub type FibAir = DeclarativeAir<FibDefinition>;
+
+pub struct FibDefinition;
+
+impl AirDefinition for FibDefinition {
+    type BaseField = BaseElement;
+    type PublicInputs = BaseElement;
+    type Columns = [Column; 2];
+    const ID: &'static [u8] = b"winterfell/examples/fib-declarative";
+
+    fn define(air: &mut AirBuilder<BaseElement>) -> [Column; 2] {
+        // each row advances the sequence by 2 terms
+        let [a, b] = air.columns(["a", "b"]);
+        air.enforce("a", a.next() - (a.current() + b.current()));
+        air.enforce("b", b.next() - (b.current() + a.next()));
+        [a, b]
+    }
+
+    fn get_assertions(
+        [a, b]: &[Column; 2],
+        result: &BaseElement,
+        assertions: &mut AssertionBuilder<BaseElement>,
+    ) {
+        assertions.first(*a, BaseElement::ONE);
+        assertions.first(*b, BaseElement::ONE);
+        assertions.last(*b, *result);
+    }
+}
+
+// FIBONACCI PROVER
+// ================================================================================================
+
+pub struct FibProver {
+    options: ProofOptions,
+}
+
+impl FibProver {
+    pub fn new(options: ProofOptions) -> Self {
+        Self { options }
+    }
+
+    pub fn build_trace(sequence_length: usize) -> TraceTable<BaseElement> {
+        let builder = TraceBuilder::<FibDefinition>::new(sequence_length / 2);
+        let [a, b] = *builder.columns();
+        TraceTable::init(builder.fill(
+            |row| {
+                row[a] = BaseElement::ONE;
+                row[b] = BaseElement::ONE;
+            },
+            |_, row| {
+                let a_next = row[a] + row[b];
+                row[a] = a_next;
+                row[b] += a_next;
+            },
+        ))
+    }
+}
+
+impl Prover for FibProver {
+    type BaseField = BaseElement;
+    type Air = FibAir;
+    type Trace = TraceTable<BaseElement>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> BaseElement {
+        trace.get(1, trace.length() - 1)
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
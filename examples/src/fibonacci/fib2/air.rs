@@ -20,6 +20,7 @@ pub struct FibAir {
 impl Air for FibAir {
     type BaseField = BaseElement;
     type PublicInputs = BaseElement;
+    const ID: &'static [u8] = b"winterfell/examples/fib2";
 
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
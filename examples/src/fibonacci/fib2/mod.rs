@@ -9,11 +9,11 @@ use log::debug;
 use std::time::Instant;
 use winterfell::{
     math::{fields::f128::BaseElement, log2, FieldElement},
-    ProofOptions, Prover, StarkProof, Trace, TraceTable, VerifierError,
+    ProofOptions, Prover, Serializable, StarkProof, Trace, TraceTable, VerifierError,
 };
 
 mod air;
-use air::FibAir;
+pub use air::FibAir;
 
 mod prover;
 use prover::FibProver;
@@ -97,6 +97,10 @@ impl Example for FibExample {
         prover.prove(trace).unwrap()
     }
 
+    fn public_inputs(&self) -> Vec<u8> {
+        self.result.to_bytes()
+    }
+
     fn verify(&self, proof: StarkProof) -> Result<(), VerifierError> {
         winterfell::verify::<FibAir>(proof, self.result)
     }
@@ -3,7 +3,12 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use super::super::utils::build_proof_options;
+use super::super::utils::{build_proof_options, compute_fib_term};
+use super::air::FibAir;
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    Air, Context, StarkProof, VerifierError,
+};
 
 #[test]
 fn fib2_test_basic_proof_verification() {
@@ -22,3 +27,137 @@ fn fib2_test_basic_proof_verification_fail() {
     let fib = Box::new(super::FibExample::new(16, build_proof_options(false)));
     crate::tests::test_basic_proof_verification_fail(fib);
 }
+
+#[test]
+fn fib2_test_proof_verification_fail_wrong_version() {
+    let fib = Box::new(super::FibExample::new(16, build_proof_options(false)));
+    crate::tests::test_proof_verification_fail_wrong_version(fib);
+}
+
+#[test]
+fn fib2_test_proof_serialization() {
+    let fib = Box::new(super::FibExample::new(16, build_proof_options(false)));
+    crate::tests::test_proof_serialization(fib);
+}
+
+#[test]
+fn fib2_test_proof_serde() {
+    let fib = Box::new(super::FibExample::new(16, build_proof_options(true)));
+    crate::tests::test_proof_serde(fib);
+}
+
+#[test]
+fn fib2_test_proof_decoding() {
+    use winterfell::{
+        crypto::hashers::Blake3_256,
+        math::fields::{f128::BaseElement, QuadExtension},
+    };
+
+    let fib = Box::new(super::FibExample::new(16, build_proof_options(true)));
+    crate::tests::test_proof_decoding::<QuadExtension<BaseElement>, Blake3_256<BaseElement>>(fib);
+}
+
+#[test]
+fn fib2_test_distributed_proof_generation() {
+    crate::tests::test_distributed_proof_generation(|| {
+        let prover = super::FibProver::new(build_proof_options(true));
+        let trace = prover.build_trace(64);
+        (prover, trace)
+    });
+}
+
+#[test]
+fn fib2_test_unsupported_hash_function() {
+    use winterfell::{FieldExtension, HashFunction, ProofOptions, Prover, ProverError};
+
+    // Poseidon64_256 is defined over the 64-bit field only, and thus, it cannot be used to
+    // generate proofs for computations over the 128-bit field
+    let options = ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Poseidon64_256,
+        FieldExtension::None,
+        4,
+        256,
+    );
+    let prover = super::FibProver::new(options);
+    let trace = prover.build_trace(16);
+    assert_eq!(
+        Err(ProverError::UnsupportedHashFunction(
+            HashFunction::Poseidon64_256
+        )),
+        prover.prove(trace)
+    );
+}
+
+// KECCAK-256 GOLDEN PROOF
+// ------------------------------------------------------------------------------------------------
+
+/// Proof generation is deterministic, and thus, a proof generated using Keccak-256 hash function
+/// for a fixed set of parameters can be used as a test vector for independent verifier
+/// implementations (e.g., Solidity verifiers). The proof is stored in `keccak_256_proof.hex`; it
+/// was generated for a sequence of 16 terms with public input 987 (the 16th Fibonacci term), and
+/// with 4 queries, blowup factor 8, no grinding, no field extension, FRI folding factor 4, and
+/// max FRI remainder size 32.
+///
+/// With `concurrent` feature enabled, the proof-of-work nonce is searched for in multiple threads,
+/// and thus, the resulting proof is not guaranteed to be the same across runs.
+#[test]
+#[cfg(not(feature = "concurrent"))]
+fn fib2_test_keccak_golden_proof() {
+    use winterfell::{FieldExtension, HashFunction, ProofOptions, Prover, StarkProof};
+
+    let options = ProofOptions::new(
+        4,
+        8,
+        0,
+        HashFunction::Keccak256,
+        FieldExtension::None,
+        4,
+        32,
+    );
+    let prover = super::FibProver::new(options);
+    let trace = prover.build_trace(16);
+    let proof = prover.prove(trace).unwrap();
+
+    let expected = include_str!("keccak_256_proof.hex").trim();
+    assert_eq!(expected, hex::encode(proof.to_bytes()));
+
+    // the stored proof is valid against the expected result
+    let proof = StarkProof::from_bytes(&hex::decode(expected).unwrap()).unwrap();
+    assert!(
+        winterfell::verify::<super::FibAir>(proof, super::super::utils::compute_fib_term(16))
+            .is_ok()
+    );
+}
+
+#[test]
+fn fib2_test_legacy_proof_verification() {
+    // proof for 1024 terms of the sequence generated before proofs were versioned; the proof
+    // context does not include the protocol version, and the transcript is not bound to AIR ID
+    let proof_bytes = hex::decode(include_str!("legacy_proof.hex").trim()).unwrap();
+    let proof = StarkProof::from_bytes(&proof_bytes).unwrap();
+    assert_eq!(Context::LEGACY_PROTOCOL_VERSION, proof.context.version());
+
+    let result = compute_fib_term(1024);
+    assert!(winterfell::verify_legacy::<FibAir>(proof.clone(), result).is_ok());
+    assert!(winterfell::verify_legacy::<FibAir>(proof.clone(), result + BaseElement::ONE).is_err());
+
+    // legacy proofs are not bound to AIR domain separation tag, and thus, are rejected by the
+    // default verification procedure
+    assert!(!FibAir::ID.is_empty());
+    assert_eq!(
+        Err(VerifierError::UnsupportedProtocolVersion(
+            Context::LEGACY_PROTOCOL_VERSION
+        )),
+        winterfell::verify::<FibAir>(proof.clone(), result)
+    );
+
+    // after re-serialization using the current format, the proof remains a legacy proof
+    let proof_bytes = proof.to_bytes();
+    assert!(proof_bytes.starts_with(&StarkProof::MAGIC));
+    let parsed_proof = StarkProof::from_bytes(&proof_bytes).unwrap();
+    assert_eq!(proof, parsed_proof);
+    assert!(winterfell::verify_legacy::<FibAir>(parsed_proof, result).is_ok());
+}
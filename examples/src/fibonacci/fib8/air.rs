@@ -21,6 +21,7 @@ pub struct Fib8Air {
 impl Air for Fib8Air {
     type BaseField = BaseElement;
     type PublicInputs = BaseElement;
+    const ID: &'static [u8] = b"winterfell/examples/fib8";
 
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
@@ -9,11 +9,11 @@ use log::debug;
 use std::time::Instant;
 use winterfell::{
     math::{fields::f128::BaseElement, log2, FieldElement},
-    ProofOptions, Prover, StarkProof, Trace, TraceTable, VerifierError,
+    ProofOptions, Prover, Serializable, StarkProof, Trace, TraceTable, VerifierError,
 };
 
 mod air;
-use air::Fib8Air;
+pub use air::Fib8Air;
 
 mod prover;
 use prover::Fib8Prover;
@@ -96,6 +96,10 @@ impl Example for Fib8Example {
         prover.prove(trace).unwrap()
     }
 
+    fn public_inputs(&self) -> Vec<u8> {
+        self.result.to_bytes()
+    }
+
     fn verify(&self, proof: StarkProof) -> Result<(), VerifierError> {
         winterfell::verify::<Fib8Air>(proof, self.result)
     }
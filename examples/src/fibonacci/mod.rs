@@ -3,6 +3,7 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+pub mod declarative;
 pub mod fib2;
 pub mod fib8;
 pub mod mulfib2;
@@ -9,11 +9,11 @@ use log::debug;
 use std::time::Instant;
 use winterfell::{
     math::{fields::f128::BaseElement, log2, FieldElement},
-    ProofOptions, Prover, StarkProof, Trace, TraceTable, VerifierError,
+    ProofOptions, Prover, Serializable, StarkProof, Trace, TraceTable, VerifierError,
 };
 
 mod air;
-use air::MulFib2Air;
+pub use air::MulFib2Air;
 
 mod prover;
 use prover::MulFib2Prover;
@@ -91,6 +91,10 @@ impl Example for MulFib2Example {
         prover.prove(trace).unwrap()
     }
 
+    fn public_inputs(&self) -> Vec<u8> {
+        self.result.to_bytes()
+    }
+
     fn verify(&self, proof: StarkProof) -> Result<(), VerifierError> {
         winterfell::verify::<MulFib2Air>(proof, self.result)
     }
@@ -23,6 +23,7 @@ pub struct MulFib8Air {
 impl Air for MulFib8Air {
     type BaseField = BaseElement;
     type PublicInputs = BaseElement;
+    const ID: &'static [u8] = b"winterfell/examples/mulfib8";
 
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
@@ -9,11 +9,11 @@ use log::debug;
 use std::time::Instant;
 use winterfell::{
     math::{fields::f128::BaseElement, log2, FieldElement},
-    ProofOptions, Prover, StarkProof, Trace, TraceTable, VerifierError,
+    ProofOptions, Prover, Serializable, StarkProof, Trace, TraceTable, VerifierError,
 };
 
 mod air;
-use air::MulFib8Air;
+pub use air::MulFib8Air;
 
 mod prover;
 use prover::MulFib8Prover;
@@ -92,6 +92,10 @@ impl Example for MulFib8Example {
         prover.prove(trace).unwrap()
     }
 
+    fn public_inputs(&self) -> Vec<u8> {
+        self.result.to_bytes()
+    }
+
     fn verify(&self, proof: StarkProof) -> Result<(), VerifierError> {
         winterfell::verify::<MulFib8Air>(proof, self.result)
     }
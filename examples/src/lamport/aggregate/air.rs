@@ -9,8 +9,8 @@ use super::{
 use crate::utils::{are_equal, is_binary, is_zero, not, EvaluationResult};
 use winterfell::{
     math::{fields::f128::BaseElement, FieldElement},
-    Air, AirContext, Assertion, ByteWriter, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
-    TransitionConstraintDegree,
+    Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    EvaluationFrame, ProofOptions, Serializable, TraceInfo, TransitionConstraintDegree,
 };
 
 // CONSTANTS
@@ -33,6 +33,29 @@ impl Serializable for PublicInputs {
     }
 }
 
+impl Deserializable for PublicInputs {
+    /// Reads public inputs from the specified `source`; since the number of signatures is not
+    /// serialized, all remaining bytes of the `source` are consumed.
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let mut elements = Vec::new();
+        while source.has_more_bytes() {
+            elements.push(BaseElement::read_from(source)?);
+        }
+        if elements.len() % 4 != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "number of public key and message elements must be divisible by 4, but was {}",
+                elements.len()
+            )));
+        }
+
+        let (pub_keys, messages) = elements.split_at(elements.len() / 2);
+        Ok(PublicInputs {
+            pub_keys: pub_keys.chunks(2).map(|key| [key[0], key[1]]).collect(),
+            messages: messages.chunks(2).map(|msg| [msg[0], msg[1]]).collect(),
+        })
+    }
+}
+
 pub struct LamportAggregateAir {
     context: AirContext<BaseElement>,
     pub_keys: Vec<[BaseElement; 2]>,
@@ -42,6 +65,7 @@ pub struct LamportAggregateAir {
 impl Air for LamportAggregateAir {
     type BaseField = BaseElement;
     type PublicInputs = PublicInputs;
+    const ID: &'static [u8] = b"winterfell/examples/lamport_aggregate";
 
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
@@ -153,11 +177,11 @@ impl Air for LamportAggregateAir {
             Assertion::periodic(0, last_cycle_step, SIG_CYCLE_LEN, BaseElement::ZERO),
             Assertion::periodic(1, last_cycle_step, SIG_CYCLE_LEN, BaseElement::ZERO),
             // message accumulator registers should be set to message element values
-            Assertion::sequence(2, last_cycle_step, SIG_CYCLE_LEN, messages.0),
-            Assertion::sequence(3, last_cycle_step, SIG_CYCLE_LEN, messages.1),
+            Assertion::public_column(2, last_cycle_step, SIG_CYCLE_LEN, messages.0),
+            Assertion::public_column(3, last_cycle_step, SIG_CYCLE_LEN, messages.1),
             // public key hasher should terminate with public key elements
-            Assertion::sequence(16, last_cycle_step, SIG_CYCLE_LEN, pub_keys.0),
-            Assertion::sequence(17, last_cycle_step, SIG_CYCLE_LEN, pub_keys.1),
+            Assertion::public_column(16, last_cycle_step, SIG_CYCLE_LEN, pub_keys.0),
+            Assertion::public_column(17, last_cycle_step, SIG_CYCLE_LEN, pub_keys.1),
         ]
     }
 
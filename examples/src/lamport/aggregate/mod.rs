@@ -11,15 +11,18 @@ use log::debug;
 use std::time::Instant;
 use winterfell::{
     math::{fields::f128::BaseElement, get_power_series, log2, FieldElement, StarkField},
-    ProofOptions, Prover, StarkProof, Trace, TraceTable, VerifierError,
+    MatrixLayout, ProofOptions, Prover, Serializable, StarkProof, Trace, TraceTable, VerifierError,
 };
 
 mod air;
-use air::{LamportAggregateAir, PublicInputs};
+pub use air::{LamportAggregateAir, PublicInputs};
 
 mod prover;
 use prover::LamportAggregateProver;
 
+#[cfg(test)]
+mod tests;
+
 // CONSTANTS
 // ================================================================================================
 
@@ -37,6 +40,7 @@ pub fn get_example(options: ExampleOptions, num_signatures: usize) -> Box<dyn Ex
 
 pub struct LamportAggregateExample {
     options: ProofOptions,
+    lde_layout: MatrixLayout,
     pub_keys: Vec<[BaseElement; 2]>,
     messages: Vec<[BaseElement; 2]>,
     signatures: Vec<Signature>,
@@ -94,11 +98,25 @@ impl LamportAggregateExample {
 
         LamportAggregateExample {
             options,
+            lde_layout: MatrixLayout::default(),
             pub_keys,
             messages,
             signatures,
         }
     }
+
+    /// Sets the memory layout of low-degree extensions built when generating proofs.
+    pub fn with_lde_layout(mut self, lde_layout: MatrixLayout) -> Self {
+        self.lde_layout = lde_layout;
+        self
+    }
+
+    fn get_pub_inputs(&self) -> PublicInputs {
+        PublicInputs {
+            pub_keys: self.pub_keys.clone(),
+            messages: self.messages.clone(),
+        }
+    }
 }
 
 // EXAMPLE IMPLEMENTATION
@@ -114,8 +132,12 @@ impl Example for LamportAggregateExample {
         );
 
         // create a prover
-        let prover =
-            LamportAggregateProver::new(&self.pub_keys, &self.messages, self.options.clone());
+        let prover = LamportAggregateProver::new(
+            &self.pub_keys,
+            &self.messages,
+            self.options.clone(),
+            self.lde_layout,
+        );
 
         let now = Instant::now();
         let trace = prover.build_trace(&self.messages, &self.signatures);
@@ -131,12 +153,12 @@ impl Example for LamportAggregateExample {
         prover.prove(trace).unwrap()
     }
 
+    fn public_inputs(&self) -> Vec<u8> {
+        self.get_pub_inputs().to_bytes()
+    }
+
     fn verify(&self, proof: StarkProof) -> Result<(), VerifierError> {
-        let pub_inputs = PublicInputs {
-            pub_keys: self.pub_keys.clone(),
-            messages: self.messages.clone(),
-        };
-        winterfell::verify::<LamportAggregateAir>(proof, pub_inputs)
+        winterfell::verify::<LamportAggregateAir>(proof, self.get_pub_inputs())
     }
 
     fn verify_with_wrong_inputs(&self, proof: StarkProof) -> Result<(), VerifierError> {
@@ -4,9 +4,9 @@
 // LICENSE file in the root directory of this source tree.
 
 use super::{
-    get_power_series, rescue, BaseElement, FieldElement, LamportAggregateAir, ProofOptions, Prover,
-    PublicInputs, Signature, StarkField, TraceTable, CYCLE_LENGTH, NUM_HASH_ROUNDS,
-    SIG_CYCLE_LENGTH, TRACE_WIDTH,
+    get_power_series, rescue, BaseElement, FieldElement, LamportAggregateAir, MatrixLayout,
+    ProofOptions, Prover, PublicInputs, Signature, StarkField, TraceTable, CYCLE_LENGTH,
+    NUM_HASH_ROUNDS, SIG_CYCLE_LENGTH, TRACE_WIDTH,
 };
 
 #[cfg(feature = "concurrent")]
@@ -40,6 +40,7 @@ struct KeySchedule {
 pub struct LamportAggregateProver {
     pub_inputs: PublicInputs,
     options: ProofOptions,
+    lde_layout: MatrixLayout,
 }
 
 impl LamportAggregateProver {
@@ -47,6 +48,7 @@ impl LamportAggregateProver {
         pub_keys: &[[BaseElement; 2]],
         messages: &[[BaseElement; 2]],
         options: ProofOptions,
+        lde_layout: MatrixLayout,
     ) -> Self {
         let pub_inputs = PublicInputs {
             pub_keys: pub_keys.to_vec(),
@@ -55,6 +57,7 @@ impl LamportAggregateProver {
         Self {
             pub_inputs,
             options,
+            lde_layout,
         }
     }
 
@@ -98,6 +101,10 @@ impl Prover for LamportAggregateProver {
     fn options(&self) -> &ProofOptions {
         &self.options
     }
+
+    fn lde_layout(&self) -> MatrixLayout {
+        self.lde_layout
+    }
 }
 
 // TRACE INITIALIZATION
@@ -0,0 +1,42 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use winterfell::{FieldExtension, HashFunction, MatrixLayout, ProofOptions};
+
+#[test]
+fn lamport_aggregate_test_basic_proof_verification() {
+    let lamport_eg = Box::new(super::LamportAggregateExample::new(2, build_options(false)));
+    crate::tests::test_basic_proof_verification(lamport_eg);
+}
+
+#[test]
+fn lamport_aggregate_test_basic_proof_verification_extension() {
+    let lamport_eg = Box::new(super::LamportAggregateExample::new(2, build_options(true)));
+    crate::tests::test_basic_proof_verification(lamport_eg);
+}
+
+#[test]
+fn lamport_aggregate_test_basic_proof_verification_row_major() {
+    let lamport_eg = Box::new(
+        super::LamportAggregateExample::new(2, build_options(true))
+            .with_lde_layout(MatrixLayout::RowMajor),
+    );
+    crate::tests::test_basic_proof_verification(lamport_eg);
+}
+
+#[test]
+fn lamport_aggregate_test_basic_proof_verification_fail() {
+    let lamport_eg = Box::new(super::LamportAggregateExample::new(2, build_options(false)));
+    crate::tests::test_basic_proof_verification_fail(lamport_eg);
+}
+
+fn build_options(use_extension_field: bool) -> ProofOptions {
+    let extension = if use_extension_field {
+        FieldExtension::Quadratic
+    } else {
+        FieldExtension::None
+    };
+    ProofOptions::new(28, 8, 0, HashFunction::Blake3_256, extension, 4, 256)
+}
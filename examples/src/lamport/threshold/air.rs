@@ -10,8 +10,8 @@ use super::{
 use crate::utils::{are_equal, is_binary, is_zero, not, EvaluationResult};
 use winterfell::{
     math::{fields::f128::BaseElement, log2, FieldElement, StarkField},
-    Air, AirContext, Assertion, ByteWriter, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
-    TransitionConstraintDegree,
+    Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    EvaluationFrame, ProofOptions, Serializable, TraceInfo, TransitionConstraintDegree,
 };
 
 // CONSTANTS
@@ -38,6 +38,21 @@ impl Serializable for PublicInputs {
     }
 }
 
+impl Deserializable for PublicInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let pub_key_root = BaseElement::read_batch_from(source, 2)?;
+        let num_pub_keys = source.read_u32()? as usize;
+        let num_signatures = source.read_u32()? as usize;
+        let message = BaseElement::read_batch_from(source, 2)?;
+        Ok(PublicInputs {
+            pub_key_root: [pub_key_root[0], pub_key_root[1]],
+            num_pub_keys,
+            num_signatures,
+            message: [message[0], message[1]],
+        })
+    }
+}
+
 pub struct LamportThresholdAir {
     context: AirContext<BaseElement>,
     pub_key_root: [BaseElement; 2],
@@ -49,6 +64,7 @@ pub struct LamportThresholdAir {
 impl Air for LamportThresholdAir {
     type BaseField = BaseElement;
     type PublicInputs = PublicInputs;
+    const ID: &'static [u8] = b"winterfell/examples/lamport_threshold";
 
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
@@ -12,14 +12,14 @@ use log::debug;
 use std::time::Instant;
 use winterfell::{
     math::{fields::f128::BaseElement, get_power_series, log2, FieldElement, StarkField},
-    ProofOptions, Prover, StarkProof, Trace, TraceTable, VerifierError,
+    ProofOptions, Prover, Serializable, StarkProof, Trace, TraceTable, VerifierError,
 };
 
 mod signature;
 use signature::AggPublicKey;
 
 mod air;
-use air::{LamportThresholdAir, PublicInputs};
+pub use air::{LamportThresholdAir, PublicInputs};
 
 mod prover;
 use prover::LamportThresholdProver;
@@ -84,6 +84,15 @@ impl LamportThresholdExample {
             message: message_to_elements(message.as_bytes()),
         }
     }
+
+    fn get_pub_inputs(&self) -> PublicInputs {
+        PublicInputs {
+            pub_key_root: self.pub_key.root().to_elements(),
+            num_pub_keys: self.pub_key.num_keys(),
+            num_signatures: self.signatures.len(),
+            message: self.message,
+        }
+    }
 }
 
 // EXAMPLE IMPLEMENTATION
@@ -122,14 +131,12 @@ impl Example for LamportThresholdExample {
         prover.prove(trace).unwrap()
     }
 
+    fn public_inputs(&self) -> Vec<u8> {
+        self.get_pub_inputs().to_bytes()
+    }
+
     fn verify(&self, proof: StarkProof) -> Result<(), VerifierError> {
-        let pub_inputs = PublicInputs {
-            pub_key_root: self.pub_key.root().to_elements(),
-            num_pub_keys: self.pub_key.num_keys(),
-            num_signatures: self.signatures.len(),
-            message: self.message,
-        };
-        winterfell::verify::<LamportThresholdAir>(proof, pub_inputs)
+        winterfell::verify::<LamportThresholdAir>(proof, self.get_pub_inputs())
     }
 
     fn verify_with_wrong_inputs(&self, proof: StarkProof) -> Result<(), VerifierError> {
@@ -3,19 +3,27 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use std::path::PathBuf;
 use structopt::StructOpt;
-use winterfell::{FieldExtension, HashFunction, ProofOptions, StarkProof, VerifierError};
+use winterfell::{
+    Air, ByteReader, Deserializable, DeserializationError, FieldExtension, HashFunction,
+    ProofOptions, SliceReader, StarkProof, VerifierError,
+};
 
 pub mod fibonacci;
 #[cfg(feature = "std")]
 pub mod lamport;
 #[cfg(feature = "std")]
 pub mod merkle;
+#[cfg(feature = "std")]
+pub mod merkle_update;
 pub mod rescue;
+pub mod rescue_prime;
 #[cfg(feature = "std")]
 pub mod rescue_raps;
 pub mod utils;
 pub mod vdf;
+pub mod vm;
 
 #[cfg(test)]
 mod tests;
@@ -25,10 +33,28 @@ mod tests;
 
 pub trait Example {
     fn prove(&self) -> StarkProof;
+    fn public_inputs(&self) -> Vec<u8>;
     fn verify(&self, proof: StarkProof) -> Result<(), VerifierError>;
     fn verify_with_wrong_inputs(&self, proof: StarkProof) -> Result<(), VerifierError>;
 }
 
+/// Reads public inputs for the computation described by `AIR` from the specified bytes.
+///
+/// # Errors
+/// Returns an error if the bytes could not be parsed into valid public inputs, or if not all
+/// bytes were consumed.
+pub fn read_pub_inputs<AIR: Air>(bytes: &[u8]) -> Result<AIR::PublicInputs, DeserializationError>
+where
+    AIR::PublicInputs: Deserializable,
+{
+    let mut source = SliceReader::new(bytes);
+    let pub_inputs = AIR::PublicInputs::read_from(&mut source)?;
+    if source.has_more_bytes() {
+        return Err(DeserializationError::UnconsumedBytes);
+    }
+    Ok(pub_inputs)
+}
+
 // EXAMPLE OPTIONS
 // ================================================================================================
 
@@ -61,6 +87,14 @@ pub struct ExampleOptions {
     /// Folding factor for FRI protocol
     #[structopt(short = "f", long = "folding", default_value = "8")]
     folding_factor: usize,
+
+    /// File to which the generated proof is written
+    #[structopt(short = "o", long = "proof_file", parse(from_os_str))]
+    pub proof_file: Option<PathBuf>,
+
+    /// File to which public inputs of the proven computation are written
+    #[structopt(short = "i", long = "inputs_file", parse(from_os_str))]
+    pub inputs_file: Option<PathBuf>,
 }
 
 impl ExampleOptions {
@@ -77,6 +111,8 @@ impl ExampleOptions {
             "blake3_192" => HashFunction::Blake3_192,
             "blake3_256" => HashFunction::Blake3_256,
             "sha3_256" => HashFunction::Sha3_256,
+            "poseidon_64_256" => HashFunction::Poseidon64_256,
+            "keccak_256" => HashFunction::Keccak256,
             val => panic!("'{}' is not a valid hash function option", val),
         };
 
@@ -119,6 +155,12 @@ pub enum ExampleType {
         #[structopt(short = "n", default_value = "1048576")]
         sequence_length: usize,
     },
+    /// Same as the Fib example, but the AIR is described declaratively
+    FibDeclarative {
+        /// Length of Fibonacci sequence; must be a power of two
+        #[structopt(short = "n", default_value = "1048576")]
+        sequence_length: usize,
+    },
     /// Execute a simple VDF function
     Vdf {
         /// Number of steps in the VDF function; must be a power of two
@@ -131,12 +173,30 @@ pub enum ExampleType {
         #[structopt(short = "n", default_value = "1048575")]
         num_steps: usize,
     },
+    /// Similar to the VDF example, but the number of steps does not need to be a power of two.
+    VdfPadded {
+        /// Number of steps in the VDF function; must be at least 8
+        #[structopt(short = "n", default_value = "1000000")]
+        num_steps: usize,
+    },
+    /// Same as the VDF example, but the AIR is described declaratively
+    VdfDeclarative {
+        /// Number of steps in the VDF function; must be a power of two
+        #[structopt(short = "n", default_value = "1048576")]
+        num_steps: usize,
+    },
     /// Compute a hash chain using Rescue hash function
     Rescue {
         /// Length of the hash chain; must be a power of two
         #[structopt(short = "n", default_value = "1024")]
         chain_length: usize,
     },
+    /// Compute a hash chain using Rp64_256 (Rescue Prime) hash function
+    RescuePrime {
+        /// Length of the hash chain; must be a power of two
+        #[structopt(short = "n", default_value = "1024")]
+        chain_length: usize,
+    },
     /// Compute two hash chains absorbing sequences that are a permutation of each other
     #[cfg(feature = "std")]
     RescueRaps {
@@ -144,6 +204,12 @@ pub enum ExampleType {
         #[structopt(short = "n", default_value = "1024")]
         chain_length: usize,
     },
+    /// Execute a program which sums squares of integers on a register-based VM
+    Vm {
+        /// Number of integers to sum; must be greater than zero
+        #[structopt(short = "n", default_value = "1024")]
+        num_values: usize,
+    },
     /// Compute a root of a Merkle path using Rescue hash function
     #[cfg(feature = "std")]
     Merkle {
@@ -151,6 +217,28 @@ pub enum ExampleType {
         #[structopt(short = "n", default_value = "7")]
         tree_depth: usize,
     },
+    /// Prove a batch of leaf updates in a Merkle tree built using Rescue hash function
+    #[cfg(feature = "std")]
+    MerkleUpdate {
+        /// Depth of the Merkle tree; must be one less than a power of two
+        #[structopt(short = "n", default_value = "7")]
+        tree_depth: usize,
+
+        /// Number of leaf updates; must be a power of two
+        #[structopt(short = "u", default_value = "8")]
+        num_updates: usize,
+    },
+    /// Prove a batch of leaf updates in a Merkle tree built using Rp64_256 hash function
+    #[cfg(feature = "std")]
+    MerkleUpdateRp64 {
+        /// Depth of the Merkle tree; must be one less than a power of two
+        #[structopt(short = "n", default_value = "7")]
+        tree_depth: usize,
+
+        /// Number of leaf updates; must be a power of two
+        #[structopt(short = "u", default_value = "8")]
+        num_updates: usize,
+    },
     /// Compute an aggregate Lamport+ signature
     #[cfg(feature = "std")]
     LamportA {
@@ -5,17 +5,57 @@
 
 use log::debug;
 use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
+use std::{fs, process};
 use structopt::StructOpt;
-use winterfell::StarkProof;
+use winterfell::{
+    math::{
+        fields::{f128, f62, f64 as f64_field},
+        StarkField,
+    },
+    Air, Deserializable, ProofSection, Serializable, StarkProof,
+};
 
-use examples::{fibonacci, rescue, vdf, ExampleOptions, ExampleType};
+use examples::{
+    fibonacci, read_pub_inputs, rescue, rescue_prime, vdf, vm, ExampleOptions, ExampleType,
+};
 #[cfg(feature = "std")]
-use examples::{lamport, merkle, rescue_raps};
+use examples::{lamport, merkle, merkle_update, rescue_raps};
 
-// EXAMPLE RUNNER
+// COMMANDS
 // ================================================================================================
 
+#[derive(StructOpt, Debug)]
+#[structopt(name = "winterfell", about = "Winterfell STARK prover and verifier")]
+enum Command {
+    /// Generate a proof for one of the examples, verify it, and optionally write it to disk
+    Prove(ExampleOptions),
+    /// Verify a proof read from disk against public inputs read from disk
+    Verify {
+        /// Name of the example for which the proof was generated (e.g., fib, rescue, lamport-a)
+        example: String,
+
+        /// File containing the proof
+        #[structopt(short = "p", long = "proof_file", parse(from_os_str))]
+        proof_file: PathBuf,
+
+        /// File containing public inputs of the proven computation
+        #[structopt(short = "i", long = "inputs_file", parse(from_os_str))]
+        inputs_file: PathBuf,
+    },
+    /// Print context, options, security level, and component sizes of a proof read from disk
+    Inspect {
+        /// File containing the proof
+        #[structopt(parse(from_os_str))]
+        proof_file: PathBuf,
+
+        /// Also print all components of the proof decoded into field elements and digests
+        #[structopt(short = "d", long = "decode")]
+        decode: bool,
+    },
+}
+
 fn main() {
     // configure logging
     env_logger::Builder::new()
@@ -23,11 +63,32 @@ fn main() {
         .filter_level(log::LevelFilter::Debug)
         .init();
 
-    // read command-line args
-    let options = ExampleOptions::from_args();
+    // read command-line args and execute the command
+    let result = match Command::from_args() {
+        Command::Prove(options) => prove(options),
+        Command::Verify {
+            example,
+            proof_file,
+            inputs_file,
+        } => verify(&example, &proof_file, &inputs_file),
+        Command::Inspect { proof_file, decode } => inspect(&proof_file, decode),
+    };
+
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
+
+// PROVE
+// ================================================================================================
 
+fn prove(options: ExampleOptions) -> Result<(), String> {
     debug!("============================================================");
 
+    let proof_file = options.proof_file.clone();
+    let inputs_file = options.inputs_file.clone();
+
     // instantiate and prepare the example
     let example = match options.example {
         ExampleType::Fib { sequence_length } => {
@@ -42,14 +103,35 @@ fn main() {
         ExampleType::Mulfib8 { sequence_length } => {
             fibonacci::mulfib8::get_example(options, sequence_length)
         }
+        ExampleType::FibDeclarative { sequence_length } => {
+            fibonacci::declarative::get_example(options, sequence_length)
+        }
         ExampleType::Vdf { num_steps } => vdf::regular::get_example(options, num_steps),
         ExampleType::VdfExempt { num_steps } => vdf::exempt::get_example(options, num_steps),
+        ExampleType::VdfPadded { num_steps } => vdf::padded::get_example(options, num_steps),
+        ExampleType::VdfDeclarative { num_steps } => {
+            vdf::declarative::get_example(options, num_steps)
+        }
         ExampleType::Rescue { chain_length } => rescue::get_example(options, chain_length),
+        ExampleType::RescuePrime { chain_length } => {
+            rescue_prime::get_example(options, chain_length)
+        }
         #[cfg(feature = "std")]
         ExampleType::RescueRaps { chain_length } => rescue_raps::get_example(options, chain_length),
+        ExampleType::Vm { num_values } => vm::get_example(options, num_values),
         #[cfg(feature = "std")]
         ExampleType::Merkle { tree_depth } => merkle::get_example(options, tree_depth),
         #[cfg(feature = "std")]
+        ExampleType::MerkleUpdate {
+            tree_depth,
+            num_updates,
+        } => merkle_update::f128::get_example(options, tree_depth, num_updates),
+        #[cfg(feature = "std")]
+        ExampleType::MerkleUpdateRp64 {
+            tree_depth,
+            num_updates,
+        } => merkle_update::rp64_256::get_example(options, tree_depth, num_updates),
+        #[cfg(feature = "std")]
         ExampleType::LamportA { num_signatures } => {
             lamport::aggregate::get_example(options, num_signatures)
         }
@@ -76,6 +158,16 @@ fn main() {
         hex::encode(blake3::hash(&proof_bytes).as_bytes())
     );
 
+    // write the proof and public inputs to disk, if requested
+    if let Some(path) = proof_file {
+        write_file(&path, &proof_bytes)?;
+        debug!("Proof written to {}", path.display());
+    }
+    if let Some(path) = inputs_file {
+        write_file(&path, &example.public_inputs())?;
+        debug!("Public inputs written to {}", path.display());
+    }
+
     // verify the proof
     debug!("---------------------");
     let parsed_proof = StarkProof::from_bytes(&proof_bytes).unwrap();
@@ -89,4 +181,185 @@ fn main() {
         Err(msg) => debug!("Failed to verify proof: {}", msg),
     }
     debug!("============================================================");
+    Ok(())
+}
+
+// VERIFY
+// ================================================================================================
+
+fn verify(example: &str, proof_file: &Path, inputs_file: &Path) -> Result<(), String> {
+    let proof = read_proof(proof_file)?;
+    let pub_inputs = read_file(inputs_file)?;
+
+    let now = Instant::now();
+    match example {
+        "fib" => verify_proof::<fibonacci::fib2::FibAir>(proof, &pub_inputs),
+        "fib8" => verify_proof::<fibonacci::fib8::Fib8Air>(proof, &pub_inputs),
+        "mulfib" => verify_proof::<fibonacci::mulfib2::MulFib2Air>(proof, &pub_inputs),
+        "mulfib8" => verify_proof::<fibonacci::mulfib8::MulFib8Air>(proof, &pub_inputs),
+        "fib-declarative" => verify_proof::<fibonacci::declarative::FibAir>(proof, &pub_inputs),
+        "vdf" => verify_proof::<vdf::regular::VdfAir>(proof, &pub_inputs),
+        "vdf-exempt" => verify_proof::<vdf::exempt::VdfAir>(proof, &pub_inputs),
+        "vdf-padded" => verify_proof::<vdf::padded::VdfAir>(proof, &pub_inputs),
+        "vdf-declarative" => verify_proof::<vdf::declarative::VdfAir>(proof, &pub_inputs),
+        "rescue" => verify_proof::<rescue::RescueAir>(proof, &pub_inputs),
+        "rescue-prime" => verify_proof::<rescue_prime::RescuePrimeAir>(proof, &pub_inputs),
+        #[cfg(feature = "std")]
+        "rescue-raps" => verify_proof::<rescue_raps::RescueRapsAir>(proof, &pub_inputs),
+        "vm" => verify_proof::<vm::VmAir>(proof, &pub_inputs),
+        #[cfg(feature = "std")]
+        "merkle" => verify_proof::<merkle::MerkleAir>(proof, &pub_inputs),
+        #[cfg(feature = "std")]
+        "merkle-update" => verify_proof::<merkle_update::f128::MerkleUpdateAir>(proof, &pub_inputs),
+        #[cfg(feature = "std")]
+        "merkle-update-rp64" => {
+            verify_proof::<merkle_update::rp64_256::MerkleUpdateAir>(proof, &pub_inputs)
+        }
+        #[cfg(feature = "std")]
+        "lamport-a" => verify_proof::<lamport::aggregate::LamportAggregateAir>(proof, &pub_inputs),
+        #[cfg(feature = "std")]
+        "lamport-t" => verify_proof::<lamport::threshold::LamportThresholdAir>(proof, &pub_inputs),
+        _ => return Err(format!("'{}' is not a valid example", example)),
+    }?;
+
+    println!(
+        "Proof verified in {:.1} ms",
+        now.elapsed().as_micros() as f64 / 1000f64
+    );
+    Ok(())
+}
+
+/// Verifies the proof for the computation described by `AIR` against serialized public inputs.
+fn verify_proof<AIR: Air>(proof: StarkProof, pub_inputs: &[u8]) -> Result<(), String>
+where
+    AIR::PublicInputs: Deserializable,
+{
+    let pub_inputs = read_pub_inputs::<AIR>(pub_inputs)
+        .map_err(|err| format!("failed to parse public inputs: {}", err))?;
+    winterfell::verify::<AIR>(proof, pub_inputs)
+        .map_err(|err| format!("failed to verify proof: {}", err))
+}
+
+// INSPECT
+// ================================================================================================
+
+fn inspect(proof_file: &Path, decode: bool) -> Result<(), String> {
+    let proof_bytes = read_file(proof_file)?;
+    let proof = StarkProof::from_bytes(&proof_bytes)
+        .map_err(|err| format!("failed to parse proof: {}", err))?;
+    let context = &proof.context;
+    let options = proof.options();
+    let layout = proof.trace_layout();
+
+    println!(
+        "Proof: {} ({} bytes)",
+        proof_file.display(),
+        proof_bytes.len()
+    );
+    if proof_bytes.starts_with(&StarkProof::MAGIC) {
+        println!("Format version: {}", proof_bytes[StarkProof::MAGIC.len()]);
+    } else {
+        println!("Format version: 1 (legacy)");
+    }
+
+    println!("Context");
+    println!("  protocol version: {}", context.version());
+    println!("  field: {}", field_name(context.field_modulus_bytes()));
+    println!("  trace length: {}", proof.trace_length());
+    println!("  main trace width: {}", layout.main_trace_width());
+    for i in 0..layout.num_aux_segments() {
+        println!(
+            "  aux segment {} width: {}",
+            i,
+            layout.get_aux_segment_width(i)
+        );
+    }
+    println!(
+        "  trace meta: {} bytes",
+        proof.get_trace_info().meta().len()
+    );
+    println!("  LDE domain size: {}", proof.lde_domain_size());
+
+    println!("Options");
+    println!("  hash function: {:?}", options.hash_fn());
+    println!("  field extension: {:?}", options.field_extension());
+    println!("  blowup factor: {}", options.blowup_factor());
+    println!("  number of queries: {}", options.num_queries());
+    println!("  grinding factor: {}", options.grinding_factor());
+    println!(
+        "  FRI folding factor: {}",
+        options.to_fri_options().folding_factor()
+    );
+
+    println!(
+        "Security: {} bits (conjectured)",
+        proof.security_level(true)
+    );
+
+    println!("Component sizes");
+    let sizes = [
+        (ProofSection::Context, context.to_bytes().len()),
+        (
+            ProofSection::Commitments,
+            proof.commitments.to_bytes().len(),
+        ),
+        (
+            ProofSection::TraceQueries,
+            proof.trace_queries.to_bytes().len(),
+        ),
+        (
+            ProofSection::ConstraintQueries,
+            proof.constraint_queries.to_bytes().len(),
+        ),
+        (ProofSection::OodFrame, proof.ood_frame.to_bytes().len()),
+        (ProofSection::FriProof, proof.fri_proof.to_bytes().len()),
+        (ProofSection::PowNonce, proof.pow_nonce.to_le_bytes().len()),
+    ];
+    for (section, size) in sizes {
+        println!("  {}: {} bytes", section, size);
+    }
+    println!("  FRI layers: {}", proof.fri_proof.num_layers());
+
+    if decode {
+        let decoded = match field_name(context.field_modulus_bytes()) {
+            "f62" => proof.to_pretty_string::<f62::BaseElement>(),
+            "f64" => proof.to_pretty_string::<f64_field::BaseElement>(),
+            "f128" => proof.to_pretty_string::<f128::BaseElement>(),
+            _ => return Err("cannot decode proofs over an unknown field".to_string()),
+        }
+        .map_err(|err| format!("failed to decode proof: {}", err))?;
+        println!("Decoded proof");
+        println!("{}", decoded);
+    }
+
+    Ok(())
+}
+
+/// Returns the name of one of the built-in fields with the specified modulus.
+fn field_name(modulus_bytes: &[u8]) -> &'static str {
+    if modulus_bytes == f62::BaseElement::get_modulus_le_bytes() {
+        "f62"
+    } else if modulus_bytes == f64_field::BaseElement::get_modulus_le_bytes() {
+        "f64"
+    } else if modulus_bytes == f128::BaseElement::get_modulus_le_bytes() {
+        "f128"
+    } else {
+        "unknown"
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn read_proof(path: &Path) -> Result<StarkProof, String> {
+    let proof_bytes = read_file(path)?;
+    StarkProof::from_bytes(&proof_bytes).map_err(|err| format!("failed to parse proof: {}", err))
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>, String> {
+    fs::read(path).map_err(|err| format!("failed to read {}: {}", path.display(), err))
+}
+
+fn write_file(path: &Path, bytes: &[u8]) -> Result<(), String> {
+    fs::write(path, bytes).map_err(|err| format!("failed to write {}: {}", path.display(), err))
 }
@@ -17,11 +17,11 @@ use std::time::Instant;
 use winterfell::{
     crypto::{Digest, MerkleTree},
     math::{fields::f128::BaseElement, log2, FieldElement, StarkField},
-    ProofOptions, Prover, StarkProof, Trace, TraceTable, VerifierError,
+    ProofOptions, Prover, Serializable, StarkProof, Trace, TraceTable, VerifierError,
 };
 
 mod air;
-use air::{MerkleAir, PublicInputs};
+pub use air::{MerkleAir, PublicInputs};
 
 mod prover;
 use prover::MerkleProver;
@@ -88,6 +88,12 @@ impl MerkleExample {
             path,
         }
     }
+
+    fn get_pub_inputs(&self) -> PublicInputs {
+        PublicInputs {
+            tree_root: self.tree_root.to_elements(),
+        }
+    }
 }
 
 // EXAMPLE IMPLEMENTATION
@@ -119,11 +125,12 @@ impl Example for MerkleExample {
         prover.prove(trace).unwrap()
     }
 
+    fn public_inputs(&self) -> Vec<u8> {
+        self.get_pub_inputs().to_bytes()
+    }
+
     fn verify(&self, proof: StarkProof) -> Result<(), VerifierError> {
-        let pub_inputs = PublicInputs {
-            tree_root: self.tree_root.to_elements(),
-        };
-        winterfell::verify::<MerkleAir>(proof, pub_inputs)
+        winterfell::verify::<MerkleAir>(proof, self.get_pub_inputs())
     }
 
     fn verify_with_wrong_inputs(&self, proof: StarkProof) -> Result<(), VerifierError> {
@@ -23,6 +23,12 @@ fn merkle_test_basic_proof_verification_fail() {
     crate::tests::test_basic_proof_verification_fail(merkle);
 }
 
+#[test]
+fn merkle_test_public_inputs_serialization() {
+    let merkle = Box::new(super::MerkleExample::new(7, build_options(false)));
+    crate::tests::test_public_inputs_serialization::<super::MerkleAir>(merkle);
+}
+
 fn build_options(use_extension_field: bool) -> ProofOptions {
     let extension = if use_extension_field {
         FieldExtension::Quadratic
@@ -0,0 +1,289 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{
+    get_update_cycle_length, rescue, BaseElement, FieldElement, DIGEST_SIZE, HASH_CYCLE_LEN,
+    HASH_STATE_WIDTH, INDEX_BIT, NEW_HASH, OLD_HASH, ROOT, TRACE_WIDTH,
+};
+use crate::utils::{are_equal, is_binary, not};
+use winterfell::{
+    Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    EvaluationFrame, ProofOptions, Serializable, TraceInfo, TransitionConstraintDegree,
+    TransitionDivisor,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+// Offsets of constraint groups in the list of transition constraints.
+const OLD_ROUND: usize = 0;
+const NEW_ROUND: usize = OLD_ROUND + HASH_STATE_WIDTH;
+const OLD_INIT: usize = NEW_ROUND + HASH_STATE_WIDTH;
+const NEW_INIT: usize = OLD_INIT + HASH_STATE_WIDTH;
+const SIBLING: usize = NEW_INIT + HASH_STATE_WIDTH;
+const BIT: usize = SIBLING + DIGEST_SIZE;
+const ROOT_UPDATE: usize = BIT + 1;
+const ROOT_CHECK: usize = ROOT_UPDATE + DIGEST_SIZE;
+const NUM_CONSTRAINTS: usize = ROOT_CHECK + DIGEST_SIZE;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+pub struct PublicInputs {
+    pub tree_depth: usize,
+    pub old_root: [BaseElement; DIGEST_SIZE],
+    pub new_root: [BaseElement; DIGEST_SIZE],
+}
+
+impl Serializable for PublicInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.tree_depth as u8);
+        target.write(&self.old_root[..]);
+        target.write(&self.new_root[..]);
+    }
+}
+
+impl Deserializable for PublicInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let tree_depth = source.read_u8()? as usize;
+        if !(tree_depth + 1).is_power_of_two() {
+            return Err(DeserializationError::InvalidValue(format!(
+                "tree depth must be one less than a power of 2, but was {}",
+                tree_depth
+            )));
+        }
+        let old_root = BaseElement::read_batch_from(source, DIGEST_SIZE)?;
+        let new_root = BaseElement::read_batch_from(source, DIGEST_SIZE)?;
+        Ok(PublicInputs {
+            tree_depth,
+            old_root: [old_root[0], old_root[1]],
+            new_root: [new_root[0], new_root[1]],
+        })
+    }
+}
+
+// MERKLE TREE UPDATE AIR
+// ================================================================================================
+
+/// AIR for updating leaves of a Merkle tree one by one.
+///
+/// Every update occupies a cycle of `(tree_depth + 1) * 8` steps. Within this cycle, two Merkle
+/// paths are computed in parallel: one from the old value of the leaf and one from the new value.
+/// Both paths share the index bits and the sibling nodes, and thus, they are paths for the same
+/// leaf in the same tree. The root computed from the old value must be equal to the root of the
+/// tree before the update, and the root computed from the new value becomes the root of the tree
+/// for the next update.
+pub struct MerkleUpdateAir {
+    context: AirContext<BaseElement>,
+    update_cycle_len: usize,
+    old_root: [BaseElement; DIGEST_SIZE],
+    new_root: [BaseElement; DIGEST_SIZE],
+}
+
+impl Air for MerkleUpdateAir {
+    type BaseField = BaseElement;
+    type PublicInputs = PublicInputs;
+    const ID: &'static [u8] = b"winterfell/examples/merkle_update_f128";
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        let update_cycle_len = get_update_cycle_length(pub_inputs.tree_depth, HASH_CYCLE_LEN);
+        assert!(
+            trace_info.length() % update_cycle_len == 0,
+            "execution trace length must be a multiple of {}",
+            update_cycle_len
+        );
+
+        let round_degree = TransitionConstraintDegree::with_cycles(5, vec![HASH_CYCLE_LEN]);
+        let path_degree = TransitionConstraintDegree::with_cycles(2, vec![update_cycle_len]);
+        let mut degrees = vec![round_degree; 2 * HASH_STATE_WIDTH];
+        for _ in 0..2 {
+            degrees.append(&mut vec![path_degree.clone(); 2 * DIGEST_SIZE]);
+            degrees.append(&mut vec![TransitionConstraintDegree::new(1); 2]);
+        }
+        degrees.append(&mut vec![path_degree; DIGEST_SIZE]);
+        degrees.push(TransitionConstraintDegree::new(2));
+        degrees.append(&mut vec![
+            TransitionConstraintDegree::with_cycles(
+                1,
+                vec![update_cycle_len]
+            );
+            DIGEST_SIZE
+        ]);
+        degrees.append(&mut vec![TransitionConstraintDegree::new(1); DIGEST_SIZE]);
+        debug_assert_eq!(NUM_CONSTRAINTS, degrees.len());
+
+        // hasher states need to be initialized only on the last step of every hash cycle, and the
+        // root of the tree needs to be checked only on the last step of every update cycle
+        let context = AirContext::new(trace_info, degrees, 2 * HASH_STATE_WIDTH, options)
+            .set_main_transition_divisor(
+                OLD_INIT..BIT,
+                TransitionDivisor::periodic(HASH_CYCLE_LEN, HASH_CYCLE_LEN - 1)
+                    .with_num_exemptions(1),
+            )
+            .set_main_transition_divisor(
+                ROOT_CHECK..NUM_CONSTRAINTS,
+                TransitionDivisor::periodic(update_cycle_len, update_cycle_len - 1),
+            );
+
+        MerkleUpdateAir {
+            context,
+            update_cycle_len,
+            old_root: pub_inputs.old_root,
+            new_root: pub_inputs.new_root,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        debug_assert_eq!(TRACE_WIDTH, current.len());
+        debug_assert_eq!(TRACE_WIDTH, next.len());
+
+        // split periodic values into masks and Rescue round constants
+        let hash_flag = periodic_values[0];
+        let update_flag = periodic_values[1];
+        let ark = &periodic_values[2..];
+
+        // when hash_flag = 1, constraints for Rescue round are enforced for both hasher states
+        let old_hash = OLD_HASH..OLD_HASH + HASH_STATE_WIDTH;
+        let new_hash = NEW_HASH..NEW_HASH + HASH_STATE_WIDTH;
+        rescue::enforce_round(
+            &mut result[OLD_ROUND..NEW_ROUND],
+            &current[old_hash.clone()],
+            &next[old_hash.clone()],
+            ark,
+            hash_flag,
+        );
+        rescue::enforce_round(
+            &mut result[NEW_ROUND..OLD_INIT],
+            &current[new_hash.clone()],
+            &next[new_hash.clone()],
+            ark,
+            hash_flag,
+        );
+
+        // on the last step of every hash cycle, hasher states are initialized either for hashing
+        // the next node of the path, or, on the last step of every update cycle, for hashing the
+        // value of the next leaf
+        let bit = next[INDEX_BIT];
+        enforce_state_init(
+            &mut result[OLD_INIT..NEW_INIT],
+            &current[old_hash.clone()],
+            &next[old_hash.clone()],
+            bit,
+            update_flag,
+        );
+        enforce_state_init(
+            &mut result[NEW_INIT..SIBLING],
+            &current[new_hash.clone()],
+            &next[new_hash.clone()],
+            bit,
+            update_flag,
+        );
+
+        // both hasher states must contain the same sibling node
+        let (old_next, new_next) = (&next[old_hash], &next[new_hash]);
+        for i in 0..DIGEST_SIZE {
+            let sibling = DIGEST_SIZE + i;
+            result[SIBLING + i] = not(update_flag)
+                * (not(bit) * are_equal(old_next[sibling], new_next[sibling])
+                    + bit * are_equal(old_next[i], new_next[i]));
+        }
+
+        // index bits must be binary
+        result[BIT] = is_binary(current[INDEX_BIT]);
+
+        // the root remains the same until the end of the update cycle; at this point, the root
+        // computed from the new value of the leaf becomes the root for the next update
+        for i in 0..DIGEST_SIZE {
+            let root = current[ROOT + i];
+            result[ROOT_UPDATE + i] = are_equal(
+                next[ROOT + i],
+                root + update_flag * (current[NEW_HASH + i] - root),
+            );
+        }
+
+        // at the end of the update cycle, the root computed from the old value of the leaf must
+        // be equal to the root of the tree before the update
+        for i in 0..DIGEST_SIZE {
+            result[ROOT_CHECK + i] = are_equal(current[OLD_HASH + i], current[ROOT + i]);
+        }
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // assert that the leaf values are hashed with all other hasher registers set to ZERO,
+        // that the tree starts with the old root, and that the last update results in the new
+        // root
+        let last_step = self.trace_length() - 1;
+        let mut assertions = Vec::new();
+        for i in DIGEST_SIZE..HASH_STATE_WIDTH {
+            assertions.push(Assertion::single(OLD_HASH + i, 0, BaseElement::ZERO));
+            assertions.push(Assertion::single(NEW_HASH + i, 0, BaseElement::ZERO));
+        }
+        for i in 0..DIGEST_SIZE {
+            assertions.push(Assertion::single(ROOT + i, 0, self.old_root[i]));
+            assertions.push(Assertion::single(NEW_HASH + i, last_step, self.new_root[i]));
+        }
+        assertions
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        let mut update_mask = vec![BaseElement::ZERO; self.update_cycle_len];
+        update_mask[self.update_cycle_len - 1] = BaseElement::ONE;
+
+        let mut result = vec![HASH_CYCLE_MASK.to_vec(), update_mask];
+        result.append(&mut rescue::get_round_constants());
+        result
+    }
+}
+
+// HELPER EVALUATORS
+// ------------------------------------------------------------------------------------------------
+
+/// Enforces that the next hasher state is initialized for hashing the digest contained in the
+/// current state together with the next sibling node; the `bit` defines whether the digest goes
+/// into the first or the second half of the rate. When `update_flag` = 1, the next state is
+/// instead initialized for hashing a new leaf value, i.e., all registers except for the first
+/// half of the rate must be set to ZERO.
+fn enforce_state_init<E: FieldElement>(
+    result: &mut [E],
+    current: &[E],
+    next: &[E],
+    bit: E,
+    update_flag: E,
+) {
+    let path_flag = not(update_flag);
+    for i in 0..DIGEST_SIZE {
+        let j = DIGEST_SIZE + i;
+        result[i] = path_flag * not(bit) * are_equal(next[i], current[i]);
+        result[j] = path_flag * bit * are_equal(next[j], current[i]) + update_flag * next[j];
+    }
+    result[2 * DIGEST_SIZE..].copy_from_slice(&next[2 * DIGEST_SIZE..]);
+}
+
+// MASKS
+// ================================================================================================
+const HASH_CYCLE_MASK: [BaseElement; HASH_CYCLE_LEN] = [
+    BaseElement::ONE,
+    BaseElement::ONE,
+    BaseElement::ONE,
+    BaseElement::ONE,
+    BaseElement::ONE,
+    BaseElement::ONE,
+    BaseElement::ONE,
+    BaseElement::ZERO,
+];
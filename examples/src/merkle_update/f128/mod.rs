@@ -0,0 +1,163 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{apply_updates, get_update_cycle_length, validate_params, Example, LeafUpdate};
+use crate::utils::rescue::{
+    self, Hash, Rescue128, CYCLE_LENGTH as HASH_CYCLE_LEN, NUM_ROUNDS as NUM_HASH_ROUNDS,
+    STATE_WIDTH as HASH_STATE_WIDTH,
+};
+use crate::ExampleOptions;
+use log::debug;
+use rand_utils::rand_value;
+use std::time::Instant;
+use winterfell::{
+    crypto::Digest,
+    math::{fields::f128::BaseElement, log2, FieldElement},
+    ProofOptions, Prover, Serializable, StarkProof, Trace, TraceTable, VerifierError,
+};
+
+mod air;
+pub use air::{MerkleUpdateAir, PublicInputs};
+
+mod prover;
+use prover::MerkleUpdateProver;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of elements in a leaf value and in a tree node.
+const DIGEST_SIZE: usize = 2;
+
+// Columns of the execution trace: hasher states for the old and the new value of the leaf, the
+// bit of the leaf index which defines the position of the next path node in both hasher states,
+// and the root of the tree before the leaf is updated.
+const OLD_HASH: usize = 0;
+const NEW_HASH: usize = OLD_HASH + HASH_STATE_WIDTH;
+const INDEX_BIT: usize = NEW_HASH + HASH_STATE_WIDTH;
+const ROOT: usize = INDEX_BIT + 1;
+
+const TRACE_WIDTH: usize = ROOT + DIGEST_SIZE;
+
+// MERKLE TREE UPDATE EXAMPLE
+// ================================================================================================
+
+pub fn get_example(
+    options: ExampleOptions,
+    tree_depth: usize,
+    num_updates: usize,
+) -> Box<dyn Example> {
+    Box::new(MerkleUpdateExample::new(
+        tree_depth,
+        num_updates,
+        options.to_proof_options(28, 8),
+    ))
+}
+
+/// Proves that a batch of leaf updates transitions a Merkle tree built using Rescue hash function
+/// from the old root to the new root.
+pub struct MerkleUpdateExample {
+    options: ProofOptions,
+    tree_depth: usize,
+    old_root: Hash,
+    new_root: Hash,
+    updates: Vec<LeafUpdate<[BaseElement; DIGEST_SIZE], Hash>>,
+}
+
+impl MerkleUpdateExample {
+    pub fn new(tree_depth: usize, num_updates: usize, options: ProofOptions) -> Self {
+        validate_params(tree_depth, num_updates);
+
+        // build random leaf values and a random set of updates
+        let num_leaves = 1 << tree_depth;
+        let mut values = (0..num_leaves)
+            .map(|_| [rand_value(), rand_value()])
+            .collect::<Vec<[BaseElement; DIGEST_SIZE]>>();
+        let updates = (0..num_updates)
+            .map(|_| {
+                let index = (rand_value::<u64>() % num_leaves as u64) as usize;
+                (index, [rand_value(), rand_value()])
+            })
+            .collect::<Vec<_>>();
+
+        // apply the updates to the tree
+        let now = Instant::now();
+        let (old_root, new_root, updates) =
+            apply_updates::<Rescue128, _, _>(&mut values, &updates, |value| {
+                Rescue128::digest(value)
+            });
+        debug!(
+            "Applied {} updates to a Merkle tree of depth {} in {} ms; tree root changed from {} to {}",
+            num_updates,
+            tree_depth,
+            now.elapsed().as_millis(),
+            hex::encode(old_root.as_bytes()),
+            hex::encode(new_root.as_bytes()),
+        );
+
+        MerkleUpdateExample {
+            options,
+            tree_depth,
+            old_root,
+            new_root,
+            updates,
+        }
+    }
+
+    fn get_pub_inputs(&self) -> PublicInputs {
+        PublicInputs {
+            tree_depth: self.tree_depth,
+            old_root: self.old_root.to_elements(),
+            new_root: self.new_root.to_elements(),
+        }
+    }
+}
+
+// EXAMPLE IMPLEMENTATION
+// ================================================================================================
+
+impl Example for MerkleUpdateExample {
+    fn prove(&self) -> StarkProof {
+        debug!(
+            "Generating proof for applying {} updates to a Merkle tree of depth {}\n\
+            ---------------------",
+            self.updates.len(),
+            self.tree_depth
+        );
+
+        // create the prover
+        let prover = MerkleUpdateProver::new(self.tree_depth, self.options.clone());
+
+        // generate the execution trace
+        let now = Instant::now();
+        let trace = prover.build_trace(self.old_root, &self.updates);
+        let trace_length = trace.length();
+        debug!(
+            "Generated execution trace of {} registers and 2^{} steps in {} ms",
+            trace.width(),
+            log2(trace_length),
+            now.elapsed().as_millis()
+        );
+
+        // generate the proof
+        prover.prove(trace).unwrap()
+    }
+
+    fn public_inputs(&self) -> Vec<u8> {
+        self.get_pub_inputs().to_bytes()
+    }
+
+    fn verify(&self, proof: StarkProof) -> Result<(), VerifierError> {
+        winterfell::verify::<MerkleUpdateAir>(proof, self.get_pub_inputs())
+    }
+
+    fn verify_with_wrong_inputs(&self, proof: StarkProof) -> Result<(), VerifierError> {
+        let mut pub_inputs = self.get_pub_inputs();
+        pub_inputs.new_root[0] += BaseElement::ONE;
+        winterfell::verify::<MerkleUpdateAir>(proof, pub_inputs)
+    }
+}
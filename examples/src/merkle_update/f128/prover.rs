@@ -0,0 +1,145 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{
+    get_update_cycle_length, rescue, BaseElement, FieldElement, Hash, LeafUpdate, MerkleUpdateAir,
+    ProofOptions, Prover, PublicInputs, Trace, TraceTable, DIGEST_SIZE, HASH_CYCLE_LEN,
+    HASH_STATE_WIDTH, INDEX_BIT, NEW_HASH, NUM_HASH_ROUNDS, OLD_HASH, ROOT, TRACE_WIDTH,
+};
+
+// MERKLE TREE UPDATE PROVER
+// ================================================================================================
+
+pub struct MerkleUpdateProver {
+    tree_depth: usize,
+    options: ProofOptions,
+}
+
+impl MerkleUpdateProver {
+    pub fn new(tree_depth: usize, options: ProofOptions) -> Self {
+        Self {
+            tree_depth,
+            options,
+        }
+    }
+
+    pub fn build_trace(
+        &self,
+        old_root: Hash,
+        updates: &[LeafUpdate<[BaseElement; DIGEST_SIZE], Hash>],
+    ) -> TraceTable<BaseElement> {
+        // allocate memory to hold the trace table
+        let update_cycle_len = get_update_cycle_length(self.tree_depth, HASH_CYCLE_LEN);
+        let trace_length = updates.len() * update_cycle_len;
+        let mut trace = TraceTable::new(TRACE_WIDTH, trace_length);
+
+        trace.fill(
+            |state| {
+                // initialize hasher states with the old and the new value of the first leaf
+                init_leaf_state(state, &updates[0]);
+                state[INDEX_BIT] = BaseElement::ZERO;
+                state[ROOT..ROOT + DIGEST_SIZE].copy_from_slice(&old_root.to_elements());
+            },
+            |step, state| {
+                // for the first 7 steps of each 8-step cycle, compute a single round of Rescue
+                // hash in both hasher states. On the 8th step, insert the next path node into
+                // both hasher states in the positions defined by the next bit of the leaf index;
+                // at the end of an update cycle, start hashing values of the next leaf instead.
+                let update = &updates[step / update_cycle_len];
+                let cycle_num = (step % update_cycle_len) / HASH_CYCLE_LEN;
+                let cycle_pos = step % HASH_CYCLE_LEN;
+
+                if cycle_pos < NUM_HASH_ROUNDS {
+                    rescue::apply_round(&mut state[OLD_HASH..OLD_HASH + HASH_STATE_WIDTH], step);
+                    rescue::apply_round(&mut state[NEW_HASH..NEW_HASH + HASH_STATE_WIDTH], step);
+                } else if cycle_num == self.tree_depth {
+                    // the root computed from the new leaf value becomes the root of the tree
+                    for i in 0..DIGEST_SIZE {
+                        state[ROOT + i] = state[NEW_HASH + i];
+                    }
+                    init_leaf_state(state, &updates[step / update_cycle_len + 1]);
+                    state[INDEX_BIT] = BaseElement::ZERO;
+                } else {
+                    let node = update.path[cycle_num].to_elements();
+                    let index_bit = BaseElement::new(((update.index >> cycle_num) & 1) as u128);
+                    init_node_state(
+                        &mut state[OLD_HASH..OLD_HASH + HASH_STATE_WIDTH],
+                        node,
+                        index_bit,
+                    );
+                    init_node_state(
+                        &mut state[NEW_HASH..NEW_HASH + HASH_STATE_WIDTH],
+                        node,
+                        index_bit,
+                    );
+                    state[INDEX_BIT] = index_bit;
+                }
+            },
+        );
+
+        // set index bit at the second step to one; this still results in a valid execution trace
+        // because index bits are read only on the first step of every hash cycle, but it ensures
+        // that there are no repeating patterns in the index bit register, and thus the degree of
+        // the index bit constraint is stable.
+        trace.set(INDEX_BIT, 1, FieldElement::ONE);
+
+        trace
+    }
+}
+
+impl Prover for MerkleUpdateProver {
+    type BaseField = BaseElement;
+    type Air = MerkleUpdateAir;
+    type Trace = TraceTable<BaseElement>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs {
+        let last_step = trace.length() - 1;
+        PublicInputs {
+            tree_depth: self.tree_depth,
+            old_root: [trace.get(ROOT, 0), trace.get(ROOT + 1, 0)],
+            new_root: [
+                trace.get(NEW_HASH, last_step),
+                trace.get(NEW_HASH + 1, last_step),
+            ],
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Initializes hasher states for hashing the old and the new value of the updated leaf.
+fn init_leaf_state(
+    state: &mut [BaseElement],
+    update: &LeafUpdate<[BaseElement; DIGEST_SIZE], Hash>,
+) {
+    for (offset, value) in [(OLD_HASH, update.old_value), (NEW_HASH, update.new_value)] {
+        state[offset..offset + DIGEST_SIZE].copy_from_slice(&value);
+        state[offset + DIGEST_SIZE..offset + HASH_STATE_WIDTH].fill(BaseElement::ZERO);
+    }
+}
+
+/// Initializes the hasher state for merging the digest in the state with the specified path node.
+/// If the index bit is ZERO, the node goes into registers [2, 3] and the digest remains in
+/// registers [0, 1]; otherwise, the digest goes into registers [2, 3], and the node goes into
+/// registers [0, 1].
+fn init_node_state(
+    state: &mut [BaseElement],
+    node: [BaseElement; DIGEST_SIZE],
+    index_bit: BaseElement,
+) {
+    if index_bit == BaseElement::ZERO {
+        state[DIGEST_SIZE..2 * DIGEST_SIZE].copy_from_slice(&node);
+    } else {
+        state.copy_within(0..DIGEST_SIZE, DIGEST_SIZE);
+        state[..DIGEST_SIZE].copy_from_slice(&node);
+    }
+    // reset the capacity registers of the state to ZERO
+    state[2 * DIGEST_SIZE..].fill(BaseElement::ZERO);
+}
@@ -0,0 +1,45 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use winterfell::{FieldExtension, HashFunction, ProofOptions};
+
+#[test]
+fn merkle_update_f128_test_basic_proof_verification() {
+    let merkle = Box::new(super::MerkleUpdateExample::new(7, 4, build_options(false)));
+    crate::tests::test_basic_proof_verification(merkle);
+}
+
+#[test]
+fn merkle_update_f128_test_basic_proof_verification_extension() {
+    let merkle = Box::new(super::MerkleUpdateExample::new(7, 4, build_options(true)));
+    crate::tests::test_basic_proof_verification(merkle);
+}
+
+#[test]
+fn merkle_update_f128_test_basic_proof_verification_single_update() {
+    let merkle = Box::new(super::MerkleUpdateExample::new(3, 1, build_options(false)));
+    crate::tests::test_basic_proof_verification(merkle);
+}
+
+#[test]
+fn merkle_update_f128_test_basic_proof_verification_fail() {
+    let merkle = Box::new(super::MerkleUpdateExample::new(7, 4, build_options(false)));
+    crate::tests::test_basic_proof_verification_fail(merkle);
+}
+
+#[test]
+fn merkle_update_f128_test_public_inputs_serialization() {
+    let merkle = Box::new(super::MerkleUpdateExample::new(7, 4, build_options(false)));
+    crate::tests::test_public_inputs_serialization::<super::MerkleUpdateAir>(merkle);
+}
+
+fn build_options(use_extension_field: bool) -> ProofOptions {
+    let extension = if use_extension_field {
+        FieldExtension::Quadratic
+    } else {
+        FieldExtension::None
+    };
+    ProofOptions::new(28, 8, 0, HashFunction::Blake3_256, extension, 4, 256)
+}
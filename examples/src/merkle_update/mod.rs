@@ -0,0 +1,88 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::Example;
+use winterfell::crypto::{Hasher, MerkleTree};
+
+pub mod f128;
+pub mod rp64_256;
+
+// LEAF UPDATES
+// ================================================================================================
+
+/// A single update of a Merkle tree leaf.
+///
+/// Leaves of the tree are hashes of leaf values; the authentication path of the leaf is computed
+/// against the tree as it was right before the leaf was updated, and thus, the same path
+/// authenticates both the old and the new value of the leaf.
+#[derive(Debug, Clone)]
+pub struct LeafUpdate<V, D> {
+    pub index: usize,
+    pub old_value: V,
+    pub new_value: V,
+    pub path: Vec<D>,
+}
+
+/// Roots of a Merkle tree before and after a batch of updates, and the list of applied updates.
+type UpdateBatch<V, D> = (D, D, Vec<LeafUpdate<V, D>>);
+
+/// Applies the specified updates to a Merkle tree built from hashes of the specified leaf values
+/// one by one; each update is a tuple (leaf index, new leaf value).
+///
+/// Returns roots of the tree before and after all updates were applied, and a list of applied
+/// updates; authentication paths in the list do not include the leaf itself.
+fn apply_updates<H, V, F>(
+    values: &mut [V],
+    updates: &[(usize, V)],
+    hash_value: F,
+) -> UpdateBatch<V, H::Digest>
+where
+    H: Hasher,
+    V: Copy,
+    F: Fn(&V) -> H::Digest,
+{
+    let mut tree = MerkleTree::<H>::new(values.iter().map(&hash_value).collect()).unwrap();
+    let old_root = *tree.root();
+
+    let mut result = Vec::with_capacity(updates.len());
+    for &(index, new_value) in updates {
+        let mut path = tree.prove(index).unwrap();
+        path.remove(0);
+        result.push(LeafUpdate {
+            index,
+            old_value: values[index],
+            new_value,
+            path,
+        });
+
+        values[index] = new_value;
+        tree = MerkleTree::new(values.iter().map(&hash_value).collect()).unwrap();
+    }
+
+    (old_root, *tree.root(), result)
+}
+
+/// Returns the number of steps in the execution trace needed to prove a single update of a leaf
+/// in a Merkle tree of the specified depth, given the length of a single hash cycle.
+fn get_update_cycle_length(tree_depth: usize, hash_cycle_length: usize) -> usize {
+    (tree_depth + 1) * hash_cycle_length
+}
+
+/// Makes sure that the specified tree depth and number of updates can be proven.
+fn validate_params(tree_depth: usize, num_updates: usize) {
+    assert!(
+        (tree_depth + 1).is_power_of_two(),
+        "tree depth must be one less than a power of 2"
+    );
+    assert!(
+        tree_depth < u8::MAX as usize,
+        "tree depth must be smaller than {}",
+        u8::MAX
+    );
+    assert!(
+        num_updates.is_power_of_two(),
+        "number of updates must be a power of 2"
+    );
+}
@@ -0,0 +1,296 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{
+    get_update_cycle_length, rp64_256, BaseElement, FieldElement, Node, DIGEST_SIZE,
+    HASH_STATE_WIDTH, INDEX_BIT, NEW_HASH, OLD_HASH, ROOT, TRACE_WIDTH,
+};
+use crate::utils::{are_equal, is_binary, not};
+use winterfell::{
+    Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    EvaluationFrame, ProofOptions, Serializable, TraceInfo, TransitionConstraintDegree,
+    TransitionDivisor,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+// Offsets of constraint groups in the list of transition constraints.
+const OLD_ROUND: usize = 0;
+const NEW_ROUND: usize = OLD_ROUND + HASH_STATE_WIDTH;
+const OLD_INIT: usize = NEW_ROUND + HASH_STATE_WIDTH;
+const NEW_INIT: usize = OLD_INIT + HASH_STATE_WIDTH;
+const SIBLING: usize = NEW_INIT + HASH_STATE_WIDTH;
+const BIT: usize = SIBLING + DIGEST_SIZE;
+const ROOT_UPDATE: usize = BIT + 1;
+const ROOT_CHECK: usize = ROOT_UPDATE + DIGEST_SIZE;
+const NUM_CONSTRAINTS: usize = ROOT_CHECK + DIGEST_SIZE;
+
+// Positions of the capacity and of the two halves of the rate in the hasher state; digests are
+// always contained in the first half of the rate.
+const CAPACITY: usize = rp64_256::CAPACITY_RANGE.start;
+const LEFT: usize = rp64_256::RATE_RANGE.start;
+const RIGHT: usize = LEFT + DIGEST_SIZE;
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+pub struct PublicInputs {
+    pub tree_depth: usize,
+    pub old_root: Node,
+    pub new_root: Node,
+}
+
+impl Serializable for PublicInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.tree_depth as u8);
+        target.write(self.old_root);
+        target.write(self.new_root);
+    }
+}
+
+impl Deserializable for PublicInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let tree_depth = source.read_u8()? as usize;
+        if !(tree_depth + 1).is_power_of_two() {
+            return Err(DeserializationError::InvalidValue(format!(
+                "tree depth must be one less than a power of 2, but was {}",
+                tree_depth
+            )));
+        }
+        Ok(PublicInputs {
+            tree_depth,
+            old_root: Node::read_from(source)?,
+            new_root: Node::read_from(source)?,
+        })
+    }
+}
+
+// MERKLE TREE UPDATE AIR
+// ================================================================================================
+
+/// AIR for updating leaves of a Merkle tree built using `Rp64_256` hash function one by one.
+///
+/// This AIR is the same as the [AIR](crate::merkle_update::f128::MerkleUpdateAir) for trees built
+/// using Rescue hash function over the 128-bit field, but it describes the hash function via the
+/// `rp64_256` gadget. Leaf values consist of 4 elements, and leaves are computed as
+/// `Rp64_256::hash_elements(value)`.
+pub struct MerkleUpdateAir {
+    context: AirContext<BaseElement>,
+    update_cycle_len: usize,
+    old_root: Node,
+    new_root: Node,
+}
+
+impl Air for MerkleUpdateAir {
+    type BaseField = BaseElement;
+    type PublicInputs = PublicInputs;
+    const ID: &'static [u8] = b"winterfell/examples/merkle_update_rp64_256";
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        let update_cycle_len =
+            get_update_cycle_length(pub_inputs.tree_depth, rp64_256::CYCLE_LENGTH);
+        assert!(
+            trace_info.length() % update_cycle_len == 0,
+            "execution trace length must be a multiple of {}",
+            update_cycle_len
+        );
+
+        let path_degree = TransitionConstraintDegree::with_cycles(2, vec![update_cycle_len]);
+        let mut degrees = rp64_256::get_constraint_degrees();
+        degrees.append(&mut rp64_256::get_constraint_degrees());
+        for _ in 0..2 {
+            degrees.append(&mut vec![
+                TransitionConstraintDegree::new(1);
+                LEFT - CAPACITY
+            ]);
+            degrees.append(&mut vec![path_degree.clone(); 2 * DIGEST_SIZE]);
+        }
+        degrees.append(&mut vec![path_degree; DIGEST_SIZE]);
+        degrees.push(TransitionConstraintDegree::new(2));
+        degrees.append(&mut vec![
+            TransitionConstraintDegree::with_cycles(
+                1,
+                vec![update_cycle_len]
+            );
+            DIGEST_SIZE
+        ]);
+        degrees.append(&mut vec![TransitionConstraintDegree::new(1); DIGEST_SIZE]);
+        debug_assert_eq!(NUM_CONSTRAINTS, degrees.len());
+
+        // hasher states need to be initialized only on the last step of every hash cycle, and the
+        // root of the tree needs to be checked only on the last step of every update cycle
+        let context = AirContext::new(trace_info, degrees, 2 * HASH_STATE_WIDTH, options)
+            .set_main_transition_divisor(
+                OLD_INIT..BIT,
+                TransitionDivisor::periodic(rp64_256::CYCLE_LENGTH, rp64_256::CYCLE_LENGTH - 1)
+                    .with_num_exemptions(1),
+            )
+            .set_main_transition_divisor(
+                ROOT_CHECK..NUM_CONSTRAINTS,
+                TransitionDivisor::periodic(update_cycle_len, update_cycle_len - 1),
+            );
+
+        MerkleUpdateAir {
+            context,
+            update_cycle_len,
+            old_root: pub_inputs.old_root,
+            new_root: pub_inputs.new_root,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        debug_assert_eq!(TRACE_WIDTH, current.len());
+        debug_assert_eq!(TRACE_WIDTH, next.len());
+
+        // split periodic values into masks and round constants
+        let round_flag = periodic_values[0];
+        let update_flag = periodic_values[1];
+        let ark = &periodic_values[2..];
+
+        // when round_flag = 1, constraints for a permutation round are enforced for both hasher
+        // states
+        let old_hash = OLD_HASH..OLD_HASH + HASH_STATE_WIDTH;
+        let new_hash = NEW_HASH..NEW_HASH + HASH_STATE_WIDTH;
+        rp64_256::enforce_round(
+            &mut result[OLD_ROUND..NEW_ROUND],
+            &current[old_hash.clone()],
+            &next[old_hash.clone()],
+            ark,
+            round_flag,
+        );
+        rp64_256::enforce_round(
+            &mut result[NEW_ROUND..OLD_INIT],
+            &current[new_hash.clone()],
+            &next[new_hash.clone()],
+            ark,
+            round_flag,
+        );
+
+        // on the last step of every hash cycle, hasher states are initialized either for merging
+        // the computed digest with the next node of the path, or, on the last step of every
+        // update cycle, for hashing the value of the next leaf
+        let bit = next[INDEX_BIT];
+        enforce_state_init(
+            &mut result[OLD_INIT..NEW_INIT],
+            &current[old_hash.clone()],
+            &next[old_hash.clone()],
+            bit,
+            update_flag,
+        );
+        enforce_state_init(
+            &mut result[NEW_INIT..SIBLING],
+            &current[new_hash.clone()],
+            &next[new_hash.clone()],
+            bit,
+            update_flag,
+        );
+
+        // both hasher states must contain the same sibling node
+        let (old_next, new_next) = (&next[old_hash], &next[new_hash]);
+        for i in 0..DIGEST_SIZE {
+            result[SIBLING + i] = not(update_flag)
+                * (not(bit) * are_equal(old_next[RIGHT + i], new_next[RIGHT + i])
+                    + bit * are_equal(old_next[LEFT + i], new_next[LEFT + i]));
+        }
+
+        // index bits must be binary
+        result[BIT] = is_binary(current[INDEX_BIT]);
+
+        // the root remains the same until the end of the update cycle; at this point, the root
+        // computed from the new value of the leaf becomes the root for the next update
+        for i in 0..DIGEST_SIZE {
+            let root = current[ROOT + i];
+            result[ROOT_UPDATE + i] = are_equal(
+                next[ROOT + i],
+                root + update_flag * (current[NEW_HASH + LEFT + i] - root),
+            );
+        }
+
+        // at the end of the update cycle, the root computed from the old value of the leaf must
+        // be equal to the root of the tree before the update
+        for i in 0..DIGEST_SIZE {
+            result[ROOT_CHECK + i] = are_equal(current[OLD_HASH + LEFT + i], current[ROOT + i]);
+        }
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // assert that hasher states are initialized for hashing the values of the first leaf,
+        // that the tree starts with the old root, and that the last update results in the new
+        // root
+        let last_step = self.trace_length() - 1;
+        let init_state = rp64_256::init_hash_state(&[BaseElement::ZERO; DIGEST_SIZE]);
+        let mut assertions = Vec::new();
+        for i in (CAPACITY..LEFT).chain(RIGHT..HASH_STATE_WIDTH) {
+            assertions.push(Assertion::single(OLD_HASH + i, 0, init_state[i]));
+            assertions.push(Assertion::single(NEW_HASH + i, 0, init_state[i]));
+        }
+        let (old_root, new_root) = (self.old_root.as_elements(), self.new_root.as_elements());
+        for i in 0..DIGEST_SIZE {
+            assertions.push(Assertion::single(ROOT + i, 0, old_root[i]));
+            assertions.push(Assertion::single(
+                NEW_HASH + LEFT + i,
+                last_step,
+                new_root[i],
+            ));
+        }
+        assertions
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        let mut update_mask = vec![BaseElement::ZERO; self.update_cycle_len];
+        update_mask[self.update_cycle_len - 1] = BaseElement::ONE;
+
+        let mut result = vec![rp64_256::CYCLE_MASK.to_vec(), update_mask];
+        result.append(&mut rp64_256::get_round_constants());
+        result
+    }
+}
+
+// HELPER EVALUATORS
+// ------------------------------------------------------------------------------------------------
+
+/// Enforces that the next hasher state is initialized for merging the digest contained in the
+/// current state with the next sibling node; the `bit` defines whether the digest goes into the
+/// first or the second half of the rate. When `update_flag` = 1, the next state is instead
+/// initialized for hashing a new leaf value, i.e., the first capacity element is set to the
+/// number of elements in the value, and the second half of the rate is set to ZEROs.
+fn enforce_state_init<E: FieldElement + From<BaseElement>>(
+    result: &mut [E],
+    current: &[E],
+    next: &[E],
+    bit: E,
+    update_flag: E,
+) {
+    let path_flag = not(update_flag);
+    let merge_len = E::from(2 * DIGEST_SIZE as u32);
+    let leaf_len = E::from(DIGEST_SIZE as u32);
+
+    result[CAPACITY] = are_equal(
+        next[CAPACITY],
+        path_flag * merge_len + update_flag * leaf_len,
+    );
+    result[CAPACITY + 1..LEFT].copy_from_slice(&next[CAPACITY + 1..LEFT]);
+    for i in 0..DIGEST_SIZE {
+        let (left, right) = (LEFT + i, RIGHT + i);
+        result[left] = path_flag * not(bit) * are_equal(next[left], current[left]);
+        result[right] =
+            path_flag * bit * are_equal(next[right], current[left]) + update_flag * next[right];
+    }
+}
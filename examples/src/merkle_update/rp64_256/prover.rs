@@ -0,0 +1,128 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{
+    get_update_cycle_length, rp64_256, BaseElement, FieldElement, LeafUpdate, MerkleUpdateAir,
+    Node, ProofOptions, Prover, PublicInputs, Trace, TraceTable, DIGEST_SIZE, HASH_STATE_WIDTH,
+    INDEX_BIT, NEW_HASH, OLD_HASH, ROOT, TRACE_WIDTH,
+};
+
+// MERKLE TREE UPDATE PROVER
+// ================================================================================================
+
+pub struct MerkleUpdateProver {
+    tree_depth: usize,
+    options: ProofOptions,
+}
+
+impl MerkleUpdateProver {
+    pub fn new(tree_depth: usize, options: ProofOptions) -> Self {
+        Self {
+            tree_depth,
+            options,
+        }
+    }
+
+    pub fn build_trace(
+        &self,
+        old_root: Node,
+        updates: &[LeafUpdate<[BaseElement; DIGEST_SIZE], Node>],
+    ) -> TraceTable<BaseElement> {
+        // allocate memory to hold the trace table
+        let update_cycle_len = get_update_cycle_length(self.tree_depth, rp64_256::CYCLE_LENGTH);
+        let trace_length = updates.len() * update_cycle_len;
+        let mut trace = TraceTable::new(TRACE_WIDTH, trace_length);
+
+        trace.fill(
+            |state| {
+                // initialize hasher states with the old and the new value of the first leaf
+                init_leaf_state(state, &updates[0]);
+                state[INDEX_BIT] = BaseElement::ZERO;
+                state[ROOT..ROOT + DIGEST_SIZE].copy_from_slice(old_root.as_elements());
+            },
+            |step, state| {
+                // for the first 7 steps in every cycle, compute a single round of the permutation
+                // in both hasher states; on the last step, initialize both hasher states for
+                // merging the resulting digests with the next path node, or, at the end of an
+                // update cycle, for hashing values of the next leaf.
+                let update = &updates[step / update_cycle_len];
+                let cycle_num = (step % update_cycle_len) / rp64_256::CYCLE_LENGTH;
+
+                if (step % rp64_256::CYCLE_LENGTH) < rp64_256::NUM_ROUNDS {
+                    rp64_256::apply_round(&mut state[OLD_HASH..OLD_HASH + HASH_STATE_WIDTH], step);
+                    rp64_256::apply_round(&mut state[NEW_HASH..NEW_HASH + HASH_STATE_WIDTH], step);
+                } else if cycle_num == self.tree_depth {
+                    // the root computed from the new leaf value becomes the root of the tree
+                    let new_root =
+                        rp64_256::get_digest(&state[NEW_HASH..NEW_HASH + HASH_STATE_WIDTH]);
+                    state[ROOT..ROOT + DIGEST_SIZE].copy_from_slice(new_root.as_elements());
+                    init_leaf_state(state, &updates[step / update_cycle_len + 1]);
+                    state[INDEX_BIT] = BaseElement::ZERO;
+                } else {
+                    let node = update.path[cycle_num];
+                    let index_bit = (update.index >> cycle_num) & 1 == 1;
+                    for offset in [OLD_HASH, NEW_HASH] {
+                        let hash_state = &mut state[offset..offset + HASH_STATE_WIDTH];
+                        let digest = rp64_256::get_digest(hash_state);
+                        let values = if index_bit {
+                            [node, digest]
+                        } else {
+                            [digest, node]
+                        };
+                        hash_state.copy_from_slice(&rp64_256::init_merge_state(&values));
+                    }
+                    state[INDEX_BIT] = BaseElement::from(index_bit as u8);
+                }
+            },
+        );
+
+        // set index bit at the second step to one; this still results in a valid execution trace
+        // because index bits are read only on the first step of every hash cycle, but it ensures
+        // that there are no repeating patterns in the index bit register, and thus the degree of
+        // the index bit constraint is stable.
+        trace.set(INDEX_BIT, 1, FieldElement::ONE);
+
+        trace
+    }
+}
+
+impl Prover for MerkleUpdateProver {
+    type BaseField = BaseElement;
+    type Air = MerkleUpdateAir;
+    type Trace = TraceTable<BaseElement>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs {
+        let last_step = trace.length() - 1;
+        let mut old_root = [BaseElement::ZERO; DIGEST_SIZE];
+        let mut new_root = [BaseElement::ZERO; DIGEST_SIZE];
+        for i in 0..DIGEST_SIZE {
+            old_root[i] = trace.get(ROOT + i, 0);
+            new_root[i] = trace.get(NEW_HASH + rp64_256::DIGEST_RANGE.start + i, last_step);
+        }
+        PublicInputs {
+            tree_depth: self.tree_depth,
+            old_root: Node::new(old_root),
+            new_root: Node::new(new_root),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Initializes hasher states for hashing the old and the new value of the updated leaf.
+fn init_leaf_state(
+    state: &mut [BaseElement],
+    update: &LeafUpdate<[BaseElement; DIGEST_SIZE], Node>,
+) {
+    state[OLD_HASH..OLD_HASH + HASH_STATE_WIDTH]
+        .copy_from_slice(&rp64_256::init_hash_state(&update.old_value));
+    state[NEW_HASH..NEW_HASH + HASH_STATE_WIDTH]
+        .copy_from_slice(&rp64_256::init_hash_state(&update.new_value));
+}
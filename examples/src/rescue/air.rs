@@ -4,10 +4,11 @@
 // LICENSE file in the root directory of this source tree.
 
 use super::{rescue, BaseElement, FieldElement, ProofOptions, CYCLE_LENGTH, TRACE_WIDTH};
-use crate::utils::{are_equal, is_zero, not, EvaluationResult};
+use crate::utils::{are_equal, is_zero};
 use winterfell::{
-    Air, AirContext, Assertion, ByteWriter, EvaluationFrame, Serializable, TraceInfo,
-    TransitionConstraintDegree,
+    Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    EvaluationFrame, SegmentPublicInputs, Serializable, TraceInfo, TransitionConstraintDegree,
+    TransitionDivisor,
 };
 
 // CONSTANTS
@@ -48,6 +49,29 @@ impl Serializable for PublicInputs {
     }
 }
 
+impl Deserializable for PublicInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let seed = BaseElement::read_batch_from(source, 2)?;
+        let result = BaseElement::read_batch_from(source, 2)?;
+        Ok(PublicInputs {
+            seed: [seed[0], seed[1]],
+            result: [result[0], result[1]],
+        })
+    }
+}
+
+impl SegmentPublicInputs for PublicInputs {
+    type State = [BaseElement; 2];
+
+    fn initial_state(&self) -> Self::State {
+        self.seed
+    }
+
+    fn final_state(&self) -> Self::State {
+        self.result
+    }
+}
+
 pub struct RescueAir {
     context: AirContext<BaseElement>,
     seed: [BaseElement; 2],
@@ -57,6 +81,7 @@ pub struct RescueAir {
 impl Air for RescueAir {
     type BaseField = BaseElement;
     type PublicInputs = PublicInputs;
+    const ID: &'static [u8] = b"winterfell/examples/rescue";
 
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
@@ -66,10 +91,31 @@ impl Air for RescueAir {
             TransitionConstraintDegree::with_cycles(3, vec![CYCLE_LENGTH]),
             TransitionConstraintDegree::with_cycles(3, vec![CYCLE_LENGTH]),
             TransitionConstraintDegree::with_cycles(3, vec![CYCLE_LENGTH]),
+            TransitionConstraintDegree::new(1),
+            TransitionConstraintDegree::new(1),
+            TransitionConstraintDegree::new(1),
+            TransitionConstraintDegree::new(1),
+            TransitionConstraintDegree::new(1),
+            TransitionConstraintDegree::new(1),
+            TransitionConstraintDegree::new(1),
+            TransitionConstraintDegree::new(1),
         ];
         assert_eq!(TRACE_WIDTH, trace_info.width());
+
+        // hash copy constraints need to hold only on the last two steps of every cycle; for the
+        // very last step of the trace, there is no next step to copy the hash into
+        let context = AirContext::new(trace_info, degrees, 4, options)
+            .set_main_transition_divisor(
+                4..8,
+                TransitionDivisor::periodic(CYCLE_LENGTH, CYCLE_LENGTH - 2),
+            )
+            .set_main_transition_divisor(
+                8..12,
+                TransitionDivisor::periodic(CYCLE_LENGTH, CYCLE_LENGTH - 1).with_num_exemptions(1),
+            );
+
         RescueAir {
-            context: AirContext::new(trace_info, degrees, 4, options),
+            context,
             seed: pub_inputs.seed,
             result: pub_inputs.result,
         }
@@ -96,12 +142,13 @@ impl Air for RescueAir {
         let ark = &periodic_values[1..];
 
         // when hash_flag = 1, constraints for Rescue round are enforced
-        rescue::enforce_round(result, current, next, ark, hash_flag);
+        rescue::enforce_round(&mut result[..4], current, next, ark, hash_flag);
 
-        // when hash_flag = 0, constraints for copying hash values to the next
-        // step are enforced.
-        let copy_flag = not(hash_flag);
-        enforce_hash_copy(result, current, next, copy_flag);
+        // constraints for copying hash values to the next step; these are enforced only on the
+        // last two steps of every cycle (i.e., when hash_flag = 0) via their divisors, and thus,
+        // don't need to be multiplied by a flag.
+        enforce_hash_copy(&mut result[4..8], current, next);
+        enforce_hash_copy(&mut result[8..], current, next);
     }
 
     fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
@@ -125,12 +172,12 @@ impl Air for RescueAir {
 // HELPER EVALUATORS
 // ------------------------------------------------------------------------------------------------
 
-/// when flag = 1, enforces that the next state of the computation is defined like so:
+/// Enforces that the next state of the computation is defined like so:
 /// - the first two registers are equal to the values from the previous step
 /// - the other two registers are equal to 0
-fn enforce_hash_copy<E: FieldElement>(result: &mut [E], current: &[E], next: &[E], flag: E) {
-    result.agg_constraint(0, flag, are_equal(current[0], next[0]));
-    result.agg_constraint(1, flag, are_equal(current[1], next[1]));
-    result.agg_constraint(2, flag, is_zero(next[2]));
-    result.agg_constraint(3, flag, is_zero(next[3]));
+fn enforce_hash_copy<E: FieldElement>(result: &mut [E], current: &[E], next: &[E]) {
+    result[0] = are_equal(current[0], next[0]);
+    result[1] = are_equal(current[1], next[1]);
+    result[2] = is_zero(next[2]);
+    result[3] = is_zero(next[3]);
 }
@@ -0,0 +1,99 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{compute_hash_chain, BaseElement, RescueAir, RescueProver};
+use log::debug;
+use std::time::Instant;
+use winterfell::{ContinuationProof, MatrixLayout, ProofOptions, Prover, Trace, VerifierError};
+
+// RESCUE HASH CHAIN CONTINUATION EXAMPLE
+// ================================================================================================
+
+/// Proves a long chain of Rescue hashes by splitting it into segments of equal length.
+///
+/// Every segment is proven using the regular [RescueAir]: the seed of a segment is the result of
+/// the previous segment, and thus, the resulting continuation attests to a chain of
+/// `num_segments * segment_length` hashes. Only one segment trace is kept in memory at a time.
+pub struct RescueContinuationExample {
+    options: ProofOptions,
+    num_segments: usize,
+    segment_length: usize,
+    seed: [BaseElement; 2],
+    result: [BaseElement; 2],
+}
+
+impl RescueContinuationExample {
+    pub fn new(
+        num_segments: usize,
+        segment_length: usize,
+        options: ProofOptions,
+    ) -> RescueContinuationExample {
+        assert!(
+            num_segments > 0,
+            "number of segments must be greater than zero"
+        );
+        assert!(
+            segment_length.is_power_of_two(),
+            "segment length must a power of 2"
+        );
+        let seed = [BaseElement::from(42u8), BaseElement::from(43u8)];
+
+        // compute the sequence of hashes using external implementation of Rescue hash
+        let now = Instant::now();
+        let result = compute_hash_chain(seed, num_segments * segment_length);
+        debug!(
+            "Computed a chain of {} Rescue hashes in {} ms",
+            num_segments * segment_length,
+            now.elapsed().as_millis(),
+        );
+
+        RescueContinuationExample {
+            options,
+            num_segments,
+            segment_length,
+            seed,
+            result,
+        }
+    }
+
+    /// Returns the seed of the hash chain.
+    pub fn seed(&self) -> [BaseElement; 2] {
+        self.seed
+    }
+
+    /// Returns the result of the hash chain.
+    pub fn result(&self) -> [BaseElement; 2] {
+        self.result
+    }
+
+    /// Generates a proof of the hash chain consisting of one proof per segment.
+    pub fn prove(&self) -> ContinuationProof {
+        debug!(
+            "Generating proof for computing a chain of {} Rescue hashes in {} segments\n\
+            ---------------------",
+            self.num_segments * self.segment_length,
+            self.num_segments
+        );
+
+        let prover = RescueProver::new(self.options.clone(), MatrixLayout::default());
+        let segment_length = self.segment_length;
+
+        // segment traces are built lazily; the seed of every segment is the last state of the
+        // previous segment
+        let segments = (0..self.num_segments).scan(self.seed, |seed, _| {
+            let trace = prover.build_trace(*seed, segment_length);
+            let last_step = trace.length() - 1;
+            *seed = [trace.get(0, last_step), trace.get(1, last_step)];
+            Some(trace)
+        });
+
+        prover.prove_continuation(segments).unwrap()
+    }
+
+    /// Verifies that the specified continuation attests to the hash chain of this example.
+    pub fn verify(&self, proof: ContinuationProof) -> Result<(), VerifierError> {
+        winterfell::verify_continuation::<RescueAir>(proof, &self.seed, &self.result)
+    }
+}
@@ -8,18 +8,21 @@ use log::debug;
 use std::time::Instant;
 use winterfell::{
     math::{fields::f128::BaseElement, log2, FieldElement},
-    ProofOptions, Prover, StarkProof, Trace, TraceTable, VerifierError,
+    MatrixLayout, ProofOptions, Prover, Serializable, StarkProof, Trace, TraceTable, VerifierError,
 };
 
 #[allow(clippy::module_inception)]
 pub(crate) mod rescue;
 
 mod air;
-use air::{PublicInputs, RescueAir};
+pub use air::{PublicInputs, RescueAir};
 
 mod prover;
 use prover::RescueProver;
 
+mod continuation;
+pub use continuation::RescueContinuationExample;
+
 #[cfg(test)]
 mod tests;
 
@@ -42,6 +45,7 @@ pub fn get_example(options: ExampleOptions, chain_length: usize) -> Box<dyn Exam
 
 pub struct RescueExample {
     options: ProofOptions,
+    lde_layout: MatrixLayout,
     chain_length: usize,
     seed: [BaseElement; 2],
     result: [BaseElement; 2],
@@ -66,11 +70,25 @@ impl RescueExample {
 
         RescueExample {
             options,
+            lde_layout: MatrixLayout::default(),
             chain_length,
             seed,
             result,
         }
     }
+
+    /// Sets the memory layout of low-degree extensions built when generating proofs.
+    pub fn with_lde_layout(mut self, lde_layout: MatrixLayout) -> Self {
+        self.lde_layout = lde_layout;
+        self
+    }
+
+    fn get_pub_inputs(&self) -> PublicInputs {
+        PublicInputs {
+            seed: self.seed,
+            result: self.result,
+        }
+    }
 }
 
 // EXAMPLE IMPLEMENTATION
@@ -86,7 +104,7 @@ impl Example for RescueExample {
         );
 
         // create a prover
-        let prover = RescueProver::new(self.options.clone());
+        let prover = RescueProver::new(self.options.clone(), self.lde_layout);
 
         // generate the execution trace
         let now = Instant::now();
@@ -103,12 +121,12 @@ impl Example for RescueExample {
         prover.prove(trace).unwrap()
     }
 
+    fn public_inputs(&self) -> Vec<u8> {
+        self.get_pub_inputs().to_bytes()
+    }
+
     fn verify(&self, proof: StarkProof) -> Result<(), VerifierError> {
-        let pub_inputs = PublicInputs {
-            seed: self.seed,
-            result: self.result,
-        };
-        winterfell::verify::<RescueAir>(proof, pub_inputs)
+        winterfell::verify::<RescueAir>(proof, self.get_pub_inputs())
     }
 
     fn verify_with_wrong_inputs(&self, proof: StarkProof) -> Result<(), VerifierError> {
@@ -4,8 +4,8 @@
 // LICENSE file in the root directory of this source tree.
 
 use super::{
-    rescue, BaseElement, FieldElement, ProofOptions, Prover, PublicInputs, RescueAir, Trace,
-    TraceTable, CYCLE_LENGTH, NUM_HASH_ROUNDS,
+    rescue, BaseElement, FieldElement, MatrixLayout, ProofOptions, Prover, PublicInputs, RescueAir,
+    Trace, TraceTable, CYCLE_LENGTH, NUM_HASH_ROUNDS,
 };
 
 // RESCUE PROVER
@@ -13,11 +13,15 @@ use super::{
 
 pub struct RescueProver {
     options: ProofOptions,
+    lde_layout: MatrixLayout,
 }
 
 impl RescueProver {
-    pub fn new(options: ProofOptions) -> Self {
-        Self { options }
+    pub fn new(options: ProofOptions, lde_layout: MatrixLayout) -> Self {
+        Self {
+            options,
+            lde_layout,
+        }
     }
 
     pub fn build_trace(
@@ -72,4 +76,8 @@ impl Prover for RescueProver {
     fn options(&self) -> &ProofOptions {
         &self.options
     }
+
+    fn lde_layout(&self) -> MatrixLayout {
+        self.lde_layout
+    }
 }
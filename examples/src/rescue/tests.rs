@@ -3,7 +3,11 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use winterfell::{FieldExtension, HashFunction, ProofOptions};
+use super::{BaseElement, FieldElement};
+use winterfell::{
+    verify_continuation, ContinuationProof, FieldExtension, HashFunction, MatrixLayout,
+    ProofOptions, Prover, ProverError, Serializable, VerifierError,
+};
 
 #[test]
 fn rescue_test_basic_proof_verification() {
@@ -17,12 +21,120 @@ fn rescue_test_basic_proof_verification_extension() {
     crate::tests::test_basic_proof_verification(rescue_eg);
 }
 
+#[test]
+fn rescue_test_basic_proof_verification_row_major() {
+    let rescue_eg = Box::new(
+        super::RescueExample::new(128, build_options(true)).with_lde_layout(MatrixLayout::RowMajor),
+    );
+    crate::tests::test_basic_proof_verification(rescue_eg);
+}
+
 #[test]
 fn rescue_test_basic_proof_verification_fail() {
     let rescue_eg = Box::new(super::RescueExample::new(128, build_options(false)));
     crate::tests::test_basic_proof_verification_fail(rescue_eg);
 }
 
+#[test]
+fn rescue_test_public_inputs_serialization() {
+    let rescue_eg = Box::new(super::RescueExample::new(128, build_options(false)));
+    crate::tests::test_public_inputs_serialization::<super::RescueAir>(rescue_eg);
+}
+
+#[test]
+fn rescue_test_continuation_verification() {
+    let rescue_eg = super::RescueContinuationExample::new(4, 32, build_options(false));
+    let proof = rescue_eg.prove();
+    assert_eq!(4, proof.num_segments());
+    assert_eq!(4 * 32 * super::CYCLE_LENGTH, proof.num_steps());
+
+    // the continuation attests to the same chain as a single proof would
+    assert_eq!(
+        super::compute_hash_chain(rescue_eg.seed(), 128),
+        rescue_eg.result()
+    );
+
+    let proof_bytes = proof.to_bytes();
+    let parsed_proof = ContinuationProof::from_bytes(&proof_bytes).unwrap();
+    assert_eq!(proof, parsed_proof);
+    assert!(rescue_eg.verify(parsed_proof).is_ok());
+}
+
+#[test]
+fn rescue_test_continuation_verification_fail() {
+    let rescue_eg = super::RescueContinuationExample::new(3, 16, build_options(false));
+    let proof = rescue_eg.prove();
+    let seed = rescue_eg.seed();
+    let result = rescue_eg.result();
+
+    // wrong boundary states of the entire computation
+    let wrong_state = [result[0], result[1] + BaseElement::ONE];
+    assert_eq!(
+        Err(VerifierError::InitialStateMismatch),
+        verify_continuation::<super::RescueAir>(proof.clone(), &wrong_state, &result)
+    );
+    assert_eq!(
+        Err(VerifierError::FinalStateMismatch),
+        verify_continuation::<super::RescueAir>(proof.clone(), &seed, &wrong_state)
+    );
+
+    // segments out of order
+    let mut bad_proof = proof.clone();
+    bad_proof.segments.swap(1, 2);
+    assert_eq!(
+        Err(VerifierError::DisconnectedSegments(1)),
+        verify_continuation::<super::RescueAir>(bad_proof, &seed, &result)
+    );
+
+    // a missing segment
+    let mut bad_proof = proof.clone();
+    bad_proof.segments.pop();
+    assert_eq!(
+        Err(VerifierError::FinalStateMismatch),
+        verify_continuation::<super::RescueAir>(bad_proof, &seed, &result)
+    );
+
+    // segment proof does not match segment public inputs
+    let mut bad_proof = proof.clone();
+    bad_proof.segments[1].proof = proof.segments[0].proof.clone();
+    assert!(matches!(
+        verify_continuation::<super::RescueAir>(bad_proof, &seed, &result),
+        Err(VerifierError::SegmentVerificationFailed(1, _))
+    ));
+
+    // malformed public inputs
+    let mut bad_proof = proof.clone();
+    bad_proof.segments[2].pub_inputs.push(0);
+    assert!(matches!(
+        verify_continuation::<super::RescueAir>(bad_proof, &seed, &result),
+        Err(VerifierError::InvalidSegmentPublicInputs(2, _))
+    ));
+
+    // empty continuation
+    let empty_proof = ContinuationProof {
+        segments: Vec::new(),
+    };
+    assert_eq!(
+        Err(VerifierError::EmptyContinuation),
+        verify_continuation::<super::RescueAir>(empty_proof, &seed, &result)
+    );
+}
+
+#[test]
+fn rescue_test_continuation_prover_rejects_disconnected_segments() {
+    let prover = super::RescueProver::new(build_options(false), MatrixLayout::default());
+    let seed = [BaseElement::from(42u8), BaseElement::from(43u8)];
+    let segments = vec![prover.build_trace(seed, 16), prover.build_trace(seed, 16)];
+    assert_eq!(
+        Err(ProverError::DisconnectedSegments(1)),
+        prover.prove_continuation(segments)
+    );
+    assert_eq!(
+        Err(ProverError::EmptyContinuation),
+        prover.prove_continuation(Vec::new())
+    );
+}
+
 fn build_options(use_extension_field: bool) -> ProofOptions {
     let extension = if use_extension_field {
         FieldExtension::Quadratic
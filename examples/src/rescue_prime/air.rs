@@ -0,0 +1,160 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{rp64_256, BaseElement, FieldElement, ProofOptions, DIGEST_SIZE, TRACE_WIDTH};
+use crate::utils::{are_equal, is_zero};
+use winterfell::{
+    Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    EvaluationFrame, Serializable, TraceInfo, TransitionConstraintDegree, TransitionDivisor,
+};
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+pub struct PublicInputs {
+    pub seed: [BaseElement; DIGEST_SIZE],
+    pub result: [BaseElement; DIGEST_SIZE],
+}
+
+impl Serializable for PublicInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(&self.seed[..]);
+        target.write(&self.result[..]);
+    }
+}
+
+impl Deserializable for PublicInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let seed = BaseElement::read_batch_from(source, DIGEST_SIZE)?;
+        let result = BaseElement::read_batch_from(source, DIGEST_SIZE)?;
+        Ok(PublicInputs {
+            seed: [seed[0], seed[1], seed[2], seed[3]],
+            result: [result[0], result[1], result[2], result[3]],
+        })
+    }
+}
+
+// RESCUE PRIME AIR
+// ================================================================================================
+
+pub struct RescuePrimeAir {
+    context: AirContext<BaseElement>,
+    seed: [BaseElement; DIGEST_SIZE],
+    result: [BaseElement; DIGEST_SIZE],
+}
+
+impl Air for RescuePrimeAir {
+    type BaseField = BaseElement;
+    type PublicInputs = PublicInputs;
+    const ID: &'static [u8] = b"winterfell/examples/rescue_prime";
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: ProofOptions) -> Self {
+        let mut degrees = rp64_256::get_constraint_degrees();
+        degrees.resize(2 * TRACE_WIDTH, TransitionConstraintDegree::new(1));
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+
+        // constraints for initializing the next hasher state need to hold only on the last step
+        // of every cycle; for the very last step of the trace, there is no next hash
+        let context = AirContext::new(trace_info, degrees, TRACE_WIDTH + DIGEST_SIZE, options)
+            .set_main_transition_divisor(
+                rp64_256::NUM_CONSTRAINTS..2 * TRACE_WIDTH,
+                TransitionDivisor::periodic(rp64_256::CYCLE_LENGTH, rp64_256::CYCLE_LENGTH - 1)
+                    .with_num_exemptions(1),
+            );
+
+        RescuePrimeAir {
+            context,
+            seed: pub_inputs.seed,
+            result: pub_inputs.result,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        debug_assert_eq!(TRACE_WIDTH, current.len());
+        debug_assert_eq!(TRACE_WIDTH, next.len());
+
+        // split periodic values into round flag and round constants
+        let round_flag = periodic_values[0];
+        let ark = &periodic_values[1..];
+
+        // when round_flag = 1, constraints for a permutation round are enforced
+        rp64_256::enforce_round(
+            &mut result[..rp64_256::NUM_CONSTRAINTS],
+            current,
+            next,
+            ark,
+            round_flag,
+        );
+
+        // constraints for initializing the hasher state with the digest computed in the current
+        // cycle; these are enforced only on the last step of every cycle via their divisors
+        enforce_state_init(&mut result[rp64_256::NUM_CONSTRAINTS..], current, next);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // assert the initial hasher state and the digest computed by the last hash in the chain
+        let init_state = rp64_256::init_hash_state(&self.seed);
+        let last_step = self.trace_length() - 1;
+        let mut assertions = init_state
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| Assertion::single(i, 0, value))
+            .collect::<Vec<_>>();
+        for (i, &value) in self.result.iter().enumerate() {
+            assertions.push(Assertion::single(
+                rp64_256::DIGEST_RANGE.start + i,
+                last_step,
+                value,
+            ));
+        }
+        assertions
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        let mut result = vec![rp64_256::CYCLE_MASK.to_vec()];
+        result.append(&mut rp64_256::get_round_constants());
+        result
+    }
+}
+
+// HELPER EVALUATORS
+// ------------------------------------------------------------------------------------------------
+
+/// Enforces that the next hasher state is initialized for hashing the digest contained in the
+/// current state, i.e.:
+/// - the first capacity element is set to the number of hashed elements, and the remaining
+///   capacity elements are zeros.
+/// - the first half of the rate contains the digest, and the second half of the rate is zeros.
+fn enforce_state_init<E: FieldElement + From<BaseElement>>(
+    result: &mut [E],
+    current: &[E],
+    next: &[E],
+) {
+    let capacity = rp64_256::CAPACITY_RANGE;
+    let rate = rp64_256::RATE_RANGE;
+    let digest = rp64_256::DIGEST_RANGE;
+
+    result[capacity.start] = are_equal(next[capacity.start], E::from(DIGEST_SIZE as u32));
+    for i in capacity.start + 1..capacity.end {
+        result[i] = is_zero(next[i]);
+    }
+    for i in 0..DIGEST_SIZE {
+        result[rate.start + i] = are_equal(next[rate.start + i], current[digest.start + i]);
+        result[rate.start + DIGEST_SIZE + i] = is_zero(next[rate.start + DIGEST_SIZE + i]);
+    }
+}
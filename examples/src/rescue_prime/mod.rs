@@ -0,0 +1,149 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use crate::{Example, ExampleOptions};
+use log::debug;
+use std::time::Instant;
+use winterfell::{
+    crypto::{hashers::Rp64_256, ElementHasher},
+    gadgets::rp64_256,
+    math::{fields::f64::BaseElement, log2, FieldElement},
+    ProofOptions, Prover, Serializable, StarkProof, Trace, TraceTable, VerifierError,
+};
+
+mod air;
+pub use air::{PublicInputs, RescuePrimeAir};
+
+mod prover;
+use prover::RescuePrimeProver;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+const TRACE_WIDTH: usize = rp64_256::STATE_WIDTH;
+const DIGEST_SIZE: usize = 4;
+
+// RESCUE PRIME HASH CHAIN EXAMPLE
+// ================================================================================================
+
+pub fn get_example(options: ExampleOptions, chain_length: usize) -> Box<dyn Example> {
+    Box::new(RescuePrimeExample::new(
+        chain_length,
+        options.to_proof_options(28, 8),
+    ))
+}
+
+/// Proves a chain of [Rp64_256] hashes using the Rescue Prime AIR gadget; each hash in the chain
+/// is computed over the digest produced by the previous hash.
+pub struct RescuePrimeExample {
+    options: ProofOptions,
+    chain_length: usize,
+    seed: [BaseElement; DIGEST_SIZE],
+    result: [BaseElement; DIGEST_SIZE],
+}
+
+impl RescuePrimeExample {
+    pub fn new(chain_length: usize, options: ProofOptions) -> RescuePrimeExample {
+        assert!(
+            chain_length.is_power_of_two(),
+            "chain length must a power of 2"
+        );
+        let seed = [
+            BaseElement::new(42),
+            BaseElement::new(43),
+            BaseElement::new(44),
+            BaseElement::new(45),
+        ];
+
+        // compute the sequence of hashes using the native implementation of Rp64_256
+        let now = Instant::now();
+        let result = compute_hash_chain(seed, chain_length);
+        debug!(
+            "Computed a chain of {} Rescue Prime hashes in {} ms",
+            chain_length,
+            now.elapsed().as_millis(),
+        );
+
+        RescuePrimeExample {
+            options,
+            chain_length,
+            seed,
+            result,
+        }
+    }
+
+    fn get_pub_inputs(&self) -> PublicInputs {
+        PublicInputs {
+            seed: self.seed,
+            result: self.result,
+        }
+    }
+}
+
+// EXAMPLE IMPLEMENTATION
+// ================================================================================================
+
+impl Example for RescuePrimeExample {
+    fn prove(&self) -> StarkProof {
+        debug!(
+            "Generating proof for computing a chain of {} Rescue Prime hashes\n\
+            ---------------------",
+            self.chain_length
+        );
+
+        // create a prover
+        let prover = RescuePrimeProver::new(self.options.clone());
+
+        // generate the execution trace
+        let now = Instant::now();
+        let trace = prover.build_trace(self.seed, self.chain_length);
+        let trace_length = trace.length();
+        debug!(
+            "Generated execution trace of {} registers and 2^{} steps in {} ms",
+            trace.width(),
+            log2(trace_length),
+            now.elapsed().as_millis()
+        );
+
+        // generate the proof
+        prover.prove(trace).unwrap()
+    }
+
+    fn public_inputs(&self) -> Vec<u8> {
+        self.get_pub_inputs().to_bytes()
+    }
+
+    fn verify(&self, proof: StarkProof) -> Result<(), VerifierError> {
+        winterfell::verify::<RescuePrimeAir>(proof, self.get_pub_inputs())
+    }
+
+    fn verify_with_wrong_inputs(&self, proof: StarkProof) -> Result<(), VerifierError> {
+        let mut result = self.result;
+        result[0] += BaseElement::ONE;
+        let pub_inputs = PublicInputs {
+            seed: self.seed,
+            result,
+        };
+        winterfell::verify::<RescuePrimeAir>(proof, pub_inputs)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn compute_hash_chain(
+    seed: [BaseElement; DIGEST_SIZE],
+    length: usize,
+) -> [BaseElement; DIGEST_SIZE] {
+    let mut result = seed;
+    for _ in 0..length {
+        let digest = Rp64_256::hash_elements(&result);
+        result.copy_from_slice(digest.as_elements());
+    }
+    result
+}
@@ -0,0 +1,72 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{
+    rp64_256, BaseElement, ProofOptions, Prover, PublicInputs, RescuePrimeAir, Trace, TraceTable,
+    DIGEST_SIZE, TRACE_WIDTH,
+};
+
+// RESCUE PRIME PROVER
+// ================================================================================================
+
+pub struct RescuePrimeProver {
+    options: ProofOptions,
+}
+
+impl RescuePrimeProver {
+    pub fn new(options: ProofOptions) -> Self {
+        Self { options }
+    }
+
+    pub fn build_trace(
+        &self,
+        seed: [BaseElement; DIGEST_SIZE],
+        chain_length: usize,
+    ) -> TraceTable<BaseElement> {
+        // allocate memory to hold the trace table; every hash in the chain takes up one cycle
+        let trace_length = chain_length * rp64_256::CYCLE_LENGTH;
+        let mut trace = TraceTable::new(TRACE_WIDTH, trace_length);
+
+        trace.fill(
+            |state| {
+                // initialize the hasher state for hashing the seed
+                state.copy_from_slice(&rp64_256::init_hash_state(&seed));
+            },
+            |step, state| {
+                // for the first 7 steps in every cycle, compute a single round of the permutation;
+                // on the last step, initialize the hasher state for hashing the resulting digest
+                if (step % rp64_256::CYCLE_LENGTH) < rp64_256::NUM_ROUNDS {
+                    rp64_256::apply_round(state, step);
+                } else {
+                    let digest = rp64_256::get_digest(state);
+                    state.copy_from_slice(&rp64_256::init_hash_state(digest.as_elements()));
+                }
+            },
+        );
+
+        trace
+    }
+}
+
+impl Prover for RescuePrimeProver {
+    type BaseField = BaseElement;
+    type Air = RescuePrimeAir;
+    type Trace = TraceTable<BaseElement>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs {
+        let last_step = trace.length() - 1;
+        let mut seed = [BaseElement::default(); DIGEST_SIZE];
+        let mut result = [BaseElement::default(); DIGEST_SIZE];
+        for i in 0..DIGEST_SIZE {
+            seed[i] = trace.get(rp64_256::RATE_RANGE.start + i, 0);
+            result[i] = trace.get(rp64_256::DIGEST_RANGE.start + i, last_step);
+        }
+        PublicInputs { seed, result }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
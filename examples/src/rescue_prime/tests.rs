@@ -0,0 +1,82 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{compute_hash_chain, rp64_256, BaseElement, RescuePrimeProver, Rp64_256};
+use winterfell::{
+    crypto::ElementHasher, FieldExtension, HashFunction, ProofOptions, Trace, TraceTable,
+};
+
+#[test]
+fn rescue_prime_test_basic_proof_verification() {
+    let rescue_eg = Box::new(super::RescuePrimeExample::new(32, build_options(false)));
+    crate::tests::test_basic_proof_verification(rescue_eg);
+}
+
+#[test]
+fn rescue_prime_test_basic_proof_verification_extension() {
+    let rescue_eg = Box::new(super::RescuePrimeExample::new(32, build_options(true)));
+    crate::tests::test_basic_proof_verification(rescue_eg);
+}
+
+#[test]
+fn rescue_prime_test_basic_proof_verification_poseidon() {
+    let options = ProofOptions::new(
+        28,
+        8,
+        0,
+        HashFunction::Poseidon64_256,
+        FieldExtension::Quadratic,
+        4,
+        256,
+    );
+    let rescue_eg = Box::new(super::RescuePrimeExample::new(32, options));
+    crate::tests::test_basic_proof_verification(rescue_eg);
+}
+
+#[test]
+fn rescue_prime_test_basic_proof_verification_fail() {
+    let rescue_eg = Box::new(super::RescuePrimeExample::new(32, build_options(false)));
+    crate::tests::test_basic_proof_verification_fail(rescue_eg);
+}
+
+#[test]
+fn rescue_prime_test_public_inputs_serialization() {
+    let rescue_eg = Box::new(super::RescuePrimeExample::new(32, build_options(false)));
+    crate::tests::test_public_inputs_serialization::<super::RescuePrimeAir>(rescue_eg);
+}
+
+#[test]
+fn rescue_prime_test_trace_matches_native_hashes() {
+    let seed = [
+        BaseElement::new(1),
+        BaseElement::new(2),
+        BaseElement::new(3),
+        BaseElement::new(4),
+    ];
+    let prover = RescuePrimeProver::new(build_options(false));
+    let trace = prover.build_trace(seed, 4);
+
+    // the digest at the end of every cycle matches the natively computed chain
+    for i in 0..4 {
+        let last_step = (i + 1) * rp64_256::CYCLE_LENGTH - 1;
+        let expected = Rp64_256::hash_elements(&compute_hash_chain(seed, i));
+        assert_eq!(expected, rp64_256::get_digest(&read_row(&trace, last_step)));
+    }
+}
+
+fn read_row(trace: &TraceTable<BaseElement>, step: usize) -> Vec<BaseElement> {
+    let mut row = vec![BaseElement::default(); trace.main_trace_width()];
+    trace.read_row_into(step, &mut row);
+    row
+}
+
+fn build_options(use_extension_field: bool) -> ProofOptions {
+    let extension = if use_extension_field {
+        FieldExtension::Quadratic
+    } else {
+        FieldExtension::None
+    };
+    ProofOptions::new(28, 8, 0, HashFunction::Blake3_256, extension, 4, 256)
+}
@@ -9,8 +9,8 @@ use super::{
 };
 use crate::utils::{are_equal, not, EvaluationResult};
 use winterfell::{
-    Air, AirContext, Assertion, AuxTraceRandElements, ByteWriter, EvaluationFrame, Serializable,
-    TraceInfo, TransitionConstraintDegree,
+    Air, AirContext, Assertion, AuxTraceRandElements, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, Serializable, TraceInfo, TransitionConstraintDegree,
 };
 
 // CONSTANTS
@@ -49,6 +49,15 @@ impl Serializable for PublicInputs {
     }
 }
 
+impl Deserializable for PublicInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let result = BaseElement::read_batch_from(source, 4)?;
+        Ok(PublicInputs {
+            result: [[result[0], result[1]], [result[2], result[3]]],
+        })
+    }
+}
+
 pub struct RescueRapsAir {
     context: AirContext<BaseElement>,
     result: [[BaseElement; 2]; 2],
@@ -57,6 +66,7 @@ pub struct RescueRapsAir {
 impl Air for RescueRapsAir {
     type BaseField = BaseElement;
     type PublicInputs = PublicInputs;
+    const ID: &'static [u8] = b"winterfell/examples/rescue_raps";
 
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
@@ -150,11 +160,12 @@ impl Air for RescueRapsAir {
         );
     }
 
-    fn evaluate_aux_transition<F, E>(
+    fn evaluate_aux_transition_with_periodic_values<F, E>(
         &self,
         main_frame: &EvaluationFrame<F>,
         aux_frame: &EvaluationFrame<E>,
         periodic_values: &[F],
+        aux_periodic_values: &[E],
         aux_rand_elements: &AuxTraceRandElements<E>,
         result: &mut [E],
     ) where
@@ -182,23 +193,20 @@ impl Air for RescueRapsAir {
         // auxiliary one. For the sake of illustrating RAPs behaviour, we will store
         // the computed values in additional columns.
 
-        let copied_value_1 = random_elements[0] * (main_next[0] - main_current[0]).into()
-            + random_elements[1] * (main_next[1] - main_current[1]).into();
+        // auxiliary periodic columns contain α_0 and α_1 on the absorption step, and zeros on
+        // all other steps; thus, the values below are already multiplied by absorption flag.
+        let alpha_0 = aux_periodic_values[0];
+        let alpha_1 = aux_periodic_values[1];
 
-        result.agg_constraint(
-            0,
-            absorption_flag.into(),
-            are_equal(aux_current[0], copied_value_1),
-        );
+        let copied_value_1 = alpha_0 * (main_next[0] - main_current[0]).into()
+            + alpha_1 * (main_next[1] - main_current[1]).into();
 
-        let copied_value_2 = random_elements[0] * (main_next[4] - main_current[4]).into()
-            + random_elements[1] * (main_next[5] - main_current[5]).into();
+        result[0] = are_equal(aux_current[0].mul_base(absorption_flag), copied_value_1);
 
-        result.agg_constraint(
-            1,
-            absorption_flag.into(),
-            are_equal(aux_current[1], copied_value_2),
-        );
+        let copied_value_2 = alpha_0 * (main_next[4] - main_current[4]).into()
+            + alpha_1 * (main_next[5] - main_current[5]).into();
+
+        result[1] = are_equal(aux_current[1].mul_base(absorption_flag), copied_value_2);
 
         // Enforce that the permutation argument column scales at each step by (aux[0] + γ) / (aux[1] + γ).
         result.agg_constraint(
@@ -250,6 +258,19 @@ impl Air for RescueRapsAir {
 
         result
     }
+
+    fn get_aux_periodic_column_values<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        aux_rand_elements: &AuxTraceRandElements<E>,
+    ) -> Vec<Vec<E>> {
+        // random elements used to combine copied values into a single cell; they are placed
+        // on the absorption step of every cycle
+        let random_elements = aux_rand_elements.get_segment_elements(0);
+        let mut result = vec![vec![E::ZERO; CYCLE_LENGTH]; 2];
+        result[0][14] = random_elements[0];
+        result[1][14] = random_elements[1];
+        result
+    }
 }
 
 // HELPER EVALUATORS
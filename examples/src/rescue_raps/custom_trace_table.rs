@@ -186,6 +186,10 @@ impl<B: StarkField> Trace for RapTraceTable<B> {
         &self.trace
     }
 
+    fn main_segment_mut(&mut self) -> Option<&mut Matrix<B>> {
+        Some(&mut self.trace)
+    }
+
     fn build_aux_segment<E>(
         &mut self,
         aux_segments: &[Matrix<E>],
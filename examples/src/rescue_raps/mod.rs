@@ -9,7 +9,7 @@ use rand_utils::rand_array;
 use std::time::Instant;
 use winterfell::{
     math::{fields::f128::BaseElement, log2, ExtensionOf, FieldElement},
-    ProofOptions, Prover, StarkProof, Trace, VerifierError,
+    ProofOptions, Prover, Serializable, StarkProof, Trace, VerifierError,
 };
 
 mod custom_trace_table;
@@ -18,7 +18,7 @@ pub use custom_trace_table::RapTraceTable;
 use super::rescue::rescue::{self, STATE_WIDTH};
 
 mod air;
-use air::{PublicInputs, RescueRapsAir};
+pub use air::{PublicInputs, RescueRapsAir};
 
 mod prover;
 use prover::RescueRapsProver;
@@ -84,6 +84,12 @@ impl RescueRapsExample {
             result,
         }
     }
+
+    fn get_pub_inputs(&self) -> PublicInputs {
+        PublicInputs {
+            result: self.result,
+        }
+    }
 }
 
 // EXAMPLE IMPLEMENTATION
@@ -116,11 +122,12 @@ impl Example for RescueRapsExample {
         prover.prove(trace).unwrap()
     }
 
+    fn public_inputs(&self) -> Vec<u8> {
+        self.get_pub_inputs().to_bytes()
+    }
+
     fn verify(&self, proof: StarkProof) -> Result<(), VerifierError> {
-        let pub_inputs = PublicInputs {
-            result: self.result,
-        };
-        winterfell::verify::<RescueRapsAir>(proof, pub_inputs)
+        winterfell::verify::<RescueRapsAir>(proof, self.get_pub_inputs())
     }
 
     fn verify_with_wrong_inputs(&self, proof: StarkProof) -> Result<(), VerifierError> {
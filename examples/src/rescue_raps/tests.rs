@@ -23,6 +23,34 @@ fn rescue_test_basic_proof_verification_fail() {
     crate::tests::test_basic_proof_verification_fail(rescue_eg);
 }
 
+#[test]
+fn rescue_test_proof_decoding() {
+    use winterfell::{crypto::hashers::Blake3_256, math::fields::f128::BaseElement};
+
+    let rescue_eg = Box::new(super::RescueRapsExample::new(128, build_options(false)));
+    crate::tests::test_proof_decoding::<BaseElement, Blake3_256<BaseElement>>(rescue_eg);
+}
+
+#[test]
+fn rescue_test_public_inputs_serialization() {
+    let rescue_eg = Box::new(super::RescueRapsExample::new(128, build_options(false)));
+    crate::tests::test_public_inputs_serialization::<super::RescueRapsAir>(rescue_eg);
+}
+
+#[test]
+fn rescue_test_distributed_proof_generation() {
+    let rescue_eg = super::RescueRapsExample::new(128, build_options(true));
+    crate::tests::test_distributed_proof_generation(|| {
+        let prover = super::RescueRapsProver::new(build_options(true));
+        let trace = prover.build_trace(
+            &rescue_eg.seeds,
+            &rescue_eg.permuted_seeds,
+            rescue_eg.result,
+        );
+        (prover, trace)
+    });
+}
+
 fn build_options(use_extension_field: bool) -> ProofOptions {
     let extension = if use_extension_field {
         FieldExtension::Quadratic
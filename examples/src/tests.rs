@@ -3,7 +3,15 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use crate::Example;
+use crate::{read_pub_inputs, Example};
+use std::{net::TcpListener, sync::Arc, thread};
+use winterfell::{
+    crypto::ElementHasher,
+    math::{fields::CubeExtension, ExtensibleField, FieldElement},
+    Air, Context, Deserializable, LocalTransport, ProofDeserializationError, ProofOptions,
+    ProofSection, Prover, ProverWorker, RequestHandler, Serializable, SliceReader, SocketTransport,
+    StarkProof, TraceInfo, Transport, VerifierError,
+};
 
 pub fn test_basic_proof_verification(e: Box<dyn Example>) {
     let proof = e.prove();
@@ -15,3 +23,357 @@ pub fn test_basic_proof_verification_fail(e: Box<dyn Example>) {
     let verified = e.verify_with_wrong_inputs(proof);
     assert!(verified.is_err());
 }
+
+pub fn test_proof_verification_fail_wrong_version(e: Box<dyn Example>) {
+    let proof = e.prove();
+    let version = proof.context.version();
+
+    // protocol version is the first byte of a serialized context
+    let context_bytes = proof.context.to_bytes();
+    let mut proof_bytes = proof.to_bytes();
+    let context_pos = proof_bytes
+        .windows(context_bytes.len())
+        .position(|window| window == context_bytes)
+        .unwrap();
+    proof_bytes[context_pos] = version + 1;
+    let proof = StarkProof::from_bytes(&proof_bytes).unwrap();
+
+    let verified = e.verify(proof);
+    assert_eq!(
+        Err(VerifierError::UnsupportedProtocolVersion(version + 1)),
+        verified
+    );
+}
+
+pub fn test_proof_serialization(e: Box<dyn Example>) {
+    let proof = e.prove();
+
+    // proof serialized using the current format can be read back and verified
+    let proof_bytes = proof.to_bytes();
+    assert!(proof_bytes.starts_with(&StarkProof::MAGIC));
+    assert_eq!(
+        StarkProof::FORMAT_VERSION,
+        proof_bytes[StarkProof::MAGIC.len()]
+    );
+    let parsed_proof = StarkProof::from_bytes(&proof_bytes).unwrap();
+    assert_eq!(proof, parsed_proof);
+
+    // proof components serialized using the legacy format can be read back, but the proof is
+    // then treated as a legacy proof, and thus, is rejected by the verifier
+    let mut legacy_bytes = Vec::new();
+    proof.context.write_legacy_into(&mut legacy_bytes);
+    proof.commitments.write_into(&mut legacy_bytes);
+    proof.trace_queries.write_into(&mut legacy_bytes);
+    proof.constraint_queries.write_into(&mut legacy_bytes);
+    proof.ood_frame.write_into(&mut legacy_bytes);
+    proof.fri_proof.write_into(&mut legacy_bytes);
+    legacy_bytes.extend_from_slice(&proof.pow_nonce.to_le_bytes());
+    let parsed_proof = StarkProof::from_bytes(&legacy_bytes).unwrap();
+    assert_eq!(
+        Context::LEGACY_PROTOCOL_VERSION,
+        parsed_proof.context.version()
+    );
+    assert_eq!(proof.commitments, parsed_proof.commitments);
+    assert_eq!(proof.fri_proof, parsed_proof.fri_proof);
+    assert_eq!(
+        Err(VerifierError::UnsupportedProtocolVersion(
+            Context::LEGACY_PROTOCOL_VERSION
+        )),
+        e.verify(parsed_proof)
+    );
+
+    // proof options are serialized at the end of the context (blowup factor is the second of 7
+    // bytes); an invalid blowup factor is rejected rather than causing a panic
+    let mut context_bytes = proof.context.to_bytes();
+    let blowup_pos = context_bytes.len() - 6;
+    assert_eq!(
+        proof.options().blowup_factor(),
+        context_bytes[blowup_pos] as usize
+    );
+    context_bytes[blowup_pos] = 3;
+    assert!(Context::read_from(&mut SliceReader::new(&context_bytes)).is_err());
+
+    // unsupported format versions are rejected
+    let mut bad_bytes = proof_bytes.clone();
+    bad_bytes[StarkProof::MAGIC.len()] = StarkProof::FORMAT_VERSION + 1;
+    assert_eq!(
+        Err(ProofDeserializationError::UnsupportedFormatVersion(
+            StarkProof::FORMAT_VERSION + 1
+        )),
+        StarkProof::from_bytes(&bad_bytes)
+    );
+
+    // the first entry of the section table describes the context section; changing its identifier
+    // to an unknown value makes the context section appear missing
+    let table_pos = StarkProof::MAGIC.len() + 2;
+    assert_eq!(ProofSection::Context.id(), proof_bytes[table_pos]);
+    let mut bad_bytes = proof_bytes.clone();
+    bad_bytes[table_pos] = u8::MAX;
+    assert_eq!(
+        Err(ProofDeserializationError::MissingSection(
+            ProofSection::Context
+        )),
+        StarkProof::from_bytes(&bad_bytes)
+    );
+
+    // truncated proofs are rejected
+    assert!(matches!(
+        StarkProof::from_bytes(&proof_bytes[..proof_bytes.len() - 1]),
+        Err(ProofDeserializationError::InvalidSectionTable(_))
+    ));
+
+    // errors within a section are attributed to that section; here, the last entry of the section
+    // table (proof-of-work nonce) is shortened by one byte
+    let nonce_entry_pos = table_pos + 5 * ProofSection::PowNonce.id() as usize;
+    assert_eq!(ProofSection::PowNonce.id(), proof_bytes[nonce_entry_pos]);
+    let mut bad_bytes = proof_bytes[..proof_bytes.len() - 1].to_vec();
+    bad_bytes[nonce_entry_pos + 1..nonce_entry_pos + 5].copy_from_slice(&7u32.to_le_bytes());
+    assert!(matches!(
+        StarkProof::from_bytes(&bad_bytes),
+        Err(ProofDeserializationError::InvalidSection(
+            ProofSection::PowNonce,
+            _
+        ))
+    ));
+}
+
+pub fn test_proof_serde(e: Box<dyn Example>) {
+    let proof = e.prove();
+
+    // JSON encodes byte sequences as hex strings
+    let json = serde_json::to_string(&proof).unwrap();
+    let parsed_proof: StarkProof = serde_json::from_str(&json).unwrap();
+    assert_eq!(proof, parsed_proof);
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert!(value["commitments"].is_string());
+
+    // CBOR encodes byte sequences natively
+    let cbor = serde_cbor::to_vec(&proof).unwrap();
+    let parsed_proof: StarkProof = serde_cbor::from_slice(&cbor).unwrap();
+    assert_eq!(proof, parsed_proof);
+
+    // a proof decoded via serde is verifiable
+    assert!(e.verify(parsed_proof).is_ok());
+
+    // invalid values are rejected with an error rather than a panic
+    let value = serde_json::to_value(&proof).unwrap();
+    let invalid_values = [
+        ("/context/trace_length", serde_json::json!(1000)),
+        ("/context/original_trace_length", serde_json::json!(3)),
+        (
+            "/context/original_trace_length",
+            serde_json::json!(usize::MAX),
+        ),
+        ("/context/field_modulus_bytes", serde_json::json!("")),
+        (
+            "/context/trace_layout/main_segment_width",
+            serde_json::json!(0),
+        ),
+        (
+            "/context/trace_layout/main_segment_width",
+            serde_json::json!(usize::MAX),
+        ),
+        (
+            "/context/trace_layout/aux_segment_rands/0",
+            serde_json::json!(256),
+        ),
+        (
+            "/context/trace_layout/num_aux_segments",
+            serde_json::json!(5),
+        ),
+        ("/context/options/num_queries", serde_json::json!(0)),
+        ("/context/options/blowup_factor", serde_json::json!(3)),
+        ("/context/options/fri_folding_factor", serde_json::json!(32)),
+        (
+            "/context/options/fri_max_remainder_size",
+            serde_json::json!(200),
+        ),
+        ("/context/options/hash_fn", serde_json::json!("Md5")),
+        (
+            "/context/options/field_extension",
+            serde_json::json!("Quartic"),
+        ),
+    ];
+    for (pointer, invalid_value) in invalid_values {
+        let mut value = value.clone();
+        *value.pointer_mut(pointer).unwrap() = invalid_value;
+        let json = serde_json::to_string(&value).unwrap();
+        assert!(
+            serde_json::from_str::<StarkProof>(&json).is_err(),
+            "invalid value at {} was accepted",
+            pointer
+        );
+    }
+
+    // the same applies to trace info
+    let trace_info = TraceInfo::new(proof.trace_layout().main_trace_width(), 17);
+    let mut value = serde_json::to_value(&trace_info).unwrap();
+    assert_eq!(32, value["length"]);
+    let parsed_trace_info: TraceInfo = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(trace_info, parsed_trace_info);
+    value["length"] = serde_json::json!(64);
+    assert!(serde_json::from_value::<TraceInfo>(value).is_err());
+}
+
+pub fn test_proof_decoding<E, H>(e: Box<dyn Example>)
+where
+    E: FieldElement,
+    E::BaseField: ExtensibleField<2> + ExtensibleField<3>,
+    H: ElementHasher<BaseField = E::BaseField>,
+{
+    let proof = e.prove();
+    let layout = proof.trace_layout().clone();
+    let num_queries = proof.options().num_queries();
+
+    let decoded = proof.decode::<E, H>().unwrap();
+    assert_eq!(layout.num_segments(), decoded.trace_commitments.len());
+    assert_eq!(num_queries, decoded.main_trace_queries.values.num_rows());
+    assert_eq!(
+        layout.main_trace_width(),
+        decoded.main_trace_queries.values.num_columns()
+    );
+    assert_eq!(layout.num_aux_segments(), decoded.aux_trace_queries.len());
+    assert_eq!(
+        decoded.ood_constraint_evaluations.len(),
+        decoded.constraint_queries.values.num_columns()
+    );
+    assert_eq!(decoded.fri_layers.len() + 1, decoded.fri_commitments.len());
+    assert_eq!(proof.pow_nonce, decoded.pow_nonce);
+
+    let pretty = proof.to_pretty_string::<E::BaseField>().unwrap();
+    assert_eq!(decoded.to_string(), pretty);
+    assert!(pretty.contains(&format!("proof-of-work nonce: {}", proof.pow_nonce)));
+
+    // decoding with mismatched field extension fails
+    assert!(matches!(
+        proof.decode::<CubeExtension<E::BaseField>, H>(),
+        Err(ProofDeserializationError::InvalidSection(
+            ProofSection::Context,
+            _
+        ))
+    ));
+}
+
+pub fn test_public_inputs_serialization<AIR: Air>(e: Box<dyn Example>)
+where
+    AIR::PublicInputs: Deserializable,
+{
+    let proof = e.prove();
+    let pub_inputs_bytes = e.public_inputs();
+
+    let pub_inputs = read_pub_inputs::<AIR>(&pub_inputs_bytes).unwrap();
+    assert!(winterfell::verify::<AIR>(proof, pub_inputs).is_ok());
+
+    // trailing bytes are rejected
+    let mut bad_bytes = pub_inputs_bytes;
+    bad_bytes.push(0);
+    assert!(read_pub_inputs::<AIR>(&bad_bytes).is_err());
+}
+
+pub fn test_distributed_proof_generation<P, F>(build_prover_and_trace: F)
+where
+    P: Prover,
+    P::Air: 'static,
+    <P::Air as Air>::PublicInputs: Deserializable,
+    F: Fn() -> (P, P::Trace),
+{
+    let (prover, trace) = build_prover_and_trace();
+    let expected = prover.prove(trace).unwrap().to_bytes();
+
+    // workers in the same process
+    let workers = (0..3)
+        .map(|_| Arc::new(ProverWorker::<P::Air>::new()) as Arc<dyn RequestHandler>)
+        .collect();
+    let transport = LocalTransport::new(workers);
+    assert_eq!(
+        expected,
+        prove_distributed(&build_prover_and_trace, transport)
+    );
+
+    // workers connected via TCP
+    let addresses = (0..2)
+        .map(|_| {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let address = listener.local_addr().unwrap();
+            thread::spawn(move || ProverWorker::<P::Air>::new().listen_tcp(listener));
+            address
+        })
+        .collect::<Vec<_>>();
+    let transport = SocketTransport::connect_tcp(&addresses).unwrap();
+    assert_eq!(
+        expected,
+        prove_distributed(&build_prover_and_trace, transport)
+    );
+
+    // workers connected via Unix domain sockets
+    #[cfg(unix)]
+    {
+        use std::os::unix::net::UnixListener;
+
+        let paths = (0..2)
+            .map(|i| {
+                let path = std::env::temp_dir().join(format!(
+                    "winterfell-worker-{}-{:?}-{}.sock",
+                    std::process::id(),
+                    thread::current().id(),
+                    i
+                ));
+                let _ = std::fs::remove_file(&path);
+                let listener = UnixListener::bind(&path).unwrap();
+                thread::spawn(move || ProverWorker::<P::Air>::new().listen_unix(listener));
+                path
+            })
+            .collect::<Vec<_>>();
+        let transport = SocketTransport::connect_unix(&paths).unwrap();
+        assert_eq!(
+            expected,
+            prove_distributed(&build_prover_and_trace, transport)
+        );
+        for path in paths {
+            std::fs::remove_file(path).unwrap();
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Generates a proof using a prover which distributes the work via the specified transport, and
+/// returns the serialized proof.
+fn prove_distributed<P, F, T>(build_prover_and_trace: &F, transport: T) -> Vec<u8>
+where
+    P: Prover,
+    F: Fn() -> (P, P::Trace),
+    T: Transport + 'static,
+{
+    let (prover, trace) = build_prover_and_trace();
+    let prover = DistributedProver {
+        prover,
+        transport: Box::new(transport),
+    };
+    prover.prove(trace).unwrap().to_bytes()
+}
+
+/// A prover which delegates to another prover but distributes the work via a transport.
+struct DistributedProver<P: Prover> {
+    prover: P,
+    transport: Box<dyn Transport>,
+}
+
+impl<P: Prover> Prover for DistributedProver<P> {
+    type BaseField = P::BaseField;
+    type Air = P::Air;
+    type Trace = P::Trace;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> <Self::Air as Air>::PublicInputs {
+        self.prover.get_pub_inputs(trace)
+    }
+
+    fn options(&self) -> &ProofOptions {
+        self.prover.options()
+    }
+
+    fn transport(&self) -> Option<&dyn Transport> {
+        Some(self.transport.as_ref())
+    }
+}
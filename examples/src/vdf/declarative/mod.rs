@@ -0,0 +1,203 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::regular::VdfInputs;
+use crate::{Example, ExampleOptions};
+use log::debug;
+use std::time::Instant;
+use winterfell::{
+    builder::{AirBuilder, AirDefinition, AssertionBuilder, Column, DeclarativeAir, TraceBuilder},
+    math::{fields::f128::BaseElement, log2, FieldElement},
+    ProofOptions, Prover, Serializable, StarkProof, Trace, TraceTable, VerifierError,
+};
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+const ALPHA: u32 = 3;
+const INV_ALPHA: u128 = 226854911280625642308916371969163307691;
+const FORTY_TWO: BaseElement = BaseElement::new(42);
+
+// VDF EXAMPLE
+// ================================================================================================
+
+pub fn get_example(options: ExampleOptions, num_steps: usize) -> Box<dyn Example> {
+    Box::new(VdfExample::new(num_steps, options.to_proof_options(85, 2)))
+}
+
+/// Same computation as in the regular VDF example, but the AIR is described via [AirDefinition].
+pub struct VdfExample {
+    options: ProofOptions,
+    num_steps: usize,
+    seed: BaseElement,
+    result: BaseElement,
+}
+
+impl VdfExample {
+    pub fn new(num_steps: usize, options: ProofOptions) -> Self {
+        assert!(
+            num_steps.is_power_of_two(),
+            "number of steps must be a power of 2"
+        );
+
+        // run the VDF function
+        let now = Instant::now();
+        let seed = BaseElement::new(123);
+        let result = execute_vdf(seed, num_steps);
+        debug!(
+            "Executed the VDF function for {} steps in {} ms",
+            num_steps,
+            now.elapsed().as_millis()
+        );
+
+        Self {
+            options,
+            num_steps,
+            seed,
+            result,
+        }
+    }
+
+    fn get_pub_inputs(&self) -> VdfInputs {
+        VdfInputs {
+            seed: self.seed,
+            result: self.result,
+        }
+    }
+}
+
+// EXAMPLE IMPLEMENTATION
+// ================================================================================================
+
+impl Example for VdfExample {
+    fn prove(&self) -> StarkProof {
+        debug!(
+            "Generating proof for executing a VDF function for {} steps\n\
+            ---------------------",
+            self.num_steps
+        );
+
+        // create a prover
+        let prover = VdfProver::new(self.options.clone());
+
+        // generate execution trace
+        let now = Instant::now();
+        let trace = VdfProver::build_trace(self.seed, self.num_steps);
+
+        let trace_width = trace.width();
+        let trace_length = trace.length();
+        debug!(
+            "Generated execution trace of {} registers and 2^{} steps in {} ms",
+            trace_width,
+            log2(trace_length),
+            now.elapsed().as_millis()
+        );
+
+        // generate the proof
+        prover.prove(trace).unwrap()
+    }
+
+    fn public_inputs(&self) -> Vec<u8> {
+        self.get_pub_inputs().to_bytes()
+    }
+
+    fn verify(&self, proof: StarkProof) -> Result<(), VerifierError> {
+        winterfell::verify::<VdfAir>(proof, self.get_pub_inputs())
+    }
+
+    fn verify_with_wrong_inputs(&self, proof: StarkProof) -> Result<(), VerifierError> {
+        let pub_inputs = VdfInputs {
+            seed: self.seed,
+            result: self.result + BaseElement::ONE,
+        };
+        winterfell::verify::<VdfAir>(proof, pub_inputs)
+    }
+}
+
+// VDF AIR
+// ================================================================================================
+
+pub type VdfAir = DeclarativeAir<VdfDefinition>;
+
+pub struct VdfDefinition;
+
+impl AirDefinition for VdfDefinition {
+    type BaseField = BaseElement;
+    type PublicInputs = VdfInputs;
+    type Columns = Column;
+    const ID: &'static [u8] = b"winterfell/examples/vdf-declarative";
+
+    fn define(air: &mut AirBuilder<BaseElement>) -> Column {
+        let state = air.column("state");
+        air.enforce(
+            "vdf",
+            state.current() - (state.next().exp(ALPHA) + FORTY_TWO),
+        );
+        state
+    }
+
+    fn get_assertions(
+        state: &Column,
+        pub_inputs: &VdfInputs,
+        assertions: &mut AssertionBuilder<BaseElement>,
+    ) {
+        assertions.first(*state, pub_inputs.seed);
+        assertions.last(*state, pub_inputs.result);
+    }
+}
+
+// VDF PROVER
+// ================================================================================================
+
+pub struct VdfProver {
+    options: ProofOptions,
+}
+
+impl VdfProver {
+    pub fn new(options: ProofOptions) -> Self {
+        Self { options }
+    }
+
+    pub fn build_trace(seed: BaseElement, n: usize) -> TraceTable<BaseElement> {
+        let builder = TraceBuilder::<VdfDefinition>::new(n);
+        let state = *builder.columns();
+        TraceTable::init(builder.fill(
+            |row| row[state] = seed,
+            |_, row| row[state] = (row[state] - FORTY_TWO).exp(INV_ALPHA),
+        ))
+    }
+}
+
+impl Prover for VdfProver {
+    type BaseField = BaseElement;
+    type Air = VdfAir;
+    type Trace = TraceTable<BaseElement>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> VdfInputs {
+        let last_step = trace.length() - 1;
+        VdfInputs {
+            seed: trace.get(0, 0),
+            result: trace.get(0, last_step),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
+
+// VDF FUNCTION
+// ================================================================================================
+
+fn execute_vdf(seed: BaseElement, n: usize) -> BaseElement {
+    let mut state = seed;
+    for _ in 0..(n - 1) {
+        state = (state - FORTY_TWO).exp(INV_ALPHA);
+    }
+    state
+}
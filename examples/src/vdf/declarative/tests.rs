@@ -0,0 +1,40 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::VdfExample;
+use winterfell::{FieldExtension, HashFunction, ProofOptions};
+
+#[test]
+fn vdf_declarative_test_basic_proof_verification() {
+    let vdf = Box::new(VdfExample::new(128, build_options(false)));
+    crate::tests::test_basic_proof_verification(vdf);
+}
+
+#[test]
+fn vdf_declarative_test_basic_proof_verification_extension() {
+    let vdf = Box::new(VdfExample::new(128, build_options(true)));
+    crate::tests::test_basic_proof_verification(vdf);
+}
+
+#[test]
+fn vdf_declarative_test_basic_proof_verification_fail() {
+    let vdf = Box::new(VdfExample::new(128, build_options(false)));
+    crate::tests::test_basic_proof_verification_fail(vdf);
+}
+
+#[test]
+fn vdf_declarative_test_public_inputs_serialization() {
+    let vdf = Box::new(VdfExample::new(128, build_options(false)));
+    crate::tests::test_public_inputs_serialization::<super::VdfAir>(vdf);
+}
+
+fn build_options(use_extension_field: bool) -> ProofOptions {
+    let extension = if use_extension_field {
+        FieldExtension::Quadratic
+    } else {
+        FieldExtension::None
+    };
+    ProofOptions::new(85, 2, 0, HashFunction::Blake3_256, extension, 4, 256)
+}
@@ -5,8 +5,8 @@
 
 use super::{BaseElement, FieldElement, ProofOptions, ALPHA, FORTY_TWO};
 use winterfell::{
-    Air, AirContext, Assertion, ByteWriter, EvaluationFrame, Serializable, TraceInfo,
-    TransitionConstraintDegree,
+    Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    EvaluationFrame, Serializable, TraceInfo, TransitionConstraintDegree,
 };
 
 // PUBLIC INPUTS
@@ -25,6 +25,14 @@ impl Serializable for VdfInputs {
     }
 }
 
+impl Deserializable for VdfInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let seed = BaseElement::read_from(source)?;
+        let result = BaseElement::read_from(source)?;
+        Ok(VdfInputs { seed, result })
+    }
+}
+
 // VDF AIR
 // ================================================================================================
 
@@ -37,6 +45,7 @@ pub struct VdfAir {
 impl Air for VdfAir {
     type BaseField = BaseElement;
     type PublicInputs = VdfInputs;
+    const ID: &'static [u8] = b"winterfell/examples/vdf_exempt";
 
     fn new(trace_info: TraceInfo, pub_inputs: VdfInputs, options: ProofOptions) -> Self {
         let degrees = vec![TransitionConstraintDegree::new(3)];
@@ -8,11 +8,11 @@ use log::debug;
 use std::time::Instant;
 use winterfell::{
     math::{fields::f128::BaseElement, log2, FieldElement},
-    ProofOptions, Prover, StarkProof, Trace, TraceTable, VerifierError,
+    ProofOptions, Prover, Serializable, StarkProof, Trace, TraceTable, VerifierError,
 };
 
 mod air;
-use air::{VdfAir, VdfInputs};
+pub use air::{VdfAir, VdfInputs};
 
 mod prover;
 use prover::VdfProver;
@@ -65,6 +65,13 @@ impl VdfExample {
             result,
         }
     }
+
+    fn get_pub_inputs(&self) -> VdfInputs {
+        VdfInputs {
+            seed: self.seed,
+            result: self.result,
+        }
+    }
 }
 
 // EXAMPLE IMPLEMENTATION
@@ -98,12 +105,12 @@ impl Example for VdfExample {
         prover.prove(trace).unwrap()
     }
 
+    fn public_inputs(&self) -> Vec<u8> {
+        self.get_pub_inputs().to_bytes()
+    }
+
     fn verify(&self, proof: StarkProof) -> Result<(), VerifierError> {
-        let pub_inputs = VdfInputs {
-            seed: self.seed,
-            result: self.result,
-        };
-        winterfell::verify::<VdfAir>(proof, pub_inputs)
+        winterfell::verify::<VdfAir>(proof, self.get_pub_inputs())
     }
 
     fn verify_with_wrong_inputs(&self, proof: StarkProof) -> Result<(), VerifierError> {
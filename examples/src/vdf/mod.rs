@@ -1,2 +1,4 @@
+pub mod declarative;
 pub mod exempt;
+pub mod padded;
 pub mod regular;
@@ -0,0 +1,93 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{BaseElement, FieldElement, ProofOptions, ALPHA, FORTY_TWO, INV_ALPHA};
+use winterfell::{
+    Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    EvaluationFrame, Serializable, TraceInfo, TracePadding, TransitionConstraintDegree,
+};
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[derive(Clone)]
+pub struct VdfInputs {
+    pub seed: BaseElement,
+    pub result: BaseElement,
+}
+
+impl Serializable for VdfInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.seed);
+        target.write(self.result);
+    }
+}
+
+impl Deserializable for VdfInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let seed = BaseElement::read_from(source)?;
+        let result = BaseElement::read_from(source)?;
+        Ok(VdfInputs { seed, result })
+    }
+}
+
+// VDF AIR
+// ================================================================================================
+
+pub struct VdfAir {
+    context: AirContext<BaseElement>,
+    seed: BaseElement,
+    result: BaseElement,
+}
+
+impl Air for VdfAir {
+    type BaseField = BaseElement;
+    type PublicInputs = VdfInputs;
+    const ID: &'static [u8] = b"winterfell/examples/vdf_padded";
+
+    fn new(trace_info: TraceInfo, pub_inputs: VdfInputs, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(3)];
+        Self {
+            context: AirContext::new(trace_info, degrees, 2, options),
+            seed: pub_inputs.seed,
+            result: pub_inputs.result,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current_state = frame.current()[0];
+        let next_state = frame.next()[0];
+
+        result[0] = current_state - (next_state.exp(ALPHA.into()) + FORTY_TWO.into());
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        // the result is asserted against the last step of the trace before padding
+        let last_step = self.original_trace_length() - 1;
+        vec![
+            Assertion::single(0, 0, self.seed),
+            Assertion::single(0, last_step, self.result),
+        ]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn trace_padding(&self) -> TracePadding {
+        TracePadding::Transition
+    }
+
+    fn apply_padding_transition(&self, current: &[Self::BaseField], next: &mut [Self::BaseField]) {
+        // padding rows continue executing the VDF function, and thus, satisfy the transition
+        // constraint without any changes to it
+        next[0] = (current[0] - FORTY_TWO).exp(INV_ALPHA);
+    }
+}
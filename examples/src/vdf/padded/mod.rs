@@ -0,0 +1,134 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use crate::{Example, ExampleOptions};
+use log::debug;
+use std::time::Instant;
+use winterfell::{
+    math::{fields::f128::BaseElement, FieldElement},
+    ProofOptions, Prover, Serializable, StarkProof, Trace, TraceTable, VerifierError,
+};
+
+mod air;
+pub use air::{VdfAir, VdfInputs};
+
+mod prover;
+use prover::VdfProver;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+const ALPHA: u64 = 3;
+const INV_ALPHA: u128 = 226854911280625642308916371969163307691;
+const FORTY_TWO: BaseElement = BaseElement::new(42);
+
+// VDF EXAMPLE
+// ================================================================================================
+
+pub fn get_example(options: ExampleOptions, num_steps: usize) -> Box<dyn Example> {
+    Box::new(VdfExample::new(num_steps, options.to_proof_options(85, 2)))
+}
+
+/// Similar to the regular VDF example, but the number of steps can be any number greater than or
+/// equal to 8; execution traces are padded to the next power of two by continuing the VDF
+/// function for the padding rows.
+pub struct VdfExample {
+    options: ProofOptions,
+    num_steps: usize,
+    seed: BaseElement,
+    result: BaseElement,
+}
+
+impl VdfExample {
+    pub fn new(num_steps: usize, options: ProofOptions) -> Self {
+        assert!(num_steps >= 8, "number of steps must be at least 8");
+
+        // run the VDF function
+        let now = Instant::now();
+        let seed = BaseElement::new(123);
+        let result = execute_vdf(seed, num_steps);
+        debug!(
+            "Executed the VDF function for {} steps in {} ms",
+            num_steps,
+            now.elapsed().as_millis()
+        );
+
+        Self {
+            options,
+            num_steps,
+            seed,
+            result,
+        }
+    }
+
+    fn get_pub_inputs(&self) -> VdfInputs {
+        VdfInputs {
+            seed: self.seed,
+            result: self.result,
+        }
+    }
+}
+
+// EXAMPLE IMPLEMENTATION
+// ================================================================================================
+
+impl Example for VdfExample {
+    fn prove(&self) -> StarkProof {
+        debug!(
+            "Generating proof for executing a VDF function for {} steps\n\
+            ---------------------",
+            self.num_steps
+        );
+
+        // create a prover
+        let prover = VdfProver::new(self.options.clone());
+
+        // generate execution trace
+        let now = Instant::now();
+        let trace = VdfProver::build_trace(self.seed, self.num_steps);
+
+        let trace_width = trace.width();
+        let trace_length = trace.length();
+        debug!(
+            "Generated execution trace of {} registers and {} steps in {} ms",
+            trace_width,
+            trace_length,
+            now.elapsed().as_millis()
+        );
+
+        // generate the proof
+        prover.prove(trace).unwrap()
+    }
+
+    fn public_inputs(&self) -> Vec<u8> {
+        self.get_pub_inputs().to_bytes()
+    }
+
+    fn verify(&self, proof: StarkProof) -> Result<(), VerifierError> {
+        winterfell::verify::<VdfAir>(proof, self.get_pub_inputs())
+    }
+
+    fn verify_with_wrong_inputs(&self, proof: StarkProof) -> Result<(), VerifierError> {
+        let pub_inputs = VdfInputs {
+            seed: self.seed,
+            result: self.result + BaseElement::ONE,
+        };
+        winterfell::verify::<VdfAir>(proof, pub_inputs)
+    }
+}
+
+// VDF FUNCTION
+// ================================================================================================
+
+fn execute_vdf(seed: BaseElement, n: usize) -> BaseElement {
+    let mut state = seed;
+    for _ in 0..(n - 1) {
+        state = (state - FORTY_TWO).exp(INV_ALPHA);
+    }
+    state
+}
@@ -0,0 +1,63 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{execute_vdf, BaseElement, VdfAir, VdfExample, VdfInputs};
+use crate::Example;
+use winterfell::{FieldExtension, HashFunction, ProofOptions};
+
+#[test]
+fn vdf_padded_test_basic_proof_verification() {
+    let vdf = Box::new(VdfExample::new(100, build_options(false)));
+    crate::tests::test_basic_proof_verification(vdf);
+}
+
+#[test]
+fn vdf_padded_test_basic_proof_verification_extension() {
+    let vdf = Box::new(VdfExample::new(100, build_options(true)));
+    crate::tests::test_basic_proof_verification(vdf);
+}
+
+#[test]
+fn vdf_padded_test_basic_proof_verification_fail() {
+    let vdf = Box::new(VdfExample::new(100, build_options(false)));
+    crate::tests::test_basic_proof_verification_fail(vdf);
+}
+
+#[test]
+fn vdf_padded_test_public_inputs_serialization() {
+    let vdf = Box::new(VdfExample::new(100, build_options(false)));
+    crate::tests::test_public_inputs_serialization::<VdfAir>(vdf);
+}
+
+#[test]
+fn vdf_padded_test_power_of_two_steps() {
+    let vdf = Box::new(VdfExample::new(128, build_options(false)));
+    crate::tests::test_basic_proof_verification(vdf);
+}
+
+#[test]
+fn vdf_padded_test_proof_records_original_length() {
+    let vdf = VdfExample::new(100, build_options(false));
+    let proof = vdf.prove();
+    assert_eq!(128, proof.trace_length());
+    assert_eq!(100, proof.original_trace_length());
+
+    // the proof does not verify against the result of the VDF executed over the padded trace
+    let seed = BaseElement::new(123);
+    let pub_inputs = VdfInputs {
+        seed,
+        result: execute_vdf(seed, 128),
+    };
+    assert!(winterfell::verify::<VdfAir>(proof, pub_inputs).is_err());
+}
+
+fn build_options(use_extension_field: bool) -> ProofOptions {
+    let extension = if use_extension_field {
+        FieldExtension::Quadratic
+    } else {
+        FieldExtension::None
+    };
+    ProofOptions::new(85, 2, 0, HashFunction::Blake3_256, extension, 4, 256)
+}
@@ -23,6 +23,12 @@ fn vdf_test_basic_proof_verification_fail() {
     crate::tests::test_basic_proof_verification_fail(fib);
 }
 
+#[test]
+fn vdf_test_public_inputs_serialization() {
+    let fib = Box::new(super::VdfExample::new(128, build_options(false)));
+    crate::tests::test_public_inputs_serialization::<super::VdfAir>(fib);
+}
+
 fn build_options(use_extension_field: bool) -> ProofOptions {
     let extension = if use_extension_field {
         FieldExtension::Quadratic
@@ -0,0 +1,390 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{
+    program::encode_word, BaseElement, ExtensionOf, FieldElement, Instruction, Program, AUX_WIDTH,
+    CLK, DST, FLAGS, IMM, INV, IS_ZERO, MEM_PERM, MEM_VALUE, NUM_OPCODES, NUM_REGISTERS, OP_A,
+    OP_B, PC, PROG_MULT, PROG_SUM, RANGE_MULT, RANGE_SUM, REGS, RES, SORTED_ACTIVE, SORTED_ADDR,
+    SORTED_CLK, SORTED_DELTA, SORTED_SAME, SORTED_VALUE, SORTED_WRITE, SRC1, SRC2, TRACE_WIDTH,
+};
+use crate::utils::{are_equal, is_binary, not};
+use winterfell::{
+    Air, AirContext, Assertion, AuxTraceRandElements, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, EvaluationFrame, ProofOptions, Serializable, TraceInfo,
+    TransitionConstraintDegree,
+};
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+/// Public inputs of the VM: the executed program, register values at the start of execution, and
+/// register values after the program has halted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicInputs {
+    pub program: Program,
+    pub inputs: [BaseElement; NUM_REGISTERS],
+    pub outputs: [BaseElement; NUM_REGISTERS],
+}
+
+impl Serializable for PublicInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.program.write_into(target);
+        target.write(&self.inputs[..]);
+        target.write(&self.outputs[..]);
+    }
+}
+
+impl Deserializable for PublicInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let program = Program::read_from(source)?;
+        let inputs = BaseElement::read_batch_from(source, NUM_REGISTERS)?;
+        let outputs = BaseElement::read_batch_from(source, NUM_REGISTERS)?;
+        Ok(PublicInputs {
+            program,
+            inputs: inputs.try_into().unwrap(),
+            outputs: outputs.try_into().unwrap(),
+        })
+    }
+}
+
+// VM AIR
+// ================================================================================================
+
+/// AIR of a register-based VM.
+///
+/// Every row of the trace describes a single execution step: the instruction executed at this
+/// step and register values before the instruction is executed. Additionally, memory accesses
+/// made during execution are recorded in the trace sorted by address and clock cycle. The
+/// auxiliary trace segment contains three columns which enforce that:
+/// * the sorted memory accesses are a permutation of the memory accesses made during execution
+///   (via a running product);
+/// * every executed instruction is an instruction of the program at the current value of the
+///   program counter (via a logarithmic derivative lookup into periodic columns which contain
+///   the program);
+/// * deltas between consecutive sorted memory accesses are smaller than the trace length (via a
+///   logarithmic derivative lookup into the clock cycle column).
+pub struct VmAir {
+    context: AirContext<BaseElement>,
+    program: Program,
+    inputs: [BaseElement; NUM_REGISTERS],
+    outputs: [BaseElement; NUM_REGISTERS],
+}
+
+impl Air for VmAir {
+    type BaseField = BaseElement;
+    type PublicInputs = PublicInputs;
+    const ID: &'static [u8] = b"winterfell/examples/vm";
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH + AUX_WIDTH, trace_info.width());
+        let trace_length = trace_info.length();
+        assert!(
+            pub_inputs.program.len() < trace_length - 1,
+            "program with {} instructions does not fit into execution trace of {} steps",
+            pub_inputs.program.len(),
+            trace_length
+        );
+
+        let mut main_degrees = vec![TransitionConstraintDegree::new(1)];
+        // flags and register selectors are binary and one-hot
+        main_degrees.append(&mut vec![TransitionConstraintDegree::new(2); IMM - FLAGS]);
+        main_degrees.append(&mut vec![TransitionConstraintDegree::new(1); 4]);
+        // operands, result, and memory value
+        main_degrees.append(&mut vec![
+            TransitionConstraintDegree::new(2),
+            TransitionConstraintDegree::new(2),
+            TransitionConstraintDegree::new(3),
+            TransitionConstraintDegree::new(2),
+        ]);
+        // registers, program counter, zero test, and halting
+        main_degrees.append(&mut vec![
+            TransitionConstraintDegree::new(3);
+            NUM_REGISTERS + 1
+        ]);
+        main_degrees.append(&mut vec![TransitionConstraintDegree::new(2); 3]);
+        // sorted memory accesses
+        main_degrees.append(&mut vec![TransitionConstraintDegree::new(2); 6]);
+        main_degrees.append(&mut vec![TransitionConstraintDegree::new(3); 3]);
+
+        let aux_degrees = vec![
+            TransitionConstraintDegree::new(3),
+            TransitionConstraintDegree::with_cycles(2, vec![trace_length]),
+            TransitionConstraintDegree::new(3),
+        ];
+
+        VmAir {
+            context: AirContext::new_multi_segment(
+                trace_info,
+                main_degrees,
+                aux_degrees,
+                4 + 2 * NUM_REGISTERS,
+                6,
+                options,
+            ),
+            program: pub_inputs.program,
+            inputs: pub_inputs.inputs,
+            outputs: pub_inputs.outputs,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        debug_assert_eq!(TRACE_WIDTH, current.len());
+        debug_assert_eq!(TRACE_WIDTH, next.len());
+
+        let flag = |opcode: usize| current[FLAGS + opcode];
+        let select = |selector: usize| -> E {
+            (0..NUM_REGISTERS)
+                .map(|i| current[selector + i] * current[REGS + i])
+                .fold(E::ZERO, |acc, value| acc + value)
+        };
+        let sum = |start: usize, len: usize| -> E {
+            current[start..start + len]
+                .iter()
+                .fold(E::ZERO, |acc, &value| acc + value)
+        };
+        let one = E::ONE;
+
+        // clock cycle is incremented by one at every step
+        result[0] = are_equal(next[CLK], current[CLK] + one);
+
+        // flags and register selectors are binary, and exactly one of them is set in each group
+        for (i, column) in (FLAGS..IMM).enumerate() {
+            result[1 + i] = is_binary(current[column]);
+        }
+        let base = 1 + IMM - FLAGS;
+        result[base] = are_equal(sum(FLAGS, NUM_OPCODES), one);
+        result[base + 1] = are_equal(sum(DST, NUM_REGISTERS), one);
+        result[base + 2] = are_equal(sum(SRC1, NUM_REGISTERS), one);
+        result[base + 3] = are_equal(sum(SRC2, NUM_REGISTERS), one);
+
+        // operands are values of the selected source registers
+        let (op_a, op_b, res) = (current[OP_A], current[OP_B], current[RES]);
+        let base = base + 4;
+        result[base] = are_equal(op_a, select(SRC1));
+        result[base + 1] = are_equal(op_b, select(SRC2));
+
+        // result of arithmetic instructions; for loads, the result is the value read from
+        // memory, which is enforced by the memory permutation argument
+        result[base + 2] = flag(Instruction::ADD) * are_equal(res, op_a + op_b)
+            + flag(Instruction::MUL) * are_equal(res, op_a * op_b)
+            + flag(Instruction::SET) * are_equal(res, current[IMM]);
+
+        // value read from or written to memory
+        result[base + 3] = are_equal(
+            current[MEM_VALUE],
+            flag(Instruction::LOAD) * res + flag(Instruction::STORE) * op_b,
+        );
+
+        // the destination register is set to the result for instructions which have a result;
+        // all other registers remain unchanged
+        let writes_result = flag(Instruction::ADD)
+            + flag(Instruction::MUL)
+            + flag(Instruction::SET)
+            + flag(Instruction::LOAD);
+        let base = base + 4;
+        for i in 0..NUM_REGISTERS {
+            let register = current[REGS + i];
+            result[base + i] = are_equal(
+                next[REGS + i],
+                register + current[DST + i] * writes_result * (res - register),
+            );
+        }
+
+        // program counter: jumps set it to the immediate value, branches do so only when the
+        // operand is not zero, halts leave it unchanged, and all other instructions increment it
+        let pc = current[PC];
+        let base = base + NUM_REGISTERS;
+        let is_zero = current[IS_ZERO];
+        let increments_pc = writes_result + flag(Instruction::STORE);
+        result[base] = are_equal(
+            next[PC],
+            flag(Instruction::JMP) * current[IMM]
+                + flag(Instruction::BNZ) * (is_zero * (pc + one) + not(is_zero) * current[IMM])
+                + flag(Instruction::HALT) * pc
+                + increments_pc * (pc + one),
+        );
+
+        // is_zero is set to one if and only if the first operand is zero
+        result[base + 1] = are_equal(is_zero, one - op_a * current[INV]);
+        result[base + 2] = op_a * is_zero;
+
+        // once the VM halts, it remains halted
+        result[base + 3] = flag(Instruction::HALT) * not(next[FLAGS + Instruction::HALT]);
+
+        // sorted memory accesses: inactive rows precede active rows; the same address flag can
+        // be set only when both rows are active and have the same address
+        let base = base + 4;
+        let same = next[SORTED_SAME];
+        let is_read = not(next[SORTED_WRITE]);
+        result[base] = is_binary(next[SORTED_ACTIVE]);
+        result[base + 1] = is_binary(next[SORTED_WRITE]);
+        result[base + 2] = is_binary(same);
+        result[base + 3] = current[SORTED_ACTIVE] * not(next[SORTED_ACTIVE]);
+        result[base + 4] = same * not(current[SORTED_ACTIVE]);
+        result[base + 5] = same * are_equal(next[SORTED_ADDR], current[SORTED_ADDR]);
+
+        // a read returns the value of the previous access to the same address; the first access
+        // to an address can read only zero
+        result[base + 6] = same * is_read * are_equal(next[SORTED_VALUE], current[SORTED_VALUE]);
+        result[base + 7] = not(same) * is_read * next[SORTED_VALUE];
+
+        // delta between consecutive active rows; deltas are range-checked in the auxiliary
+        // segment, which enforces that accesses are sorted by address and then by clock cycle
+        let delta = same * (next[SORTED_CLK] - current[SORTED_CLK] - one)
+            + not(same) * (next[SORTED_ADDR] - current[SORTED_ADDR] - one);
+        result[base + 8] = current[SORTED_ACTIVE] * are_equal(next[SORTED_DELTA], delta);
+    }
+
+    fn evaluate_aux_transition<F, E>(
+        &self,
+        main_frame: &EvaluationFrame<F>,
+        aux_frame: &EvaluationFrame<E>,
+        periodic_values: &[F],
+        aux_rand_elements: &AuxTraceRandElements<E>,
+        result: &mut [E],
+    ) where
+        F: FieldElement<BaseField = Self::BaseField>,
+        E: FieldElement<BaseField = Self::BaseField> + ExtensionOf<F>,
+    {
+        let current = main_frame.current();
+        let next = main_frame.next();
+        let aux_current = aux_frame.current();
+        let aux_next = aux_frame.next();
+        let rand_elements = aux_rand_elements.get_segment_elements(0);
+
+        // memory accesses made at the current step must match the sorted accesses in the next
+        // row; rows without memory accesses and inactive sorted rows do not affect the product
+        let is_mem = current[FLAGS + Instruction::LOAD] + current[FLAGS + Instruction::STORE];
+        let access = memory_access_fingerprint(
+            &rand_elements[..4],
+            current[CLK],
+            current[OP_A],
+            current[MEM_VALUE],
+            current[FLAGS + Instruction::STORE],
+        );
+        let sorted_access = memory_access_fingerprint(
+            &rand_elements[..4],
+            next[SORTED_CLK],
+            next[SORTED_ADDR],
+            next[SORTED_VALUE],
+            next[SORTED_WRITE],
+        );
+        let is_sorted_active = next[SORTED_ACTIVE];
+        result[0] = are_equal(
+            aux_next[MEM_PERM]
+                * (sorted_access.mul_base(is_sorted_active) + E::from(F::ONE - is_sorted_active)),
+            aux_current[MEM_PERM] * (access.mul_base(is_mem) + E::from(F::ONE - is_mem)),
+        );
+
+        // the instruction executed at the current step must be in the program at index equal to
+        // the program counter; program instructions are provided via periodic columns
+        let [alpha, beta_word, beta_imm] = [rand_elements[4], rand_elements[5], rand_elements[6]];
+        let fetched = alpha
+            - (E::from(current[PC])
+                + beta_word.mul_base(decode_word(current))
+                + beta_imm.mul_base(current[IMM]));
+        let table = alpha
+            - (E::from(current[CLK])
+                + beta_word.mul_base(periodic_values[0])
+                + beta_imm.mul_base(periodic_values[1]));
+        result[1] = (aux_next[PROG_SUM] - aux_current[PROG_SUM]) * fetched * table - table
+            + fetched.mul_base(current[PROG_MULT]);
+
+        // deltas of sorted memory accesses must be among the clock cycles 0..n-1
+        let gamma = rand_elements[7];
+        let delta = gamma - E::from(next[SORTED_DELTA]);
+        let table = gamma - E::from(current[CLK]);
+        result[2] = (aux_next[RANGE_SUM] - aux_current[RANGE_SUM]) * delta * table - table
+            + delta.mul_base(current[RANGE_MULT]);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let last_step = self.trace_length() - 1;
+        let mut assertions = vec![
+            Assertion::single(CLK, 0, BaseElement::ZERO),
+            Assertion::single(PC, 0, BaseElement::ZERO),
+            // the VM must have halted at the step preceding the last one, so that the halting
+            // instruction is looked up in the program
+            Assertion::single(FLAGS + Instruction::HALT, last_step - 1, BaseElement::ONE),
+            // the first row of sorted memory accesses is always inactive
+            Assertion::single(SORTED_ACTIVE, 0, BaseElement::ZERO),
+        ];
+        for i in 0..NUM_REGISTERS {
+            assertions.push(Assertion::single(REGS + i, 0, self.inputs[i]));
+            assertions.push(Assertion::single(REGS + i, last_step, self.outputs[i]));
+        }
+        assertions
+    }
+
+    fn get_aux_assertions<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        _aux_rand_elements: &AuxTraceRandElements<E>,
+    ) -> Vec<Assertion<E>> {
+        let last_step = self.trace_length() - 1;
+        vec![
+            Assertion::single(MEM_PERM, 0, E::ONE),
+            Assertion::single(MEM_PERM, last_step, E::ONE),
+            Assertion::single(PROG_SUM, 0, E::ZERO),
+            Assertion::single(PROG_SUM, last_step, E::ZERO),
+            Assertion::single(RANGE_SUM, 0, E::ZERO),
+            Assertion::single(RANGE_SUM, last_step, E::ZERO),
+        ]
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        let (words, imms) = self.program.to_table(self.trace_length());
+        vec![words, imms]
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Reduces a memory access to a single value using the provided random elements; the first
+/// random element is subtracted from the result.
+pub fn memory_access_fingerprint<F, E>(
+    rand_elements: &[E],
+    clk: F,
+    address: F,
+    value: F,
+    is_write: F,
+) -> E
+where
+    F: FieldElement,
+    E: FieldElement + ExtensionOf<F>,
+{
+    rand_elements[0]
+        - (E::from(clk)
+            + rand_elements[1].mul_base(address)
+            + rand_elements[2].mul_base(value)
+            + rand_elements[3].mul_base(is_write))
+}
+
+/// Computes the encoding of the instruction described by the specified row of the trace; see
+/// [Instruction::encode()].
+pub fn decode_word<E: FieldElement>(row: &[E]) -> E {
+    let index = |start: usize, len: usize| -> E {
+        (0..len).fold(E::ZERO, |acc, i| acc + row[start + i] * E::from(i as u8))
+    };
+    encode_word(
+        index(FLAGS, NUM_OPCODES),
+        index(DST, NUM_REGISTERS),
+        index(SRC1, NUM_REGISTERS),
+        index(SRC2, NUM_REGISTERS),
+    )
+}
@@ -0,0 +1,224 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use crate::{Example, ExampleOptions};
+use log::debug;
+use std::time::Instant;
+use winterfell::{
+    math::{fields::f128::BaseElement, log2, ExtensionOf, FieldElement, StarkField},
+    ProofOptions, Prover, Serializable, StarkProof, Trace, VerifierError,
+};
+
+mod program;
+pub use program::{Execution, Instruction, MemoryAccess, Program, State};
+
+mod air;
+pub use air::{PublicInputs, VmAir};
+
+mod prover;
+pub use prover::VmProver;
+
+mod trace;
+pub use trace::VmTrace;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of general-purpose registers of the VM.
+pub const NUM_REGISTERS: usize = 4;
+
+/// Number of distinct instructions of the VM.
+const NUM_OPCODES: usize = 8;
+
+/// Maximum number of steps a program is allowed to execute.
+const MAX_STEPS: usize = 1 << 20;
+
+// Columns of the main trace segment describing execution of instructions; each row of the trace
+// describes a single execution step.
+const CLK: usize = 0;
+const PC: usize = 1;
+/// One-hot encoded opcode of the instruction.
+const FLAGS: usize = 2;
+/// One-hot encoded index of the destination register.
+const DST: usize = FLAGS + NUM_OPCODES;
+/// One-hot encoded index of the first source register.
+const SRC1: usize = DST + NUM_REGISTERS;
+/// One-hot encoded index of the second source register.
+const SRC2: usize = SRC1 + NUM_REGISTERS;
+const IMM: usize = SRC2 + NUM_REGISTERS;
+const REGS: usize = IMM + 1;
+/// Value of the first source register.
+const OP_A: usize = REGS + NUM_REGISTERS;
+/// Value of the second source register.
+const OP_B: usize = OP_A + 1;
+/// Value written into the destination register.
+const RES: usize = OP_B + 1;
+/// Value read from or written to memory; zero when memory is not accessed.
+const MEM_VALUE: usize = RES + 1;
+/// Inverse of the first operand, or zero if the first operand is zero.
+const INV: usize = MEM_VALUE + 1;
+/// Set to one when the first operand is zero.
+const IS_ZERO: usize = INV + 1;
+/// Number of times the program instruction at index equal to the clock cycle was executed.
+const PROG_MULT: usize = IS_ZERO + 1;
+
+// Columns of the main trace segment describing memory accesses sorted by address and then by
+// clock cycle; the sorted accesses are placed at the end of the trace and are preceded by inactive
+// rows.
+const SORTED_ACTIVE: usize = PROG_MULT + 1;
+const SORTED_CLK: usize = SORTED_ACTIVE + 1;
+const SORTED_ADDR: usize = SORTED_CLK + 1;
+const SORTED_VALUE: usize = SORTED_ADDR + 1;
+const SORTED_WRITE: usize = SORTED_VALUE + 1;
+/// Set to one when the access has the same address as the previous access.
+const SORTED_SAME: usize = SORTED_WRITE + 1;
+/// Difference between clock cycles (for the same address) or addresses (otherwise) of the
+/// previous access and this access, minus one; must be smaller than the trace length.
+const SORTED_DELTA: usize = SORTED_SAME + 1;
+/// Number of times the value equal to the clock cycle was used as a delta.
+const RANGE_MULT: usize = SORTED_DELTA + 1;
+
+const TRACE_WIDTH: usize = RANGE_MULT + 1;
+
+// Columns of the auxiliary trace segment.
+/// Running product for the permutation between memory accesses and sorted memory accesses.
+const MEM_PERM: usize = 0;
+/// Running sum for the lookup of executed instructions into the program.
+const PROG_SUM: usize = 1;
+/// Running sum for the lookup of deltas between sorted memory accesses into the clock cycles.
+const RANGE_SUM: usize = 2;
+
+const AUX_WIDTH: usize = RANGE_SUM + 1;
+
+/// Number of random elements used by the auxiliary trace segment: 4 for memory accesses, 3 for
+/// the program lookup, and 1 for the range check.
+const NUM_RAND_ELEMENTS: usize = 8;
+
+// VM EXAMPLE
+// ================================================================================================
+
+pub fn get_example(options: ExampleOptions, num_values: usize) -> Box<dyn Example> {
+    Box::new(VmExample::new(num_values, options.to_proof_options(28, 8)))
+}
+
+/// Proves execution of a program which writes squares of integers 0..n into memory, and then
+/// reads them back to compute their sum.
+pub struct VmExample {
+    options: ProofOptions,
+    program: Program,
+    inputs: [BaseElement; NUM_REGISTERS],
+    outputs: [BaseElement; NUM_REGISTERS],
+}
+
+impl VmExample {
+    pub fn new(num_values: usize, options: ProofOptions) -> VmExample {
+        assert!(num_values > 0, "number of values must be greater than zero");
+
+        // execute the program
+        let now = Instant::now();
+        let program = sum_of_squares_program();
+        let mut inputs = [BaseElement::ZERO; NUM_REGISTERS];
+        inputs[0] = BaseElement::from(num_values as u64);
+        let execution = program.execute(inputs, MAX_STEPS);
+        debug!(
+            "Executed a program of {} instructions in {} steps in {} ms",
+            program.len(),
+            execution.states.len(),
+            now.elapsed().as_millis()
+        );
+
+        VmExample {
+            options,
+            program,
+            inputs,
+            outputs: execution.outputs(),
+        }
+    }
+
+    fn get_pub_inputs(&self) -> PublicInputs {
+        PublicInputs {
+            program: self.program.clone(),
+            inputs: self.inputs,
+            outputs: self.outputs,
+        }
+    }
+}
+
+// EXAMPLE IMPLEMENTATION
+// ================================================================================================
+
+impl Example for VmExample {
+    fn prove(&self) -> StarkProof {
+        debug!(
+            "Generating proof for executing a program of {} instructions\n\
+            ---------------------",
+            self.program.len()
+        );
+
+        // create a prover
+        let prover = VmProver::new(self.program.clone(), self.options.clone());
+
+        // generate the execution trace
+        let now = Instant::now();
+        let trace = prover.build_trace(self.inputs);
+        let trace_length = trace.length();
+        debug!(
+            "Generated execution trace of {} registers and 2^{} steps in {} ms",
+            trace.width(),
+            log2(trace_length),
+            now.elapsed().as_millis()
+        );
+
+        // generate the proof
+        prover.prove(trace).unwrap()
+    }
+
+    fn public_inputs(&self) -> Vec<u8> {
+        self.get_pub_inputs().to_bytes()
+    }
+
+    fn verify(&self, proof: StarkProof) -> Result<(), VerifierError> {
+        winterfell::verify::<VmAir>(proof, self.get_pub_inputs())
+    }
+
+    fn verify_with_wrong_inputs(&self, proof: StarkProof) -> Result<(), VerifierError> {
+        let mut pub_inputs = self.get_pub_inputs();
+        pub_inputs.outputs[0] += BaseElement::ONE;
+        winterfell::verify::<VmAir>(proof, pub_inputs)
+    }
+}
+
+// PROGRAMS
+// ================================================================================================
+
+/// Returns a program which expects `n` in register 0, stores squares of integers 0..n at
+/// memory addresses 0..n, then loads them back and leaves their sum in register 0.
+///
+/// The program assumes that `n` is greater than zero.
+pub fn sum_of_squares_program() -> Program {
+    use Instruction::*;
+    let minus_one = -BaseElement::ONE;
+    Program::new(vec![
+        Set(1, BaseElement::ZERO), // 0: r1 = 0 (address)
+        Set(3, BaseElement::ONE),  // 1: r3 = 1
+        Bnz(0, 4),                 // 2: loop while r0 != 0
+        Jmp(10),                   // 3: otherwise, exit the loop
+        Mul(2, 1, 1),              // 4: r2 = r1 * r1
+        Store(1, 2),               // 5: mem[r1] = r2
+        Add(1, 3, 1),              // 6: r1 = r1 + 1
+        Set(2, minus_one),         // 7: r2 = -1
+        Add(0, 2, 0),              // 8: r0 = r0 - 1
+        Jmp(2),                    // 9
+        Set(2, minus_one),         // 10: r2 = -1; r0 = 0 at this point
+        Add(1, 1, 2),              // 11: r1 = r1 - 1
+        Load(3, 1),                // 12: r3 = mem[r1]
+        Add(0, 0, 3),              // 13: r0 = r0 + r3
+        Bnz(1, 11),                // 14: loop while r1 != 0
+        Halt,                      // 15
+    ])
+}
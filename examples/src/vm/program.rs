@@ -0,0 +1,359 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{BaseElement, FieldElement, StarkField, NUM_REGISTERS};
+use std::collections::BTreeMap;
+use winterfell::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+// INSTRUCTION
+// ================================================================================================
+
+/// A single instruction of the VM.
+///
+/// Registers are referred to by their indexes; jump targets are indexes of instructions in the
+/// program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    /// Stops execution; the VM keeps executing this instruction until the end of the trace.
+    Halt,
+    /// `dst = src1 + src2`
+    Add(usize, usize, usize),
+    /// `dst = src1 * src2`
+    Mul(usize, usize, usize),
+    /// `dst = imm`
+    Set(usize, BaseElement),
+    /// `pc = target`
+    Jmp(usize),
+    /// `if src != 0 { pc = target }`
+    Bnz(usize, usize),
+    /// `dst = mem[addr]`, where `addr` is a register holding the address.
+    Load(usize, usize),
+    /// `mem[addr] = src`, where `addr` is a register holding the address.
+    Store(usize, usize),
+}
+
+impl Instruction {
+    pub const HALT: usize = 0;
+    pub const ADD: usize = 1;
+    pub const MUL: usize = 2;
+    pub const SET: usize = 3;
+    pub const JMP: usize = 4;
+    pub const BNZ: usize = 5;
+    pub const LOAD: usize = 6;
+    pub const STORE: usize = 7;
+
+    /// Returns the fields of this instruction as a tuple (opcode, dst, src1, src2, imm); fields
+    /// which are not used by the instruction are set to zero.
+    pub fn fields(&self) -> (usize, usize, usize, usize, BaseElement) {
+        let zero = BaseElement::ZERO;
+        match *self {
+            Self::Halt => (Self::HALT, 0, 0, 0, zero),
+            Self::Add(dst, src1, src2) => (Self::ADD, dst, src1, src2, zero),
+            Self::Mul(dst, src1, src2) => (Self::MUL, dst, src1, src2, zero),
+            Self::Set(dst, imm) => (Self::SET, dst, 0, 0, imm),
+            Self::Jmp(target) => (Self::JMP, 0, 0, 0, to_element(target)),
+            Self::Bnz(src, target) => (Self::BNZ, 0, src, 0, to_element(target)),
+            Self::Load(dst, addr) => (Self::LOAD, dst, addr, 0, zero),
+            Self::Store(addr, src) => (Self::STORE, 0, addr, src, zero),
+        }
+    }
+
+    /// Encodes opcode and register indexes of this instruction into a single field element.
+    ///
+    /// Together with the immediate value, the encoding uniquely identifies the instruction; the
+    /// [Halt](Instruction::Halt) instruction is encoded as zero.
+    pub fn encode(&self) -> (BaseElement, BaseElement) {
+        let (opcode, dst, src1, src2, imm) = self.fields();
+        let word = encode_word(
+            to_element(opcode),
+            to_element(dst),
+            to_element(src1),
+            to_element(src2),
+        );
+        (word, imm)
+    }
+
+    /// Builds an instruction from its fields; returns an error if the fields do not describe a
+    /// valid instruction.
+    fn from_fields(
+        opcode: usize,
+        dst: usize,
+        src1: usize,
+        src2: usize,
+        imm: BaseElement,
+    ) -> Result<Self, String> {
+        let target =
+            || usize::try_from(imm.as_int()).map_err(|_| format!("invalid jump target {}", imm));
+        let instruction = match opcode {
+            Self::HALT => Self::Halt,
+            Self::ADD => Self::Add(dst, src1, src2),
+            Self::MUL => Self::Mul(dst, src1, src2),
+            Self::SET => Self::Set(dst, imm),
+            Self::JMP => Self::Jmp(target()?),
+            Self::BNZ => Self::Bnz(src1, target()?),
+            Self::LOAD => Self::Load(dst, src1),
+            Self::STORE => Self::Store(src1, src2),
+            _ => return Err(format!("invalid opcode {}", opcode)),
+        };
+        if instruction.fields() != (opcode, dst, src1, src2, imm) {
+            return Err(format!("invalid fields for opcode {}", opcode));
+        }
+        Ok(instruction)
+    }
+}
+
+/// Encodes opcode and register indexes into a single field element.
+pub fn encode_word<E: FieldElement>(opcode: E, dst: E, src1: E, src2: E) -> E {
+    opcode + dst * E::from(8u8) + src1 * E::from(32u8) + src2 * E::from(128u8)
+}
+
+// PROGRAM
+// ================================================================================================
+
+/// A sequence of VM instructions; execution starts with the first instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program {
+    instructions: Vec<Instruction>,
+}
+
+impl Program {
+    /// Returns a new program consisting of the specified instructions.
+    ///
+    /// # Panics
+    /// Panics if the program is empty, refers to registers which do not exist, or contains
+    /// jumps to instructions outside of the program.
+    pub fn new(instructions: Vec<Instruction>) -> Self {
+        validate_instructions(&instructions).unwrap_or_else(|err| panic!("{}", err));
+        Self { instructions }
+    }
+
+    /// Returns instructions of this program.
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    /// Returns the number of instructions in this program.
+    pub fn len(&self) -> usize {
+        self.instructions.len()
+    }
+
+    /// Returns true if this program does not contain any instructions; this is never the case
+    /// for a valid program.
+    pub fn is_empty(&self) -> bool {
+        self.instructions.is_empty()
+    }
+
+    /// Returns the instruction at the specified index, or [Instruction::Halt] if the index is
+    /// outside of the program.
+    pub fn get(&self, pc: usize) -> Instruction {
+        self.instructions
+            .get(pc)
+            .copied()
+            .unwrap_or(Instruction::Halt)
+    }
+
+    /// Returns encoded instructions of this program padded with [Instruction::Halt] to the
+    /// specified length; the first vector contains instruction words, and the second one
+    /// contains immediate values.
+    pub fn to_table(&self, length: usize) -> (Vec<BaseElement>, Vec<BaseElement>) {
+        assert!(
+            self.len() <= length,
+            "program with {} instructions cannot fit into a table of {} rows",
+            self.len(),
+            length
+        );
+        let mut words = vec![BaseElement::ZERO; length];
+        let mut imms = vec![BaseElement::ZERO; length];
+        for (i, instruction) in self.instructions.iter().enumerate() {
+            let (word, imm) = instruction.encode();
+            words[i] = word;
+            imms[i] = imm;
+        }
+        (words, imms)
+    }
+
+    /// Executes this program starting with the specified register values until it reaches a
+    /// [Halt](Instruction::Halt) instruction.
+    ///
+    /// # Panics
+    /// Panics if the program does not halt within `max_steps` steps.
+    pub fn execute(&self, inputs: [BaseElement; NUM_REGISTERS], max_steps: usize) -> Execution {
+        let mut pc = 0;
+        let mut registers = inputs;
+        let mut memory = BTreeMap::new();
+        let mut states = Vec::new();
+        let mut memory_accesses = Vec::new();
+
+        loop {
+            // instructions past the end of the program are treated as halts
+            let instruction = self.get(pc);
+            states.push(State { pc, registers });
+            if instruction == Instruction::Halt {
+                break;
+            }
+            assert!(
+                states.len() <= max_steps,
+                "program did not halt within {} steps",
+                max_steps
+            );
+
+            let clk = states.len() - 1;
+            pc += 1;
+            match instruction {
+                Instruction::Halt => unreachable!(),
+                Instruction::Add(dst, src1, src2) => {
+                    registers[dst] = registers[src1] + registers[src2];
+                }
+                Instruction::Mul(dst, src1, src2) => {
+                    registers[dst] = registers[src1] * registers[src2];
+                }
+                Instruction::Set(dst, imm) => registers[dst] = imm,
+                Instruction::Jmp(target) => pc = target,
+                Instruction::Bnz(src, target) => {
+                    if registers[src] != BaseElement::ZERO {
+                        pc = target;
+                    }
+                }
+                Instruction::Load(dst, addr) => {
+                    let address = registers[addr].as_int();
+                    let value = *memory.get(&address).unwrap_or(&BaseElement::ZERO);
+                    memory_accesses.push(MemoryAccess::new(clk, address, value, false));
+                    registers[dst] = value;
+                }
+                Instruction::Store(addr, src) => {
+                    let address = registers[addr].as_int();
+                    memory.insert(address, registers[src]);
+                    memory_accesses.push(MemoryAccess::new(clk, address, registers[src], true));
+                }
+            }
+        }
+
+        Execution {
+            states,
+            memory_accesses,
+        }
+    }
+}
+
+impl Serializable for Program {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u16(self.instructions.len() as u16);
+        for instruction in self.instructions.iter() {
+            let (opcode, dst, src1, src2, imm) = instruction.fields();
+            target.write_u8(opcode as u8);
+            target.write_u8(dst as u8);
+            target.write_u8(src1 as u8);
+            target.write_u8(src2 as u8);
+            target.write(imm);
+        }
+    }
+}
+
+impl Deserializable for Program {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_instructions = source.read_u16()? as usize;
+        let mut instructions = Vec::with_capacity(num_instructions);
+        for _ in 0..num_instructions {
+            let opcode = source.read_u8()? as usize;
+            let dst = source.read_u8()? as usize;
+            let src1 = source.read_u8()? as usize;
+            let src2 = source.read_u8()? as usize;
+            let imm = BaseElement::read_from(source)?;
+            let instruction = Instruction::from_fields(opcode, dst, src1, src2, imm)
+                .map_err(DeserializationError::InvalidValue)?;
+            instructions.push(instruction);
+        }
+        validate_instructions(&instructions).map_err(DeserializationError::InvalidValue)?;
+        Ok(Self { instructions })
+    }
+}
+
+// EXECUTION
+// ================================================================================================
+
+/// State of the VM before an instruction is executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct State {
+    pub pc: usize,
+    pub registers: [BaseElement; NUM_REGISTERS],
+}
+
+/// A single read from or write to memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryAccess {
+    pub clk: usize,
+    pub address: u128,
+    pub value: BaseElement,
+    pub is_write: bool,
+}
+
+impl MemoryAccess {
+    fn new(clk: usize, address: u128, value: BaseElement, is_write: bool) -> Self {
+        Self {
+            clk,
+            address,
+            value,
+            is_write,
+        }
+    }
+}
+
+/// Result of executing a program.
+#[derive(Debug, Clone)]
+pub struct Execution {
+    /// States of the VM at every step of execution; the last state is the state at which the
+    /// VM has reached a [Halt](Instruction::Halt) instruction.
+    pub states: Vec<State>,
+    /// All memory accesses in the order in which they were made.
+    pub memory_accesses: Vec<MemoryAccess>,
+}
+
+impl Execution {
+    /// Returns register values after the program has halted.
+    pub fn outputs(&self) -> [BaseElement; NUM_REGISTERS] {
+        self.states.last().expect("no execution states").registers
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn to_element(value: usize) -> BaseElement {
+    BaseElement::from(value as u64)
+}
+
+fn validate_instructions(instructions: &[Instruction]) -> Result<(), String> {
+    if instructions.is_empty() {
+        return Err("program must contain at least one instruction".to_string());
+    }
+    if instructions.len() > u16::MAX as usize {
+        return Err(format!(
+            "program cannot contain more than {} instructions, but was {}",
+            u16::MAX,
+            instructions.len()
+        ));
+    }
+    for (i, instruction) in instructions.iter().enumerate() {
+        let (_, dst, src1, src2, _) = instruction.fields();
+        if dst >= NUM_REGISTERS || src1 >= NUM_REGISTERS || src2 >= NUM_REGISTERS {
+            return Err(format!(
+                "instruction {} refers to a register which does not exist",
+                i
+            ));
+        }
+        match instruction {
+            Instruction::Jmp(target) | Instruction::Bnz(_, target)
+                if *target >= instructions.len() =>
+            {
+                return Err(format!(
+                    "instruction {} jumps to instruction {} outside of the program",
+                    i, target
+                ));
+            }
+            _ => (),
+        }
+    }
+    Ok(())
+}
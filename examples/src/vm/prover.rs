@@ -0,0 +1,156 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{
+    BaseElement, FieldElement, Instruction, Program, ProofOptions, Prover, PublicInputs,
+    StarkField, Trace, VmAir, VmTrace, CLK, DST, FLAGS, IMM, INV, IS_ZERO, MAX_STEPS, MEM_VALUE,
+    NUM_REGISTERS, OP_A, OP_B, PC, PROG_MULT, RANGE_MULT, REGS, RES, SORTED_ACTIVE, SORTED_ADDR,
+    SORTED_CLK, SORTED_DELTA, SORTED_SAME, SORTED_VALUE, SORTED_WRITE, SRC1, SRC2, TRACE_WIDTH,
+};
+use winterfell::TraceInfo;
+
+// VM PROVER
+// ================================================================================================
+
+pub struct VmProver {
+    program: Program,
+    options: ProofOptions,
+}
+
+impl VmProver {
+    pub fn new(program: Program, options: ProofOptions) -> Self {
+        Self { program, options }
+    }
+
+    /// Executes the program with the specified register values and builds the execution trace.
+    ///
+    /// The trace is long enough to fit the program, and for the VM to reach a halting
+    /// instruction at least one step before the last step of the trace.
+    ///
+    /// # Panics
+    /// Panics if the program does not halt within the maximum number of steps, or if addresses
+    /// accessed by the program are too far apart.
+    pub fn build_trace(&self, inputs: [BaseElement; NUM_REGISTERS]) -> VmTrace {
+        let execution = self.program.execute(inputs, MAX_STEPS);
+        let num_steps = execution.states.len();
+        let trace_length = (num_steps + 1)
+            .max(self.program.len() + 2)
+            .max(TraceInfo::MIN_TRACE_LENGTH)
+            .next_power_of_two();
+
+        let mut columns = vec![vec![BaseElement::ZERO; trace_length]; TRACE_WIDTH];
+
+        // fill in execution steps; after the VM halts, the last state is repeated until the end
+        // of the trace
+        for i in 0..trace_length {
+            let state = execution.states[i.min(num_steps - 1)];
+            let instruction = self.program.get(state.pc);
+            let (opcode, dst, src1, src2, imm) = instruction.fields();
+            let registers = state.registers;
+            let (op_a, op_b) = (registers[src1], registers[src2]);
+            let res = match instruction {
+                Instruction::Add(..) => op_a + op_b,
+                Instruction::Mul(..) => op_a * op_b,
+                Instruction::Set(_, imm) => imm,
+                Instruction::Load(dst, _) => execution.states[i + 1].registers[dst],
+                _ => BaseElement::ZERO,
+            };
+            let mem_value = match instruction {
+                Instruction::Load(..) => res,
+                Instruction::Store(..) => op_b,
+                _ => BaseElement::ZERO,
+            };
+
+            columns[CLK][i] = BaseElement::from(i as u64);
+            columns[PC][i] = BaseElement::from(state.pc as u64);
+            columns[FLAGS + opcode][i] = BaseElement::ONE;
+            columns[DST + dst][i] = BaseElement::ONE;
+            columns[SRC1 + src1][i] = BaseElement::ONE;
+            columns[SRC2 + src2][i] = BaseElement::ONE;
+            columns[IMM][i] = imm;
+            for (j, &value) in registers.iter().enumerate() {
+                columns[REGS + j][i] = value;
+            }
+            columns[OP_A][i] = op_a;
+            columns[OP_B][i] = op_b;
+            columns[RES][i] = res;
+            columns[MEM_VALUE][i] = mem_value;
+            columns[INV][i] = op_a.inv();
+            if op_a == BaseElement::ZERO {
+                columns[IS_ZERO][i] = BaseElement::ONE;
+            }
+
+            // the last row is not looked up in the program
+            if i < trace_length - 1 {
+                columns[PROG_MULT][state.pc] += BaseElement::ONE;
+            }
+        }
+
+        // fill in memory accesses sorted by address and then by clock cycle; these are placed at
+        // the end of the trace
+        let mut accesses = execution.memory_accesses;
+        accesses.sort_by_key(|access| (access.address, access.clk));
+        let offset = trace_length - accesses.len();
+        for (j, access) in accesses.iter().enumerate() {
+            let i = offset + j;
+            columns[SORTED_ACTIVE][i] = BaseElement::ONE;
+            columns[SORTED_CLK][i] = BaseElement::from(access.clk as u64);
+            columns[SORTED_ADDR][i] = BaseElement::from(access.address);
+            columns[SORTED_VALUE][i] = access.value;
+            columns[SORTED_WRITE][i] = BaseElement::from(access.is_write as u8);
+
+            if j > 0 {
+                let previous = accesses[j - 1];
+                let delta = if previous.address == access.address {
+                    columns[SORTED_SAME][i] = BaseElement::ONE;
+                    (access.clk - previous.clk - 1) as u128
+                } else {
+                    access.address - previous.address - 1
+                };
+                assert!(
+                    delta < (trace_length - 1) as u128,
+                    "addresses {} and {} are too far apart",
+                    previous.address,
+                    access.address
+                );
+                columns[SORTED_DELTA][i] = BaseElement::from(delta);
+            }
+        }
+
+        // count how many times each clock cycle value is used as a delta; deltas in all rows
+        // except the first one are looked up
+        for i in 1..trace_length {
+            let delta = columns[SORTED_DELTA][i].as_int() as usize;
+            columns[RANGE_MULT][delta] += BaseElement::ONE;
+        }
+
+        VmTrace::new(columns, self.program.to_table(trace_length))
+    }
+}
+
+impl Prover for VmProver {
+    type BaseField = BaseElement;
+    type Air = VmAir;
+    type Trace = VmTrace;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs {
+        let last_step = trace.length() - 1;
+        let mut inputs = [BaseElement::ZERO; NUM_REGISTERS];
+        let mut outputs = [BaseElement::ZERO; NUM_REGISTERS];
+        for i in 0..NUM_REGISTERS {
+            inputs[i] = trace.get(REGS + i, 0);
+            outputs[i] = trace.get(REGS + i, last_step);
+        }
+        PublicInputs {
+            program: self.program.clone(),
+            inputs,
+            outputs,
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
@@ -0,0 +1,265 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{
+    sum_of_squares_program, BaseElement, FieldElement, Instruction, Program, Prover, Trace, VmAir,
+    VmExample, VmProver, VmTrace, MAX_STEPS, MEM_PERM, MEM_VALUE, NUM_RAND_ELEMENTS, NUM_REGISTERS,
+    PROG_SUM, RANGE_MULT, RANGE_SUM, REGS, RES, SORTED_ADDR, SORTED_CLK, SORTED_DELTA,
+    SORTED_VALUE, SORTED_WRITE, TRACE_WIDTH,
+};
+use winterfell::{
+    Air, AuxTraceRandElements, Deserializable, EvaluationFrame, FieldExtension, HashFunction,
+    ProofOptions, Serializable, SliceReader,
+};
+
+#[test]
+fn vm_test_basic_proof_verification() {
+    let vm = Box::new(VmExample::new(8, build_options(false)));
+    crate::tests::test_basic_proof_verification(vm);
+}
+
+#[test]
+fn vm_test_basic_proof_verification_extension() {
+    let vm = Box::new(VmExample::new(8, build_options(true)));
+    crate::tests::test_basic_proof_verification(vm);
+}
+
+#[test]
+fn vm_test_basic_proof_verification_fail() {
+    let vm = Box::new(VmExample::new(8, build_options(false)));
+    crate::tests::test_basic_proof_verification_fail(vm);
+}
+
+#[test]
+fn vm_test_public_inputs_serialization() {
+    let vm = Box::new(VmExample::new(8, build_options(false)));
+    crate::tests::test_public_inputs_serialization::<super::VmAir>(vm);
+}
+
+#[test]
+fn vm_program_execution() {
+    let program = sum_of_squares_program();
+    let mut inputs = [BaseElement::ZERO; NUM_REGISTERS];
+    inputs[0] = BaseElement::new(10);
+    let execution = program.execute(inputs, MAX_STEPS);
+
+    // 0^2 + 1^2 + ... + 9^2 = 285
+    assert_eq!(BaseElement::new(285), execution.outputs()[0]);
+    assert_eq!(
+        Instruction::Halt,
+        program.get(execution.states.last().unwrap().pc)
+    );
+
+    // 10 stores followed by 10 loads
+    let accesses = &execution.memory_accesses;
+    assert_eq!(20, accesses.len());
+    assert!(accesses[..10].iter().all(|access| access.is_write));
+    assert!(accesses[10..].iter().all(|access| !access.is_write));
+    assert_eq!(BaseElement::new(81), accesses[10].value);
+}
+
+#[test]
+fn vm_program_serialization() {
+    let program = sum_of_squares_program();
+    let bytes = program.to_bytes();
+    assert_eq!(
+        program,
+        Program::read_from(&mut SliceReader::new(&bytes)).unwrap()
+    );
+
+    // jumps outside of the program are rejected
+    let program = Program::new(vec![Instruction::Jmp(1), Instruction::Halt]);
+    let mut bytes = program.to_bytes();
+    bytes[6] = 2;
+    assert!(Program::read_from(&mut SliceReader::new(&bytes)).is_err());
+}
+
+#[test]
+#[should_panic(expected = "instruction 0 refers to a register which does not exist")]
+fn vm_invalid_register() {
+    Program::new(vec![
+        Instruction::Add(NUM_REGISTERS, 0, 0),
+        Instruction::Halt,
+    ]);
+}
+
+#[test]
+fn vm_honest_trace_satisfies_aux_constraints() {
+    let program = sum_of_squares_program();
+    let mut inputs = [BaseElement::ZERO; NUM_REGISTERS];
+    inputs[0] = BaseElement::new(3);
+    let trace = build_trace(&program, inputs);
+    assert!(find_failing_aux_columns(&program, trace).is_empty());
+}
+
+#[test]
+fn vm_load_of_unstored_value_fails_memory_permutation() {
+    use Instruction::*;
+    let program = Program::new(vec![
+        Set(0, BaseElement::new(5)), // 0: r0 = 5 (address)
+        Set(1, BaseElement::new(7)), // 1: r1 = 7
+        Store(0, 1),                 // 2: mem[r0] = r1
+        Load(2, 0),                  // 3: r2 = mem[r0]
+        Halt,                        // 4
+    ]);
+    let mut columns = get_columns(&build_trace(&program, [BaseElement::ZERO; NUM_REGISTERS]));
+
+    // the load at step 3 returns 8 instead of 7; sorted memory accesses remain unchanged
+    let value = BaseElement::new(8);
+    columns[RES][3] = value;
+    columns[MEM_VALUE][3] = value;
+    for step in 4..columns[0].len() {
+        columns[REGS + 2][step] = value;
+    }
+
+    let trace = VmTrace::new(columns, program.to_table(8));
+    assert_eq!(vec![MEM_PERM], find_failing_aux_columns(&program, trace));
+}
+
+#[test]
+fn vm_execution_of_other_program_fails_program_lookup() {
+    use Instruction::*;
+    let program = Program::new(vec![
+        Set(0, BaseElement::new(5)), // 0: r0 = 5 (address)
+        Set(1, BaseElement::new(7)), // 1: r1 = 7
+        Store(0, 1),                 // 2: mem[r0] = r1
+        Load(2, 0),                  // 3: r2 = mem[r0]
+        Halt,                        // 4
+    ]);
+
+    // execute a program which differs from the public program only in the immediate value of
+    // instruction 1; the trace is checked against the public program
+    let mut other_instructions = program.instructions().to_vec();
+    other_instructions[1] = Set(1, BaseElement::new(8));
+    let other_program = Program::new(other_instructions);
+    let columns = get_columns(&build_trace(
+        &other_program,
+        [BaseElement::ZERO; NUM_REGISTERS],
+    ));
+
+    let trace = VmTrace::new(columns, program.to_table(8));
+    assert_eq!(vec![PROG_SUM], find_failing_aux_columns(&program, trace));
+}
+
+#[test]
+fn vm_unsorted_memory_accesses_fail_range_check() {
+    use Instruction::*;
+    let program = Program::new(vec![
+        Set(0, BaseElement::new(1)), // 0: r0 = 1
+        Set(1, BaseElement::new(2)), // 1: r1 = 2
+        Store(0, 1),                 // 2: mem[1] = 2
+        Store(1, 0),                 // 3: mem[2] = 1
+        Halt,                        // 4
+    ]);
+    let mut columns = get_columns(&build_trace(&program, [BaseElement::ZERO; NUM_REGISTERS]));
+
+    // the two sorted accesses occupy the last two rows of the trace; swapping them keeps the
+    // accesses a permutation of the executed accesses, but the delta between their addresses
+    // becomes 1 - 2 - 1 = -2, which is not a valid clock cycle
+    for column in [SORTED_CLK, SORTED_ADDR, SORTED_VALUE, SORTED_WRITE] {
+        columns[column].swap(6, 7);
+    }
+    columns[SORTED_DELTA][7] = -BaseElement::new(2);
+    columns[RANGE_MULT][0] -= BaseElement::ONE;
+
+    let trace = VmTrace::new(columns, program.to_table(8));
+    assert_eq!(vec![RANGE_SUM], find_failing_aux_columns(&program, trace));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_options(use_extension_field: bool) -> ProofOptions {
+    let extension = if use_extension_field {
+        FieldExtension::Quadratic
+    } else {
+        FieldExtension::None
+    };
+    ProofOptions::new(28, 8, 0, HashFunction::Blake3_256, extension, 4, 256)
+}
+
+fn build_trace(program: &Program, inputs: [BaseElement; NUM_REGISTERS]) -> VmTrace {
+    VmProver::new(program.clone(), build_options(false)).build_trace(inputs)
+}
+
+fn get_columns(trace: &VmTrace) -> Vec<Vec<BaseElement>> {
+    (0..TRACE_WIDTH)
+        .map(|column| trace.main_segment().get_column(column).to_vec())
+        .collect()
+}
+
+/// Evaluates constraints of the VM AIR for the specified program against the specified trace,
+/// and returns indexes of auxiliary columns for which a transition constraint or an assertion
+/// does not hold.
+///
+/// The auxiliary segment is built from the main segment as an honest prover would build it. All
+/// constraints of the main segment must hold, so that tampering with the trace can be detected
+/// only by constraints of the auxiliary segment.
+fn find_failing_aux_columns(program: &Program, mut trace: VmTrace) -> Vec<usize> {
+    let trace_length = trace.length();
+    let prover = VmProver::new(program.clone(), build_options(false));
+    let pub_inputs = prover.get_pub_inputs(&trace);
+    let air = VmAir::new(trace.get_info(), pub_inputs, build_options(false));
+
+    let mut frame = EvaluationFrame::new(TRACE_WIDTH);
+    let mut result = vec![BaseElement::ZERO; air.context().num_main_transition_constraints()];
+    for step in 0..trace_length - 1 {
+        trace.read_main_frame(step, &mut frame);
+        air.evaluate_transition(&frame, &[], &mut result);
+        assert!(
+            result.iter().all(|&value| value == BaseElement::ZERO),
+            "main transition constraint does not hold at step {}",
+            step
+        );
+    }
+    for assertion in air.get_assertions() {
+        assertion.apply(trace_length, |step, value| {
+            assert_eq!(value, trace.get(assertion.column(), step));
+        });
+    }
+
+    // random elements are fixed here; an honest trace satisfies the constraints for any of them
+    let rand_elements = (0..NUM_RAND_ELEMENTS)
+        .map(|i| BaseElement::new(0x9e37_79b9_7f4a_7c15).exp(i as u128 + 3))
+        .collect::<Vec<_>>();
+    let aux_segment = trace.build_aux_segment(&[], &rand_elements).unwrap();
+    let mut aux_rand_elements = AuxTraceRandElements::new();
+    aux_rand_elements.add_segment_elements(rand_elements);
+
+    let (words, imms) = program.to_table(trace_length);
+    let mut aux_current = vec![BaseElement::ZERO; aux_segment.num_cols()];
+    let mut aux_next = vec![BaseElement::ZERO; aux_segment.num_cols()];
+    let mut failing_columns = Vec::new();
+    let mut result = vec![BaseElement::ZERO; air.context().num_aux_transition_constraints()];
+    for step in 0..trace_length - 1 {
+        trace.read_main_frame(step, &mut frame);
+        aux_segment.read_row_into(step, &mut aux_current);
+        aux_segment.read_row_into(step + 1, &mut aux_next);
+        let aux_frame = EvaluationFrame::from_rows(aux_current.clone(), aux_next.clone());
+        air.evaluate_aux_transition(
+            &frame,
+            &aux_frame,
+            &[words[step], imms[step]],
+            &aux_rand_elements,
+            &mut result,
+        );
+        for (column, &value) in result.iter().enumerate() {
+            if value != BaseElement::ZERO {
+                failing_columns.push(column);
+            }
+        }
+    }
+    for assertion in air.get_aux_assertions(&aux_rand_elements) {
+        assertion.apply(trace_length, |step, value| {
+            if aux_segment.get(assertion.column(), step) != value {
+                failing_columns.push(assertion.column());
+            }
+        });
+    }
+
+    failing_columns.sort_unstable();
+    failing_columns.dedup();
+    failing_columns
+}
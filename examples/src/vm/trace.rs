@@ -0,0 +1,182 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{
+    air::{decode_word, memory_access_fingerprint},
+    BaseElement, FieldElement, Instruction, AUX_WIDTH, CLK, FLAGS, IMM, MEM_PERM, MEM_VALUE,
+    NUM_RAND_ELEMENTS, OP_A, PC, PROG_MULT, PROG_SUM, RANGE_MULT, RANGE_SUM, SORTED_ACTIVE,
+    SORTED_ADDR, SORTED_CLK, SORTED_DELTA, SORTED_VALUE, SORTED_WRITE, TRACE_WIDTH,
+};
+use winterfell::{math::batch_inversion, EvaluationFrame, Matrix, Trace, TraceLayout};
+
+// VM TRACE
+// ================================================================================================
+
+/// Execution trace of the VM; in addition to the main trace segment, the trace holds the program
+/// table needed to build the auxiliary segment.
+pub struct VmTrace {
+    layout: TraceLayout,
+    trace: Matrix<BaseElement>,
+    program_table: (Vec<BaseElement>, Vec<BaseElement>),
+    meta: Vec<u8>,
+}
+
+impl VmTrace {
+    /// Creates a new trace from the specified main trace columns and the program table; the
+    /// program table must have the same length as the columns.
+    pub fn new(
+        columns: Vec<Vec<BaseElement>>,
+        program_table: (Vec<BaseElement>, Vec<BaseElement>),
+    ) -> Self {
+        assert_eq!(TRACE_WIDTH, columns.len());
+        assert_eq!(columns[0].len(), program_table.0.len());
+        Self {
+            layout: TraceLayout::new(TRACE_WIDTH, [AUX_WIDTH], [NUM_RAND_ELEMENTS]),
+            trace: Matrix::new(columns),
+            program_table,
+            meta: vec![],
+        }
+    }
+
+    /// Returns the number of columns in the main trace segment.
+    pub fn width(&self) -> usize {
+        self.main_trace_width()
+    }
+
+    /// Returns the value in the specified column of the main trace segment at the specified step.
+    pub fn get(&self, column: usize, step: usize) -> BaseElement {
+        self.trace.get(column, step)
+    }
+}
+
+// TRACE TRAIT IMPLEMENTATION
+// ================================================================================================
+
+impl Trace for VmTrace {
+    type BaseField = BaseElement;
+
+    fn layout(&self) -> &TraceLayout {
+        &self.layout
+    }
+
+    fn length(&self) -> usize {
+        self.trace.num_rows()
+    }
+
+    fn meta(&self) -> &[u8] {
+        &self.meta
+    }
+
+    fn read_main_frame(&self, row_idx: usize, frame: &mut EvaluationFrame<Self::BaseField>) {
+        let next_row_idx = (row_idx + 1) % self.length();
+        self.trace.read_row_into(row_idx, frame.current_mut());
+        self.trace.read_row_into(next_row_idx, frame.next_mut());
+    }
+
+    fn main_segment(&self) -> &Matrix<BaseElement> {
+        &self.trace
+    }
+
+    fn main_segment_mut(&mut self) -> Option<&mut Matrix<BaseElement>> {
+        Some(&mut self.trace)
+    }
+
+    fn build_aux_segment<E>(
+        &mut self,
+        aux_segments: &[Matrix<E>],
+        rand_elements: &[E],
+    ) -> Option<Matrix<E>>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        // there is only one auxiliary segment
+        if !aux_segments.is_empty() {
+            return None;
+        }
+
+        let n = self.length();
+        let mut row = vec![BaseElement::ZERO; TRACE_WIDTH];
+        let mut access_factors = Vec::with_capacity(n);
+        let mut sorted_factors = Vec::with_capacity(n);
+        let mut fetched = Vec::with_capacity(n);
+        let mut table = Vec::with_capacity(n);
+        let mut deltas = Vec::with_capacity(n);
+        let mut clocks = Vec::with_capacity(n);
+
+        let [alpha, beta_word, beta_imm, gamma] = [
+            rand_elements[4],
+            rand_elements[5],
+            rand_elements[6],
+            rand_elements[7],
+        ];
+        for i in 0..n {
+            self.trace.read_row_into(i, &mut row);
+
+            // memory accesses made during execution; rows without accesses contribute one
+            let is_mem = row[FLAGS + Instruction::LOAD] + row[FLAGS + Instruction::STORE];
+            access_factors.push(if is_mem == BaseElement::ONE {
+                memory_access_fingerprint(
+                    &rand_elements[..4],
+                    row[CLK],
+                    row[OP_A],
+                    row[MEM_VALUE],
+                    row[FLAGS + Instruction::STORE],
+                )
+            } else {
+                E::ONE
+            });
+
+            // sorted memory accesses; inactive rows contribute one
+            sorted_factors.push(if row[SORTED_ACTIVE] == BaseElement::ONE {
+                memory_access_fingerprint(
+                    &rand_elements[..4],
+                    row[SORTED_CLK],
+                    row[SORTED_ADDR],
+                    row[SORTED_VALUE],
+                    row[SORTED_WRITE],
+                )
+            } else {
+                E::ONE
+            });
+
+            // executed instructions and program table
+            fetched.push(
+                alpha
+                    - (E::from(row[PC])
+                        + beta_word.mul_base(decode_word(&row))
+                        + beta_imm.mul_base(row[IMM])),
+            );
+            table.push(
+                alpha
+                    - (E::from(row[CLK])
+                        + beta_word.mul_base(self.program_table.0[i])
+                        + beta_imm.mul_base(self.program_table.1[i])),
+            );
+
+            // deltas of sorted memory accesses and clock cycles
+            deltas.push(gamma - E::from(row[SORTED_DELTA]));
+            clocks.push(gamma - E::from(row[CLK]));
+        }
+
+        let sorted_factors = batch_inversion(&sorted_factors);
+        let fetched = batch_inversion(&fetched);
+        let inv_table = batch_inversion(&table);
+        let deltas = batch_inversion(&deltas);
+        let clocks = batch_inversion(&clocks);
+
+        let mut aux_columns = vec![vec![E::ZERO; n]; AUX_WIDTH];
+        aux_columns[MEM_PERM][0] = E::ONE;
+        for i in 0..n - 1 {
+            aux_columns[MEM_PERM][i + 1] =
+                aux_columns[MEM_PERM][i] * access_factors[i] * sorted_factors[i + 1];
+            aux_columns[PROG_SUM][i + 1] = aux_columns[PROG_SUM][i] + fetched[i]
+                - inv_table[i].mul_base(self.trace.get(PROG_MULT, i));
+            aux_columns[RANGE_SUM][i + 1] = aux_columns[RANGE_SUM][i] + deltas[i + 1]
+                - clocks[i].mul_base(self.trace.get(RANGE_MULT, i));
+        }
+
+        Some(Matrix::new(aux_columns))
+    }
+}
@@ -10,6 +10,9 @@ use utils::{
     DeserializationError, Serializable, SliceReader,
 };
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 // FRI PROOF
 // ================================================================================================
 
@@ -29,8 +32,10 @@ use utils::{
 /// they can be returned to the user. To do this, [parse_layers()](FriProof::parse_layers())
 /// and [parse_remainder()](FriProof::parse_remainder()) methods can be used.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FriProof {
     layers: Vec<FriProofLayer>,
+    #[cfg_attr(feature = "serde", serde(with = "utils::serde_hex"))]
     remainder: Vec<u8>,
     num_partitions: u8, // stored as power of 2
 }
@@ -243,8 +248,11 @@ impl Deserializable for FriProof {
 // ================================================================================================
 
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FriProofLayer {
+    #[cfg_attr(feature = "serde", serde(with = "utils::serde_hex"))]
     values: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "utils::serde_hex"))]
     paths: Vec<u8>,
 }
 
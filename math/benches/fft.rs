@@ -13,6 +13,7 @@ use winter_math::{
 };
 
 const SIZES: [usize; 3] = [262_144, 524_288, 1_048_576];
+const NUM_POLYS: [usize; 2] = [16, 128];
 
 fn fft_evaluate_poly<B, E>(c: &mut Criterion, field_name: &str)
 where
@@ -89,6 +90,99 @@ where
     group.finish();
 }
 
+fn fft_evaluate_polys<B, E>(c: &mut Criterion, field_name: &str)
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    let mut group = c.benchmark_group(format!("{}/fft_evaluate_polys", field_name));
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+
+    let blowup_factor = 8;
+    let num_rows = 8_192;
+
+    for &num_polys in NUM_POLYS.iter() {
+        let columns: Vec<Vec<E>> = (0..num_polys).map(|_| rand_vector(num_rows)).collect();
+        let twiddles: Vec<B> = fft::get_twiddles(num_rows);
+        group.bench_function(BenchmarkId::new("per_column", num_polys), |bench| {
+            bench.iter_with_large_drop(|| {
+                columns
+                    .iter()
+                    .map(|p| {
+                        fft::evaluate_poly_with_offset(p, &twiddles, B::GENERATOR, blowup_factor)
+                    })
+                    .collect::<Vec<_>>()
+            });
+        });
+
+        let polys: Vec<E> = rand_vector(num_rows * num_polys);
+        group.bench_function(BenchmarkId::new("batched", num_polys), |bench| {
+            bench.iter_with_large_drop(|| {
+                fft::evaluate_polys_with_offset(
+                    &polys,
+                    num_polys,
+                    &twiddles,
+                    B::GENERATOR,
+                    blowup_factor,
+                )
+            });
+        });
+    }
+
+    group.finish();
+}
+
+/// Compares FFT performance in the 64-bit field with and without SIMD arithmetic; the SIMD
+/// backend is the one detected for the CPU the benchmark runs on.
+#[cfg(feature = "simd")]
+fn fft_simd(c: &mut Criterion) {
+    use winter_math::fields::f64::simd::{self, Backend};
+
+    let mut group = c.benchmark_group("f64/fft_simd");
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+
+    let blowup_factor = 8;
+    let detected_backend = simd::backend();
+
+    for (name, backend) in [("scalar", Backend::Scalar), ("simd", detected_backend)] {
+        simd::set_backend(backend);
+
+        for &size in SIZES.iter() {
+            let p: Vec<f64::BaseElement> = rand_vector(size / blowup_factor);
+            let twiddles = fft::get_twiddles(size / blowup_factor);
+            let id = BenchmarkId::new(format!("evaluate_{}", name), size);
+            group.bench_function(id, |bench| {
+                bench.iter_with_large_drop(|| {
+                    fft::evaluate_poly_with_offset(
+                        &p,
+                        &twiddles,
+                        f64::BaseElement::GENERATOR,
+                        blowup_factor,
+                    )
+                });
+            });
+        }
+
+        for &size in SIZES.iter() {
+            let p: Vec<f64::BaseElement> = rand_vector(size);
+            let inv_twiddles = fft::get_inv_twiddles(size);
+            let id = BenchmarkId::new(format!("interpolate_{}", name), size);
+            group.bench_function(id, |bench| {
+                bench.iter_batched_ref(
+                    || p.clone(),
+                    |p| fft::interpolate_poly(p, &inv_twiddles),
+                    BatchSize::LargeInput,
+                );
+            });
+        }
+    }
+
+    simd::set_backend(detected_backend);
+    group.finish();
+}
+
 fn get_twiddles(c: &mut Criterion) {
     let mut group = c.benchmark_group("fft_get_twiddles");
     group.sample_size(10);
@@ -111,10 +205,16 @@ fn bench_fft(c: &mut Criterion) {
 
     fft_evaluate_poly::<f64::BaseElement, CubeExtension<f64::BaseElement>>(c, "f64_cube");
 
+    fft_evaluate_polys::<f64::BaseElement, f64::BaseElement>(c, "f64");
+    fft_evaluate_polys::<f64::BaseElement, QuadExtension<f64::BaseElement>>(c, "f64_quad");
+
     fft_interpolate_poly::<f62::BaseElement, f62::BaseElement>(c, "f62");
     fft_interpolate_poly::<f64::BaseElement, f64::BaseElement>(c, "f64");
     fft_interpolate_poly::<f128::BaseElement, f128::BaseElement>(c, "f128");
 }
 
+#[cfg(not(feature = "simd"))]
 criterion_group!(fft_group, bench_fft, get_twiddles);
+#[cfg(feature = "simd")]
+criterion_group!(fft_group, bench_fft, get_twiddles, fft_simd);
 criterion_main!(fft_group);
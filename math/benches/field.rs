@@ -35,6 +35,14 @@ pub fn batch_inv(c: &mut Criterion) {
         });
     }
 
+    for &size in SIZES.iter() {
+        let values = rand_vector::<f64::BaseElement>(size);
+
+        group.bench_function(BenchmarkId::new("f64", size), |bench| {
+            bench.iter_with_large_drop(|| batch_inversion(&values));
+        });
+    }
+
     group.finish();
 }
 
@@ -89,6 +89,102 @@ where
         });
 }
 
+// BATCHED POLYNOMIAL EVALUATION
+// ================================================================================================
+
+/// Evaluates polynomials stored in columns of the row-major `polys` using FFT algorithm; the
+/// evaluation is done in-place, meaning `polys` are updated with results of the evaluation.
+pub fn evaluate_polys<B, E>(polys: &mut [E], row_width: usize, twiddles: &[B])
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    split_radix_fft_rows(polys, row_width, twiddles);
+    permute_rows(polys, row_width);
+}
+
+/// Evaluates polynomials stored in columns of the row-major `polys` using FFT algorithm and
+/// returns the results as a row-major matrix. The polynomials are evaluated over domain specified
+/// by `twiddles`, expanded by the `blowup_factor`, and shifted by the `domain_offset`.
+pub fn evaluate_polys_with_offset<B, E>(
+    polys: &[E],
+    row_width: usize,
+    twiddles: &[B],
+    domain_offset: B,
+    blowup_factor: usize,
+) -> Vec<E>
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    let domain_size = polys.len() / row_width * blowup_factor;
+    let g = B::get_root_of_unity(log2(domain_size));
+    let mut result = unsafe { uninit_vector(domain_size * row_width) };
+
+    result
+        .as_mut_slice()
+        .par_chunks_mut(polys.len())
+        .enumerate()
+        .for_each(|(i, chunk)| {
+            let idx = super::permute_index(blowup_factor, i) as u64;
+            let offset = g.exp(idx.into()) * domain_offset;
+            clone_and_shift_rows(polys, chunk, row_width, offset);
+            split_radix_fft_rows(chunk, row_width, twiddles);
+        });
+
+    permute_rows(&mut result, row_width);
+    result
+}
+
+// BATCHED POLYNOMIAL INTERPOLATION
+// ================================================================================================
+
+/// Uses FFT algorithm to interpolate polynomials from columns of the row-major `values`; the
+/// interpolation is done in-place, meaning `values` are updated with polynomial coefficients.
+pub fn interpolate_polys<B, E>(values: &mut [E], row_width: usize, inv_twiddles: &[B])
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    split_radix_fft_rows(values, row_width, inv_twiddles);
+    let inv_length = E::inv(((values.len() / row_width) as u64).into());
+    values.par_iter_mut().for_each(|e| *e *= inv_length);
+    permute_rows(values, row_width);
+}
+
+/// Uses FFT algorithm to interpolate polynomials from columns of the row-major `values` over the
+/// domain defined by `inv_twiddles` and offset by `domain_offset` factor.
+pub fn interpolate_polys_with_offset<B, E>(
+    values: &mut [E],
+    row_width: usize,
+    inv_twiddles: &[B],
+    domain_offset: B,
+) where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    split_radix_fft_rows(values, row_width, inv_twiddles);
+    permute_rows(values, row_width);
+
+    let domain_offset = domain_offset.inv();
+    let num_rows = values.len() / row_width;
+    let inv_len = B::inv((num_rows as u64).into());
+    let batch_size = num_rows / rayon::current_num_threads().next_power_of_two();
+
+    values
+        .par_chunks_mut(batch_size * row_width)
+        .enumerate()
+        .for_each(|(i, batch)| {
+            let mut offset = domain_offset.exp(((i * batch_size) as u64).into()) * inv_len;
+            for row in batch.chunks_mut(row_width) {
+                for coeff in row.iter_mut() {
+                    *coeff = coeff.mul_base(offset);
+                }
+                offset *= domain_offset;
+            }
+        });
+}
+
 // PERMUTATIONS
 // ================================================================================================
 
@@ -115,6 +211,31 @@ pub fn permute<E: FieldElement>(v: &mut [E]) {
     });
 }
 
+/// Permutes rows of the row-major `v` such that row `i` is swapped with row at the bit-reversed
+/// index of `i`.
+pub fn permute_rows<E: FieldElement>(v: &mut [E], row_width: usize) {
+    let n = v.len() / row_width;
+    let num_batches = rayon::current_num_threads().next_power_of_two();
+    let batch_size = n / num_batches;
+    rayon::scope(|s| {
+        for batch_idx in 0..num_batches {
+            // create another mutable reference to the slice of values to use in a new thread; this
+            // is OK because we never write the same rows in the slice from different threads
+            let values = unsafe { &mut *(&mut v[..] as *mut [E]) };
+            s.spawn(move |_| {
+                let batch_start = batch_idx * batch_size;
+                let batch_end = batch_start + batch_size;
+                for i in batch_start..batch_end {
+                    let j = super::permute_index(n, i);
+                    if j > i {
+                        super::serial::swap_rows(values, row_width, i, j);
+                    }
+                }
+            });
+        }
+    });
+}
+
 // SPLIT-RADIX FFT
 // ================================================================================================
 
@@ -164,20 +285,91 @@ pub(super) fn split_radix_fft<B: StarkField, E: FieldElement<BaseField = B>>(
         });
 }
 
+/// In-place recursive FFT with permuted output applied to all columns of the row-major `values`.
+///
+/// This is the same algorithm as [split_radix_fft()] where every element is a row of `row_width`
+/// values.
+pub(super) fn split_radix_fft_rows<B: StarkField, E: FieldElement<BaseField = B>>(
+    values: &mut [E],
+    row_width: usize,
+    twiddles: &[B],
+) {
+    // generator of the domain should be in the middle of twiddles
+    let n = values.len() / row_width;
+    let g = twiddles[twiddles.len() / 2];
+    debug_assert_eq!(g.exp((n as u32).into()), E::BaseField::ONE);
+
+    let inner_len = 1_usize << (log2(n) / 2);
+    let outer_len = n / inner_len;
+    let stretch = outer_len / inner_len;
+    debug_assert!(outer_len == inner_len || outer_len == 2 * inner_len);
+    debug_assert_eq!(outer_len * inner_len, n);
+
+    // transpose inner x inner x stretch square matrix of rows
+    transpose_square_stretch_rows(values, row_width, inner_len, stretch);
+
+    // apply inner FFTs
+    values
+        .par_chunks_mut(outer_len * row_width)
+        .for_each(|block| {
+            super::serial::fft_in_place_rows(block, twiddles, row_width, stretch, stretch, 0)
+        });
+
+    // transpose inner x inner x stretch square matrix of rows
+    transpose_square_stretch_rows(values, row_width, inner_len, stretch);
+
+    // apply outer FFTs
+    values
+        .par_chunks_mut(outer_len * row_width)
+        .enumerate()
+        .for_each(|(i, block)| {
+            if i > 0 {
+                let i = super::permute_index(inner_len, i);
+                let inner_twiddle = g.exp((i as u32).into());
+                let mut outer_twiddle = inner_twiddle;
+                for row in block.chunks_mut(row_width).skip(1) {
+                    for element in row.iter_mut() {
+                        *element = element.mul_base(outer_twiddle);
+                    }
+                    outer_twiddle *= inner_twiddle;
+                }
+            }
+            super::serial::fft_in_place_rows(block, twiddles, row_width, 1, 1, 0)
+        });
+}
+
 // TRANSPOSING
 // ================================================================================================
 
 fn transpose_square_stretch<T>(matrix: &mut [T], size: usize, stretch: usize) {
     assert_eq!(matrix.len(), size * size * stretch);
     match stretch {
-        1 => transpose_square_1(matrix, size),
-        2 => transpose_square_2(matrix, size),
+        1 => transpose_square_1(size, |i, j| matrix.swap(i, j)),
+        2 => transpose_square_2(size, |i, j| matrix.swap(i, j)),
         _ => unimplemented!("only stretch sizes 1 and 2 are supported"),
     }
 }
 
-fn transpose_square_1<T>(matrix: &mut [T], size: usize) {
-    debug_assert_eq!(matrix.len(), size * size);
+/// Same as [transpose_square_stretch()] but every element of the matrix is a row of `row_width`
+/// values.
+fn transpose_square_stretch_rows<T>(
+    matrix: &mut [T],
+    row_width: usize,
+    size: usize,
+    stretch: usize,
+) {
+    assert_eq!(matrix.len(), size * size * stretch * row_width);
+    let swap = |i, j| super::serial::swap_rows(matrix, row_width, i, j);
+    match stretch {
+        1 => transpose_square_1(size, swap),
+        2 => transpose_square_2(size, swap),
+        _ => unimplemented!("only stretch sizes 1 and 2 are supported"),
+    }
+}
+
+/// Transposes a square matrix of the specified size; `swap` is expected to swap matrix elements
+/// at the provided positions.
+fn transpose_square_1<F: FnMut(usize, usize)>(size: usize, mut swap: F) {
     if size % 2 != 0 {
         unimplemented!("odd sizes are not supported");
     }
@@ -185,28 +377,28 @@ fn transpose_square_1<T>(matrix: &mut [T], size: usize) {
     // iterate over upper-left triangle, working in 2x2 blocks
     for row in (0..size).step_by(2) {
         let i = row * size + row;
-        matrix.swap(i + 1, i + size);
+        swap(i + 1, i + size);
         for col in (row..size).step_by(2).skip(1) {
             let i = row * size + col;
             let j = col * size + row;
-            matrix.swap(i, j);
-            matrix.swap(i + 1, j + size);
-            matrix.swap(i + size, j + 1);
-            matrix.swap(i + size + 1, j + size + 1);
+            swap(i, j);
+            swap(i + 1, j + size);
+            swap(i + size, j + 1);
+            swap(i + size + 1, j + size + 1);
         }
     }
 }
 
-fn transpose_square_2<T>(matrix: &mut [T], size: usize) {
-    debug_assert_eq!(matrix.len(), 2 * size * size);
-
+/// Transposes a square matrix of the specified size where every element consists of two values;
+/// `swap` is expected to swap matrix values at the provided positions.
+fn transpose_square_2<F: FnMut(usize, usize)>(size: usize, mut swap: F) {
     // iterate over upper-left triangle, working in 1x2 blocks
     for row in 0..size {
         for col in (row..size).skip(1) {
             let i = (row * size + col) * 2;
             let j = (col * size + row) * 2;
-            matrix.swap(i, j);
-            matrix.swap(i + 1, j + 1);
+            swap(i, j);
+            swap(i + 1, j + 1);
         }
     }
 }
@@ -228,3 +420,21 @@ fn clone_and_shift<E: FieldElement>(source: &[E], destination: &mut [E], offset:
             }
         });
 }
+
+fn clone_and_shift_rows<E: FieldElement>(
+    source: &[E],
+    destination: &mut [E],
+    row_width: usize,
+    offset: E::BaseField,
+) {
+    let num_rows = source.len() / row_width;
+    let batch_size = num_rows / rayon::current_num_threads().next_power_of_two();
+    source
+        .par_chunks(batch_size * row_width)
+        .zip(destination.par_chunks_mut(batch_size * row_width))
+        .enumerate()
+        .for_each(|(i, (source, destination))| {
+            let factor = offset.exp(((i * batch_size) as u64).into());
+            super::serial::shift_rows(source, destination, row_width, factor, offset);
+        });
+}
@@ -394,6 +394,371 @@ pub fn interpolate_poly_with_offset<B, E>(
     }
 }
 
+// BATCHED POLYNOMIAL EVALUATION
+// ================================================================================================
+
+/// Evaluates a set of polynomials on all points of the specified domain using the FFT algorithm.
+///
+/// The polynomials are expected to be in coefficient form and to be arranged as columns of a
+/// row-major matrix `polys` with `num_polys` columns; that is, the coefficient `i` of polynomial
+/// `j` is located at `polys[i * num_polys + j]`. All polynomials are evaluated at once sharing
+/// the same `twiddles`, and every FFT butterfly is applied to entire rows of the matrix. This is
+/// significantly faster than evaluating each polynomial separately when the number of
+/// polynomials is large. The evaluation is done in-place, and the results are written back into
+/// `polys` using the same layout.
+///
+/// The size of the domain is assumed to be equal to the number of rows in `polys` (i.e.,
+/// `polys.len()` / `num_polys`) which must be a power of two. The `twiddles` needed for
+/// evaluation can be obtained via `fft::get_twiddles()` function using the number of rows as the
+/// domain size parameter.
+///
+/// When `concurrent` feature is enabled, the evaluation is done in multiple threads.
+///
+/// # Panics
+/// Panics if:
+/// * `num_polys` is zero or `polys.len()` is not divisible by `num_polys`.
+/// * Number of rows in `polys` is not a power of two.
+/// * Length of `twiddles` is not half the number of rows in `polys`.
+/// * Field specified by `B` does not contain a multiplicative subgroup of size equal to the
+///   number of rows in `polys`.
+///
+/// # Examples
+/// ```
+/// # use winter_math::fft::*;
+/// # use winter_math::{fields::{f128::BaseElement}, FieldElement, StarkField};
+/// # use rand_utils::rand_vector;
+/// let n = 1024;
+/// let num_polys = 3;
+///
+/// // build a set of random polynomials arranged as columns of a row-major matrix
+/// let mut polys: Vec<BaseElement> = rand_vector(n * num_polys);
+///
+/// // evaluate each polynomial separately
+/// let twiddles = get_twiddles::<BaseElement>(n);
+/// let mut expected = polys.clone();
+/// for j in 0..num_polys {
+///     let mut p = (0..n).map(|i| polys[i * num_polys + j]).collect::<Vec<_>>();
+///     evaluate_poly(&mut p, &twiddles);
+///     for (i, value) in p.into_iter().enumerate() {
+///         expected[i * num_polys + j] = value;
+///     }
+/// }
+///
+/// // evaluate all polynomials at once
+/// evaluate_polys(&mut polys, num_polys, &twiddles);
+///
+/// assert_eq!(expected, polys);
+/// ```
+pub fn evaluate_polys<B, E>(polys: &mut [E], num_polys: usize, twiddles: &[B])
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    let num_rows = get_num_rows(polys, num_polys);
+    assert!(
+        num_rows.is_power_of_two(),
+        "number of coefficients must be a power of 2"
+    );
+    assert_eq!(
+        num_rows,
+        twiddles.len() * 2,
+        "invalid number of twiddles: expected {} but received {}",
+        num_rows / 2,
+        twiddles.len()
+    );
+    assert!(
+        log2(num_rows) <= B::TWO_ADICITY,
+        "multiplicative subgroup of size {} does not exist in the specified base field",
+        num_rows
+    );
+
+    // when `concurrent` feature is enabled, run the concurrent version of the function; unless
+    // the polynomials are small, then don't bother with the concurrent version
+    if cfg!(feature = "concurrent") && num_rows >= MIN_CONCURRENT_SIZE {
+        #[cfg(feature = "concurrent")]
+        concurrent::evaluate_polys(polys, num_polys, twiddles);
+    } else {
+        serial::evaluate_polys(polys, num_polys, twiddles);
+    }
+}
+
+/// Evaluates a set of polynomials on all points of the specified (shifted) domain using the FFT
+/// algorithm.
+///
+/// The polynomials are expected to be in coefficient form and to be arranged as columns of a
+/// row-major matrix `polys` with `num_polys` columns (see [evaluate_polys()]). Each polynomial is
+/// evaluated over a domain defined by the number of rows in `polys`, expanded by the
+/// `blowup_factor`, and shifted by the `domain_offset`. The results are returned as a row-major
+/// matrix with `num_polys` columns; that is, the evaluation of polynomial `j` at domain point `i`
+/// is located at index `i * num_polys + j` of the returned vector.
+///
+/// The `twiddles` needed for evaluation can be obtained via `fft::get_twiddles()` function using
+/// the number of rows in `polys` as the domain size parameter.
+///
+/// When `concurrent` feature is enabled, the evaluation is done in multiple threads.
+///
+/// # Panics
+/// Panics if:
+/// * `num_polys` is zero or `polys.len()` is not divisible by `num_polys`.
+/// * Number of rows in `polys` is not a power of two.
+/// * `blowup_factor` is not a power of two.
+/// * Length of `twiddles` is not half the number of rows in `polys`.
+/// * Field specified by `B` does not contain a multiplicative subgroup of size equal to the
+///   number of rows in `polys` times `blowup_factor`.
+/// * `domain_offset` is ZERO.
+///
+/// # Examples
+/// ```
+/// # use winter_math::fft::*;
+/// # use winter_math::{fields::{f128::BaseElement}, FieldElement, StarkField};
+/// # use rand_utils::rand_vector;
+/// let n = 1024;
+/// let num_polys = 3;
+/// let offset = BaseElement::GENERATOR;
+/// let blowup_factor = 4;
+///
+/// // build a set of random polynomials arranged as columns of a row-major matrix
+/// let polys: Vec<BaseElement> = rand_vector(n * num_polys);
+///
+/// // evaluate all polynomials at once
+/// let twiddles = get_twiddles::<BaseElement>(n);
+/// let actual = evaluate_polys_with_offset(&polys, num_polys, &twiddles, offset, blowup_factor);
+///
+/// // evaluate each polynomial separately and compare
+/// for j in 0..num_polys {
+///     let p = (0..n).map(|i| polys[i * num_polys + j]).collect::<Vec<_>>();
+///     let expected = evaluate_poly_with_offset(&p, &twiddles, offset, blowup_factor);
+///     for (i, &value) in expected.iter().enumerate() {
+///         assert_eq!(value, actual[i * num_polys + j]);
+///     }
+/// }
+/// ```
+pub fn evaluate_polys_with_offset<B, E>(
+    polys: &[E],
+    num_polys: usize,
+    twiddles: &[B],
+    domain_offset: B,
+    blowup_factor: usize,
+) -> Vec<E>
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    let num_rows = get_num_rows(polys, num_polys);
+    assert!(
+        num_rows.is_power_of_two(),
+        "number of coefficients must be a power of 2"
+    );
+    assert!(
+        blowup_factor.is_power_of_two(),
+        "blowup factor must be a power of 2"
+    );
+    assert_eq!(
+        num_rows,
+        twiddles.len() * 2,
+        "invalid number of twiddles: expected {} but received {}",
+        num_rows / 2,
+        twiddles.len()
+    );
+    assert!(
+        log2(num_rows * blowup_factor) <= B::TWO_ADICITY,
+        "multiplicative subgroup of size {} does not exist in the specified base field",
+        num_rows * blowup_factor
+    );
+    assert_ne!(domain_offset, B::ZERO, "domain offset cannot be zero");
+
+    // assign a dummy value here to make the compiler happy
+    #[allow(unused_assignments)]
+    let mut result = Vec::new();
+
+    // when `concurrent` feature is enabled, run the concurrent version of the function; unless
+    // the polynomials are small, then don't bother with the concurrent version
+    if cfg!(feature = "concurrent") && num_rows >= MIN_CONCURRENT_SIZE {
+        #[cfg(feature = "concurrent")]
+        {
+            result = concurrent::evaluate_polys_with_offset(
+                polys,
+                num_polys,
+                twiddles,
+                domain_offset,
+                blowup_factor,
+            );
+        }
+    } else {
+        result = serial::evaluate_polys_with_offset(
+            polys,
+            num_polys,
+            twiddles,
+            domain_offset,
+            blowup_factor,
+        );
+    }
+
+    result
+}
+
+// BATCHED POLYNOMIAL INTERPOLATION
+// ================================================================================================
+
+/// Interpolates evaluations of a set of polynomials over the specified domain into polynomials
+/// in coefficient form using the FFT algorithm.
+///
+/// The evaluations are expected to be arranged as columns of a row-major matrix `evaluations`
+/// with `num_polys` columns (see [evaluate_polys()]). All columns are interpolated at once
+/// sharing the same `inv_twiddles`. The interpolation is done in-place, and the coefficients are
+/// written back into `evaluations` using the same layout.
+///
+/// The `inv_twiddles` needed for interpolation can be obtained via `fft::get_inv_twiddles()`
+/// function using the number of rows in `evaluations` as the domain size parameter.
+///
+/// When `concurrent` feature is enabled, the interpolation is done in multiple threads.
+///
+/// # Panics
+/// Panics if:
+/// * `num_polys` is zero or `evaluations.len()` is not divisible by `num_polys`.
+/// * Number of rows in `evaluations` is not a power of two.
+/// * Length of `inv_twiddles` is not half the number of rows in `evaluations`.
+/// * Field specified by `B` does not contain a multiplicative subgroup of size equal to the
+///   number of rows in `evaluations`.
+///
+/// # Examples
+/// ```
+/// # use winter_math::fft::*;
+/// # use winter_math::{fields::{f128::BaseElement}, FieldElement, StarkField};
+/// # use rand_utils::rand_vector;
+/// let n = 1024;
+/// let num_polys = 3;
+///
+/// // build a set of random polynomials arranged as columns of a row-major matrix
+/// let polys: Vec<BaseElement> = rand_vector(n * num_polys);
+///
+/// // evaluate and then interpolate the polynomials
+/// let mut values = polys.clone();
+/// evaluate_polys(&mut values, num_polys, &get_twiddles::<BaseElement>(n));
+/// interpolate_polys(&mut values, num_polys, &get_inv_twiddles::<BaseElement>(n));
+///
+/// assert_eq!(polys, values);
+/// ```
+pub fn interpolate_polys<B, E>(evaluations: &mut [E], num_polys: usize, inv_twiddles: &[B])
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    let num_rows = get_num_rows(evaluations, num_polys);
+    assert!(
+        num_rows.is_power_of_two(),
+        "number of evaluations must be a power of 2, but was {}",
+        num_rows
+    );
+    assert_eq!(
+        num_rows,
+        inv_twiddles.len() * 2,
+        "invalid number of twiddles: expected {} but received {}",
+        num_rows / 2,
+        inv_twiddles.len()
+    );
+    assert!(
+        log2(num_rows) <= B::TWO_ADICITY,
+        "multiplicative subgroup of size {} does not exist in the specified base field",
+        num_rows
+    );
+
+    // when `concurrent` feature is enabled, run the concurrent version of the function; unless
+    // the number of evaluations is small, then don't bother with the concurrent version
+    if cfg!(feature = "concurrent") && num_rows >= MIN_CONCURRENT_SIZE {
+        #[cfg(feature = "concurrent")]
+        concurrent::interpolate_polys(evaluations, num_polys, inv_twiddles);
+    } else {
+        serial::interpolate_polys(evaluations, num_polys, inv_twiddles);
+    }
+}
+
+/// Interpolates evaluations of a set of polynomials over the specified (shifted) domain into
+/// polynomials in coefficient form using the FFT algorithm.
+///
+/// The evaluations are expected to be arranged as columns of a row-major matrix `evaluations`
+/// with `num_polys` columns (see [evaluate_polys()]). The domain is defined by the number of rows
+/// in `evaluations` and shifted by the `domain_offset`. The interpolation is done in-place, and
+/// the coefficients are written back into `evaluations` using the same layout.
+///
+/// The `inv_twiddles` needed for interpolation can be obtained via `fft::get_inv_twiddles()`
+/// function using the number of rows in `evaluations` as the domain size parameter.
+///
+/// When `concurrent` feature is enabled, the interpolation is done in multiple threads.
+///
+/// # Panics
+/// Panics if:
+/// * `num_polys` is zero or `evaluations.len()` is not divisible by `num_polys`.
+/// * Number of rows in `evaluations` is not a power of two.
+/// * Length of `inv_twiddles` is not half the number of rows in `evaluations`.
+/// * Field specified by `B` does not contain a multiplicative subgroup of size equal to the
+///   number of rows in `evaluations`.
+/// * `domain_offset` is ZERO.
+///
+/// # Examples
+/// ```
+/// # use winter_math::fft::*;
+/// # use winter_math::{fields::{f128::BaseElement}, FieldElement, StarkField};
+/// # use rand_utils::rand_vector;
+/// let n = 1024;
+/// let num_polys = 3;
+/// let offset = BaseElement::GENERATOR;
+///
+/// // build a set of random polynomials arranged as columns of a row-major matrix
+/// let polys: Vec<BaseElement> = rand_vector(n * num_polys);
+///
+/// // evaluate the polynomials over the shifted domain and then interpolate them back
+/// let twiddles = get_twiddles::<BaseElement>(n);
+/// let mut values = evaluate_polys_with_offset(&polys, num_polys, &twiddles, offset, 1);
+/// let inv_twiddles = get_inv_twiddles::<BaseElement>(n);
+/// interpolate_polys_with_offset(&mut values, num_polys, &inv_twiddles, offset);
+///
+/// assert_eq!(polys, values);
+/// ```
+pub fn interpolate_polys_with_offset<B, E>(
+    evaluations: &mut [E],
+    num_polys: usize,
+    inv_twiddles: &[B],
+    domain_offset: B,
+) where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    let num_rows = get_num_rows(evaluations, num_polys);
+    assert!(
+        num_rows.is_power_of_two(),
+        "number of evaluations must be a power of 2, but was {}",
+        num_rows
+    );
+    assert_eq!(
+        num_rows,
+        inv_twiddles.len() * 2,
+        "invalid number of twiddles: expected {} but received {}",
+        num_rows / 2,
+        inv_twiddles.len()
+    );
+    assert!(
+        log2(num_rows) <= B::TWO_ADICITY,
+        "multiplicative subgroup of size {} does not exist in the specified base field",
+        num_rows
+    );
+    assert_ne!(domain_offset, B::ZERO, "domain offset cannot be zero");
+
+    // when `concurrent` feature is enabled, run the concurrent version of the function; unless
+    // the number of evaluations is small, then don't bother with the concurrent version
+    if cfg!(feature = "concurrent") && num_rows >= MIN_CONCURRENT_SIZE {
+        #[cfg(feature = "concurrent")]
+        concurrent::interpolate_polys_with_offset(
+            evaluations,
+            num_polys,
+            inv_twiddles,
+            domain_offset,
+        );
+    } else {
+        serial::interpolate_polys_with_offset(evaluations, num_polys, inv_twiddles, domain_offset);
+    }
+}
+
 // RAW FFT ALGORITHM
 // ================================================================================================
 
@@ -595,6 +960,20 @@ fn permute<E: FieldElement>(v: &mut [E]) {
     }
 }
 
+/// Returns the number of rows in a row-major matrix with `num_polys` columns.
+fn get_num_rows<E>(values: &[E], num_polys: usize) -> usize {
+    assert!(
+        num_polys > 0,
+        "number of polynomials must be greater than zero"
+    );
+    assert_eq!(
+        values.len() % num_polys,
+        0,
+        "number of values must be divisible by the number of polynomials"
+    );
+    values.len() / num_polys
+}
+
 fn permute_index(size: usize, index: usize) -> usize {
     debug_assert!(index < size);
     if size == 1 {
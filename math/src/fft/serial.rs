@@ -102,6 +102,96 @@ pub fn interpolate_poly_with_offset<B, E>(
     }
 }
 
+// BATCHED POLYNOMIAL EVALUATION
+// ================================================================================================
+
+/// Evaluates polynomials stored in columns of the row-major `polys` in-place over the domain of
+/// length `polys.len() / row_width` using the FFT algorithm.
+pub fn evaluate_polys<B, E>(polys: &mut [E], row_width: usize, twiddles: &[B])
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    fft_in_place_rows(polys, twiddles, row_width, 1, 1, 0);
+    permute_rows(polys, row_width);
+}
+
+/// Evaluates polynomials stored in columns of the row-major `polys` over the domain of length
+/// `polys.len() / row_width` * `blowup_factor` shifted by `domain_offset` using the FFT algorithm,
+/// and returns the results as a row-major matrix of the same width.
+pub fn evaluate_polys_with_offset<B, E>(
+    polys: &[E],
+    row_width: usize,
+    twiddles: &[B],
+    domain_offset: B,
+    blowup_factor: usize,
+) -> Vec<E>
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    let domain_size = polys.len() / row_width * blowup_factor;
+    let g = B::get_root_of_unity(log2(domain_size));
+    let mut result = unsafe { uninit_vector(domain_size * row_width) };
+
+    result
+        .as_mut_slice()
+        .chunks_mut(polys.len())
+        .enumerate()
+        .for_each(|(i, chunk)| {
+            let idx = super::permute_index(blowup_factor, i) as u64;
+            let offset = g.exp(idx.into()) * domain_offset;
+            shift_rows(polys, chunk, row_width, B::ONE, offset);
+            fft_in_place_rows(chunk, twiddles, row_width, 1, 1, 0);
+        });
+
+    permute_rows(&mut result, row_width);
+    result
+}
+
+// BATCHED POLYNOMIAL INTERPOLATION
+// ================================================================================================
+
+/// Interpolates columns of the row-major `evaluations` over a domain of length
+/// `evaluations.len() / row_width` into polynomials in coefficient form using the FFT algorithm.
+pub fn interpolate_polys<B, E>(evaluations: &mut [E], row_width: usize, inv_twiddles: &[B])
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    fft_in_place_rows(evaluations, inv_twiddles, row_width, 1, 1, 0);
+    let inv_length = E::inv(((evaluations.len() / row_width) as u64).into());
+    for e in evaluations.iter_mut() {
+        *e *= inv_length;
+    }
+    permute_rows(evaluations, row_width);
+}
+
+/// Interpolates columns of the row-major `evaluations` over a domain of length
+/// `evaluations.len() / row_width` and shifted by `domain_offset` into polynomials in coefficient
+/// form using the FFT algorithm.
+pub fn interpolate_polys_with_offset<B, E>(
+    evaluations: &mut [E],
+    row_width: usize,
+    inv_twiddles: &[B],
+    domain_offset: B,
+) where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    fft_in_place_rows(evaluations, inv_twiddles, row_width, 1, 1, 0);
+    permute_rows(evaluations, row_width);
+
+    let domain_offset = domain_offset.inv();
+    let mut offset = B::inv(((evaluations.len() / row_width) as u64).into());
+    for row in evaluations.chunks_mut(row_width) {
+        for coeff in row.iter_mut() {
+            *coeff = coeff.mul_base(offset);
+        }
+        offset *= domain_offset;
+    }
+}
+
 // PERMUTATIONS
 // ================================================================================================
 
@@ -115,6 +205,49 @@ pub fn permute<T>(values: &mut [T]) {
     }
 }
 
+/// Permutes rows of the row-major `values` such that row `i` is swapped with row at the
+/// bit-reversed index of `i`.
+pub fn permute_rows<T>(values: &mut [T], row_width: usize) {
+    let n = values.len() / row_width;
+    for i in 0..n {
+        let j = super::permute_index(n, i);
+        if j > i {
+            swap_rows(values, row_width, i, j);
+        }
+    }
+}
+
+/// Swaps rows `i` and `j` of the row-major `values`; `i` must be smaller than `j`.
+#[inline(always)]
+pub(super) fn swap_rows<T>(values: &mut [T], row_width: usize, i: usize, j: usize) {
+    debug_assert!(i < j);
+    let (left, right) = values.split_at_mut(j * row_width);
+    left[i * row_width..(i + 1) * row_width].swap_with_slice(&mut right[..row_width]);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Copies rows of `source` into `destination` multiplying row `i` by `first_factor * offset^i`.
+pub(super) fn shift_rows<E: FieldElement>(
+    source: &[E],
+    destination: &mut [E],
+    row_width: usize,
+    first_factor: E::BaseField,
+    offset: E::BaseField,
+) {
+    let mut factor = first_factor;
+    for (d_row, s_row) in destination
+        .chunks_mut(row_width)
+        .zip(source.chunks(row_width))
+    {
+        for (d, &s) in d_row.iter_mut().zip(s_row) {
+            *d = s.mul_base(factor);
+        }
+        factor *= offset;
+    }
+}
+
 // CORE FFT ALGORITHM
 // ================================================================================================
 
@@ -131,24 +264,51 @@ pub(super) fn fft_in_place<B, E>(
     B: StarkField,
     E: FieldElement<BaseField = B>,
 {
-    let size = values.len() / stride;
+    fft_in_place_rows(values, twiddles, 1, count, stride, offset);
+}
+
+/// In-place recursive FFT with permuted output over rows of the specified width.
+///
+/// This is the same algorithm as [fft_in_place()] where every element is a row of `row_width`
+/// values; thus, FFT is applied to all columns of the row-major `values` at once.
+pub(super) fn fft_in_place_rows<B, E>(
+    values: &mut [E],
+    twiddles: &[B],
+    row_width: usize,
+    count: usize,
+    stride: usize,
+    offset: usize,
+) where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    let size = values.len() / (stride * row_width);
     debug_assert!(size.is_power_of_two());
     debug_assert!(offset < stride);
-    debug_assert_eq!(values.len() % size, 0);
+    debug_assert_eq!(values.len() % (size * row_width), 0);
 
     // Keep recursing until size is 2
     if size > 2 {
         if stride == count && count < MAX_LOOP {
-            fft_in_place(values, twiddles, 2 * count, 2 * stride, offset);
+            fft_in_place_rows(values, twiddles, row_width, 2 * count, 2 * stride, offset);
         } else {
-            fft_in_place(values, twiddles, count, 2 * stride, offset);
-            fft_in_place(values, twiddles, count, 2 * stride, offset + stride);
+            fft_in_place_rows(values, twiddles, row_width, count, 2 * stride, offset);
+            fft_in_place_rows(
+                values,
+                twiddles,
+                row_width,
+                count,
+                2 * stride,
+                offset + stride,
+            );
         }
     }
 
-    for offset in offset..(offset + count) {
-        butterfly(values, offset, stride);
-    }
+    // the first pair of blocks does not need to be multiplied by a twiddle
+    let block_len = count * row_width;
+    let (left, right) = values.split_at_mut((offset + stride) * row_width);
+    let start = offset * row_width;
+    E::fft_butterflies(&mut left[start..start + block_len], &mut right[..block_len]);
 
     let last_offset = offset + size * stride;
     for (i, offset) in (offset..last_offset)
@@ -156,37 +316,12 @@ pub(super) fn fft_in_place<B, E>(
         .enumerate()
         .skip(1)
     {
-        for j in offset..(offset + count) {
-            butterfly_twiddle(values, twiddles[i], j, stride);
-        }
+        let (left, right) = values.split_at_mut((offset + stride) * row_width);
+        let start = offset * row_width;
+        E::fft_twiddle_butterflies(
+            &mut left[start..start + block_len],
+            &mut right[..block_len],
+            twiddles[i],
+        );
     }
 }
-
-// HELPER FUNCTIONS
-// ================================================================================================
-
-#[inline(always)]
-fn butterfly<E>(values: &mut [E], offset: usize, stride: usize)
-where
-    E: FieldElement,
-{
-    let i = offset;
-    let j = offset + stride;
-    let temp = values[i];
-    values[i] = temp + values[j];
-    values[j] = temp - values[j];
-}
-
-#[inline(always)]
-fn butterfly_twiddle<B, E>(values: &mut [E], twiddle: B, offset: usize, stride: usize)
-where
-    B: StarkField,
-    E: FieldElement<BaseField = B>,
-{
-    let i = offset;
-    let j = offset + stride;
-    let temp = values[i];
-    values[j] = values[j].mul_base(twiddle);
-    values[i] = temp + values[j];
-    values[j] = temp - values[j];
-}
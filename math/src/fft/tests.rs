@@ -4,7 +4,7 @@
 // LICENSE file in the root directory of this source tree.
 
 use crate::{
-    field::{f128::BaseElement, StarkField},
+    field::{f128::BaseElement, f64, FieldElement, QuadExtension, StarkField},
     polynom,
     utils::{get_power_series, log2},
 };
@@ -57,6 +57,40 @@ fn fft_in_place() {
     assert_eq!(expected, p);
 }
 
+#[test]
+fn fft_in_place_f64() {
+    // FFT over the 64-bit field and its extensions uses specialized butterflies; make sure the
+    // results are consistent with polynomial evaluation for domains of all relevant sizes
+    for n in [2, 4, 8, 16, 1024, 4096] {
+        let g = f64::BaseElement::get_root_of_unity(log2(n));
+        let domain = get_power_series(g, n);
+        let twiddles = super::get_twiddles::<f64::BaseElement>(n);
+
+        let mut p: Vec<f64::BaseElement> = rand_vector(n);
+        let expected = polynom::eval_many(&p, &domain);
+        super::serial::fft_in_place(&mut p, &twiddles, 1, 1, 0);
+        super::permute(&mut p);
+        assert_eq!(expected, p);
+
+        let mut p: Vec<QuadExtension<f64::BaseElement>> = rand_vector(n);
+        let expected = domain
+            .iter()
+            .map(|&x| polynom::eval(&p, QuadExtension::from(x)))
+            .collect::<Vec<_>>();
+        super::serial::fft_in_place(&mut p, &twiddles, 1, 1, 0);
+        super::permute(&mut p);
+        assert_eq!(expected, p);
+
+        // interpolation should invert evaluation
+        let inv_twiddles = super::get_inv_twiddles::<f64::BaseElement>(n);
+        let mut coefficients = p.clone();
+        super::interpolate_poly(&mut coefficients, &inv_twiddles);
+        super::evaluate_poly(&mut coefficients, &twiddles);
+        assert_eq!(p, coefficients);
+        assert_ne!(QuadExtension::<f64::BaseElement>::ZERO, p[0]);
+    }
+}
+
 #[test]
 fn fft_get_twiddles() {
     let n = super::MIN_CONCURRENT_SIZE * 2;
@@ -69,6 +103,81 @@ fn fft_get_twiddles() {
     assert_eq!(expected, twiddles);
 }
 
+// BATCHED FFT
+// ================================================================================================
+
+#[test]
+fn fft_in_place_rows() {
+    for (n, num_polys) in [(2, 1), (4, 3), (16, 8), (256, 5), (4096, 17)] {
+        let twiddles = super::get_twiddles::<f64::BaseElement>(n);
+        let polys: Vec<f64::BaseElement> = rand_vector(n * num_polys);
+
+        let mut expected = polys.clone();
+        for (j, column) in transpose(&polys, num_polys).into_iter().enumerate() {
+            let mut column = column;
+            super::serial::fft_in_place(&mut column, &twiddles, 1, 1, 0);
+            super::permute(&mut column);
+            set_column(&mut expected, num_polys, j, &column);
+        }
+
+        let mut actual = polys;
+        super::serial::fft_in_place_rows(&mut actual, &twiddles, num_polys, 1, 1, 0);
+        super::serial::permute_rows(&mut actual, num_polys);
+        assert_eq!(expected, actual);
+    }
+}
+
+#[test]
+fn evaluate_polys_with_offset() {
+    let offset = f64::BaseElement::GENERATOR;
+    let blowup_factor = 8;
+    for (n, num_polys) in [(4, 3), (64, 9), (super::MIN_CONCURRENT_SIZE * 2, 13)] {
+        let twiddles = super::get_twiddles::<f64::BaseElement>(n);
+        let polys: Vec<QuadExtension<f64::BaseElement>> = rand_vector(n * num_polys);
+
+        let mut expected = vec![QuadExtension::ZERO; n * blowup_factor * num_polys];
+        for (j, column) in transpose(&polys, num_polys).into_iter().enumerate() {
+            let column =
+                super::evaluate_poly_with_offset(&column, &twiddles, offset, blowup_factor);
+            set_column(&mut expected, num_polys, j, &column);
+        }
+
+        let actual =
+            super::evaluate_polys_with_offset(&polys, num_polys, &twiddles, offset, blowup_factor);
+        assert_eq!(expected, actual);
+
+        // interpolation should invert evaluation
+        let inv_twiddles = super::get_inv_twiddles::<f64::BaseElement>(n);
+        let mut coefficients =
+            super::evaluate_polys_with_offset(&polys, num_polys, &twiddles, offset, 1);
+        super::interpolate_polys_with_offset(&mut coefficients, num_polys, &inv_twiddles, offset);
+        assert_eq!(polys, coefficients);
+    }
+}
+
+#[test]
+fn interpolate_polys() {
+    for (n, num_polys) in [(2, 2), (128, 7), (super::MIN_CONCURRENT_SIZE * 4, 4)] {
+        let inv_twiddles = super::get_inv_twiddles::<BaseElement>(n);
+        let evaluations: Vec<BaseElement> = rand_vector(n * num_polys);
+
+        let mut expected = evaluations.clone();
+        for (j, column) in transpose(&evaluations, num_polys).into_iter().enumerate() {
+            let mut column = column;
+            super::interpolate_poly(&mut column, &inv_twiddles);
+            set_column(&mut expected, num_polys, j, &column);
+        }
+
+        let mut actual = evaluations.clone();
+        super::interpolate_polys(&mut actual, num_polys, &inv_twiddles);
+        assert_eq!(expected, actual);
+
+        let twiddles = super::get_twiddles::<BaseElement>(n);
+        super::evaluate_polys(&mut actual, num_polys, &twiddles);
+        assert_eq!(evaluations, actual);
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -76,3 +185,16 @@ fn build_domain(size: usize) -> Vec<BaseElement> {
     let g = BaseElement::get_root_of_unity(log2(size));
     get_power_series(g, size)
 }
+
+/// Splits a row-major matrix with the specified number of columns into a vector of columns.
+fn transpose<E: FieldElement>(values: &[E], num_cols: usize) -> Vec<Vec<E>> {
+    (0..num_cols)
+        .map(|j| values.iter().skip(j).step_by(num_cols).copied().collect())
+        .collect()
+}
+
+fn set_column<E: FieldElement>(values: &mut [E], num_cols: usize, col_idx: usize, column: &[E]) {
+    for (i, &value) in column.iter().enumerate() {
+        values[i * num_cols + col_idx] = value;
+    }
+}
@@ -15,6 +15,9 @@ use utils::{
     DeserializationError, Randomizable, Serializable, SliceReader,
 };
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 // QUADRATIC EXTENSION FIELD
 // ================================================================================================
 
@@ -25,6 +28,11 @@ use utils::{
 /// field elements.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "B: Serialize", deserialize = "B: Deserialize<'de>"))
+)]
 pub struct CubeExtension<B: ExtensibleField<3>>(B, B, B);
 
 impl<B: ExtensibleField<3>> CubeExtension<B> {
@@ -140,6 +148,19 @@ impl<B: ExtensibleField<3>> FieldElement for CubeExtension<B> {
         let len = elements.len() * 3;
         unsafe { slice::from_raw_parts(ptr as *const Self::BaseField, len) }
     }
+
+    #[inline]
+    fn fft_butterflies(lo: &mut [Self], hi: &mut [Self]) {
+        // addition and subtraction in the extension field are performed coefficient-wise, and
+        // thus, butterflies can be computed directly over the underlying base elements
+        B::fft_butterflies(as_base_elements_mut(lo), as_base_elements_mut(hi));
+    }
+
+    #[inline]
+    fn fft_twiddle_butterflies(lo: &mut [Self], hi: &mut [Self], twiddle: Self::BaseField) {
+        // same as addition, multiplication by a base field element is performed coefficient-wise
+        B::fft_twiddle_butterflies(as_base_elements_mut(lo), as_base_elements_mut(hi), twiddle);
+    }
 }
 
 impl<B: ExtensibleField<3>> ExtensionOf<B> for CubeExtension<B> {
@@ -335,6 +356,18 @@ impl<B: ExtensibleField<3>> Deserializable for CubeExtension<B> {
     }
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Converts a mutable list of extension field elements into a mutable list of the underlying
+/// base field elements.
+#[inline(always)]
+fn as_base_elements_mut<B: ExtensibleField<3>>(elements: &mut [CubeExtension<B>]) -> &mut [B] {
+    let ptr = elements.as_mut_ptr();
+    let len = elements.len() * 3;
+    unsafe { slice::from_raw_parts_mut(ptr as *mut B, len) }
+}
+
 // TESTS
 // ================================================================================================
 
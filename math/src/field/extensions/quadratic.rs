@@ -15,6 +15,9 @@ use utils::{
     DeserializationError, Randomizable, Serializable, SliceReader,
 };
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 // QUADRATIC EXTENSION FIELD
 // ================================================================================================
 
@@ -25,6 +28,11 @@ use utils::{
 /// elements.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "B: Serialize", deserialize = "B: Deserialize<'de>"))
+)]
 pub struct QuadExtension<B: ExtensibleField<2>>(B, B);
 
 impl<B: ExtensibleField<2>> QuadExtension<B> {
@@ -132,6 +140,19 @@ impl<B: ExtensibleField<2>> FieldElement for QuadExtension<B> {
         let len = elements.len() * 2;
         unsafe { slice::from_raw_parts(ptr as *const Self::BaseField, len) }
     }
+
+    #[inline]
+    fn fft_butterflies(lo: &mut [Self], hi: &mut [Self]) {
+        // addition and subtraction in the extension field are performed coefficient-wise, and
+        // thus, butterflies can be computed directly over the underlying base elements
+        B::fft_butterflies(as_base_elements_mut(lo), as_base_elements_mut(hi));
+    }
+
+    #[inline]
+    fn fft_twiddle_butterflies(lo: &mut [Self], hi: &mut [Self], twiddle: Self::BaseField) {
+        // same as addition, multiplication by a base field element is performed coefficient-wise
+        B::fft_twiddle_butterflies(as_base_elements_mut(lo), as_base_elements_mut(hi), twiddle);
+    }
 }
 
 impl<B: ExtensibleField<2>> ExtensionOf<B> for QuadExtension<B> {
@@ -324,6 +345,18 @@ impl<B: ExtensibleField<2>> Deserializable for QuadExtension<B> {
     }
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Converts a mutable list of extension field elements into a mutable list of the underlying
+/// base field elements.
+#[inline(always)]
+fn as_base_elements_mut<B: ExtensibleField<2>>(elements: &mut [QuadExtension<B>]) -> &mut [B] {
+    let ptr = elements.as_mut_ptr();
+    let len = elements.len() * 2;
+    unsafe { slice::from_raw_parts_mut(ptr as *mut B, len) }
+}
+
 // TESTS
 // ================================================================================================
 
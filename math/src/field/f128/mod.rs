@@ -25,6 +25,11 @@ use utils::{
     Serializable,
 };
 
+#[cfg(feature = "serde")]
+use super::serde_int;
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
 #[cfg(test)]
 mod tests;
 
@@ -388,6 +393,27 @@ impl Deserializable for BaseElement {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for BaseElement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_int::serialize(self.as_int(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for BaseElement {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value: u128 = serde_int::deserialize(deserializer)?;
+        if value >= M {
+            return Err(D::Error::custom(format!(
+                "invalid field element: value {} is greater than or equal to the field modulus",
+                value
+            )));
+        }
+        Ok(BaseElement(value))
+    }
+}
+
 // FINITE FIELD ARITHMETIC
 // ================================================================================================
 
@@ -22,6 +22,11 @@ use utils::{
     DeserializationError, Randomizable, Serializable,
 };
 
+#[cfg(feature = "serde")]
+use super::serde_int;
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
 #[cfg(test)]
 mod tests;
 
@@ -498,6 +503,27 @@ impl Deserializable for BaseElement {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for BaseElement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_int::serialize(self.as_int(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for BaseElement {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value: u64 = serde_int::deserialize(deserializer)?;
+        if value >= M {
+            return Err(D::Error::custom(format!(
+                "invalid field element: value {} is greater than or equal to the field modulus",
+                value
+            )));
+        }
+        Ok(BaseElement::new(value))
+    }
+}
+
 // FINITE FIELD ARITHMETIC
 // ================================================================================================
 
@@ -27,6 +27,14 @@ use utils::{
     DeserializationError, Randomizable, Serializable,
 };
 
+#[cfg(feature = "serde")]
+use super::serde_int;
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "simd")]
+pub mod simd;
+
 #[cfg(test)]
 mod tests;
 
@@ -52,6 +60,7 @@ const ELEMENT_BYTES: usize = core::mem::size_of::<u64>();
 ///
 /// Internal values are stored in the range [0, 2^64). The backing type is `u64`.
 #[derive(Copy, Clone, Debug, Default)]
+#[repr(transparent)]
 pub struct BaseElement(u64);
 impl BaseElement {
     /// Creates a new field element from the provided `value`; the value is converted into
@@ -196,6 +205,23 @@ impl FieldElement for BaseElement {
     fn as_base_elements(elements: &[Self]) -> &[Self::BaseField] {
         elements
     }
+
+    #[cfg(feature = "simd")]
+    #[inline]
+    fn fft_butterflies(lo: &mut [Self], hi: &mut [Self]) {
+        simd::butterflies(lo, hi);
+    }
+
+    #[cfg(feature = "simd")]
+    #[inline]
+    fn fft_twiddle_butterflies(lo: &mut [Self], hi: &mut [Self], twiddle: Self) {
+        simd::twiddle_butterflies(lo, hi, twiddle);
+    }
+
+    #[cfg(feature = "simd")]
+    fn batch_inverse(values: &[Self], result: &mut [Self]) {
+        simd::batch_inversion(values, result);
+    }
 }
 
 impl StarkField for BaseElement {
@@ -542,6 +568,27 @@ impl Deserializable for BaseElement {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for BaseElement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_int::serialize(self.as_int(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for BaseElement {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value: u64 = serde_int::deserialize(deserializer)?;
+        if value >= M {
+            return Err(D::Error::custom(format!(
+                "invalid field element: value {} is greater than or equal to the field modulus",
+                value
+            )));
+        }
+        Ok(Self::new(value))
+    }
+}
+
 /// Squares the base N number of times and multiplies the result by the tail value.
 #[inline(always)]
 fn exp_acc<const N: usize>(base: BaseElement, tail: BaseElement) -> BaseElement {
@@ -0,0 +1,141 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::kernels::{impl_kernels, Packed};
+use core::arch::x86_64::*;
+
+impl_kernels!(PackedAvx2, "avx2");
+
+// CONSTANTS
+// ================================================================================================
+
+/// Field modulus = 2^64 - 2^32 + 1
+const M: i64 = 0xFFFFFFFF00000001u64 as i64;
+
+/// Mask for the lower 32 bits of a 64-bit value.
+const LO_32: i64 = 0xFFFFFFFF;
+
+/// Sign bit of a 64-bit value; used for emulating unsigned comparisons.
+const SIGN_BIT: i64 = i64::MIN;
+
+// PACKED AVX2 ELEMENTS
+// ================================================================================================
+
+/// Four field elements packed into a 256-bit vector.
+#[derive(Clone, Copy)]
+pub(super) struct PackedAvx2(__m256i);
+
+impl Packed for PackedAvx2 {
+    const WIDTH: usize = 4;
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn load(src: *const u64) -> Self {
+        Self(_mm256_loadu_si256(src as *const __m256i))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn store(self, dst: *mut u64) {
+        _mm256_storeu_si256(dst as *mut __m256i, self.0)
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn splat(value: u64) -> Self {
+        Self(_mm256_set1_epi64x(value as i64))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn add(self, rhs: Self) -> Self {
+        // we compute a + b = a - (p - b), and if the subtraction underflows, we subtract
+        // 2^32 - 1 from the result (which is equivalent to adding p modulo 2^64)
+        let p_minus_b = _mm256_sub_epi64(_mm256_set1_epi64x(M), rhs.0);
+        let x = _mm256_sub_epi64(self.0, p_minus_b);
+        let underflow = lt_u64(self.0, p_minus_b);
+        Self(_mm256_sub_epi64(x, _mm256_and_si256(underflow, lo_32())))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn sub(self, rhs: Self) -> Self {
+        let x = _mm256_sub_epi64(self.0, rhs.0);
+        let underflow = lt_u64(self.0, rhs.0);
+        Self(_mm256_sub_epi64(x, _mm256_and_si256(underflow, lo_32())))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn mul(self, rhs: Self) -> Self {
+        let (hi, lo) = mul_64x64(self.0, rhs.0);
+        Self(mont_red(hi, lo))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn select_zero(self, if_zero: Self, otherwise: Self) -> Self {
+        let is_zero = _mm256_cmpeq_epi64(self.0, _mm256_setzero_si256());
+        Self(_mm256_blendv_epi8(otherwise.0, if_zero.0, is_zero))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn lo_32() -> __m256i {
+    _mm256_set1_epi64x(LO_32)
+}
+
+/// Returns a mask with all bits set in lanes where `a` < `b` (as unsigned integers).
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn lt_u64(a: __m256i, b: __m256i) -> __m256i {
+    let sign = _mm256_set1_epi64x(SIGN_BIT);
+    _mm256_cmpgt_epi64(_mm256_xor_si256(b, sign), _mm256_xor_si256(a, sign))
+}
+
+/// Computes full 128-bit products of 64-bit lanes and returns (high, low) halves of the results.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn mul_64x64(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+    let a_hi = _mm256_srli_epi64::<32>(a);
+    let b_hi = _mm256_srli_epi64::<32>(b);
+
+    // 32-bit x 32-bit partial products; _mm256_mul_epu32 uses the lower 32 bits of every lane
+    let ll = _mm256_mul_epu32(a, b);
+    let lh = _mm256_mul_epu32(a, b_hi);
+    let hl = _mm256_mul_epu32(a_hi, b);
+    let hh = _mm256_mul_epu32(a_hi, b_hi);
+
+    // combine partial products; none of the additions below can overflow
+    let t = _mm256_add_epi64(hl, _mm256_srli_epi64::<32>(ll));
+    let u = _mm256_add_epi64(lh, _mm256_and_si256(t, lo_32()));
+    let lo = _mm256_or_si256(_mm256_slli_epi64::<32>(u), _mm256_and_si256(ll, lo_32()));
+    let hi = _mm256_add_epi64(
+        hh,
+        _mm256_add_epi64(_mm256_srli_epi64::<32>(t), _mm256_srli_epi64::<32>(u)),
+    );
+    (hi, lo)
+}
+
+/// Montgomery reduction of a 128-bit value (hi, lo); this mirrors the scalar implementation.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn mont_red(hi: __m256i, lo: __m256i) -> __m256i {
+    // a = lo + (lo << 32), e = 1 if the addition overflows
+    let a = _mm256_add_epi64(lo, _mm256_slli_epi64::<32>(lo));
+    let e = lt_u64(a, lo);
+
+    // b = a - (a >> 32) - e; e is either 0 or -1, so we add it
+    let b = _mm256_add_epi64(_mm256_sub_epi64(a, _mm256_srli_epi64::<32>(a)), e);
+
+    // r = hi - b, and if the subtraction underflows, subtract 2^32 - 1
+    let r = _mm256_sub_epi64(hi, b);
+    let c = lt_u64(hi, b);
+    _mm256_sub_epi64(r, _mm256_and_si256(c, lo_32()))
+}
@@ -0,0 +1,133 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+// AVX-512 intrinsics were stabilized in Rust 1.89; this backend is compiled only when the `simd`
+// feature is enabled, and this feature requires a correspondingly recent compiler.
+#![allow(clippy::incompatible_msrv)]
+
+use super::kernels::{impl_kernels, Packed};
+use core::arch::x86_64::*;
+
+impl_kernels!(PackedAvx512, "avx512f");
+
+// CONSTANTS
+// ================================================================================================
+
+/// Field modulus = 2^64 - 2^32 + 1
+const M: i64 = 0xFFFFFFFF00000001u64 as i64;
+
+/// Mask for the lower 32 bits of a 64-bit value.
+const LO_32: i64 = 0xFFFFFFFF;
+
+// PACKED AVX-512 ELEMENTS
+// ================================================================================================
+
+/// Eight field elements packed into a 512-bit vector.
+#[derive(Clone, Copy)]
+pub(super) struct PackedAvx512(__m512i);
+
+impl Packed for PackedAvx512 {
+    const WIDTH: usize = 8;
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn load(src: *const u64) -> Self {
+        Self(_mm512_loadu_si512(src as *const _))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn store(self, dst: *mut u64) {
+        _mm512_storeu_si512(dst as *mut _, self.0)
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn splat(value: u64) -> Self {
+        Self(_mm512_set1_epi64(value as i64))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn add(self, rhs: Self) -> Self {
+        // we compute a + b = a - (p - b), and if the subtraction underflows, we subtract
+        // 2^32 - 1 from the result (which is equivalent to adding p modulo 2^64)
+        let p_minus_b = _mm512_sub_epi64(_mm512_set1_epi64(M), rhs.0);
+        let x = _mm512_sub_epi64(self.0, p_minus_b);
+        let underflow = _mm512_cmplt_epu64_mask(self.0, p_minus_b);
+        Self(_mm512_mask_sub_epi64(x, underflow, x, lo_32()))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn sub(self, rhs: Self) -> Self {
+        let x = _mm512_sub_epi64(self.0, rhs.0);
+        let underflow = _mm512_cmplt_epu64_mask(self.0, rhs.0);
+        Self(_mm512_mask_sub_epi64(x, underflow, x, lo_32()))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn mul(self, rhs: Self) -> Self {
+        let (hi, lo) = mul_64x64(self.0, rhs.0);
+        Self(mont_red(hi, lo))
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn select_zero(self, if_zero: Self, otherwise: Self) -> Self {
+        let is_zero = _mm512_cmpeq_epi64_mask(self.0, _mm512_setzero_si512());
+        Self(_mm512_mask_blend_epi64(is_zero, otherwise.0, if_zero.0))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+#[inline]
+#[target_feature(enable = "avx512f")]
+unsafe fn lo_32() -> __m512i {
+    _mm512_set1_epi64(LO_32)
+}
+
+/// Computes full 128-bit products of 64-bit lanes and returns (high, low) halves of the results.
+#[inline]
+#[target_feature(enable = "avx512f")]
+unsafe fn mul_64x64(a: __m512i, b: __m512i) -> (__m512i, __m512i) {
+    let a_hi = _mm512_srli_epi64::<32>(a);
+    let b_hi = _mm512_srli_epi64::<32>(b);
+
+    // 32-bit x 32-bit partial products; _mm512_mul_epu32 uses the lower 32 bits of every lane
+    let ll = _mm512_mul_epu32(a, b);
+    let lh = _mm512_mul_epu32(a, b_hi);
+    let hl = _mm512_mul_epu32(a_hi, b);
+    let hh = _mm512_mul_epu32(a_hi, b_hi);
+
+    // combine partial products; none of the additions below can overflow
+    let t = _mm512_add_epi64(hl, _mm512_srli_epi64::<32>(ll));
+    let u = _mm512_add_epi64(lh, _mm512_and_si512(t, lo_32()));
+    let lo = _mm512_or_si512(_mm512_slli_epi64::<32>(u), _mm512_and_si512(ll, lo_32()));
+    let hi = _mm512_add_epi64(
+        hh,
+        _mm512_add_epi64(_mm512_srli_epi64::<32>(t), _mm512_srli_epi64::<32>(u)),
+    );
+    (hi, lo)
+}
+
+/// Montgomery reduction of a 128-bit value (hi, lo); this mirrors the scalar implementation.
+#[inline]
+#[target_feature(enable = "avx512f")]
+unsafe fn mont_red(hi: __m512i, lo: __m512i) -> __m512i {
+    // a = lo + (lo << 32), and if the addition overflows, b = a - (a >> 32) - 1
+    let a = _mm512_add_epi64(lo, _mm512_slli_epi64::<32>(lo));
+    let e = _mm512_cmplt_epu64_mask(a, lo);
+    let b = _mm512_sub_epi64(a, _mm512_srli_epi64::<32>(a));
+    let b = _mm512_mask_sub_epi64(b, e, b, _mm512_set1_epi64(1));
+
+    // r = hi - b, and if the subtraction underflows, subtract 2^32 - 1
+    let r = _mm512_sub_epi64(hi, b);
+    let c = _mm512_cmplt_epu64_mask(hi, b);
+    _mm512_mask_sub_epi64(r, c, r, lo_32())
+}
@@ -0,0 +1,372 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{as_mut_ptr, as_ptr, one, BaseElement};
+use crate::FieldElement;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum number of lanes across all backends.
+const MAX_WIDTH: usize = 8;
+
+/// Number of vectors processed in lock-step by exponentiation kernels.
+const NUM_INTERLEAVED: usize = 4;
+
+// PACKED FIELD ELEMENTS
+// ================================================================================================
+
+/// A vector of field elements in Montgomery representation.
+///
+/// All operations must produce results identical to the results of the corresponding scalar
+/// operations performed on every lane independently.
+///
+/// All methods are unsafe because implementations may rely on CPU features which must be
+/// available at runtime.
+pub(super) trait Packed: Copy {
+    /// Number of field elements in a single vector.
+    const WIDTH: usize;
+
+    /// Loads [Packed::WIDTH] values from the specified memory location.
+    unsafe fn load(src: *const u64) -> Self;
+
+    /// Stores this vector at the specified memory location.
+    unsafe fn store(self, dst: *mut u64);
+
+    /// Returns a vector with all lanes set to the specified value.
+    unsafe fn splat(value: u64) -> Self;
+
+    unsafe fn add(self, rhs: Self) -> Self;
+
+    unsafe fn sub(self, rhs: Self) -> Self;
+
+    unsafe fn mul(self, rhs: Self) -> Self;
+
+    /// Returns a vector which contains lanes of `if_zero` where lanes of this vector are equal
+    /// to zero, and lanes of `otherwise` everywhere else.
+    ///
+    /// Same as for scalar field elements, a lane is considered to be zero only if its internal
+    /// value is zero.
+    unsafe fn select_zero(self, if_zero: Self, otherwise: Self) -> Self;
+}
+
+// GENERIC KERNELS
+// ================================================================================================
+// The kernels below process the vectorizable head of the input slices using packed arithmetic
+// and delegate the remaining tail (if any) to scalar kernels. These kernels are always inlined
+// into the backend-specific wrappers generated by the `impl_kernels` macro, and this way, are
+// compiled with CPU features of the backend.
+
+#[inline(always)]
+pub(super) unsafe fn butterflies<P: Packed>(lo: &mut [BaseElement], hi: &mut [BaseElement]) {
+    let head = lo.len() - lo.len() % P::WIDTH;
+    let (lo_ptr, hi_ptr) = (as_mut_ptr(lo), as_mut_ptr(hi));
+    for i in (0..head).step_by(P::WIDTH) {
+        let a = P::load(lo_ptr.add(i));
+        let b = P::load(hi_ptr.add(i));
+        a.add(b).store(lo_ptr.add(i));
+        a.sub(b).store(hi_ptr.add(i));
+    }
+    scalar::butterflies(&mut lo[head..], &mut hi[head..]);
+}
+
+#[inline(always)]
+pub(super) unsafe fn twiddle_butterflies<P: Packed>(
+    lo: &mut [BaseElement],
+    hi: &mut [BaseElement],
+    twiddle: BaseElement,
+) {
+    let head = lo.len() - lo.len() % P::WIDTH;
+    let (lo_ptr, hi_ptr) = (as_mut_ptr(lo), as_mut_ptr(hi));
+    let t = P::splat(twiddle.inner());
+    for i in (0..head).step_by(P::WIDTH) {
+        let a = P::load(lo_ptr.add(i));
+        let b = P::load(hi_ptr.add(i)).mul(t);
+        a.add(b).store(lo_ptr.add(i));
+        a.sub(b).store(hi_ptr.add(i));
+    }
+    scalar::twiddle_butterflies(&mut lo[head..], &mut hi[head..], twiddle);
+}
+
+#[inline(always)]
+pub(super) unsafe fn add_assign<P: Packed>(a: &mut [BaseElement], b: &[BaseElement]) {
+    let head = a.len() - a.len() % P::WIDTH;
+    let (a_ptr, b_ptr) = (as_mut_ptr(a), as_ptr(b));
+    for i in (0..head).step_by(P::WIDTH) {
+        let x = P::load(a_ptr.add(i));
+        x.add(P::load(b_ptr.add(i))).store(a_ptr.add(i));
+    }
+    scalar::add_assign(&mut a[head..], &b[head..]);
+}
+
+#[inline(always)]
+pub(super) unsafe fn mul_assign<P: Packed>(a: &mut [BaseElement], b: &[BaseElement]) {
+    let head = a.len() - a.len() % P::WIDTH;
+    let (a_ptr, b_ptr) = (as_mut_ptr(a), as_ptr(b));
+    for i in (0..head).step_by(P::WIDTH) {
+        let x = P::load(a_ptr.add(i));
+        x.mul(P::load(b_ptr.add(i))).store(a_ptr.add(i));
+    }
+    scalar::mul_assign(&mut a[head..], &b[head..]);
+}
+
+#[inline(always)]
+pub(super) unsafe fn mul_scalar_assign<P: Packed>(a: &mut [BaseElement], b: BaseElement) {
+    let head = a.len() - a.len() % P::WIDTH;
+    let a_ptr = as_mut_ptr(a);
+    let y = P::splat(b.inner());
+    for i in (0..head).step_by(P::WIDTH) {
+        P::load(a_ptr.add(i)).mul(y).store(a_ptr.add(i));
+    }
+    scalar::mul_scalar_assign(&mut a[head..], b);
+}
+
+// Exponentiation kernels are bound by latency of multiplications rather than by throughput. To
+// hide the latency, these kernels process blocks of several vectors in lock-step, and handle the
+// tail of a slice as a zero-padded vector rather than falling back onto scalar arithmetic.
+
+#[inline(always)]
+pub(super) unsafe fn exp7_assign<P: Packed>(a: &mut [BaseElement]) {
+    for a in a.chunks_mut(NUM_INTERLEAVED * P::WIDTH) {
+        let mut x = load_block::<P>(a);
+        for x in x.iter_mut() {
+            let x2 = x.mul(*x);
+            let x4 = x2.mul(x2);
+            let x3 = x2.mul(*x);
+            *x = x3.mul(x4);
+        }
+        store_block(&x, a);
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn exp_acc_assign<P: Packed>(a: &mut [BaseElement], m: usize, b: &[BaseElement]) {
+    let block_size = NUM_INTERLEAVED * P::WIDTH;
+    for (a, b) in a.chunks_mut(block_size).zip(b.chunks(block_size)) {
+        let mut x = load_block::<P>(a);
+        for _ in 0..m {
+            for x in x.iter_mut() {
+                *x = x.mul(*x);
+            }
+        }
+        let y = load_block::<P>(b);
+        for (x, y) in x.iter_mut().zip(y.iter()) {
+            *x = x.mul(*y);
+        }
+        store_block(&x, a);
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn batch_inversion<P: Packed>(
+    values: &[BaseElement],
+    result: &mut [BaseElement],
+) {
+    let num_rows = values.len() / P::WIDTH;
+    let head = num_rows * P::WIDTH;
+    if num_rows > 0 {
+        let (v_ptr, r_ptr) = (as_ptr(values), as_mut_ptr(result));
+        let one = P::splat(one());
+        let zero = P::splat(0);
+
+        // compute running products in every lane, skipping over zeros
+        let mut acc = one;
+        for i in (0..head).step_by(P::WIDTH) {
+            acc.store(r_ptr.add(i));
+            let v = P::load(v_ptr.add(i));
+            acc = acc.mul(v.select_zero(one, v));
+        }
+
+        // invert the final products of all lanes; these products cannot be zeros
+        let mut lanes = [BaseElement::ZERO; MAX_WIDTH];
+        let mut inv_lanes = [BaseElement::ZERO; MAX_WIDTH];
+        acc.store(as_mut_ptr(&mut lanes));
+        scalar::batch_inversion(&lanes[..P::WIDTH], &mut inv_lanes[..P::WIDTH]);
+        let mut inv = P::load(as_ptr(&inv_lanes));
+
+        // unwind running products to get inverses of individual values
+        for i in (0..head).step_by(P::WIDTH).rev() {
+            let v = P::load(v_ptr.add(i));
+            let r = P::load(r_ptr.add(i)).mul(inv);
+            v.select_zero(zero, r).store(r_ptr.add(i));
+            inv = inv.mul(v.select_zero(one, v));
+        }
+    }
+    scalar::batch_inversion(&values[head..], &mut result[head..]);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// A block of up to [NUM_INTERLEAVED] vectors.
+struct Block<P: Packed> {
+    vectors: [P; NUM_INTERLEAVED],
+    len: usize,
+}
+
+impl<P: Packed> Block<P> {
+    #[inline(always)]
+    fn iter_mut(&mut self) -> core::slice::IterMut<'_, P> {
+        self.vectors[..self.len].iter_mut()
+    }
+
+    #[inline(always)]
+    fn iter(&self) -> core::slice::Iter<'_, P> {
+        self.vectors[..self.len].iter()
+    }
+}
+
+/// Loads up to [NUM_INTERLEAVED] vectors from the provided slice; if the length of the slice is
+/// not a multiple of the vector width, the last vector is padded with zeros.
+#[inline(always)]
+unsafe fn load_block<P: Packed>(src: &[BaseElement]) -> Block<P> {
+    debug_assert!(src.len() <= NUM_INTERLEAVED * P::WIDTH);
+    let mut vectors = [P::splat(0); NUM_INTERLEAVED];
+    let mut len = 0;
+    for (vector, chunk) in vectors.iter_mut().zip(src.chunks(P::WIDTH)) {
+        *vector = if chunk.len() == P::WIDTH {
+            P::load(as_ptr(chunk))
+        } else {
+            let mut padded = [BaseElement::ZERO; MAX_WIDTH];
+            padded[..chunk.len()].copy_from_slice(chunk);
+            P::load(as_ptr(&padded))
+        };
+        len += 1;
+    }
+    Block { vectors, len }
+}
+
+/// Stores the vectors of the block into the provided slice; padding lanes of the last vector
+/// are discarded.
+#[inline(always)]
+unsafe fn store_block<P: Packed>(block: &Block<P>, dst: &mut [BaseElement]) {
+    for (vector, chunk) in block.iter().zip(dst.chunks_mut(P::WIDTH)) {
+        if chunk.len() == P::WIDTH {
+            vector.store(as_mut_ptr(chunk));
+        } else {
+            let mut padded = [BaseElement::ZERO; MAX_WIDTH];
+            vector.store(as_mut_ptr(&mut padded));
+            chunk.copy_from_slice(&padded[..chunk.len()]);
+        }
+    }
+}
+
+// BACKEND WRAPPERS
+// ================================================================================================
+
+/// Generates entry points for all kernels specialized for the specified packed type and compiled
+/// with the specified CPU features enabled.
+macro_rules! impl_kernels {
+    ($packed:ty $(, $feature:literal)?) => {
+        use super::{kernels, BaseElement};
+
+        $(#[target_feature(enable = $feature)])?
+        pub(super) unsafe fn butterflies(lo: &mut [BaseElement], hi: &mut [BaseElement]) {
+            kernels::butterflies::<$packed>(lo, hi)
+        }
+
+        $(#[target_feature(enable = $feature)])?
+        pub(super) unsafe fn twiddle_butterflies(
+            lo: &mut [BaseElement],
+            hi: &mut [BaseElement],
+            twiddle: BaseElement,
+        ) {
+            kernels::twiddle_butterflies::<$packed>(lo, hi, twiddle)
+        }
+
+        $(#[target_feature(enable = $feature)])?
+        pub(super) unsafe fn add_assign(a: &mut [BaseElement], b: &[BaseElement]) {
+            kernels::add_assign::<$packed>(a, b)
+        }
+
+        $(#[target_feature(enable = $feature)])?
+        pub(super) unsafe fn mul_assign(a: &mut [BaseElement], b: &[BaseElement]) {
+            kernels::mul_assign::<$packed>(a, b)
+        }
+
+        $(#[target_feature(enable = $feature)])?
+        pub(super) unsafe fn mul_scalar_assign(a: &mut [BaseElement], b: BaseElement) {
+            kernels::mul_scalar_assign::<$packed>(a, b)
+        }
+
+        $(#[target_feature(enable = $feature)])?
+        pub(super) unsafe fn exp7_assign(a: &mut [BaseElement]) {
+            kernels::exp7_assign::<$packed>(a)
+        }
+
+        $(#[target_feature(enable = $feature)])?
+        pub(super) unsafe fn exp_acc_assign(a: &mut [BaseElement], m: usize, b: &[BaseElement]) {
+            kernels::exp_acc_assign::<$packed>(a, m, b)
+        }
+
+        $(#[target_feature(enable = $feature)])?
+        pub(super) unsafe fn batch_inversion(values: &[BaseElement], result: &mut [BaseElement]) {
+            kernels::batch_inversion::<$packed>(values, result)
+        }
+    };
+}
+
+pub(super) use impl_kernels;
+
+// SCALAR KERNELS
+// ================================================================================================
+
+/// Kernels which use regular field arithmetic; these are used for processing the tails of
+/// slices which are not multiples of the vector width, and as a fallback on CPUs without
+/// vector instructions.
+pub(super) mod scalar {
+    use super::BaseElement;
+    use crate::FieldElement;
+
+    pub fn butterflies(lo: &mut [BaseElement], hi: &mut [BaseElement]) {
+        for (a, b) in lo.iter_mut().zip(hi.iter_mut()) {
+            let temp = *a;
+            *a = temp + *b;
+            *b = temp - *b;
+        }
+    }
+
+    pub fn twiddle_butterflies(
+        lo: &mut [BaseElement],
+        hi: &mut [BaseElement],
+        twiddle: BaseElement,
+    ) {
+        for (a, b) in lo.iter_mut().zip(hi.iter_mut()) {
+            let temp = *a;
+            *b *= twiddle;
+            *a = temp + *b;
+            *b = temp - *b;
+        }
+    }
+
+    pub fn add_assign(a: &mut [BaseElement], b: &[BaseElement]) {
+        a.iter_mut().zip(b).for_each(|(a, &b)| *a += b);
+    }
+
+    pub fn mul_assign(a: &mut [BaseElement], b: &[BaseElement]) {
+        a.iter_mut().zip(b).for_each(|(a, &b)| *a *= b);
+    }
+
+    pub fn mul_scalar_assign(a: &mut [BaseElement], b: BaseElement) {
+        a.iter_mut().for_each(|a| *a *= b);
+    }
+
+    pub fn exp7_assign(a: &mut [BaseElement]) {
+        a.iter_mut().for_each(|a| *a = a.exp7());
+    }
+
+    pub fn exp_acc_assign(a: &mut [BaseElement], m: usize, b: &[BaseElement]) {
+        for (a, &b) in a.iter_mut().zip(b) {
+            for _ in 0..m {
+                *a = a.square();
+            }
+            *a *= b;
+        }
+    }
+
+    pub fn batch_inversion(values: &[BaseElement], result: &mut [BaseElement]) {
+        crate::utils::serial_batch_inversion(values, result);
+    }
+}
@@ -0,0 +1,266 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Vectorized arithmetic for the 64-bit field.
+//!
+//! This module contains implementations of field operations over slices of [BaseElement]s which
+//! process several elements at a time using SIMD instructions. The following backends are
+//! supported:
+//! * **AVX-512** - 8 elements per vector; used on x86_64 CPUs which support `avx512f`.
+//! * **AVX2** - 4 elements per vector; used on x86_64 CPUs which support `avx2`.
+//! * **Portable** - 4 elements per vector; implemented in plain Rust using only 32-bit
+//!   multiplications so that the compiler can map it onto NEON instructions on aarch64.
+//! * **Scalar** - the regular (non-vectorized) field arithmetic.
+//!
+//! The backend is selected at runtime based on the features of the CPU the code is running on,
+//! and the selection is cached after the first call. All backends produce results identical to
+//! scalar arithmetic.
+//!
+//! The functions in this module are used by FFT butterflies (and thus, by polynomial
+//! evaluation and interpolation), by [batch_inversion()](crate::batch_inversion), and by the
+//! `Rp64_256` hash function.
+
+use super::BaseElement;
+use crate::FieldElement;
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// Executes the specified kernel using the specified backend.
+macro_rules! dispatch {
+    ($backend:expr, $kernel:ident($($arg:expr),*)) => {
+        match $backend {
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx512 => unsafe { avx512::$kernel($($arg),*) },
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx2 => unsafe { avx2::$kernel($($arg),*) },
+            Backend::Portable => unsafe { portable::$kernel($($arg),*) },
+            _ => kernels::scalar::$kernel($($arg),*),
+        }
+    };
+}
+
+mod kernels;
+mod portable;
+
+#[cfg(target_arch = "x86_64")]
+mod avx2;
+
+#[cfg(target_arch = "x86_64")]
+mod avx512;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Slices shorter than this are processed using scalar arithmetic directly, as they cannot fill
+/// a single vector of any backend.
+const MIN_VECTOR_LENGTH: usize = 4;
+
+// BACKEND SELECTION
+// ================================================================================================
+
+/// SIMD instruction sets which can be used for vectorized field arithmetic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// Regular (non-vectorized) field arithmetic.
+    Scalar,
+    /// Plain Rust implementation processing 4 elements at a time.
+    Portable,
+    /// AVX2 implementation processing 4 elements at a time.
+    Avx2,
+    /// AVX-512 implementation processing 8 elements at a time.
+    Avx512,
+}
+
+/// Cached backend selection; 0 means that the backend has not been selected yet.
+static BACKEND: AtomicU8 = AtomicU8::new(0);
+
+/// Returns the backend used for vectorized field arithmetic on this CPU.
+///
+/// The backend is detected on the first call to this function.
+#[inline]
+pub fn backend() -> Backend {
+    match BACKEND.load(Ordering::Relaxed) {
+        1 => Backend::Scalar,
+        2 => Backend::Portable,
+        3 => Backend::Avx2,
+        4 => Backend::Avx512,
+        _ => {
+            let backend = detect_backend();
+            let id = match backend {
+                Backend::Scalar => 1,
+                Backend::Portable => 2,
+                Backend::Avx2 => 3,
+                Backend::Avx512 => 4,
+            };
+            BACKEND.store(id, Ordering::Relaxed);
+            backend
+        }
+    }
+}
+
+/// Overrides the backend used for vectorized field arithmetic; this is intended primarily for
+/// comparing performance of the backends, e.g., in benchmarks.
+///
+/// # Panics
+/// Panics if the specified backend is not supported by this CPU.
+pub fn set_backend(backend: Backend) {
+    assert!(
+        is_supported(backend),
+        "{:?} backend is not supported by this CPU",
+        backend
+    );
+    let id = match backend {
+        Backend::Scalar => 1,
+        Backend::Portable => 2,
+        Backend::Avx2 => 3,
+        Backend::Avx512 => 4,
+    };
+    BACKEND.store(id, Ordering::Relaxed);
+}
+
+/// Returns true if the specified backend can be used on this CPU.
+fn is_supported(backend: Backend) -> bool {
+    match backend {
+        Backend::Scalar | Backend::Portable => true,
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx2 => std::is_x86_feature_detected!("avx2"),
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx512 => std::is_x86_feature_detected!("avx512f"),
+        #[cfg(not(target_arch = "x86_64"))]
+        Backend::Avx2 | Backend::Avx512 => false,
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn detect_backend() -> Backend {
+    if std::is_x86_feature_detected!("avx512f") {
+        Backend::Avx512
+    } else if std::is_x86_feature_detected!("avx2") {
+        Backend::Avx2
+    } else {
+        Backend::Scalar
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+fn detect_backend() -> Backend {
+    // NEON is always available on aarch64
+    Backend::Portable
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+fn detect_backend() -> Backend {
+    Backend::Scalar
+}
+
+// VECTORIZED OPERATIONS
+// ================================================================================================
+
+/// Computes `lo[i], hi[i] = lo[i] + hi[i], lo[i] - hi[i]` for all `i`.
+///
+/// # Panics
+/// Panics if `lo` and `hi` have different lengths.
+#[inline]
+pub fn butterflies(lo: &mut [BaseElement], hi: &mut [BaseElement]) {
+    assert_eq!(lo.len(), hi.len(), "slices must have the same length");
+    if lo.len() < MIN_VECTOR_LENGTH {
+        return kernels::scalar::butterflies(lo, hi);
+    }
+    dispatch!(backend(), butterflies(lo, hi))
+}
+
+/// Computes `lo[i], hi[i] = lo[i] + hi[i] * t, lo[i] - hi[i] * t` for all `i`, where `t` is the
+/// specified `twiddle`.
+///
+/// # Panics
+/// Panics if `lo` and `hi` have different lengths.
+#[inline]
+pub fn twiddle_butterflies(lo: &mut [BaseElement], hi: &mut [BaseElement], twiddle: BaseElement) {
+    assert_eq!(lo.len(), hi.len(), "slices must have the same length");
+    if lo.len() < MIN_VECTOR_LENGTH {
+        return kernels::scalar::twiddle_butterflies(lo, hi, twiddle);
+    }
+    dispatch!(backend(), twiddle_butterflies(lo, hi, twiddle))
+}
+
+/// Computes `a[i] = a[i] + b[i]` for all `i`.
+///
+/// # Panics
+/// Panics if `a` and `b` have different lengths.
+pub fn add_assign(a: &mut [BaseElement], b: &[BaseElement]) {
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    dispatch!(backend(), add_assign(a, b))
+}
+
+/// Computes `a[i] = a[i] * b[i]` for all `i`.
+///
+/// # Panics
+/// Panics if `a` and `b` have different lengths.
+pub fn mul_assign(a: &mut [BaseElement], b: &[BaseElement]) {
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    dispatch!(backend(), mul_assign(a, b))
+}
+
+/// Computes `a[i] = a[i] * b` for all `i`.
+pub fn mul_scalar_assign(a: &mut [BaseElement], b: BaseElement) {
+    dispatch!(backend(), mul_scalar_assign(a, b))
+}
+
+/// Computes `a[i] = a[i]^7` for all `i`.
+pub fn exp7_assign(a: &mut [BaseElement]) {
+    dispatch!(backend(), exp7_assign(a))
+}
+
+/// Computes `a[i] = a[i]^(2^m) * b[i]` for all `i`.
+///
+/// This is the building block for evaluating addition chains (e.g., for computing inverse S-Boxes
+/// of arithmetization-friendly hash functions).
+///
+/// # Panics
+/// Panics if `a` and `b` have different lengths.
+pub fn exp_acc_assign(a: &mut [BaseElement], m: usize, b: &[BaseElement]) {
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    dispatch!(backend(), exp_acc_assign(a, m, b))
+}
+
+/// Computes multiplicative inverses of all `values` and writes them into `result`. Zero values
+/// are mapped to zeros.
+///
+/// This uses Montgomery batch inversion independently in every vector lane, and thus, requires
+/// only a handful of field inversions.
+///
+/// # Panics
+/// Panics if `values` and `result` have different lengths.
+pub fn batch_inversion(values: &[BaseElement], result: &mut [BaseElement]) {
+    assert_eq!(
+        values.len(),
+        result.len(),
+        "slices must have the same length"
+    );
+    dispatch!(backend(), batch_inversion(values, result))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a raw pointer to the inner `u64` values of the provided elements.
+#[inline(always)]
+fn as_ptr(values: &[BaseElement]) -> *const u64 {
+    values.as_ptr() as *const u64
+}
+
+/// Returns a raw mutable pointer to the inner `u64` values of the provided elements.
+#[inline(always)]
+fn as_mut_ptr(values: &mut [BaseElement]) -> *mut u64 {
+    values.as_mut_ptr() as *mut u64
+}
+
+/// Returns Montgomery representation of ONE.
+#[inline(always)]
+fn one() -> u64 {
+    BaseElement::ONE.inner()
+}
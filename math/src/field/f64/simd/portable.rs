@@ -0,0 +1,122 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::kernels::{impl_kernels, Packed};
+
+impl_kernels!(PackedPortable);
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of field elements in a single vector.
+const WIDTH: usize = 4;
+
+/// Field modulus = 2^64 - 2^32 + 1
+const M: u64 = 0xFFFFFFFF00000001;
+
+/// Mask for the lower 32 bits of a 64-bit value.
+const LO_32: u64 = 0xFFFFFFFF;
+
+// PACKED PORTABLE ELEMENTS
+// ================================================================================================
+
+/// Four field elements processed in lock-step.
+///
+/// All operations are written as branch-free loops over lanes which use only 32-bit x 32-bit
+/// multiplications, additions, shifts and comparisons. This allows the compiler to map them onto
+/// vector instructions of the target (e.g., NEON `umull` and `cmhi` instructions on aarch64).
+#[derive(Clone, Copy)]
+pub(super) struct PackedPortable([u64; WIDTH]);
+
+impl Packed for PackedPortable {
+    const WIDTH: usize = WIDTH;
+
+    #[inline(always)]
+    unsafe fn load(src: *const u64) -> Self {
+        Self(core::ptr::read_unaligned(src as *const [u64; WIDTH]))
+    }
+
+    #[inline(always)]
+    unsafe fn store(self, dst: *mut u64) {
+        core::ptr::write_unaligned(dst as *mut [u64; WIDTH], self.0)
+    }
+
+    #[inline(always)]
+    unsafe fn splat(value: u64) -> Self {
+        Self([value; WIDTH])
+    }
+
+    #[inline(always)]
+    unsafe fn add(self, rhs: Self) -> Self {
+        let mut result = [0; WIDTH];
+        for (i, r) in result.iter_mut().enumerate() {
+            // we compute a + b = a - (p - b)
+            let p_minus_b = M.wrapping_sub(rhs.0[i]);
+            let x = self.0[i].wrapping_sub(p_minus_b);
+            let underflow = mask(self.0[i] < p_minus_b);
+            *r = x.wrapping_sub(underflow & LO_32);
+        }
+        Self(result)
+    }
+
+    #[inline(always)]
+    unsafe fn sub(self, rhs: Self) -> Self {
+        let mut result = [0; WIDTH];
+        for (i, r) in result.iter_mut().enumerate() {
+            let x = self.0[i].wrapping_sub(rhs.0[i]);
+            let underflow = mask(self.0[i] < rhs.0[i]);
+            *r = x.wrapping_sub(underflow & LO_32);
+        }
+        Self(result)
+    }
+
+    #[inline(always)]
+    unsafe fn mul(self, rhs: Self) -> Self {
+        let mut result = [0; WIDTH];
+        for (i, r) in result.iter_mut().enumerate() {
+            let (a, b) = (self.0[i], rhs.0[i]);
+
+            // 32-bit x 32-bit partial products
+            let ll = (a & LO_32) * (b & LO_32);
+            let lh = (a & LO_32) * (b >> 32);
+            let hl = (a >> 32) * (b & LO_32);
+            let hh = (a >> 32) * (b >> 32);
+
+            // combine partial products; none of the additions below can overflow
+            let t = hl + (ll >> 32);
+            let u = lh + (t & LO_32);
+            let lo = (u << 32) | (ll & LO_32);
+            let hi = hh + (t >> 32) + (u >> 32);
+
+            // Montgomery reduction; this mirrors the scalar implementation
+            let a = lo.wrapping_add(lo << 32);
+            let e = mask(a < lo);
+            let b = a.wrapping_sub(a >> 32).wrapping_add(e);
+            let x = hi.wrapping_sub(b);
+            let c = mask(hi < b);
+            *r = x.wrapping_sub(c & LO_32);
+        }
+        Self(result)
+    }
+
+    #[inline(always)]
+    unsafe fn select_zero(self, if_zero: Self, otherwise: Self) -> Self {
+        let mut result = [0; WIDTH];
+        for (i, r) in result.iter_mut().enumerate() {
+            let is_zero = mask(self.0[i] == 0);
+            *r = (if_zero.0[i] & is_zero) | (otherwise.0[i] & !is_zero);
+        }
+        Self(result)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Converts a boolean into a mask with either all bits set or all bits cleared.
+#[inline(always)]
+fn mask(condition: bool) -> u64 {
+    0u64.wrapping_sub(condition as u64)
+}
@@ -0,0 +1,180 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{kernels, portable, Backend, BaseElement};
+use crate::FieldElement;
+use rand_utils::rand_value;
+use utils::collections::Vec;
+
+#[cfg(target_arch = "x86_64")]
+use super::{avx2, avx512};
+
+/// Slice lengths which cover empty slices, slices shorter than a single vector, and slices with
+/// and without scalar tails for all backends.
+const LENGTHS: [usize; 8] = [0, 1, 3, 4, 7, 8, 13, 64];
+
+// BACKEND TESTS
+// ================================================================================================
+
+#[test]
+fn butterflies() {
+    for backend in available_backends() {
+        for n in LENGTHS {
+            let lo = build_values(n);
+            let hi = build_values(n);
+
+            let (mut lo_expected, mut hi_expected) = (lo.clone(), hi.clone());
+            kernels::scalar::butterflies(&mut lo_expected, &mut hi_expected);
+
+            let (mut lo_actual, mut hi_actual) = (lo.clone(), hi.clone());
+            dispatch!(backend, butterflies(&mut lo_actual, &mut hi_actual));
+
+            assert_same(&lo_expected, &lo_actual, backend);
+            assert_same(&hi_expected, &hi_actual, backend);
+        }
+    }
+}
+
+#[test]
+fn twiddle_butterflies() {
+    for backend in available_backends() {
+        for n in LENGTHS {
+            let lo = build_values(n);
+            let hi = build_values(n);
+            let twiddle = rand_value::<BaseElement>();
+
+            let (mut lo_expected, mut hi_expected) = (lo.clone(), hi.clone());
+            kernels::scalar::twiddle_butterflies(&mut lo_expected, &mut hi_expected, twiddle);
+
+            let (mut lo_actual, mut hi_actual) = (lo.clone(), hi.clone());
+            dispatch!(
+                backend,
+                twiddle_butterflies(&mut lo_actual, &mut hi_actual, twiddle)
+            );
+
+            assert_same(&lo_expected, &lo_actual, backend);
+            assert_same(&hi_expected, &hi_actual, backend);
+        }
+    }
+}
+
+#[test]
+fn add_and_mul() {
+    for backend in available_backends() {
+        for n in LENGTHS {
+            let a = build_values(n);
+            let b = build_values(n);
+            let c = rand_value::<BaseElement>();
+
+            let expected = a.iter().zip(&b).map(|(&a, &b)| a + b).collect::<Vec<_>>();
+            let mut actual = a.clone();
+            dispatch!(backend, add_assign(&mut actual, &b));
+            assert_same(&expected, &actual, backend);
+
+            let expected = a.iter().zip(&b).map(|(&a, &b)| a * b).collect::<Vec<_>>();
+            let mut actual = a.clone();
+            dispatch!(backend, mul_assign(&mut actual, &b));
+            assert_same(&expected, &actual, backend);
+
+            let expected = a.iter().map(|&a| a * c).collect::<Vec<_>>();
+            let mut actual = a.clone();
+            dispatch!(backend, mul_scalar_assign(&mut actual, c));
+            assert_same(&expected, &actual, backend);
+        }
+    }
+}
+
+#[test]
+fn exponentiation() {
+    for backend in available_backends() {
+        for n in LENGTHS {
+            let a = build_values(n);
+            let b = build_values(n);
+
+            let expected = a.iter().map(|&a| a.exp(7)).collect::<Vec<_>>();
+            let mut actual = a.clone();
+            dispatch!(backend, exp7_assign(&mut actual));
+            assert_eq!(expected, actual, "exp7 failed for {:?} backend", backend);
+
+            let expected = a
+                .iter()
+                .zip(&b)
+                .map(|(&a, &b)| a.exp(1 << 5) * b)
+                .collect::<Vec<_>>();
+            let mut actual = a.clone();
+            dispatch!(backend, exp_acc_assign(&mut actual, 5, &b));
+            assert_eq!(expected, actual, "exp_acc failed for {:?} backend", backend);
+        }
+    }
+}
+
+#[test]
+fn batch_inversion() {
+    for backend in available_backends() {
+        for n in LENGTHS {
+            let values = build_values(n);
+            let expected = values
+                .iter()
+                .map(|&v| if v == BaseElement::ZERO { v } else { v.inv() })
+                .collect::<Vec<_>>();
+
+            let mut actual = vec![BaseElement::ZERO; n];
+            dispatch!(backend, batch_inversion(&values, &mut actual));
+            assert_eq!(
+                expected, actual,
+                "inversion failed for {:?} backend",
+                backend
+            );
+        }
+    }
+}
+
+#[test]
+fn backend_detection() {
+    let backend = super::backend();
+    assert!(available_backends().contains(&backend));
+    // the selection is cached
+    assert_eq!(backend, super::backend());
+
+    // the selection can be overridden; all backends produce the same results, and thus, this
+    // does not affect other tests
+    super::set_backend(Backend::Scalar);
+    assert_eq!(Backend::Scalar, super::backend());
+    super::set_backend(backend);
+    assert_eq!(backend, super::backend());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn available_backends() -> Vec<Backend> {
+    [
+        Backend::Scalar,
+        Backend::Portable,
+        Backend::Avx2,
+        Backend::Avx512,
+    ]
+    .into_iter()
+    .filter(|&backend| super::is_supported(backend))
+    .collect()
+}
+
+/// Builds a vector of random values with a few edge cases (zeros and ones) mixed in.
+fn build_values(n: usize) -> Vec<BaseElement> {
+    let mut result = (0..n).map(|_| rand_value()).collect::<Vec<BaseElement>>();
+    for (i, inner) in [0, 1, 0].into_iter().enumerate() {
+        if 3 * i < n {
+            result[3 * i] = BaseElement::from_mont(inner);
+        }
+    }
+    result
+}
+
+/// Asserts that both vectors contain the same values in the same internal representation.
+fn assert_same(expected: &[BaseElement], actual: &[BaseElement], backend: Backend) {
+    let expected = expected.iter().map(|e| e.inner()).collect::<Vec<_>>();
+    let actual = actual.iter().map(|e| e.inner()).collect::<Vec<_>>();
+    assert_eq!(expected, actual, "results differ for {:?} backend", backend);
+}
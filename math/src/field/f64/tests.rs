@@ -217,6 +217,33 @@ fn bytes_as_elements() {
     assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
 }
 
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    let element = BaseElement::new(M - 1);
+
+    // human-readable formats encode elements as decimal strings
+    let json = serde_json::to_string(&element).unwrap();
+    assert_eq!(format!("\"{}\"", M - 1), json);
+    assert_eq!(element, serde_json::from_str(&json).unwrap());
+
+    // binary formats encode elements as integers
+    let cbor = serde_cbor::to_vec(&element).unwrap();
+    assert_eq!(element, serde_cbor::from_slice(&cbor).unwrap());
+
+    // non-canonical values are rejected
+    let json = format!("\"{}\"", M);
+    assert!(serde_json::from_str::<BaseElement>(&json).is_err());
+    let cbor = serde_cbor::to_vec(&M).unwrap();
+    assert!(serde_cbor::from_slice::<BaseElement>(&cbor).is_err());
+
+    // extension elements are encoded as sequences of base field elements
+    let element = QuadExtension::new(BaseElement::new(1), BaseElement::new(2));
+    let json = serde_json::to_string(&element).unwrap();
+    assert_eq!("[\"1\",\"2\"]", json);
+    assert_eq!(element, serde_json::from_str(&json).unwrap());
+}
+
 // INITIALIZATION
 // ------------------------------------------------------------------------------------------------
 
@@ -12,3 +12,6 @@ pub mod f64;
 
 mod extensions;
 pub use extensions::{CubeExtension, QuadExtension};
+
+#[cfg(feature = "serde")]
+mod serde_int;
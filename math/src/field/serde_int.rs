@@ -0,0 +1,46 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Serde helpers for integer representations of field elements.
+//!
+//! When used with a human-readable format (e.g. JSON), integers are encoded as decimal strings
+//! (this avoids precision loss for values exceeding 2^53 in JSON parsers); otherwise (e.g. CBOR),
+//! integers are encoded natively.
+
+use core::{fmt::Display, str::FromStr};
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+use utils::string::String;
+
+/// Serializes the specified integer as a decimal string for human-readable formats, and as a
+/// native integer otherwise.
+pub fn serialize<S, T>(value: T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Display + Serialize,
+{
+    if serializer.is_human_readable() {
+        serializer.collect_str(&value)
+    } else {
+        value.serialize(serializer)
+    }
+}
+
+/// Deserializes an integer from a decimal string for human-readable formats, and from a native
+/// integer otherwise.
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr + Deserialize<'de>,
+    T::Err: Display,
+{
+    if deserializer.is_human_readable() {
+        let value = String::deserialize(deserializer)?;
+        value
+            .parse()
+            .map_err(|err| D::Error::custom(format!("invalid field element '{}': {}", value, err)))
+    } else {
+        T::deserialize(deserializer)
+    }
+}
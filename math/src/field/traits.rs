@@ -183,6 +183,44 @@ pub trait FieldElement:
         vec![Self::ZERO; n]
     }
 
+    /// Computes FFT butterflies for the provided slices; that is, sets `lo[i] = lo[i] + hi[i]`
+    /// and `hi[i] = lo[i] - hi[i]` for all `i`.
+    ///
+    /// Specialized implementations of this function may process several elements at a time
+    /// using vector instructions.
+    #[inline]
+    fn fft_butterflies(lo: &mut [Self], hi: &mut [Self]) {
+        for (a, b) in lo.iter_mut().zip(hi.iter_mut()) {
+            let temp = *a;
+            *a = temp + *b;
+            *b = temp - *b;
+        }
+    }
+
+    /// Computes FFT butterflies with the specified twiddle for the provided slices; that is, sets
+    /// `lo[i] = lo[i] + hi[i] * twiddle` and `hi[i] = lo[i] - hi[i] * twiddle` for all `i`.
+    ///
+    /// Specialized implementations of this function may process several elements at a time
+    /// using vector instructions.
+    #[inline]
+    fn fft_twiddle_butterflies(lo: &mut [Self], hi: &mut [Self], twiddle: Self::BaseField) {
+        for (a, b) in lo.iter_mut().zip(hi.iter_mut()) {
+            let temp = *a;
+            *b = b.mul_base(twiddle);
+            *a = temp + *b;
+            *b = temp - *b;
+        }
+    }
+
+    /// Computes multiplicative inverses of all `values` and writes them into `result`; zero
+    /// values are mapped to zeros.
+    ///
+    /// Specialized implementations of this function may process several elements at a time
+    /// using vector instructions.
+    fn batch_inverse(values: &[Self], result: &mut [Self]) {
+        crate::utils::serial_batch_inversion(values, result);
+    }
+
     /// Converts a list of field elements into a list of elements in the underlying base field.
     ///
     /// For base STARK fields, the input and output lists are the same. For extension field, the
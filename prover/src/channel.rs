@@ -5,7 +5,7 @@
 
 use air::{
     proof::{Commitments, Context, OodFrame, Queries, StarkProof},
-    Air, ConstraintCompositionCoefficients, DeepCompositionCoefficients,
+    write_air_id, Air, ConstraintCompositionCoefficients, DeepCompositionCoefficients,
 };
 use core::marker::PhantomData;
 use crypto::{ElementHasher, RandomCoin};
@@ -46,13 +46,15 @@ where
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Creates a new prover channel for the specified `air` and public inputs.
-    pub fn new(air: &'a A, pub_inputs_bytes: Vec<u8>) -> Self {
+    pub fn new(air: &'a A, pub_inputs_bytes: &[u8]) -> Self {
         let context = Context::new::<A::BaseField>(air.trace_info(), air.options().clone());
 
-        // build a seed for the public coin; the initial seed is the hash of public inputs and proof
-        // context, but as the protocol progresses, the coin will be reseeded with the info sent to
-        // the verifier
-        let mut coin_seed = pub_inputs_bytes;
+        // build a seed for the public coin; the initial seed is the hash of AIR domain separation
+        // tag, public inputs, and proof context, but as the protocol progresses, the coin will be
+        // reseeded with the info sent to the verifier
+        let mut coin_seed = Vec::with_capacity(A::ID.len() + 1 + pub_inputs_bytes.len());
+        write_air_id::<A, _>(&mut coin_seed);
+        coin_seed.extend_from_slice(pub_inputs_bytes);
         context.write_into(&mut coin_seed);
 
         ProverChannel {
@@ -201,7 +201,8 @@ impl<E: FieldElement> BoundaryConstraintGroup<E> {
             if constraint.poly().len() == 1 {
                 let constraint = SingleValueConstraint::new(constraint);
                 result.main_single_value.push(constraint);
-            } else if constraint.poly().len() < SMALL_POLY_DEGREE {
+            } else if !constraint.is_public_column() && constraint.poly().len() < SMALL_POLY_DEGREE
+            {
                 let constraint = SmallPolyConstraint::new(constraint);
                 result.main_small_poly.push(constraint);
             } else {
@@ -256,7 +257,8 @@ impl<E: FieldElement> BoundaryConstraintGroup<E> {
             if constraint.poly().len() == 1 {
                 let constraint = SingleValueConstraint::new(constraint);
                 self.aux_single_value.push(constraint);
-            } else if constraint.poly().len() < SMALL_POLY_DEGREE {
+            } else if !constraint.is_public_column() && constraint.poly().len() < SMALL_POLY_DEGREE
+            {
                 let constraint = SmallPolyConstraint::new(constraint);
                 self.aux_small_poly.push(constraint);
             } else {
@@ -455,18 +457,28 @@ where
         twiddle_map: &mut BTreeMap<usize, Vec<F::BaseField>>,
     ) -> Self {
         debug_assert!(
-            source.poly().len() >= SMALL_POLY_DEGREE,
+            source.is_public_column() || source.poly().len() >= SMALL_POLY_DEGREE,
             "not a large poly constraint"
         );
+        // public column constraints do not come with a value polynomial, so we need to
+        // interpolate it from the public values first
+        let public_poly;
+        let poly = if source.is_public_column() {
+            public_poly = source.interpolate_poly();
+            &public_poly
+        } else {
+            source.poly()
+        };
+
         // evaluate the polynomial over the entire constraint evaluation domain; first
         // get twiddles for the evaluation; if twiddles haven't been built yet, build them
-        let poly_length = source.poly().len();
+        let poly_length = poly.len();
         let twiddles = twiddle_map
             .entry(poly_length)
             .or_insert_with(|| fft::get_twiddles(poly_length));
 
         let values = fft::evaluate_poly_with_offset(
-            source.poly(),
+            poly,
             twiddles,
             air.domain_offset(),
             air.ce_domain_size() / poly_length,
@@ -3,7 +3,7 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use super::{Matrix, StarkDomain};
+use super::{LdeMatrix, Matrix, MatrixLayout, StarkDomain};
 use math::{polynom, FieldElement, StarkField};
 use utils::{collections::Vec, uninit_vector};
 
@@ -57,6 +57,11 @@ impl<E: FieldElement> CompositionPoly<E> {
         self.data.num_cols()
     }
 
+    /// Returns the matrix of column polynomials of this composition polynomial.
+    pub fn data(&self) -> &Matrix<E> {
+        &self.data
+    }
+
     /// Returns the length of individual column polynomials; this is guaranteed to be a power of 2.
     pub fn column_len(&self) -> usize {
         self.data.num_rows()
@@ -71,8 +76,8 @@ impl<E: FieldElement> CompositionPoly<E> {
     // LOW-DEGREE EXTENSION
     // --------------------------------------------------------------------------------------------
     /// Evaluates the columns of the composition polynomial over the specified LDE domain and
-    /// returns the result.
-    pub fn evaluate<B>(&self, domain: &StarkDomain<B>) -> Matrix<E>
+    /// returns the result arranged according to the specified layout.
+    pub fn evaluate<B>(&self, domain: &StarkDomain<B>, layout: MatrixLayout) -> LdeMatrix<E>
     where
         B: StarkField,
         E: FieldElement<BaseField = B>,
@@ -85,7 +90,7 @@ impl<E: FieldElement> CompositionPoly<E> {
             domain.trace_length()
         );
 
-        self.data.evaluate_columns_over(domain)
+        LdeMatrix::evaluate(&self.data, domain, layout)
     }
 
     /// Returns evaluations of all composition polynomial columns at point z^m, where m is
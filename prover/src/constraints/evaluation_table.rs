@@ -33,6 +33,8 @@ pub struct ConstraintEvaluationTable<E: FieldElement> {
     aux_transition_evaluations: Vec<Vec<E>>,
     #[cfg(debug_assertions)]
     expected_transition_degrees: Vec<usize>,
+    #[cfg(debug_assertions)]
+    transition_divisor_indexes: Vec<usize>,
 }
 
 impl<E: FieldElement> ConstraintEvaluationTable<E> {
@@ -74,6 +76,15 @@ impl<E: FieldElement> ConstraintEvaluationTable<E> {
         let expected_transition_degrees =
             build_transition_constraint_degrees(transition_constraints, domain.trace_length());
 
+        // collect divisor indexes for all transition constraints so that we can divide their
+        // evaluations by the right divisor when computing actual degrees
+        let transition_divisor_indexes = transition_constraints
+            .main_constraint_divisor_indexes()
+            .iter()
+            .chain(transition_constraints.aux_constraint_divisor_indexes())
+            .copied()
+            .collect();
+
         ConstraintEvaluationTable {
             evaluations: uninit_matrix(num_columns, num_rows),
             divisors,
@@ -82,6 +93,48 @@ impl<E: FieldElement> ConstraintEvaluationTable<E> {
             main_transition_evaluations: uninit_matrix(num_tm_columns, num_rows),
             aux_transition_evaluations: uninit_matrix(num_ta_columns, num_rows),
             expected_transition_degrees,
+            transition_divisor_indexes,
+        }
+    }
+
+    /// Returns a constraint evaluation table assembled from previously computed constraint
+    /// evaluations; `evaluations` must contain one column per divisor, and each column must
+    /// contain one value per step of the constraint evaluation domain.
+    ///
+    /// This is used when constraints were evaluated in chunks outside of this table (e.g., by
+    /// remote workers). Transition constraint degrees are not validated for such tables.
+    #[cfg(feature = "std")]
+    pub fn from_evaluations(
+        domain: &StarkDomain<E::BaseField>,
+        divisors: Vec<ConstraintDivisor<E::BaseField>>,
+        evaluations: Vec<Vec<E>>,
+    ) -> Self {
+        assert_eq!(
+            divisors.len(),
+            evaluations.len(),
+            "number of evaluation columns must be equal to the number of divisors"
+        );
+        for column in evaluations.iter() {
+            assert_eq!(
+                domain.ce_domain_size(),
+                column.len(),
+                "evaluation columns must span the entire constraint evaluation domain"
+            );
+        }
+
+        ConstraintEvaluationTable {
+            evaluations,
+            divisors,
+            domain_offset: domain.offset(),
+            trace_length: domain.trace_length(),
+            #[cfg(debug_assertions)]
+            main_transition_evaluations: Vec::new(),
+            #[cfg(debug_assertions)]
+            aux_transition_evaluations: Vec::new(),
+            #[cfg(debug_assertions)]
+            expected_transition_degrees: Vec::new(),
+            #[cfg(debug_assertions)]
+            transition_divisor_indexes: Vec::new(),
         }
     }
 
@@ -94,9 +147,9 @@ impl<E: FieldElement> ConstraintEvaluationTable<E> {
         self.evaluations[0].len()
     }
 
-    /// Returns number of columns in this table. The first column always contains the value of
-    /// combined transition constraint evaluations; the remaining columns contain values of
-    /// assertion constraint evaluations combined based on common divisors.
+    /// Returns number of columns in this table. The first columns contain values of transition
+    /// constraint evaluations combined based on common divisors; the remaining columns contain
+    /// values of assertion constraint evaluations combined based on common divisors.
     #[allow(dead_code)]
     pub fn num_columns(&self) -> usize {
         self.evaluations.len()
@@ -161,7 +214,13 @@ impl<E: FieldElement> ConstraintEvaluationTable<E> {
     /// Divides constraint evaluation columns by their respective divisor (in evaluation form),
     /// combines the results into a single column, and interpolates this column into a composition
     /// polynomial in coefficient form.
-    pub fn into_poly(self) -> Result<CompositionPoly<E>, ProverError> {
+    ///
+    /// The interpolation uses inverse twiddles of the constraint evaluation domain provided by
+    /// the specified `domain`; these may be shared across proofs via a domain cache.
+    pub fn into_poly(
+        self,
+        domain: &StarkDomain<E::BaseField>,
+    ) -> Result<CompositionPoly<E>, ProverError> {
         let domain_offset = self.domain_offset;
 
         // allocate memory for the combined polynomial
@@ -182,8 +241,11 @@ impl<E: FieldElement> ConstraintEvaluationTable<E> {
 
         // at this point, combined_poly contains evaluations of the combined constraint polynomial;
         // we interpolate this polynomial to transform it into coefficient form.
-        let inv_twiddles = fft::get_inv_twiddles::<E::BaseField>(combined_poly.len());
-        fft::interpolate_poly_with_offset(&mut combined_poly, &inv_twiddles, domain_offset);
+        fft::interpolate_poly_with_offset(
+            &mut combined_poly,
+            domain.ce_inv_twiddles(),
+            domain_offset,
+        );
 
         Ok(CompositionPoly::new(combined_poly, self.trace_length))
     }
@@ -193,14 +255,20 @@ impl<E: FieldElement> ConstraintEvaluationTable<E> {
 
     #[cfg(debug_assertions)]
     pub fn validate_transition_degrees(&mut self) {
-        // evaluate transition constraint divisor (which is assumed to be the first one in the
-        // divisor list) over the constraint evaluation domain. this is used later to compute
+        // evaluate transition constraint divisors (which are assumed to be at the front of the
+        // divisor list) over the constraint evaluation domain. these are used later to compute
         // actual degrees of transition constraint evaluations.
-        let div_values = evaluate_divisor::<E::BaseField>(
-            &self.divisors[0],
-            self.num_rows(),
-            self.domain_offset,
-        );
+        let num_transition_divisors = self
+            .transition_divisor_indexes
+            .iter()
+            .max()
+            .map_or(0, |&idx| idx + 1);
+        let div_values = self.divisors[..num_transition_divisors]
+            .iter()
+            .map(|divisor| {
+                evaluate_divisor::<E::BaseField>(divisor, self.num_rows(), self.domain_offset)
+            })
+            .collect::<Vec<_>>();
 
         // collect actual degrees for all transition constraints by interpolating saved
         // constraint evaluations into polynomials and checking their degree; also
@@ -210,15 +278,18 @@ impl<E: FieldElement> ConstraintEvaluationTable<E> {
         let inv_twiddles = fft::get_inv_twiddles::<E::BaseField>(self.num_rows());
 
         // first process transition constraint evaluations for the main trace segment
+        let mut divisor_indexes = self.transition_divisor_indexes.iter();
         for evaluations in self.main_transition_evaluations.iter() {
-            let degree = get_transition_poly_degree(evaluations, &inv_twiddles, &div_values);
+            let div_values = &div_values[*divisor_indexes.next().unwrap()];
+            let degree = get_transition_poly_degree(evaluations, &inv_twiddles, div_values);
             actual_degrees.push(degree);
             max_degree = core::cmp::max(max_degree, degree);
         }
 
         // then process transition constraint evaluations for auxiliary trace segments
         for evaluations in self.aux_transition_evaluations.iter() {
-            let degree = get_transition_poly_degree(evaluations, &inv_twiddles, &div_values);
+            let div_values = &div_values[*divisor_indexes.next().unwrap()];
+            let degree = get_transition_poly_degree(evaluations, &inv_twiddles, div_values);
             actual_degrees.push(degree);
             max_degree = core::cmp::max(max_degree, degree);
         }
@@ -296,6 +367,87 @@ impl<'a, E: FieldElement> EvaluationTableFragment<'a, E> {
     }
 }
 
+// EVALUATION TABLE CHUNK
+// ================================================================================================
+
+/// Constraint evaluations for a contiguous range of rows of a constraint evaluation table.
+///
+/// Unlike a table fragment, a chunk owns its data; thus, it can be evaluated independently of
+/// the table (e.g., by a remote worker), and the evaluations of all chunks can then be assembled
+/// into a table via [ConstraintEvaluationTable::from_evaluations()].
+#[cfg(feature = "std")]
+pub struct EvaluationTableChunk<E: FieldElement> {
+    offset: usize,
+    evaluations: Vec<Vec<E>>,
+
+    #[cfg(debug_assertions)]
+    tm_evaluations: Vec<Vec<E::BaseField>>,
+    #[cfg(debug_assertions)]
+    ta_evaluations: Vec<Vec<E>>,
+}
+
+#[cfg(feature = "std")]
+impl<E: FieldElement> EvaluationTableChunk<E> {
+    /// Returns a new chunk of `num_rows` rows starting at row `offset` of a constraint evaluation
+    /// table with the specified number of columns.
+    #[cfg(not(debug_assertions))]
+    pub fn new(offset: usize, num_rows: usize, num_columns: usize) -> Self {
+        Self {
+            offset,
+            evaluations: uninit_matrix(num_columns, num_rows),
+        }
+    }
+
+    /// Similar to the above constructor but used in debug mode. In debug mode, fragments also
+    /// record individual transition constraint evaluations, and thus, a chunk needs to allocate
+    /// memory for them as well.
+    #[cfg(debug_assertions)]
+    pub fn new(
+        offset: usize,
+        num_rows: usize,
+        num_columns: usize,
+        transition_constraints: &TransitionConstraints<E>,
+    ) -> Self {
+        let num_tm_columns = transition_constraints.num_main_constraints();
+        let num_ta_columns = transition_constraints.num_aux_constraints();
+        Self {
+            offset,
+            evaluations: uninit_matrix(num_columns, num_rows),
+            tm_evaluations: uninit_matrix(num_tm_columns, num_rows),
+            ta_evaluations: uninit_matrix(num_ta_columns, num_rows),
+        }
+    }
+
+    /// Returns a fragment which can be used to update all rows of this chunk.
+    pub fn fragment(&mut self) -> EvaluationTableFragment<'_, E> {
+        EvaluationTableFragment {
+            offset: self.offset,
+            evaluations: self
+                .evaluations
+                .iter_mut()
+                .map(|c| c.as_mut_slice())
+                .collect(),
+            #[cfg(debug_assertions)]
+            tm_evaluations: self
+                .tm_evaluations
+                .iter_mut()
+                .map(|c| c.as_mut_slice())
+                .collect(),
+            #[cfg(debug_assertions)]
+            ta_evaluations: self
+                .ta_evaluations
+                .iter_mut()
+                .map(|c| c.as_mut_slice())
+                .collect(),
+        }
+    }
+
+    /// Returns the columns of constraint evaluations contained in this chunk.
+    pub fn into_columns(self) -> Vec<Vec<E>> {
+        self.evaluations
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -376,7 +528,7 @@ fn acc_column<E: FieldElement>(
                     let e = divisor.evaluate_exemptions_at(x);
                     x *= g;
                     // determine which value of z corresponds to the current domain point
-                    let z = z[i % z.len()];
+                    let z = z[(batch_offset + i) % z.len()];
                     // compute value * e(x) * z and add it to the result
                     *acc_value += column[batch_offset + i].mul_base(z * e);
                 }
@@ -423,9 +575,9 @@ fn get_inv_evaluation<B: StarkField>(
 /// Returns evaluation degrees of all transition constraints.
 ///
 /// An evaluation degree is defined as degree of transition constraints in the context of a given
-/// execution trace accounting for constraint divisor degree. For most constraints, this degree is
-/// computed as `([trace_length - 1] * [constraint degree]) - [divisor degree]`. However, for
-/// constraints which rely on periodic columns this computation is slightly more complex.
+/// execution trace accounting for degree of the constraint's divisor. For most constraints, this
+/// degree is computed as `([trace_length - 1] * [constraint degree]) - [divisor degree]`. However,
+/// for constraints which rely on periodic columns this computation is slightly more complex.
 ///
 /// The general idea is that evaluation degree is the degree of rational function `C(x) / z(x)`,
 /// where `C(x)` is the constraint polynomial and `z(x)` is the divisor polynomial.
@@ -435,13 +587,22 @@ fn build_transition_constraint_degrees<E: FieldElement>(
     trace_length: usize,
 ) -> Vec<usize> {
     let mut result = Vec::new();
+    let divisors = constraints.divisors();
 
-    for degree in constraints.main_constraint_degrees() {
-        result.push(degree.get_evaluation_degree(trace_length) - constraints.divisor().degree())
+    let main_degrees = constraints
+        .main_constraint_degrees()
+        .iter()
+        .zip(constraints.main_constraint_divisor_indexes());
+    for (degree, &divisor_idx) in main_degrees {
+        result.push(degree.get_evaluation_degree(trace_length) - divisors[divisor_idx].degree())
     }
 
-    for degree in constraints.aux_constraint_degrees() {
-        result.push(degree.get_evaluation_degree(trace_length) - constraints.divisor().degree())
+    let aux_degrees = constraints
+        .aux_constraint_degrees()
+        .iter()
+        .zip(constraints.aux_constraint_divisor_indexes());
+    for (degree, &divisor_idx) in aux_degrees {
+        result.push(degree.get_evaluation_degree(trace_length) - divisors[divisor_idx].degree())
     }
 
     result
@@ -4,15 +4,20 @@
 // LICENSE file in the root directory of this source tree.
 
 use super::{
-    super::TraceLde, evaluation_table::EvaluationTableFragment, BoundaryConstraints,
-    ConstraintEvaluationTable, PeriodicValueTable, StarkDomain,
+    super::{TraceFrameReader, TraceLde},
+    evaluation_table::EvaluationTableFragment,
+    BoundaryConstraints, ConstraintDivisor, ConstraintEvaluationTable, PeriodicValueTable,
+    StarkDomain,
 };
 use air::{
     Air, AuxTraceRandElements, ConstraintCompositionCoefficients, EvaluationFrame,
     TransitionConstraints,
 };
 use math::FieldElement;
-use utils::iter_mut;
+use utils::{collections::Vec, iter_mut};
+
+#[cfg(feature = "std")]
+use super::evaluation_table::EvaluationTableChunk;
 
 #[cfg(feature = "concurrent")]
 use utils::{iterators::*, rayon};
@@ -32,6 +37,7 @@ pub struct ConstraintEvaluator<'a, A: Air, E: FieldElement<BaseField = A::BaseFi
     transition_constraints: TransitionConstraints<E>,
     aux_rand_elements: AuxTraceRandElements<E>,
     periodic_values: PeriodicValueTable<E::BaseField>,
+    aux_periodic_values: PeriodicValueTable<E>,
 }
 
 impl<'a, A: Air, E: FieldElement<BaseField = A::BaseField>> ConstraintEvaluator<'a, A, E> {
@@ -49,8 +55,10 @@ impl<'a, A: Air, E: FieldElement<BaseField = A::BaseField>> ConstraintEvaluator<
         let transition_constraints =
             air.get_transition_constraints(&composition_coefficients.transition);
 
-        // build periodic value table
+        // build periodic value tables; values of auxiliary periodic columns may depend on
+        // random elements used to build auxiliary trace segments
         let periodic_values = PeriodicValueTable::new(air);
+        let aux_periodic_values = PeriodicValueTable::new_aux(air, &aux_rand_elements);
 
         // build boundary constraint groups; these will be used to evaluate and compose boundary
         // constraint evaluations.
@@ -63,6 +71,7 @@ impl<'a, A: Air, E: FieldElement<BaseField = A::BaseField>> ConstraintEvaluator<
             transition_constraints,
             aux_rand_elements,
             periodic_values,
+            aux_periodic_values,
         }
     }
 
@@ -82,11 +91,9 @@ impl<'a, A: Air, E: FieldElement<BaseField = A::BaseField>> ConstraintEvaluator<
             "extended trace length is not consistent with evaluation domain"
         );
 
-        // build a list of constraint divisors; currently, all transition constraints have the same
-        // divisor which we put at the front of the list; boundary constraint divisors are appended
-        // after that
-        let mut divisors = vec![self.transition_constraints.divisor().clone()];
-        divisors.append(&mut self.boundary_constraints.get_divisors());
+        // build a list of constraint divisors; transition constraint divisors are put at the
+        // front of the list, and boundary constraint divisors are appended after that
+        let divisors = self.divisors();
 
         // allocate space for constraint evaluations; when we are in debug mode, we also allocate
         // memory to hold all transition constraint evaluations (before they are merged into a
@@ -131,15 +138,61 @@ impl<'a, A: Air, E: FieldElement<BaseField = A::BaseField>> ConstraintEvaluator<
         evaluation_table
     }
 
+    /// Evaluates constraints over `num_rows` steps of the constraint evaluation domain starting
+    /// with step `offset`, and returns the results as columns of the constraint evaluation table
+    /// (one column per divisor).
+    ///
+    /// The provided `trace` needs to be able to supply evaluation frames only for the steps
+    /// being evaluated. Thus, the table can be evaluated in chunks (e.g., by different machines)
+    /// which are then assembled via [ConstraintEvaluationTable::from_evaluations()].
+    #[cfg(feature = "std")]
+    pub fn evaluate_rows<T: TraceFrameReader<E>>(
+        &self,
+        trace: &T,
+        domain: &StarkDomain<E::BaseField>,
+        offset: usize,
+        num_rows: usize,
+    ) -> Vec<Vec<E>> {
+        let num_columns = self.divisors().len();
+
+        #[cfg(not(debug_assertions))]
+        let mut chunk = EvaluationTableChunk::<E>::new(offset, num_rows, num_columns);
+        #[cfg(debug_assertions)]
+        let mut chunk = EvaluationTableChunk::<E>::new(
+            offset,
+            num_rows,
+            num_columns,
+            &self.transition_constraints,
+        );
+
+        let mut fragment = chunk.fragment();
+        if self.air.trace_info().is_multi_segment() {
+            self.evaluate_fragment_full(trace, domain, &mut fragment);
+        } else {
+            self.evaluate_fragment_main(trace, domain, &mut fragment);
+        }
+
+        chunk.into_columns()
+    }
+
+    /// Returns divisors of all constraints evaluated by this evaluator; transition constraint
+    /// divisors are put at the front of the list, and boundary constraint divisors are appended
+    /// after that.
+    pub fn divisors(&self) -> Vec<ConstraintDivisor<E::BaseField>> {
+        let mut divisors = self.transition_constraints.divisors().to_vec();
+        divisors.append(&mut self.boundary_constraints.get_divisors());
+        divisors
+    }
+
     // EVALUATION HELPERS
     // --------------------------------------------------------------------------------------------
 
     /// Evaluates constraints for a single fragment of the evaluation table.
     ///
     /// This evaluates constraints only over the main segment of the execution trace.
-    fn evaluate_fragment_main(
+    fn evaluate_fragment_main<T: TraceFrameReader<E>>(
         &self,
-        trace: &TraceLde<E>,
+        trace: &T,
         domain: &StarkDomain<A::BaseField>,
         fragment: &mut EvaluationTableFragment<E>,
     ) {
@@ -165,20 +218,22 @@ impl<'a, A: Air, E: FieldElement<BaseField = A::BaseField>> ConstraintEvaluator<
             // evaluation domain, into a step in LDE domain, in case these domains are different
             trace.read_main_trace_frame_into(step << lde_shift, &mut main_frame);
 
-            // evaluate transition constraints and save the merged result the first slot of the
-            // evaluations buffer
-            evaluations[0] =
-                self.evaluate_main_transition(&main_frame, x, step, &mut t_evaluations);
+            // evaluate transition constraints and save the merged results into the first slots
+            // of the evaluations buffer (one slot per transition constraint divisor)
+            let (t_merged, b_evaluations) =
+                evaluations.split_at_mut(self.num_transition_divisors());
+            t_merged.fill(E::ZERO);
+            self.evaluate_main_transition(&main_frame, x, step, &mut t_evaluations, t_merged);
 
             // when in debug mode, save transition constraint evaluations
             #[cfg(debug_assertions)]
-            fragment.update_transition_evaluations(step, &t_evaluations, &[]);
+            fragment.update_transition_evaluations(i, &t_evaluations, &[]);
 
             // evaluate boundary constraints; the results go into remaining slots of the
             // evaluations buffer
             let main_state = main_frame.current();
             self.boundary_constraints
-                .evaluate_main(main_state, x, step, &mut evaluations[1..]);
+                .evaluate_main(main_state, x, step, b_evaluations);
 
             // record the result in the evaluation table
             fragment.update_row(i, &evaluations);
@@ -192,9 +247,9 @@ impl<'a, A: Air, E: FieldElement<BaseField = A::BaseField>> ConstraintEvaluator<
     ///
     /// This evaluates constraints only over all segments of the execution trace (i.e. main segment
     /// and all auxiliary segments).
-    fn evaluate_fragment_full(
+    fn evaluate_fragment_full<T: TraceFrameReader<E>>(
         &self,
-        trace: &TraceLde<E>,
+        trace: &T,
         domain: &StarkDomain<A::BaseField>,
         fragment: &mut EvaluationTableFragment<E>,
     ) {
@@ -220,29 +275,33 @@ impl<'a, A: Air, E: FieldElement<BaseField = A::BaseField>> ConstraintEvaluator<
             trace.read_main_trace_frame_into(step << lde_shift, &mut main_frame);
             trace.read_aux_trace_frame_into(step << lde_shift, &mut aux_frame);
 
-            // evaluate transition constraints and save the merged result the first slot of the
-            // evaluations buffer; we evaluate and compose constraints in the same function, we
-            // can just add up the results of evaluating main and auxiliary constraints.
-            evaluations[0] =
-                self.evaluate_main_transition(&main_frame, x, step, &mut tm_evaluations);
-            evaluations[0] +=
-                self.evaluate_aux_transition(&main_frame, &aux_frame, x, step, &mut ta_evaluations);
+            // evaluate transition constraints and save the merged results into the first slots
+            // of the evaluations buffer (one slot per transition constraint divisor); we evaluate
+            // and compose constraints in the same function, so we can just add up the results of
+            // evaluating main and auxiliary constraints.
+            let (t_merged, b_evaluations) =
+                evaluations.split_at_mut(self.num_transition_divisors());
+            t_merged.fill(E::ZERO);
+            self.evaluate_main_transition(&main_frame, x, step, &mut tm_evaluations, t_merged);
+            self.evaluate_aux_transition(
+                &main_frame,
+                &aux_frame,
+                x,
+                step,
+                &mut ta_evaluations,
+                t_merged,
+            );
 
             // when in debug mode, save transition constraint evaluations
             #[cfg(debug_assertions)]
-            fragment.update_transition_evaluations(step, &tm_evaluations, &ta_evaluations);
+            fragment.update_transition_evaluations(i, &tm_evaluations, &ta_evaluations);
 
             // evaluate boundary constraints; the results go into remaining slots of the
             // evaluations buffer
             let main_state = main_frame.current();
             let aux_state = aux_frame.current();
-            self.boundary_constraints.evaluate_all(
-                main_state,
-                aux_state,
-                x,
-                step,
-                &mut evaluations[1..],
-            );
+            self.boundary_constraints
+                .evaluate_all(main_state, aux_state, x, step, b_evaluations);
 
             // record the result in the evaluation table
             fragment.update_row(i, &evaluations);
@@ -260,6 +319,9 @@ impl<'a, A: Air, E: FieldElement<BaseField = A::BaseField>> ConstraintEvaluator<
     ///
     /// `x` is the corresponding domain value at the specified step. That is, x = s * g^step,
     /// where g is the generator of the constraint evaluation domain, and s is the domain offset.
+    ///
+    /// Merged evaluations of constraints sharing the same divisor are added to the slot of
+    /// `result` corresponding to that divisor.
     #[rustfmt::skip]
     fn evaluate_main_transition(
         &self,
@@ -267,7 +329,8 @@ impl<'a, A: Air, E: FieldElement<BaseField = A::BaseField>> ConstraintEvaluator<
         x: E::BaseField,
         step: usize,
         evaluations: &mut [E::BaseField],
-    ) -> E {
+        result: &mut [E],
+    ) {
         // TODO: use a more efficient way to zero out memory
         evaluations.fill(E::BaseField::ZERO);
 
@@ -278,11 +341,11 @@ impl<'a, A: Air, E: FieldElement<BaseField = A::BaseField>> ConstraintEvaluator<
         // the results into evaluations buffer
         self.air.evaluate_transition(main_frame, periodic_values, evaluations);
 
-        // merge transition constraint evaluations into a single value and return it;
-        // we can do this here because all transition constraints have the same divisor.
-        self.transition_constraints.main_constraints().iter().fold(E::ZERO, |result, group| {
-            result + group.merge_evaluations(evaluations, x)
-        })
+        // merge transition constraint evaluations into a single value per divisor; we can do
+        // this here because all constraints merged into the same value have the same divisor.
+        for group in self.transition_constraints.main_constraints().iter() {
+            result[group.divisor_index()] += group.merge_evaluations(evaluations, x);
+        }
     }
 
     /// Evaluates all transition constraints (i.e., for main and auxiliary trace segments) at the
@@ -290,6 +353,9 @@ impl<'a, A: Air, E: FieldElement<BaseField = A::BaseField>> ConstraintEvaluator<
     ///
     /// `x` is the corresponding domain value at the specified step. That is, x = s * g^step,
     /// where g is the generator of the constraint evaluation domain, and s is the domain offset.
+    ///
+    /// Merged evaluations of constraints sharing the same divisor are added to the slot of
+    /// `result` corresponding to that divisor.
     #[rustfmt::skip]
     fn evaluate_aux_transition(
         &self,
@@ -298,28 +364,32 @@ impl<'a, A: Air, E: FieldElement<BaseField = A::BaseField>> ConstraintEvaluator<
         x: E::BaseField,
         step: usize,
         evaluations: &mut [E],
-    ) -> E {
+        result: &mut [E],
+    ) {
         // TODO: use a more efficient way to zero out memory
         evaluations.fill(E::ZERO);
 
         // get periodic values at the evaluation step
         let periodic_values = self.periodic_values.get_row(step);
+        let aux_periodic_values = self.aux_periodic_values.get_row(step);
 
         // evaluate transition constraints over auxiliary trace segments and save the results into
         // evaluations buffer
-        self.air.evaluate_aux_transition(
+        self.air.evaluate_aux_transition_with_periodic_values(
             main_frame,
             aux_frame,
             periodic_values,
+            aux_periodic_values,
             &self.aux_rand_elements,
             evaluations,
         );
 
-        // merge transition constraint evaluations into a single value and return it;
-        // we can do this here because all transition constraints have the same divisor.
-        self.transition_constraints.aux_constraints().iter().fold(E::ZERO, |result, group| {
-            result + group.merge_evaluations::<E::BaseField, E>(evaluations, x)
-        })
+        // merge transition constraint evaluations into a single value per divisor; we can do
+        // this here because all constraints merged into the same value have the same divisor.
+        for group in self.transition_constraints.aux_constraints().iter() {
+            result[group.divisor_index()] +=
+                group.merge_evaluations::<E::BaseField, E>(evaluations, x);
+        }
     }
 
     // ACCESSORS
@@ -335,4 +405,9 @@ impl<'a, A: Air, E: FieldElement<BaseField = A::BaseField>> ConstraintEvaluator<
     fn num_aux_transition_constraints(&self) -> usize {
         self.transition_constraints.num_aux_constraints()
     }
+
+    /// Returns the number of distinct divisors of transition constraints.
+    fn num_transition_divisors(&self) -> usize {
+        self.transition_constraints.divisors().len()
+    }
 }
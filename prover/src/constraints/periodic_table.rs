@@ -3,15 +3,15 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use air::Air;
-use math::{fft, StarkField};
+use air::{Air, AuxTraceRandElements};
+use math::{fft, FieldElement, StarkField};
 use utils::{
     collections::{BTreeMap, Vec},
     uninit_vector,
 };
 
-pub struct PeriodicValueTable<B: StarkField> {
-    values: Vec<B>,
+pub struct PeriodicValueTable<E: FieldElement> {
+    values: Vec<E>,
     length: usize,
     width: usize,
 }
@@ -23,9 +23,31 @@ impl<B: StarkField> PeriodicValueTable<B> {
     /// values of all periodic columns normalized to the same length. This enables simple lookup
     /// into the able using step index of the constraint evaluation domain.
     pub fn new<A: Air<BaseField = B>>(air: &A) -> PeriodicValueTable<B> {
-        // get a list of polynomials describing periodic columns from AIR. if there are no
-        // periodic columns return an empty table
-        let polys = air.get_periodic_column_polys();
+        Self::from_polys(air, air.get_periodic_column_polys())
+    }
+}
+
+impl<E: FieldElement> PeriodicValueTable<E> {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds a table of auxiliary periodic column values for the specified AIR. This is similar
+    /// to the table built by [PeriodicValueTable::new()], but the values are defined over the
+    /// field `E` and may depend on the provided random elements.
+    pub fn new_aux<A: Air<BaseField = E::BaseField>>(
+        air: &A,
+        aux_rand_elements: &AuxTraceRandElements<E>,
+    ) -> PeriodicValueTable<E> {
+        Self::from_polys(air, air.get_aux_periodic_column_polys(aux_rand_elements))
+    }
+
+    /// Builds a table of periodic column values by evaluating the provided polynomials over the
+    /// constraint evaluation domain of the specified AIR.
+    fn from_polys<A: Air<BaseField = E::BaseField>>(
+        air: &A,
+        polys: Vec<Vec<E>>,
+    ) -> PeriodicValueTable<E> {
+        // if there are no periodic columns return an empty table
         if polys.is_empty() {
             return PeriodicValueTable {
                 values: Vec::new(),
@@ -81,7 +103,7 @@ impl<B: StarkField> PeriodicValueTable<B> {
         self.width == 0
     }
 
-    pub fn get_row(&self, ce_step: usize) -> &[B] {
+    pub fn get_row(&self, ce_step: usize) -> &[E] {
         if self.is_empty() {
             &[]
         } else {
@@ -97,10 +119,10 @@ impl<B: StarkField> PeriodicValueTable<B> {
 #[cfg(test)]
 mod tests {
     use crate::tests::MockAir;
-    use air::Air;
+    use air::{Air, AuxTraceRandElements};
     use math::{
-        fields::f128::BaseElement, get_power_series_with_offset, log2, polynom, FieldElement,
-        StarkField,
+        fields::{f128::BaseElement, QuadExtension},
+        get_power_series_with_offset, log2, polynom, ExtensionOf, FieldElement, StarkField,
     };
     use utils::collections::Vec;
 
@@ -154,6 +176,50 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn aux_periodic_value_table() {
+        let trace_length = 32;
+
+        // instantiate AIR with 2 periodic columns and 2 auxiliary periodic columns
+        let col1 = vec![1u128, 2]
+            .into_iter()
+            .map(BaseElement::new)
+            .collect::<Vec<_>>();
+        let col2 = vec![3u128, 4, 5, 6, 7, 8, 9, 10]
+            .into_iter()
+            .map(BaseElement::new)
+            .collect::<Vec<_>>();
+        let air = MockAir::with_aux_periodic_columns(vec![col1, col2], trace_length);
+
+        // auxiliary periodic columns are defined over the extension field
+        let r = QuadExtension::new(BaseElement::new(11), BaseElement::new(12));
+        let mut aux_rand_elements = AuxTraceRandElements::new();
+        aux_rand_elements.add_segment_elements(vec![r]);
+
+        let table = super::PeriodicValueTable::new(&air);
+        let aux_table = super::PeriodicValueTable::new_aux(&air, &aux_rand_elements);
+        assert_eq!(2, aux_table.width);
+        assert_eq!(8 * air.ce_blowup_factor(), aux_table.length);
+
+        // since auxiliary periodic columns are periodic columns multiplied by r, their values
+        // over the constraint evaluation domain must also be the values of periodic columns
+        // multiplied by r
+        for i in 0..air.ce_domain_size() {
+            let expected = table
+                .get_row(i)
+                .iter()
+                .map(|&v| r.mul_base(v))
+                .collect::<Vec<_>>();
+            assert_eq!(expected, aux_table.get_row(i));
+        }
+
+        // AIRs without auxiliary periodic columns produce an empty table
+        let air = MockAir::with_trace_length(trace_length);
+        let aux_table = super::PeriodicValueTable::new_aux(&air, &aux_rand_elements);
+        assert!(aux_table.is_empty());
+        assert!(aux_table.get_row(3).is_empty());
+    }
+
     fn build_ce_domain(domain_size: usize, domain_offset: BaseElement) -> Vec<BaseElement> {
         let g = BaseElement::get_root_of_unity(log2(domain_size));
         get_power_series_with_offset(g, domain_offset, domain_size)
@@ -0,0 +1,315 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{
+    messages::{
+        decode_response, extension_degree, read_columns, CommitToRowsRequest,
+        EvaluateConstraintsRequest, ExtendColumnsRequest, TaskHeader, TaskKind,
+    },
+    Transport,
+};
+use crate::{
+    constraints::{ConstraintEvaluationTable, ConstraintEvaluator},
+    trace::TraceLde,
+    LdeMatrix, Matrix, MatrixLayout, ProverError, RowMatrix, StarkDomain,
+};
+use air::{
+    proof::Context, Air, AuxTraceRandElements, ConstraintCompositionCoefficients, ProofOptions,
+};
+use core::ops::Range;
+use crypto::{ElementHasher, MerkleTree};
+use math::FieldElement;
+use std::vec::Vec;
+use utils::{ByteWriter, Deserializable, Serializable, SliceReader};
+
+// LOW-DEGREE EXTENSION
+// ================================================================================================
+
+/// Interpolates columns of the execution trace into polynomials and evaluates them over the LDE
+/// domain by splitting the columns between the workers of the `transport`.
+///
+/// Returns the trace polynomials and the extended trace arranged according to `layout`.
+pub fn extend_trace<E: FieldElement>(
+    transport: &dyn Transport,
+    options: &ProofOptions,
+    trace: &Matrix<E>,
+    domain: &StarkDomain<E::BaseField>,
+    layout: MatrixLayout,
+) -> Result<(Matrix<E>, LdeMatrix<E>), ProverError> {
+    let (polys, lde) = extend_columns(transport, options, trace, domain, true)?;
+    Ok((Matrix::new(polys), build_lde_matrix(lde, layout)))
+}
+
+/// Evaluates polynomials contained in the columns of `polys` over the LDE domain by splitting
+/// the columns between the workers of the `transport`.
+pub fn extend_polys<E: FieldElement>(
+    transport: &dyn Transport,
+    options: &ProofOptions,
+    polys: &Matrix<E>,
+    domain: &StarkDomain<E::BaseField>,
+    layout: MatrixLayout,
+) -> Result<LdeMatrix<E>, ProverError> {
+    let (_, lde) = extend_columns(transport, options, polys, domain, false)?;
+    Ok(build_lde_matrix(lde, layout))
+}
+
+#[allow(clippy::type_complexity)]
+fn extend_columns<E: FieldElement>(
+    transport: &dyn Transport,
+    options: &ProofOptions,
+    columns: &Matrix<E>,
+    domain: &StarkDomain<E::BaseField>,
+    interpolate: bool,
+) -> Result<(Vec<Vec<E>>, Vec<Vec<E>>), ProverError> {
+    // assign contiguous ranges of columns to workers
+    let num_tasks = num_workers(transport)?.min(columns.num_cols());
+    let requests = split_range(columns.num_cols(), num_tasks)
+        .map(|range| {
+            let mut request = new_request::<E>(TaskKind::ExtendColumns, options);
+            let columns = range.map(|i| columns.get_column(i)).collect::<Vec<_>>();
+            ExtendColumnsRequest::write(domain, interpolate, &columns, &mut request);
+            request
+        })
+        .collect();
+
+    let mut polys = Vec::with_capacity(columns.num_cols());
+    let mut lde = Vec::with_capacity(columns.num_cols());
+    for (worker_idx, response) in execute(transport, requests)?.iter().enumerate() {
+        let mut source = SliceReader::new(decode_response(worker_idx, response)?);
+        if interpolate {
+            polys.append(&mut read_columns(&mut source).map_err(|e| malformed(worker_idx, e))?);
+        }
+        lde.append(&mut read_columns(&mut source).map_err(|e| malformed(worker_idx, e))?);
+    }
+
+    if lde.len() != columns.num_cols()
+        || lde.iter().any(|c| c.len() != domain.lde_domain_size())
+        || polys.iter().any(|c| c.len() != domain.trace_length())
+    {
+        return Err(ProverError::DistributedTaskFailed(
+            "extended columns returned by workers have unexpected shape".into(),
+        ));
+    }
+    Ok((polys, lde))
+}
+
+// COMMITMENTS
+// ================================================================================================
+
+/// Builds a commitment to the rows of the specified matrix by assigning contiguous ranges of
+/// rows to the workers of the `transport`; each worker builds a Merkle subtree over its rows, and
+/// the subtrees are then joined into a single Merkle tree.
+///
+/// The resulting tree is the same as the one returned by [LdeMatrix::commit_to_rows()].
+pub fn commit_to_rows<E, H>(
+    transport: &dyn Transport,
+    options: &ProofOptions,
+    matrix: &LdeMatrix<E>,
+) -> Result<MerkleTree<H>, ProverError>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+{
+    // subtrees can be joined only if there is a power of two of them, and each subtree must
+    // contain at least two leaves
+    let num_rows = matrix.num_rows();
+    let num_subtrees = prev_power_of_two(num_workers(transport)?.min(num_rows / 2));
+    let subtree_size = num_rows / num_subtrees;
+
+    let requests = (0..num_subtrees)
+        .map(|i| {
+            let mut rows = unsafe { utils::uninit_vector(subtree_size * matrix.num_cols()) };
+            for (j, row) in rows.chunks_mut(matrix.num_cols()).enumerate() {
+                matrix.read_row_into(i * subtree_size + j, row);
+            }
+            let mut request = new_request::<E>(TaskKind::CommitToRows, options);
+            CommitToRowsRequest::write(matrix.num_cols(), &rows, &mut request);
+            request
+        })
+        .collect();
+
+    let mut subtrees = Vec::with_capacity(num_subtrees);
+    for (worker_idx, response) in execute(transport, requests)?.iter().enumerate() {
+        let mut source = SliceReader::new(decode_response(worker_idx, response)?);
+        let subtree =
+            MerkleTree::<H>::read_from(&mut source).map_err(|e| malformed(worker_idx, e))?;
+        if subtree.leaves().len() != subtree_size {
+            return Err(malformed(worker_idx, "unexpected number of leaves"));
+        }
+        subtrees.push(subtree);
+    }
+
+    MerkleTree::from_subtrees(subtrees)
+        .map_err(|err| ProverError::DistributedTaskFailed(err.to_string()))
+}
+
+// CONSTRAINT EVALUATION
+// ================================================================================================
+
+/// Evaluates constraints of the specified AIR over the constraint evaluation domain by
+/// assigning contiguous ranges of the domain to the workers of the `transport`.
+///
+/// Every worker receives the rows of the extended trace needed to evaluate constraints over its
+/// range together with the public inputs of the computation; the latter are used to instantiate
+/// the AIR on the worker. Transition constraint degrees are not validated in debug mode.
+pub fn evaluate_constraints<A, E>(
+    transport: &dyn Transport,
+    air: &A,
+    pub_inputs_bytes: &[u8],
+    aux_rand_elements: AuxTraceRandElements<E>,
+    composition_coefficients: ConstraintCompositionCoefficients<E>,
+    trace: &TraceLde<E>,
+    domain: &StarkDomain<E::BaseField>,
+) -> Result<ConstraintEvaluationTable<E>, ProverError>
+where
+    A: Air,
+    E: FieldElement<BaseField = A::BaseField>,
+{
+    // build the part of the request which is the same for all workers
+    let mut common = new_request::<E>(TaskKind::EvaluateConstraints, air.options());
+    let context = Context::new::<A::BaseField>(air.trace_info(), air.options().clone());
+    let num_aux_segments = air.trace_layout().num_aux_segments();
+    let rand_elements = (0..num_aux_segments)
+        .map(|i| aux_rand_elements.get_segment_elements(i))
+        .collect::<Vec<_>>();
+    EvaluateConstraintsRequest::write_common(
+        &context,
+        pub_inputs_bytes,
+        &rand_elements,
+        &composition_coefficients,
+        &mut common,
+    );
+
+    // the next row of an evaluation frame is located trace_to_ce_blowup steps after the current
+    // row in the constraint evaluation domain; thus, every worker needs this many extra rows
+    let ce_domain_size = domain.ce_domain_size();
+    let lde_shift = domain.ce_to_lde_blowup().trailing_zeros();
+    let frame_distance = domain.trace_to_ce_blowup();
+    let main_width = air.trace_layout().main_trace_width();
+    let aux_width = air.trace_layout().aux_trace_width();
+
+    let num_tasks = num_workers(transport)?.min(ce_domain_size);
+    let ranges = split_range(ce_domain_size, num_tasks).collect::<Vec<_>>();
+    let requests = ranges
+        .iter()
+        .map(|range| {
+            let num_window_rows = range.len() + frame_distance;
+            let mut main_rows = vec![E::BaseField::ZERO; num_window_rows * main_width];
+            let mut aux_rows = vec![E::ZERO; num_window_rows * aux_width];
+            for (i, step) in (range.start..range.end + frame_distance).enumerate() {
+                let lde_step = (step % ce_domain_size) << lde_shift;
+                trace.get_main_segment().read_row_into(
+                    lde_step,
+                    &mut main_rows[i * main_width..(i + 1) * main_width],
+                );
+                let mut offset = i * aux_width;
+                for j in 0..num_aux_segments {
+                    let segment = trace.get_aux_segment(j);
+                    let segment_row = &mut aux_rows[offset..offset + segment.num_cols()];
+                    segment.read_row_into(lde_step, segment_row);
+                    offset += segment.num_cols();
+                }
+            }
+
+            let mut request = common.clone();
+            EvaluateConstraintsRequest::write_range(
+                range.start,
+                range.len(),
+                &main_rows,
+                &aux_rows,
+                &mut request,
+            );
+            request
+        })
+        .collect();
+
+    // stitch evaluations returned by workers into columns of the evaluation table
+    let divisors =
+        ConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients).divisors();
+    let mut evaluations = (0..divisors.len())
+        .map(|_| Vec::with_capacity(ce_domain_size))
+        .collect::<Vec<_>>();
+    let responses = execute(transport, requests)?;
+    for (worker_idx, (response, range)) in responses.iter().zip(ranges).enumerate() {
+        let mut source = SliceReader::new(decode_response(worker_idx, response)?);
+        let chunk: Vec<Vec<E>> = read_columns(&mut source).map_err(|e| malformed(worker_idx, e))?;
+        if chunk.len() != divisors.len() || chunk.iter().any(|c| c.len() != range.len()) {
+            return Err(malformed(
+                worker_idx,
+                "unexpected shape of constraint evaluations",
+            ));
+        }
+        for (column, values) in evaluations.iter_mut().zip(chunk) {
+            column.extend_from_slice(&values);
+        }
+    }
+
+    Ok(ConstraintEvaluationTable::from_evaluations(
+        domain,
+        divisors,
+        evaluations,
+    ))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a new request for a task of the specified kind which operates on elements of `E`.
+fn new_request<E: FieldElement>(kind: TaskKind, options: &ProofOptions) -> Vec<u8> {
+    let mut request = Vec::new();
+    TaskHeader::new(kind, options).write_into(&mut request);
+    if kind != TaskKind::EvaluateConstraints {
+        request.write_u8(extension_degree::<E>());
+    }
+    request
+}
+
+/// Returns the number of workers reachable via the transport.
+///
+/// # Errors
+/// Returns an error if the transport reports that there are no workers.
+fn num_workers(transport: &dyn Transport) -> Result<usize, ProverError> {
+    match transport.num_workers() {
+        0 => Err(ProverError::DistributedTaskFailed(
+            "transport does not have any workers".into(),
+        )),
+        num_workers => Ok(num_workers),
+    }
+}
+
+/// Sends the requests to the workers of the transport and waits for all of them to respond.
+fn execute(transport: &dyn Transport, requests: Vec<Vec<u8>>) -> Result<Vec<Vec<u8>>, ProverError> {
+    transport
+        .execute(requests)
+        .map_err(|err| ProverError::DistributedTaskFailed(err.to_string()))
+}
+
+/// Arranges the columns according to the specified layout.
+fn build_lde_matrix<E: FieldElement>(columns: Vec<Vec<E>>, layout: MatrixLayout) -> LdeMatrix<E> {
+    let matrix = Matrix::new(columns);
+    match layout {
+        MatrixLayout::ColumnMajor => matrix.into(),
+        MatrixLayout::RowMajor => RowMatrix::from_columns(&matrix).into(),
+    }
+}
+
+/// Splits the range 0..n into the specified number of contiguous ranges of nearly equal size.
+fn split_range(n: usize, num_parts: usize) -> impl Iterator<Item = Range<usize>> {
+    (0..num_parts).map(move |i| (n * i / num_parts)..(n * (i + 1) / num_parts))
+}
+
+/// Returns the largest power of two which is smaller than or equal to `n`.
+fn prev_power_of_two(n: usize) -> usize {
+    debug_assert!(n > 0);
+    1 << (usize::BITS - 1 - n.leading_zeros())
+}
+
+fn malformed<T: ToString>(worker_idx: usize, reason: T) -> ProverError {
+    ProverError::DistributedTaskFailed(format!(
+        "worker {} returned a malformed response: {}",
+        worker_idx,
+        reason.to_string()
+    ))
+}
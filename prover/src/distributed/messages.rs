@@ -0,0 +1,387 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Encoding of requests and responses exchanged between a distributed prover and its workers.
+//!
+//! Every request starts with a [TaskHeader] which specifies the task and the field extension
+//! and hash function used by the proof; this allows workers to select the right generic
+//! instantiation of the task. Every response starts with a status byte followed either by the
+//! result of the task or by a description of the error which occurred.
+
+use crate::{ProverError, StarkDomain};
+use air::{
+    proof::Context, AuxTraceRandElements, ConstraintCompositionCoefficients, FieldExtension,
+    HashFunction, ProofOptions,
+};
+use math::{FieldElement, StarkField};
+use std::{string::String, vec::Vec};
+use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+// CONSTANTS
+// ================================================================================================
+
+const STATUS_OK: u8 = 0;
+const STATUS_ERROR: u8 = 1;
+
+// TASK HEADER
+// ================================================================================================
+
+/// Types of tasks which can be delegated to workers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskKind {
+    /// Interpolate (optionally) and evaluate a set of columns over the LDE domain.
+    ExtendColumns = 1,
+    /// Hash a range of rows and build a Merkle tree from the resulting hashes.
+    CommitToRows = 2,
+    /// Evaluate constraints over a range of steps of the constraint evaluation domain.
+    EvaluateConstraints = 3,
+}
+
+/// Header included at the start of every request sent to a worker.
+pub struct TaskHeader {
+    pub kind: TaskKind,
+    pub field_extension: FieldExtension,
+    pub hash_fn: HashFunction,
+}
+
+impl TaskHeader {
+    pub fn new(kind: TaskKind, options: &ProofOptions) -> Self {
+        Self {
+            kind,
+            field_extension: options.field_extension(),
+            hash_fn: options.hash_fn(),
+        }
+    }
+}
+
+impl Serializable for TaskHeader {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.kind as u8);
+        target.write(self.field_extension);
+        target.write(self.hash_fn);
+    }
+}
+
+impl Deserializable for TaskHeader {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let kind = match source.read_u8()? {
+            1 => TaskKind::ExtendColumns,
+            2 => TaskKind::CommitToRows,
+            3 => TaskKind::EvaluateConstraints,
+            value => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "value {} cannot be deserialized as a task kind",
+                    value
+                )))
+            }
+        };
+        Ok(Self {
+            kind,
+            field_extension: FieldExtension::read_from(source)?,
+            hash_fn: HashFunction::read_from(source)?,
+        })
+    }
+}
+
+// DOMAIN PARAMETERS
+// ================================================================================================
+
+/// Parameters which allow a worker to re-build the STARK domain used by the prover.
+pub struct DomainParams<B: StarkField> {
+    pub trace_length: usize,
+    pub ce_domain_size: usize,
+    pub lde_domain_size: usize,
+    pub offset: B,
+}
+
+impl<B: StarkField> DomainParams<B> {
+    pub fn write_domain<W: ByteWriter>(domain: &StarkDomain<B>, target: &mut W) {
+        target.write_u64(domain.trace_length() as u64);
+        target.write_u64(domain.ce_domain_size() as u64);
+        target.write_u64(domain.lde_domain_size() as u64);
+        target.write(domain.offset());
+    }
+
+    pub fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let trace_length = read_size(source)?;
+        let ce_domain_size = read_size(source)?;
+        let lde_domain_size = read_size(source)?;
+        let offset = B::read_from(source)?;
+
+        let valid_sizes = [trace_length, ce_domain_size, lde_domain_size]
+            .iter()
+            .all(|size| size.is_power_of_two())
+            && trace_length < ce_domain_size
+            && ce_domain_size <= lde_domain_size;
+        if !valid_sizes || offset == B::ZERO {
+            return Err(DeserializationError::InvalidValue(
+                "invalid STARK domain parameters".into(),
+            ));
+        }
+
+        Ok(Self {
+            trace_length,
+            ce_domain_size,
+            lde_domain_size,
+            offset,
+        })
+    }
+}
+
+// EXTEND COLUMNS
+// ================================================================================================
+
+/// Columns of the execution trace or of the composition polynomial to be extended over the LDE
+/// domain; when `interpolate` is set, columns contain trace values rather than polynomial
+/// coefficients, and the response contains both the polynomials and their evaluations.
+pub struct ExtendColumnsRequest<E: FieldElement> {
+    pub domain: DomainParams<E::BaseField>,
+    pub interpolate: bool,
+    pub columns: Vec<Vec<E>>,
+}
+
+impl<E: FieldElement> ExtendColumnsRequest<E> {
+    pub fn write<W: ByteWriter>(
+        domain: &StarkDomain<E::BaseField>,
+        interpolate: bool,
+        columns: &[&[E]],
+        target: &mut W,
+    ) {
+        DomainParams::write_domain(domain, target);
+        target.write_u8(interpolate as u8);
+        write_columns(columns, target);
+    }
+
+    pub fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let domain = DomainParams::read_from(source)?;
+        let interpolate = source.read_u8()? == 1;
+        let columns = read_columns(source)?;
+        if columns.iter().any(|c| c.len() != domain.trace_length) {
+            return Err(DeserializationError::InvalidValue(
+                "column length must be equal to trace length".into(),
+            ));
+        }
+        Ok(Self {
+            domain,
+            interpolate,
+            columns,
+        })
+    }
+}
+
+// COMMIT TO ROWS
+// ================================================================================================
+
+/// A range of rows of an LDE matrix to be hashed into leaves of a Merkle subtree; rows are
+/// stored one after another.
+pub struct CommitToRowsRequest<E: FieldElement> {
+    pub row_width: usize,
+    pub rows: Vec<E>,
+}
+
+impl<E: FieldElement> CommitToRowsRequest<E> {
+    pub fn write<W: ByteWriter>(row_width: usize, rows: &[E], target: &mut W) {
+        target.write_u64(row_width as u64);
+        target.write_u64((rows.len() / row_width) as u64);
+        E::write_batch_into(rows, target);
+    }
+
+    pub fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let row_width = read_size(source)?;
+        let num_rows = read_size(source)?;
+        if row_width == 0 || num_rows < 2 || !num_rows.is_power_of_two() {
+            return Err(DeserializationError::InvalidValue(
+                "invalid shape of the committed rows".into(),
+            ));
+        }
+        let num_elements = checked_mul(row_width, num_rows)?;
+        let rows = E::read_batch_from(source, num_elements)?;
+        Ok(Self { row_width, rows })
+    }
+}
+
+// EVALUATE CONSTRAINTS
+// ================================================================================================
+
+/// Everything needed to instantiate the AIR of the computation and to evaluate its constraints
+/// over a range of steps of the constraint evaluation domain.
+///
+/// The window of the trace contains main and auxiliary trace rows for constraint evaluation
+/// steps `offset` through `offset + num_rows + frame_distance`, where `frame_distance` is the
+/// distance between the current and the next rows of an evaluation frame.
+pub struct EvaluateConstraintsRequest<E: FieldElement> {
+    pub context: Context,
+    pub pub_inputs: Vec<u8>,
+    pub aux_rand_elements: AuxTraceRandElements<E>,
+    pub composition_coefficients: ConstraintCompositionCoefficients<E>,
+    pub offset: usize,
+    pub num_rows: usize,
+    pub main_rows: Vec<E::BaseField>,
+    pub aux_rows: Vec<E>,
+}
+
+impl<E: FieldElement> EvaluateConstraintsRequest<E> {
+    /// Writes the part of the request which is common to all workers.
+    pub fn write_common<W: ByteWriter>(
+        context: &Context,
+        pub_inputs: &[u8],
+        aux_rand_elements: &[&[E]],
+        composition_coefficients: &ConstraintCompositionCoefficients<E>,
+        target: &mut W,
+    ) {
+        context.write_into(target);
+        target.write_u64(pub_inputs.len() as u64);
+        target.write_u8_slice(pub_inputs);
+        write_columns(aux_rand_elements, target);
+        write_pairs(&composition_coefficients.transition, target);
+        write_pairs(&composition_coefficients.boundary, target);
+    }
+
+    /// Writes the part of the request which describes the range of steps to be evaluated.
+    pub fn write_range<W: ByteWriter>(
+        offset: usize,
+        num_rows: usize,
+        main_rows: &[E::BaseField],
+        aux_rows: &[E],
+        target: &mut W,
+    ) {
+        target.write_u64(offset as u64);
+        target.write_u64(num_rows as u64);
+        target.write_u64(main_rows.len() as u64);
+        E::BaseField::write_batch_into(main_rows, target);
+        target.write_u64(aux_rows.len() as u64);
+        E::write_batch_into(aux_rows, target);
+    }
+
+    pub fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let context = Context::read_from(source)?;
+        let num_pub_inputs_bytes = read_size(source)?;
+        let pub_inputs = source.read_u8_vec(num_pub_inputs_bytes)?;
+
+        let mut aux_rand_elements = AuxTraceRandElements::new();
+        for rand_elements in read_columns(source)? {
+            aux_rand_elements.add_segment_elements(rand_elements);
+        }
+        let composition_coefficients = ConstraintCompositionCoefficients {
+            transition: read_pairs(source)?,
+            boundary: read_pairs(source)?,
+        };
+
+        let offset = read_size(source)?;
+        let num_rows = read_size(source)?;
+        let num_main_elements = read_size(source)?;
+        let main_rows = E::BaseField::read_batch_from(source, num_main_elements)?;
+        let num_aux_elements = read_size(source)?;
+        let aux_rows = E::read_batch_from(source, num_aux_elements)?;
+
+        Ok(Self {
+            context,
+            pub_inputs,
+            aux_rand_elements,
+            composition_coefficients,
+            offset,
+            num_rows,
+            main_rows,
+            aux_rows,
+        })
+    }
+}
+
+// RESPONSES
+// ================================================================================================
+
+/// Encodes the result of processing a request into a response.
+pub fn encode_response(result: Result<Vec<u8>, String>) -> Vec<u8> {
+    match result {
+        Ok(payload) => {
+            let mut response = Vec::with_capacity(payload.len() + 1);
+            response.push(STATUS_OK);
+            response.extend_from_slice(&payload);
+            response
+        }
+        Err(message) => {
+            let mut response = Vec::with_capacity(message.len() + 1);
+            response.push(STATUS_ERROR);
+            response.extend_from_slice(message.as_bytes());
+            response
+        }
+    }
+}
+
+/// Returns the payload of a response received from the worker with the specified index, or an
+/// error if the worker failed to process the request.
+pub fn decode_response(worker_idx: usize, response: &[u8]) -> Result<&[u8], ProverError> {
+    match response.split_first() {
+        Some((&STATUS_OK, payload)) => Ok(payload),
+        Some((&STATUS_ERROR, message)) => Err(ProverError::DistributedTaskFailed(format!(
+            "worker {} failed to process a task: {}",
+            worker_idx,
+            String::from_utf8_lossy(message)
+        ))),
+        _ => Err(ProverError::DistributedTaskFailed(format!(
+            "worker {} returned a malformed response",
+            worker_idx
+        ))),
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the degree of extension of `E` over its base field.
+pub fn extension_degree<E: FieldElement>() -> u8 {
+    (E::ELEMENT_BYTES / <E::BaseField as FieldElement>::ELEMENT_BYTES) as u8
+}
+
+/// Writes the number of columns followed by the length and the values of each column.
+pub fn write_columns<E: FieldElement, W: ByteWriter>(columns: &[&[E]], target: &mut W) {
+    target.write_u64(columns.len() as u64);
+    for column in columns {
+        target.write_u64(column.len() as u64);
+        E::write_batch_into(column, target);
+    }
+}
+
+/// Reads columns written via [write_columns()].
+pub fn read_columns<E: FieldElement, R: ByteReader>(
+    source: &mut R,
+) -> Result<Vec<Vec<E>>, DeserializationError> {
+    let num_columns = read_size(source)?;
+    let mut columns = Vec::new();
+    for _ in 0..num_columns {
+        let len = read_size(source)?;
+        columns.push(E::read_batch_from(source, len)?);
+    }
+    Ok(columns)
+}
+
+fn write_pairs<E: FieldElement, W: ByteWriter>(pairs: &[(E, E)], target: &mut W) {
+    target.write_u64(pairs.len() as u64);
+    for (a, b) in pairs {
+        target.write(*a);
+        target.write(*b);
+    }
+}
+
+fn read_pairs<E: FieldElement, R: ByteReader>(
+    source: &mut R,
+) -> Result<Vec<(E, E)>, DeserializationError> {
+    let num_pairs = read_size(source)?;
+    let mut pairs = Vec::new();
+    for _ in 0..num_pairs {
+        pairs.push((E::read_from(source)?, E::read_from(source)?));
+    }
+    Ok(pairs)
+}
+
+fn read_size<R: ByteReader>(source: &mut R) -> Result<usize, DeserializationError> {
+    usize::try_from(source.read_u64()?)
+        .map_err(|_| DeserializationError::InvalidValue("size does not fit into usize".into()))
+}
+
+fn checked_mul(a: usize, b: usize) -> Result<usize, DeserializationError> {
+    a.checked_mul(b)
+        .ok_or_else(|| DeserializationError::InvalidValue("size does not fit into usize".into()))
+}
@@ -0,0 +1,37 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Distributed proof generation.
+//!
+//! When a [Prover](crate::Prover) returns a [Transport] from its
+//! [transport()](crate::Prover::transport) method, the most expensive steps of proof generation
+//! are split into tasks which are executed by workers reachable via the transport:
+//!
+//! * Low-degree extensions of the execution trace and of the constraint composition polynomial
+//!   are split by columns; every worker interpolates and evaluates a contiguous range of columns.
+//! * Commitments to the extended trace and to the composition polynomial evaluations are split
+//!   by rows; every worker hashes a contiguous range of rows and builds a Merkle subtree from the
+//!   resulting hashes. The subtrees are then joined into the full Merkle tree.
+//! * Constraint evaluation is split by ranges of the constraint evaluation domain; every worker
+//!   receives the trace rows needed for its range and returns a chunk of the evaluation table.
+//!
+//! The prover stitches the results together, and thus, the resulting proof is exactly the same
+//! as the proof generated by a single machine. Workers are instances of [ProverWorker]; they can
+//! live in the same process (see [LocalTransport]) or in other processes and on other machines
+//! (see [SocketTransport]).
+
+mod coordinator;
+pub(crate) use coordinator::{commit_to_rows, evaluate_constraints, extend_polys, extend_trace};
+
+mod messages;
+
+mod transport;
+pub use transport::{LocalTransport, RequestHandler, SocketTransport, Transport};
+
+mod worker;
+pub use worker::ProverWorker;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,248 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{
+    commit_to_rows, extend_polys, extend_trace,
+    messages::{decode_response, TaskHeader, TaskKind},
+    transport::{read_frame, write_frame},
+    LocalTransport, ProverWorker, RequestHandler, SocketTransport, Transport,
+};
+use crate::{
+    tests::{build_fib_trace, MockAir},
+    LdeMatrix, MatrixLayout, ProverError, StarkDomain, Trace,
+};
+use air::Air;
+use crypto::hashers::Blake3_256;
+use math::fields::f128::BaseElement;
+use std::{
+    io::{self, Cursor},
+    net::TcpListener,
+    sync::Arc,
+    thread,
+    vec::Vec,
+};
+use utils::Serializable;
+
+type Blake3 = Blake3_256<BaseElement>;
+
+// TRANSPORTS
+// ================================================================================================
+
+#[test]
+fn frames_round_trip() {
+    let mut stream = Vec::new();
+    write_frame(&mut stream, &[1, 2, 3]).unwrap();
+    write_frame(&mut stream, &[]).unwrap();
+
+    let mut stream = Cursor::new(stream);
+    assert_eq!(Some(vec![1, 2, 3]), read_frame(&mut stream).unwrap());
+    assert_eq!(Some(vec![]), read_frame(&mut stream).unwrap());
+    assert_eq!(None, read_frame(&mut stream).unwrap());
+
+    // a frame truncated in the middle is an error
+    let mut stream = Vec::new();
+    write_frame(&mut stream, &[1, 2, 3]).unwrap();
+    stream.pop();
+    assert!(read_frame(&mut Cursor::new(stream)).is_err());
+
+    // a frame with a huge length is an error rather than a huge allocation
+    let mut stream = u64::MAX.to_le_bytes().to_vec();
+    stream.extend_from_slice(&[1, 2, 3]);
+    assert!(read_frame(&mut Cursor::new(stream)).is_err());
+}
+
+#[test]
+fn local_transport_rejects_too_many_requests() {
+    let transport = build_local_transport(2);
+    assert!(transport.execute(vec![vec![]; 3]).is_err());
+    assert_eq!(2, transport.execute(vec![vec![]; 2]).unwrap().len());
+}
+
+#[test]
+fn transport_without_workers_is_rejected() {
+    let air = MockAir::with_trace_length(16);
+    let domain = StarkDomain::new(&air);
+    let trace = build_fib_trace(32);
+    let lde = LdeMatrix::evaluate(
+        &trace.main_segment().interpolate_columns(),
+        &domain,
+        MatrixLayout::ColumnMajor,
+    );
+
+    let transport = EmptyTransport;
+    assert!(matches!(
+        extend_trace(
+            &transport,
+            air.options(),
+            trace.main_segment(),
+            &domain,
+            MatrixLayout::ColumnMajor
+        ),
+        Err(ProverError::DistributedTaskFailed(_))
+    ));
+    assert!(matches!(
+        commit_to_rows::<_, Blake3>(&transport, air.options(), &lde),
+        Err(ProverError::DistributedTaskFailed(_))
+    ));
+}
+
+#[test]
+fn worker_reports_malformed_requests() {
+    let air = MockAir::with_trace_length(16);
+    let worker = ProverWorker::<MockAir>::new();
+
+    // unknown task kind
+    let response = worker.handle_request(&[u8::MAX]);
+    assert!(matches!(
+        decode_response(3, &response),
+        Err(ProverError::DistributedTaskFailed(_))
+    ));
+
+    // truncated request
+    let request = TaskHeader::new(TaskKind::ExtendColumns, air.options()).to_bytes();
+    let response = worker.handle_request(&request);
+    assert!(matches!(
+        decode_response(3, &response),
+        Err(ProverError::DistributedTaskFailed(_))
+    ));
+}
+
+// LOW-DEGREE EXTENSION
+// ================================================================================================
+
+#[test]
+fn distributed_trace_extension() {
+    let trace_length = 32;
+    let air = MockAir::with_trace_length(trace_length);
+    let domain = StarkDomain::new(&air);
+    let trace = build_fib_trace(trace_length * 2);
+    let expected_polys = trace
+        .main_segment()
+        .interpolate_columns_with_twiddles(domain.trace_inv_twiddles());
+
+    for num_workers in [1, 2, 3, 5] {
+        let transport = build_local_transport(num_workers);
+        for layout in [MatrixLayout::ColumnMajor, MatrixLayout::RowMajor] {
+            let expected_lde = LdeMatrix::evaluate(&expected_polys, &domain, layout);
+            let (polys, lde) = extend_trace(
+                &transport,
+                air.options(),
+                trace.main_segment(),
+                &domain,
+                layout,
+            )
+            .unwrap();
+            assert_eq!(
+                expected_polys.columns().collect::<Vec<_>>(),
+                polys.columns().collect::<Vec<_>>()
+            );
+            assert_matrices_eq(&expected_lde, &lde);
+
+            let lde = extend_polys(&transport, air.options(), &polys, &domain, layout).unwrap();
+            assert_matrices_eq(&expected_lde, &lde);
+        }
+    }
+}
+
+#[test]
+fn distributed_trace_extension_over_tcp() {
+    let trace_length = 32;
+    let air = MockAir::with_trace_length(trace_length);
+    let domain = StarkDomain::new(&air);
+    let trace = build_fib_trace(trace_length * 2);
+    let layout = MatrixLayout::ColumnMajor;
+
+    let polys = trace
+        .main_segment()
+        .interpolate_columns_with_twiddles(domain.trace_inv_twiddles());
+    let expected_lde = LdeMatrix::evaluate(&polys, &domain, layout);
+
+    let transport = build_tcp_transport(3);
+    let (_, lde) = extend_trace(
+        &transport,
+        air.options(),
+        trace.main_segment(),
+        &domain,
+        layout,
+    )
+    .unwrap();
+    assert_matrices_eq(&expected_lde, &lde);
+
+    // the same connections can be used for subsequent tasks
+    let tree = commit_to_rows::<_, Blake3>(&transport, air.options(), &lde).unwrap();
+    assert_eq!(expected_lde.commit_to_rows::<Blake3>().root(), tree.root());
+}
+
+// COMMITMENTS
+// ================================================================================================
+
+#[test]
+fn distributed_commitment() {
+    let trace_length = 32;
+    let air = MockAir::with_trace_length(trace_length);
+    let domain = StarkDomain::new(&air);
+    let trace = build_fib_trace(trace_length * 2);
+    let polys = trace
+        .main_segment()
+        .interpolate_columns_with_twiddles(domain.trace_inv_twiddles());
+
+    for num_workers in [1, 2, 3, 4, 7] {
+        let transport = build_local_transport(num_workers);
+        for layout in [MatrixLayout::ColumnMajor, MatrixLayout::RowMajor] {
+            let lde = LdeMatrix::evaluate(&polys, &domain, layout);
+            let expected = lde.commit_to_rows::<Blake3>();
+            let tree = commit_to_rows::<_, Blake3>(&transport, air.options(), &lde).unwrap();
+            assert_eq!(expected.root(), tree.root());
+            assert_eq!(expected.leaves(), tree.leaves());
+            for i in [0, 5, lde.num_rows() - 1] {
+                assert_eq!(expected.prove(i).unwrap(), tree.prove(i).unwrap());
+            }
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_local_transport(num_workers: usize) -> LocalTransport {
+    let workers = (0..num_workers)
+        .map(|_| Arc::new(ProverWorker::<MockAir>::new()) as Arc<dyn RequestHandler>)
+        .collect();
+    LocalTransport::new(workers)
+}
+
+fn build_tcp_transport(num_workers: usize) -> SocketTransport {
+    let addresses = (0..num_workers)
+        .map(|_| {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let address = listener.local_addr().unwrap();
+            thread::spawn(move || ProverWorker::<MockAir>::new().listen_tcp(listener));
+            address
+        })
+        .collect::<Vec<_>>();
+    SocketTransport::connect_tcp(&addresses).unwrap()
+}
+
+/// A custom transport which does not have any workers.
+struct EmptyTransport;
+
+impl Transport for EmptyTransport {
+    fn num_workers(&self) -> usize {
+        0
+    }
+
+    fn execute(&self, _requests: Vec<Vec<u8>>) -> io::Result<Vec<Vec<u8>>> {
+        Ok(Vec::new())
+    }
+}
+
+fn assert_matrices_eq(expected: &LdeMatrix<BaseElement>, actual: &LdeMatrix<BaseElement>) {
+    assert_eq!(expected.layout(), actual.layout());
+    assert_eq!(expected.num_cols(), actual.num_cols());
+    assert_eq!(expected.num_rows(), actual.num_rows());
+    for row_idx in 0..expected.num_rows() {
+        assert_eq!(expected.get_row(row_idx), actual.get_row(row_idx));
+    }
+}
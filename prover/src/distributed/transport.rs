@@ -0,0 +1,247 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use std::{
+    boxed::Box,
+    io::{self, Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+    sync::{Arc, Mutex},
+    thread,
+    vec::Vec,
+};
+
+#[cfg(unix)]
+use std::{os::unix::net::UnixStream, path::Path};
+
+// TRANSPORT
+// ================================================================================================
+
+/// Defines how requests of a distributed prover are delivered to its workers.
+///
+/// Requests and responses are opaque byte strings: requests are built by the prover and are
+/// expected to be processed by a [ProverWorker](super::ProverWorker) (or any other
+/// [RequestHandler]) on the receiving side.
+pub trait Transport: Send + Sync {
+    /// Returns the number of workers reachable via this transport.
+    ///
+    /// A distributed prover returns an error if this is zero.
+    fn num_workers(&self) -> usize;
+
+    /// Sends `requests[i]` to the worker with index `i` and returns responses of all workers in
+    /// the same order; workers are expected to process their requests concurrently.
+    ///
+    /// # Errors
+    /// Returns an error if the number of requests is greater than the number of workers, or if
+    /// any of the workers could not be reached or did not respond.
+    fn execute(&self, requests: Vec<Vec<u8>>) -> io::Result<Vec<Vec<u8>>>;
+}
+
+/// Processes requests sent to a worker of a distributed prover.
+pub trait RequestHandler: Send + Sync {
+    /// Processes the specified request and returns a response to it.
+    ///
+    /// Failures to process a request are expected to be reported via the response.
+    fn handle_request(&self, request: &[u8]) -> Vec<u8>;
+}
+
+// LOCAL TRANSPORT
+// ================================================================================================
+
+/// A [Transport] which delivers requests to workers living in the same process.
+///
+/// Every request is processed in a separate thread. This transport is intended mostly for
+/// testing, but it can also be used to spread proof generation across threads without enabling
+/// the `concurrent` feature.
+pub struct LocalTransport {
+    workers: Vec<Arc<dyn RequestHandler>>,
+}
+
+impl LocalTransport {
+    /// Returns a new transport which delivers requests to the specified workers.
+    ///
+    /// # Panics
+    /// Panics if `workers` is empty.
+    pub fn new(workers: Vec<Arc<dyn RequestHandler>>) -> Self {
+        assert!(!workers.is_empty(), "at least one worker must be provided");
+        Self { workers }
+    }
+}
+
+impl Transport for LocalTransport {
+    fn num_workers(&self) -> usize {
+        self.workers.len()
+    }
+
+    fn execute(&self, requests: Vec<Vec<u8>>) -> io::Result<Vec<Vec<u8>>> {
+        check_num_requests(requests.len(), self.num_workers())?;
+
+        let handles = requests
+            .into_iter()
+            .zip(self.workers.iter())
+            .map(|(request, worker)| {
+                let worker = worker.clone();
+                thread::spawn(move || worker.handle_request(&request))
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .enumerate()
+            .map(|(worker_idx, handle)| {
+                handle.join().map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::Other,
+                        format!("worker {} panicked while processing a request", worker_idx),
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+// SOCKET TRANSPORT
+// ================================================================================================
+
+/// A [Transport] which delivers requests to workers over TCP or Unix domain socket connections.
+///
+/// A single connection is kept open to every worker. Requests and responses are sent over these
+/// connections as frames prefixed with their length; on the worker side, such connections can be
+/// served via [ProverWorker::serve()](super::ProverWorker::serve). All requests of a batch are
+/// written before any of the responses are read, and thus, workers process them concurrently.
+pub struct SocketTransport {
+    connections: Vec<Mutex<Box<dyn Connection>>>,
+}
+
+impl SocketTransport {
+    /// Returns a new transport connected to the workers listening on the specified TCP
+    /// addresses.
+    ///
+    /// # Errors
+    /// Returns an error if a connection to any of the workers could not be established.
+    pub fn connect_tcp<A: ToSocketAddrs>(addresses: &[A]) -> io::Result<Self> {
+        let mut connections = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            let stream = TcpStream::connect(address)?;
+            stream.set_nodelay(true)?;
+            connections.push(Mutex::new(Box::new(stream) as Box<dyn Connection>));
+        }
+        Self::from_connections(connections)
+    }
+
+    /// Returns a new transport connected to the workers listening on Unix domain sockets at the
+    /// specified paths.
+    ///
+    /// # Errors
+    /// Returns an error if a connection to any of the workers could not be established.
+    #[cfg(unix)]
+    pub fn connect_unix<P: AsRef<Path>>(paths: &[P]) -> io::Result<Self> {
+        let mut connections = Vec::with_capacity(paths.len());
+        for path in paths {
+            let stream = UnixStream::connect(path)?;
+            connections.push(Mutex::new(Box::new(stream) as Box<dyn Connection>));
+        }
+        Self::from_connections(connections)
+    }
+
+    fn from_connections(connections: Vec<Mutex<Box<dyn Connection>>>) -> io::Result<Self> {
+        if connections.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "at least one worker must be provided",
+            ));
+        }
+        Ok(Self { connections })
+    }
+}
+
+impl Transport for SocketTransport {
+    fn num_workers(&self) -> usize {
+        self.connections.len()
+    }
+
+    fn execute(&self, requests: Vec<Vec<u8>>) -> io::Result<Vec<Vec<u8>>> {
+        check_num_requests(requests.len(), self.num_workers())?;
+
+        // connections are always locked in the same order, and thus, concurrent batches cannot
+        // deadlock each other
+        let mut connections = self.connections[..requests.len()]
+            .iter()
+            .map(|connection| connection.lock().expect("connection lock poisoned"))
+            .collect::<Vec<_>>();
+
+        for (connection, request) in connections.iter_mut().zip(requests.iter()) {
+            write_frame(&mut ***connection, request)?;
+        }
+
+        let mut responses = Vec::with_capacity(requests.len());
+        for (worker_idx, connection) in connections.iter_mut().enumerate() {
+            match read_frame(&mut ***connection)? {
+                Some(response) => responses.push(response),
+                None => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        format!("worker {} closed the connection", worker_idx),
+                    ))
+                }
+            }
+        }
+        Ok(responses)
+    }
+}
+
+/// A bidirectional stream connecting the prover to a worker.
+trait Connection: Read + Write + Send {}
+
+impl<T: Read + Write + Send> Connection for T {}
+
+// FRAMING
+// ================================================================================================
+
+/// Writes the specified message into the stream prefixed with its length.
+pub(super) fn write_frame<W: Write + ?Sized>(stream: &mut W, message: &[u8]) -> io::Result<()> {
+    stream.write_all(&(message.len() as u64).to_le_bytes())?;
+    stream.write_all(message)?;
+    stream.flush()
+}
+
+/// Reads a length-prefixed message from the stream; returns None if the stream was closed
+/// before the message started.
+pub(super) fn read_frame<R: Read + ?Sized>(stream: &mut R) -> io::Result<Option<Vec<u8>>> {
+    let mut len_bytes = [0u8; 8];
+    match stream.read_exact(&mut len_bytes) {
+        Ok(()) => (),
+        Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(err) => return Err(err),
+    }
+
+    // the length comes from the other side of the stream, and thus, the message is read
+    // incrementally rather than into a buffer pre-allocated for the entire length
+    let len = u64::from_le_bytes(len_bytes);
+    let mut message = Vec::new();
+    stream.take(len).read_to_end(&mut message)?;
+    if message.len() as u64 != len {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "stream was closed before the message was complete",
+        ));
+    }
+    Ok(Some(message))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn check_num_requests(num_requests: usize, num_workers: usize) -> io::Result<()> {
+    if num_requests > num_workers {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "number of requests ({}) cannot exceed number of workers ({})",
+                num_requests, num_workers
+            ),
+        ));
+    }
+    Ok(())
+}
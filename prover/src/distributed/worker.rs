@@ -0,0 +1,420 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{
+    messages::{
+        encode_response, extension_degree, CommitToRowsRequest, EvaluateConstraintsRequest,
+        ExtendColumnsRequest, TaskHeader, TaskKind,
+    },
+    transport::{read_frame, write_frame},
+    RequestHandler,
+};
+use crate::{
+    constraints::ConstraintEvaluator, trace::TraceFrameReader, DomainCache, Matrix, ProverError,
+};
+use air::{Air, EvaluationFrame, FieldExtension, HashFunction};
+use core::marker::PhantomData;
+use crypto::{
+    hashers::{Blake3_192, Blake3_256, Keccak256, Poseidon64_256, Sha3_256},
+    ElementHasher, MerkleTree,
+};
+use log::debug;
+use math::{
+    fields::{CubeExtension, QuadExtension},
+    ExtensibleField, FieldElement, StarkField,
+};
+use std::{
+    io::{self, Read, Write},
+    net::TcpListener,
+    string::{String, ToString},
+    vec::Vec,
+};
+use utils::{ByteReader, Deserializable, Serializable, SliceReader};
+
+#[cfg(unix)]
+use std::os::unix::net::UnixListener;
+
+// PROVER WORKER
+// ================================================================================================
+
+/// A worker of a distributed prover for computations described by AIR `A`.
+///
+/// A worker processes tasks which a prover delegates to it via a [Transport](super::Transport):
+/// extending columns of the execution trace and of the constraint composition polynomial over
+/// the LDE domain, building Merkle subtrees over ranges of LDE rows, and evaluating constraints
+/// over ranges of the constraint evaluation domain. The worker keeps a [DomainCache], and thus,
+/// twiddles are computed only once for every domain shape the worker encounters.
+///
+/// To evaluate constraints, a worker needs to instantiate the AIR from public inputs received
+/// from the prover; thus, public inputs of the AIR must be deserializable.
+pub struct ProverWorker<A: Air> {
+    domains: DomainCache<A::BaseField>,
+    _air: PhantomData<fn() -> A>,
+}
+
+impl<A: Air> ProverWorker<A> {
+    /// Returns a new worker for computations described by AIR `A`.
+    pub fn new() -> Self {
+        Self {
+            domains: DomainCache::new(),
+            _air: PhantomData,
+        }
+    }
+}
+
+impl<A: Air> Default for ProverWorker<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> ProverWorker<A>
+where
+    A: Air,
+    A::BaseField: ExtensibleField<2> + ExtensibleField<3>,
+    A::PublicInputs: Deserializable,
+{
+    // SERVING CONNECTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Processes requests received over the specified connection until the connection is closed
+    /// by the prover.
+    ///
+    /// # Errors
+    /// Returns an error if reading a request from or writing a response to the connection fails.
+    pub fn serve<S: Read + Write>(&self, mut stream: S) -> io::Result<()> {
+        while let Some(request) = read_frame(&mut stream)? {
+            let response = self.handle_request(&request);
+            write_frame(&mut stream, &response)?;
+        }
+        Ok(())
+    }
+
+    /// Serves connections accepted by the specified TCP listener one at a time.
+    ///
+    /// # Errors
+    /// Returns an error if the listener fails to accept a connection; errors which occur while
+    /// serving individual connections are logged and the connections are dropped.
+    pub fn listen_tcp(&self, listener: TcpListener) -> io::Result<()> {
+        for stream in listener.incoming() {
+            let stream = stream?;
+            stream.set_nodelay(true)?;
+            if let Err(err) = self.serve(stream) {
+                debug!("Dropped prover connection: {}", err);
+            }
+        }
+        Ok(())
+    }
+
+    /// Serves connections accepted by the specified Unix domain socket listener one at a time.
+    ///
+    /// # Errors
+    /// Returns an error if the listener fails to accept a connection; errors which occur while
+    /// serving individual connections are logged and the connections are dropped.
+    #[cfg(unix)]
+    pub fn listen_unix(&self, listener: UnixListener) -> io::Result<()> {
+        for stream in listener.incoming() {
+            if let Err(err) = self.serve(stream?) {
+                debug!("Dropped prover connection: {}", err);
+            }
+        }
+        Ok(())
+    }
+
+    // TASK DISPATCH
+    // --------------------------------------------------------------------------------------------
+
+    /// Selects the generic instantiation of the task based on the field extension and the hash
+    /// function specified in the request header.
+    #[rustfmt::skip]
+    fn process_request(&self, request: &[u8]) -> Result<Vec<u8>, String> {
+        let mut source = SliceReader::new(request);
+        let header = TaskHeader::read_from(&mut source).map_err(|err| err.to_string())?;
+        let kind = header.kind;
+
+        if header.hash_fn == HashFunction::Poseidon64_256 && !<Poseidon64_256<A::BaseField>>::is_supported() {
+            return Err(ProverError::UnsupportedHashFunction(header.hash_fn).to_string());
+        }
+        match header.field_extension {
+            FieldExtension::None => match header.hash_fn {
+                HashFunction::Blake3_256 => self.process_task::<A::BaseField, Blake3_256<A::BaseField>>(kind, &mut source),
+                HashFunction::Blake3_192 => self.process_task::<A::BaseField, Blake3_192<A::BaseField>>(kind, &mut source),
+                HashFunction::Sha3_256 => self.process_task::<A::BaseField, Sha3_256<A::BaseField>>(kind, &mut source),
+                HashFunction::Keccak256 => self.process_task::<A::BaseField, Keccak256<A::BaseField>>(kind, &mut source),
+                HashFunction::Poseidon64_256 => self.process_task::<A::BaseField, Poseidon64_256<A::BaseField>>(kind, &mut source),
+            },
+            FieldExtension::Quadratic => {
+                if !<QuadExtension<A::BaseField>>::is_supported() {
+                    return Err(ProverError::UnsupportedFieldExtension(2).to_string());
+                }
+                match header.hash_fn {
+                    HashFunction::Blake3_256 => self.process_task::<QuadExtension<A::BaseField>, Blake3_256<A::BaseField>>(kind, &mut source),
+                    HashFunction::Blake3_192 => self.process_task::<QuadExtension<A::BaseField>, Blake3_192<A::BaseField>>(kind, &mut source),
+                    HashFunction::Sha3_256 => self.process_task::<QuadExtension<A::BaseField>, Sha3_256<A::BaseField>>(kind, &mut source),
+                    HashFunction::Keccak256 => self.process_task::<QuadExtension<A::BaseField>, Keccak256<A::BaseField>>(kind, &mut source),
+                    HashFunction::Poseidon64_256 => self.process_task::<QuadExtension<A::BaseField>, Poseidon64_256<A::BaseField>>(kind, &mut source),
+                }
+            }
+            FieldExtension::Cubic => {
+                if !<CubeExtension<A::BaseField>>::is_supported() {
+                    return Err(ProverError::UnsupportedFieldExtension(3).to_string());
+                }
+                match header.hash_fn {
+                    HashFunction::Blake3_256 => self.process_task::<CubeExtension<A::BaseField>, Blake3_256<A::BaseField>>(kind, &mut source),
+                    HashFunction::Blake3_192 => self.process_task::<CubeExtension<A::BaseField>, Blake3_192<A::BaseField>>(kind, &mut source),
+                    HashFunction::Sha3_256 => self.process_task::<CubeExtension<A::BaseField>, Sha3_256<A::BaseField>>(kind, &mut source),
+                    HashFunction::Keccak256 => self.process_task::<CubeExtension<A::BaseField>, Keccak256<A::BaseField>>(kind, &mut source),
+                    HashFunction::Poseidon64_256 => self.process_task::<CubeExtension<A::BaseField>, Poseidon64_256<A::BaseField>>(kind, &mut source),
+                }
+            }
+        }
+    }
+
+    /// Processes a task for a proof generated using extension field `E` and hash function `H`.
+    ///
+    /// Columns of the main trace segment are in the base field while columns of auxiliary trace
+    /// segments and of the composition polynomial are in the extension field; thus, column tasks
+    /// specify the degree of the field their elements belong to.
+    fn process_task<E, H>(
+        &self,
+        kind: TaskKind,
+        source: &mut SliceReader,
+    ) -> Result<Vec<u8>, String>
+    where
+        E: FieldElement<BaseField = A::BaseField>,
+        H: ElementHasher<BaseField = A::BaseField>,
+    {
+        let mut result = Vec::new();
+        match kind {
+            TaskKind::ExtendColumns => match read_element_degree::<E>(source)? {
+                1 => self.extend_columns::<A::BaseField>(source, &mut result)?,
+                _ => self.extend_columns::<E>(source, &mut result)?,
+            },
+            TaskKind::CommitToRows => match read_element_degree::<E>(source)? {
+                1 => commit_to_rows::<A::BaseField, H>(source, &mut result)?,
+                _ => commit_to_rows::<E, H>(source, &mut result)?,
+            },
+            TaskKind::EvaluateConstraints => self.evaluate_constraints::<E>(source, &mut result)?,
+        }
+
+        if source.has_more_bytes() {
+            return Err("request contains unconsumed bytes".to_string());
+        }
+        Ok(result)
+    }
+
+    // TASKS
+    // --------------------------------------------------------------------------------------------
+
+    /// Interpolates the received columns into polynomials (if requested) and evaluates these
+    /// polynomials over the LDE domain.
+    fn extend_columns<E>(
+        &self,
+        source: &mut SliceReader,
+        target: &mut Vec<u8>,
+    ) -> Result<(), String>
+    where
+        E: FieldElement<BaseField = A::BaseField>,
+    {
+        let request = ExtendColumnsRequest::<E>::read_from(source).map_err(|e| e.to_string())?;
+        let params = request.domain;
+        let domain = self.domains.get_domain_with_sizes(
+            params.trace_length,
+            params.ce_domain_size,
+            params.lde_domain_size,
+            params.offset,
+        );
+
+        let columns = Matrix::new(request.columns);
+        let polys = if request.interpolate {
+            let polys = columns.interpolate_columns_with_twiddles(domain.trace_inv_twiddles());
+            write_matrix(&polys, target);
+            polys
+        } else {
+            columns
+        };
+        write_matrix(&polys.evaluate_columns_over(&domain), target);
+        Ok(())
+    }
+
+    /// Instantiates the AIR from the received context and public inputs, and evaluates its
+    /// constraints over the requested range of the constraint evaluation domain.
+    fn evaluate_constraints<E>(
+        &self,
+        source: &mut SliceReader,
+        target: &mut Vec<u8>,
+    ) -> Result<(), String>
+    where
+        E: FieldElement<BaseField = A::BaseField>,
+    {
+        let request =
+            EvaluateConstraintsRequest::<E>::read_from(source).map_err(|e| e.to_string())?;
+        if request.context.field_modulus_bytes() != A::BaseField::get_modulus_le_bytes() {
+            return Err("base field of the proof does not match base field of the AIR".to_string());
+        }
+        let pub_inputs = A::PublicInputs::read_from(&mut SliceReader::new(&request.pub_inputs))
+            .map_err(|e| e.to_string())?;
+        let air = A::new(
+            request.context.get_trace_info(),
+            pub_inputs,
+            request.context.options().clone(),
+        );
+        let domain = self.domains.get_domain(&air);
+
+        // make sure the window contains all rows needed to evaluate the requested range
+        let window = TraceWindow::new(
+            request.offset,
+            request.num_rows + domain.trace_to_ce_blowup(),
+            domain.ce_to_lde_blowup(),
+            domain.trace_to_ce_blowup(),
+            (air.trace_layout().main_trace_width(), request.main_rows),
+            (air.trace_layout().aux_trace_width(), request.aux_rows),
+        )?;
+        if request.offset + request.num_rows > domain.ce_domain_size() {
+            return Err("requested rows are outside of constraint evaluation domain".to_string());
+        }
+
+        let evaluator = ConstraintEvaluator::new(
+            &air,
+            request.aux_rand_elements,
+            request.composition_coefficients,
+        );
+        let columns = evaluator.evaluate_rows(&window, &domain, request.offset, request.num_rows);
+        let columns = columns.iter().map(|c| c.as_slice()).collect::<Vec<_>>();
+        super::messages::write_columns(&columns, target);
+        Ok(())
+    }
+}
+
+impl<A> RequestHandler for ProverWorker<A>
+where
+    A: Air,
+    A::BaseField: ExtensibleField<2> + ExtensibleField<3>,
+    A::PublicInputs: Deserializable,
+{
+    fn handle_request(&self, request: &[u8]) -> Vec<u8> {
+        encode_response(self.process_request(request))
+    }
+}
+
+// TRACE WINDOW
+// ================================================================================================
+
+/// Rows of an extended execution trace needed to evaluate constraints over a contiguous range of
+/// steps of the constraint evaluation domain.
+///
+/// The window contains one row per step of the constraint evaluation domain starting with step
+/// `first_step`; the next row of an evaluation frame is located `frame_distance` rows after its
+/// current row.
+struct TraceWindow<E: FieldElement> {
+    first_step: usize,
+    lde_shift: u32,
+    frame_distance: usize,
+    main_width: usize,
+    main_rows: Vec<E::BaseField>,
+    aux_width: usize,
+    aux_rows: Vec<E>,
+}
+
+impl<E: FieldElement> TraceWindow<E> {
+    fn new(
+        first_step: usize,
+        num_rows: usize,
+        ce_to_lde_blowup: usize,
+        frame_distance: usize,
+        (main_width, main_rows): (usize, Vec<E::BaseField>),
+        (aux_width, aux_rows): (usize, Vec<E>),
+    ) -> Result<Self, String> {
+        if main_rows.len() != num_rows * main_width || aux_rows.len() != num_rows * aux_width {
+            return Err("trace window does not contain the expected number of rows".to_string());
+        }
+        Ok(Self {
+            first_step,
+            lde_shift: ce_to_lde_blowup.trailing_zeros(),
+            frame_distance,
+            main_width,
+            main_rows,
+            aux_width,
+            aux_rows,
+        })
+    }
+
+    /// Returns the index of the row in the window which corresponds to the specified step in
+    /// the LDE domain.
+    fn row_idx(&self, lde_step: usize) -> usize {
+        (lde_step >> self.lde_shift) - self.first_step
+    }
+}
+
+impl<E: FieldElement> TraceFrameReader<E> for TraceWindow<E> {
+    fn main_trace_width(&self) -> usize {
+        self.main_width
+    }
+
+    fn aux_trace_width(&self) -> usize {
+        self.aux_width
+    }
+
+    fn read_main_trace_frame_into(
+        &self,
+        lde_step: usize,
+        frame: &mut EvaluationFrame<E::BaseField>,
+    ) {
+        let current = self.row_idx(lde_step) * self.main_width;
+        let next = current + self.frame_distance * self.main_width;
+        frame
+            .current_mut()
+            .copy_from_slice(&self.main_rows[current..current + self.main_width]);
+        frame
+            .next_mut()
+            .copy_from_slice(&self.main_rows[next..next + self.main_width]);
+    }
+
+    fn read_aux_trace_frame_into(&self, lde_step: usize, frame: &mut EvaluationFrame<E>) {
+        let current = self.row_idx(lde_step) * self.aux_width;
+        let next = current + self.frame_distance * self.aux_width;
+        frame
+            .current_mut()
+            .copy_from_slice(&self.aux_rows[current..current + self.aux_width]);
+        frame
+            .next_mut()
+            .copy_from_slice(&self.aux_rows[next..next + self.aux_width]);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Hashes each of the received rows and builds a Merkle tree from the resulting hashes.
+fn commit_to_rows<E, H>(source: &mut SliceReader, target: &mut Vec<u8>) -> Result<(), String>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+{
+    let request = CommitToRowsRequest::<E>::read_from(source).map_err(|e| e.to_string())?;
+    let leaves = request
+        .rows
+        .chunks(request.row_width)
+        .map(|row| H::hash_elements(row))
+        .collect();
+    let tree = MerkleTree::<H>::new(leaves).map_err(|e| e.to_string())?;
+    tree.write_into(target);
+    Ok(())
+}
+
+/// Reads the degree of the field to which elements of the columns in a request belong; the
+/// degree must be either 1 (base field) or the degree of the extension field `E`.
+fn read_element_degree<E: FieldElement>(source: &mut SliceReader) -> Result<u8, String> {
+    let degree = source.read_u8().map_err(|e| e.to_string())?;
+    if degree != 1 && degree != extension_degree::<E>() {
+        return Err(format!("unsupported field element degree: {}", degree));
+    }
+    Ok(degree)
+}
+
+fn write_matrix<E: FieldElement>(matrix: &Matrix<E>, target: &mut Vec<u8>) {
+    let columns = matrix.columns().collect::<Vec<_>>();
+    super::messages::write_columns(&columns, target);
+}
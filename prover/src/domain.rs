@@ -7,16 +7,19 @@ use air::Air;
 use math::{fft, log2, StarkField};
 use utils::collections::Vec;
 
+#[cfg(not(feature = "std"))]
+use alloc::sync::Arc;
+
+#[cfg(feature = "std")]
+use std::sync::{Arc, RwLock};
+
 // TYPES AND INTERFACES
 // ================================================================================================
 
 pub struct StarkDomain<B: StarkField> {
-    /// Twiddles which can be used to evaluate polynomials in the trace domain. Length of this
-    /// vector is half the length of the trace domain size.
-    trace_twiddles: Vec<B>,
-
-    /// Size of the constraint evaluation domain.
-    ce_domain_size: usize,
+    /// Twiddles needed for FFT-based evaluation and interpolation over the trace and constraint
+    /// evaluation domains. These are shared between all domains built from the same cache entry.
+    twiddles: Arc<DomainTwiddles<B>>,
 
     /// LDE domain size / constraint evaluation domain size
     ce_to_lde_blowup: usize,
@@ -25,16 +28,30 @@ pub struct StarkDomain<B: StarkField> {
     domain_offset: B,
 }
 
+/// Twiddles which depend only on the sizes of the trace and constraint evaluation domains.
+struct DomainTwiddles<B: StarkField> {
+    /// Twiddles which can be used to evaluate polynomials in the trace domain. Length of this
+    /// vector is half the length of the trace domain size.
+    trace_twiddles: Vec<B>,
+
+    /// Inverse twiddles which can be used to interpolate polynomials over the trace domain.
+    trace_inv_twiddles: Vec<B>,
+
+    /// Inverse twiddles which can be used to interpolate polynomials over the constraint
+    /// evaluation domain. Length of this vector is half the size of the constraint evaluation
+    /// domain.
+    ce_inv_twiddles: Vec<B>,
+}
+
 // STARK DOMAIN IMPLEMENTATION
 // ================================================================================================
 
 impl<B: StarkField> StarkDomain<B> {
     /// Returns a new STARK domain initialized with the provided `context`.
     pub fn new<A: Air<BaseField = B>>(air: &A) -> Self {
-        let trace_twiddles = fft::get_twiddles(air.trace_length());
+        let twiddles = DomainTwiddles::new(air.trace_length(), air.ce_domain_size());
         StarkDomain {
-            trace_twiddles,
-            ce_domain_size: air.ce_domain_size(),
+            twiddles: Arc::new(twiddles),
             ce_to_lde_blowup: air.lde_domain_size() / air.ce_domain_size(),
             domain_offset: air.domain_offset(),
         }
@@ -45,12 +62,17 @@ impl<B: StarkField> StarkDomain<B> {
 
     /// Returns length of the execution trace for this computation.
     pub fn trace_length(&self) -> usize {
-        &self.trace_twiddles.len() * 2
+        self.twiddles.trace_twiddles.len() * 2
     }
 
     /// Returns twiddles which can be used to evaluate trace polynomials.
     pub fn trace_twiddles(&self) -> &[B] {
-        &self.trace_twiddles
+        &self.twiddles.trace_twiddles
+    }
+
+    /// Returns inverse twiddles which can be used to interpolate trace columns into polynomials.
+    pub fn trace_inv_twiddles(&self) -> &[B] {
+        &self.twiddles.trace_inv_twiddles
     }
 
     /// Returns blowup factor from trace to constraint evaluation domain.
@@ -68,7 +90,7 @@ impl<B: StarkField> StarkDomain<B> {
 
     /// Returns the size of the constraint evaluation domain for this computation.
     pub fn ce_domain_size(&self) -> usize {
-        self.ce_domain_size
+        self.twiddles.ce_inv_twiddles.len() * 2
     }
 
     /// Returns the generator of constraint evaluation domain.
@@ -81,6 +103,12 @@ impl<B: StarkField> StarkDomain<B> {
         self.ce_to_lde_blowup
     }
 
+    /// Returns inverse twiddles which can be used to interpolate polynomials from their
+    /// evaluations over the constraint evaluation domain.
+    pub fn ce_inv_twiddles(&self) -> &[B] {
+        &self.twiddles.ce_inv_twiddles
+    }
+
     // LOW-DEGREE EXTENSION DOMAIN
     // --------------------------------------------------------------------------------------------
 
@@ -94,3 +122,281 @@ impl<B: StarkField> StarkDomain<B> {
         self.domain_offset
     }
 }
+
+impl<B: StarkField> Clone for StarkDomain<B> {
+    /// Returns a copy of this domain; twiddles are shared between the copies.
+    fn clone(&self) -> Self {
+        Self {
+            twiddles: self.twiddles.clone(),
+            ce_to_lde_blowup: self.ce_to_lde_blowup,
+            domain_offset: self.domain_offset,
+        }
+    }
+}
+
+impl<B: StarkField> DomainTwiddles<B> {
+    fn new(trace_length: usize, ce_domain_size: usize) -> Self {
+        Self {
+            trace_twiddles: fft::get_twiddles(trace_length),
+            trace_inv_twiddles: fft::get_inv_twiddles(trace_length),
+            ce_inv_twiddles: fft::get_inv_twiddles(ce_domain_size),
+        }
+    }
+}
+
+// DOMAIN CACHE
+// ================================================================================================
+
+/// A thread-safe cache of STARK domains which can be shared across many proofs.
+///
+/// Building a [StarkDomain] requires computing twiddles for the trace and constraint evaluation
+/// domains; for large traces this takes a noticeable amount of time. When many proofs are
+/// generated for traces of the same shape, a single cache can be shared between provers (e.g.,
+/// by returning it from [Prover::domain_cache()](crate::Prover::domain_cache)), and the domains
+/// will be computed only once.
+///
+/// Domains are keyed by the base field (via the `B` type parameter), trace length, constraint
+/// evaluation domain size, LDE domain size, and domain offset. Domains which are known in advance
+/// can be computed ahead of time via [DomainCache::preload()] or [DomainCache::preload_domain()].
+#[cfg(feature = "std")]
+#[derive(Default)]
+pub struct DomainCache<B: StarkField> {
+    domains: RwLock<Vec<(DomainKey<B>, StarkDomain<B>)>>,
+}
+
+#[cfg(feature = "std")]
+impl<B: StarkField> DomainCache<B> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new empty domain cache.
+    pub fn new() -> Self {
+        Self {
+            domains: RwLock::new(Vec::new()),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of domains stored in this cache.
+    pub fn len(&self) -> usize {
+        self.domains
+            .read()
+            .expect("domain cache lock poisoned")
+            .len()
+    }
+
+    /// Returns true if this cache does not contain any domains.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a STARK domain for the computation described by the provided AIR.
+    ///
+    /// If a matching domain is not in the cache yet, the domain is built and added to the cache.
+    pub fn get_domain<A: Air<BaseField = B>>(&self, air: &A) -> StarkDomain<B> {
+        let key = DomainKey {
+            trace_length: air.trace_length(),
+            ce_domain_size: air.ce_domain_size(),
+            lde_domain_size: air.lde_domain_size(),
+            domain_offset: air.domain_offset(),
+        };
+        self.get_or_build(key)
+    }
+
+    // PRELOADING
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds a STARK domain for the computation described by the provided AIR and adds it to
+    /// this cache, unless the cache already contains such a domain.
+    pub fn preload<A: Air<BaseField = B>>(&self, air: &A) {
+        self.get_domain(air);
+    }
+
+    /// Builds a STARK domain with the specified parameters and adds it to this cache, unless the
+    /// cache already contains such a domain.
+    ///
+    /// Constraint evaluation domain size is computed as `trace_length` * `ce_blowup_factor`, and
+    /// LDE domain size is computed as `trace_length` * `lde_blowup_factor`.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `trace_length`, `ce_blowup_factor` or `lde_blowup_factor` is not a power of two.
+    /// * `ce_blowup_factor` is greater than `lde_blowup_factor`.
+    /// * `domain_offset` is ZERO.
+    pub fn preload_domain(
+        &self,
+        trace_length: usize,
+        ce_blowup_factor: usize,
+        lde_blowup_factor: usize,
+        domain_offset: B,
+    ) {
+        assert!(
+            trace_length.is_power_of_two(),
+            "trace length must be a power of 2"
+        );
+        assert!(
+            ce_blowup_factor.is_power_of_two(),
+            "constraint evaluation blowup factor must be a power of 2"
+        );
+        assert!(
+            lde_blowup_factor.is_power_of_two(),
+            "LDE blowup factor must be a power of 2"
+        );
+        assert!(
+            ce_blowup_factor <= lde_blowup_factor,
+            "constraint evaluation blowup factor cannot be greater than LDE blowup factor"
+        );
+        assert_ne!(domain_offset, B::ZERO, "domain offset cannot be zero");
+
+        self.get_or_build(DomainKey {
+            trace_length,
+            ce_domain_size: trace_length * ce_blowup_factor,
+            lde_domain_size: trace_length * lde_blowup_factor,
+            domain_offset,
+        });
+    }
+
+    /// Returns a STARK domain with the specified sizes and offset, building it if a matching
+    /// domain is not in the cache yet.
+    pub(crate) fn get_domain_with_sizes(
+        &self,
+        trace_length: usize,
+        ce_domain_size: usize,
+        lde_domain_size: usize,
+        domain_offset: B,
+    ) -> StarkDomain<B> {
+        self.get_or_build(DomainKey {
+            trace_length,
+            ce_domain_size,
+            lde_domain_size,
+            domain_offset,
+        })
+    }
+
+    /// Removes all domains from this cache.
+    pub fn clear(&self) {
+        self.domains
+            .write()
+            .expect("domain cache lock poisoned")
+            .clear();
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn get_or_build(&self, key: DomainKey<B>) -> StarkDomain<B> {
+        // twiddles depend only on domain sizes; thus, they can be shared with an existing entry
+        // even if the domain itself is not in the cache (e.g., when only domain offsets differ)
+        let shared_twiddles = {
+            let domains = self.domains.read().expect("domain cache lock poisoned");
+            if let Some((_, domain)) = domains.iter().find(|(k, _)| *k == key) {
+                return domain.clone();
+            }
+            domains
+                .iter()
+                .find(|(k, _)| k.has_same_twiddles(&key))
+                .map(|(_, domain)| domain.twiddles.clone())
+        };
+
+        // build the twiddles without holding the lock so that other threads are not blocked
+        let twiddles = shared_twiddles
+            .unwrap_or_else(|| Arc::new(DomainTwiddles::new(key.trace_length, key.ce_domain_size)));
+        let domain = StarkDomain {
+            twiddles,
+            ce_to_lde_blowup: key.lde_domain_size / key.ce_domain_size,
+            domain_offset: key.domain_offset,
+        };
+
+        // if another thread inserted the same domain in the meantime, use the existing one
+        let mut domains = self.domains.write().expect("domain cache lock poisoned");
+        if let Some((_, existing)) = domains.iter().find(|(k, _)| *k == key) {
+            return existing.clone();
+        }
+        domains.push((key, domain.clone()));
+        domain
+    }
+}
+
+/// Parameters which uniquely identify a STARK domain in a given base field.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DomainKey<B: StarkField> {
+    trace_length: usize,
+    ce_domain_size: usize,
+    lde_domain_size: usize,
+    domain_offset: B,
+}
+
+#[cfg(feature = "std")]
+impl<B: StarkField> DomainKey<B> {
+    fn has_same_twiddles(&self, other: &Self) -> bool {
+        self.trace_length == other.trace_length && self.ce_domain_size == other.ce_domain_size
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{Arc, DomainCache, StarkDomain};
+    use crate::tests::MockAir;
+    use air::Air;
+    use math::{fields::f128::BaseElement, FieldElement};
+
+    #[test]
+    fn domain_cache_reuses_domains() {
+        let cache = DomainCache::<BaseElement>::new();
+        assert!(cache.is_empty());
+
+        let air = MockAir::with_trace_length(32);
+        let domain = cache.get_domain(&air);
+        let expected = StarkDomain::new(&air);
+        assert_eq!(expected.trace_length(), domain.trace_length());
+        assert_eq!(expected.ce_domain_size(), domain.ce_domain_size());
+        assert_eq!(expected.lde_domain_size(), domain.lde_domain_size());
+        assert_eq!(expected.offset(), domain.offset());
+        assert_eq!(expected.trace_twiddles(), domain.trace_twiddles());
+        assert_eq!(expected.trace_inv_twiddles(), domain.trace_inv_twiddles());
+        assert_eq!(expected.ce_inv_twiddles(), domain.ce_inv_twiddles());
+
+        // requesting the same domain again should not build a new one
+        let domain2 = cache.get_domain(&MockAir::with_trace_length(32));
+        assert_eq!(1, cache.len());
+        assert!(Arc::ptr_eq(&domain.twiddles, &domain2.twiddles));
+
+        // a domain of a different size should be added to the cache
+        cache.get_domain(&MockAir::with_trace_length(64));
+        assert_eq!(2, cache.len());
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn domain_cache_preload() {
+        let cache = DomainCache::<BaseElement>::new();
+        let air = MockAir::with_trace_length(32);
+        let ce_blowup = air.ce_domain_size() / air.trace_length();
+        let lde_blowup = air.lde_domain_size() / air.trace_length();
+
+        // preloading via AIR and via explicit parameters should result in the same domain
+        cache.preload(&air);
+        cache.preload_domain(32, ce_blowup, lde_blowup, air.domain_offset());
+        assert_eq!(1, cache.len());
+
+        // domains which differ only in the offset should share twiddles
+        let offset = air.domain_offset().square();
+        cache.preload_domain(32, ce_blowup, lde_blowup, offset);
+        assert_eq!(2, cache.len());
+        let domain = cache.get_domain(&air);
+        let domains = cache.domains.read().unwrap();
+        let (_, shifted) = domains
+            .iter()
+            .find(|(k, _)| k.domain_offset == offset)
+            .unwrap();
+        assert!(Arc::ptr_eq(&domain.twiddles, &shifted.twiddles));
+        assert_eq!(offset, shifted.offset());
+    }
+}
@@ -5,8 +5,12 @@
 
 //! Contains common error types for prover and verifier.
 
+use air::HashFunction;
 use core::fmt;
 
+#[cfg(feature = "std")]
+use std::string::String;
+
 // PROVER ERROR
 // ================================================================================================
 /// Represents an error returned by the prover during an execution of the protocol.
@@ -21,6 +25,24 @@ pub enum ProverError {
     /// This error occurs when the base field specified by the AIR does not support field extension
     /// of degree specified by proof options.
     UnsupportedFieldExtension(usize),
+    /// This error occurs when the base field specified by the AIR cannot be used with the hash
+    /// function specified by proof options.
+    UnsupportedHashFunction(HashFunction),
+    /// This error occurs when the length of an execution trace is not a power of two, but the
+    /// AIR of the computation does not declare a padding policy for such traces.
+    TraceLengthNotPowerOfTwo(usize),
+    /// This error occurs when an execution trace needs to be padded to a power of two, but the
+    /// trace does not provide mutable access to its main segment.
+    TracePaddingNotSupported(usize),
+    /// This error occurs when a continuation is to be proven but no segments were provided.
+    EmptyContinuation,
+    /// This error occurs when the initial state of the specified segment of a continuation does
+    /// not match the final state of the previous segment.
+    DisconnectedSegments(usize),
+    /// This error occurs when a task of a distributed proof generation could not be completed
+    /// because a worker could not be reached or failed to process the task.
+    #[cfg(feature = "std")]
+    DistributedTaskFailed(String),
 }
 
 impl fmt::Display for ProverError {
@@ -36,6 +58,25 @@ impl fmt::Display for ProverError {
             Self::UnsupportedFieldExtension(degree) => {
                 write!(f, "field extension of degree {} is not supported for the specified base field", degree)
             }
+            Self::UnsupportedHashFunction(hash_fn) => {
+                write!(f, "hash function {:?} is not supported for the specified base field", hash_fn)
+            }
+            Self::TraceLengthNotPowerOfTwo(length) => {
+                write!(f, "execution trace length must be a power of two, but was {}; the AIR does not declare a padding policy", length)
+            }
+            Self::TracePaddingNotSupported(length) => {
+                write!(f, "execution trace of length {} must be padded to a power of two, but the trace does not support padding", length)
+            }
+            Self::EmptyContinuation => {
+                write!(f, "a continuation must contain at least one segment")
+            }
+            Self::DisconnectedSegments(segment) => {
+                write!(f, "initial state of segment {} does not match the final state of segment {}", segment, segment - 1)
+            }
+            #[cfg(feature = "std")]
+            Self::DistributedTaskFailed(reason) => {
+                write!(f, "distributed proving task failed: {}", reason)
+            }
         }
     }
 }
@@ -44,10 +44,16 @@
 extern crate alloc;
 
 pub use air::{
-    proof::StarkProof, Air, AirContext, Assertion, AuxTraceRandElements, BoundaryConstraint,
-    BoundaryConstraintGroup, ConstraintCompositionCoefficients, ConstraintDivisor,
-    DeepCompositionCoefficients, EvaluationFrame, FieldExtension, HashFunction, ProofOptions,
-    TraceInfo, TraceLayout, TransitionConstraintDegree, TransitionConstraintGroup,
+    builder, gadgets,
+    proof::{
+        Context, ContinuationProof, DecodedFriLayer, DecodedProof, DecodedQueries, ProofSection,
+        SegmentProof, StarkProof,
+    },
+    Air, AirContext, Assertion, AuxTraceRandElements, BoundaryConstraint, BoundaryConstraintGroup,
+    ConstraintCompositionCoefficients, ConstraintDivisor, DeepCompositionCoefficients,
+    EvaluationFrame, FieldExtension, HashFunction, ProofDeserializationError, ProofOptions,
+    SegmentPublicInputs, TraceInfo, TraceLayout, TracePadding, TransitionConstraintDegree,
+    TransitionConstraintGroup, TransitionDivisor,
 };
 pub use utils::{
     iterators, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
@@ -66,7 +72,7 @@ use math::{
 
 pub use crypto;
 use crypto::{
-    hashers::{Blake3_192, Blake3_256, Sha3_256},
+    hashers::{Blake3_192, Blake3_256, Keccak256, Poseidon64_256, Sha3_256},
     ElementHasher, MerkleTree,
 };
 
@@ -80,8 +86,16 @@ use std::time::Instant;
 mod domain;
 pub use domain::StarkDomain;
 
+#[cfg(feature = "std")]
+pub use domain::DomainCache;
+
+#[cfg(feature = "std")]
+mod distributed;
+#[cfg(feature = "std")]
+pub use distributed::{LocalTransport, ProverWorker, RequestHandler, SocketTransport, Transport};
+
 mod matrix;
-pub use matrix::Matrix;
+pub use matrix::{LdeMatrix, Matrix, MatrixLayout, RowMatrix};
 
 mod constraints;
 use constraints::{CompositionPoly, ConstraintCommitment, ConstraintEvaluator};
@@ -91,7 +105,7 @@ use composer::DeepCompositionPoly;
 
 mod trace;
 pub use trace::{Trace, TraceTable, TraceTableFragment};
-use trace::{TraceCommitment, TraceLde, TracePolyTable};
+use trace::{TraceCommitment, TraceFrameReader, TraceLde, TracePolyTable};
 
 mod channel;
 use channel::ProverChannel;
@@ -149,6 +163,41 @@ pub trait Prover {
     // PROVIDED METHODS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns the memory layout of low-degree extensions of the execution trace and of the
+    /// constraint composition polynomial built by this prover.
+    ///
+    /// The layout does not affect the generated proofs. [MatrixLayout::RowMajor] makes hashing of
+    /// LDE rows and reading of evaluation frames during constraint evaluation more cache friendly
+    /// at the expense of transposing the results of column-wise FFTs. By default,
+    /// [MatrixLayout::ColumnMajor] is used.
+    fn lde_layout(&self) -> MatrixLayout {
+        MatrixLayout::default()
+    }
+
+    /// Returns a cache of STARK domains which this prover draws domains from.
+    ///
+    /// Domains contain twiddles needed for FFT-based polynomial evaluation and interpolation.
+    /// When a cache is provided, a domain is built only the first time a trace of a given shape
+    /// is proved, and is then reused for all subsequent proofs. A single [DomainCache] can be
+    /// shared between many provers and threads. By default, no cache is used and a new domain is
+    /// built for every proof.
+    #[cfg(feature = "std")]
+    fn domain_cache(&self) -> Option<&DomainCache<Self::BaseField>> {
+        None
+    }
+
+    /// Returns a transport to workers among which proof generation is distributed.
+    ///
+    /// When a transport is provided, low-degree extension of the execution trace and of the
+    /// constraint composition polynomial, building of Merkle trees over the extended values, and
+    /// constraint evaluation are split into tasks executed by [ProverWorker]s reachable via the
+    /// transport. The generated proofs are exactly the same as without a transport. By default,
+    /// no transport is used and all work is done in the current process.
+    #[cfg(feature = "std")]
+    fn transport(&self) -> Option<&dyn Transport> {
+        None
+    }
+
     /// Returns a STARK proof attesting to a correct execution of a computation defined by the
     /// provided trace.
     ///
@@ -160,11 +209,17 @@ pub trait Prover {
     fn prove(&self, trace: Self::Trace) -> Result<StarkProof, ProverError> {
         // figure out which version of the generic proof generation procedure to run. this is a sort
         // of static dispatch for selecting two generic parameter: extension field and hash function.
+        let hash_fn = self.options().hash_fn();
+        if hash_fn == HashFunction::Poseidon64_256 && !<Poseidon64_256<Self::BaseField>>::is_supported() {
+            return Err(ProverError::UnsupportedHashFunction(hash_fn));
+        }
         match self.options().field_extension() {
             FieldExtension::None => match self.options().hash_fn() {
                 HashFunction::Blake3_256 => self.generate_proof::<Self::BaseField, Blake3_256<Self::BaseField>>(trace),
                 HashFunction::Blake3_192 => self.generate_proof::<Self::BaseField, Blake3_192<Self::BaseField>>(trace),
                 HashFunction::Sha3_256 => self.generate_proof::<Self::BaseField, Sha3_256<Self::BaseField>>(trace),
+                HashFunction::Keccak256 => self.generate_proof::<Self::BaseField, Keccak256<Self::BaseField>>(trace),
+                HashFunction::Poseidon64_256 => self.generate_proof::<Self::BaseField, Poseidon64_256<Self::BaseField>>(trace),
             },
             FieldExtension::Quadratic => {
                 if !<QuadExtension<Self::BaseField>>::is_supported() {
@@ -174,6 +229,8 @@ pub trait Prover {
                     HashFunction::Blake3_256 => self.generate_proof::<QuadExtension<Self::BaseField>, Blake3_256<Self::BaseField>>(trace),
                     HashFunction::Blake3_192 => self.generate_proof::<QuadExtension<Self::BaseField>, Blake3_192<Self::BaseField>>(trace),
                     HashFunction::Sha3_256 => self.generate_proof::<QuadExtension<Self::BaseField>, Sha3_256<Self::BaseField>>(trace),
+                    HashFunction::Keccak256 => self.generate_proof::<QuadExtension<Self::BaseField>, Keccak256<Self::BaseField>>(trace),
+                    HashFunction::Poseidon64_256 => self.generate_proof::<QuadExtension<Self::BaseField>, Poseidon64_256<Self::BaseField>>(trace),
                 }
             }
             FieldExtension::Cubic => {
@@ -184,9 +241,53 @@ pub trait Prover {
                     HashFunction::Blake3_256 => self.generate_proof::<CubeExtension<Self::BaseField>, Blake3_256<Self::BaseField>>(trace),
                     HashFunction::Blake3_192 => self.generate_proof::<CubeExtension<Self::BaseField>, Blake3_192<Self::BaseField>>(trace),
                     HashFunction::Sha3_256 => self.generate_proof::<CubeExtension<Self::BaseField>, Sha3_256<Self::BaseField>>(trace),
+                    HashFunction::Keccak256 => self.generate_proof::<CubeExtension<Self::BaseField>, Keccak256<Self::BaseField>>(trace),
+                    HashFunction::Poseidon64_256 => self.generate_proof::<CubeExtension<Self::BaseField>, Poseidon64_256<Self::BaseField>>(trace),
+                }
+            }
+        }
+    }
+
+    /// Returns a proof attesting to a correct execution of a long computation which was split
+    /// into the provided sequence of segment traces.
+    ///
+    /// Each segment is proven separately via [prove()](Prover::prove), and thus, traces of
+    /// individual segments can be generated lazily by the `segments` iterator. Public inputs of
+    /// every segment are computed via [Self::get_pub_inputs()](Prover::get_pub_inputs) and are
+    /// included in the returned [ContinuationProof]; the final state exposed by public inputs of
+    /// every segment must match the initial state of the next segment.
+    ///
+    /// # Errors
+    /// Returns an error if `segments` is empty, if boundary states of consecutive segments do not
+    /// match, or if a proof for any of the segments could not be generated.
+    fn prove_continuation<I>(&self, segments: I) -> Result<ContinuationProof, ProverError>
+    where
+        I: IntoIterator<Item = Self::Trace>,
+        <Self::Air as Air>::PublicInputs: SegmentPublicInputs,
+    {
+        let mut result = Vec::new();
+        let mut prev_state = None;
+        for (i, trace) in segments.into_iter().enumerate() {
+            let pub_inputs = self.get_pub_inputs(&trace);
+            if let Some(prev_state) = prev_state {
+                if pub_inputs.initial_state() != prev_state {
+                    return Err(ProverError::DisconnectedSegments(i));
                 }
             }
+            prev_state = Some(pub_inputs.final_state());
+
+            #[cfg(feature = "std")]
+            debug!("Generating proof for segment {} of the continuation", i);
+            result.push(SegmentProof {
+                pub_inputs: pub_inputs.to_bytes(),
+                proof: self.prove(trace)?,
+            });
         }
+
+        if result.is_empty() {
+            return Err(ProverError::EmptyContinuation);
+        }
+        Ok(ContinuationProof { segments: result })
     }
 
     // HELPER METHODS
@@ -213,16 +314,26 @@ pub trait Prover {
         // execution of the computation for the provided public inputs.
         let air = Self::Air::new(trace.get_info(), pub_inputs, self.options().clone());
 
+        // pad the main segment of the trace to a power of two as specified by the AIR; this needs
+        // to happen after the AIR is instantiated so that the AIR is aware of the original length
+        trace::pad_main_segment(&air, &mut trace)?;
+
         // create a channel which is used to simulate interaction between the prover and the
         // verifier; the channel will be used to commit to values and to draw randomness that
         // should come from the verifier.
-        let mut channel = ProverChannel::<Self::Air, E, H>::new(&air, pub_inputs_bytes);
+        let mut channel = ProverChannel::<Self::Air, E, H>::new(&air, &pub_inputs_bytes);
 
         // 1 ----- Commit to the execution trace --------------------------------------------------
 
         // build computation domain; this is used later for polynomial evaluations
         #[cfg(feature = "std")]
         let now = Instant::now();
+        #[cfg(feature = "std")]
+        let domain = match self.domain_cache() {
+            Some(cache) => cache.get_domain(&air),
+            None => StarkDomain::new(&air),
+        };
+        #[cfg(not(feature = "std"))]
         let domain = StarkDomain::new(&air);
         #[cfg(feature = "std")]
         debug!(
@@ -233,7 +344,7 @@ pub trait Prover {
 
         // extend the main execution trace and build a Merkle tree from the extended trace
         let (main_trace_lde, main_trace_tree, main_trace_polys) =
-            self.build_trace_commitment::<Self::BaseField, H>(trace.main_segment(), &domain);
+            self.build_trace_commitment::<Self::BaseField, H>(trace.main_segment(), &domain)?;
 
         // commit to the LDE of the main trace by writing the root of its Merkle tree into
         // the channel
@@ -264,7 +375,7 @@ pub trait Prover {
 
             // extend the auxiliary trace segment and build a Merkle tree from the extended trace
             let (aux_segment_lde, aux_segment_tree, aux_segment_polys) =
-                self.build_trace_commitment::<E, H>(&aux_segment, &domain);
+                self.build_trace_commitment::<E, H>(&aux_segment, &domain)?;
 
             // commit to the LDE of the extended auxiliary trace segment  by writing the root of
             // its Merkle tree into the channel
@@ -293,8 +404,24 @@ pub trait Prover {
         #[cfg(feature = "std")]
         let now = Instant::now();
         let constraint_coeffs = channel.get_constraint_composition_coeffs();
-        let evaluator = ConstraintEvaluator::new(&air, aux_trace_rand_elements, constraint_coeffs);
-        let constraint_evaluations = evaluator.evaluate(trace_commitment.trace_table(), &domain);
+        #[cfg(feature = "std")]
+        let constraint_evaluations = match self.transport() {
+            Some(transport) => distributed::evaluate_constraints(
+                transport,
+                &air,
+                &pub_inputs_bytes,
+                aux_trace_rand_elements,
+                constraint_coeffs,
+                trace_commitment.trace_table(),
+                &domain,
+            )?,
+            None => ConstraintEvaluator::new(&air, aux_trace_rand_elements, constraint_coeffs)
+                .evaluate(trace_commitment.trace_table(), &domain),
+        };
+        #[cfg(not(feature = "std"))]
+        let constraint_evaluations =
+            ConstraintEvaluator::new(&air, aux_trace_rand_elements, constraint_coeffs)
+                .evaluate(trace_commitment.trace_table(), &domain);
         #[cfg(feature = "std")]
         debug!(
             "Evaluated constraints over domain of 2^{} elements in {} ms",
@@ -312,7 +439,7 @@ pub trait Prover {
         //   trace_length - 1
         #[cfg(feature = "std")]
         let now = Instant::now();
-        let composition_poly = constraint_evaluations.into_poly()?;
+        let composition_poly = constraint_evaluations.into_poly(&domain)?;
         #[cfg(feature = "std")]
         debug!(
             "Converted constraint evaluations into {} composition polynomial columns of degree {} in {} ms",
@@ -323,7 +450,7 @@ pub trait Prover {
 
         // then, build a commitment to the evaluations of the composition polynomial columns
         let constraint_commitment =
-            self.build_constraint_commitment::<E, H>(&composition_poly, &domain);
+            self.build_constraint_commitment::<E, H>(&composition_poly, &domain)?;
 
         // then, commit to the evaluations of constraints by writing the root of the constraint
         // Merkle tree into the channel
@@ -452,15 +579,18 @@ pub trait Prover {
     ///
     /// The extension is performed by interpolating each column of the execution trace into a
     /// polynomial of degree = trace_length - 1, and then evaluating the polynomial over the LDE
-    /// domain.
+    /// domain. The extended trace is arranged in memory as specified by [Prover::lde_layout()].
     ///
     /// Trace commitment is computed by hashing each row of the extended execution trace, and then
     /// building a Merkle tree from the resulting hashes.
+    ///
+    /// When [Prover::transport()] returns a transport, both steps are distributed among workers.
+    #[allow(clippy::type_complexity)]
     fn build_trace_commitment<E, H>(
         &self,
         trace: &Matrix<E>,
         domain: &StarkDomain<Self::BaseField>,
-    ) -> (Matrix<E>, MerkleTree<H>, Matrix<E>)
+    ) -> Result<(LdeMatrix<E>, MerkleTree<H>, Matrix<E>), ProverError>
     where
         E: FieldElement<BaseField = Self::BaseField>,
         H: ElementHasher<BaseField = Self::BaseField>,
@@ -468,8 +598,26 @@ pub trait Prover {
         // extend the execution trace
         #[cfg(feature = "std")]
         let now = Instant::now();
-        let trace_polys = trace.interpolate_columns();
-        let trace_lde = trace_polys.evaluate_columns_over(domain);
+        #[cfg(feature = "std")]
+        let (trace_polys, trace_lde) = match self.transport() {
+            Some(transport) => distributed::extend_trace(
+                transport,
+                self.options(),
+                trace,
+                domain,
+                self.lde_layout(),
+            )?,
+            None => {
+                let trace_polys =
+                    trace.interpolate_columns_with_twiddles(domain.trace_inv_twiddles());
+                let trace_lde = LdeMatrix::evaluate(&trace_polys, domain, self.lde_layout());
+                (trace_polys, trace_lde)
+            }
+        };
+        #[cfg(not(feature = "std"))]
+        let trace_polys = trace.interpolate_columns_with_twiddles(domain.trace_inv_twiddles());
+        #[cfg(not(feature = "std"))]
+        let trace_lde = LdeMatrix::evaluate(&trace_polys, domain, self.lde_layout());
         #[cfg(feature = "std")]
         debug!(
             "Extended execution trace of {} columns from 2^{} to 2^{} steps ({}x blowup) in {} ms",
@@ -483,6 +631,12 @@ pub trait Prover {
         // build trace commitment
         #[cfg(feature = "std")]
         let now = Instant::now();
+        #[cfg(feature = "std")]
+        let trace_tree = match self.transport() {
+            Some(transport) => distributed::commit_to_rows(transport, self.options(), &trace_lde)?,
+            None => trace_lde.commit_to_rows(),
+        };
+        #[cfg(not(feature = "std"))]
         let trace_tree = trace_lde.commit_to_rows();
         #[cfg(feature = "std")]
         debug!(
@@ -491,7 +645,7 @@ pub trait Prover {
             now.elapsed().as_millis()
         );
 
-        (trace_lde, trace_tree, trace_polys)
+        Ok((trace_lde, trace_tree, trace_polys))
     }
 
     /// Evaluates constraint composition polynomial over the LDE domain and builds a commitment
@@ -502,11 +656,13 @@ pub trait Prover {
     ///
     /// The commitment is computed by hashing each row in the evaluation matrix, and then building
     /// a Merkle tree from the resulting hashes.
+    ///
+    /// When [Prover::transport()] returns a transport, both steps are distributed among workers.
     fn build_constraint_commitment<E, H>(
         &self,
         composition_poly: &CompositionPoly<E>,
         domain: &StarkDomain<Self::BaseField>,
-    ) -> ConstraintCommitment<E, H>
+    ) -> Result<ConstraintCommitment<E, H>, ProverError>
     where
         E: FieldElement<BaseField = Self::BaseField>,
         H: ElementHasher<BaseField = Self::BaseField>,
@@ -514,7 +670,19 @@ pub trait Prover {
         // evaluate composition polynomial columns over the LDE domain
         #[cfg(feature = "std")]
         let now = Instant::now();
-        let composed_evaluations = composition_poly.evaluate(domain);
+        #[cfg(feature = "std")]
+        let composed_evaluations = match self.transport() {
+            Some(transport) => distributed::extend_polys(
+                transport,
+                self.options(),
+                composition_poly.data(),
+                domain,
+                self.lde_layout(),
+            )?,
+            None => composition_poly.evaluate(domain, self.lde_layout()),
+        };
+        #[cfg(not(feature = "std"))]
+        let composed_evaluations = composition_poly.evaluate(domain, self.lde_layout());
         #[cfg(feature = "std")]
         debug!(
             "Evaluated {} composition polynomial columns over LDE domain (2^{} elements) in {} ms",
@@ -526,6 +694,14 @@ pub trait Prover {
         // build constraint evaluation commitment
         #[cfg(feature = "std")]
         let now = Instant::now();
+        #[cfg(feature = "std")]
+        let commitment = match self.transport() {
+            Some(transport) => {
+                distributed::commit_to_rows(transport, self.options(), &composed_evaluations)?
+            }
+            None => composed_evaluations.commit_to_rows(),
+        };
+        #[cfg(not(feature = "std"))]
         let commitment = composed_evaluations.commit_to_rows();
         let constraint_commitment = ConstraintCommitment::new(composed_evaluations, commitment);
         #[cfg(feature = "std")]
@@ -534,6 +710,6 @@ pub trait Prover {
             constraint_commitment.tree_depth(),
             now.elapsed().as_millis()
         );
-        constraint_commitment
+        Ok(constraint_commitment)
     }
 }
@@ -1,4 +1,5 @@
-use super::StarkDomain;
+use super::row_matrix::transpose_into;
+use crate::StarkDomain;
 use core::{iter::FusedIterator, slice};
 use crypto::{ElementHasher, MerkleTree};
 use math::{fft, polynom, FieldElement};
@@ -7,6 +8,14 @@ use utils::{batch_iter_mut, collections::Vec, iter, iter_mut, uninit_vector};
 #[cfg(feature = "concurrent")]
 use utils::iterators::*;
 
+// CONSTANTS
+// ================================================================================================
+
+/// Number of columns which are interpolated or evaluated together using batched FFT. Larger
+/// batches amortize the cost of FFT twiddles across more columns, but require more memory for
+/// holding intermediate row-major values.
+const FFT_BATCH_SIZE: usize = 16;
+
 // MATRIX
 // ================================================================================================
 
@@ -21,7 +30,10 @@ use utils::iterators::*;
 /// A matrix imposes the following restrictions on its content:
 /// - A matrix must consist of at least 1 column and at least 2 rows.
 /// - All columns must be of the same length.
-/// - Number of rows must be a power of two.
+///
+/// Number of rows in a matrix is usually a power of two; methods which require this (e.g.,
+/// interpolation and evaluation of columns) panic otherwise. A matrix with any number of rows can
+/// be padded to a power of two via [Matrix::pad_rows()].
 #[derive(Debug, Clone)]
 pub struct Matrix<E: FieldElement> {
     columns: Vec<Vec<E>>,
@@ -37,7 +49,6 @@ impl<E: FieldElement> Matrix<E> {
     /// * The provided vector of columns is empty.
     /// * Not all of the columns have the same number of elements.
     /// * Number of rows is smaller than or equal to 1.
-    /// * Number of rows is not a power of two.
     pub fn new(columns: Vec<Vec<E>>) -> Self {
         assert!(
             !columns.is_empty(),
@@ -48,10 +59,6 @@ impl<E: FieldElement> Matrix<E> {
             num_rows > 1,
             "number of rows in a matrix must be greater than one"
         );
-        assert!(
-            num_rows.is_power_of_two(),
-            "number of rows in a matrix must be a power of 2"
-        );
         for column in columns.iter().skip(1) {
             assert_eq!(
                 column.len(),
@@ -122,6 +129,30 @@ impl<E: FieldElement> Matrix<E> {
         }
     }
 
+    /// Appends the specified number of rows to this matrix.
+    ///
+    /// Values of every appended row are computed by the `pad` closure which receives the values
+    /// of the previous row and a mutable slice for the values of the new row.
+    pub fn pad_rows<F>(&mut self, num_rows: usize, mut pad: F)
+    where
+        F: FnMut(&[E], &mut [E]),
+    {
+        let mut current = vec![E::ZERO; self.num_cols()];
+        let mut next = vec![E::ZERO; self.num_cols()];
+        self.read_row_into(self.num_rows() - 1, &mut current);
+        for column in self.columns.iter_mut() {
+            column.reserve_exact(num_rows);
+        }
+
+        for _ in 0..num_rows {
+            pad(&current, &mut next);
+            for (column, &value) in self.columns.iter_mut().zip(next.iter()) {
+                column.push(value);
+            }
+            core::mem::swap(&mut current, &mut next);
+        }
+    }
+
     // ITERATION
     // --------------------------------------------------------------------------------------------
 
@@ -144,16 +175,37 @@ impl<E: FieldElement> Matrix<E> {
     /// The interpolation is performed as follows:
     /// * Each column of the matrix is interpreted as evaluations of degree `num_rows - 1`
     ///   polynomial over a subgroup of size `num_rows`.
-    /// * Then each column is interpolated using iFFT algorithm into a polynomial in coefficient
-    ///   form.
+    /// * Then columns are interpolated in batches using batched iFFT algorithm into polynomials
+    ///   in coefficient form.
     /// * The resulting polynomials are returned as a single matrix where each column contains
     ///   coefficients of a degree `num_rows - 1` polynomial.
     pub fn interpolate_columns(&self) -> Self {
         let inv_twiddles = fft::get_inv_twiddles::<E::BaseField>(self.num_rows());
-        // TODO: get ride of cloning by introducing another version of fft::interpolate_poly()
-        let mut result = self.clone();
-        iter_mut!(result.columns).for_each(|column| fft::interpolate_poly(column, &inv_twiddles));
-        result
+        self.interpolate_columns_with_twiddles(&inv_twiddles)
+    }
+
+    /// Interpolates columns of the matrix into polynomials in coefficient form using the
+    /// provided inverse twiddles and returns the result.
+    ///
+    /// This is the same as [Matrix::interpolate_columns()] but allows reusing precomputed
+    /// twiddles (e.g., the ones provided by [StarkDomain::trace_inv_twiddles()]).
+    ///
+    /// # Panics
+    /// Panics if the length of `inv_twiddles` is not half the number of rows in this matrix.
+    pub fn interpolate_columns_with_twiddles(&self, inv_twiddles: &[E::BaseField]) -> Self {
+        let mut columns = (0..self.num_cols())
+            .map(|_| unsafe { uninit_vector(self.num_rows()) })
+            .collect::<Vec<_>>();
+        for (source, destination) in self
+            .columns
+            .chunks(FFT_BATCH_SIZE)
+            .zip(columns.chunks_mut(FFT_BATCH_SIZE))
+        {
+            let mut values = rows_from_columns(source);
+            fft::interpolate_polys(&mut values, source.len(), inv_twiddles);
+            write_rows_into_columns(&values, destination);
+        }
+        Self { columns }
     }
 
     /// Interpolates columns of the matrix into polynomials in coefficient form and returns the
@@ -162,13 +214,17 @@ impl<E: FieldElement> Matrix<E> {
     /// The interpolation is performed as follows:
     /// * Each column of the matrix is interpreted as evaluations of degree `num_rows - 1`
     ///   polynomial over a subgroup of size `num_rows`.
-    /// * Then each column is interpolated (in place) using iFFT algorithm into a polynomial in
-    ///   coefficient form.
+    /// * Then columns are interpolated in batches using batched iFFT algorithm into polynomials
+    ///   in coefficient form; the results are written back into the same columns.
     /// * The resulting polynomials are returned as a single matrix where each column contains
     ///   coefficients of a degree `num_rows - 1` polynomial.
     pub fn interpolate_columns_into(mut self) -> Self {
         let inv_twiddles = fft::get_inv_twiddles::<E::BaseField>(self.num_rows());
-        iter_mut!(self.columns).for_each(|column| fft::interpolate_poly(column, &inv_twiddles));
+        for batch in self.columns.chunks_mut(FFT_BATCH_SIZE) {
+            let mut values = rows_from_columns(batch);
+            fft::interpolate_polys(&mut values, batch.len(), &inv_twiddles);
+            write_rows_into_columns(&values, batch);
+        }
         self
     }
 
@@ -178,21 +234,29 @@ impl<E: FieldElement> Matrix<E> {
     /// The evaluation is done as follows:
     /// * Each column of the matrix is interpreted as coefficients of degree `num_rows - 1`
     ///   polynomial.
-    /// * These polynomials are evaluated over the LDE domain defined by the specified
-    ///   [StarkDomain] using FFT algorithm. The domain specification includes the size of the
-    ///   subgroup as well as the domain offset (to define a coset).
+    /// * These polynomials are evaluated in batches over the LDE domain defined by the specified
+    ///   [StarkDomain] using batched FFT algorithm. The domain specification includes the size of
+    ///   the subgroup as well as the domain offset (to define a coset).
     /// * The resulting evaluations are returned in a new Matrix.
     pub fn evaluate_columns_over(&self, domain: &StarkDomain<E::BaseField>) -> Self {
-        let columns = iter!(self.columns)
-            .map(|poly| {
-                fft::evaluate_poly_with_offset(
-                    poly,
-                    domain.trace_twiddles(),
-                    domain.offset(),
-                    domain.trace_to_lde_blowup(),
-                )
-            })
-            .collect();
+        let lde_domain_size = self.num_rows() * domain.trace_to_lde_blowup();
+        let mut columns = (0..self.num_cols())
+            .map(|_| unsafe { uninit_vector(lde_domain_size) })
+            .collect::<Vec<_>>();
+        for (source, destination) in self
+            .columns
+            .chunks(FFT_BATCH_SIZE)
+            .zip(columns.chunks_mut(FFT_BATCH_SIZE))
+        {
+            let evaluations = fft::evaluate_polys_with_offset(
+                &rows_from_columns(source),
+                source.len(),
+                domain.trace_twiddles(),
+                domain.offset(),
+                domain.trace_to_lde_blowup(),
+            );
+            write_rows_into_columns(&evaluations, destination);
+        }
         Self { columns }
     }
 
@@ -392,3 +456,27 @@ impl<'a, E: FieldElement> ExactSizeIterator for MultiColumnIter<'a, E> {
 }
 
 impl<'a, E: FieldElement> FusedIterator for MultiColumnIter<'a, E> {}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns values of the provided columns arranged in row-major order.
+fn rows_from_columns<E: FieldElement>(columns: &[Vec<E>]) -> Vec<E> {
+    let columns = columns.iter().map(|c| c.as_slice()).collect::<Vec<_>>();
+    let mut values = unsafe { uninit_vector(columns.len() * columns[0].len()) };
+    transpose_into(&mut values, columns.len(), 0, &columns);
+    values
+}
+
+/// Copies values of the row-major `values` into the provided columns; the number of columns
+/// determines the width of rows in `values`.
+fn write_rows_into_columns<E: FieldElement>(values: &[E], columns: &mut [Vec<E>]) {
+    let row_width = columns.len();
+    iter_mut!(columns)
+        .enumerate()
+        .for_each(|(col_idx, column)| {
+            for (value, row) in column.iter_mut().zip(values.chunks_exact(row_width)) {
+                *value = row[col_idx];
+            }
+        });
+}
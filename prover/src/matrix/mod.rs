@@ -0,0 +1,167 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use crate::StarkDomain;
+use crypto::{ElementHasher, MerkleTree};
+use math::FieldElement;
+use utils::collections::Vec;
+
+mod col_matrix;
+pub use col_matrix::{ColumnIter, Matrix, MultiColumnIter};
+
+mod row_matrix;
+pub use row_matrix::RowMatrix;
+
+#[cfg(test)]
+mod tests;
+
+// MATRIX LAYOUT
+// ================================================================================================
+
+/// Defines how low-degree extensions of the execution trace and of the constraint composition
+/// polynomial are arranged in memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixLayout {
+    /// Values of each column are stored contiguously. Rows are gathered from all columns when
+    /// they are hashed or read during constraint evaluation.
+    ColumnMajor,
+    /// Values of each row are stored contiguously. Column polynomials are evaluated together
+    /// using a batched FFT which writes the results directly into rows.
+    RowMajor,
+}
+
+impl Default for MatrixLayout {
+    fn default() -> Self {
+        Self::ColumnMajor
+    }
+}
+
+// LDE MATRIX
+// ================================================================================================
+
+/// Evaluations of column polynomials over the LDE domain arranged according to one of the
+/// supported [MatrixLayout]s.
+#[derive(Debug, Clone)]
+pub enum LdeMatrix<E: FieldElement> {
+    ColumnMajor(Matrix<E>),
+    RowMajor(RowMatrix<E>),
+}
+
+impl<E: FieldElement> LdeMatrix<E> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Evaluates polynomials contained in the columns of the provided matrix over the specified
+    /// domain, and arranges the results according to the specified layout.
+    pub fn evaluate(
+        polys: &Matrix<E>,
+        domain: &StarkDomain<E::BaseField>,
+        layout: MatrixLayout,
+    ) -> Self {
+        match layout {
+            MatrixLayout::ColumnMajor => Self::ColumnMajor(polys.evaluate_columns_over(domain)),
+            MatrixLayout::RowMajor => Self::RowMajor(RowMatrix::evaluate_polys_over(polys, domain)),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the layout of this matrix.
+    pub fn layout(&self) -> MatrixLayout {
+        match self {
+            Self::ColumnMajor(_) => MatrixLayout::ColumnMajor,
+            Self::RowMajor(_) => MatrixLayout::RowMajor,
+        }
+    }
+
+    /// Returns the number of columns in this matrix.
+    pub fn num_cols(&self) -> usize {
+        match self {
+            Self::ColumnMajor(matrix) => matrix.num_cols(),
+            Self::RowMajor(matrix) => matrix.num_cols(),
+        }
+    }
+
+    /// Returns the number of rows in this matrix.
+    pub fn num_rows(&self) -> usize {
+        match self {
+            Self::ColumnMajor(matrix) => matrix.num_rows(),
+            Self::RowMajor(matrix) => matrix.num_rows(),
+        }
+    }
+
+    /// Returns the element located at the specified column and row indexes in this matrix.
+    ///
+    /// # Panics
+    /// Panics if either `col_idx` or `row_idx` are out of bounds for this matrix.
+    pub fn get(&self, col_idx: usize, row_idx: usize) -> E {
+        match self {
+            Self::ColumnMajor(matrix) => matrix.get(col_idx, row_idx),
+            Self::RowMajor(matrix) => matrix.get(col_idx, row_idx),
+        }
+    }
+
+    /// Copies values of all columns at the specified row into the specified row slice.
+    ///
+    /// # Panics
+    /// Panics if `row_idx` is out of bounds for this matrix.
+    #[inline]
+    pub fn read_row_into(&self, row_idx: usize, row: &mut [E]) {
+        match self {
+            Self::ColumnMajor(matrix) => matrix.read_row_into(row_idx, row),
+            Self::RowMajor(matrix) => matrix.read_row_into(row_idx, row),
+        }
+    }
+
+    /// Returns a copy of the row at the specified index.
+    ///
+    /// # Panics
+    /// Panics if `row_idx` is out of bounds for this matrix.
+    pub fn get_row(&self, row_idx: usize) -> Vec<E> {
+        let mut row = vec![E::ZERO; self.num_cols()];
+        self.read_row_into(row_idx, &mut row);
+        row
+    }
+
+    /// Returns a copy of the column at the specified index.
+    ///
+    /// # Panics
+    /// Panics if `col_idx` is out of bounds for this matrix.
+    pub fn get_column(&self, col_idx: usize) -> Vec<E> {
+        match self {
+            Self::ColumnMajor(matrix) => matrix.get_column(col_idx).to_vec(),
+            Self::RowMajor(matrix) => matrix.get_column(col_idx),
+        }
+    }
+
+    // COMMITMENTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a commitment to this matrix.
+    ///
+    /// The commitment is a Merkle tree where each leaf is a hash of the corresponding row of the
+    /// matrix; the result does not depend on the layout of the matrix.
+    pub fn commit_to_rows<H>(&self) -> MerkleTree<H>
+    where
+        H: ElementHasher<BaseField = E::BaseField>,
+    {
+        match self {
+            Self::ColumnMajor(matrix) => matrix.commit_to_rows(),
+            Self::RowMajor(matrix) => matrix.commit_to_rows(),
+        }
+    }
+}
+
+impl<E: FieldElement> From<Matrix<E>> for LdeMatrix<E> {
+    fn from(matrix: Matrix<E>) -> Self {
+        Self::ColumnMajor(matrix)
+    }
+}
+
+impl<E: FieldElement> From<RowMatrix<E>> for LdeMatrix<E> {
+    fn from(matrix: RowMatrix<E>) -> Self {
+        Self::RowMajor(matrix)
+    }
+}
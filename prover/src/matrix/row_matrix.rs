@@ -0,0 +1,238 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::Matrix;
+use crate::StarkDomain;
+use core::slice::ChunksExact;
+use crypto::{ElementHasher, MerkleTree};
+use math::{fft, FieldElement};
+use utils::{batch_iter_mut, collections::Vec, uninit_vector};
+
+#[cfg(feature = "concurrent")]
+use utils::iterators::*;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Minimum number of rows processed by a single thread when transposing or hashing rows.
+#[cfg(feature = "concurrent")]
+const MIN_CONCURRENT_ROWS: usize = 128;
+
+// ROW MATRIX
+// ================================================================================================
+
+/// A two-dimensional matrix of field elements arranged in row-major order.
+///
+/// Compared to [Matrix], this layout makes reading and hashing of individual rows cheap, since
+/// all values of a row are located next to each other in memory. This makes it well suited for
+/// storing low-degree extensions of the execution trace, rows of which are read during constraint
+/// evaluation and hashed when committing to the trace.
+///
+/// A matrix imposes the following restrictions on its content:
+/// - A matrix must consist of at least 1 column and at least 2 rows.
+/// - Number of rows must be a power of two.
+#[derive(Debug, Clone)]
+pub struct RowMatrix<E: FieldElement> {
+    data: Vec<E>,
+    row_width: usize,
+}
+
+impl<E: FieldElement> RowMatrix<E> {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new [RowMatrix] instantiated with the provided data; the data is interpreted as
+    /// a sequence of rows of `row_width` elements each.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `row_width` is zero.
+    /// * Length of the data is not divisible by `row_width`.
+    /// * Number of rows is smaller than or equal to 1.
+    /// * Number of rows is not a power of two.
+    pub fn new(data: Vec<E>, row_width: usize) -> Self {
+        assert!(row_width > 0, "a matrix must contain at least one column");
+        assert_eq!(
+            data.len() % row_width,
+            0,
+            "data length must be divisible by row width"
+        );
+        let num_rows = data.len() / row_width;
+        assert!(
+            num_rows > 1,
+            "number of rows in a matrix must be greater than one"
+        );
+        assert!(
+            num_rows.is_power_of_two(),
+            "number of rows in a matrix must be a power of 2"
+        );
+
+        Self { data, row_width }
+    }
+
+    /// Returns a new [RowMatrix] containing the same values as the provided column-major matrix.
+    pub fn from_columns(matrix: &Matrix<E>) -> Self {
+        let columns = matrix.columns().collect::<Vec<_>>();
+        let mut data = unsafe { uninit_vector(matrix.num_rows() * matrix.num_cols()) };
+        transpose_into(&mut data, matrix.num_cols(), 0, &columns);
+        Self::new(data, matrix.num_cols())
+    }
+
+    /// Evaluates polynomials contained in the columns of the provided matrix over the specified
+    /// domain and returns the result arranged in row-major order.
+    ///
+    /// The evaluation is done as follows:
+    /// * Each column of `polys` is interpreted as coefficients of degree `num_rows - 1`
+    ///   polynomial.
+    /// * The coefficients are transposed into rows, and all polynomials are evaluated at once
+    ///   over the LDE domain defined by the specified [StarkDomain] using batched FFT algorithm.
+    ///
+    /// Since batched FFT operates on row-major data, the evaluations are written directly into
+    /// rows of the returned matrix without any additional transposition.
+    pub fn evaluate_polys_over(polys: &Matrix<E>, domain: &StarkDomain<E::BaseField>) -> Self {
+        let row_width = polys.num_cols();
+        let coefficients = Self::from_columns(polys);
+        let data = fft::evaluate_polys_with_offset(
+            coefficients.data(),
+            row_width,
+            domain.trace_twiddles(),
+            domain.offset(),
+            domain.trace_to_lde_blowup(),
+        );
+        Self::new(data, row_width)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of columns in this matrix.
+    pub fn num_cols(&self) -> usize {
+        self.row_width
+    }
+
+    /// Returns the number of rows in this matrix.
+    pub fn num_rows(&self) -> usize {
+        self.data.len() / self.row_width
+    }
+
+    /// Returns the element located at the specified column and row indexes in this matrix.
+    ///
+    /// # Panics
+    /// Panics if either `col_idx` or `row_idx` are out of bounds for this matrix.
+    pub fn get(&self, col_idx: usize, row_idx: usize) -> E {
+        self.row(row_idx)[col_idx]
+    }
+
+    /// Returns a reference to the row at the specified index.
+    ///
+    /// # Panics
+    /// Panics if `row_idx` is out of bounds for this matrix.
+    pub fn row(&self, row_idx: usize) -> &[E] {
+        let start = row_idx * self.row_width;
+        &self.data[start..start + self.row_width]
+    }
+
+    /// Copies values of the row at the specified index into the specified row slice.
+    ///
+    /// # Panics
+    /// Panics if `row_idx` is out of bounds for this matrix.
+    #[inline]
+    pub fn read_row_into(&self, row_idx: usize, row: &mut [E]) {
+        let values = self.row(row_idx);
+        let len = values.len().min(row.len());
+        row[..len].copy_from_slice(&values[..len]);
+    }
+
+    /// Returns a copy of the column at the specified index.
+    ///
+    /// # Panics
+    /// Panics if `col_idx` is out of bounds for this matrix.
+    pub fn get_column(&self, col_idx: usize) -> Vec<E> {
+        assert!(col_idx < self.row_width, "column index out of bounds");
+        self.rows().map(|row| row[col_idx]).collect()
+    }
+
+    /// Returns the underlying row-major data of this matrix.
+    pub fn data(&self) -> &[E] {
+        &self.data
+    }
+
+    // ITERATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an iterator over the rows of this matrix.
+    pub fn rows(&self) -> ChunksExact<'_, E> {
+        self.data.chunks_exact(self.row_width)
+    }
+
+    // COMMITMENTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a commitment to this matrix.
+    ///
+    /// The commitment is built as follows:
+    /// * Each row of the matrix is hashed into a single digest of the specified hash function.
+    ///   Since rows are stored contiguously, they are hashed in place without being copied.
+    /// * The resulting values are used to built a binary Merkle tree such that each row digest
+    ///   becomes a leaf in the tree. Thus, the number of leaves in the tree is equal to the
+    ///   number of rows in the matrix.
+    /// * The resulting Merkle tree is return as the commitment to the entire matrix.
+    pub fn commit_to_rows<H>(&self) -> MerkleTree<H>
+    where
+        H: ElementHasher<BaseField = E::BaseField>,
+    {
+        // allocate vector to store row hashes
+        let mut row_hashes = unsafe { uninit_vector::<H::Digest>(self.num_rows()) };
+
+        // iterate though matrix rows, hashing each row
+        batch_iter_mut!(
+            &mut row_hashes,
+            MIN_CONCURRENT_ROWS,
+            |batch: &mut [H::Digest], batch_offset: usize| {
+                for (i, row_hash) in batch.iter_mut().enumerate() {
+                    *row_hash = H::hash_elements(self.row(i + batch_offset));
+                }
+            }
+        );
+
+        // build Merkle tree out of hashed rows
+        MerkleTree::new(row_hashes).expect("failed to construct trace Merkle tree")
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the underlying row-major data of this matrix.
+    pub fn into_data(self) -> Vec<E> {
+        self.data
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes values of the provided columns into the row-major `data` such that column `i` is
+/// written into the column at index `first_col + i` of the matrix with the specified row width.
+pub(super) fn transpose_into<E: FieldElement>(
+    data: &mut [E],
+    row_width: usize,
+    first_col: usize,
+    columns: &[&[E]],
+) {
+    batch_iter_mut!(
+        data,
+        MIN_CONCURRENT_ROWS * row_width,
+        |batch: &mut [E], batch_offset: usize| {
+            // batch size is always a multiple of the row width because the number of rows is a
+            // power of two
+            let first_row = batch_offset / row_width;
+            for (i, row) in batch.chunks_exact_mut(row_width).enumerate() {
+                let row = &mut row[first_col..first_col + columns.len()];
+                for (value, column) in row.iter_mut().zip(columns) {
+                    *value = column[first_row + i];
+                }
+            }
+        }
+    );
+}
@@ -0,0 +1,115 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{LdeMatrix, Matrix, MatrixLayout, RowMatrix};
+use crate::{tests::MockAir, StarkDomain};
+use crypto::hashers::Blake3_256;
+use math::{fft, fields::f128::BaseElement, FieldElement};
+use utils::collections::Vec;
+
+type Blake3 = Blake3_256<BaseElement>;
+
+#[test]
+fn row_matrix_from_columns() {
+    let columns: Vec<Vec<BaseElement>> = build_columns(11, 16);
+    let matrix = Matrix::new(columns.clone());
+    let row_matrix = RowMatrix::from_columns(&matrix);
+
+    assert_eq!(11, row_matrix.num_cols());
+    assert_eq!(16, row_matrix.num_rows());
+    for (row_idx, row) in row_matrix.rows().enumerate() {
+        for (col_idx, column) in columns.iter().enumerate() {
+            assert_eq!(column[row_idx], row[col_idx]);
+            assert_eq!(column[row_idx], row_matrix.get(col_idx, row_idx));
+        }
+    }
+    for (col_idx, column) in columns.iter().enumerate() {
+        assert_eq!(*column, row_matrix.get_column(col_idx));
+    }
+}
+
+#[test]
+fn matrix_batched_fft_matches_column_fft() {
+    // use more columns than fit into a single FFT batch
+    let trace_length = 32;
+    let air = MockAir::with_trace_length(trace_length);
+    let domain = StarkDomain::new(&air);
+    let columns: Vec<Vec<BaseElement>> = build_columns(19, trace_length);
+    let matrix = Matrix::new(columns.clone());
+
+    // interpolation
+    let inv_twiddles = fft::get_inv_twiddles::<BaseElement>(trace_length);
+    let expected = columns
+        .iter()
+        .map(|column| {
+            let mut poly = column.clone();
+            fft::interpolate_poly(&mut poly, &inv_twiddles);
+            poly
+        })
+        .collect::<Vec<_>>();
+    let polys = matrix.interpolate_columns();
+    assert_eq!(expected, polys.clone().into_columns());
+    assert_eq!(expected, matrix.interpolate_columns_into().into_columns());
+
+    // evaluation
+    let expected = expected
+        .iter()
+        .map(|poly| {
+            fft::evaluate_poly_with_offset(
+                poly,
+                domain.trace_twiddles(),
+                domain.offset(),
+                domain.trace_to_lde_blowup(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        expected,
+        polys.evaluate_columns_over(&domain).into_columns()
+    );
+}
+
+#[test]
+fn lde_matrix_layouts_are_equivalent() {
+    // use more columns than fit into a single FFT batch
+    let trace_length = 16;
+    let air = MockAir::with_trace_length(trace_length);
+    let domain = StarkDomain::new(&air);
+    let columns: Vec<Vec<BaseElement>> = build_columns(19, trace_length);
+    let polys = Matrix::new(columns);
+
+    let col_lde = LdeMatrix::evaluate(&polys, &domain, MatrixLayout::ColumnMajor);
+    let row_lde = LdeMatrix::evaluate(&polys, &domain, MatrixLayout::RowMajor);
+    assert_eq!(MatrixLayout::ColumnMajor, col_lde.layout());
+    assert_eq!(MatrixLayout::RowMajor, row_lde.layout());
+
+    assert_eq!(col_lde.num_cols(), row_lde.num_cols());
+    assert_eq!(domain.lde_domain_size(), row_lde.num_rows());
+    for row_idx in 0..row_lde.num_rows() {
+        assert_eq!(col_lde.get_row(row_idx), row_lde.get_row(row_idx));
+    }
+    for col_idx in 0..row_lde.num_cols() {
+        assert_eq!(col_lde.get_column(col_idx), row_lde.get_column(col_idx));
+    }
+
+    // commitments must not depend on the layout
+    let col_tree = col_lde.commit_to_rows::<Blake3>();
+    let row_tree = row_lde.commit_to_rows::<Blake3>();
+    assert_eq!(col_tree.root(), row_tree.root());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_columns(num_cols: usize, num_rows: usize) -> Vec<Vec<BaseElement>> {
+    (0..num_cols)
+        .map(|i| {
+            let seed = BaseElement::from(i as u64 + 3);
+            (0..num_rows)
+                .map(|j| seed.exp(j as u128 + 1) + BaseElement::from(j as u64))
+                .collect()
+        })
+        .collect()
+}
@@ -5,8 +5,8 @@
 
 use crate::TraceTable;
 use air::{
-    Air, AirContext, Assertion, EvaluationFrame, FieldExtension, HashFunction, ProofOptions,
-    TraceInfo, TransitionConstraintDegree,
+    Air, AirContext, Assertion, AuxTraceRandElements, EvaluationFrame, FieldExtension,
+    HashFunction, ProofOptions, TraceInfo, TracePadding, TransitionConstraintDegree,
 };
 use math::{fields::f128::BaseElement, FieldElement, StarkField};
 use utils::collections::Vec;
@@ -35,6 +35,8 @@ pub struct MockAir {
     context: AirContext<BaseElement>,
     assertions: Vec<Assertion<BaseElement>>,
     periodic_columns: Vec<Vec<BaseElement>>,
+    has_aux_periodic_columns: bool,
+    trace_padding: TracePadding,
 }
 
 impl MockAir {
@@ -75,6 +77,23 @@ impl MockAir {
         result
     }
 
+    /// Similar to [MockAir::with_periodic_columns()], but also defines auxiliary periodic columns
+    /// which are the periodic columns multiplied by the first auxiliary random element.
+    pub fn with_aux_periodic_columns(
+        column_values: Vec<Vec<BaseElement>>,
+        trace_length: usize,
+    ) -> Self {
+        let mut result = Self::with_periodic_columns(column_values, trace_length);
+        result.has_aux_periodic_columns = true;
+        result
+    }
+
+    pub fn with_trace_padding(trace_length: usize, trace_padding: TracePadding) -> Self {
+        let mut result = Self::with_trace_length(trace_length);
+        result.trace_padding = trace_padding;
+        result
+    }
+
     pub fn with_assertions(assertions: Vec<Assertion<BaseElement>>, trace_length: usize) -> Self {
         let mut result = Self::new(
             TraceInfo::new(4, trace_length),
@@ -104,6 +123,8 @@ impl Air for MockAir {
             context,
             assertions: Vec::new(),
             periodic_columns: Vec::new(),
+            has_aux_periodic_columns: false,
+            trace_padding: TracePadding::None,
         }
     }
 
@@ -123,9 +144,32 @@ impl Air for MockAir {
         self.assertions.clone()
     }
 
+    fn trace_padding(&self) -> TracePadding {
+        self.trace_padding
+    }
+
+    fn apply_padding_transition(&self, current: &[Self::BaseField], next: &mut [Self::BaseField]) {
+        next[0] = current[0] + current[1];
+        next[1] = current[0] + BaseElement::from(2u8) * current[1];
+    }
+
     fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
         self.periodic_columns.clone()
     }
+
+    fn get_aux_periodic_column_values<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        aux_rand_elements: &AuxTraceRandElements<E>,
+    ) -> Vec<Vec<E>> {
+        if !self.has_aux_periodic_columns {
+            return Vec::new();
+        }
+        let r = aux_rand_elements.get_segment_elements(0)[0];
+        self.periodic_columns
+            .iter()
+            .map(|column| column.iter().map(|&v| r.mul_base(v)).collect())
+            .collect()
+    }
 }
 
 // HELPER FUNCTIONS
@@ -3,7 +3,7 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use crate::Matrix;
+use crate::LdeMatrix;
 use air::proof::Queries;
 use crypto::{ElementHasher, MerkleTree};
 use math::FieldElement;
@@ -31,7 +31,7 @@ impl<E: FieldElement, H: ElementHasher<BaseField = E::BaseField>> TraceCommitmen
     /// Creates a new trace commitment from the provided main trace low-degree extension and the
     /// corresponding Merkle tree commitment.
     pub fn new(
-        main_trace_lde: Matrix<E::BaseField>,
+        main_trace_lde: LdeMatrix<E::BaseField>,
         main_trace_tree: MerkleTree<H>,
         blowup: usize,
     ) -> Self {
@@ -51,7 +51,7 @@ impl<E: FieldElement, H: ElementHasher<BaseField = E::BaseField>> TraceCommitmen
     // --------------------------------------------------------------------------------------------
 
     /// Adds the provided auxiliary segment trace LDE and Merkle tree to this trace commitment.
-    pub fn add_segment(&mut self, aux_segment_lde: Matrix<E>, aux_segment_tree: MerkleTree<H>) {
+    pub fn add_segment(&mut self, aux_segment_lde: LdeMatrix<E>, aux_segment_tree: MerkleTree<H>) {
         assert_eq!(
             aux_segment_lde.num_rows(),
             aux_segment_tree.leaves().len(),
@@ -104,7 +104,7 @@ impl<E: FieldElement, H: ElementHasher<BaseField = E::BaseField>> TraceCommitmen
 
     /// Returns the entire trace for the column at the specified index.
     #[cfg(test)]
-    pub fn get_main_trace_column(&self, col_idx: usize) -> &[E::BaseField] {
+    pub fn get_main_trace_column(&self, col_idx: usize) -> Vec<E::BaseField> {
         self.trace_lde.get_main_segment().get_column(col_idx)
     }
 }
@@ -113,7 +113,7 @@ impl<E: FieldElement, H: ElementHasher<BaseField = E::BaseField>> TraceCommitmen
 // ================================================================================================
 
 fn build_segment_queries<E, H>(
-    segment_lde: &Matrix<E>,
+    segment_lde: &LdeMatrix<E>,
     segment_tree: &MerkleTree<H>,
     positions: &[usize],
 ) -> Queries
@@ -127,8 +127,7 @@ where
     // copy values from the trace segment LDE at the specified positions into rows
     // and append the rows to trace_states
     for &i in positions.iter() {
-        let row = segment_lde.columns().map(|column| column[i]).collect();
-        trace_states.push(row);
+        trace_states.push(segment_lde.get_row(i));
     }
 
     // build Merkle authentication paths to the leaves specified by positions
@@ -4,11 +4,12 @@
 // LICENSE file in the root directory of this source tree.
 
 use super::{matrix::MultiColumnIter, Matrix};
-use air::{Air, AuxTraceRandElements, EvaluationFrame, TraceInfo, TraceLayout};
+use crate::ProverError;
+use air::{Air, AuxTraceRandElements, EvaluationFrame, TraceInfo, TraceLayout, TracePadding};
 use math::{polynom, FieldElement, StarkField};
 
 mod trace_lde;
-pub use trace_lde::TraceLde;
+pub use trace_lde::{TraceFrameReader, TraceLde};
 
 mod poly_table;
 pub use poly_table::TracePolyTable;
@@ -51,6 +52,10 @@ pub trait Trace: Sized {
     fn layout(&self) -> &TraceLayout;
 
     /// Returns the number of rows in this trace.
+    ///
+    /// The number of rows does not need to be a power of two if the AIR of the computation
+    /// declares a [TracePadding] policy; in such a case, the prover pads the main segment of
+    /// the trace before building auxiliary segments.
     fn length(&self) -> usize;
 
     /// Returns metadata associated with this trace.
@@ -78,6 +83,16 @@ pub trait Trace: Sized {
     // PROVIDED METHODS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns a mutable reference to a [Matrix] describing the main segment of this trace, or
+    /// None if the main segment of this trace cannot be modified.
+    ///
+    /// This is used by the prover to pad the main segment of the trace to a power of two. The
+    /// default implementation returns None, and thus, traces which do not override this method
+    /// cannot be padded.
+    fn main_segment_mut(&mut self) -> Option<&mut Matrix<Self::BaseField>> {
+        None
+    }
+
     /// Returns trace info for this trace.
     fn get_info(&self) -> TraceInfo {
         TraceInfo::new_multi_segment(self.layout().clone(), self.length(), self.meta().to_vec())
@@ -164,6 +179,8 @@ pub trait Trace: Sized {
         let g = air.trace_domain_generator();
         let periodic_values_polys = air.get_periodic_column_polys();
         let mut periodic_values = vec![Self::BaseField::ZERO; periodic_values_polys.len()];
+        let aux_periodic_values_polys = air.get_aux_periodic_column_polys(aux_rand_elements);
+        let mut aux_periodic_values = vec![E::ZERO; aux_periodic_values_polys.len()];
 
         // initialize buffers to hold evaluation frames and results of constraint evaluations
         let mut x = Self::BaseField::ONE;
@@ -177,21 +194,37 @@ pub trait Trace: Sized {
             vec![Self::BaseField::ZERO; air.context().num_main_transition_constraints()];
         let mut aux_evaluations = vec![E::ZERO; air.context().num_aux_transition_constraints()];
 
-        // we check transition constraints on all steps except the last k steps, where k is the
-        // number of steps exempt from transition constraints (guaranteed to be at least 1)
-        for step in 0..self.length() - air.context().num_transition_exemptions() {
+        // we check each transition constraint only on the steps described by its divisor; by
+        // default, these are all steps except the last k steps, where k is the number of steps
+        // exempt from transition constraints (guaranteed to be at least 1)
+        let context = air.context();
+        for step in 0..self.length() {
             // build periodic values
             for (p, v) in periodic_values_polys.iter().zip(periodic_values.iter_mut()) {
                 let num_cycles = air.trace_length() / p.len();
                 let x = x.exp((num_cycles as u32).into());
                 *v = polynom::eval(p, x);
             }
+            for (p, v) in aux_periodic_values_polys
+                .iter()
+                .zip(aux_periodic_values.iter_mut())
+            {
+                let num_cycles = air.trace_length() / p.len();
+                let x = x.exp((num_cycles as u32).into());
+                *v = polynom::eval(p, E::from(x));
+            }
 
             // evaluate transition constraints for the main trace segment and make sure they all
             // evaluate to zeros
             self.read_main_frame(step, &mut main_frame);
             air.evaluate_transition(&main_frame, &periodic_values, &mut main_evaluations);
             for (i, &evaluation) in main_evaluations.iter().enumerate() {
+                if !context
+                    .main_transition_divisor(i)
+                    .is_active_at(step, self.length())
+                {
+                    continue;
+                }
                 assert!(
                     evaluation == Self::BaseField::ZERO,
                     "main transition constraint {} did not evaluate to ZERO at step {}",
@@ -204,14 +237,21 @@ pub trait Trace: Sized {
             // sure they all evaluate to zeros
             if let Some(ref mut aux_frame) = aux_frame {
                 read_aux_frame(aux_segments, step, aux_frame);
-                air.evaluate_aux_transition(
+                air.evaluate_aux_transition_with_periodic_values(
                     &main_frame,
                     aux_frame,
                     &periodic_values,
+                    &aux_periodic_values,
                     aux_rand_elements,
                     &mut aux_evaluations,
                 );
                 for (i, &evaluation) in aux_evaluations.iter().enumerate() {
+                    if !context
+                        .aux_transition_divisor(i)
+                        .is_active_at(step, self.length())
+                    {
+                        continue;
+                    }
                     assert!(
                         evaluation == E::ZERO,
                         "auxiliary transition constraint {} did not evaluate to ZERO at step {}",
@@ -227,6 +267,62 @@ pub trait Trace: Sized {
     }
 }
 
+// TRACE PADDING
+// ================================================================================================
+
+/// Pads the main segment of the specified trace to the trace length expected by the specified AIR
+/// according to the padding policy declared by the AIR.
+///
+/// # Errors
+/// Returns an error if the trace needs to be padded, but the AIR does not allow padding or the
+/// main segment of the trace cannot be modified.
+///
+/// # Panics
+/// Panics if the selector column of [TracePadding::ZeroFill] policy is out of bounds for the
+/// main segment of the trace.
+pub(super) fn pad_main_segment<A, T>(air: &A, trace: &mut T) -> Result<(), ProverError>
+where
+    A: Air,
+    T: Trace<BaseField = A::BaseField>,
+{
+    let trace_length = trace.length();
+    let num_padding_rows = air.trace_length() - trace_length;
+    if num_padding_rows == 0 {
+        return Ok(());
+    }
+
+    let padding = air.trace_padding();
+    if padding == TracePadding::None {
+        return Err(ProverError::TraceLengthNotPowerOfTwo(trace_length));
+    }
+    let segment = trace
+        .main_segment_mut()
+        .ok_or(ProverError::TracePaddingNotSupported(trace_length))?;
+    match padding {
+        TracePadding::None => unreachable!("padding policy was checked above"),
+        TracePadding::RepeatLastRow => segment.pad_rows(num_padding_rows, |current, next| {
+            next.copy_from_slice(current)
+        }),
+        TracePadding::Transition => segment.pad_rows(num_padding_rows, |current, next| {
+            air.apply_padding_transition(current, next)
+        }),
+        TracePadding::ZeroFill { selector_column } => {
+            assert!(
+                selector_column < segment.num_cols(),
+                "padding selector column index {} is out of bounds for trace width {}",
+                selector_column,
+                segment.num_cols()
+            );
+            segment
+                .get_column_mut(selector_column)
+                .fill(A::BaseField::ONE);
+            segment.pad_rows(num_padding_rows, |_, next| next.fill(A::BaseField::ZERO));
+        }
+    }
+
+    Ok(())
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -3,11 +3,13 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use super::pad_main_segment;
 use crate::{
     tests::{build_fib_trace, MockAir},
     trace::TracePolyTable,
-    StarkDomain, Trace, TraceCommitment,
+    LdeMatrix, Matrix, MatrixLayout, ProverError, StarkDomain, Trace, TraceCommitment, TraceTable,
 };
+use air::{Air, EvaluationFrame, TraceLayout, TracePadding};
 use crypto::{hashers::Blake3_256, ElementHasher, MerkleTree};
 use math::{
     fields::f128::BaseElement, get_power_series, get_power_series_with_offset, log2, polynom,
@@ -48,7 +50,7 @@ fn extend_trace_table() {
 
     // build extended trace commitment
     let trace_polys = trace.main_segment().interpolate_columns();
-    let trace_lde = trace_polys.evaluate_columns_over(&domain);
+    let trace_lde = LdeMatrix::evaluate(&trace_polys, &domain, MatrixLayout::ColumnMajor);
     let trace_tree = trace_lde.commit_to_rows::<Blake3>();
     let trace_comm = TraceCommitment::<BaseElement, Blake3>::new(
         trace_lde,
@@ -83,11 +85,11 @@ fn extend_trace_table() {
     let lde_domain = build_lde_domain(domain.lde_domain_size());
     assert_eq!(
         trace_polys.get_main_trace_poly(0),
-        polynom::interpolate(&lde_domain, trace_comm.get_main_trace_column(0), true)
+        polynom::interpolate(&lde_domain, &trace_comm.get_main_trace_column(0), true)
     );
     assert_eq!(
         trace_polys.get_main_trace_poly(1),
-        polynom::interpolate(&lde_domain, trace_comm.get_main_trace_column(1), true)
+        polynom::interpolate(&lde_domain, &trace_comm.get_main_trace_column(1), true)
     );
 }
 
@@ -101,7 +103,7 @@ fn commit_trace_table() {
 
     // build extended trace commitment
     let trace_polys = trace.main_segment().interpolate_columns();
-    let trace_lde = trace_polys.evaluate_columns_over(&domain);
+    let trace_lde = LdeMatrix::evaluate(&trace_polys, &domain, MatrixLayout::RowMajor);
     let trace_tree = trace_lde.commit_to_rows::<Blake3>();
     let trace_comm = TraceCommitment::<BaseElement, Blake3>::new(
         trace_lde,
@@ -127,6 +129,114 @@ fn commit_trace_table() {
     assert_eq!(*expected_tree.root(), trace_comm.main_trace_root())
 }
 
+#[test]
+fn pad_trace_table() {
+    let trace_length = 12;
+    let columns = build_fib_trace(32)
+        .main_segment()
+        .columns()
+        .map(|column| column[..trace_length].to_vec())
+        .collect::<Vec<_>>();
+    let last_row = [columns[0][trace_length - 1], columns[1][trace_length - 1]];
+
+    // traces which are not a power of two are rejected unless the AIR allows padding
+    let air = MockAir::with_trace_length(trace_length);
+    assert_eq!(16, air.trace_length());
+    assert_eq!(trace_length, air.original_trace_length());
+    let mut trace = TraceTable::init(columns.clone());
+    assert_eq!(
+        Err(ProverError::TraceLengthNotPowerOfTwo(trace_length)),
+        pad_main_segment(&air, &mut trace)
+    );
+
+    // padding rows repeat the last row
+    let air = MockAir::with_trace_padding(trace_length, TracePadding::RepeatLastRow);
+    let mut trace = TraceTable::init(columns.clone());
+    pad_main_segment(&air, &mut trace).unwrap();
+    assert_eq!(16, trace.length());
+    for step in trace_length..16 {
+        assert_eq!(last_row, [trace.get(0, step), trace.get(1, step)]);
+    }
+
+    // padding rows continue the computation; for the mock AIR this continues the sequence
+    let air = MockAir::with_trace_padding(trace_length, TracePadding::Transition);
+    let mut trace = TraceTable::init(columns.clone());
+    pad_main_segment(&air, &mut trace).unwrap();
+    let expected = build_fib_trace(32);
+    assert_eq!(expected.get_column(0), trace.get_column(0));
+    assert_eq!(expected.get_column(1), trace.get_column(1));
+
+    // padding rows are zeros, and the selector column is set to ONE only for the original rows
+    let padding = TracePadding::ZeroFill { selector_column: 1 };
+    let air = MockAir::with_trace_padding(trace_length, padding);
+    let mut trace = TraceTable::init(columns.clone());
+    pad_main_segment(&air, &mut trace).unwrap();
+    assert_eq!(&columns[0][..], &trace.get_column(0)[..trace_length]);
+    assert!(trace.get_column(0)[trace_length..]
+        .iter()
+        .all(|&v| v == BaseElement::ZERO));
+    for (step, &value) in trace.get_column(1).iter().enumerate() {
+        let expected = if step < trace_length {
+            BaseElement::ONE
+        } else {
+            BaseElement::ZERO
+        };
+        assert_eq!(expected, value);
+    }
+
+    // traces which are already a power of two are not modified
+    let air = MockAir::with_trace_padding(16, TracePadding::RepeatLastRow);
+    let mut trace = build_fib_trace(32);
+    pad_main_segment(&air, &mut trace).unwrap();
+    assert_eq!(16, trace.length());
+
+    // traces which do not provide mutable access to the main segment cannot be padded
+    let air = MockAir::with_trace_padding(trace_length, TracePadding::RepeatLastRow);
+    let mut trace = ImmutableTrace(TraceTable::init(columns));
+    assert_eq!(
+        Err(ProverError::TracePaddingNotSupported(trace_length)),
+        pad_main_segment(&air, &mut trace)
+    );
+}
+
+// IMMUTABLE TRACE
+// ================================================================================================
+
+/// A trace which relies on the default implementation of [Trace::main_segment_mut()].
+struct ImmutableTrace(TraceTable<BaseElement>);
+
+impl Trace for ImmutableTrace {
+    type BaseField = BaseElement;
+
+    fn layout(&self) -> &TraceLayout {
+        self.0.layout()
+    }
+
+    fn length(&self) -> usize {
+        self.0.length()
+    }
+
+    fn meta(&self) -> &[u8] {
+        self.0.meta()
+    }
+
+    fn main_segment(&self) -> &Matrix<BaseElement> {
+        self.0.main_segment()
+    }
+
+    fn build_aux_segment<E: FieldElement<BaseField = BaseElement>>(
+        &mut self,
+        _aux_segments: &[Matrix<E>],
+        _rand_elements: &[E],
+    ) -> Option<Matrix<E>> {
+        None
+    }
+
+    fn read_main_frame(&self, row_idx: usize, frame: &mut EvaluationFrame<BaseElement>) {
+        self.0.read_main_frame(row_idx, frame)
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -3,7 +3,7 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use crate::Matrix;
+use crate::LdeMatrix;
 use air::EvaluationFrame;
 use math::FieldElement;
 use utils::collections::Vec;
@@ -12,8 +12,8 @@ use utils::collections::Vec;
 // ================================================================================================
 /// TODO: add docs
 pub struct TraceLde<E: FieldElement> {
-    main_segment_lde: Matrix<E::BaseField>,
-    aux_segment_ldes: Vec<Matrix<E>>,
+    main_segment_lde: LdeMatrix<E::BaseField>,
+    aux_segment_ldes: Vec<LdeMatrix<E>>,
     blowup: usize,
 }
 
@@ -21,7 +21,7 @@ impl<E: FieldElement> TraceLde<E> {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Creates a new trace low-degree extension table from the provided main trace segment LDE.
-    pub fn new(main_trace_lde: Matrix<E::BaseField>, blowup: usize) -> Self {
+    pub fn new(main_trace_lde: LdeMatrix<E::BaseField>, blowup: usize) -> Self {
         Self {
             main_segment_lde: main_trace_lde,
             aux_segment_ldes: Vec::new(),
@@ -33,7 +33,7 @@ impl<E: FieldElement> TraceLde<E> {
     // --------------------------------------------------------------------------------------------
 
     /// Adds the provided auxiliary segment LDE to this trace LDE.
-    pub fn add_aux_segment(&mut self, aux_segment_lde: Matrix<E>) {
+    pub fn add_aux_segment(&mut self, aux_segment_lde: LdeMatrix<E>) {
         assert_eq!(
             self.main_segment_lde.num_rows(),
             aux_segment_lde.num_rows(),
@@ -67,8 +67,55 @@ impl<E: FieldElement> TraceLde<E> {
         self.blowup
     }
 
+    /// Returns a reference to [LdeMatrix] representing the main trace segment.
+    pub fn get_main_segment(&self) -> &LdeMatrix<E::BaseField> {
+        &self.main_segment_lde
+    }
+
+    /// Returns a reference to a [LdeMatrix] representing an auxiliary trace segment at the specified
+    /// index.
+    pub fn get_aux_segment(&self, aux_segment_idx: usize) -> &LdeMatrix<E> {
+        &self.aux_segment_ldes[aux_segment_idx]
+    }
+}
+
+// TRACE FRAME READER
+// ================================================================================================
+
+/// Defines how evaluation frames are read from an extended execution trace during constraint
+/// evaluation.
+///
+/// Frames are identified by steps in the LDE domain. The full trace LDE implements this trait,
+/// but so can a structure which holds only the rows needed to evaluate constraints over a range
+/// of the constraint evaluation domain.
+pub trait TraceFrameReader<E: FieldElement> {
+    /// Returns number of columns in the main segment of the execution trace.
+    fn main_trace_width(&self) -> usize;
+
+    /// Returns number of columns in the auxiliary segments of the execution trace.
+    fn aux_trace_width(&self) -> usize;
+
     /// Reads current and next rows from the main trace segment into the specified frame.
-    pub fn read_main_trace_frame_into(
+    fn read_main_trace_frame_into(
+        &self,
+        lde_step: usize,
+        frame: &mut EvaluationFrame<E::BaseField>,
+    );
+
+    /// Reads current and next rows from the auxiliary trace segment into the specified frame.
+    fn read_aux_trace_frame_into(&self, lde_step: usize, frame: &mut EvaluationFrame<E>);
+}
+
+impl<E: FieldElement> TraceFrameReader<E> for TraceLde<E> {
+    fn main_trace_width(&self) -> usize {
+        TraceLde::main_trace_width(self)
+    }
+
+    fn aux_trace_width(&self) -> usize {
+        TraceLde::aux_trace_width(self)
+    }
+
+    fn read_main_trace_frame_into(
         &self,
         lde_step: usize,
         frame: &mut EvaluationFrame<E::BaseField>,
@@ -83,8 +130,7 @@ impl<E: FieldElement> TraceLde<E> {
             .read_row_into(next_lde_step, frame.next_mut());
     }
 
-    /// Reads current and next rows from the auxiliary trace segment into the specified frame.
-    pub fn read_aux_trace_frame_into(&self, lde_step: usize, frame: &mut EvaluationFrame<E>) {
+    fn read_aux_trace_frame_into(&self, lde_step: usize, frame: &mut EvaluationFrame<E>) {
         // at the end of the trace, next state wraps around and we read the first step again
         let next_lde_step = (lde_step + self.blowup()) % self.trace_len();
 
@@ -96,15 +142,4 @@ impl<E: FieldElement> TraceLde<E> {
             offset += segment.num_cols();
         }
     }
-
-    /// Returns a reference to [Matrix] representing the main trace segment.
-    pub fn get_main_segment(&self) -> &Matrix<E::BaseField> {
-        &self.main_segment_lde
-    }
-
-    /// Returns a reference to a [Matrix] representing an auxiliary trace segment at the specified
-    /// index.
-    pub fn get_aux_segment(&self, aux_segment_idx: usize) -> &Matrix<E> {
-        &self.aux_segment_ldes[aux_segment_idx]
-    }
 }
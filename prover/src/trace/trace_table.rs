@@ -32,7 +32,8 @@ const MIN_FRAGMENT_LENGTH: usize = 2;
 /// requirements. These requirements are:
 ///
 /// 1. Lengths of all columns in the execution trace must be the same.
-/// 2. The length of the columns must be some power of two.
+/// 2. The length of the columns must be some power of two, unless the AIR of the computation
+///    declares a [TracePadding](air::TracePadding) policy.
 ///
 /// The other approach is to use [TraceTable::new()] function, which takes trace width and
 /// length as parameters. This function will allocate memory for the trace, but will not fill it
@@ -77,8 +78,8 @@ impl<B: StarkField> TraceTable<B> {
     /// # Panics
     /// Panics if:
     /// * `width` is zero or greater than 255.
-    /// * `length` is smaller than 8, greater than biggest multiplicative subgroup in the field
-    ///   `B`, or is not a power of two.
+    /// * `length` is smaller than 8, or its next power of two is greater than the biggest
+    ///   multiplicative subgroup in the field `B`.
     pub fn new(width: usize, length: usize) -> Self {
         Self::with_meta(width, length, vec![])
     }
@@ -92,8 +93,8 @@ impl<B: StarkField> TraceTable<B> {
     /// # Panics
     /// Panics if:
     /// * `width` is zero or greater than 255.
-    /// * `length` is smaller than 8, greater than the biggest multiplicative subgroup in the
-    ///   field `B`, or is not a power of two.
+    /// * `length` is smaller than 8, or its next power of two is greater than the biggest
+    ///   multiplicative subgroup in the field `B`.
     /// * Length of `meta` is greater than 65535;
     pub fn with_meta(width: usize, length: usize, meta: Vec<u8>) -> Self {
         assert!(
@@ -113,14 +114,10 @@ impl<B: StarkField> TraceTable<B> {
             length
         );
         assert!(
-            length.is_power_of_two(),
-            "execution trace length must be a power of 2"
-        );
-        assert!(
-            log2(length) as u32 <= B::TWO_ADICITY,
-            "execution trace length cannot exceed 2^{} steps, but was 2^{}",
+            log2(length.next_power_of_two()) as u32 <= B::TWO_ADICITY,
+            "execution trace length cannot exceed 2^{} steps, but was {}",
             B::TWO_ADICITY,
-            log2(length)
+            length
         );
         assert!(
             meta.len() <= TraceInfo::MAX_META_LENGTH,
@@ -142,8 +139,8 @@ impl<B: StarkField> TraceTable<B> {
     /// # Panics
     /// Panics if:
     /// * The `columns` vector is empty or has over 255 columns.
-    /// * Number of elements in any of the columns is smaller than 8, greater than the biggest
-    ///   multiplicative subgroup in the field `B`, or is not a power of two.
+    /// * Number of elements in any of the columns is smaller than 8, or its next power of two is
+    ///   greater than the biggest multiplicative subgroup in the field `B`.
     /// * Number of elements is not identical for all columns.
     pub fn init(columns: Vec<Vec<B>>) -> Self {
         assert!(
@@ -164,14 +161,10 @@ impl<B: StarkField> TraceTable<B> {
             trace_length
         );
         assert!(
-            trace_length.is_power_of_two(),
-            "execution trace length must be a power of 2"
-        );
-        assert!(
-            log2(trace_length) as u32 <= B::TWO_ADICITY,
-            "execution trace length cannot exceed 2^{} steps, but was 2^{}",
+            log2(trace_length.next_power_of_two()) as u32 <= B::TWO_ADICITY,
+            "execution trace length cannot exceed 2^{} steps, but was {}",
             B::TWO_ADICITY,
-            log2(trace_length)
+            trace_length
         );
         for column in columns.iter().skip(1) {
             assert_eq!(
@@ -298,6 +291,12 @@ impl<B: StarkField> TraceTable<B> {
             fragment_length.is_power_of_two(),
             "fragment length must be a power of 2"
         );
+        assert!(
+            self.length() % fragment_length == 0,
+            "execution trace length {} must be divisible by fragment length {}",
+            self.length(),
+            fragment_length
+        );
         let num_fragments = self.length() / fragment_length;
 
         let mut fragment_data = (0..num_fragments).map(|_| Vec::new()).collect::<Vec<_>>();
@@ -370,6 +369,10 @@ impl<B: StarkField> Trace for TraceTable<B> {
         &self.trace
     }
 
+    fn main_segment_mut(&mut self) -> Option<&mut Matrix<B>> {
+        Some(&mut self.trace)
+    }
+
     fn build_aux_segment<E>(
         &mut self,
         _aux_segments: &[Matrix<E>],
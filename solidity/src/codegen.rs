@@ -0,0 +1,435 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use crate::spec::{AssertionValue, VerifierSpec};
+use air::{builder::Node, ConstraintDivisor};
+use core::fmt::Write;
+use math::{log2, StarkField};
+use utils::Serializable;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Template of the verifier contract; the placeholders are replaced with the code generated for
+/// a specific computation.
+const TEMPLATE: &str = include_str!("templates/verifier.sol");
+
+// CONTRACT GENERATION
+// ================================================================================================
+
+impl<B: StarkField> VerifierSpec<B> {
+    /// Returns the source code of a Solidity contract with the specified name which verifies
+    /// proofs described by this specification.
+    ///
+    /// The contract exposes a single external function:
+    /// ```text
+    /// function verify(uint256[] calldata publicInputs, bytes calldata proof) external view returns (bool)
+    /// ```
+    /// which returns `true` if the proof is valid, and reverts otherwise. Calldata for this
+    /// function can be built via [VerifierSpec::encode_calldata()].
+    ///
+    /// # Panics
+    /// Panics if `contract_name` is not a valid Solidity identifier.
+    pub fn generate_contract(&self, contract_name: &str) -> String {
+        assert!(
+            is_identifier(contract_name),
+            "contract name must be a valid identifier, but was '{}'",
+            contract_name
+        );
+
+        let air_id = self
+            .air_id
+            .iter()
+            .flat_map(|&b| core::ascii::escape_default(b))
+            .map(char::from)
+            .collect::<String>();
+
+        TEMPLATE
+            .replace("{{CONSTANTS}}", &self.build_constants())
+            .replace(
+                "{{CONSTRAINT_EVALUATION}}",
+                &self.build_constraint_evaluation(),
+            )
+            .replace("{{CONTRACT_NAME}}", contract_name)
+            .replace("{{AIR_ID}}", &air_id)
+            .replace("{{TRACE_LENGTH}}", &self.trace_length.to_string())
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds declarations of all constants of the verifier contract.
+    fn build_constants(&self) -> String {
+        let layout = self.layout();
+        let remainder_size = self.remainder_size();
+        let folding_root = self
+            .lde_generator
+            .exp(((self.lde_domain_size / self.folding_factor) as u64).into());
+        let remainder_root_inv = self
+            .lde_generator
+            .exp(((self.lde_domain_size / remainder_size) as u64).into())
+            .inv();
+
+        let sections: [(&str, Vec<(&str, String)>); 6] = [
+            (
+                "field",
+                vec![
+                    ("MODULUS", bytes_to_hex(&B::get_modulus_le_bytes())),
+                    ("ELEMENT_BYTES", B::ELEMENT_BYTES.to_string()),
+                ],
+            ),
+            (
+                "computation",
+                vec![
+                    ("TRACE_LENGTH", self.trace_length.to_string()),
+                    ("TRACE_WIDTH", self.trace_width.to_string()),
+                    (
+                        "NUM_CONSTRAINT_COLUMNS",
+                        self.num_constraint_columns.to_string(),
+                    ),
+                    ("NUM_PUBLIC_INPUTS", self.num_public_inputs.to_string()),
+                    (
+                        "NUM_TRANSITION_CONSTRAINTS",
+                        self.num_transition_constraints.to_string(),
+                    ),
+                    ("NUM_ASSERTIONS", self.num_assertions.to_string()),
+                    ("TRACE_GENERATOR", element_to_hex(self.trace_generator)),
+                ],
+            ),
+            (
+                "proof options",
+                vec![
+                    ("LDE_DOMAIN_SIZE", self.lde_domain_size.to_string()),
+                    ("LDE_DEPTH", self.lde_depth().to_string()),
+                    ("LDE_GENERATOR", element_to_hex(self.lde_generator)),
+                    ("DOMAIN_OFFSET", element_to_hex(self.domain_offset)),
+                    ("NUM_QUERIES", self.num_queries.to_string()),
+                    (
+                        "GRINDING_MASK",
+                        format!("{:#x}", (1u64 << self.grinding_factor) - 1),
+                    ),
+                ],
+            ),
+            (
+                "FRI",
+                vec![
+                    ("FOLDING_FACTOR", self.folding_factor.to_string()),
+                    ("LOG_FOLDING_FACTOR", log2(self.folding_factor).to_string()),
+                    ("FOLDING_ROOT", element_to_hex(folding_root)),
+                    ("NUM_FRI_LAYERS", self.num_fri_layers.to_string()),
+                    ("REMAINDER_SIZE", remainder_size.to_string()),
+                    (
+                        "REMAINDER_MAX_DEGREE",
+                        self.remainder_max_degree.to_string(),
+                    ),
+                    ("REMAINDER_ROOT_INV", element_to_hex(remainder_root_inv)),
+                ],
+            ),
+            (
+                "proof layout (byte offsets into the encoded proof)",
+                vec![
+                    ("TRACE_ROOT_OFFSET", layout.trace_root.to_string()),
+                    ("CONSTRAINT_ROOT_OFFSET", layout.constraint_root.to_string()),
+                    ("FRI_ROOTS_OFFSET", layout.fri_roots.to_string()),
+                    ("OOD_CURRENT_OFFSET", layout.ood_current.to_string()),
+                    ("OOD_NEXT_OFFSET", layout.ood_next.to_string()),
+                    ("OOD_CONSTRAINTS_OFFSET", layout.ood_constraints.to_string()),
+                    ("POW_NONCE_OFFSET", layout.pow_nonce.to_string()),
+                    ("REMAINDER_OFFSET", layout.remainder.to_string()),
+                    ("QUERIES_OFFSET", layout.queries.to_string()),
+                    ("QUERY_SIZE", layout.query_size.to_string()),
+                    ("FRI_QUERIES_OFFSET", layout.fri_queries.to_string()),
+                    ("PROOF_SIZE", layout.size.to_string()),
+                ],
+            ),
+            ("public coin", vec![]),
+        ];
+
+        let mut result = String::new();
+        for (title, constants) in sections.iter() {
+            if !result.is_empty() {
+                result.push('\n');
+            }
+            writeln!(result, "    // {}", title).unwrap();
+            for (name, value) in constants.iter() {
+                writeln!(
+                    result,
+                    "    uint256 internal constant {} = {};",
+                    name, value
+                )
+                .unwrap();
+            }
+        }
+
+        // bytes which are hashed together with public inputs to seed the public coin
+        let seed_prefix = self.seed_prefix();
+        let context = self.context.to_bytes();
+        writeln!(
+            result,
+            "    bytes internal constant SEED_PREFIX = hex\"{}\";",
+            bytes_to_plain_hex(&seed_prefix)
+        )
+        .unwrap();
+        write!(
+            result,
+            "    bytes internal constant CONTEXT = hex\"{}\";",
+            bytes_to_plain_hex(&context)
+        )
+        .unwrap();
+
+        result
+    }
+
+    /// Builds the body of the function which evaluates constraints at the out-of-domain point.
+    fn build_constraint_evaluation(&self) -> String {
+        let mut out = String::new();
+
+        // evaluate all nodes of the constraint graph
+        let nodes = self.graph.nodes();
+        writeln!(out, "        // evaluate nodes of the constraint graph").unwrap();
+        writeln!(
+            out,
+            "        uint256[] memory v = new uint256[]({});",
+            nodes.len()
+        )
+        .unwrap();
+        for (i, node) in nodes.iter().enumerate() {
+            match *node {
+                Node::Constant(value) => {
+                    writeln!(out, "        v[{}] = {};", i, element_to_hex(value)).unwrap()
+                }
+                Node::Current(column) => {
+                    writeln!(out, "        v[{}] = t.oodCurrent[{}];", i, column).unwrap()
+                }
+                Node::Next(column) => {
+                    writeln!(out, "        v[{}] = t.oodNext[{}];", i, column).unwrap()
+                }
+                Node::Periodic { index, .. } => {
+                    // periodic column polynomials are evaluated at z^(n / cycle_length) using
+                    // Horner's method
+                    let poly = &self.periodic_column_polys[index];
+                    let num_cycles = self.trace_length / poly.len();
+                    writeln!(out, "        {{").unwrap();
+                    writeln!(out, "            uint256 x = _exp(z, {});", num_cycles).unwrap();
+                    writeln!(
+                        out,
+                        "            uint256 value = {};",
+                        element_to_hex(poly[poly.len() - 1])
+                    )
+                    .unwrap();
+                    for &coefficient in poly.iter().rev().skip(1) {
+                        writeln!(
+                            out,
+                            "            value = addmod(mulmod(value, x, MODULUS), {}, MODULUS);",
+                            element_to_hex(coefficient)
+                        )
+                        .unwrap();
+                    }
+                    writeln!(out, "            v[{}] = value;", i).unwrap();
+                    writeln!(out, "        }}").unwrap();
+                }
+                Node::Add(lhs, rhs) => writeln!(
+                    out,
+                    "        v[{}] = addmod(v[{}], v[{}], MODULUS);",
+                    i,
+                    lhs.as_usize(),
+                    rhs.as_usize()
+                )
+                .unwrap(),
+                Node::Sub(lhs, rhs) => writeln!(
+                    out,
+                    "        v[{}] = addmod(v[{}], MODULUS - v[{}], MODULUS);",
+                    i,
+                    lhs.as_usize(),
+                    rhs.as_usize()
+                )
+                .unwrap(),
+                Node::Mul(lhs, rhs) => writeln!(
+                    out,
+                    "        v[{}] = mulmod(v[{}], v[{}], MODULUS);",
+                    i,
+                    lhs.as_usize(),
+                    rhs.as_usize()
+                )
+                .unwrap(),
+                Node::Neg(value) => writeln!(
+                    out,
+                    "        v[{}] = (MODULUS - v[{}]) % MODULUS;",
+                    i,
+                    value.as_usize()
+                )
+                .unwrap(),
+                Node::Exp(base, power) => writeln!(
+                    out,
+                    "        v[{}] = _exp(v[{}], {});",
+                    i,
+                    base.as_usize(),
+                    power
+                )
+                .unwrap(),
+                Node::AuxCurrent(_) | Node::AuxNext(_) | Node::RandElement(_) => {
+                    unreachable!("auxiliary trace segments are not supported")
+                }
+            }
+        }
+
+        // merge transition constraints sharing the same divisor, and divide them by the divisor
+        let constraint_nodes = self.graph.constraints();
+        for (d, divisor) in self.transition_divisors.iter().enumerate() {
+            writeln!(out).unwrap();
+            writeln!(
+                out,
+                "        // transition constraints with divisor {}",
+                divisor
+            )
+            .unwrap();
+            writeln!(out, "        {{").unwrap();
+            writeln!(out, "            uint256 sum = 0;").unwrap();
+            for group in self.transition_groups.iter() {
+                if group.divisor_index != d {
+                    continue;
+                }
+                writeln!(out, "            {{").unwrap();
+                writeln!(
+                    out,
+                    "                uint256 xp = _exp(z, {});",
+                    group.degree_adjustment
+                )
+                .unwrap();
+                writeln!(out, "                uint256 cc;").unwrap();
+                for &i in group.constraints.iter() {
+                    write_coefficients(&mut out, i);
+                    writeln!(
+                        out,
+                        "                sum = addmod(sum, mulmod(v[{}], cc, MODULUS), MODULUS);",
+                        constraint_nodes[i].as_usize()
+                    )
+                    .unwrap();
+                }
+                writeln!(out, "            }}").unwrap();
+            }
+            write_division(&mut out, divisor);
+            writeln!(out, "        }}").unwrap();
+        }
+
+        // merge assertions sharing the same divisor, and divide them by the divisor
+        for group in self.boundary_groups.iter() {
+            writeln!(out).unwrap();
+            writeln!(out, "        // assertions with divisor {}", group.divisor).unwrap();
+            writeln!(out, "        {{").unwrap();
+            writeln!(out, "            uint256 sum = 0;").unwrap();
+            writeln!(out, "            {{").unwrap();
+            writeln!(
+                out,
+                "                uint256 xp = _exp(z, {});",
+                group.degree_adjustment
+            )
+            .unwrap();
+            writeln!(out, "                uint256 cc;").unwrap();
+            for assertion in group.assertions.iter() {
+                let value = match assertion.value {
+                    AssertionValue::Constant(value) => element_to_hex(value),
+                    AssertionValue::PublicInput(index) => format!("publicInputs[{}]", index),
+                };
+                write_coefficients(
+                    &mut out,
+                    self.num_transition_constraints + assertion.coefficients,
+                );
+                writeln!(
+                    out,
+                    "                sum = addmod(sum, mulmod(addmod(t.oodCurrent[{}], MODULUS - {}, MODULUS), cc, MODULUS), MODULUS);",
+                    assertion.column, value
+                )
+                .unwrap();
+            }
+            writeln!(out, "            }}").unwrap();
+            write_division(&mut out, &group.divisor);
+            writeln!(out, "        }}").unwrap();
+        }
+
+        // remove the trailing new line, as the template already has one
+        out.pop();
+        out
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes a statement which computes cc_0 + cc_1 * xp for the pair of composition coefficients
+/// with the specified index.
+fn write_coefficients(out: &mut String, index: usize) {
+    writeln!(
+        out,
+        "                cc = addmod(c[{}], mulmod(c[{}], xp, MODULUS), MODULUS);",
+        2 * index,
+        2 * index + 1
+    )
+    .unwrap();
+}
+
+/// Writes statements which divide `sum` by the divisor evaluated at z, and add the result to
+/// `result`.
+fn write_division<B: StarkField>(out: &mut String, divisor: &ConstraintDivisor<B>) {
+    writeln!(out, "            uint256 numerator = 1;").unwrap();
+    for &(degree, constant) in divisor.numerator() {
+        writeln!(
+            out,
+            "            numerator = mulmod(numerator, addmod(_exp(z, {}), MODULUS - {}, MODULUS), MODULUS);",
+            degree,
+            element_to_hex(constant)
+        )
+        .unwrap();
+    }
+    writeln!(out, "            uint256 denominator = 1;").unwrap();
+    for &exemption in divisor.exemptions() {
+        writeln!(
+            out,
+            "            denominator = mulmod(denominator, addmod(z, MODULUS - {}, MODULUS), MODULUS);",
+            element_to_hex(exemption)
+        )
+        .unwrap();
+    }
+    writeln!(
+        out,
+        "            result = addmod(result, mulmod(mulmod(sum, denominator, MODULUS), _inv(numerator), MODULUS), MODULUS);"
+    )
+    .unwrap();
+}
+
+/// Returns a hexadecimal literal for the canonical value of the specified element.
+fn element_to_hex<B: StarkField>(value: B) -> String {
+    bytes_to_hex(&value.to_bytes())
+}
+
+/// Returns a hexadecimal literal for the integer encoded by the specified little-endian bytes.
+fn bytes_to_hex(bytes: &[u8]) -> String {
+    let digits = bytes
+        .iter()
+        .rev()
+        .skip_while(|&&b| b == 0)
+        .map(|b| format!("{:02x}", b))
+        .collect::<String>();
+    if digits.is_empty() {
+        "0x0".to_string()
+    } else {
+        format!("0x{}", digits)
+    }
+}
+
+/// Returns the hexadecimal representation of the specified bytes in their original order.
+fn bytes_to_plain_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Returns true if the specified name is a valid Solidity identifier.
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
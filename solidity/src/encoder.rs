@@ -0,0 +1,219 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use crate::{PublicInputElements, SolidityError, VerifierSpec};
+use air::proof::{DecodedProof, StarkProof};
+use crypto::{hashers::Keccak256, Digest, ElementHasher, Hasher, RandomCoin};
+use fri::folding::fold_positions;
+use math::StarkField;
+use utils::Serializable;
+
+// PROOF ENCODING
+// ================================================================================================
+
+impl<B: StarkField> VerifierSpec<B> {
+    /// Encodes the specified proof into the format expected by verifier contracts generated from
+    /// this specification.
+    ///
+    /// The encoded proof is a sequence of 32-byte words which can be read by the contract at
+    /// fixed offsets: all Merkle authentication paths are expanded into individual paths, and
+    /// query positions are not included as the contract derives them from the transcript.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The proof was generated for a trace length or proof options different from the ones of
+    ///   this specification.
+    /// * The proof could not be decoded, or its Merkle authentication paths could not be
+    ///   expanded.
+    /// * FRI layers of the proof were committed to using more than one partition.
+    pub fn encode_proof<P>(
+        &self,
+        proof: &StarkProof,
+        pub_inputs: &P,
+    ) -> Result<Vec<u8>, SolidityError>
+    where
+        P: PublicInputElements<B>,
+    {
+        if proof.context != self.context {
+            return Err(SolidityError::ContextMismatch);
+        }
+        let proof = DecodedProof::<B, Keccak256<B>>::new(proof)
+            .map_err(SolidityError::ProofDecodingFailed)?;
+        if proof.fri_num_partitions != 1 {
+            return Err(SolidityError::UnsupportedFriPartitions(
+                proof.fri_num_partitions,
+            ));
+        }
+
+        let positions = self.get_query_positions(&proof, pub_inputs)?;
+        let mut result = Vec::with_capacity(self.proof_size());
+
+        // commitments
+        write_digest(&mut result, &proof.trace_commitments[0]);
+        write_digest(&mut result, &proof.constraint_commitment);
+        for commitment in proof.fri_commitments.iter() {
+            write_digest(&mut result, commitment);
+        }
+
+        // out-of-domain frame, proof-of-work nonce, and the remainder
+        write_elements(&mut result, proof.ood_main_trace_frame.current());
+        write_elements(&mut result, proof.ood_main_trace_frame.next());
+        write_elements(&mut result, &proof.ood_constraint_evaluations);
+        write_word(&mut result, &proof.pow_nonce.to_be_bytes());
+        write_elements(&mut result, &proof.fri_remainder);
+
+        // trace and constraint queries; the first node of each path is the leaf, which the
+        // contract computes from the queried values
+        let trace_paths = proof
+            .main_trace_queries
+            .merkle_proof
+            .into_paths(&positions)
+            .map_err(SolidityError::InvalidMerkleProof)?;
+        let constraint_paths = proof
+            .constraint_queries
+            .merkle_proof
+            .into_paths(&positions)
+            .map_err(SolidityError::InvalidMerkleProof)?;
+        for (i, (trace_path, constraint_path)) in
+            trace_paths.iter().zip(constraint_paths.iter()).enumerate()
+        {
+            write_elements(&mut result, proof.main_trace_queries.values.get_row(i));
+            trace_path[1..]
+                .iter()
+                .for_each(|node| write_digest(&mut result, node));
+            write_elements(&mut result, proof.constraint_queries.values.get_row(i));
+            constraint_path[1..]
+                .iter()
+                .for_each(|node| write_digest(&mut result, node));
+        }
+
+        // FRI layer queries; values of a layer are stored once per folded position, but the
+        // contract expects them once per query
+        let mut query_positions = positions.clone();
+        let mut unique_positions = positions;
+        let mut domain_size = self.lde_domain_size;
+        for layer in proof.fri_layers.into_iter() {
+            let folded_positions =
+                fold_positions(&unique_positions, domain_size, self.folding_factor);
+            domain_size /= self.folding_factor;
+
+            let paths = layer
+                .merkle_proof
+                .into_paths(&folded_positions)
+                .map_err(SolidityError::InvalidMerkleProof)?;
+            for position in query_positions.iter_mut() {
+                *position %= domain_size;
+                let index = folded_positions
+                    .iter()
+                    .position(|p| p == position)
+                    .expect("folded position not found");
+                let values = &layer.values[index * self.folding_factor..][..self.folding_factor];
+                write_elements(&mut result, values);
+                paths[index][1..]
+                    .iter()
+                    .for_each(|node| write_digest(&mut result, node));
+            }
+
+            unique_positions = folded_positions;
+        }
+
+        debug_assert_eq!(self.proof_size(), result.len());
+        Ok(result)
+    }
+
+    /// Returns calldata for a call of the `verify` function of verifier contracts generated from
+    /// this specification for the specified proof and public inputs.
+    ///
+    /// # Errors
+    /// Returns an error if the proof could not be encoded via [VerifierSpec::encode_proof()].
+    pub fn encode_calldata<P>(
+        &self,
+        proof: &StarkProof,
+        pub_inputs: &P,
+    ) -> Result<Vec<u8>, SolidityError>
+    where
+        P: PublicInputElements<B>,
+    {
+        let proof = self.encode_proof(proof, pub_inputs)?;
+        let elements = pub_inputs.to_elements();
+
+        // function selector
+        let mut result = Keccak256::<B>::hash(b"verify(uint256[],bytes)").as_bytes()[..4].to_vec();
+
+        // head: offsets of the dynamic arguments relative to the start of the arguments
+        let inputs_offset = 64;
+        let proof_offset = inputs_offset + 32 * (elements.len() + 1);
+        write_word(&mut result, &(inputs_offset as u64).to_be_bytes());
+        write_word(&mut result, &(proof_offset as u64).to_be_bytes());
+
+        // public inputs array
+        write_word(&mut result, &(elements.len() as u64).to_be_bytes());
+        write_elements(&mut result, &elements);
+
+        // proof bytes padded to a multiple of 32 bytes
+        write_word(&mut result, &(proof.len() as u64).to_be_bytes());
+        result.extend_from_slice(&proof);
+        result.resize(result.len() + (32 - proof.len() % 32) % 32, 0);
+
+        Ok(result)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Replays the transcript of the proof to determine the positions at which the proof was
+    /// queried.
+    ///
+    /// Values drawn from the public coin between reseedings do not affect its state, and thus,
+    /// only the reseedings need to be replayed.
+    fn get_query_positions<P: PublicInputElements<B>>(
+        &self,
+        proof: &DecodedProof<B, Keccak256<B>>,
+        pub_inputs: &P,
+    ) -> Result<Vec<usize>, SolidityError> {
+        let mut seed = self.seed_prefix();
+        pub_inputs.write_into(&mut seed);
+        proof.context.write_into(&mut seed);
+
+        let mut coin = RandomCoin::<B, Keccak256<B>>::new(&seed);
+        coin.reseed(proof.trace_commitments[0]);
+        coin.reseed(proof.constraint_commitment);
+        coin.reseed(Keccak256::hash_elements(
+            proof.ood_main_trace_frame.current(),
+        ));
+        coin.reseed(Keccak256::hash_elements(proof.ood_main_trace_frame.next()));
+        coin.reseed(Keccak256::hash_elements(&proof.ood_constraint_evaluations));
+        for commitment in proof.fri_commitments.iter() {
+            coin.reseed(*commitment);
+        }
+        coin.reseed_with_int(proof.pow_nonce);
+
+        coin.draw_integers(self.num_queries, self.lde_domain_size)
+            .map_err(|_| SolidityError::RandomCoinError)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes the specified big-endian bytes as a 32-byte word.
+fn write_word(target: &mut Vec<u8>, bytes: &[u8]) {
+    target.resize(target.len() + 32 - bytes.len(), 0);
+    target.extend_from_slice(bytes);
+}
+
+/// Writes canonical values of the specified elements as 32-byte big-endian words.
+fn write_elements<B: StarkField>(target: &mut Vec<u8>, elements: &[B]) {
+    for element in elements {
+        let mut bytes = element.to_bytes();
+        bytes.reverse();
+        write_word(target, &bytes);
+    }
+}
+
+/// Writes the specified digest as is.
+fn write_digest<D: Digest>(target: &mut Vec<u8>, digest: &D) {
+    target.extend_from_slice(&digest.as_bytes());
+}
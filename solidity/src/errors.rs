@@ -0,0 +1,101 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use air::{FieldExtension, HashFunction, ProofDeserializationError};
+use core::fmt;
+use crypto::MerkleTreeError;
+
+// SOLIDITY ERROR
+// ================================================================================================
+/// Represents an error returned when a Solidity verifier could not be generated for a computation,
+/// or when a proof could not be encoded for such a verifier.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SolidityError {
+    /// This error occurs when proof options specify a hash function other than Keccak-256.
+    UnsupportedHashFunction(HashFunction),
+    /// This error occurs when proof options specify a field extension; only proofs generated in
+    /// the base field are supported.
+    UnsupportedFieldExtension(FieldExtension),
+    /// This error occurs when the computation declares auxiliary trace columns or random
+    /// elements.
+    AuxTraceNotSupported,
+    /// This error occurs when an assertion of the computation is not a single-value assertion
+    /// (i.e., it is a sequence or a public column assertion). The parameter is the index of the
+    /// trace column against which the assertion is placed.
+    UnsupportedAssertion(usize),
+    /// This error occurs when the value of an assertion is neither a constant nor one of the
+    /// elements of public inputs. The parameter is the index of the trace column against which
+    /// the assertion is placed.
+    UnsupportedAssertionValue(usize),
+    /// This error occurs when the structure of the constraints (e.g., the set of assertions or
+    /// their steps) depends on the values of public inputs.
+    InputDependentStructure,
+    /// This error occurs when serialized public inputs are not equal to the serialized list of
+    /// their elements.
+    InconsistentPublicInputs,
+    /// This error occurs when the degree of FRI layers would be truncated during folding, or the
+    /// remainder of the FRI protocol could not be checked for the specified parameters.
+    UnsupportedFriParameters,
+    /// This error occurs when a proof was generated for a different computation instance (e.g., a
+    /// different trace length or different proof options) than the one the verifier was generated
+    /// for.
+    ContextMismatch,
+    /// This error occurs when FRI layers of a proof were committed to using more than one
+    /// partition.
+    UnsupportedFriPartitions(usize),
+    /// This error occurs when a proof could not be decoded.
+    ProofDecodingFailed(ProofDeserializationError),
+    /// This error occurs when Merkle authentication paths could not be extracted from a proof.
+    InvalidMerkleProof(MerkleTreeError),
+    /// This error occurs when query positions could not be drawn for a proof.
+    RandomCoinError,
+}
+
+impl fmt::Display for SolidityError {
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedHashFunction(hash_fn) => {
+                write!(f, "hash function {:?} is not supported; only Keccak256 can be used", hash_fn)
+            }
+            Self::UnsupportedFieldExtension(extension) => {
+                write!(f, "field extension {:?} is not supported", extension)
+            }
+            Self::AuxTraceNotSupported => {
+                write!(f, "auxiliary trace segments are not supported")
+            }
+            Self::UnsupportedAssertion(column) => {
+                write!(f, "assertion against column {} is not a single-value assertion", column)
+            }
+            Self::UnsupportedAssertionValue(column) => {
+                write!(f, "value of assertion against column {} is neither a constant nor a public input element", column)
+            }
+            Self::InputDependentStructure => {
+                write!(f, "structure of constraints depends on values of public inputs")
+            }
+            Self::InconsistentPublicInputs => {
+                write!(f, "serialized public inputs are not equal to serialized public input elements")
+            }
+            Self::UnsupportedFriParameters => {
+                write!(f, "FRI parameters are not supported for the specified trace length")
+            }
+            Self::ContextMismatch => {
+                write!(f, "proof context does not match the context of the verifier")
+            }
+            Self::UnsupportedFriPartitions(num_partitions) => {
+                write!(f, "FRI proofs with {} partitions are not supported", num_partitions)
+            }
+            Self::ProofDecodingFailed(err) => {
+                write!(f, "failed to decode proof: {}", err)
+            }
+            Self::InvalidMerkleProof(err) => {
+                write!(f, "failed to extract Merkle authentication paths: {}", err)
+            }
+            Self::RandomCoinError => {
+                write!(f, "failed to draw query positions")
+            }
+        }
+    }
+}
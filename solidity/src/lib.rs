@@ -0,0 +1,44 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! This crate contains a generator of Solidity verifier contracts for Winterfell STARK proofs.
+//!
+//! A verifier contract checks proofs of a single computation instance: the computation must be
+//! described by an [AirDefinition](air::builder::AirDefinition), and the proof options and the
+//! length of the execution trace are fixed when the contract is generated. The generated contract
+//! replays the Keccak-based transcript of the proof, evaluates constraints at the out-of-domain
+//! point, checks Merkle authentication paths of all queries, and verifies the FRI proof.
+//!
+//! # Usage
+//! 1. Build a [VerifierSpec] for your computation, proof options, and trace length.
+//! 2. Generate the source code of the contract via [VerifierSpec::generate_contract()], and
+//!    deploy the compiled contract.
+//! 3. For each proof, build calldata for the `verify` function of the contract via
+//!    [VerifierSpec::encode_calldata()].
+//!
+//! # Restrictions
+//! Only a subset of computations and proof options is supported:
+//! * Proofs must be generated using [HashFunction::Keccak256](air::HashFunction::Keccak256)
+//!   without field extensions, and FRI layers must be committed to using a single partition.
+//! * Computations cannot have auxiliary trace segments.
+//! * All assertions must be single-value assertions, and their values must be either constants
+//!   or elements of public inputs. Public inputs must implement [PublicInputElements].
+//!
+//! Verification cost grows quadratically with the size of the FRI remainder, and thus, proof
+//! options which result in small remainders should be preferred.
+
+pub use air::{proof::StarkProof, ProofOptions};
+
+mod codegen;
+mod encoder;
+
+mod errors;
+pub use errors::SolidityError;
+
+mod spec;
+pub use spec::{PublicInputElements, VerifierSpec};
+
+#[cfg(test)]
+mod tests;
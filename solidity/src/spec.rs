@@ -0,0 +1,478 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use crate::SolidityError;
+use air::{
+    builder::{AirBuilder, AirDefinition, ConstraintGraph, DeclarativeAir},
+    proof::Context,
+    Air, AuxTraceRandElements, ConstraintDivisor, FieldExtension, HashFunction, ProofOptions,
+    TraceInfo,
+};
+use crypto::{hashers::Keccak256, Digest, Hasher};
+use math::{log2, FieldElement, StarkField};
+use utils::Serializable;
+
+// PUBLIC INPUT ELEMENTS
+// ================================================================================================
+/// Public inputs which can be represented as a fixed-size list of base field elements.
+///
+/// Public inputs are passed to a generated Solidity verifier as an array of field elements, and
+/// the verifier serializes them into bytes when building the seed of the public coin. Thus, the
+/// serialization of public inputs (via [Serializable]) must be the same as the serialization of
+/// their elements written one after another; this is checked when a verifier is generated.
+pub trait PublicInputElements<B: StarkField>: Serializable + Sized {
+    /// Number of field elements in the public inputs.
+    const NUM_ELEMENTS: usize;
+
+    /// Builds public inputs from the provided list of field elements.
+    fn from_elements(elements: &[B]) -> Self;
+
+    /// Returns the elements of these public inputs.
+    fn to_elements(&self) -> Vec<B>;
+}
+
+impl<B: StarkField> PublicInputElements<B> for B {
+    const NUM_ELEMENTS: usize = 1;
+
+    fn from_elements(elements: &[B]) -> Self {
+        elements[0]
+    }
+
+    fn to_elements(&self) -> Vec<B> {
+        vec![*self]
+    }
+}
+
+// VERIFIER SPECIFICATION
+// ================================================================================================
+/// Description of a STARK verifier for a single instance of a computation.
+///
+/// A specification is derived from an [AirDefinition] for fixed [ProofOptions] and a fixed trace
+/// length; it contains all constants which a verifier for such proofs needs to know. The
+/// specification can be used to generate a Solidity verifier contract via
+/// [VerifierSpec::generate_contract()], and to encode proofs into calldata for such a contract via
+/// [VerifierSpec::encode_calldata()].
+#[derive(Debug, Clone)]
+pub struct VerifierSpec<B: StarkField> {
+    pub(crate) air_id: Vec<u8>,
+    pub(crate) context: Context,
+    pub(crate) trace_length: usize,
+    pub(crate) trace_width: usize,
+    pub(crate) lde_domain_size: usize,
+    pub(crate) num_constraint_columns: usize,
+    pub(crate) num_public_inputs: usize,
+    pub(crate) num_queries: usize,
+    pub(crate) grinding_factor: u32,
+    pub(crate) folding_factor: usize,
+    pub(crate) num_fri_layers: usize,
+    pub(crate) remainder_max_degree: usize,
+    pub(crate) trace_generator: B,
+    pub(crate) lde_generator: B,
+    pub(crate) domain_offset: B,
+    pub(crate) graph: ConstraintGraph<B>,
+    pub(crate) periodic_column_polys: Vec<Vec<B>>,
+    pub(crate) num_transition_constraints: usize,
+    pub(crate) transition_divisors: Vec<ConstraintDivisor<B>>,
+    pub(crate) transition_groups: Vec<TransitionGroup>,
+    pub(crate) num_assertions: usize,
+    pub(crate) boundary_groups: Vec<BoundaryGroup<B>>,
+}
+
+/// Transition constraints which share the same divisor and degree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TransitionGroup {
+    pub divisor_index: usize,
+    pub degree_adjustment: u32,
+    /// Indexes of constraints in the group; the index of a constraint is also the index of its
+    /// pair of composition coefficients.
+    pub constraints: Vec<usize>,
+}
+
+/// Assertions which share the same divisor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BoundaryGroup<B: StarkField> {
+    pub divisor: ConstraintDivisor<B>,
+    pub degree_adjustment: u32,
+    pub assertions: Vec<BoundaryAssertion<B>>,
+}
+
+/// A single-value assertion against a column of the execution trace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BoundaryAssertion<B: StarkField> {
+    pub column: usize,
+    /// Index of the pair of composition coefficients for this assertion among the coefficients
+    /// drawn for assertions.
+    pub coefficients: usize,
+    pub value: AssertionValue<B>,
+}
+
+/// Source of the value of an assertion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AssertionValue<B: StarkField> {
+    Constant(B),
+    PublicInput(usize),
+}
+
+impl<B: StarkField> VerifierSpec<B> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a specification of a verifier for proofs of the computation described by `D`
+    /// generated with the specified `options` for an execution trace of the specified length.
+    ///
+    /// The structure of the constraints is obtained by instantiating the AIR for pseudo-random
+    /// public inputs; values of assertions are recognized either as constants, or as elements of
+    /// public inputs.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * `options` do not specify [HashFunction::Keccak256] or specify a field extension.
+    /// * The computation declares auxiliary trace columns or random elements.
+    /// * Any of the assertions is not a single-value assertion, or its value is neither a
+    ///   constant nor an element of public inputs.
+    /// * The structure of the constraints depends on the values of public inputs, or public
+    ///   inputs are not serialized as a list of their elements.
+    /// * FRI parameters implied by `options` are not valid for the specified trace length.
+    ///
+    /// # Panics
+    /// Panics if `trace_length` is not a power of two or is smaller than 8.
+    pub fn new<D>(options: ProofOptions, trace_length: usize) -> Result<Self, SolidityError>
+    where
+        D: AirDefinition<BaseField = B>,
+        D::PublicInputs: PublicInputElements<B>,
+    {
+        if options.hash_fn() != HashFunction::Keccak256 {
+            return Err(SolidityError::UnsupportedHashFunction(options.hash_fn()));
+        }
+        if options.field_extension() != FieldExtension::None {
+            return Err(SolidityError::UnsupportedFieldExtension(
+                options.field_extension(),
+            ));
+        }
+
+        let mut builder = AirBuilder::new();
+        D::define(&mut builder);
+        if !builder.aux_column_names().is_empty() || builder.num_rand_elements() > 0 {
+            return Err(SolidityError::AuxTraceNotSupported);
+        }
+        let trace_info = TraceInfo::new(builder.column_names().len(), trace_length);
+
+        // instantiate the AIR for two sets of pseudo-random public inputs; the structure of the
+        // constraints must be the same for both of them
+        let num_public_inputs = D::PublicInputs::NUM_ELEMENTS;
+        let probes = [1u8, 2].map(|seed| build_probe_elements::<B>(seed, num_public_inputs));
+        let mut airs = Vec::with_capacity(probes.len());
+        for elements in probes.iter() {
+            let pub_inputs = D::PublicInputs::from_elements(elements);
+            if pub_inputs.to_bytes() != elements.to_bytes() || &pub_inputs.to_elements() != elements
+            {
+                return Err(SolidityError::InconsistentPublicInputs);
+            }
+            airs.push(DeclarativeAir::<D>::new(
+                trace_info.clone(),
+                pub_inputs,
+                options.clone(),
+            ));
+        }
+        let air = &airs[0];
+
+        let boundary_groups = build_boundary_groups(&airs, &probes)?;
+        let (transition_divisors, transition_groups) = build_transition_groups(air);
+
+        // make sure the degree of FRI layers is not truncated during folding, and that the
+        // remainder can be checked by the verifier
+        let fri_options = options.to_fri_options();
+        let lde_domain_size = air.lde_domain_size();
+        let folding_factor = fri_options.folding_factor();
+        let num_fri_layers = fri_options.num_fri_layers(lde_domain_size);
+        let mut max_degree_plus_1 = air.trace_poly_degree() + 1;
+        for _ in 0..num_fri_layers {
+            if max_degree_plus_1 % folding_factor != 0 {
+                return Err(SolidityError::UnsupportedFriParameters);
+            }
+            max_degree_plus_1 /= folding_factor;
+        }
+        let remainder_size = lde_domain_size / folding_factor.pow(num_fri_layers as u32);
+        if max_degree_plus_1 >= remainder_size || remainder_size / folding_factor < 2 {
+            return Err(SolidityError::UnsupportedFriParameters);
+        }
+
+        Ok(VerifierSpec {
+            air_id: D::ID.to_vec(),
+            context: Context::new::<B>(&trace_info, options.clone()),
+            trace_length,
+            trace_width: trace_info.width(),
+            lde_domain_size,
+            num_constraint_columns: air.ce_blowup_factor(),
+            num_public_inputs,
+            num_queries: options.num_queries(),
+            grinding_factor: options.grinding_factor(),
+            folding_factor,
+            num_fri_layers,
+            remainder_max_degree: max_degree_plus_1 - 1,
+            trace_generator: air.trace_domain_generator(),
+            lde_generator: air.lde_domain_generator(),
+            domain_offset: air.domain_offset(),
+            graph: air.graph().clone(),
+            periodic_column_polys: air.get_periodic_column_polys(),
+            num_transition_constraints: air.context().num_main_transition_constraints(),
+            transition_divisors,
+            transition_groups,
+            num_assertions: air.context().num_assertions(),
+            boundary_groups,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the length of execution traces of proofs accepted by the verifier.
+    pub fn trace_length(&self) -> usize {
+        self.trace_length
+    }
+
+    /// Returns the number of field elements in public inputs accepted by the verifier.
+    pub fn num_public_inputs(&self) -> usize {
+        self.num_public_inputs
+    }
+
+    /// Returns the size of encoded proofs accepted by the verifier in bytes.
+    pub fn proof_size(&self) -> usize {
+        self.layout().size
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the bytes which precede serialized public inputs in the seed of the public coin.
+    pub(crate) fn seed_prefix(&self) -> Vec<u8> {
+        let mut result = vec![self.air_id.len() as u8];
+        result.extend_from_slice(&self.air_id);
+        result
+    }
+
+    /// Returns the size of the remainder of the FRI protocol.
+    pub(crate) fn remainder_size(&self) -> usize {
+        self.lde_domain_size / self.folding_factor.pow(self.num_fri_layers as u32)
+    }
+
+    /// Returns the depth of the Merkle trees committing to the trace and constraint evaluations.
+    pub(crate) fn lde_depth(&self) -> usize {
+        log2(self.lde_domain_size) as usize
+    }
+
+    /// Returns the depth of the Merkle tree committing to the specified FRI layer.
+    pub(crate) fn fri_layer_depth(&self, layer: usize) -> usize {
+        self.lde_depth() - (layer + 1) * log2(self.folding_factor) as usize
+    }
+
+    /// Returns the layout of encoded proofs.
+    pub(crate) fn layout(&self) -> ProofLayout {
+        ProofLayout::new(self)
+    }
+}
+
+// PROOF LAYOUT
+// ================================================================================================
+/// Byte offsets of the components of an encoded proof.
+///
+/// All components of an encoded proof are 32-byte words: digests are written as is, while field
+/// elements and the proof-of-work nonce are written as big-endian integers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ProofLayout {
+    pub trace_root: usize,
+    pub constraint_root: usize,
+    /// Commitments to FRI layers followed by the commitment to the FRI remainder.
+    pub fri_roots: usize,
+    pub ood_current: usize,
+    pub ood_next: usize,
+    pub ood_constraints: usize,
+    pub pow_nonce: usize,
+    pub remainder: usize,
+    /// For each query: trace row, its Merkle path, constraint evaluations, and their Merkle path.
+    pub queries: usize,
+    pub query_size: usize,
+    /// For each FRI layer, and for each query: layer values, and their Merkle path.
+    pub fri_queries: usize,
+    pub size: usize,
+}
+
+impl ProofLayout {
+    fn new<B: StarkField>(spec: &VerifierSpec<B>) -> Self {
+        let trace_root = 0;
+        let constraint_root = trace_root + 32;
+        let fri_roots = constraint_root + 32;
+        let ood_current = fri_roots + (spec.num_fri_layers + 1) * 32;
+        let ood_next = ood_current + spec.trace_width * 32;
+        let ood_constraints = ood_next + spec.trace_width * 32;
+        let pow_nonce = ood_constraints + spec.num_constraint_columns * 32;
+        let remainder = pow_nonce + 32;
+        let queries = remainder + spec.remainder_size() * 32;
+        let query_size =
+            (spec.trace_width + spec.num_constraint_columns + 2 * spec.lde_depth()) * 32;
+        let fri_queries = queries + spec.num_queries * query_size;
+        let size = fri_queries
+            + (0..spec.num_fri_layers)
+                .map(|layer| {
+                    spec.num_queries * (spec.folding_factor + spec.fri_layer_depth(layer)) * 32
+                })
+                .sum::<usize>();
+
+        ProofLayout {
+            trace_root,
+            constraint_root,
+            fri_roots,
+            ood_current,
+            ood_next,
+            ood_constraints,
+            pow_nonce,
+            remainder,
+            queries,
+            query_size,
+            fri_queries,
+            size,
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns `n` pseudo-random elements derived from the specified seed; these are used as public
+/// inputs when probing the structure of the constraints.
+fn build_probe_elements<B: StarkField>(seed: u8, n: usize) -> Vec<B> {
+    (0..n)
+        .map(|i| {
+            let mut bytes = [0; 16];
+            let digest = Keccak256::<B>::hash(&[&[seed][..], &(i as u64).to_le_bytes()].concat());
+            bytes.copy_from_slice(&digest.as_bytes()[..16]);
+            B::from(u128::from_le_bytes(bytes))
+        })
+        .collect()
+}
+
+/// Groups transition constraints of the AIR by their divisors and degrees.
+fn build_transition_groups<D: AirDefinition>(
+    air: &DeclarativeAir<D>,
+) -> (Vec<ConstraintDivisor<D::BaseField>>, Vec<TransitionGroup>) {
+    let context = air.context();
+    let coefficients =
+        vec![(D::BaseField::ZERO, D::BaseField::ZERO); context.num_transition_constraints()];
+    let constraints = air.get_transition_constraints(&coefficients);
+
+    let divisors = constraints.divisors().to_vec();
+    let groups = constraints
+        .main_constraints()
+        .iter()
+        .map(|group| {
+            // this mirrors the degree adjustment computed by transition constraint groups
+            let divisor_degree = divisors[group.divisor_index()].degree();
+            let evaluation_degree = group.degree().get_evaluation_degree(air.trace_length());
+            TransitionGroup {
+                divisor_index: group.divisor_index(),
+                degree_adjustment: (context.composition_degree() + divisor_degree
+                    - evaluation_degree) as u32,
+                constraints: group.indexes().to_vec(),
+            }
+        })
+        .collect();
+
+    (divisors, groups)
+}
+
+/// Groups assertions of the AIR instantiated for different public inputs by their divisors, and
+/// determines the source of the value of each assertion.
+fn build_boundary_groups<D: AirDefinition>(
+    airs: &[DeclarativeAir<D>],
+    probes: &[Vec<D::BaseField>],
+) -> Result<Vec<BoundaryGroup<D::BaseField>>, SolidityError> {
+    // assign a distinct coefficient to each assertion so that the assertions could be identified
+    // in the groups
+    let num_assertions = airs[0].context().num_assertions();
+    let coefficients = (0..num_assertions)
+        .map(|i| (D::BaseField::from(i as u64), D::BaseField::ZERO))
+        .collect::<Vec<_>>();
+    let coefficient_index = |value: D::BaseField| {
+        coefficients
+            .iter()
+            .position(|&(c, _)| c == value)
+            .expect("unknown composition coefficient")
+    };
+
+    // read the assertions of each AIR instance, and record their values
+    let mut instances = Vec::with_capacity(airs.len());
+    for air in airs {
+        if air.context().num_assertions() != num_assertions {
+            return Err(SolidityError::InputDependentStructure);
+        }
+        let constraints = air.get_boundary_constraints(&AuxTraceRandElements::new(), &coefficients);
+        let mut groups = Vec::new();
+        for group in constraints.main_constraints() {
+            let mut assertions = Vec::new();
+            for constraint in group.constraints() {
+                if constraint.is_public_column() || constraint.poly().len() != 1 {
+                    return Err(SolidityError::UnsupportedAssertion(constraint.column()));
+                }
+                assertions.push(BoundaryAssertion {
+                    column: constraint.column(),
+                    coefficients: coefficient_index(constraint.cc().0),
+                    value: AssertionValue::Constant(constraint.poly()[0]),
+                });
+            }
+            groups.push(BoundaryGroup {
+                divisor: group.divisor().clone(),
+                degree_adjustment: group.degree_adjustment(),
+                assertions,
+            });
+        }
+        instances.push(groups);
+    }
+
+    // an assertion value is a public input element if it equals the same element of all probed
+    // public inputs; otherwise, it must be the same constant for all of them
+    let mut result = instances[0].clone();
+    for (g, group) in result.iter_mut().enumerate() {
+        for (a, assertion) in group.assertions.iter_mut().enumerate() {
+            let values = instances
+                .iter()
+                .map(|groups| {
+                    let other = groups
+                        .get(g)
+                        .and_then(|group| group.assertions.get(a))
+                        .ok_or(SolidityError::InputDependentStructure)?;
+                    if other.column != assertion.column
+                        || other.coefficients != assertion.coefficients
+                        || groups[g].divisor != instances[0][g].divisor
+                    {
+                        return Err(SolidityError::InputDependentStructure);
+                    }
+                    match other.value {
+                        AssertionValue::Constant(value) => Ok(value),
+                        AssertionValue::PublicInput(_) => unreachable!(),
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let input_index = (0..probes[0].len()).find(|&i| {
+                values
+                    .iter()
+                    .zip(probes.iter())
+                    .all(|(&value, probe)| value == probe[i])
+            });
+            assertion.value = match input_index {
+                Some(i) => AssertionValue::PublicInput(i),
+                None if values.iter().all(|&value| value == values[0]) => {
+                    AssertionValue::Constant(values[0])
+                }
+                None => return Err(SolidityError::UnsupportedAssertionValue(assertion.column)),
+            };
+        }
+    }
+
+    if instances.iter().any(|groups| groups.len() != result.len()) {
+        return Err(SolidityError::InputDependentStructure);
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,504 @@
+// SPDX-License-Identifier: MIT
+// This file was generated by winter-solidity; do not edit it manually.
+pragma solidity ^0.8.0;
+
+/// @title {{CONTRACT_NAME}}
+/// @notice Verifier of STARK proofs for the computation "{{AIR_ID}}"
+/// with execution traces of {{TRACE_LENGTH}} steps. Proofs must be generated with the proof
+/// options this contract was generated for, and must be encoded via the proof encoder of
+/// winter-solidity.
+contract {{CONTRACT_NAME}} {
+    // CONSTANTS
+    // ============================================================================================
+
+{{CONSTANTS}}
+
+    // VERIFIER STATE
+    // ============================================================================================
+
+    /// State of the public coin used to draw pseudo-random values.
+    struct Coin {
+        bytes32 seed;
+        uint64 counter;
+    }
+
+    /// Values derived from the proof transcript which are needed to check individual queries.
+    struct Transcript {
+        uint256 z;
+        uint256[] oodCurrent;
+        uint256[] oodNext;
+        uint256[] oodConstraints;
+        uint256[] traceCoefficients;
+        uint256[] constraintCoefficients;
+        uint256 degreeCoefficient0;
+        uint256 degreeCoefficient1;
+        uint256[] alphas;
+        uint256[] positions;
+        uint256[] remainder;
+    }
+
+    // VERIFICATION
+    // ============================================================================================
+
+    /// @notice Verifies a STARK proof against the specified public inputs. Reverts with the
+    /// description of the failed check if the proof is not valid.
+    /// @param publicInputs elements of public inputs of the computation.
+    /// @param proof proof encoded by the proof encoder of winter-solidity.
+    /// @return true if the proof is valid.
+    function verify(uint256[] calldata publicInputs, bytes calldata proof)
+        external
+        view
+        returns (bool)
+    {
+        require(publicInputs.length == NUM_PUBLIC_INPUTS, "invalid number of public inputs");
+        for (uint256 i = 0; i < NUM_PUBLIC_INPUTS; i++) {
+            require(publicInputs[i] < MODULUS, "invalid public input");
+        }
+        require(proof.length == PROOF_SIZE, "invalid proof size");
+
+        Transcript memory t = _readTranscript(publicInputs, proof);
+        _verifyRemainder(t.remainder, _readDigest(proof, FRI_ROOTS_OFFSET + NUM_FRI_LAYERS * 32));
+        for (uint256 q = 0; q < NUM_QUERIES; q++) {
+            _verifyQuery(t, proof, q);
+        }
+        return true;
+    }
+
+    /// Replays the proof transcript, and checks out-of-domain constraint evaluations and
+    /// proof-of-work.
+    function _readTranscript(uint256[] calldata publicInputs, bytes calldata proof)
+        internal
+        view
+        returns (Transcript memory t)
+    {
+        // seed the public coin with the AIR identifier, public inputs, and proof context
+        Coin memory coin = Coin(
+            keccak256(abi.encodePacked(SEED_PREFIX, _serializeElements(publicInputs), CONTEXT)),
+            0
+        );
+
+        // draw constraint composition coefficients
+        _reseed(coin, _readDigest(proof, TRACE_ROOT_OFFSET));
+        uint256[] memory coefficients = new uint256[](
+            2 * (NUM_TRANSITION_CONSTRAINTS + NUM_ASSERTIONS)
+        );
+        for (uint256 i = 0; i < coefficients.length; i++) {
+            coefficients[i] = _draw(coin);
+        }
+
+        // draw the out-of-domain point, and make sure constraints evaluated over the
+        // out-of-domain frame are consistent with the evaluations of composition columns
+        _reseed(coin, _readDigest(proof, CONSTRAINT_ROOT_OFFSET));
+        t.z = _draw(coin);
+        t.oodCurrent = _readElements(proof, OOD_CURRENT_OFFSET, TRACE_WIDTH);
+        t.oodNext = _readElements(proof, OOD_NEXT_OFFSET, TRACE_WIDTH);
+        t.oodConstraints = _readElements(proof, OOD_CONSTRAINTS_OFFSET, NUM_CONSTRAINT_COLUMNS);
+        require(
+            _evaluateConstraints(t, coefficients, publicInputs) == _combineOodConstraints(t),
+            "inconsistent out-of-domain constraint evaluations"
+        );
+        _reseed(coin, _hashElements(t.oodCurrent));
+        _reseed(coin, _hashElements(t.oodNext));
+        _reseed(coin, _hashElements(t.oodConstraints));
+
+        // draw DEEP composition coefficients; the third coefficient of each trace column is used
+        // only with field extensions, but it must be drawn nonetheless
+        t.traceCoefficients = new uint256[](2 * TRACE_WIDTH);
+        for (uint256 i = 0; i < TRACE_WIDTH; i++) {
+            t.traceCoefficients[2 * i] = _draw(coin);
+            t.traceCoefficients[2 * i + 1] = _draw(coin);
+            _draw(coin);
+        }
+        t.constraintCoefficients = new uint256[](NUM_CONSTRAINT_COLUMNS);
+        for (uint256 i = 0; i < NUM_CONSTRAINT_COLUMNS; i++) {
+            t.constraintCoefficients[i] = _draw(coin);
+        }
+        t.degreeCoefficient0 = _draw(coin);
+        t.degreeCoefficient1 = _draw(coin);
+
+        // draw FRI folding values; a value is drawn for the remainder commitment as well
+        t.alphas = new uint256[](NUM_FRI_LAYERS + 1);
+        for (uint256 i = 0; i <= NUM_FRI_LAYERS; i++) {
+            _reseed(coin, _readDigest(proof, FRI_ROOTS_OFFSET + i * 32));
+            t.alphas[i] = _draw(coin);
+        }
+
+        // check proof-of-work and draw query positions
+        uint256 nonce = _readWord(proof, POW_NONCE_OFFSET);
+        require(nonce <= type(uint64).max, "invalid proof-of-work nonce");
+        _reseedWithInt(coin, uint64(nonce));
+        require((_readLE(coin.seed, 8) & GRINDING_MASK) == 0, "proof-of-work verification failed");
+        t.positions = _drawPositions(coin);
+
+        t.remainder = _readElements(proof, REMAINDER_OFFSET, REMAINDER_SIZE);
+    }
+
+    /// Checks trace and constraint evaluations at a single query position, and checks that the
+    /// DEEP composition polynomial evaluated at this position is folded correctly by FRI.
+    function _verifyQuery(Transcript memory t, bytes calldata proof, uint256 q) internal view {
+        uint256 position = t.positions[q];
+        uint256 offset = QUERIES_OFFSET + q * QUERY_SIZE;
+
+        uint256[] memory traceRow = _readElements(proof, offset, TRACE_WIDTH);
+        offset += TRACE_WIDTH * 32;
+        require(
+            _computeRoot(proof, offset, LDE_DEPTH, _hashElements(traceRow), position) ==
+                _readDigest(proof, TRACE_ROOT_OFFSET),
+            "trace query does not match commitment"
+        );
+        offset += LDE_DEPTH * 32;
+
+        uint256[] memory constraintRow = _readElements(proof, offset, NUM_CONSTRAINT_COLUMNS);
+        offset += NUM_CONSTRAINT_COLUMNS * 32;
+        require(
+            _computeRoot(proof, offset, LDE_DEPTH, _hashElements(constraintRow), position) ==
+                _readDigest(proof, CONSTRAINT_ROOT_OFFSET),
+            "constraint query does not match commitment"
+        );
+
+        uint256 x = mulmod(DOMAIN_OFFSET, _exp(LDE_GENERATOR, position), MODULUS);
+        uint256 evaluation = _composeDeep(t, traceRow, constraintRow, x);
+        _verifyFriQuery(t, proof, q, position, evaluation);
+    }
+
+    // CONSTRAINT EVALUATION
+    // ============================================================================================
+
+    /// Evaluates the random linear combination of transition and boundary constraints over the
+    /// out-of-domain frame, and divides the constraints by their divisors.
+    function _evaluateConstraints(
+        Transcript memory t,
+        uint256[] memory c,
+        uint256[] calldata publicInputs
+    ) internal view returns (uint256 result) {
+        uint256 z = t.z;
+{{CONSTRAINT_EVALUATION}}
+    }
+
+    /// Combines out-of-domain evaluations of composition columns as sum(z^i * value_i).
+    function _combineOodConstraints(Transcript memory t) internal pure returns (uint256 result) {
+        for (uint256 i = NUM_CONSTRAINT_COLUMNS; i > 0; i--) {
+            result = addmod(mulmod(result, t.z, MODULUS), t.oodConstraints[i - 1], MODULUS);
+        }
+    }
+
+    // DEEP COMPOSITION
+    // ============================================================================================
+
+    /// Evaluates the DEEP composition polynomial at x from trace and constraint evaluations at x.
+    function _composeDeep(
+        Transcript memory t,
+        uint256[] memory traceRow,
+        uint256[] memory constraintRow,
+        uint256 x
+    ) internal view returns (uint256 result) {
+        uint256 invZ = _inv(addmod(x, MODULUS - t.z, MODULUS));
+        uint256 invZg = _inv(addmod(x, MODULUS - mulmod(t.z, TRACE_GENERATOR, MODULUS), MODULUS));
+        for (uint256 i = 0; i < TRACE_WIDTH; i++) {
+            uint256 t1 = mulmod(addmod(traceRow[i], MODULUS - t.oodCurrent[i], MODULUS), invZ, MODULUS);
+            uint256 t2 = mulmod(addmod(traceRow[i], MODULUS - t.oodNext[i], MODULUS), invZg, MODULUS);
+            result = addmod(result, mulmod(t1, t.traceCoefficients[2 * i], MODULUS), MODULUS);
+            result = addmod(result, mulmod(t2, t.traceCoefficients[2 * i + 1], MODULUS), MODULUS);
+        }
+
+        uint256 invZm = _inv(addmod(x, MODULUS - _exp(t.z, NUM_CONSTRAINT_COLUMNS), MODULUS));
+        for (uint256 i = 0; i < NUM_CONSTRAINT_COLUMNS; i++) {
+            uint256 h = mulmod(addmod(constraintRow[i], MODULUS - t.oodConstraints[i], MODULUS), invZm, MODULUS);
+            result = addmod(result, mulmod(h, t.constraintCoefficients[i], MODULUS), MODULUS);
+        }
+
+        uint256 adjustment = addmod(t.degreeCoefficient0, mulmod(x, t.degreeCoefficient1, MODULUS), MODULUS);
+        result = mulmod(result, adjustment, MODULUS);
+    }
+
+    // FRI
+    // ============================================================================================
+
+    /// Checks that the evaluation of the DEEP composition polynomial at the specified query
+    /// position is folded correctly through all FRI layers into the remainder.
+    function _verifyFriQuery(
+        Transcript memory t,
+        bytes calldata proof,
+        uint256 q,
+        uint256 position,
+        uint256 evaluation
+    ) internal view {
+        uint256 domainGenerator = LDE_GENERATOR;
+        uint256 layerOffset = FRI_QUERIES_OFFSET;
+        for (uint256 layer = 0; layer < NUM_FRI_LAYERS; layer++) {
+            // the Merkle tree of a layer has as many leaves as there are rows in the layer
+            uint256 depth = LDE_DEPTH - (layer + 1) * LOG_FOLDING_FACTOR;
+            uint256[] memory values = _readLayerValues(
+                proof,
+                layer,
+                layerOffset + q * (FOLDING_FACTOR + depth) * 32,
+                depth,
+                position,
+                evaluation
+            );
+            position = position & ((1 << depth) - 1);
+            evaluation = _foldCoset(
+                values,
+                mulmod(_exp(domainGenerator, position), DOMAIN_OFFSET, MODULUS),
+                t.alphas[layer]
+            );
+            domainGenerator = _exp(domainGenerator, FOLDING_FACTOR);
+            layerOffset += NUM_QUERIES * (FOLDING_FACTOR + depth) * 32;
+        }
+        require(t.remainder[position] == evaluation, "invalid FRI remainder folding");
+    }
+
+    /// Reads values of a FRI layer at the row into which the specified position is folded, and
+    /// checks them against the layer commitment and the evaluation from the previous layer.
+    function _readLayerValues(
+        bytes calldata proof,
+        uint256 layer,
+        uint256 offset,
+        uint256 depth,
+        uint256 position,
+        uint256 evaluation
+    ) internal pure returns (uint256[] memory values) {
+        values = _readElements(proof, offset, FOLDING_FACTOR);
+        require(values[position >> depth] == evaluation, "invalid FRI layer folding");
+        bytes32 leaf = _hashElements(values);
+        require(
+            _computeRoot(proof, offset + FOLDING_FACTOR * 32, depth, leaf, position & ((1 << depth) - 1)) ==
+                _readDigest(proof, FRI_ROOTS_OFFSET + layer * 32),
+            "FRI layer query does not match commitment"
+        );
+    }
+
+    /// Evaluates at alpha the polynomial which takes the specified values over the coset
+    /// xe * w^j, where w is a root of unity of order FOLDING_FACTOR.
+    ///
+    /// The polynomial is evaluated via the Lagrange formula, which for a coset becomes:
+    /// p(alpha) = sum(y_j * x_j / (alpha - x_j)) * (alpha^N - xe^N) / (N * xe^N).
+    function _foldCoset(uint256[] memory values, uint256 xe, uint256 alpha)
+        internal
+        view
+        returns (uint256 result)
+    {
+        // compute x_j and prefix products of (alpha - x_j)
+        uint256[] memory xs = new uint256[](FOLDING_FACTOR);
+        uint256[] memory products = new uint256[](FOLDING_FACTOR);
+        {
+            uint256 x = xe;
+            uint256 product = 1;
+            for (uint256 j = 0; j < FOLDING_FACTOR; j++) {
+                xs[j] = x;
+                product = mulmod(product, addmod(alpha, MODULUS - x, MODULUS), MODULUS);
+                products[j] = product;
+                x = mulmod(x, FOLDING_ROOT, MODULUS);
+            }
+        }
+
+        // invert all (alpha - x_j) and N * xe^N at once
+        uint256 xeN = _exp(xe, FOLDING_FACTOR);
+        uint256 inv = _inv(
+            mulmod(products[FOLDING_FACTOR - 1], mulmod(FOLDING_FACTOR, xeN, MODULUS), MODULUS)
+        );
+        for (uint256 j = FOLDING_FACTOR - 1; j > 0; j--) {
+            uint256 term = mulmod(values[j], xs[j], MODULUS);
+            result = addmod(result, mulmod(term, mulmod(inv, products[j - 1], MODULUS), MODULUS), MODULUS);
+            inv = mulmod(inv, addmod(alpha, MODULUS - xs[j], MODULUS), MODULUS);
+        }
+        result = addmod(result, mulmod(mulmod(values[0], xs[0], MODULUS), inv, MODULUS), MODULUS);
+
+        uint256 vanishing = addmod(_exp(alpha, FOLDING_FACTOR), MODULUS - xeN, MODULUS);
+        result = mulmod(result, vanishing, MODULUS);
+    }
+
+    /// Checks that the remainder matches its commitment, and that the remainder values are
+    /// evaluations of a polynomial of degree at most REMAINDER_MAX_DEGREE.
+    function _verifyRemainder(uint256[] memory remainder, bytes32 root) internal view {
+        // leaf i of the remainder commitment is the hash of values at positions
+        // i, i + n/N, ..., i + (N - 1) * n/N
+        uint256 numLeaves = REMAINDER_SIZE / FOLDING_FACTOR;
+        bytes32[] memory nodes = new bytes32[](2 * numLeaves);
+        uint256[] memory values = new uint256[](FOLDING_FACTOR);
+        for (uint256 i = 0; i < numLeaves; i++) {
+            for (uint256 j = 0; j < FOLDING_FACTOR; j++) {
+                values[j] = remainder[i + j * numLeaves];
+            }
+            nodes[numLeaves + i] = _hashElements(values);
+        }
+        for (uint256 i = numLeaves - 1; i > 0; i--) {
+            nodes[i] = _merge(nodes[2 * i], nodes[2 * i + 1]);
+        }
+        require(nodes[1] == root, "FRI remainder does not match commitment");
+
+        // all coefficients of the interpolated polynomial above the max degree must be zero; the
+        // coefficient k is proportional to sum(r_j * w^(-j * k))
+        for (uint256 k = REMAINDER_MAX_DEGREE + 1; k < REMAINDER_SIZE; k++) {
+            uint256 w = _exp(REMAINDER_ROOT_INV, k);
+            uint256 x = 1;
+            uint256 coefficient = 0;
+            for (uint256 j = 0; j < REMAINDER_SIZE; j++) {
+                coefficient = addmod(coefficient, mulmod(remainder[j], x, MODULUS), MODULUS);
+                x = mulmod(x, w, MODULUS);
+            }
+            require(coefficient == 0, "FRI remainder degree is too high");
+        }
+    }
+
+    // PUBLIC COIN
+    // ============================================================================================
+
+    function _reseed(Coin memory coin, bytes32 data) internal pure {
+        coin.seed = _merge(coin.seed, data);
+        coin.counter = 0;
+    }
+
+    function _reseedWithInt(Coin memory coin, uint64 value) internal pure {
+        coin.seed = keccak256(abi.encodePacked(coin.seed, _toLE64(value)));
+        coin.counter = 0;
+    }
+
+    function _next(Coin memory coin) internal pure returns (bytes32) {
+        coin.counter += 1;
+        return keccak256(abi.encodePacked(coin.seed, _toLE64(coin.counter)));
+    }
+
+    /// Draws a field element from the first ELEMENT_BYTES of the next pseudo-random value.
+    function _draw(Coin memory coin) internal pure returns (uint256) {
+        for (uint256 i = 0; i < 1000; i++) {
+            uint256 value = _readLE(_next(coin), ELEMENT_BYTES);
+            if (value < MODULUS) {
+                return value;
+            }
+        }
+        revert("failed to draw a field element");
+    }
+
+    /// Draws NUM_QUERIES unique positions in the LDE domain.
+    function _drawPositions(Coin memory coin) internal pure returns (uint256[] memory positions) {
+        positions = new uint256[](NUM_QUERIES);
+        uint256 count = 0;
+        for (uint256 i = 0; i < 1000 && count < NUM_QUERIES; i++) {
+            uint256 value = _readLE(_next(coin), 8) & (LDE_DOMAIN_SIZE - 1);
+            bool duplicate = false;
+            for (uint256 j = 0; j < count; j++) {
+                if (positions[j] == value) {
+                    duplicate = true;
+                    break;
+                }
+            }
+            if (!duplicate) {
+                positions[count] = value;
+                count += 1;
+            }
+        }
+        require(count == NUM_QUERIES, "failed to draw query positions");
+    }
+
+    // HASHING
+    // ============================================================================================
+
+    function _merge(bytes32 a, bytes32 b) internal pure returns (bytes32 result) {
+        assembly {
+            mstore(0x00, a)
+            mstore(0x20, b)
+            result := keccak256(0x00, 0x40)
+        }
+    }
+
+    /// Hashes canonical little-endian serializations of the specified field elements.
+    function _hashElements(uint256[] memory elements) internal pure returns (bytes32) {
+        return keccak256(_serializeElements(elements));
+    }
+
+    /// Computes the root of a Merkle tree from a leaf at the specified index and a path of
+    /// sibling nodes read from the proof at the specified offset.
+    function _computeRoot(
+        bytes calldata proof,
+        uint256 offset,
+        uint256 depth,
+        bytes32 leaf,
+        uint256 index
+    ) internal pure returns (bytes32 node) {
+        node = leaf;
+        for (uint256 i = 0; i < depth; i++) {
+            bytes32 sibling = _readDigest(proof, offset + i * 32);
+            node = (index & 1) == 0 ? _merge(node, sibling) : _merge(sibling, node);
+            index >>= 1;
+        }
+    }
+
+    // SERIALIZATION
+    // ============================================================================================
+
+    function _readWord(bytes calldata proof, uint256 offset) internal pure returns (uint256 value) {
+        assembly {
+            value := calldataload(add(proof.offset, offset))
+        }
+    }
+
+    function _readDigest(bytes calldata proof, uint256 offset) internal pure returns (bytes32) {
+        return bytes32(_readWord(proof, offset));
+    }
+
+    function _readElements(bytes calldata proof, uint256 offset, uint256 count)
+        internal
+        pure
+        returns (uint256[] memory elements)
+    {
+        elements = new uint256[](count);
+        for (uint256 i = 0; i < count; i++) {
+            uint256 value = _readWord(proof, offset + i * 32);
+            require(value < MODULUS, "invalid field element");
+            elements[i] = value;
+        }
+    }
+
+    function _serializeElements(uint256[] memory elements) internal pure returns (bytes memory result) {
+        result = new bytes(elements.length * ELEMENT_BYTES);
+        for (uint256 i = 0; i < elements.length; i++) {
+            uint256 value = elements[i];
+            for (uint256 j = 0; j < ELEMENT_BYTES; j++) {
+                result[i * ELEMENT_BYTES + j] = bytes1(uint8(value >> (8 * j)));
+            }
+        }
+    }
+
+    /// Interprets the first numBytes of the digest as a little-endian integer.
+    function _readLE(bytes32 digest, uint256 numBytes) internal pure returns (uint256 result) {
+        for (uint256 i = 0; i < numBytes; i++) {
+            result |= uint256(uint8(digest[i])) << (8 * i);
+        }
+    }
+
+    function _toLE64(uint64 value) internal pure returns (bytes8) {
+        uint64 result = 0;
+        for (uint256 i = 0; i < 8; i++) {
+            result = (result << 8) | (value & 0xff);
+            value >>= 8;
+        }
+        return bytes8(result);
+    }
+
+    // FIELD ARITHMETIC
+    // ============================================================================================
+
+    /// Computes base^exponent using the modular exponentiation precompile.
+    function _exp(uint256 base, uint256 exponent) internal view returns (uint256 result) {
+        uint256 modulus = MODULUS;
+        assembly {
+            let p := mload(0x40)
+            mstore(p, 0x20)
+            mstore(add(p, 0x20), 0x20)
+            mstore(add(p, 0x40), 0x20)
+            mstore(add(p, 0x60), base)
+            mstore(add(p, 0x80), exponent)
+            mstore(add(p, 0xa0), modulus)
+            if iszero(staticcall(gas(), 0x05, p, 0xc0, p, 0x20)) {
+                revert(0, 0)
+            }
+            result := mload(p)
+        }
+    }
+
+    function _inv(uint256 value) internal view returns (uint256) {
+        require(value != 0, "division by zero");
+        return _exp(value, MODULUS - 2);
+    }
+}
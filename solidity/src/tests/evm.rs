@@ -0,0 +1,245 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Tests which compile a generated verifier contract with `solc` and execute it in the revm
+//! implementation of the EVM.
+//!
+//! The compiler is not a Rust dependency, and thus, these tests are ignored by default. To run
+//! them, install `solc` 0.8 or later and run:
+//!
+//! ```text
+//! cargo test -p winter-solidity -- --ignored
+//! ```
+//!
+//! The compiler is looked up on `PATH`, unless the path to it is specified via the `SOLC`
+//! environment variable; the tests fail if the compiler cannot be found.
+
+use super::{build_test_case, BaseElement, PublicInputElements};
+use math::{FieldElement, StarkField};
+use revm::{
+    db::InMemoryDB,
+    primitives::{hex, Address, Bytes, ExecutionResult, Output, TxKind},
+    Evm,
+};
+use std::{
+    env,
+    io::Write,
+    process::{Command, Stdio},
+};
+
+const CONTRACT_NAME: &str = "TestVerifier";
+
+// TESTS
+// ================================================================================================
+
+#[test]
+#[ignore = "requires solc"]
+fn evm_verify_valid_proof() {
+    let (spec, _, proof, pub_inputs) = build_test_case();
+    let mut verifier = EvmVerifier::deploy(&spec.generate_contract(CONTRACT_NAME));
+
+    let calldata = spec.encode_calldata(&proof, &pub_inputs).unwrap();
+    let encoded = spec.encode_proof(&proof, &pub_inputs).unwrap();
+    assert_eq!(calldata, encode_call(&pub_inputs.to_elements(), &encoded));
+    assert_eq!(Ok(true), verifier.call(calldata));
+}
+
+#[test]
+#[ignore = "requires solc"]
+fn evm_verify_wrong_public_inputs() {
+    let (spec, _, proof, pub_inputs) = build_test_case();
+    let mut verifier = EvmVerifier::deploy(&spec.generate_contract(CONTRACT_NAME));
+    let encoded = spec.encode_proof(&proof, &pub_inputs).unwrap();
+
+    let mut elements = pub_inputs.to_elements();
+    elements[1] += BaseElement::ONE;
+    assert!(verifier.verify(&elements, &encoded).is_err());
+    assert_eq!(
+        Err("invalid number of public inputs".to_string()),
+        verifier.verify(&elements[..1], &encoded)
+    );
+
+    // the contract accepts public inputs as 256-bit words, and thus, they can be out of range
+    let mut calldata = encode_call(&pub_inputs.to_elements(), &encoded);
+    let modulus = BaseElement::MODULUS.to_be_bytes();
+    calldata[4 + 3 * 32 + 16..4 + 4 * 32].copy_from_slice(&modulus);
+    assert_eq!(
+        Err("invalid public input".to_string()),
+        verifier.call(calldata)
+    );
+}
+
+#[test]
+#[ignore = "requires solc"]
+fn evm_verify_tampered_proof() {
+    let (spec, _, proof, pub_inputs) = build_test_case();
+    let mut verifier = EvmVerifier::deploy(&spec.generate_contract(CONTRACT_NAME));
+    let encoded = spec.encode_proof(&proof, &pub_inputs).unwrap();
+    let elements = pub_inputs.to_elements();
+    let layout = spec.layout();
+
+    // the last byte of a word is the least significant byte of an element
+    let mut tamper = |offset: usize| {
+        let mut proof = encoded.clone();
+        proof[offset + 31] ^= 1;
+        verifier.verify(&elements, &proof)
+    };
+    for (offset, reason) in [
+        (
+            layout.ood_current,
+            "inconsistent out-of-domain constraint evaluations",
+        ),
+        (layout.remainder, "FRI remainder does not match commitment"),
+        (layout.queries, "trace query does not match commitment"),
+        (
+            layout.size - 32,
+            "FRI layer query does not match commitment",
+        ),
+    ] {
+        assert_eq!(Err(reason.to_string()), tamper(offset));
+    }
+    assert!(tamper(layout.trace_root).is_err());
+    assert!(tamper(layout.pow_nonce).is_err());
+
+    assert_eq!(
+        Err("invalid proof size".to_string()),
+        verifier.verify(&elements, &encoded[..encoded.len() - 32])
+    );
+}
+
+// EVM VERIFIER
+// ================================================================================================
+
+/// A verifier contract deployed into an in-memory EVM.
+struct EvmVerifier {
+    evm: Evm<'static, (), InMemoryDB>,
+    address: Address,
+}
+
+impl EvmVerifier {
+    /// Compiles the specified source code of a verifier contract and deploys the contract.
+    fn deploy(source: &str) -> Self {
+        let mut evm = Evm::builder().with_db(InMemoryDB::default()).build();
+        evm.tx_mut().transact_to = TxKind::Create;
+        evm.tx_mut().data = Bytes::from(compile(source));
+
+        let address = match evm
+            .transact_commit()
+            .expect("failed to execute transaction")
+        {
+            ExecutionResult::Success {
+                output: Output::Create(_, Some(address)),
+                ..
+            } => address,
+            result => panic!("failed to deploy contract: {:?}", result),
+        };
+        EvmVerifier { evm, address }
+    }
+
+    /// Calls the `verify` function of the contract with the specified public inputs and encoded
+    /// proof.
+    fn verify(&mut self, elements: &[BaseElement], proof: &[u8]) -> Result<bool, String> {
+        self.call(encode_call(elements, proof))
+    }
+
+    /// Calls the contract with the specified calldata, and returns the boolean returned by the
+    /// contract, or the reason of the revert.
+    fn call(&mut self, calldata: Vec<u8>) -> Result<bool, String> {
+        self.evm.tx_mut().transact_to = TxKind::Call(self.address);
+        self.evm.tx_mut().data = Bytes::from(calldata);
+
+        let result = self.evm.transact().expect("failed to execute transaction");
+        match result.result {
+            ExecutionResult::Success {
+                output: Output::Call(output),
+                ..
+            } => {
+                assert_eq!(32, output.len(), "unexpected return data");
+                Ok(output[31] == 1)
+            }
+            ExecutionResult::Revert { output, .. } => Err(decode_revert_reason(&output)),
+            result => panic!("contract call halted: {:?}", result),
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Compiles the specified source code with `solc`, and returns the creation bytecode of the
+/// contract.
+fn compile(source: &str) -> Vec<u8> {
+    let solc = env::var("SOLC").unwrap_or_else(|_| "solc".into());
+    let mut child = Command::new(&solc)
+        .args(["--optimize", "--bin", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap_or_else(|err| {
+            panic!(
+                "failed to run {}: {}; install solc or specify the path to it via SOLC",
+                solc, err
+            )
+        });
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(source.as_bytes())
+        .unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    assert!(
+        output.status.success(),
+        "failed to compile contract:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // the output consists of the name of the contract followed by "Binary:" and a line of hex
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let bytecode = stdout
+        .lines()
+        .skip_while(|line| *line != "Binary:")
+        .nth(1)
+        .expect("bytecode not found in solc output");
+    hex::decode(bytecode.trim()).expect("invalid bytecode")
+}
+
+/// Returns ABI-encoded calldata for `verify(uint256[],bytes)`; unlike the calldata built by
+/// [VerifierSpec::encode_calldata()](crate::VerifierSpec::encode_calldata), public inputs and
+/// the proof do not have to be valid.
+fn encode_call(elements: &[BaseElement], proof: &[u8]) -> Vec<u8> {
+    let mut result = vec![0xbd, 0x20, 0x5a, 0x90];
+    let word = |value: u128| {
+        let mut word = [0; 32];
+        word[16..].copy_from_slice(&value.to_be_bytes());
+        word
+    };
+
+    result.extend_from_slice(&word(64));
+    result.extend_from_slice(&word(64 + 32 * (elements.len() as u128 + 1)));
+    result.extend_from_slice(&word(elements.len() as u128));
+    for element in elements {
+        result.extend_from_slice(&word(element.as_int()));
+    }
+    result.extend_from_slice(&word(proof.len() as u128));
+    result.extend_from_slice(proof);
+    result.resize(result.len() + (32 - proof.len() % 32) % 32, 0);
+    result
+}
+
+/// Decodes the reason of a revert from `Error(string)` return data; panics are described by
+/// their codes (see `Panic(uint256)` in the Solidity documentation).
+fn decode_revert_reason(output: &[u8]) -> String {
+    match output[..4] {
+        [0x08, 0xc3, 0x79, 0xa0] => {
+            let len = u64::from_be_bytes(output[60..68].try_into().unwrap()) as usize;
+            String::from_utf8(output[68..68 + len].to_vec()).expect("invalid revert reason")
+        }
+        [0x4e, 0x48, 0x7b, 0x71] => format!("panic 0x{}", hex::encode(&output[4..36])),
+        _ => panic!("unexpected revert data: 0x{}", hex::encode(output)),
+    }
+}
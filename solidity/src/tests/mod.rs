@@ -0,0 +1,362 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{PublicInputElements, SolidityError, VerifierSpec};
+use air::{
+    builder::{AirBuilder, AirDefinition, AssertionBuilder, Column, DeclarativeAir, TraceBuilder},
+    proof::StarkProof,
+    FieldExtension, HashFunction, ProofOptions,
+};
+use math::{fields::f128::BaseElement, FieldElement};
+use prover::{Prover, Trace, TraceTable};
+use utils::{ByteWriter, Serializable};
+
+mod evm;
+
+mod model;
+use model::ContractModel;
+
+const TRACE_LENGTH: usize = 64;
+const PERIODIC_VALUES: [u128; 4] = [1, 2, 3, 4];
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn verify_valid_proof() {
+    let (spec, model, proof, pub_inputs) = build_test_case();
+    let encoded = spec.encode_proof(&proof, &pub_inputs).unwrap();
+    assert_eq!(spec.proof_size(), encoded.len());
+    assert_eq!(Ok(()), model.verify(&pub_inputs.to_elements(), &encoded));
+}
+
+#[test]
+fn verify_wrong_public_inputs() {
+    let (spec, model, proof, pub_inputs) = build_test_case();
+    let encoded = spec.encode_proof(&proof, &pub_inputs).unwrap();
+
+    let mut elements = pub_inputs.to_elements();
+    elements[1] += BaseElement::ONE;
+    assert!(model.verify(&elements, &encoded).is_err());
+    assert_eq!(
+        Err("invalid number of public inputs"),
+        model.verify(&elements[..1], &encoded)
+    );
+}
+
+#[test]
+fn verify_tampered_proof() {
+    let (spec, model, proof, pub_inputs) = build_test_case();
+    let encoded = spec.encode_proof(&proof, &pub_inputs).unwrap();
+    let elements = pub_inputs.to_elements();
+    let layout = spec.layout();
+
+    // the last byte of a word is the least significant byte of an element
+    let tamper = |offset: usize| {
+        let mut proof = encoded.clone();
+        proof[offset + 31] ^= 1;
+        model.verify(&elements, &proof)
+    };
+    assert_eq!(
+        Err("inconsistent out-of-domain constraint evaluations"),
+        tamper(layout.ood_current)
+    );
+    assert_eq!(
+        Err("FRI remainder does not match commitment"),
+        tamper(layout.remainder)
+    );
+    assert_eq!(
+        Err("trace query does not match commitment"),
+        tamper(layout.queries)
+    );
+    assert_eq!(
+        Err("FRI layer query does not match commitment"),
+        tamper(layout.size - 32)
+    );
+    assert!(tamper(layout.trace_root).is_err());
+    assert!(tamper(layout.pow_nonce).is_err());
+
+    assert_eq!(
+        Err("invalid proof size"),
+        model.verify(&elements, &encoded[..encoded.len() - 32])
+    );
+}
+
+#[test]
+fn encode_calldata() {
+    let (spec, _, proof, pub_inputs) = build_test_case();
+    let encoded = spec.encode_proof(&proof, &pub_inputs).unwrap();
+    let calldata = spec.encode_calldata(&proof, &pub_inputs).unwrap();
+
+    // selector of verify(uint256[],bytes)
+    assert_eq!([0xbd, 0x20, 0x5a, 0x90], calldata[..4]);
+
+    // head with offsets of public inputs and proof, followed by 2 public inputs
+    let word = |i: usize| &calldata[4 + i * 32..4 + (i + 1) * 32];
+    assert_eq!(64, word(0)[31]);
+    assert_eq!(160, word(1)[31]);
+    assert_eq!(2, word(2)[31]);
+    assert_eq!(
+        pub_inputs.start,
+        BaseElement::from(u128::from_be_bytes(word(3)[16..].try_into().unwrap()))
+    );
+    assert_eq!((encoded.len() as u64).to_be_bytes(), word(5)[24..]);
+    assert_eq!(encoded, calldata[4 + 6 * 32..]);
+}
+
+#[test]
+fn generate_contract() {
+    let (spec, ..) = build_test_case();
+    let source = spec.generate_contract("TestVerifier");
+    solang_parser::parse(&source, 0).expect("generated contract is not valid Solidity");
+
+    assert!(source.contains("contract TestVerifier {"));
+    assert!(source.contains("uint256 internal constant TRACE_LENGTH = 64;"));
+    assert!(source.contains("publicInputs[1]"));
+    assert!(!source.contains("{{"));
+}
+
+#[test]
+#[should_panic(expected = "contract name must be a valid identifier")]
+fn generate_contract_invalid_name() {
+    let spec = VerifierSpec::new::<TestDefinition>(build_options(), TRACE_LENGTH).unwrap();
+    spec.generate_contract("1Verifier");
+}
+
+#[test]
+fn spec_structure() {
+    let spec = VerifierSpec::new::<TestDefinition>(build_options(), TRACE_LENGTH).unwrap();
+    assert_eq!(3, spec.num_transition_constraints);
+    assert_eq!(4, spec.num_assertions);
+    assert_eq!(2, spec.num_public_inputs());
+    assert_eq!(1, spec.periodic_column_polys.len());
+
+    // assertions against the first step and against the last step
+    assert_eq!(2, spec.boundary_groups.len());
+    let values = spec
+        .boundary_groups
+        .iter()
+        .flat_map(|group| group.assertions.iter().map(|a| (a.column, a.value)))
+        .collect::<Vec<_>>();
+    use super::spec::AssertionValue::*;
+    assert!(values.contains(&(0, Constant(BaseElement::ONE))));
+    assert!(values.contains(&(1, PublicInput(0))));
+    assert!(values.contains(&(2, Constant(BaseElement::ZERO))));
+    assert!(values.contains(&(1, PublicInput(1))));
+}
+
+#[test]
+fn unsupported_options() {
+    let options = ProofOptions::new(
+        28,
+        8,
+        4,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    );
+    assert_eq!(
+        Err(SolidityError::UnsupportedHashFunction(
+            HashFunction::Blake3_256
+        )),
+        VerifierSpec::new::<TestDefinition>(options, TRACE_LENGTH).map(|_| ())
+    );
+
+    let options = ProofOptions::new(
+        28,
+        8,
+        4,
+        HashFunction::Keccak256,
+        FieldExtension::Quadratic,
+        4,
+        32,
+    );
+    assert_eq!(
+        Err(SolidityError::UnsupportedFieldExtension(
+            FieldExtension::Quadratic
+        )),
+        VerifierSpec::new::<TestDefinition>(options, TRACE_LENGTH).map(|_| ())
+    );
+}
+
+#[test]
+fn unsupported_assertion_value() {
+    assert_eq!(
+        Err(SolidityError::UnsupportedAssertionValue(0)),
+        VerifierSpec::new::<DerivedValueDefinition>(build_options(), TRACE_LENGTH).map(|_| ())
+    );
+}
+
+#[test]
+fn context_mismatch() {
+    let (_, _, proof, pub_inputs) = build_test_case();
+    let spec = VerifierSpec::new::<TestDefinition>(build_options(), 2 * TRACE_LENGTH).unwrap();
+    assert_eq!(
+        Err(SolidityError::ContextMismatch),
+        spec.encode_proof(&proof, &pub_inputs)
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        28,
+        8,
+        4,
+        HashFunction::Keccak256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
+
+fn build_test_case() -> (
+    VerifierSpec<BaseElement>,
+    ContractModel,
+    StarkProof,
+    TestInputs,
+) {
+    let spec = VerifierSpec::new::<TestDefinition>(build_options(), TRACE_LENGTH).unwrap();
+    let model = ContractModel::parse(&spec.generate_contract("TestVerifier"));
+
+    let prover = TestProver {
+        options: build_options(),
+    };
+    let trace = prover.build_trace(BaseElement::new(3));
+    let pub_inputs = prover.get_pub_inputs(&trace);
+    let proof = prover.prove(trace).unwrap();
+
+    (spec, model, proof, pub_inputs)
+}
+
+// TEST COMPUTATION
+// ================================================================================================
+
+/// Public inputs of the test computation: the initial value of column `b`, and its final value.
+struct TestInputs {
+    start: BaseElement,
+    result: BaseElement,
+}
+
+impl Serializable for TestInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.start);
+        target.write(self.result);
+    }
+}
+
+impl PublicInputElements<BaseElement> for TestInputs {
+    const NUM_ELEMENTS: usize = 2;
+
+    fn from_elements(elements: &[BaseElement]) -> Self {
+        TestInputs {
+            start: elements[0],
+            result: elements[1],
+        }
+    }
+
+    fn to_elements(&self) -> Vec<BaseElement> {
+        vec![self.start, self.result]
+    }
+}
+
+/// A Fibonacci-like sequence in columns `a` and `b`, and a cubic recurrence with a periodic
+/// round constant in column `c`.
+struct TestDefinition;
+
+impl AirDefinition for TestDefinition {
+    type BaseField = BaseElement;
+    type PublicInputs = TestInputs;
+    type Columns = [Column; 3];
+    const ID: &'static [u8] = b"winterfell/solidity/test";
+
+    fn define(air: &mut AirBuilder<BaseElement>) -> [Column; 3] {
+        let [a, b, c] = air.columns(["a", "b", "c"]);
+        let k = air.periodic_column("k", PERIODIC_VALUES.map(BaseElement::new).to_vec());
+        air.enforce("a", a.next() - (a.current() + b.current()));
+        air.enforce("b", b.next() - (b.current() + a.next()));
+        air.enforce("c", c.next() - (c.current().exp(3) + k));
+        [a, b, c]
+    }
+
+    fn get_assertions(
+        [a, b, c]: &[Column; 3],
+        inputs: &TestInputs,
+        assertions: &mut AssertionBuilder<BaseElement>,
+    ) {
+        assertions.first(*a, BaseElement::ONE);
+        assertions.first(*b, inputs.start);
+        assertions.first(*c, BaseElement::ZERO);
+        assertions.last(*b, inputs.result);
+    }
+}
+
+struct TestProver {
+    options: ProofOptions,
+}
+
+impl TestProver {
+    fn build_trace(&self, start: BaseElement) -> TraceTable<BaseElement> {
+        let builder = TraceBuilder::<TestDefinition>::new(TRACE_LENGTH);
+        let [a, b, c] = *builder.columns();
+        TraceTable::init(builder.fill(
+            |row| {
+                row[a] = BaseElement::ONE;
+                row[b] = start;
+            },
+            |step, row| {
+                let a_next = row[a] + row[b];
+                row[a] = a_next;
+                row[b] += a_next;
+                let k = BaseElement::new(PERIODIC_VALUES[step % PERIODIC_VALUES.len()]);
+                row[c] = row[c].exp(3) + k;
+            },
+        ))
+    }
+}
+
+impl Prover for TestProver {
+    type BaseField = BaseElement;
+    type Air = DeclarativeAir<TestDefinition>;
+    type Trace = TraceTable<BaseElement>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> TestInputs {
+        TestInputs {
+            start: trace.get(1, 0),
+            result: trace.get(1, trace.length() - 1),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+}
+
+/// A computation with an assertion value derived from public inputs rather than equal to one of
+/// their elements.
+struct DerivedValueDefinition;
+
+impl AirDefinition for DerivedValueDefinition {
+    type BaseField = BaseElement;
+    type PublicInputs = BaseElement;
+    type Columns = Column;
+
+    fn define(air: &mut AirBuilder<BaseElement>) -> Column {
+        let a = air.column("a");
+        air.enforce("a", a.next() - a.current().exp(2));
+        a
+    }
+
+    fn get_assertions(
+        a: &Column,
+        input: &BaseElement,
+        assertions: &mut AssertionBuilder<BaseElement>,
+    ) {
+        assertions.first(*a, input.double());
+    }
+}
@@ -0,0 +1,678 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! A model of generated verifier contracts used to test them without an EVM.
+//!
+//! Constants of the model are read from the source code of a generated contract, and the
+//! generated constraint evaluation code is interpreted statement by statement; the rest of the
+//! verification logic mirrors the contract template.
+
+use crypto::{hashers::Keccak256, Digest, Hasher};
+use math::{fields::f128::BaseElement, FieldElement, StarkField};
+use solang_parser::pt::{ContractPart, Expression, SourceUnitPart, Statement};
+use std::collections::BTreeMap;
+
+type Element = BaseElement;
+
+// CONTRACT MODEL
+// ================================================================================================
+
+pub struct ContractModel {
+    constants: BTreeMap<String, u128>,
+    bytes: BTreeMap<String, Vec<u8>>,
+    evaluation: Statement,
+}
+
+impl ContractModel {
+    /// Parses the source code of a generated contract.
+    pub fn parse(source: &str) -> Self {
+        let (unit, _) = solang_parser::parse(source, 0).expect("failed to parse contract");
+        let contract = unit
+            .0
+            .iter()
+            .find_map(|part| match part {
+                SourceUnitPart::ContractDefinition(contract) => Some(contract),
+                _ => None,
+            })
+            .expect("contract definition not found");
+
+        let mut constants = BTreeMap::new();
+        let mut bytes = BTreeMap::new();
+        let mut evaluation = None;
+        for part in contract.parts.iter() {
+            match part {
+                ContractPart::VariableDefinition(variable) => {
+                    let name = variable.name.as_ref().unwrap().name.clone();
+                    match variable.initializer.as_ref().unwrap() {
+                        Expression::HexNumberLiteral(_, value, _) => {
+                            let value = u128::from_str_radix(&value[2..], 16).unwrap();
+                            constants.insert(name, value);
+                        }
+                        Expression::NumberLiteral(_, value, _, _) => {
+                            constants.insert(name, value.parse().unwrap());
+                        }
+                        Expression::HexLiteral(literals) => {
+                            let digits = literals
+                                .iter()
+                                .flat_map(|l| l.hex.trim_start_matches("hex").chars())
+                                .filter(|c| c.is_ascii_hexdigit())
+                                .collect::<Vec<_>>();
+                            let value = digits
+                                .chunks(2)
+                                .map(|d| u8::from_str_radix(&d.iter().collect::<String>(), 16))
+                                .collect::<Result<Vec<_>, _>>()
+                                .unwrap();
+                            bytes.insert(name, value);
+                        }
+                        other => panic!("unexpected initializer of {}: {:?}", name, other),
+                    }
+                }
+                ContractPart::FunctionDefinition(function) => {
+                    let name = function.name.as_ref().map(|n| n.name.as_str());
+                    if name == Some("_evaluateConstraints") {
+                        evaluation = function.body.clone();
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        ContractModel {
+            constants,
+            bytes,
+            evaluation: evaluation.expect("constraint evaluation function not found"),
+        }
+    }
+
+    /// Verifies an encoded proof in the same way as the contract does; returns the message of
+    /// the failed check if the proof is not valid.
+    pub fn verify(&self, public_inputs: &[Element], proof: &[u8]) -> Result<(), &'static str> {
+        if public_inputs.len() != self.int("NUM_PUBLIC_INPUTS") {
+            return Err("invalid number of public inputs");
+        }
+        if proof.len() != self.int("PROOF_SIZE") {
+            return Err("invalid proof size");
+        }
+
+        let t = self.read_transcript(public_inputs, proof)?;
+        let remainder_root = read_digest(
+            proof,
+            self.int("FRI_ROOTS_OFFSET") + self.int("NUM_FRI_LAYERS") * 32,
+        );
+        self.verify_remainder(&t.remainder, remainder_root)?;
+        for q in 0..self.int("NUM_QUERIES") {
+            self.verify_query(&t, proof, q)?;
+        }
+        Ok(())
+    }
+
+    // TRANSCRIPT
+    // --------------------------------------------------------------------------------------------
+
+    fn read_transcript(
+        &self,
+        public_inputs: &[Element],
+        proof: &[u8],
+    ) -> Result<Transcript, &'static str> {
+        let mut seed = self.bytes["SEED_PREFIX"].clone();
+        seed.extend_from_slice(&self.serialize_elements(public_inputs));
+        seed.extend_from_slice(&self.bytes["CONTEXT"]);
+        let mut coin = Coin {
+            seed: keccak(&seed),
+            counter: 0,
+        };
+
+        coin.reseed(read_digest(proof, self.int("TRACE_ROOT_OFFSET")));
+        let num_coefficients =
+            2 * (self.int("NUM_TRANSITION_CONSTRAINTS") + self.int("NUM_ASSERTIONS"));
+        let coefficients = (0..num_coefficients)
+            .map(|_| self.draw(&mut coin))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        coin.reseed(read_digest(proof, self.int("CONSTRAINT_ROOT_OFFSET")));
+        let z = self.draw(&mut coin)?;
+        let width = self.int("TRACE_WIDTH");
+        let num_columns = self.int("NUM_CONSTRAINT_COLUMNS");
+        let ood_current = read_elements(proof, self.int("OOD_CURRENT_OFFSET"), width)?;
+        let ood_next = read_elements(proof, self.int("OOD_NEXT_OFFSET"), width)?;
+        let ood_constraints =
+            read_elements(proof, self.int("OOD_CONSTRAINTS_OFFSET"), num_columns)?;
+
+        let evaluation = Interpreter::new(self, z, &ood_current, &ood_next, &coefficients)
+            .run(&self.evaluation, public_inputs)?;
+        let combined = ood_constraints
+            .iter()
+            .rev()
+            .fold(Element::ZERO, |result, &value| result * z + value);
+        if evaluation != combined {
+            return Err("inconsistent out-of-domain constraint evaluations");
+        }
+        coin.reseed(keccak(&self.serialize_elements(&ood_current)));
+        coin.reseed(keccak(&self.serialize_elements(&ood_next)));
+        coin.reseed(keccak(&self.serialize_elements(&ood_constraints)));
+
+        let mut trace_coefficients = Vec::with_capacity(2 * width);
+        for _ in 0..width {
+            trace_coefficients.push(self.draw(&mut coin)?);
+            trace_coefficients.push(self.draw(&mut coin)?);
+            self.draw(&mut coin)?;
+        }
+        let constraint_coefficients = (0..num_columns)
+            .map(|_| self.draw(&mut coin))
+            .collect::<Result<Vec<_>, _>>()?;
+        let degree_coefficients = (self.draw(&mut coin)?, self.draw(&mut coin)?);
+
+        let mut alphas = Vec::new();
+        for i in 0..=self.int("NUM_FRI_LAYERS") {
+            coin.reseed(read_digest(proof, self.int("FRI_ROOTS_OFFSET") + i * 32));
+            alphas.push(self.draw(&mut coin)?);
+        }
+
+        let nonce = read_word(proof, self.int("POW_NONCE_OFFSET"));
+        if nonce[..24].iter().any(|&b| b != 0) {
+            return Err("invalid proof-of-work nonce");
+        }
+        let nonce = u64::from_be_bytes(nonce[24..].try_into().unwrap());
+        coin.reseed_with_int(nonce);
+        if read_le(&coin.seed, 8) & self.constants["GRINDING_MASK"] != 0 {
+            return Err("proof-of-work verification failed");
+        }
+        let positions = self.draw_positions(&mut coin)?;
+
+        let remainder = read_elements(
+            proof,
+            self.int("REMAINDER_OFFSET"),
+            self.int("REMAINDER_SIZE"),
+        )?;
+
+        Ok(Transcript {
+            z,
+            ood_current,
+            ood_next,
+            ood_constraints,
+            trace_coefficients,
+            constraint_coefficients,
+            degree_coefficients,
+            alphas,
+            positions,
+            remainder,
+        })
+    }
+
+    // QUERIES
+    // --------------------------------------------------------------------------------------------
+
+    fn verify_query(&self, t: &Transcript, proof: &[u8], q: usize) -> Result<(), &'static str> {
+        let width = self.int("TRACE_WIDTH");
+        let num_columns = self.int("NUM_CONSTRAINT_COLUMNS");
+        let depth = self.int("LDE_DEPTH");
+        let position = t.positions[q];
+        let mut offset = self.int("QUERIES_OFFSET") + q * self.int("QUERY_SIZE");
+
+        let trace_row = read_elements(proof, offset, width)?;
+        offset += width * 32;
+        let leaf = keccak(&self.serialize_elements(&trace_row));
+        if compute_root(proof, offset, depth, leaf, position)
+            != read_digest(proof, self.int("TRACE_ROOT_OFFSET"))
+        {
+            return Err("trace query does not match commitment");
+        }
+        offset += depth * 32;
+
+        let constraint_row = read_elements(proof, offset, num_columns)?;
+        offset += num_columns * 32;
+        let leaf = keccak(&self.serialize_elements(&constraint_row));
+        if compute_root(proof, offset, depth, leaf, position)
+            != read_digest(proof, self.int("CONSTRAINT_ROOT_OFFSET"))
+        {
+            return Err("constraint query does not match commitment");
+        }
+
+        let x = self.element("DOMAIN_OFFSET") * self.element("LDE_GENERATOR").exp(position as u128);
+        let evaluation = self.compose_deep(t, &trace_row, &constraint_row, x);
+        self.verify_fri_query(t, proof, q, position, evaluation)
+    }
+
+    fn compose_deep(
+        &self,
+        t: &Transcript,
+        trace_row: &[Element],
+        constraint_row: &[Element],
+        x: Element,
+    ) -> Element {
+        let zg = t.z * self.element("TRACE_GENERATOR");
+        let mut result = Element::ZERO;
+        for (i, &value) in trace_row.iter().enumerate() {
+            result += (value - t.ood_current[i]) / (x - t.z) * t.trace_coefficients[2 * i];
+            result += (value - t.ood_next[i]) / (x - zg) * t.trace_coefficients[2 * i + 1];
+        }
+
+        let zm = t.z.exp(constraint_row.len() as u128);
+        for (i, &value) in constraint_row.iter().enumerate() {
+            result += (value - t.ood_constraints[i]) / (x - zm) * t.constraint_coefficients[i];
+        }
+
+        result * (t.degree_coefficients.0 + x * t.degree_coefficients.1)
+    }
+
+    // FRI
+    // --------------------------------------------------------------------------------------------
+
+    fn verify_fri_query(
+        &self,
+        t: &Transcript,
+        proof: &[u8],
+        q: usize,
+        mut position: usize,
+        mut evaluation: Element,
+    ) -> Result<(), &'static str> {
+        let folding_factor = self.int("FOLDING_FACTOR");
+        let mut domain_generator = self.element("LDE_GENERATOR");
+        let mut layer_offset = self.int("FRI_QUERIES_OFFSET");
+        for layer in 0..self.int("NUM_FRI_LAYERS") {
+            let depth = self.int("LDE_DEPTH") - (layer + 1) * self.int("LOG_FOLDING_FACTOR");
+            let offset = layer_offset + q * (folding_factor + depth) * 32;
+
+            let values = read_elements(proof, offset, folding_factor)?;
+            if values[position >> depth] != evaluation {
+                return Err("invalid FRI layer folding");
+            }
+            position &= (1 << depth) - 1;
+            let leaf = keccak(&self.serialize_elements(&values));
+            if compute_root(proof, offset + folding_factor * 32, depth, leaf, position)
+                != read_digest(proof, self.int("FRI_ROOTS_OFFSET") + layer * 32)
+            {
+                return Err("FRI layer query does not match commitment");
+            }
+
+            let xe = domain_generator.exp(position as u128) * self.element("DOMAIN_OFFSET");
+            evaluation = self.fold_coset(&values, xe, t.alphas[layer]);
+            domain_generator = domain_generator.exp(folding_factor as u128);
+            layer_offset += self.int("NUM_QUERIES") * (folding_factor + depth) * 32;
+        }
+
+        if t.remainder[position] != evaluation {
+            return Err("invalid FRI remainder folding");
+        }
+        Ok(())
+    }
+
+    /// Interpolates the values over the coset xe * w^j, and evaluates the result at alpha.
+    fn fold_coset(&self, values: &[Element], xe: Element, alpha: Element) -> Element {
+        let root = self.element("FOLDING_ROOT");
+        let xs = (0..values.len())
+            .map(|j| xe * root.exp(j as u128))
+            .collect::<Vec<_>>();
+
+        let mut result = Element::ZERO;
+        for (j, &value) in values.iter().enumerate() {
+            let mut basis = Element::ONE;
+            for (k, &x) in xs.iter().enumerate() {
+                if k != j {
+                    basis *= (alpha - x) / (xs[j] - x);
+                }
+            }
+            result += value * basis;
+        }
+        result
+    }
+
+    fn verify_remainder(&self, remainder: &[Element], root: [u8; 32]) -> Result<(), &'static str> {
+        let folding_factor = self.int("FOLDING_FACTOR");
+        let num_leaves = remainder.len() / folding_factor;
+        let mut nodes = vec![[0u8; 32]; 2 * num_leaves];
+        for i in 0..num_leaves {
+            let values = (0..folding_factor)
+                .map(|j| remainder[i + j * num_leaves])
+                .collect::<Vec<_>>();
+            nodes[num_leaves + i] = keccak(&self.serialize_elements(&values));
+        }
+        for i in (1..num_leaves).rev() {
+            nodes[i] = merge(nodes[2 * i], nodes[2 * i + 1]);
+        }
+        if nodes[1] != root {
+            return Err("FRI remainder does not match commitment");
+        }
+
+        for k in self.int("REMAINDER_MAX_DEGREE") + 1..remainder.len() {
+            let w = self.element("REMAINDER_ROOT_INV").exp(k as u128);
+            let coefficient = remainder
+                .iter()
+                .enumerate()
+                .fold(Element::ZERO, |acc, (j, &r)| acc + r * w.exp(j as u128));
+            if coefficient != Element::ZERO {
+                return Err("FRI remainder degree is too high");
+            }
+        }
+        Ok(())
+    }
+
+    // PUBLIC COIN
+    // --------------------------------------------------------------------------------------------
+
+    fn draw(&self, coin: &mut Coin) -> Result<Element, &'static str> {
+        for _ in 0..1000 {
+            let value = read_le(&coin.next(), self.int("ELEMENT_BYTES"));
+            if value < self.constants["MODULUS"] {
+                return Ok(Element::new(value));
+            }
+        }
+        Err("failed to draw a field element")
+    }
+
+    fn draw_positions(&self, coin: &mut Coin) -> Result<Vec<usize>, &'static str> {
+        let num_queries = self.int("NUM_QUERIES");
+        let mask = self.constants["LDE_DOMAIN_SIZE"] - 1;
+        let mut positions = Vec::new();
+        for _ in 0..1000 {
+            if positions.len() == num_queries {
+                break;
+            }
+            let value = (read_le(&coin.next(), 8) & mask) as usize;
+            if !positions.contains(&value) {
+                positions.push(value);
+            }
+        }
+        if positions.len() != num_queries {
+            return Err("failed to draw query positions");
+        }
+        Ok(positions)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn int(&self, name: &str) -> usize {
+        self.constants[name] as usize
+    }
+
+    fn element(&self, name: &str) -> Element {
+        Element::new(self.constants[name])
+    }
+
+    fn serialize_elements(&self, elements: &[Element]) -> Vec<u8> {
+        let element_bytes = self.int("ELEMENT_BYTES");
+        elements
+            .iter()
+            .flat_map(|e| e.as_int().to_le_bytes()[..element_bytes].to_vec())
+            .collect()
+    }
+}
+
+// TRANSCRIPT
+// ================================================================================================
+
+struct Transcript {
+    z: Element,
+    ood_current: Vec<Element>,
+    ood_next: Vec<Element>,
+    ood_constraints: Vec<Element>,
+    trace_coefficients: Vec<Element>,
+    constraint_coefficients: Vec<Element>,
+    degree_coefficients: (Element, Element),
+    alphas: Vec<Element>,
+    positions: Vec<usize>,
+    remainder: Vec<Element>,
+}
+
+// PUBLIC COIN
+// ================================================================================================
+
+struct Coin {
+    seed: [u8; 32],
+    counter: u64,
+}
+
+impl Coin {
+    fn reseed(&mut self, data: [u8; 32]) {
+        self.seed = merge(self.seed, data);
+        self.counter = 0;
+    }
+
+    fn reseed_with_int(&mut self, value: u64) {
+        let mut data = self.seed.to_vec();
+        data.extend_from_slice(&value.to_le_bytes());
+        self.seed = keccak(&data);
+        self.counter = 0;
+    }
+
+    fn next(&mut self) -> [u8; 32] {
+        self.counter += 1;
+        let mut data = self.seed.to_vec();
+        data.extend_from_slice(&self.counter.to_le_bytes());
+        keccak(&data)
+    }
+}
+
+// CONSTRAINT EVALUATION INTERPRETER
+// ================================================================================================
+
+/// Interprets generated constraint evaluation code; all arithmetic is performed in the field, and
+/// thus, MODULUS evaluates to zero.
+struct Interpreter<'a> {
+    model: &'a ContractModel,
+    z: Element,
+    ood_current: &'a [Element],
+    ood_next: &'a [Element],
+    coefficients: &'a [Element],
+    scalars: BTreeMap<String, Element>,
+    arrays: BTreeMap<String, Vec<Element>>,
+}
+
+impl<'a> Interpreter<'a> {
+    fn new(
+        model: &'a ContractModel,
+        z: Element,
+        ood_current: &'a [Element],
+        ood_next: &'a [Element],
+        coefficients: &'a [Element],
+    ) -> Self {
+        Interpreter {
+            model,
+            z,
+            ood_current,
+            ood_next,
+            coefficients,
+            scalars: BTreeMap::new(),
+            arrays: BTreeMap::new(),
+        }
+    }
+
+    fn run(mut self, body: &Statement, public_inputs: &[Element]) -> Result<Element, &'static str> {
+        self.scalars.insert("result".into(), Element::ZERO);
+        self.arrays.insert("c".into(), self.coefficients.to_vec());
+        self.arrays
+            .insert("publicInputs".into(), public_inputs.to_vec());
+        self.execute(body)?;
+        Ok(self.scalars["result"])
+    }
+
+    fn execute(&mut self, statement: &Statement) -> Result<(), &'static str> {
+        match statement {
+            Statement::Block { statements, .. } => {
+                for statement in statements {
+                    self.execute(statement)?;
+                }
+            }
+            Statement::VariableDefinition(_, declaration, initializer) => {
+                let name = declaration.name.as_ref().unwrap().name.clone();
+                match initializer {
+                    Some(Expression::New(_, array)) => match &**array {
+                        Expression::FunctionCall(_, _, args) => {
+                            let length = self.eval_int(&args[0]);
+                            self.arrays.insert(name, vec![Element::ZERO; length]);
+                        }
+                        other => panic!("unsupported allocation: {:?}", other),
+                    },
+                    Some(expression) => {
+                        let value = self.eval(expression)?;
+                        self.scalars.insert(name, value);
+                    }
+                    None => {
+                        self.scalars.insert(name, Element::ZERO);
+                    }
+                }
+            }
+            Statement::Expression(_, Expression::Assign(_, target, value)) => {
+                let value = self.eval(value)?;
+                match &**target {
+                    Expression::Variable(name) => {
+                        self.scalars.insert(name.name.clone(), value);
+                    }
+                    Expression::ArraySubscript(_, array, Some(index)) => {
+                        let index = self.eval_int(index);
+                        match &**array {
+                            Expression::Variable(name) => {
+                                self.arrays.get_mut(&name.name).unwrap()[index] = value
+                            }
+                            other => panic!("unsupported assignment target: {:?}", other),
+                        }
+                    }
+                    other => panic!("unsupported assignment target: {:?}", other),
+                }
+            }
+            other => panic!("unsupported statement: {:?}", other),
+        }
+        Ok(())
+    }
+
+    fn eval(&self, expression: &Expression) -> Result<Element, &'static str> {
+        Ok(match expression {
+            Expression::HexNumberLiteral(_, value, _) => {
+                Element::new(u128::from_str_radix(&value[2..], 16).unwrap())
+            }
+            Expression::NumberLiteral(..) => Element::new(self.eval_int(expression) as u128),
+            Expression::Variable(name) => match name.name.as_str() {
+                "MODULUS" => Element::ZERO,
+                name => match self.scalars.get(name) {
+                    Some(&value) => value,
+                    None => self.model.element(name),
+                },
+            },
+            Expression::MemberAccess(_, object, member) => {
+                match (&**object, member.name.as_str()) {
+                    (Expression::Variable(t), "z") if t.name == "t" => self.z,
+                    other => panic!("unsupported member access: {:?}", other),
+                }
+            }
+            Expression::ArraySubscript(_, array, Some(index)) => {
+                let index = self.eval_int(index);
+                match &**array {
+                    Expression::Variable(name) => self.arrays[&name.name][index],
+                    Expression::MemberAccess(_, object, member) => {
+                        match (&**object, member.name.as_str()) {
+                            (Expression::Variable(t), "oodCurrent") if t.name == "t" => {
+                                self.ood_current[index]
+                            }
+                            (Expression::Variable(t), "oodNext") if t.name == "t" => {
+                                self.ood_next[index]
+                            }
+                            other => panic!("unsupported array: {:?}", other),
+                        }
+                    }
+                    other => panic!("unsupported array: {:?}", other),
+                }
+            }
+            Expression::Parenthesis(_, value) => self.eval(value)?,
+            Expression::Subtract(_, lhs, rhs) => self.eval(lhs)? - self.eval(rhs)?,
+            Expression::Modulo(_, lhs, rhs) => {
+                assert_eq!(
+                    Element::ZERO,
+                    self.eval(rhs)?,
+                    "only MODULUS can be a divisor"
+                );
+                self.eval(lhs)?
+            }
+            Expression::FunctionCall(_, function, args) => match &**function {
+                Expression::Variable(name) => match name.name.as_str() {
+                    "addmod" => self.eval(&args[0])? + self.eval(&args[1])?,
+                    "mulmod" => self.eval(&args[0])? * self.eval(&args[1])?,
+                    "_exp" => self.eval(&args[0])?.exp(self.eval_int(&args[1]) as u128),
+                    "_inv" => {
+                        let value = self.eval(&args[0])?;
+                        if value == Element::ZERO {
+                            return Err("division by zero");
+                        }
+                        value.inv()
+                    }
+                    other => panic!("unsupported function: {}", other),
+                },
+                other => panic!("unsupported function: {:?}", other),
+            },
+            other => panic!("unsupported expression: {:?}", other),
+        })
+    }
+
+    fn eval_int(&self, expression: &Expression) -> usize {
+        match expression {
+            Expression::NumberLiteral(_, value, exponent, _) => {
+                assert!(exponent.is_empty(), "exponents are not supported");
+                value.parse().unwrap()
+            }
+            other => panic!("unsupported integer expression: {:?}", other),
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn keccak(bytes: &[u8]) -> [u8; 32] {
+    Keccak256::<Element>::hash(bytes).as_bytes()
+}
+
+fn merge(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    let mut data = a.to_vec();
+    data.extend_from_slice(&b);
+    keccak(&data)
+}
+
+fn read_le(bytes: &[u8], num_bytes: usize) -> u128 {
+    bytes[..num_bytes]
+        .iter()
+        .rev()
+        .fold(0, |acc, &b| (acc << 8) | b as u128)
+}
+
+fn read_word(proof: &[u8], offset: usize) -> [u8; 32] {
+    proof[offset..offset + 32].try_into().unwrap()
+}
+
+fn read_digest(proof: &[u8], offset: usize) -> [u8; 32] {
+    read_word(proof, offset)
+}
+
+fn read_elements(proof: &[u8], offset: usize, count: usize) -> Result<Vec<Element>, &'static str> {
+    (0..count)
+        .map(|i| {
+            let word = read_word(proof, offset + i * 32);
+            let value = u128::from_be_bytes(word[16..].try_into().unwrap());
+            if word[..16].iter().any(|&b| b != 0) || value >= Element::MODULUS {
+                return Err("invalid field element");
+            }
+            Ok(Element::new(value))
+        })
+        .collect()
+}
+
+fn compute_root(
+    proof: &[u8],
+    offset: usize,
+    depth: usize,
+    leaf: [u8; 32],
+    mut index: usize,
+) -> [u8; 32] {
+    let mut node = leaf;
+    for i in 0..depth {
+        let sibling = read_digest(proof, offset + i * 32);
+        node = if index & 1 == 0 {
+            merge(node, sibling)
+        } else {
+            merge(sibling, node)
+        };
+        index >>= 1;
+    }
+    node
+}
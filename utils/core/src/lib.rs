@@ -25,6 +25,9 @@ pub mod iterators;
 mod errors;
 pub use errors::DeserializationError;
 
+#[cfg(feature = "serde")]
+pub mod serde_hex;
+
 #[cfg(test)]
 mod tests;
 
@@ -160,6 +163,12 @@ pub trait Deserializable: Sized {
     }
 }
 
+impl Deserializable for () {
+    fn read_from<R: ByteReader>(_source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(())
+    }
+}
+
 // BYTE READER
 // ================================================================================================
 
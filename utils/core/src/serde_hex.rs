@@ -0,0 +1,126 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Serde helpers for byte sequences.
+//!
+//! When used with a human-readable format (e.g. JSON), bytes are encoded as a lowercase hex
+//! string; otherwise (e.g. CBOR), bytes are encoded as a native byte string. These functions are
+//! intended to be used via `#[serde(with = "utils::serde_hex")]` attribute on `Vec<u8>` fields.
+
+use crate::{collections::Vec, string::String};
+use core::fmt;
+use serde::{
+    de::{Error, SeqAccess, Visitor},
+    Deserializer, Serializer,
+};
+
+// SERDE FUNCTIONS
+// ================================================================================================
+
+/// Serializes the specified bytes as a hex string for human-readable formats, and as a byte
+/// string otherwise.
+pub fn serialize<S, T>(bytes: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: AsRef<[u8]> + ?Sized,
+{
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&encode(bytes.as_ref()))
+    } else {
+        serializer.serialize_bytes(bytes.as_ref())
+    }
+}
+
+/// Deserializes a vector of bytes from a hex string for human-readable formats, and from a byte
+/// string otherwise.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(BytesVisitor)
+    } else {
+        deserializer.deserialize_byte_buf(BytesVisitor)
+    }
+}
+
+// HEX ENCODING
+// ================================================================================================
+
+/// Encodes the specified bytes as a lowercase hex string.
+pub fn encode(bytes: &[u8]) -> String {
+    const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+    let mut result = String::with_capacity(bytes.len() * 2);
+    for &byte in bytes {
+        result.push(HEX_CHARS[(byte >> 4) as usize] as char);
+        result.push(HEX_CHARS[(byte & 0xf) as usize] as char);
+    }
+    result
+}
+
+/// Decodes a hex string into a vector of bytes.
+///
+/// Both lowercase and uppercase hex digits are accepted; the string may optionally start with
+/// `0x` prefix.
+///
+/// # Errors
+/// Returns an error if the string has an odd number of digits or contains non-hex characters.
+pub fn decode(value: &str) -> Result<Vec<u8>, String> {
+    let value = value.strip_prefix("0x").unwrap_or(value);
+    if value.len() % 2 != 0 {
+        return Err(format!(
+            "hex string must have an even number of digits, but had {}",
+            value.len()
+        ));
+    }
+
+    value
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| Ok((decode_digit(pair[0])? << 4) | decode_digit(pair[1])?))
+        .collect()
+}
+
+fn decode_digit(digit: u8) -> Result<u8, String> {
+    match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        _ => Err(format!("invalid hex digit '{}'", digit as char)),
+    }
+}
+
+// BYTES VISITOR
+// ================================================================================================
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a hex string or a byte string")
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+        decode(value).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+        Ok(value.to_vec())
+    }
+
+    fn visit_byte_buf<E: Error>(self, value: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(value)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut result = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            result.push(byte);
+        }
+        Ok(result)
+    }
+}
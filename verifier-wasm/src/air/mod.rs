@@ -0,0 +1,16 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! AIRs of the example computations which can be verified via this crate.
+//!
+//! These mirror AIRs of the corresponding computations in the `examples` crate exactly (including
+//! their domain separation tags), and thus, proofs generated by the `winterfell` binary can be
+//! verified against them.
+
+pub mod fibonacci;
+pub use fibonacci::FibAir;
+
+pub mod vdf;
+pub use vdf::{VdfAir, VdfInputs};
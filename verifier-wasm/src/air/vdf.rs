@@ -0,0 +1,94 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use utils::collections::Vec;
+use verifier::{
+    math::{fields::f128::BaseElement, FieldElement},
+    Air, AirContext, Assertion, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    EvaluationFrame, ProofOptions, Serializable, TraceInfo, TransitionConstraintDegree,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+pub const TRACE_WIDTH: usize = 1;
+const ALPHA: u64 = 3;
+const FORTY_TWO: BaseElement = BaseElement::new(42);
+
+// PUBLIC INPUTS
+// ================================================================================================
+
+/// Public inputs of the `vdf` example: the seed of the VDF and its output.
+#[derive(Clone)]
+pub struct VdfInputs {
+    pub seed: BaseElement,
+    pub result: BaseElement,
+}
+
+impl Serializable for VdfInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.seed);
+        target.write(self.result);
+    }
+}
+
+impl Deserializable for VdfInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let seed = BaseElement::read_from(source)?;
+        let result = BaseElement::read_from(source)?;
+        Ok(VdfInputs { seed, result })
+    }
+}
+
+// VDF AIR
+// ================================================================================================
+
+/// AIR of the `vdf` example: a verifiable delay function computed by iterating the inverse of
+/// the map x -> x^3 + 42.
+pub struct VdfAir {
+    context: AirContext<BaseElement>,
+    seed: BaseElement,
+    result: BaseElement,
+}
+
+impl Air for VdfAir {
+    type BaseField = BaseElement;
+    type PublicInputs = VdfInputs;
+    const ID: &'static [u8] = b"winterfell/examples/vdf";
+
+    fn new(trace_info: TraceInfo, pub_inputs: VdfInputs, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(3)];
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        Self {
+            context: AirContext::new(trace_info, degrees, 2, options),
+            seed: pub_inputs.seed,
+            result: pub_inputs.result,
+        }
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current_state = frame.current()[0];
+        let next_state = frame.next()[0];
+
+        result[0] = current_state - (next_state.exp(ALPHA.into()) + FORTY_TWO.into());
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let last_step = self.trace_length() - 1;
+        vec![
+            Assertion::single(0, 0, self.seed),
+            Assertion::single(0, last_step, self.result),
+        ]
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+}
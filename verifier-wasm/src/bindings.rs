@@ -0,0 +1,65 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! JavaScript bindings generated via wasm-bindgen.
+
+use crate::{ExampleAir, WasmVerifierError};
+use wasm_bindgen::prelude::*;
+
+// VERIFIER
+// ================================================================================================
+
+/// Verifier of proofs for one of the registered example AIRs.
+///
+/// From JavaScript, the verifier is used as follows:
+///
+/// ```text
+/// const verifier = new Verifier("fib");
+/// verifier.verify(proofBytes, pubInputBytes); // throws if the proof is not valid
+/// ```
+#[wasm_bindgen]
+pub struct Verifier {
+    air: ExampleAir,
+}
+
+#[wasm_bindgen]
+impl Verifier {
+    /// Returns a verifier for the AIR with the specified name.
+    ///
+    /// # Errors
+    /// Returns an error if the name does not refer to any of the registered AIRs.
+    #[wasm_bindgen(constructor)]
+    pub fn new(air: &str) -> Result<Verifier, JsError> {
+        match ExampleAir::from_name(air) {
+            Some(air) => Ok(Verifier { air }),
+            None => Err(to_js_error(WasmVerifierError::UnknownAir(air.into()))),
+        }
+    }
+
+    /// Returns the name of the AIR for which this verifier verifies proofs.
+    #[wasm_bindgen(getter)]
+    pub fn air(&self) -> String {
+        self.air.name().into()
+    }
+
+    /// Verifies that the specified proof attests to a correct execution of the computation for
+    /// the specified public inputs.
+    ///
+    /// # Errors
+    /// Returns an error if the proof or the public inputs could not be deserialized, or if the
+    /// proof is not valid.
+    pub fn verify(&self, proof_bytes: &[u8], pub_input_bytes: &[u8]) -> Result<(), JsError> {
+        self.air
+            .verify(proof_bytes, pub_input_bytes)
+            .map_err(to_js_error)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn to_js_error(err: WasmVerifierError) -> JsError {
+    JsError::new(&err.to_string())
+}
@@ -0,0 +1,74 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::fmt;
+use utils::string::String;
+use verifier::{DeserializationError, ProofDeserializationError, VerifierError};
+
+// WASM VERIFIER ERROR
+// ================================================================================================
+/// Represents an error returned when a proof for one of the registered example AIRs could not be
+/// verified.
+#[derive(Debug, PartialEq, Eq)]
+pub enum WasmVerifierError {
+    /// This error occurs when the name or the identifier of an AIR does not refer to any of the
+    /// registered AIRs.
+    UnknownAir(String),
+    /// This error occurs when the proof bytes could not be deserialized into a STARK proof.
+    InvalidProof(ProofDeserializationError),
+    /// This error occurs when the proof was generated for an execution trace with a number of
+    /// columns different from the one expected by the AIR. The parameters are the expected and
+    /// the actual number of columns.
+    TraceWidthMismatch(usize, usize),
+    /// This error occurs when the public input bytes could not be deserialized into public inputs
+    /// of the AIR, or when not all of the bytes were consumed.
+    InvalidPublicInputs(DeserializationError),
+    /// This error occurs when the proof does not attest to a correct execution of the computation
+    /// for the specified public inputs.
+    VerificationFailed(VerifierError),
+}
+
+impl WasmVerifierError {
+    /// Returns a numeric code of this error, as returned from the `winter_verify` function
+    /// exported from WebAssembly modules built from this crate.
+    ///
+    /// The codes are: 1 for [UnknownAir](Self::UnknownAir), 2 for
+    /// [InvalidProof](Self::InvalidProof), 3 for [InvalidPublicInputs](Self::InvalidPublicInputs),
+    /// 4 for [VerificationFailed](Self::VerificationFailed), and 5 for
+    /// [TraceWidthMismatch](Self::TraceWidthMismatch); 0 is reserved for successful
+    /// verification.
+    pub fn code(&self) -> i32 {
+        match self {
+            Self::UnknownAir(_) => 1,
+            Self::InvalidProof(_) => 2,
+            Self::InvalidPublicInputs(_) => 3,
+            Self::VerificationFailed(_) => 4,
+            Self::TraceWidthMismatch(..) => 5,
+        }
+    }
+}
+
+impl fmt::Display for WasmVerifierError {
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownAir(air) => {
+                write!(f, "AIR '{}' is not registered", air)
+            }
+            Self::InvalidProof(err) => {
+                write!(f, "failed to read proof: {}", err)
+            }
+            Self::TraceWidthMismatch(expected, actual) => {
+                write!(f, "expected execution trace with {} columns, but proof was generated for {} columns", expected, actual)
+            }
+            Self::InvalidPublicInputs(err) => {
+                write!(f, "failed to read public inputs: {}", err)
+            }
+            Self::VerificationFailed(err) => {
+                write!(f, "failed to verify proof: {}", err)
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Functions exported from WebAssembly modules built from this crate.
+//!
+//! A host verifies a proof by allocating buffers for the proof and public inputs via
+//! `winter_alloc`, copying the bytes into the linear memory of the module, calling
+//! `winter_verify`, and releasing the buffers via `winter_dealloc`.
+
+use crate::{ExampleAir, WasmVerifierError};
+use core::{
+    alloc::Layout,
+    ptr::{self, NonNull},
+    slice,
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::alloc::{alloc, dealloc};
+#[cfg(feature = "std")]
+use std::alloc::{alloc, dealloc};
+
+// EXPORTED FUNCTIONS
+// ================================================================================================
+
+/// Allocates a buffer of `len` bytes in the linear memory of the module and returns a pointer to
+/// it; null is returned if the allocation failed.
+#[no_mangle]
+pub extern "C" fn winter_alloc(len: usize) -> *mut u8 {
+    if len == 0 {
+        return NonNull::dangling().as_ptr();
+    }
+    match Layout::array::<u8>(len) {
+        // SAFETY: the layout has a non-zero size
+        Ok(layout) => unsafe { alloc(layout) },
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Releases a buffer previously allocated via `winter_alloc`.
+///
+/// # Safety
+/// `ptr` must have been returned by `winter_alloc` for the same `len`, and must not have been
+/// released before.
+#[no_mangle]
+pub unsafe extern "C" fn winter_dealloc(ptr: *mut u8, len: usize) {
+    if len != 0 {
+        dealloc(ptr, Layout::from_size_align_unchecked(len, 1));
+    }
+}
+
+/// Verifies a proof for the AIR with the specified identifier (see [ExampleAir::id()]).
+///
+/// Returns 0 if the proof is valid, and [WasmVerifierError::code()] otherwise.
+///
+/// # Safety
+/// `proof_ptr` and `inputs_ptr` must point to initialized buffers of `proof_len` and
+/// `inputs_len` bytes respectively (e.g., allocated via `winter_alloc`).
+#[no_mangle]
+pub unsafe extern "C" fn winter_verify(
+    air: u32,
+    proof_ptr: *const u8,
+    proof_len: usize,
+    inputs_ptr: *const u8,
+    inputs_len: usize,
+) -> i32 {
+    let air = match ExampleAir::from_id(air) {
+        Some(air) => air,
+        None => return WasmVerifierError::UnknownAir(format!("{}", air)).code(),
+    };
+    let proof_bytes = to_slice(proof_ptr, proof_len);
+    let pub_input_bytes = to_slice(inputs_ptr, inputs_len);
+
+    match air.verify(proof_bytes, pub_input_bytes) {
+        Ok(()) => 0,
+        Err(err) => err.code(),
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a slice of `len` bytes starting at `ptr`; pointers of empty slices are ignored.
+unsafe fn to_slice<'a>(ptr: *const u8, len: usize) -> &'a [u8] {
+    if len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(ptr, len)
+    }
+}
@@ -0,0 +1,200 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! This crate contains a WebAssembly-friendly facade of the Winterfell STARK verifier.
+//!
+//! The generic [verify()](verifier::verify) function of the `winter-verifier` crate requires
+//! the caller to know the type of the AIR at compile time. This crate instead exposes a
+//! registry of example AIRs ([ExampleAir]) and a [verify()] function which accepts a proof and
+//! public inputs as raw bytes. This makes it possible to verify proofs generated by the
+//! `winterfell` examples binary (e.g., `winterfell prove fib --proof-file ...`) in environments
+//! where only bytes can be passed around, such as browsers.
+//!
+//! # WebAssembly interface
+//! When compiled for the `wasm32` target, the crate exports the following functions with a
+//! C ABI:
+//! * `winter_alloc(len) -> ptr` and `winter_dealloc(ptr, len)`, which can be used by the host to
+//!   allocate buffers in the linear memory of the module.
+//! * `winter_verify(air, proof_ptr, proof_len, inputs_ptr, inputs_len) -> code`, which verifies
+//!   a proof for the AIR with the specified [ExampleAir::id()] and returns 0 on success, or
+//!   [WasmVerifierError::code()] otherwise.
+//!
+//! When built with `--no-default-features`, the module is `no_std` and does not import anything
+//! from the host, and thus, it can be instantiated by any WebAssembly runtime. The smallest
+//! modules are produced using the `release-wasm` profile of the workspace:
+//!
+//! ```text
+//! cargo build -p winter-verifier-wasm --target wasm32-unknown-unknown --no-default-features --profile release-wasm
+//! ```
+//!
+//! # Crate features
+//! * `std` - enabled by default; relies on the Rust standard library.
+//! * `bindgen` - exposes a [wasm-bindgen](https://rustwasm.github.io/wasm-bindgen/) `Verifier`
+//!   class for use from JavaScript; implies `std`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+#[macro_use]
+extern crate alloc;
+
+use verifier::{Air, ByteReader, Deserializable, SliceReader};
+pub use verifier::{ProofDeserializationError, StarkProof, VerifierError};
+
+pub mod air;
+use air::{fibonacci, vdf, FibAir, VdfAir};
+
+mod errors;
+pub use errors::WasmVerifierError;
+
+#[cfg(feature = "bindgen")]
+mod bindings;
+#[cfg(feature = "bindgen")]
+pub use bindings::Verifier;
+
+#[cfg(target_arch = "wasm32")]
+mod ffi;
+
+#[cfg(all(target_arch = "wasm32", not(feature = "std")))]
+mod runtime;
+
+#[cfg(test)]
+mod tests;
+
+// VERIFIER
+// ================================================================================================
+
+/// Verifies that the specified proof attests to a correct execution of the example computation
+/// with the specified name for the specified public inputs.
+///
+/// Names of the computations match the names of the examples in the `winterfell` binary (see
+/// [ExampleAir::from_name()]), and both the proof and the public inputs are expected to be
+/// serialized the same way as they are written to disk by the `winterfell prove` command.
+///
+/// # Errors
+/// Returns an error if:
+/// * The name does not refer to any of the registered AIRs.
+/// * The proof or the public inputs could not be deserialized.
+/// * The proof was generated for an execution trace with a different number of columns.
+/// * The proof does not attest to a correct execution of the computation.
+pub fn verify(
+    air: &str,
+    proof_bytes: &[u8],
+    pub_input_bytes: &[u8],
+) -> Result<(), WasmVerifierError> {
+    ExampleAir::from_name(air)
+        .ok_or_else(|| WasmVerifierError::UnknownAir(air.into()))?
+        .verify(proof_bytes, pub_input_bytes)
+}
+
+// EXAMPLE AIR REGISTRY
+// ================================================================================================
+
+/// Example computations for which proofs can be verified via this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExampleAir {
+    /// Computation of a Fibonacci sequence with 2 terms per step (the `fib` example). Public
+    /// inputs consist of a single f128 field element: the last term of the sequence.
+    Fibonacci,
+    /// A verifiable delay function (the `vdf` example). Public inputs consist of two f128 field
+    /// elements: the seed and the result of the function.
+    Vdf,
+}
+
+impl ExampleAir {
+    /// All registered AIRs.
+    pub const ALL: [ExampleAir; 2] = [ExampleAir::Fibonacci, ExampleAir::Vdf];
+
+    /// Returns the AIR with the specified name, or None if no such AIR has been registered.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|air| air.name() == name)
+    }
+
+    /// Returns the AIR with the specified numeric identifier, or None if no such AIR has been
+    /// registered.
+    pub fn from_id(id: u32) -> Option<Self> {
+        Self::ALL.into_iter().find(|air| air.id() == id)
+    }
+
+    /// Returns the name of this AIR; this is the same as the name of the corresponding example in
+    /// the `winterfell` binary.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Fibonacci => "fib",
+            Self::Vdf => "vdf",
+        }
+    }
+
+    /// Returns the number of columns in execution traces of this computation.
+    pub fn trace_width(&self) -> usize {
+        match self {
+            Self::Fibonacci => fibonacci::TRACE_WIDTH,
+            Self::Vdf => vdf::TRACE_WIDTH,
+        }
+    }
+
+    /// Returns a numeric identifier of this AIR; identifiers are stable across releases and are
+    /// used to refer to AIRs across the WebAssembly boundary.
+    pub fn id(&self) -> u32 {
+        match self {
+            Self::Fibonacci => 0,
+            Self::Vdf => 1,
+        }
+    }
+
+    /// Verifies that the specified proof attests to a correct execution of this computation for
+    /// the specified public inputs.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The proof or the public inputs could not be deserialized.
+    /// * The proof was generated for an execution trace with a different number of columns.
+    /// * The proof does not attest to a correct execution of the computation.
+    pub fn verify(
+        &self,
+        proof_bytes: &[u8],
+        pub_input_bytes: &[u8],
+    ) -> Result<(), WasmVerifierError> {
+        let proof = StarkProof::from_bytes(proof_bytes).map_err(WasmVerifierError::InvalidProof)?;
+
+        // AIRs cannot be instantiated for traces of unexpected width, and thus, this is checked
+        // here rather than left to the verifier
+        let trace_width = proof.get_trace_info().width();
+        if trace_width != self.trace_width() {
+            return Err(WasmVerifierError::TraceWidthMismatch(
+                self.trace_width(),
+                trace_width,
+            ));
+        }
+
+        match self {
+            Self::Fibonacci => verify_proof::<FibAir>(proof, pub_input_bytes),
+            Self::Vdf => verify_proof::<VdfAir>(proof, pub_input_bytes),
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Deserializes public inputs for the specified AIR and verifies the proof against them.
+fn verify_proof<AIR: Air>(
+    proof: StarkProof,
+    pub_input_bytes: &[u8],
+) -> Result<(), WasmVerifierError>
+where
+    AIR::PublicInputs: Deserializable,
+{
+    let mut source = SliceReader::new(pub_input_bytes);
+    let pub_inputs = AIR::PublicInputs::read_from(&mut source)
+        .map_err(WasmVerifierError::InvalidPublicInputs)?;
+    if source.has_more_bytes() {
+        return Err(WasmVerifierError::InvalidPublicInputs(
+            verifier::DeserializationError::UnconsumedBytes,
+        ));
+    }
+
+    verifier::verify::<AIR>(proof, pub_inputs).map_err(WasmVerifierError::VerificationFailed)
+}
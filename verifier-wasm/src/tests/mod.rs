@@ -0,0 +1,186 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{
+    air::{fibonacci, vdf, FibAir, VdfAir, VdfInputs},
+    verify, ExampleAir, WasmVerifierError,
+};
+use examples::{
+    fibonacci::fib2::{self, FibExample},
+    vdf::regular::{self, VdfExample},
+    Example,
+};
+use rand_utils::{rand_value, rand_vector};
+use verifier::{
+    math::{fields::f128::BaseElement, FieldElement},
+    Air, DeserializationError, EvaluationFrame, FieldExtension, HashFunction,
+    ProofDeserializationError, ProofOptions, TraceInfo,
+};
+
+mod wasm;
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn verify_fibonacci() {
+    let (proof, pub_inputs) = build_proof(ExampleAir::Fibonacci);
+    assert_eq!(Ok(()), verify("fib", &proof, &pub_inputs));
+}
+
+#[test]
+fn verify_vdf() {
+    let (proof, pub_inputs) = build_proof(ExampleAir::Vdf);
+    assert_eq!(Ok(()), verify("vdf", &proof, &pub_inputs));
+}
+
+#[test]
+fn verify_wrong_air() {
+    let (proof, pub_inputs) = build_proof(ExampleAir::Vdf);
+    let err = ExampleAir::Fibonacci
+        .verify(&proof, &pub_inputs)
+        .unwrap_err();
+    assert_eq!(WasmVerifierError::TraceWidthMismatch(2, 1), err);
+    assert_eq!(5, err.code());
+}
+
+#[test]
+fn verify_wrong_public_inputs() {
+    let (proof, mut pub_inputs) = build_proof(ExampleAir::Fibonacci);
+    pub_inputs[0] ^= 1;
+    assert!(matches!(
+        verify("fib", &proof, &pub_inputs),
+        Err(WasmVerifierError::VerificationFailed(_))
+    ));
+
+    let err = verify("fib", &proof, &pub_inputs[..8]).unwrap_err();
+    assert!(matches!(err, WasmVerifierError::InvalidPublicInputs(_)));
+    assert_eq!(3, err.code());
+
+    pub_inputs.push(0);
+    assert_eq!(
+        Err(WasmVerifierError::InvalidPublicInputs(
+            DeserializationError::UnconsumedBytes
+        )),
+        verify("fib", &proof, &pub_inputs)
+    );
+}
+
+#[test]
+fn verify_malformed_proof() {
+    let (proof, pub_inputs) = build_proof(ExampleAir::Fibonacci);
+    let err = verify("fib", &proof[..proof.len() - 1], &pub_inputs).unwrap_err();
+    assert!(matches!(err, WasmVerifierError::InvalidProof(_)));
+    assert_eq!(2, err.code());
+
+    let mut proof = proof;
+    proof.push(0);
+    assert_eq!(
+        Err(WasmVerifierError::InvalidProof(
+            ProofDeserializationError::UnconsumedBytes
+        )),
+        verify("fib", &proof, &pub_inputs)
+    );
+}
+
+#[test]
+fn unknown_air() {
+    let err = verify("fib3", &[], &[]).unwrap_err();
+    assert_eq!(WasmVerifierError::UnknownAir("fib3".into()), err);
+    assert_eq!(1, err.code());
+}
+
+#[test]
+fn registry() {
+    for air in ExampleAir::ALL {
+        assert_eq!(Some(air), ExampleAir::from_name(air.name()));
+        assert_eq!(Some(air), ExampleAir::from_id(air.id()));
+    }
+    assert_eq!(None, ExampleAir::from_id(ExampleAir::ALL.len() as u32));
+}
+
+#[test]
+fn fibonacci_air_matches_example() {
+    for trace_length in [8, 64, 1024] {
+        let trace_info = TraceInfo::new(fibonacci::TRACE_WIDTH, trace_length);
+        let result = rand_value::<BaseElement>();
+        let air = FibAir::new(trace_info.clone(), result, build_options());
+        let expected = fib2::FibAir::new(trace_info, result, build_options());
+        assert_same_air(&air, &expected);
+    }
+}
+
+#[test]
+fn vdf_air_matches_example() {
+    for trace_length in [8, 64, 1024] {
+        let trace_info = TraceInfo::new(vdf::TRACE_WIDTH, trace_length);
+        let (seed, result) = (rand_value::<BaseElement>(), rand_value::<BaseElement>());
+        let air = VdfAir::new(
+            trace_info.clone(),
+            VdfInputs { seed, result },
+            build_options(),
+        );
+        let expected = regular::VdfAir::new(
+            trace_info,
+            regular::VdfInputs { seed, result },
+            build_options(),
+        );
+        assert_same_air(&air, &expected);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Checks that an AIR of this crate is identical to the AIR of the corresponding example: the
+/// AIRs must have the same identifiers, contexts, periodic columns, and assertions, and must
+/// evaluate transition constraints identically over random frames.
+fn assert_same_air<A, E>(air: &A, expected: &E)
+where
+    A: Air<BaseField = BaseElement>,
+    E: Air<BaseField = BaseElement>,
+{
+    assert_eq!(E::ID, A::ID);
+    assert!(expected.context() == air.context(), "AIR contexts differ");
+    assert_eq!(
+        expected.get_periodic_column_values(),
+        air.get_periodic_column_values()
+    );
+    assert_eq!(expected.get_assertions(), air.get_assertions());
+
+    let width = air.trace_info().width();
+    let num_constraints = air.context().num_main_transition_constraints();
+    for _ in 0..16 {
+        let frame = EvaluationFrame::from_rows(rand_vector(width), rand_vector(width));
+        let mut result = vec![BaseElement::ZERO; num_constraints];
+        air.evaluate_transition(&frame, &[], &mut result);
+        let mut expected_result = vec![BaseElement::ZERO; num_constraints];
+        expected.evaluate_transition(&frame, &[], &mut expected_result);
+        assert_eq!(expected_result, result);
+    }
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(
+        32,
+        8,
+        0,
+        HashFunction::Blake3_256,
+        FieldExtension::None,
+        4,
+        32,
+    )
+}
+
+/// Generates a proof for the specified example computation using the `examples` crate, and
+/// returns the proof and the public inputs serialized as by the `winterfell prove` command.
+fn build_proof(air: ExampleAir) -> (Vec<u8>, Vec<u8>) {
+    let options = build_options();
+    let example: Box<dyn Example> = match air {
+        ExampleAir::Fibonacci => Box::new(FibExample::new(64, options)),
+        ExampleAir::Vdf => Box::new(VdfExample::new(64, options)),
+    };
+    (example.prove().to_bytes(), example.public_inputs())
+}
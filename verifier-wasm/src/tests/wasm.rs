@@ -0,0 +1,221 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Tests which build a `no_std` WebAssembly module from this crate and verify proofs with it in
+//! the wasmtime runtime.
+//!
+//! The module is built using the `release-wasm` profile, which requires the
+//! `wasm32-unknown-unknown` target. Since the target is not installed by default, these tests
+//! are skipped (with a message printed to stderr) if the target is not installed; to run them,
+//! install the target:
+//!
+//! ```text
+//! rustup target add wasm32-unknown-unknown
+//! ```
+
+use super::{build_proof, ExampleAir};
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::OnceLock,
+};
+use wasmtime::{Engine, Instance, Memory, Module, Store, TypedFunc};
+
+const WASM_TARGET: &str = "wasm32-unknown-unknown";
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn wasm_verify_valid_proofs() {
+    let mut verifier = match WasmVerifier::new() {
+        Some(verifier) => verifier,
+        None => return,
+    };
+    for air in ExampleAir::ALL {
+        let (proof, pub_inputs) = build_proof(air);
+        assert_eq!(0, verifier.verify(air.id(), &proof, &pub_inputs));
+    }
+}
+
+#[test]
+fn wasm_verify_invalid_proofs() {
+    let mut verifier = match WasmVerifier::new() {
+        Some(verifier) => verifier,
+        None => return,
+    };
+    let (proof, mut pub_inputs) = build_proof(ExampleAir::Vdf);
+    let vdf = ExampleAir::Vdf.id();
+
+    assert_eq!(
+        1,
+        verifier.verify(ExampleAir::ALL.len() as u32, &proof, &pub_inputs)
+    );
+    assert_eq!(
+        2,
+        verifier.verify(vdf, &proof[..proof.len() / 2], &pub_inputs)
+    );
+    assert_eq!(3, verifier.verify(vdf, &proof, &pub_inputs[..20]));
+
+    assert_eq!(
+        5,
+        verifier.verify(ExampleAir::Fibonacci.id(), &proof, &pub_inputs)
+    );
+
+    pub_inputs[0] ^= 1;
+    assert_eq!(4, verifier.verify(vdf, &proof, &pub_inputs));
+}
+
+#[test]
+fn wasm_module_is_self_contained() {
+    let verifier = match WasmVerifier::new() {
+        Some(verifier) => verifier,
+        None => return,
+    };
+    assert_eq!(0, verifier.module.imports().len());
+}
+
+// WASM VERIFIER
+// ================================================================================================
+
+/// An instance of the WebAssembly module built from this crate.
+struct WasmVerifier {
+    module: Module,
+    store: Store<()>,
+    memory: Memory,
+    alloc: TypedFunc<u32, u32>,
+    dealloc: TypedFunc<(u32, u32), ()>,
+    verify: TypedFunc<(u32, u32, u32, u32, u32), i32>,
+}
+
+impl WasmVerifier {
+    /// Builds the module (once per test run) and instantiates it; returns `None` if the module
+    /// cannot be built because the wasm32 target is not installed.
+    fn new() -> Option<Self> {
+        static MODULE_PATH: OnceLock<Option<PathBuf>> = OnceLock::new();
+        let path = MODULE_PATH.get_or_init(build_module).as_ref()?;
+
+        let engine = Engine::default();
+        let module = Module::from_file(&engine, path).expect("failed to load module");
+        let mut store = Store::new(&engine, ());
+        let instance =
+            Instance::new(&mut store, &module, &[]).expect("failed to instantiate module");
+
+        let memory = instance.get_memory(&mut store, "memory").unwrap();
+        let alloc = instance.get_typed_func(&mut store, "winter_alloc").unwrap();
+        let dealloc = instance
+            .get_typed_func(&mut store, "winter_dealloc")
+            .unwrap();
+        let verify = instance
+            .get_typed_func(&mut store, "winter_verify")
+            .unwrap();
+
+        Some(WasmVerifier {
+            module,
+            store,
+            memory,
+            alloc,
+            dealloc,
+            verify,
+        })
+    }
+
+    /// Copies the proof and public inputs into the memory of the module and calls
+    /// `winter_verify`.
+    fn verify(&mut self, air: u32, proof: &[u8], pub_inputs: &[u8]) -> i32 {
+        let proof_ptr = self.write(proof);
+        let inputs_ptr = self.write(pub_inputs);
+        let args = (
+            air,
+            proof_ptr,
+            proof.len() as u32,
+            inputs_ptr,
+            pub_inputs.len() as u32,
+        );
+        let result = self.verify.call(&mut self.store, args).unwrap();
+
+        self.dealloc
+            .call(&mut self.store, (proof_ptr, proof.len() as u32))
+            .unwrap();
+        self.dealloc
+            .call(&mut self.store, (inputs_ptr, pub_inputs.len() as u32))
+            .unwrap();
+        result
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> u32 {
+        let ptr = self
+            .alloc
+            .call(&mut self.store, bytes.len() as u32)
+            .unwrap();
+        assert_ne!(0, ptr, "allocation failed");
+        self.memory
+            .write(&mut self.store, ptr as usize, bytes)
+            .unwrap();
+        ptr
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Builds the module via cargo into a separate target directory, and returns the path to it;
+/// returns `None` if the wasm32 target is not installed.
+///
+/// # Panics
+/// Panics if the module could not be built.
+fn build_module() -> Option<PathBuf> {
+    if !is_target_installed() {
+        eprintln!(
+            "skipping WebAssembly tests: {} target is not installed; install it via \
+            `rustup target add {}`",
+            WASM_TARGET, WASM_TARGET
+        );
+        return None;
+    }
+
+    // tests are executed from target/<profile>/deps
+    let target_dir = env::current_exe()
+        .unwrap()
+        .ancestors()
+        .nth(3)
+        .unwrap()
+        .join("verifier-wasm");
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".into());
+    let status = Command::new(cargo)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args([
+            "build",
+            "--no-default-features",
+            "--profile",
+            "release-wasm",
+        ])
+        .args(["--target", WASM_TARGET])
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .expect("failed to run cargo");
+    assert!(status.success(), "failed to build WebAssembly module");
+
+    let path = target_dir
+        .join(WASM_TARGET)
+        .join("release-wasm")
+        .join("winter_verifier_wasm.wasm");
+    Some(path)
+}
+
+fn is_target_installed() -> bool {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".into());
+    let output = Command::new(rustc)
+        .args(["--print", "sysroot"])
+        .output()
+        .expect("failed to run rustc");
+    let sysroot = String::from_utf8(output.stdout).unwrap();
+    Path::new(sysroot.trim())
+        .join("lib/rustlib")
+        .join(WASM_TARGET)
+        .exists()
+}
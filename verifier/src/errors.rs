@@ -5,20 +5,30 @@
 
 //! Contains common error types for prover and verifier.
 
+use air::{proof::Context, HashFunction};
 use core::fmt;
 use utils::string::String;
 
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+
 // VERIFIER ERROR
 // ================================================================================================
 /// Represents an error returned by the verifier during an execution of the protocol.
 #[derive(Debug, PartialEq, Eq)]
 pub enum VerifierError {
+    /// This error occurs when a proof was generated using a version of the STARK protocol which
+    /// is different from the version implemented by the verifier.
+    UnsupportedProtocolVersion(u8),
     /// This error occurs when base field read by a verifier from a proof does not match the
     /// base field of AIR with which the verifier was instantiated.
     InconsistentBaseField,
     /// This error occurs when the base field in which the proof was generated does not support
     /// field extension of degree specified by the proof.
     UnsupportedFieldExtension(usize),
+    /// This error occurs when the base field in which the proof was generated cannot be used
+    /// with the hash function specified by the proof.
+    UnsupportedHashFunction(HashFunction),
     /// This error occurs when a verifier cannot deserialize the specified proof.
     ProofDeserializationError(String),
     /// This error occurs when a verifier fails to draw a random value from a random coin
@@ -41,18 +51,40 @@ pub enum VerifierError {
     /// constraint evaluation queries do not represent a polynomial of the degree expected by the
     /// verifier.
     FriVerificationFailed(fri::VerifierError),
+    /// This error occurs when a continuation proof does not contain any segments.
+    EmptyContinuation,
+    /// This error occurs when public inputs of the specified segment of a continuation could not
+    /// be deserialized.
+    InvalidSegmentPublicInputs(usize, String),
+    /// This error occurs when the initial state of the first segment of a continuation does not
+    /// match the initial state expected by the verifier.
+    InitialStateMismatch,
+    /// This error occurs when the final state of the last segment of a continuation does not
+    /// match the final state expected by the verifier.
+    FinalStateMismatch,
+    /// This error occurs when the initial state of the specified segment of a continuation does
+    /// not match the final state of the previous segment.
+    DisconnectedSegments(usize),
+    /// This error occurs when the proof of the specified segment of a continuation is not valid.
+    SegmentVerificationFailed(usize, Box<VerifierError>),
 }
 
 impl fmt::Display for VerifierError {
     #[rustfmt::skip]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::UnsupportedProtocolVersion(version) => {
+                write!(f, "proof was generated using protocol version {} but the verifier supports only version {}", version, Context::PROTOCOL_VERSION)
+            }
             Self::InconsistentBaseField =>  {
                 write!(f, "base field of the proof does not match base field of the specified AIR")
             }
             Self::UnsupportedFieldExtension(degree) => {
                 write!(f, "field extension of degree {} is not supported for the proof base field", degree)
             }
+            Self::UnsupportedHashFunction(hash_fn) => {
+                write!(f, "hash function {:?} is not supported for the proof base field", hash_fn)
+            }
             Self::ProofDeserializationError(msg) => {
                 write!(f, "proof deserialization failed: {}", msg)
             }
@@ -74,6 +106,24 @@ impl fmt::Display for VerifierError {
             Self::FriVerificationFailed(err) => {
                 write!(f, "verification of low-degree proof failed: {}", err)
             }
+            Self::EmptyContinuation => {
+                write!(f, "continuation proof does not contain any segments")
+            }
+            Self::InvalidSegmentPublicInputs(segment, msg) => {
+                write!(f, "failed to read public inputs of segment {}: {}", segment, msg)
+            }
+            Self::InitialStateMismatch => {
+                write!(f, "initial state of the continuation does not match the expected state")
+            }
+            Self::FinalStateMismatch => {
+                write!(f, "final state of the continuation does not match the expected state")
+            }
+            Self::DisconnectedSegments(segment) => {
+                write!(f, "initial state of segment {} does not match the final state of segment {}", segment, segment - 1)
+            }
+            Self::SegmentVerificationFailed(segment, err) => {
+                write!(f, "verification of segment {} failed: {}", segment, err)
+            }
         }
     }
 }
@@ -35,6 +35,17 @@ pub fn evaluate_constraints<A: Air, E: FieldElement<BaseField = A::BaseField>>(
         })
         .collect::<Vec<_>>();
 
+    // compute values of auxiliary periodic columns at x
+    let aux_periodic_values = air
+        .get_aux_periodic_column_polys(&aux_rand_elements)
+        .iter()
+        .map(|poly| {
+            let num_cycles = air.trace_length() / poly.len();
+            let x = x.exp((num_cycles as u32).into());
+            polynom::eval(poly, x)
+        })
+        .collect::<Vec<_>>();
+
     // evaluate transition constraints for the main trace segment
     let mut t_evaluations1 = E::zeroed_vector(t_constraints.num_main_constraints());
     air.evaluate_transition(main_trace_frame, &periodic_values, &mut t_evaluations1);
@@ -42,18 +53,19 @@ pub fn evaluate_constraints<A: Air, E: FieldElement<BaseField = A::BaseField>>(
     // evaluate transition constraints for auxiliary trace segments (if any)
     let mut t_evaluations2 = E::zeroed_vector(t_constraints.num_aux_constraints());
     if let Some(aux_trace_frame) = aux_trace_frame {
-        air.evaluate_aux_transition(
+        air.evaluate_aux_transition_with_periodic_values(
             main_trace_frame,
             aux_trace_frame,
             &periodic_values,
+            &aux_periodic_values,
             &aux_rand_elements,
             &mut t_evaluations2,
         );
     }
 
     // merge all constraint evaluations into a single value by computing their random linear
-    // combination using coefficients drawn from the public coin. this also divides the result
-    // by the divisor of transition constraints.
+    // combination using coefficients drawn from the public coin. this also divides the
+    // combination of constraints sharing the same divisor by that divisor.
     let mut result = t_constraints.combine_evaluations::<E>(&t_evaluations1, &t_evaluations2, x);
 
     // 2 ----- evaluate boundary constraints ------------------------------------------------------
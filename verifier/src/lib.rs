@@ -32,11 +32,17 @@
 #[macro_use]
 extern crate alloc;
 
+use air::write_air_id;
 pub use air::{
-    proof::StarkProof, Air, AirContext, Assertion, AuxTraceRandElements, BoundaryConstraint,
-    BoundaryConstraintGroup, ConstraintCompositionCoefficients, ConstraintDivisor,
-    DeepCompositionCoefficients, EvaluationFrame, FieldExtension, HashFunction, ProofOptions,
-    TraceInfo, TransitionConstraintDegree, TransitionConstraintGroup,
+    proof::{
+        Context, ContinuationProof, DecodedFriLayer, DecodedProof, DecodedQueries, ProofSection,
+        SegmentProof, StarkProof,
+    },
+    Air, AirContext, Assertion, AuxTraceRandElements, BoundaryConstraint, BoundaryConstraintGroup,
+    ConstraintCompositionCoefficients, ConstraintDivisor, DeepCompositionCoefficients,
+    EvaluationFrame, FieldExtension, HashFunction, ProofDeserializationError, ProofOptions,
+    SegmentPublicInputs, TraceInfo, TracePadding, TransitionConstraintDegree,
+    TransitionConstraintGroup, TransitionDivisor,
 };
 
 pub use math;
@@ -45,14 +51,14 @@ use math::{
     FieldElement,
 };
 
-use utils::collections::Vec;
+use utils::{collections::Vec, string::ToString};
 pub use utils::{
     ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
 };
 
 pub use crypto;
 use crypto::{
-    hashers::{Blake3_192, Blake3_256, Sha3_256},
+    hashers::{Blake3_192, Blake3_256, Keccak256, Poseidon64_256, Sha3_256},
     ElementHasher, RandomCoin,
 };
 
@@ -83,23 +89,79 @@ pub use errors::VerifierError;
 /// a correct execution of the computation. This could happen for many various reasons, including:
 /// - The specified proof was generated for a different computation.
 /// - The specified proof was generated for this computation but for different public inputs.
-#[rustfmt::skip]
+/// - The specified proof is a legacy proof; such proofs can be verified only via
+///   [verify_legacy()].
 pub fn verify<AIR: Air>(
     proof: StarkProof,
     pub_inputs: AIR::PublicInputs,
 ) -> Result<(), VerifierError> {
-    // build a seed for the public coin; the initial seed is the hash of public inputs and proof
-    // context, but as the protocol progresses, the coin will be reseeded with the info received
-    // from the prover
+    // make sure the proof was generated using the same version of the protocol as the one
+    // implemented by this verifier
+    let version = proof.context.version();
+    if version != Context::PROTOCOL_VERSION {
+        return Err(VerifierError::UnsupportedProtocolVersion(version));
+    }
+
+    // build a seed for the public coin; the initial seed is the hash of AIR domain separation tag,
+    // public inputs, and proof context, but as the protocol progresses, the coin will be reseeded
+    // with the info received from the prover
     let mut public_coin_seed = Vec::new();
+    write_air_id::<AIR, _>(&mut public_coin_seed);
     pub_inputs.write_into(&mut public_coin_seed);
     proof.context.write_into(&mut public_coin_seed);
 
+    verify_with_seed::<AIR>(proof, pub_inputs, public_coin_seed)
+}
+
+/// Verifies a legacy proof of the specified computation against the specified inputs.
+///
+/// Legacy proofs are proofs generated before protocol versions were recorded in proofs. This
+/// function is meant for verifying archived proofs, and accepts only such proofs.
+///
+/// # Security
+/// The transcript of legacy proofs is not bound to the AIR domain separation tag (see
+/// [Air::ID](air::Air::ID)), and thus, a legacy proof generated for one computation could be
+/// accepted for another computation with identical constraints. [verify()] rejects legacy proofs
+/// so that they cannot be used to bypass domain separation; this function should be called only
+/// when the caller expects a proof to be a legacy proof.
+///
+/// # Errors
+/// Returns an error if the specified proof is not a legacy proof, or if combination of the
+/// provided proof and public inputs does not attest to a correct execution of the computation.
+pub fn verify_legacy<AIR: Air>(
+    proof: StarkProof,
+    pub_inputs: AIR::PublicInputs,
+) -> Result<(), VerifierError> {
+    let version = proof.context.version();
+    if version != Context::LEGACY_PROTOCOL_VERSION {
+        return Err(VerifierError::UnsupportedProtocolVersion(version));
+    }
+
+    // for legacy proofs, the seed did not include AIR domain separation tag, and the context was
+    // serialized without the protocol version
+    let mut public_coin_seed = Vec::new();
+    pub_inputs.write_into(&mut public_coin_seed);
+    proof.context.write_legacy_into(&mut public_coin_seed);
+
+    verify_with_seed::<AIR>(proof, pub_inputs, public_coin_seed)
+}
+
+/// Verifies the specified proof using a public coin initialized with the specified seed.
+#[rustfmt::skip]
+fn verify_with_seed<AIR: Air>(
+    proof: StarkProof,
+    pub_inputs: AIR::PublicInputs,
+    public_coin_seed: Vec<u8>,
+) -> Result<(), VerifierError> {
     // create AIR instance for the computation specified in the proof
     let air = AIR::new(proof.get_trace_info(), pub_inputs, proof.options().clone());
 
     // figure out which version of the generic proof verification procedure to run. this is a sort
     // of static dispatch for selecting two generic parameter: extension field and hash function.
+    let hash_fn = air.options().hash_fn();
+    if hash_fn == HashFunction::Poseidon64_256 && !<Poseidon64_256<AIR::BaseField>>::is_supported() {
+        return Err(VerifierError::UnsupportedHashFunction(hash_fn));
+    }
     match air.options().field_extension() {
         FieldExtension::None => match air.options().hash_fn() {
             HashFunction::Blake3_256 => {
@@ -117,6 +179,16 @@ pub fn verify<AIR: Air>(
                 let channel = VerifierChannel::new(&air, proof)?;
                 perform_verification::<AIR, AIR::BaseField, Sha3_256<AIR::BaseField>>(air, channel, public_coin)
             }
+            HashFunction::Keccak256 => {
+                let public_coin = RandomCoin::new(&public_coin_seed);
+                let channel = VerifierChannel::new(&air, proof)?;
+                perform_verification::<AIR, AIR::BaseField, Keccak256<AIR::BaseField>>(air, channel, public_coin)
+            }
+            HashFunction::Poseidon64_256 => {
+                let public_coin = RandomCoin::new(&public_coin_seed);
+                let channel = VerifierChannel::new(&air, proof)?;
+                perform_verification::<AIR, AIR::BaseField, Poseidon64_256<AIR::BaseField>>(air, channel, public_coin)
+            }
         },
         FieldExtension::Quadratic => {
             if !<QuadExtension<AIR::BaseField>>::is_supported() {
@@ -138,6 +210,16 @@ pub fn verify<AIR: Air>(
                     let channel = VerifierChannel::new(&air, proof)?;
                     perform_verification::<AIR, QuadExtension<AIR::BaseField>, Sha3_256<AIR::BaseField>>(air, channel, public_coin)
                 }
+                HashFunction::Keccak256 => {
+                    let public_coin = RandomCoin::new(&public_coin_seed);
+                    let channel = VerifierChannel::new(&air, proof)?;
+                    perform_verification::<AIR, QuadExtension<AIR::BaseField>, Keccak256<AIR::BaseField>>(air, channel, public_coin)
+                }
+                HashFunction::Poseidon64_256 => {
+                    let public_coin = RandomCoin::new(&public_coin_seed);
+                    let channel = VerifierChannel::new(&air, proof)?;
+                    perform_verification::<AIR, QuadExtension<AIR::BaseField>, Poseidon64_256<AIR::BaseField>>(air, channel, public_coin)
+                }
             }
         },
         FieldExtension::Cubic => {
@@ -160,11 +242,88 @@ pub fn verify<AIR: Air>(
                     let channel = VerifierChannel::new(&air, proof)?;
                     perform_verification::<AIR, CubeExtension<AIR::BaseField>, Sha3_256<AIR::BaseField>>(air, channel, public_coin)
                 }
+                HashFunction::Keccak256 => {
+                    let public_coin = RandomCoin::new(&public_coin_seed);
+                    let channel = VerifierChannel::new(&air, proof)?;
+                    perform_verification::<AIR, CubeExtension<AIR::BaseField>, Keccak256<AIR::BaseField>>(air, channel, public_coin)
+                }
+                HashFunction::Poseidon64_256 => {
+                    let public_coin = RandomCoin::new(&public_coin_seed);
+                    let channel = VerifierChannel::new(&air, proof)?;
+                    perform_verification::<AIR, CubeExtension<AIR::BaseField>, Poseidon64_256<AIR::BaseField>>(air, channel, public_coin)
+                }
             }
         },
     }
 }
 
+// CONTINUATION VERIFIER
+// ================================================================================================
+/// Verifies that a long computation split into segments was executed correctly starting with
+/// `initial_state` and ending with `final_state`.
+///
+/// Specifically, for a computation specified by `AIR` type parameter, verifies that:
+/// - Proofs of all segments in the provided continuation `proof` are valid against public inputs
+///   of the respective segments.
+/// - The initial state of the first segment is `initial_state`, and the final state of the last
+///   segment is `final_state`.
+/// - The final state of every segment is equal to the initial state of the next segment.
+///
+/// If the verification is successful, `Ok(())` is returned.
+///
+/// # Errors
+/// Returns an error if the continuation does not contain any segments, if public inputs of any
+/// of the segments could not be deserialized, if boundary states of the segments are not linked
+/// as described above, or if proof of any of the segments is not valid.
+pub fn verify_continuation<AIR: Air>(
+    proof: ContinuationProof,
+    initial_state: &<AIR::PublicInputs as SegmentPublicInputs>::State,
+    final_state: &<AIR::PublicInputs as SegmentPublicInputs>::State,
+) -> Result<(), VerifierError>
+where
+    AIR::PublicInputs: SegmentPublicInputs + Deserializable,
+{
+    if proof.segments.is_empty() {
+        return Err(VerifierError::EmptyContinuation);
+    }
+
+    // read public inputs of all segments and make sure boundary states of the segments are
+    // linked; this is much cheaper than verifying segment proofs, and thus, is done first
+    let mut pub_inputs = Vec::with_capacity(proof.segments.len());
+    for (i, segment) in proof.segments.iter().enumerate() {
+        let mut reader = SliceReader::new(&segment.pub_inputs);
+        let segment_inputs = AIR::PublicInputs::read_from(&mut reader)
+            .map_err(|err| VerifierError::InvalidSegmentPublicInputs(i, err.to_string()))?;
+        if reader.has_more_bytes() {
+            return Err(VerifierError::InvalidSegmentPublicInputs(
+                i,
+                DeserializationError::UnconsumedBytes.to_string(),
+            ));
+        }
+        pub_inputs.push(segment_inputs);
+    }
+
+    if pub_inputs[0].initial_state() != *initial_state {
+        return Err(VerifierError::InitialStateMismatch);
+    }
+    for (i, window) in pub_inputs.windows(2).enumerate() {
+        if window[0].final_state() != window[1].initial_state() {
+            return Err(VerifierError::DisconnectedSegments(i + 1));
+        }
+    }
+    if pub_inputs[pub_inputs.len() - 1].final_state() != *final_state {
+        return Err(VerifierError::FinalStateMismatch);
+    }
+
+    // verify proofs of individual segments
+    for (i, (segment, segment_inputs)) in proof.segments.into_iter().zip(pub_inputs).enumerate() {
+        verify::<AIR>(segment.proof, segment_inputs)
+            .map_err(|err| VerifierError::SegmentVerificationFailed(i, err.into()))?;
+    }
+
+    Ok(())
+}
+
 // VERIFICATION PROCEDURE
 // ================================================================================================
 /// Performs the actual verification by reading the data from the `channel` and making sure it
@@ -526,11 +526,19 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use prover::{
-    crypto, iterators, math, Air, AirContext, Assertion, AuxTraceRandElements, BoundaryConstraint,
-    BoundaryConstraintGroup, ByteReader, ByteWriter, ConstraintCompositionCoefficients,
-    ConstraintDivisor, DeepCompositionCoefficients, Deserializable, DeserializationError,
-    EvaluationFrame, FieldExtension, HashFunction, Matrix, ProofOptions, Prover, ProverError,
-    Serializable, SliceReader, StarkProof, Trace, TraceInfo, TraceLayout, TraceTable,
-    TraceTableFragment, TransitionConstraintDegree, TransitionConstraintGroup,
+    builder, crypto, gadgets, iterators, math, Air, AirContext, Assertion, AuxTraceRandElements,
+    BoundaryConstraint, BoundaryConstraintGroup, ByteReader, ByteWriter,
+    ConstraintCompositionCoefficients, ConstraintDivisor, Context, ContinuationProof,
+    DecodedFriLayer, DecodedProof, DecodedQueries, DeepCompositionCoefficients, Deserializable,
+    DeserializationError, EvaluationFrame, FieldExtension, HashFunction, LdeMatrix, Matrix,
+    MatrixLayout, ProofDeserializationError, ProofOptions, ProofSection, Prover, ProverError,
+    RowMatrix, SegmentProof, SegmentPublicInputs, Serializable, SliceReader, StarkProof, Trace,
+    TraceInfo, TraceLayout, TracePadding, TraceTable, TraceTableFragment,
+    TransitionConstraintDegree, TransitionConstraintGroup, TransitionDivisor,
+};
+pub use verifier::{verify, verify_continuation, verify_legacy, VerifierError};
+
+#[cfg(feature = "std")]
+pub use prover::{
+    DomainCache, LocalTransport, ProverWorker, RequestHandler, SocketTransport, Transport,
 };
-pub use verifier::{verify, VerifierError};